@@ -3,8 +3,15 @@
 //! Provides shared state abstractions for async-loading TUI components,
 //! eliminating duplicated loading/error/refresh patterns.
 
+use crate::errors::ComponentError;
 use std::time::{Duration, Instant};
 
+/// Minimum time between a completed refresh and a user-triggered one
+///
+/// Key presses queued up while a slow fetch is running would otherwise each
+/// trigger another fetch as soon as the first completes.
+pub const MANUAL_REFRESH_DEBOUNCE: Duration = Duration::from_millis(1000);
+
 /// Shared state for async-loading components
 ///
 /// This struct consolidates the common pattern of loading state, error handling,
@@ -36,8 +43,8 @@ pub struct AsyncState<T> {
     data: Option<T>,
     /// Whether data is currently being loaded
     loading: bool,
-    /// Error message if the last load failed
-    error: Option<String>,
+    /// Error if the last load failed
+    error: Option<ComponentError>,
     /// When the data was last successfully refreshed
     last_refresh: Option<Instant>,
     /// Number of consecutive failures (for retry logic)
@@ -96,7 +103,12 @@ impl<T> AsyncState<T> {
 
     /// Get the error message if any
     pub fn error(&self) -> Option<&str> {
-        self.error.as_deref()
+        self.error.as_ref().map(ComponentError::message)
+    }
+
+    /// Get the error, with its kind, if any
+    pub fn component_error(&self) -> Option<&ComponentError> {
+        self.error.as_ref()
     }
 
     /// Get a reference to the data
@@ -147,20 +159,21 @@ impl<T> AsyncState<T> {
     ///
     /// Increments retry count and clears loading state.
     /// Preserves existing data for continued display.
-    pub fn set_error(&mut self, error: impl ToString) {
-        self.error = Some(error.to_string());
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
+        self.error = Some(error.into());
         self.loading = false;
         self.retry_count += 1;
     }
 
     /// Set an error with context about retry count
-    pub fn set_error_with_retry(&mut self, error: impl ToString) {
+    pub fn set_error_with_retry(&mut self, error: impl Into<ComponentError>) {
         self.retry_count += 1;
-        self.error = Some(format!(
-            "{} (retry {})",
-            error.to_string(),
-            self.retry_count
-        ));
+        let retry_count = self.retry_count;
+        self.error = Some(
+            error
+                .into()
+                .map_message(|msg| format!("{} (retry {})", msg, retry_count)),
+        );
         self.loading = false;
     }
 
@@ -211,8 +224,23 @@ impl<T> AsyncState<T> {
     /// Check if auto-refresh should trigger
     ///
     /// Convenience method combining should_refresh with auto_refresh flag.
+    /// Stops after a fatal error (e.g. rejected credentials), which
+    /// retrying cannot fix; a manual refresh still goes through.
     pub fn should_auto_refresh(&self, auto_refresh_enabled: bool, interval: Duration) -> bool {
-        auto_refresh_enabled && self.should_refresh(interval)
+        auto_refresh_enabled
+            && !self.error.as_ref().is_some_and(ComponentError::is_fatal)
+            && self.should_refresh(interval)
+    }
+
+    /// Check if a user-triggered refresh should be started
+    ///
+    /// Returns false while a load is in flight or if the last refresh
+    /// completed within [`MANUAL_REFRESH_DEBOUNCE`].
+    pub fn can_refresh(&self) -> bool {
+        !self.loading
+            && self
+                .last_refresh
+                .is_none_or(|last| last.elapsed() >= MANUAL_REFRESH_DEBOUNCE)
     }
 
     /// Get elapsed time since last refresh
@@ -381,4 +409,34 @@ mod tests {
         // Just loaded, shouldn't auto-refresh
         assert!(!state.should_auto_refresh(true, Duration::from_secs(100)));
     }
+
+    #[test]
+    fn test_auto_refresh_stops_on_fatal_error() {
+        let mut state: AsyncState<i32> = AsyncState::idle();
+        state.set_error(ComponentError::timeout(10));
+        assert!(state.should_auto_refresh(true, Duration::ZERO));
+
+        state.set_error_with_retry(ComponentError::Auth("Authentication failed".into()));
+        assert!(!state.should_auto_refresh(true, Duration::ZERO));
+        assert!(state.can_refresh());
+        assert_eq!(state.error(), Some("Authentication failed (retry 2)"));
+    }
+
+    #[test]
+    fn test_can_refresh() {
+        let mut state: AsyncState<i32> = AsyncState::idle();
+        assert!(state.can_refresh());
+
+        state.start_loading();
+        assert!(!state.can_refresh());
+
+        // Just completed - debounced
+        state.set_data(42);
+        assert!(!state.can_refresh());
+
+        // Failed loads don't update last_refresh, so retry is allowed
+        let mut failed: AsyncState<i32> = AsyncState::new();
+        failed.set_error("boom");
+        assert!(failed.can_refresh());
+    }
 }
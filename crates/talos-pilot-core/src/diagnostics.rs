@@ -3,6 +3,7 @@
 //! This module provides domain types for the diagnostics system,
 //! separated from UI concerns.
 
+use crate::constants::HIGH_RESTART_THRESHOLD;
 use crate::{HasHealth, HealthIndicator};
 use serde::{Deserialize, Serialize};
 
@@ -35,7 +36,7 @@ impl HasHealth for CheckStatus {
 }
 
 /// Category of diagnostic checks
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum CheckCategory {
     System,
     Kubernetes,
@@ -96,6 +97,14 @@ pub struct CniPodInfo {
     pub restart_count: i32,
 }
 
+impl CniPodInfo {
+    /// Whether the pod is up now but has restarted often enough to be
+    /// crash-looping between refreshes
+    pub fn is_flapping(&self) -> bool {
+        self.phase == "Running" && self.ready && self.restart_count >= HIGH_RESTART_THRESHOLD
+    }
+}
+
 /// CNI information from K8s API
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CniInfo {
@@ -116,6 +125,11 @@ impl CniInfo {
             .all(|pod| pod.phase == "Running" && pod.ready)
     }
 
+    /// Healthy-looking pods with a high restart count
+    pub fn flapping_pods(&self) -> Vec<&CniPodInfo> {
+        self.pods.iter().filter(|p| p.is_flapping()).collect()
+    }
+
     /// Get summary of CNI pod health
     pub fn pod_health_summary(&self) -> String {
         if self.pods.is_empty() {
@@ -150,10 +164,23 @@ pub struct UnhealthyPodInfo {
     pub name: String,
     /// Pod namespace
     pub namespace: String,
+    /// Name of the unhealthy container within the pod
+    #[serde(default)]
+    pub container: String,
     /// Container state (e.g., "CrashLoopBackOff", "ImagePullBackOff")
     pub state: String,
     /// Number of restarts
     pub restart_count: i32,
+    /// Why the container is waiting, e.g. the image pull error
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl UnhealthyPodInfo {
+    /// Whether the container never started because its image can't be pulled
+    pub fn is_image_pull_error(&self) -> bool {
+        matches!(self.state.as_str(), "ImagePullBackOff" | "ErrImagePull")
+    }
 }
 
 /// Pod health information from K8s API
@@ -231,6 +258,17 @@ mod tests {
             }],
         };
         assert!(healthy_info.are_pods_healthy());
+        assert!(healthy_info.flapping_pods().is_empty());
+
+        // Running and ready, but restarting over and over
+        let mut flapping_info = healthy_info.clone();
+        flapping_info.pods[0].restart_count = HIGH_RESTART_THRESHOLD;
+        assert!(flapping_info.are_pods_healthy());
+        assert_eq!(flapping_info.flapping_pods().len(), 1);
+
+        // A pod that is down is unhealthy rather than flapping
+        flapping_info.pods[0].ready = false;
+        assert!(flapping_info.flapping_pods().is_empty());
     }
 
     #[test]
@@ -243,13 +281,16 @@ mod tests {
             crashing: vec![UnhealthyPodInfo {
                 name: "app-1".to_string(),
                 namespace: "default".to_string(),
+                container: "app".to_string(),
                 state: "CrashLoopBackOff".to_string(),
                 restart_count: 5,
+                message: None,
             }],
             image_pull_errors: vec![],
             total_pods: 10,
         };
         assert!(unhealthy.has_issues());
         assert_eq!(unhealthy.summary(), "1 crashing");
+        assert!(!unhealthy.crashing[0].is_image_pull_error());
     }
 }
@@ -1,7 +1,9 @@
 //! Error formatting utilities
 //!
-//! Provides user-friendly error message formatting for Talos API errors.
+//! Provides user-friendly error message formatting for Talos API errors,
+//! and the categorized error type components keep in their async state.
 
+use std::fmt;
 use talos_rs::TalosError;
 
 /// Format a TalosError into a user-friendly message
@@ -169,6 +171,112 @@ pub fn categorize_error(error: &TalosError) -> ErrorCategory {
     }
 }
 
+/// Error shown by a component after a failed load
+///
+/// The kind decides how the UI reacts: timeouts and unreachable nodes are
+/// retried by auto-refresh, while rejected credentials stop it until the
+/// user refreshes by hand. Plain strings convert to [`ComponentError::Other`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComponentError {
+    /// The request did not finish in time
+    Timeout(String),
+    /// The node or endpoint could not be reached
+    Unreachable(String),
+    /// Credentials were rejected or are invalid
+    Auth(String),
+    /// The API answered with an error
+    Api(String),
+    /// Anything else, e.g. missing local configuration
+    Other(String),
+}
+
+impl ComponentError {
+    /// Timeout error for a request that ran longer than `timeout_secs`
+    pub fn timeout(timeout_secs: u64) -> Self {
+        ComponentError::Timeout(format_timeout_error(timeout_secs, 0))
+    }
+
+    /// The user-facing message
+    pub fn message(&self) -> &str {
+        match self {
+            ComponentError::Timeout(msg)
+            | ComponentError::Unreachable(msg)
+            | ComponentError::Auth(msg)
+            | ComponentError::Api(msg)
+            | ComponentError::Other(msg) => msg,
+        }
+    }
+
+    /// Whether the error is likely to clear up on its own
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            ComponentError::Timeout(_) | ComponentError::Unreachable(_)
+        )
+    }
+
+    /// Whether retrying without user action is pointless
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, ComponentError::Auth(_))
+    }
+
+    /// Same kind of error with the message prefixed by `context`
+    pub fn context(self, context: &str) -> Self {
+        self.map_message(|msg| format!("{}: {}", context, msg))
+    }
+
+    /// Same kind of error with a rewritten message
+    pub fn map_message(self, f: impl FnOnce(String) -> String) -> Self {
+        match self {
+            ComponentError::Timeout(msg) => ComponentError::Timeout(f(msg)),
+            ComponentError::Unreachable(msg) => ComponentError::Unreachable(f(msg)),
+            ComponentError::Auth(msg) => ComponentError::Auth(f(msg)),
+            ComponentError::Api(msg) => ComponentError::Api(f(msg)),
+            ComponentError::Other(msg) => ComponentError::Other(f(msg)),
+        }
+    }
+}
+
+impl fmt::Display for ComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl From<String> for ComponentError {
+    fn from(msg: String) -> Self {
+        ComponentError::Other(msg)
+    }
+}
+
+impl From<&str> for ComponentError {
+    fn from(msg: &str) -> Self {
+        ComponentError::Other(msg.to_string())
+    }
+}
+
+impl From<&TalosError> for ComponentError {
+    fn from(error: &TalosError) -> Self {
+        let msg = format_talos_error(error);
+        match categorize_error(error) {
+            ErrorCategory::Timeout => ComponentError::Timeout(msg),
+            ErrorCategory::Network => ComponentError::Unreachable(msg),
+            ErrorCategory::Auth => ComponentError::Auth(msg),
+            ErrorCategory::NotFound => ComponentError::Api(msg),
+            ErrorCategory::Other if matches!(error, TalosError::Grpc(_)) => {
+                ComponentError::Api(msg)
+            }
+            ErrorCategory::Config | ErrorCategory::Other => ComponentError::Other(msg),
+        }
+    }
+}
+
+impl From<TalosError> for ComponentError {
+    fn from(error: TalosError) -> Self {
+        ComponentError::from(&error)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,6 +309,35 @@ mod tests {
         assert_eq!(categorize_error(&error), ErrorCategory::Timeout);
     }
 
+    #[test]
+    fn test_component_error_from_talos_error() {
+        let error = ComponentError::from(TalosError::Connection("timeout".to_string()));
+        assert!(matches!(error, ComponentError::Timeout(_)));
+        assert!(error.is_transient());
+
+        let error = ComponentError::from(TalosError::Tls("bad certificate".to_string()));
+        assert!(matches!(error, ComponentError::Auth(_)));
+        assert!(error.is_fatal());
+
+        let status = tonic::Status::internal("boom");
+        let error = ComponentError::from(TalosError::Grpc(status));
+        assert_eq!(error, ComponentError::Api("gRPC error: boom".to_string()));
+
+        let error = ComponentError::from(TalosError::ContextNotFound("ctx".to_string()));
+        assert!(matches!(error, ComponentError::Other(_)));
+        assert!(!error.is_transient() && !error.is_fatal());
+    }
+
+    #[test]
+    fn test_component_error_context_keeps_kind() {
+        let error = ComponentError::timeout(10).context("Failed to fetch stats");
+        assert_eq!(
+            error,
+            ComponentError::Timeout("Failed to fetch stats: Request timed out after 10s".into())
+        );
+        assert_eq!(ComponentError::from("plain").message(), "plain");
+    }
+
     #[test]
     fn test_categorize_config_error() {
         let error = TalosError::ConfigNotFound("/path".to_string());
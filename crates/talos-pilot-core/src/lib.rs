@@ -17,6 +17,7 @@
 //! - [`async_state`] - Async component state management (loading, error, refresh)
 //! - [`errors`] - Error formatting utilities for user-friendly messages
 //! - [`network`] - Network analysis utilities (port mapping, connection classification)
+//! - [`metrics`] - Prometheus text exposition export for network metrics
 //! - [`diagnostics`] - Diagnostic types for health checks and CNI detection
 //! - [`constants`] - Shared constants (thresholds, CRD names, refresh intervals)
 
@@ -26,6 +27,7 @@ pub mod diagnostics;
 pub mod errors;
 pub mod formatting;
 pub mod indicators;
+pub mod metrics;
 pub mod network;
 pub mod selection;
 pub mod types;
@@ -0,0 +1,188 @@
+//! Metrics export utilities
+//!
+//! Renders node network statistics in the Prometheus text exposition format
+//! so they can be scraped or dumped to a file for external monitoring.
+
+use std::fmt::Write;
+use talos_rs::{ConnectionCounts, NetDevStats};
+
+/// Network metrics snapshot for a single node
+#[derive(Debug, Clone)]
+pub struct NodeNetworkMetrics<'a> {
+    /// Node hostname or address
+    pub node: &'a str,
+    /// Per-device interface statistics
+    pub devices: &'a [NetDevStats],
+    /// Connection counts by state
+    pub connections: &'a ConnectionCounts,
+}
+
+/// Interface counters exported per device: (metric suffix, help text, accessor)
+const DEVICE_COUNTERS: &[(&str, &str, fn(&NetDevStats) -> u64)] = &[
+    (
+        "receive_bytes_total",
+        "Bytes received on the interface",
+        |d| d.rx_bytes,
+    ),
+    (
+        "transmit_bytes_total",
+        "Bytes transmitted on the interface",
+        |d| d.tx_bytes,
+    ),
+    (
+        "receive_packets_total",
+        "Packets received on the interface",
+        |d| d.rx_packets,
+    ),
+    (
+        "transmit_packets_total",
+        "Packets transmitted on the interface",
+        |d| d.tx_packets,
+    ),
+    (
+        "receive_errors_total",
+        "Receive errors on the interface",
+        |d| d.rx_errors,
+    ),
+    (
+        "transmit_errors_total",
+        "Transmit errors on the interface",
+        |d| d.tx_errors,
+    ),
+    (
+        "receive_drop_total",
+        "Received packets dropped on the interface",
+        |d| d.rx_dropped,
+    ),
+    (
+        "transmit_drop_total",
+        "Transmitted packets dropped on the interface",
+        |d| d.tx_dropped,
+    ),
+];
+
+/// Escape a Prometheus label value (backslash, double quote and newline)
+pub fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Render network metrics for the given nodes in Prometheus text format
+///
+/// Interface counters are exported as `talos_network_*_total` counters labeled
+/// by node and device, and connection counts as the `talos_network_connections`
+/// gauge labeled by node and TCP state.
+pub fn format_prometheus(nodes: &[NodeNetworkMetrics<'_>]) -> String {
+    let mut out = String::new();
+
+    for (suffix, help, value) in DEVICE_COUNTERS {
+        let name = format!("talos_network_{}", suffix);
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} counter", name);
+        for node in nodes {
+            for device in node.devices {
+                let _ = writeln!(
+                    out,
+                    "{}{{node=\"{}\",device=\"{}\"}} {}",
+                    name,
+                    escape_label_value(node.node),
+                    escape_label_value(&device.name),
+                    value(device)
+                );
+            }
+        }
+    }
+
+    let name = "talos_network_connections";
+    let _ = writeln!(out, "# HELP {} TCP connections by state", name);
+    let _ = writeln!(out, "# TYPE {} gauge", name);
+    for node in nodes {
+        let counts = node.connections;
+        let states = [
+            ("established", counts.established),
+            ("listen", counts.listen),
+            ("time_wait", counts.time_wait),
+            ("close_wait", counts.close_wait),
+            ("syn_sent", counts.syn_sent),
+            ("other", counts.other),
+        ];
+        for (state, count) in states {
+            let _ = writeln!(
+                out,
+                "{}{{node=\"{}\",state=\"{}\"}} {}",
+                name,
+                escape_label_value(node.node),
+                state,
+                count
+            );
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(name: &str) -> NetDevStats {
+        NetDevStats {
+            name: name.to_string(),
+            rx_bytes: 1024,
+            rx_packets: 10,
+            rx_errors: 1,
+            rx_dropped: 2,
+            tx_bytes: 2048,
+            tx_packets: 20,
+            tx_errors: 0,
+            tx_dropped: 0,
+        }
+    }
+
+    #[test]
+    fn test_escape_label_value() {
+        assert_eq!(escape_label_value("eth0"), "eth0");
+        assert_eq!(escape_label_value("a\"b"), "a\\\"b");
+        assert_eq!(escape_label_value("a\\b"), "a\\\\b");
+        assert_eq!(escape_label_value("a\nb"), "a\\nb");
+    }
+
+    #[test]
+    fn test_format_prometheus() {
+        let devices = vec![device("eth0")];
+        let connections = ConnectionCounts {
+            established: 5,
+            listen: 3,
+            ..Default::default()
+        };
+        let output = format_prometheus(&[NodeNetworkMetrics {
+            node: "cp-1",
+            devices: &devices,
+            connections: &connections,
+        }]);
+
+        assert!(output.contains("# TYPE talos_network_receive_bytes_total counter"));
+        assert!(
+            output
+                .contains("talos_network_receive_bytes_total{node=\"cp-1\",device=\"eth0\"} 1024")
+        );
+        assert!(
+            output
+                .contains("talos_network_transmit_bytes_total{node=\"cp-1\",device=\"eth0\"} 2048")
+        );
+        assert!(output.contains("# TYPE talos_network_connections gauge"));
+        assert!(
+            output.contains("talos_network_connections{node=\"cp-1\",state=\"established\"} 5")
+        );
+        assert!(output.contains("talos_network_connections{node=\"cp-1\",state=\"listen\"} 3"));
+    }
+
+    #[test]
+    fn test_format_prometheus_empty() {
+        let output = format_prometheus(&[]);
+        assert!(output.contains("# TYPE talos_network_connections gauge"));
+        assert!(!output.contains("node="));
+    }
+}
@@ -183,6 +183,22 @@ impl<T> SelectableList<T> {
         let _ = old_len; // silence unused warning
     }
 
+    /// Replace all items, keeping the same item selected by identity
+    ///
+    /// The previously selected item is looked up in the new list by `key`.
+    /// If it no longer exists, the selection falls back to the old index
+    /// (clamped to the new bounds).
+    pub fn update_items_by_key<K, F>(&mut self, items: Vec<T>, key: F)
+    where
+        K: PartialEq,
+        F: Fn(&T) -> K,
+    {
+        let previous = self.selected().map(&key);
+        self.items = items;
+        self.selected_index =
+            reselect_index(&self.items, previous.as_ref(), self.selected_index, key);
+    }
+
     /// Push an item to the end
     pub fn push(&mut self, item: T) {
         self.items.push(item);
@@ -265,6 +281,39 @@ impl<'a, T> IntoIterator for &'a SelectableList<T> {
     }
 }
 
+/// Find the new index of a previously selected item after a list refresh
+///
+/// Matches on a stable identity (`key`) rather than position, so the same
+/// item stays selected when rows are added, removed or reordered. Falls back
+/// to `previous_index` clamped to the new bounds when the item disappeared.
+///
+/// # Examples
+///
+/// ```
+/// use talos_pilot_core::selection::reselect_index;
+///
+/// let items = vec!["eth1", "eth0", "lo"];
+/// assert_eq!(reselect_index(&items, Some(&"eth0"), 0, |s| *s), 1);
+/// assert_eq!(reselect_index(&items, Some(&"cni0"), 5, |s| *s), 2);
+/// ```
+pub fn reselect_index<T, K, F>(
+    items: &[T],
+    previous: Option<&K>,
+    previous_index: usize,
+    key: F,
+) -> usize
+where
+    K: PartialEq,
+    F: Fn(&T) -> K,
+{
+    if items.is_empty() {
+        return 0;
+    }
+    previous
+        .and_then(|prev| items.iter().position(|item| key(item) == *prev))
+        .unwrap_or_else(|| previous_index.min(items.len() - 1))
+}
+
 /// A multi-selection list that tracks multiple selected items
 ///
 /// Useful for batch operations where multiple items can be selected.
@@ -503,6 +552,37 @@ mod tests {
         assert_eq!(list.selected(), Some(&"banana"));
     }
 
+    #[test]
+    fn test_selectable_list_update_items_by_key() {
+        let mut list = SelectableList::new(vec!["a", "b", "c"]);
+        list.select(1);
+
+        // Same item moved - follow it
+        list.update_items_by_key(vec!["c", "a", "b"], |s| *s);
+        assert_eq!(list.selected(), Some(&"b"));
+
+        // Item gone - keep position, clamped
+        list.update_items_by_key(vec!["x", "y"], |s| *s);
+        assert_eq!(list.selected_index(), 1);
+
+        list.update_items_by_key(vec![], |s| *s);
+        assert_eq!(list.selected_index(), 0);
+    }
+
+    #[test]
+    fn test_reselect_index() {
+        let items = vec![("sda", 1), ("sdb", 2), ("nvme0n1", 3)];
+
+        assert_eq!(reselect_index(&items, Some(&"sdb"), 0, |d| d.0), 1);
+        assert_eq!(reselect_index(&items, Some(&"sdc"), 1, |d| d.0), 1);
+        assert_eq!(reselect_index(&items, Some(&"sdc"), 7, |d| d.0), 2);
+        assert_eq!(reselect_index(&items, None, 2, |d| d.0), 2);
+        assert_eq!(
+            reselect_index::<(&str, i32), _, _>(&[], Some(&"sda"), 3, |d| d.0),
+            0
+        );
+    }
+
     #[test]
     fn test_multi_select_list() {
         let mut list = MultiSelectList::new(vec!["a", "b", "c"]);
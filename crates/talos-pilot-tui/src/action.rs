@@ -2,8 +2,18 @@
 //!
 //! Actions represent events that can modify application state.
 
+use crate::ui_ext::NotifyLevel;
 use talos_pilot_core::{Cluster, Node};
 
+/// Program run on the real terminal while the TUI is suspended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalProgram {
+    /// The user's shell; exiting it returns to talos-pilot
+    Shell,
+    /// `$VISUAL`/`$EDITOR` on the machine config being viewed
+    EditMachineConfig,
+}
+
 /// Actions that can be dispatched in the application
 #[derive(Debug, Clone)]
 pub enum Action {
@@ -33,28 +43,60 @@ pub enum Action {
     ShowProcesses(String, String),
     /// Show network stats for a node: (hostname, address)
     ShowNetwork(String, String),
-    /// Show diagnostics for a node: (hostname, address, role, controlplane_endpoint)
-    /// The controlplane_endpoint is used for fetching kubeconfig when diagnosing worker nodes
-    ShowDiagnostics(String, String, String, Option<String>),
+    /// Show all connections of a node: (hostname, address)
+    ShowNetworkConnections(String, String),
+    /// Show cluster-wide network stats for nodes: Vec<(hostname, address)>
+    ShowNetworkGroup(Vec<(String, String)>),
+    /// Show diagnostics for a node: (hostname, address, role, controlplane_endpoints)
+    /// The controlplane_endpoints are tried in order for fetching kubeconfig when
+    /// diagnosing worker nodes
+    ShowDiagnostics(String, String, String, Vec<String>),
+    /// Compare diagnostics of two nodes side by side:
+    /// ((hostname, address, role), (hostname, address, role), controlplane_endpoints)
+    ShowDiagnosticsCompare(
+        (String, String, String),
+        (String, String, String),
+        Vec<String>,
+    ),
     /// Apply a diagnostic fix (triggered from confirmation dialog)
     ApplyDiagnosticFix,
+    /// Load logs for the selected unhealthy pod in the diagnostics drill-down
+    LoadDiagnosticPodLogs,
+    /// Show Kubernetes warning events (opened from diagnostics)
+    ShowEvents,
     /// Show security/certificates view
     ShowSecurity,
     /// Show lifecycle/version view
     ShowLifecycle,
     /// Show workload health view
     ShowWorkloads,
+    /// Show Kubernetes pod logs, optionally starting at (namespace, pod)
+    ShowPodLogs(Option<String>, Option<String>),
     /// Show storage/disks view for a node: (hostname, address)
     ShowStorage(String, String),
+    /// Show the machine config of a node: (hostname, address)
+    ShowMachineConfig(String, String),
     /// Show node operations overlay: (hostname, address, is_controlplane)
     ShowNodeOperations(String, String, bool),
     /// Show rolling operations overlay with node list: Vec<(hostname, address, is_controlplane)>
     ShowRollingOperations(Vec<(String, String, bool)>),
+    /// Point the current node view at another node: (hostname, address)
+    RetargetNode(String, String),
 
     // UI state
     Tick,
     Resize(u16, u16),
     Refresh,
+    /// Rebuild the Talos client for the active context, then refresh
+    Reconnect,
+    /// Switch network rates between bytes and bits per second (session-wide)
+    ToggleRateUnit,
+    /// Switch network rates between instantaneous and smoothed (session-wide)
+    ToggleRateSmoothing,
+    /// Suspend the TUI and run an external program on the terminal
+    Suspend(ExternalProgram),
+    /// Show a transient message in the corner without taking focus
+    Notify(String, NotifyLevel),
 
     // Effects
     StartFadeIn,
@@ -79,4 +121,7 @@ pub enum Action {
     WizardComplete(Option<String>),
     /// Tick for wizard polling
     WizardTick,
+    /// Leave the overview and run the bootstrap wizard against a
+    /// maintenance mode node: (endpoint)
+    StartWizard(String),
 }
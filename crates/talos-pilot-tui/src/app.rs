@@ -1,17 +1,39 @@
 //! Application state and main loop
 
-use crate::action::Action;
+use crate::action::{Action, ExternalProgram};
+use crate::components::diagnostics::compare::CompareNode;
+use crate::components::network::{DEFAULT_SMOOTHING_ALPHA, KeyPort, RateUnit, key_ports};
 use crate::components::rolling_operations::RollingNodeInfo;
-use crate::components::wizard::{WizardComponent, WizardState};
+use crate::components::wizard::{
+    DEFAULT_MIN_DISK_SIZE, HealthChecklist, WaitLimits, WizardComponent, WizardState,
+};
 use crate::components::{
-    ClusterComponent, Component, DiagnosticsComponent, EtcdComponent, LifecycleComponent,
-    MultiLogsComponent, NetworkStatsComponent, NodeOperationsComponent, ProcessesComponent,
-    RollingOperationsComponent, SecurityComponent, StorageComponent, WorkloadHealthComponent,
+    ClusterComponent, Component, DiagnosticsCompareComponent, DiagnosticsComponent, EtcdComponent,
+    EventsComponent, LifecycleComponent, MachineConfigComponent, MultiLogsComponent,
+    NetworkGroupComponent, NetworkStatsComponent, NodeOperationsComponent, NodeSwitcherComponent,
+    PodLogsComponent, ProcessesComponent, RollingOperationsComponent, SecurityComponent,
+    StorageComponent, WorkloadHealthComponent,
 };
+use crate::keymap::Keymap;
+use crate::state::default_config_dir;
+use crate::transition::{TRANSITION_FRAME, Transition, TransitionStyle};
 use crate::tui::{self, Tui};
+use crate::ui_ext::{NodeInfo, NotifyLevel, Toasts, glyphs, report_error};
 use color_eyre::Result;
-use crossterm::event::{self, Event, KeyEventKind};
-use std::time::Duration;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use talos_pilot_core::ComponentError;
+use talos_rs::{
+    ConnectionThresholds, ProxyConfig, TalosClient, TalosConfig, sanitize_log_bytes, talosctl_env,
+};
 use tokio::sync::mpsc;
 
 /// Current view in the application
@@ -22,15 +44,53 @@ enum View {
     Etcd,
     Processes,
     Network,
+    NetworkGroup,
     Diagnostics,
+    DiagnosticsCompare,
+    Events,
     Security,
     Lifecycle,
     Workloads,
+    PodLogs,
     Storage,
+    MachineConfig,
     NodeOperations,
     RollingOperations,
 }
 
+/// Verbosity of talos-pilot's own log, switchable at runtime with Ctrl+T
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogLevel {
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// Next level in the Ctrl+T cycle
+    pub fn next(self) -> Self {
+        match self {
+            LogLevel::Info => LogLevel::Debug,
+            LogLevel::Debug => LogLevel::Trace,
+            LogLevel::Trace => LogLevel::Info,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+/// Applies a log level to the running tracing subscriber
+///
+/// Provided by the binary, which owns the subscriber's reload handle.
+pub type LogLevelSetter = Box<dyn Fn(LogLevel) -> std::result::Result<(), String>>;
+
 /// Main application state
 pub struct App {
     /// Whether the application should quit
@@ -47,34 +107,122 @@ pub struct App {
     processes: Option<ProcessesComponent>,
     /// Network stats component (created when viewing network)
     network: Option<NetworkStatsComponent>,
+    /// Cluster-wide network component (created when viewing all nodes)
+    network_group: Option<NetworkGroupComponent>,
     /// Diagnostics component (created when viewing diagnostics)
     diagnostics: Option<DiagnosticsComponent>,
+    /// Two-node diagnostics comparison (created when comparing nodes)
+    diagnostics_compare: Option<DiagnosticsCompareComponent>,
+    /// Kubernetes events component (created when viewing events from diagnostics)
+    events: Option<EventsComponent>,
     /// Security component (created when viewing certificates)
     security: Option<SecurityComponent>,
     /// Lifecycle component (created when viewing versions)
     lifecycle: Option<LifecycleComponent>,
     /// Workload health component (created when viewing workloads)
     workloads: Option<WorkloadHealthComponent>,
+    /// Kubernetes pod logs component (created when viewing pod logs from workloads)
+    pod_logs: Option<PodLogsComponent>,
     /// Storage component (created when viewing disks/volumes)
     storage: Option<StorageComponent>,
+    /// Machine config component (created when viewing a node's config)
+    machine_config: Option<MachineConfigComponent>,
     /// Node operations component (overlay for node operations)
     node_operations: Option<NodeOperationsComponent>,
     /// Rolling operations component (overlay for multi-node operations)
     rolling_operations: Option<RollingOperationsComponent>,
     /// Number of log lines to fetch per service
     tail_lines: i32,
+    /// Unit for network rates, kept across network views for the session
+    rate_unit: RateUnit,
+    /// Show smoothed network rates, kept across network views for the session
+    rate_smoothing: bool,
+    /// Weight of the newest sample in smoothed network rates
+    smoothing_alpha: f64,
+    /// Connection counts that trigger warnings in the network view
+    conn_thresholds: ConnectionThresholds,
+    /// Ports shown in the network view's service health row
+    key_ports: Vec<KeyPort>,
     /// Tick rate for animations (ms)
     tick_rate: Duration,
     /// Channel for async action results
     action_rx: mpsc::UnboundedReceiver<AsyncResult>,
-    #[allow(dead_code)] // Will be used for background log streaming
     action_tx: mpsc::UnboundedSender<AsyncResult>,
+    /// Watches talosconfig for edits (kept alive for the watch to stay active)
+    config_watcher: Option<notify::RecommendedWatcher>,
+    /// When talosconfig last changed, while a reload is pending
+    config_changed_at: Option<Instant>,
     /// Custom config file path (from --config flag)
     config_path: Option<String>,
     /// Whether running in insecure mode (no TLS)
     insecure: bool,
     /// Endpoint for insecure mode
     insecure_endpoint: Option<String>,
+    /// Maintenance mode endpoint to hand to the wizard once the overview exits
+    switch_to_wizard: Option<String>,
+    /// Context of a freshly bootstrapped cluster to open once the wizard exits
+    switch_to_context: Option<String>,
+    /// External program to hand the terminal to; run by the main loop, which
+    /// owns the terminal
+    suspend_request: Option<ExternalProgram>,
+    /// Whether the `?` key help overlay is shown
+    show_help: bool,
+    /// Quit was requested while an operation was running; waiting for y/n
+    confirm_quit: bool,
+    /// Server certificate verification is off (--insecure-skip-verify)
+    skip_verify: bool,
+    /// talos-pilot's own log file
+    log_path: Option<PathBuf>,
+    /// Tail of the log file while the `~` overlay is shown
+    log_tail: Option<Vec<String>>,
+    /// Node switcher overlay, opened with Ctrl+P in a node view
+    node_switcher: Option<NodeSwitcherComponent>,
+    /// User key remapping from ~/.talos-pilot/keymap.yaml
+    keymap: Keymap,
+    /// Current verbosity of talos-pilot's own log
+    log_level: LogLevel,
+    /// Changes the log level at runtime; Ctrl+T is inert without it
+    set_log_level: Option<LogLevelSetter>,
+    /// Transient messages shown in the bottom-right corner
+    toasts: Toasts,
+    /// Give-up limits for the wizard's wait states
+    wait_limits: WaitLimits,
+    /// Wizard stops after generating config, without touching the node
+    wizard_dry_run: bool,
+    /// Effect played when switching views (None disables animations)
+    transition_style: Option<TransitionStyle>,
+    /// Transition currently playing over the view
+    transition: Option<Transition>,
+    /// View drawn last frame, to notice view switches
+    drawn_view: View,
+    /// Smallest install disk (bytes) the wizard accepts
+    wizard_min_disk_size: u64,
+    /// Machine config patches the wizard merges into generated config
+    wizard_config_patches: Vec<String>,
+    /// Directory the wizard generates configs under, per cluster
+    wizard_output_dir: String,
+    /// Why the configured wizard output directory couldn't be used
+    wizard_output_dir_warning: Option<String>,
+    /// Saved diagnostics snapshot to show instead of connecting
+    diagnostics_snapshot: Option<PathBuf>,
+}
+
+/// How long the wizard's Kubernetes health checks may take per poll
+const WIZARD_K8S_PROBE_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Result of a wizard wait-state probe
+enum WizardProbeOutput {
+    /// talosctl invocation (WaitingReboot)
+    Command(std::result::Result<std::process::Output, String>),
+    /// Checklist reached, and why the next item isn't done (WaitingHealthy)
+    Health(HealthChecklist, Option<String>),
+}
+
+/// A wizard wait-state probe running in the background
+struct WizardProbe {
+    /// State the probe was started for
+    state: WizardState,
+    handle: tokio::task::JoinHandle<WizardProbeOutput>,
 }
 
 /// Results from async operations
@@ -85,11 +233,13 @@ enum AsyncResult {
     Refreshed,
     LogsLoaded(String),
     Error(String),
+    /// talosconfig was written, renamed over, or removed
+    ConfigChanged,
 }
 
 impl Default for App {
     fn default() -> Self {
-        Self::new(None, None, 500, false, None)
+        Self::new(None, None, 500, false, None, None)
     }
 }
 
@@ -100,35 +250,253 @@ impl App {
         tail_lines: i32,
         insecure: bool,
         insecure_endpoint: Option<String>,
+        proxy: Option<ProxyConfig>,
     ) -> Self {
         let (action_tx, action_rx) = mpsc::unbounded_channel();
         Self {
             should_quit: false,
             view: View::Cluster,
-            cluster: ClusterComponent::new(config_path.clone(), context),
+            cluster: ClusterComponent::new(config_path.clone(), context, proxy),
             multi_logs: None,
             etcd: None,
             processes: None,
             network: None,
+            network_group: None,
             diagnostics: None,
+            diagnostics_compare: None,
+            events: None,
             security: None,
             lifecycle: None,
             workloads: None,
+            pod_logs: None,
             storage: None,
+            machine_config: None,
             node_operations: None,
             rolling_operations: None,
             tail_lines,
+            rate_unit: RateUnit::default(),
+            rate_smoothing: false,
+            smoothing_alpha: DEFAULT_SMOOTHING_ALPHA,
+            conn_thresholds: ConnectionThresholds::default(),
+            key_ports: key_ports(&[]),
             tick_rate: Duration::from_millis(100),
             action_rx,
             action_tx,
+            config_watcher: None,
+            config_changed_at: None,
             config_path,
             insecure,
             insecure_endpoint,
+            switch_to_wizard: None,
+            switch_to_context: None,
+            suspend_request: None,
+            show_help: false,
+            confirm_quit: false,
+            skip_verify: false,
+            log_path: None,
+            log_tail: None,
+            node_switcher: None,
+            keymap: Keymap::load(),
+            log_level: LogLevel::default(),
+            set_log_level: None,
+            toasts: Toasts::default(),
+            wait_limits: WaitLimits::default(),
+            wizard_dry_run: false,
+            transition_style: Some(TransitionStyle::default()),
+            transition: None,
+            drawn_view: View::Cluster,
+            wizard_min_disk_size: DEFAULT_MIN_DISK_SIZE,
+            wizard_output_dir: default_config_dir(),
+            wizard_output_dir_warning: None,
+            wizard_config_patches: Vec::new(),
+            diagnostics_snapshot: None,
+        }
+    }
+
+    /// Set the path of talos-pilot's log file, viewable with `~`
+    pub fn with_log_path(mut self, path: PathBuf) -> Self {
+        self.log_path = Some(path);
+        self
+    }
+
+    /// Open the diagnostics snapshot at `path` read-only instead of
+    /// connecting to a cluster
+    pub fn with_diagnostics_snapshot(mut self, path: PathBuf) -> Self {
+        self.diagnostics_snapshot = Some(path);
+        self
+    }
+
+    /// Start at `level` and let Ctrl+T change it through `setter`
+    pub fn with_log_level(mut self, level: LogLevel, setter: LogLevelSetter) -> Self {
+        self.log_level = level;
+        self.set_log_level = Some(setter);
+        self
+    }
+
+    /// Move to the next log level, keeping the current one if the
+    /// subscriber rejects the change
+    fn cycle_log_level(&mut self) {
+        let Some(set_log_level) = &self.set_log_level else {
+            return;
+        };
+        let level = self.log_level.next();
+        match set_log_level(level) {
+            Ok(()) => {
+                self.log_level = level;
+                self.toasts
+                    .push(format!("log level: {}", level.label()), NotifyLevel::Info);
+                tracing::info!("Log level set to {}", level.label());
+            }
+            Err(e) => tracing::warn!("Failed to set log level to {}: {}", level.label(), e),
+        }
+    }
+
+    /// Path of talos-pilot's log file, if known
+    pub fn log_path(&self) -> Option<&Path> {
+        self.log_path.as_deref()
+    }
+
+    /// Load the end of the log file for the `~` overlay
+    fn open_log_tail(&mut self) {
+        let lines = match &self.log_path {
+            Some(path) => read_log_tail(path, LOG_TAIL_LINES),
+            None => vec!["Log file location unknown".to_string()],
+        };
+        self.log_tail = Some(lines);
+    }
+
+    /// Override how long the insecure-mode wizard waits for the node
+    pub fn with_wait_limits(mut self, limits: WaitLimits) -> Self {
+        self.wait_limits = limits;
+        self
+    }
+
+    /// Config patches (inline YAML or `@file`) for the wizard's generated config
+    pub fn with_wizard_config_patches(mut self, patches: Vec<String>) -> Self {
+        self.wizard_config_patches = patches;
+        self
+    }
+
+    /// Directory the wizard generates configs under, in a subdirectory per cluster
+    pub fn with_wizard_output_dir(mut self, dir: String) -> Self {
+        self.wizard_output_dir = dir;
+        self
+    }
+
+    /// Have the wizard confirm before generating when the configured output
+    /// directory couldn't be read and the default is used instead
+    pub fn with_wizard_output_dir_warning(mut self, warning: Option<String>) -> Self {
+        self.wizard_output_dir_warning = warning;
+        self
+    }
+
+    /// Smallest disk (bytes) the wizard lets you install to
+    pub fn with_wizard_min_disk_size(mut self, bytes: u64) -> Self {
+        self.wizard_min_disk_size = bytes;
+        self
+    }
+
+    /// Choose the effect played when switching views, or None for instant switches
+    pub fn with_transitions(mut self, style: Option<TransitionStyle>) -> Self {
+        self.transition_style = style;
+        self
+    }
+
+    /// Stop the wizard once config is generated, leaving the node untouched
+    pub fn with_wizard_dry_run(mut self, dry_run: bool) -> Self {
+        self.wizard_dry_run = dry_run;
+        self
+    }
+
+    /// Override the TIME_WAIT/CLOSE_WAIT counts that trigger network warnings
+    pub fn with_connection_thresholds(mut self, thresholds: ConnectionThresholds) -> Self {
+        self.conn_thresholds = thresholds;
+        self
+    }
+
+    /// Override how strongly the network view smooths rates
+    pub fn with_smoothing_alpha(mut self, alpha: f64) -> Self {
+        self.smoothing_alpha = alpha;
+        self
+    }
+
+    /// Override the ports shown in the network view's service health row
+    pub fn with_key_ports(mut self, key_ports: Vec<KeyPort>) -> Self {
+        self.key_ports = key_ports;
+        self
+    }
+
+    /// Connect to the selected context without verifying its server certificate
+    pub fn with_skip_verify(mut self, skip_verify: bool) -> Self {
+        self.skip_verify = skip_verify;
+        self.cluster.set_skip_verify(skip_verify);
+        self
+    }
+
+    /// Watch talosconfig so added or edited contexts show up without a restart
+    ///
+    /// The parent directory is watched rather than the file, because editors
+    /// and `talosctl config` usually save by renaming a new file over the old
+    /// one, which a watch on the original file would not survive.
+    fn watch_config(&mut self) {
+        use notify::Watcher;
+
+        let path = match &self.config_path {
+            Some(path) => PathBuf::from(path),
+            None => match TalosConfig::default_path() {
+                Ok(path) => path,
+                Err(e) => {
+                    tracing::warn!("Not watching talosconfig: {}", e);
+                    return;
+                }
+            },
+        };
+        let Some(file_name) = path.file_name().map(|n| n.to_os_string()) else {
+            return;
+        };
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        let tx = self.action_tx.clone();
+        let watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if let Ok(event) = res
+                && is_config_event(&event, &file_name)
+            {
+                let _ = tx.send(AsyncResult::ConfigChanged);
+            }
+        });
+        match watcher.and_then(|mut w| {
+            w.watch(&dir, notify::RecursiveMode::NonRecursive)
+                .map(|_| w)
+        }) {
+            Ok(w) => {
+                tracing::info!("Watching {} for changes", path.display());
+                self.config_watcher = Some(w);
+            }
+            Err(e) => tracing::warn!("Not watching talosconfig {}: {}", path.display(), e),
         }
     }
 
     /// Run the application
     pub async fn run(&mut self) -> Result<()> {
+        // Load a snapshot before taking over the terminal, so a bad file
+        // fails with a readable error
+        let snapshot = match &self.diagnostics_snapshot {
+            Some(path) => {
+                let mut diagnostics = DiagnosticsComponent::new(
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    self.config_path.clone(),
+                );
+                diagnostics.load_snapshot(path)?;
+                Some(diagnostics)
+            }
+            None => None,
+        };
+
         // Install panic hook
         tui::install_panic_hook();
 
@@ -136,10 +504,10 @@ impl App {
         let mut terminal = tui::init()?;
 
         // Main loop - choose based on mode
-        let result = if self.insecure {
-            self.insecure_loop(&mut terminal).await
+        let result = if let Some(diagnostics) = snapshot {
+            self.snapshot_loop(&mut terminal, diagnostics)
         } else {
-            self.main_loop(&mut terminal).await
+            self.modes_loop(&mut terminal).await
         };
 
         // Restore terminal
@@ -148,6 +516,103 @@ impl App {
         result
     }
 
+    /// Run the overview or the wizard, switching whenever one hands over
+    ///
+    /// The overview hands a maintenance mode node to the wizard, and the
+    /// wizard hands the context of the cluster it bootstrapped back.
+    async fn modes_loop(&mut self, terminal: &mut Tui) -> Result<()> {
+        let mut in_wizard = self.insecure;
+        loop {
+            if in_wizard {
+                self.insecure_loop(terminal).await?;
+                let Some(context) = self.switch_to_context.take() else {
+                    return Ok(());
+                };
+                tracing::info!("Opening context '{}' from the wizard", context);
+                self.cluster.set_context_filter(Some(context));
+                self.view = View::Cluster;
+            } else {
+                self.main_loop(terminal).await?;
+                let Some(endpoint) = self.switch_to_wizard.take() else {
+                    return Ok(());
+                };
+                self.insecure_endpoint = Some(endpoint);
+            }
+            self.should_quit = false;
+            in_wizard = !in_wizard;
+        }
+    }
+
+    /// Snapshot mode event loop - a saved diagnostics snapshot, no cluster
+    fn snapshot_loop(
+        &mut self,
+        terminal: &mut Tui,
+        mut diagnostics: DiagnosticsComponent,
+    ) -> Result<()> {
+        loop {
+            terminal.draw(|frame| {
+                let area = frame.area();
+                let _ = diagnostics.draw(frame, area);
+                self.toasts.draw(frame, area, Instant::now());
+                if self.show_help {
+                    let mut entries = diagnostics.key_help();
+                    entries.push(("~".to_string(), "talos-pilot's own log".to_string()));
+                    draw_help_overlay(frame, area, &entries);
+                }
+                if let Some(lines) = &self.log_tail {
+                    draw_log_overlay(frame, area, self.log_path.as_deref(), lines);
+                }
+            })?;
+
+            if event::poll(self.tick_rate)? {
+                let event = match event::read()? {
+                    Event::Key(key) if !diagnostics.is_capturing_input() => {
+                        Event::Key(self.keymap.translate(key))
+                    }
+                    event => event,
+                };
+                match event {
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press
+                            && (self.show_help || self.log_tail.is_some()) =>
+                    {
+                        // Any key dismisses an overlay
+                        self.show_help = false;
+                        self.log_tail = None;
+                    }
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press
+                            && matches!(key.code, KeyCode::Char('?') | KeyCode::Char('~'))
+                            && !diagnostics.is_capturing_input() =>
+                    {
+                        if key.code == KeyCode::Char('?') {
+                            self.show_help = true;
+                        } else {
+                            self.open_log_tail();
+                        }
+                    }
+                    Event::Key(key) if key.kind == KeyEventKind::Press => {
+                        // Leaving the view quits; the snapshot has nowhere to go back to
+                        match diagnostics.handle_key_event(key)? {
+                            Some(Action::Back | Action::Quit) => self.should_quit = true,
+                            Some(Action::Notify(message, level)) => {
+                                self.toasts.push(message, level)
+                            }
+                            _ => {}
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            if self.should_quit {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Insecure mode event loop - Bootstrap Wizard
     async fn insecure_loop(&mut self, terminal: &mut Tui) -> Result<()> {
         let endpoint = self
@@ -155,34 +620,60 @@ impl App {
             .clone()
             .expect("Insecure mode requires endpoint");
 
-        let mut wizard = WizardComponent::new(endpoint);
+        let mut wizard = WizardComponent::new(endpoint)
+            .with_wait_limits(self.wait_limits)
+            .with_min_disk_size(self.wizard_min_disk_size)
+            .with_output_dir(self.wizard_output_dir.clone())
+            .with_output_dir_warning(self.wizard_output_dir_warning.clone())
+            .with_config_patches(self.wizard_config_patches.clone())
+            .with_dry_run(self.wizard_dry_run);
 
-        // Connect on startup
-        wizard.connect().await?;
+        // Connect on startup; the result arrives on a later tick
+        wizard.start_connect();
 
-        // Polling interval for wait states
+        // Polling interval for wait states (network probes)
         let poll_interval = Duration::from_secs(5);
         let mut last_poll = std::time::Instant::now();
+        // In-flight probe, run in the background so it never stalls the UI
+        let mut probe: Option<WizardProbe> = None;
 
-        // Spinner animation interval
-        let spinner_interval = Duration::from_millis(100);
-        let mut last_spinner = std::time::Instant::now();
+        // Visual tick (spinner, elapsed timers), independent of polling
+        let tick_interval = Duration::from_millis(100);
+        let mut last_tick = std::time::Instant::now();
 
         loop {
-            // Advance spinner for animations
-            if last_spinner.elapsed() >= spinner_interval {
-                last_spinner = std::time::Instant::now();
-                wizard.data_mut().advance_spinner();
+            // Deliver Tick at a steady cadence, regardless of key input
+            if last_tick.elapsed() >= tick_interval {
+                last_tick = std::time::Instant::now();
+                wizard.update(Action::Tick)?;
             }
 
             // Draw
             terminal.draw(|frame| {
                 let _ = wizard.draw(frame, frame.area());
+                self.toasts.draw(frame, frame.area(), Instant::now());
+                if let Some(lines) = &self.log_tail {
+                    draw_log_overlay(frame, frame.area(), self.log_path.as_deref(), lines);
+                }
             })?;
 
-            // Handle events with timeout
-            if event::poll(self.tick_rate)? {
+            // Wait for input only until the next tick is due
+            let timeout = tick_interval.saturating_sub(last_tick.elapsed());
+            if event::poll(timeout)? {
                 match event::read()? {
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press && self.log_tail.is_some() =>
+                    {
+                        // Any key dismisses the log overlay
+                        self.log_tail = None;
+                    }
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press
+                            && key.code == KeyCode::Char('~')
+                            && !wizard.is_capturing_input() =>
+                    {
+                        self.open_log_tail();
+                    }
                     Event::Key(key) if key.kind == KeyEventKind::Press => {
                         if let Some(action) = wizard.handle_key_event(key)? {
                             match action {
@@ -199,14 +690,18 @@ impl App {
                                     self.wizard_bootstrap(&mut wizard).await;
                                 }
                                 Action::WizardRetry => {
-                                    wizard.connect().await?;
+                                    wizard.start_connect();
+                                }
+                                Action::Notify(message, level) => {
+                                    self.toasts.push(message, level);
                                 }
                                 Action::WizardComplete(context) => {
-                                    // Exit wizard - print instructions
+                                    // Exit the wizard into the overview of the new cluster
                                     self.should_quit = true;
-                                    if let Some(ctx) = context {
+                                    if let Some(ctx) = &context {
                                         tracing::info!("Wizard complete. Context: {}", ctx);
                                     }
+                                    self.switch_to_context = context;
                                 }
                                 _ => {}
                             }
@@ -219,10 +714,22 @@ impl App {
                 }
             }
 
-            // Polling for wait states
-            if last_poll.elapsed() >= poll_interval {
+            // Collect a finished probe; the next one waits a full interval
+            if probe.as_ref().is_some_and(|p| p.handle.is_finished())
+                && let Some(finished) = probe.take()
+            {
+                let output = finished
+                    .handle
+                    .await
+                    .unwrap_or_else(|e| WizardProbeOutput::Command(Err(e.to_string())));
+                Self::wizard_apply_probe(&mut wizard, finished.state, output);
+                last_poll = std::time::Instant::now();
+            }
+
+            // Start a probe for wait states (at most one in flight)
+            if probe.is_none() && last_poll.elapsed() >= poll_interval {
                 last_poll = std::time::Instant::now();
-                self.wizard_poll(&mut wizard).await;
+                probe = Self::wizard_spawn_probe(&mut wizard, self.config_path.as_deref());
             }
 
             if self.should_quit {
@@ -235,39 +742,51 @@ impl App {
 
     /// Generate config in wizard
     async fn wizard_generate_config(&self, wizard: &mut WizardComponent) {
-        use talos_rs::gen_config;
+        use talos_rs::gen_config_with_install_disk;
 
         // Extract data we need before any mutations
         let cluster_name = wizard.data().cluster_name.clone();
         let k8s_endpoint = wizard.data().k8s_endpoint.clone();
         let output_dir = wizard.data().output_dir.clone();
         let endpoint = wizard.data().endpoint.clone();
-        // TODO: Use disk with gen_config_with_disk when --install-disk flag support is added
-        let _disk = wizard
-            .data()
-            .selected_disk
-            .as_ref()
-            .map(|d| d.dev_path.clone());
+        let disk = wizard.data().selected_disk.clone();
+        let patches = wizard.data().all_config_patches();
 
         // Build additional SANs
         let sans: Vec<&str> = vec![&endpoint, "127.0.0.1"];
 
-        // Generate config
-        // Note: For now we use gen_config without disk selection
-        // TODO: Add gen_config_with_disk that uses --install-disk flag
-        match gen_config(&cluster_name, &k8s_endpoint, &output_dir, Some(&sans), true).await {
+        // Generate config, pinning the install disk by its stable identifiers
+        match gen_config_with_install_disk(
+            &cluster_name,
+            &k8s_endpoint,
+            &output_dir,
+            Some(&sans),
+            true,
+            disk.as_ref(),
+            &patches,
+        )
+        .await
+        {
+            Ok(result) if wizard.data().dry_run => {
+                // Leave the user's talosconfig alone; the generated one is in output_dir
+                wizard.data_mut().config_result = Some(result);
+                wizard.transition(WizardState::ConfigReady);
+            }
             Ok(result) => {
-                // Merge talosconfig and set endpoint/node
-                let merge_success = self
+                // Merge talosconfig and set endpoint/node; later steps use the context
+                match self
                     .wizard_merge_config(&result.talosconfig_path, &cluster_name, &endpoint)
-                    .await;
-
-                if merge_success {
-                    wizard.data_mut().config_result = Some(result);
-                    wizard.data_mut().context_name = Some(cluster_name);
-                    wizard.transition(WizardState::ConfigReady);
-                } else {
-                    wizard.set_error("Failed to merge talosconfig".to_string());
+                    .await
+                {
+                    Ok(merged_into) => {
+                        wizard.data_mut().config_result = Some(result);
+                        wizard.data_mut().context_name = Some(cluster_name);
+                        wizard.data_mut().merged_into = Some(merged_into);
+                        wizard.transition(WizardState::ConfigReady);
+                    }
+                    Err(e) => {
+                        wizard.set_error(format!("Failed to merge talosconfig: {}", e));
+                    }
                 }
             }
             Err(e) => {
@@ -276,68 +795,77 @@ impl App {
         }
     }
 
-    /// Merge talosconfig into user's config
+    /// `talosctl` command for the wizard, using the `--config` talosconfig
+    /// if one was given
+    fn wizard_talosctl(config_path: Option<&str>) -> tokio::process::Command {
+        let mut command = tokio::process::Command::new("talosctl");
+        command.envs(talosctl_env());
+        if let Some(path) = config_path {
+            command.args(["--talosconfig", path]);
+        }
+        command
+    }
+
+    /// Merge the generated talosconfig into the user's talosconfig (the
+    /// `--config` file, or talosctl's default) and point the new context at
+    /// the node
+    ///
+    /// Returns the talosconfig merged into.
     async fn wizard_merge_config(
         &self,
-        config_path: &str,
+        generated_path: &str,
         context_name: &str,
         endpoint: &str,
-    ) -> bool {
-        use tokio::process::Command;
-
-        // Run: talosctl config merge <path>
-        let merge_output = Command::new("talosctl")
-            .args(["config", "merge", config_path])
-            .output()
-            .await;
-
-        if let Err(e) = &merge_output {
-            tracing::warn!("Failed to run talosctl config merge: {}", e);
-            return false;
-        }
-
-        if let Ok(out) = &merge_output
-            && !out.status.success()
-        {
-            let stderr = String::from_utf8_lossy(&out.stderr);
-            tracing::warn!("Failed to merge config: {}", stderr);
-            return false;
-        }
-
-        // Set the endpoint on the context
-        let endpoint_output = Command::new("talosctl")
-            .args(["--context", context_name, "config", "endpoint", endpoint])
-            .output()
-            .await;
-
-        if let Err(e) = &endpoint_output {
-            tracing::warn!("Failed to set endpoint: {}", e);
-            return false;
-        }
-
-        // Set the node on the context
-        let node_output = Command::new("talosctl")
-            .args(["--context", context_name, "config", "node", endpoint])
-            .output()
-            .await;
-
-        if let Err(e) = &node_output {
-            tracing::warn!("Failed to set node: {}", e);
-            return false;
+    ) -> std::result::Result<PathBuf, String> {
+        let target = match &self.config_path {
+            Some(path) => PathBuf::from(path),
+            None => TalosConfig::default_path().map_err(|e| e.to_string())?,
+        };
+        let steps: [(&str, &[&str]); 3] = [
+            ("merge", &["config", "merge", generated_path]),
+            (
+                "set endpoint",
+                &["--context", context_name, "config", "endpoint", endpoint],
+            ),
+            (
+                "set node",
+                &["--context", context_name, "config", "node", endpoint],
+            ),
+        ];
+
+        for (step, args) in steps {
+            let output = Self::wizard_talosctl(self.config_path.as_deref())
+                .args(args)
+                .output()
+                .await
+                .map_err(|e| format!("failed to run talosctl: {}", e))?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                tracing::warn!("talosctl {} failed: {}", args.join(" "), stderr);
+                return Err(format!("{} failed: {}", step, stderr.trim()));
+            }
         }
 
-        true
+        tracing::info!(
+            "Merged context '{}' into {}",
+            context_name,
+            target.display()
+        );
+        Ok(target)
     }
 
     /// Apply config in wizard
     async fn wizard_apply_config(&self, wizard: &mut WizardComponent) {
-        use std::time::Instant;
         use talos_rs::apply_config_insecure;
 
+        if wizard.data().dry_run {
+            tracing::warn!("Wizard dry run: not applying config");
+            return;
+        }
         wizard.transition(WizardState::Applying);
 
         // Extract values we need before mutating
-        let endpoint = wizard.data().endpoint.clone();
+        let endpoint = wizard.data().maintenance_endpoint().to_string();
         let cluster_name = wizard.data().cluster_name.clone();
         let config_path = {
             let data = wizard.data();
@@ -352,9 +880,8 @@ impl App {
                 Ok(result) => {
                     if result.success {
                         // Start waiting for reboot
-                        wizard.data_mut().wait_started = Some(Instant::now());
                         wizard.data_mut().context_name = Some(cluster_name);
-                        wizard.transition(WizardState::WaitingReboot);
+                        wizard.begin_wait(WizardState::WaitingReboot);
                     } else {
                         wizard.set_error(result.message);
                     }
@@ -370,23 +897,19 @@ impl App {
 
     /// Bootstrap cluster in wizard
     async fn wizard_bootstrap(&self, wizard: &mut WizardComponent) {
-        use std::time::Instant;
-        use tokio::process::Command;
-
         wizard.transition(WizardState::Bootstrapping);
 
         let context = wizard.data().context_name.clone();
 
         if let Some(ctx) = context {
-            let output = Command::new("talosctl")
+            let output = Self::wizard_talosctl(self.config_path.as_deref())
                 .args(["--context", &ctx, "bootstrap"])
                 .output()
                 .await;
 
             match output {
                 Ok(out) if out.status.success() => {
-                    wizard.data_mut().wait_started = Some(Instant::now());
-                    wizard.transition(WizardState::WaitingHealthy);
+                    wizard.begin_wait(WizardState::WaitingHealthy);
                 }
                 Ok(out) => {
                     let stderr = String::from_utf8_lossy(&out.stderr);
@@ -401,82 +924,327 @@ impl App {
         }
     }
 
-    /// Poll for state changes in wait states
-    async fn wizard_poll(&self, wizard: &mut WizardComponent) {
-        use tokio::process::Command;
-
-        match wizard.state() {
-            WizardState::WaitingReboot => {
-                // Increment poll attempts
-                wizard.data_mut().poll_attempts += 1;
-
-                // Check if node is back online (with TLS)
-                if let Some(ctx) = wizard.data().context_name.clone() {
-                    let output = Command::new("talosctl")
-                        .args(["--context", &ctx, "version"])
-                        .output()
-                        .await;
-
-                    match output {
-                        Ok(out) if out.status.success() => {
-                            wizard.data_mut().last_poll_error = None;
-                            wizard.transition(WizardState::ReadyToBootstrap);
-                        }
-                        Ok(out) => {
-                            // Command ran but failed - capture error
-                            let stderr = String::from_utf8_lossy(&out.stderr);
-                            wizard.data_mut().last_poll_error =
-                                Some(stderr.lines().next().unwrap_or("Unknown error").to_string());
-                        }
-                        Err(e) => {
-                            wizard.data_mut().last_poll_error = Some(e.to_string());
-                        }
-                    }
-                }
+    /// Start a background probe for the current wait state, if any
+    fn wizard_spawn_probe(
+        wizard: &mut WizardComponent,
+        config_path: Option<&str>,
+    ) -> Option<WizardProbe> {
+        let state = wizard.state().clone();
+        if !matches!(
+            state,
+            WizardState::WaitingReboot | WizardState::WaitingHealthy
+        ) {
+            return None;
+        }
+        let ctx = wizard.data().context_name.clone()?;
+
+        // Increment poll attempts
+        wizard.data_mut().poll_attempts += 1;
+
+        let handle = if state == WizardState::WaitingReboot {
+            // Check if node is back online (with TLS)
+            let mut command = Self::wizard_talosctl(config_path);
+            command
+                .args(["--context", &ctx, "version"])
+                .kill_on_drop(true);
+            tokio::spawn(async move {
+                WizardProbeOutput::Command(command.output().await.map_err(|e| e.to_string()))
+            })
+        } else {
+            let config_path = config_path.map(str::to_string);
+            tokio::spawn(async move {
+                let (health, error) = Self::wizard_check_health(config_path, ctx).await;
+                WizardProbeOutput::Health(health, error)
+            })
+        };
 
-                // Check for timeout (5 minutes)
-                if let Some(started) = wizard.data().wait_started
-                    && started.elapsed().as_secs() > 300
-                {
-                    wizard.set_error("Timeout waiting for node to reboot".to_string());
-                }
+        Some(WizardProbe { state, handle })
+    }
+
+    /// Walk the WaitingHealthy checklist in order, stopping at the first
+    /// item that isn't done yet
+    ///
+    /// Returns how far it got and why the next item is still pending.
+    async fn wizard_check_health(
+        config_path: Option<String>,
+        ctx: String,
+    ) -> (HealthChecklist, Option<String>) {
+        use crate::components::diagnostics::k8s;
+
+        let mut health = HealthChecklist::default();
+
+        let etcd = Self::wizard_talosctl(config_path.as_deref())
+            .args(["--context", &ctx, "etcd", "status"])
+            .kill_on_drop(true)
+            .output()
+            .await;
+        match etcd {
+            Ok(out) if out.status.success() => health.etcd = true,
+            Ok(out) => {
+                let stderr = String::from_utf8_lossy(&out.stderr);
+                let error = stderr.lines().next().unwrap_or("Unknown error").to_string();
+                return (health, Some(error));
             }
-            WizardState::WaitingHealthy => {
-                // Increment poll attempts
-                wizard.data_mut().poll_attempts += 1;
+            Err(e) => return (health, Some(e.to_string())),
+        }
 
-                // Check if cluster is healthy
-                if let Some(ctx) = wizard.data().context_name.clone() {
-                    // Check etcd health
-                    let output = Command::new("talosctl")
-                        .args(["--context", &ctx, "etcd", "status"])
-                        .output()
-                        .await;
+        // Use the kubeconfig of the new cluster, never the environment's
+        let nodes = async {
+            let config = match &config_path {
+                Some(path) => TalosConfig::load_from(&PathBuf::from(path)),
+                None => TalosConfig::load_default(),
+            }
+            .map_err(|e| e.to_string())?;
+            let context = config.get_context(&ctx).map_err(|e| e.to_string())?;
+            let talos = TalosClient::from_context(context)
+                .await
+                .map_err(|e| e.to_string())?;
+            let client = k8s::create_k8s_client_from_talos(&talos)
+                .await
+                .map_err(|e| e.to_string())?;
+            k8s::count_ready_nodes(&client)
+                .await
+                .map_err(|e| e.to_string())
+        };
+        match tokio::time::timeout(WIZARD_K8S_PROBE_TIMEOUT, nodes).await {
+            Ok(Ok((ready, total))) => {
+                health.api_server = true;
+                health.node_ready = ready > 0;
+                let error = (ready == 0).then(|| format!("{} of {} nodes Ready", ready, total));
+                (health, error)
+            }
+            Ok(Err(e)) => (health, Some(e)),
+            Err(_) => (
+                health,
+                Some(format!(
+                    "Kubernetes API timeout after {}s",
+                    WIZARD_K8S_PROBE_TIMEOUT.as_secs()
+                )),
+            ),
+        }
+    }
 
-                    match output {
-                        Ok(out) if out.status.success() => {
-                            wizard.data_mut().last_poll_error = None;
-                            wizard.transition(WizardState::Complete);
-                        }
-                        Ok(out) => {
-                            let stderr = String::from_utf8_lossy(&out.stderr);
-                            wizard.data_mut().last_poll_error =
-                                Some(stderr.lines().next().unwrap_or("Unknown error").to_string());
-                        }
-                        Err(e) => {
-                            wizard.data_mut().last_poll_error = Some(e.to_string());
-                        }
-                    }
-                }
+    /// Apply the result of a finished probe to the wizard
+    fn wizard_apply_probe(
+        wizard: &mut WizardComponent,
+        probed: WizardState,
+        output: WizardProbeOutput,
+    ) {
+        // The user may have moved on (e.g. retried) while the probe was running
+        if *wizard.state() != probed {
+            return;
+        }
 
-                // Check for timeout (5 minutes)
-                if let Some(started) = wizard.data().wait_started
-                    && started.elapsed().as_secs() > 300
-                {
-                    wizard.set_error("Timeout waiting for cluster to become healthy".to_string());
+        match output {
+            WizardProbeOutput::Health(health, error) => {
+                wizard.apply_health(health, error);
+                if health.is_complete() {
+                    return;
                 }
             }
-            _ => {}
+            WizardProbeOutput::Command(Ok(out)) if out.status.success() => {
+                wizard.data_mut().last_poll_error = None;
+                wizard.transition(WizardState::ReadyToBootstrap);
+                return;
+            }
+            WizardProbeOutput::Command(Ok(out)) => {
+                // Command ran but failed - capture error
+                let stderr = String::from_utf8_lossy(&out.stderr);
+                wizard.data_mut().last_poll_error =
+                    Some(stderr.lines().next().unwrap_or("Unknown error").to_string());
+            }
+            WizardProbeOutput::Command(Err(e)) => {
+                wizard.data_mut().last_poll_error = Some(e);
+            }
+        }
+
+        // Give up once attempts or elapsed time run out
+        if wizard.data().wait_limit_exceeded() {
+            let waiting_for = match probed {
+                WizardState::WaitingReboot => "the node to reboot",
+                _ => "the cluster to become healthy",
+            };
+            wizard.fail_wait(waiting_for);
+        }
+    }
+
+    /// The component that currently receives key input
+    fn active_component(&self) -> Option<&dyn Component> {
+        match self.view {
+            View::Cluster => Some(&self.cluster),
+            View::MultiLogs => self.multi_logs.as_ref().map(|c| c as &dyn Component),
+            View::Etcd => self.etcd.as_ref().map(|c| c as &dyn Component),
+            View::Processes => self.processes.as_ref().map(|c| c as &dyn Component),
+            View::Network => self.network.as_ref().map(|c| c as &dyn Component),
+            View::NetworkGroup => self.network_group.as_ref().map(|c| c as &dyn Component),
+            View::Diagnostics => self.diagnostics.as_ref().map(|c| c as &dyn Component),
+            View::DiagnosticsCompare => self
+                .diagnostics_compare
+                .as_ref()
+                .map(|c| c as &dyn Component),
+            View::Events => self.events.as_ref().map(|c| c as &dyn Component),
+            View::Security => self.security.as_ref().map(|c| c as &dyn Component),
+            View::Lifecycle => self.lifecycle.as_ref().map(|c| c as &dyn Component),
+            View::Workloads => self.workloads.as_ref().map(|c| c as &dyn Component),
+            View::PodLogs => self.pod_logs.as_ref().map(|c| c as &dyn Component),
+            View::Storage => self.storage.as_ref().map(|c| c as &dyn Component),
+            View::MachineConfig => self.machine_config.as_ref().map(|c| c as &dyn Component),
+            View::NodeOperations => self.node_operations.as_ref().map(|c| c as &dyn Component),
+            View::RollingOperations => self
+                .rolling_operations
+                .as_ref()
+                .map(|c| c as &dyn Component),
+        }
+    }
+
+    /// Every component that exists, shown or not
+    fn open_components(&self) -> Vec<&dyn Component> {
+        let mut components: Vec<&dyn Component> = vec![&self.cluster];
+        components.extend(self.multi_logs.as_ref().map(|c| c as &dyn Component));
+        components.extend(self.etcd.as_ref().map(|c| c as &dyn Component));
+        components.extend(self.processes.as_ref().map(|c| c as &dyn Component));
+        components.extend(self.network.as_ref().map(|c| c as &dyn Component));
+        components.extend(self.network_group.as_ref().map(|c| c as &dyn Component));
+        components.extend(self.diagnostics.as_ref().map(|c| c as &dyn Component));
+        components.extend(
+            self.diagnostics_compare
+                .as_ref()
+                .map(|c| c as &dyn Component),
+        );
+        components.extend(self.events.as_ref().map(|c| c as &dyn Component));
+        components.extend(self.security.as_ref().map(|c| c as &dyn Component));
+        components.extend(self.lifecycle.as_ref().map(|c| c as &dyn Component));
+        components.extend(self.workloads.as_ref().map(|c| c as &dyn Component));
+        components.extend(self.pod_logs.as_ref().map(|c| c as &dyn Component));
+        components.extend(self.storage.as_ref().map(|c| c as &dyn Component));
+        components.extend(self.machine_config.as_ref().map(|c| c as &dyn Component));
+        components.extend(self.node_operations.as_ref().map(|c| c as &dyn Component));
+        components.extend(
+            self.rolling_operations
+                .as_ref()
+                .map(|c| c as &dyn Component),
+        );
+        components
+    }
+
+    /// Whether `R` reconnects in the current view
+    ///
+    /// Not while the view is taking typed input, and not in the network
+    /// connections view, where `R` restarts the selected service.
+    fn reconnect_key_available(&self) -> bool {
+        let restarts_service = self.view == View::Network
+            && self
+                .network
+                .as_ref()
+                .is_some_and(|n| n.in_connections_view());
+        !restarts_service
+            && !self
+                .active_component()
+                .is_some_and(|c| c.is_capturing_input())
+    }
+
+    /// Whether remapped keys are translated for the next key event
+    ///
+    /// Typed input (filters, search, the node switcher) sees raw keys.
+    fn keymap_applies(&self) -> bool {
+        self.node_switcher.is_none()
+            && !self
+                .active_component()
+                .is_some_and(|c| c.is_capturing_input())
+    }
+
+    /// Node shown by the current view, if it is a single-node view
+    fn current_view_node(&self) -> Option<&NodeInfo> {
+        match self.view {
+            View::Processes => self.processes.as_ref().map(|c| c.node_info()),
+            View::Network => self.network.as_ref().map(|c| c.node_info()),
+            View::Diagnostics => self.diagnostics.as_ref().map(|c| c.node_info()),
+            View::Storage => self.storage.as_ref().map(|c| c.node_info()),
+            View::MachineConfig => self.machine_config.as_ref().map(|c| c.node_info()),
+            _ => None,
+        }
+    }
+
+    /// Whether Ctrl+P may open the node switcher
+    ///
+    /// Switching rebuilds the view, so not while it is busy or holds
+    /// unapplied machine config edits.
+    fn node_switcher_available(&self) -> bool {
+        self.current_view_node().is_some()
+            && !self
+                .active_component()
+                .is_some_and(|c| c.is_capturing_input() || c.is_busy())
+            && !(self.view == View::MachineConfig
+                && self
+                    .machine_config
+                    .as_ref()
+                    .is_some_and(|c| c.has_unapplied_edits()))
+    }
+
+    /// Point every open view at a freshly connected client
+    ///
+    /// Node-scoped clients keep their targeting; views built on the
+    /// context's client directly get the new one as is.
+    fn rebind_clients(&mut self, client: &TalosClient) {
+        let rebind = |old: Option<&TalosClient>| old.map(|c| c.with_channel_from(client));
+
+        if let Some(multi_logs) = &mut self.multi_logs
+            && let Some(node_client) = rebind(multi_logs.client())
+        {
+            let streaming = multi_logs.is_streaming();
+            multi_logs.set_client(node_client, self.tail_lines);
+            if streaming {
+                multi_logs.start_streaming();
+            }
+        }
+        if let Some(processes) = &mut self.processes
+            && let Some(node_client) = rebind(processes.client())
+        {
+            processes.set_client(node_client);
+        }
+        if let Some(network) = &mut self.network
+            && let Some(node_client) = rebind(network.client())
+        {
+            network.set_client(node_client);
+        }
+        if let Some(network_group) = &mut self.network_group
+            && let Some(group_client) = rebind(network_group.client())
+        {
+            network_group.set_client(group_client);
+        }
+        if let Some(storage) = &mut self.storage
+            && let Some(node_client) = rebind(storage.client())
+        {
+            storage.set_client(node_client);
+        }
+        if let Some(machine_config) = &mut self.machine_config
+            && let Some(node_client) = rebind(machine_config.client())
+        {
+            machine_config.set_client(node_client);
+        }
+        if let Some(diagnostics) = &mut self.diagnostics
+            && let Some(node_client) = rebind(diagnostics.client())
+        {
+            diagnostics.set_client(node_client);
+        }
+
+        if let Some(etcd) = &mut self.etcd {
+            etcd.set_client(client.clone());
+        }
+        if let Some(compare) = &mut self.diagnostics_compare {
+            compare.set_client(client.clone());
+        }
+        if let Some(security) = &mut self.security {
+            security.set_client(client.clone());
+        }
+        if let Some(lifecycle) = &mut self.lifecycle {
+            lifecycle.set_client(client.clone());
+        }
+        if let Some(node_ops) = &mut self.node_operations {
+            node_ops.set_client(client.clone());
+        }
+        if let Some(rolling_ops) = &mut self.rolling_operations {
+            rolling_ops.set_talos_client(client.clone());
         }
     }
 
@@ -484,11 +1252,26 @@ impl App {
     async fn main_loop(&mut self, terminal: &mut Tui) -> Result<()> {
         // Connect on startup
         self.cluster.connect().await?;
+        self.watch_config();
 
+        let mut last_tick = Instant::now();
         loop {
+            if self.view != self.drawn_view {
+                self.drawn_view = self.view.clone();
+                self.transition = self.transition_style.map(Transition::new);
+            }
+
             // Draw current view
             terminal.draw(|frame| {
-                let area = frame.area();
+                let mut area = frame.area();
+
+                // Persistent warning while server certificates are not verified
+                if self.skip_verify {
+                    let [banner, rest] =
+                        Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+                    draw_skip_verify_banner(frame, banner, self.cluster.unverified_context());
+                    area = rest;
+                }
                 match self.view {
                     View::Cluster => {
                         let _ = self.cluster.draw(frame, area);
@@ -513,11 +1296,26 @@ impl App {
                             let _ = network.draw(frame, area);
                         }
                     }
+                    View::NetworkGroup => {
+                        if let Some(network_group) = &mut self.network_group {
+                            let _ = network_group.draw(frame, area);
+                        }
+                    }
                     View::Diagnostics => {
                         if let Some(diagnostics) = &mut self.diagnostics {
                             let _ = diagnostics.draw(frame, area);
                         }
                     }
+                    View::DiagnosticsCompare => {
+                        if let Some(compare) = &mut self.diagnostics_compare {
+                            let _ = compare.draw(frame, area);
+                        }
+                    }
+                    View::Events => {
+                        if let Some(events) = &mut self.events {
+                            let _ = events.draw(frame, area);
+                        }
+                    }
                     View::Security => {
                         if let Some(security) = &mut self.security {
                             let _ = security.draw(frame, area);
@@ -533,11 +1331,21 @@ impl App {
                             let _ = workloads.draw(frame, area);
                         }
                     }
+                    View::PodLogs => {
+                        if let Some(pod_logs) = &mut self.pod_logs {
+                            let _ = pod_logs.draw(frame, area);
+                        }
+                    }
                     View::Storage => {
                         if let Some(storage) = &mut self.storage {
                             let _ = storage.draw(frame, area);
                         }
                     }
+                    View::MachineConfig => {
+                        if let Some(machine_config) = &mut self.machine_config {
+                            let _ = machine_config.draw(frame, area);
+                        }
+                    }
                     View::NodeOperations => {
                         // Draw cluster in background, then overlay
                         let _ = self.cluster.draw(frame, area);
@@ -553,11 +1361,155 @@ impl App {
                         }
                     }
                 }
+
+                if let Some(transition) = &mut self.transition {
+                    transition.render(frame, area);
+                }
+
+                draw_heartbeat(frame, area, talos_rs::heartbeat::since_last_success());
+                self.toasts.draw(frame, area, Instant::now());
+
+                if let Some(switcher) = &mut self.node_switcher {
+                    let _ = switcher.draw(frame, area);
+                }
+
+                if self.show_help {
+                    let mut entries = self
+                        .active_component()
+                        .map(|c| c.key_help())
+                        .unwrap_or_default();
+                    if self.reconnect_key_available() {
+                        entries.push(("R".to_string(), "Reconnect to the cluster".to_string()));
+                    }
+                    if self.node_switcher_available() {
+                        entries.push(("Ctrl+P".to_string(), "Switch to another node".to_string()));
+                    }
+                    entries.push(("~".to_string(), "talos-pilot's own log".to_string()));
+                    if self.set_log_level.is_some() {
+                        entries.push((
+                            "Ctrl+T".to_string(),
+                            format!("Cycle log level (now {})", self.log_level.label()),
+                        ));
+                    }
+                    entries.push((
+                        "Ctrl+Z".to_string(),
+                        "Suspend to a shell (exit to return)".to_string(),
+                    ));
+                    draw_help_overlay(frame, area, &entries);
+                }
+
+                if let Some(lines) = &self.log_tail {
+                    draw_log_overlay(frame, area, self.log_path.as_deref(), lines);
+                }
+
+                if self.confirm_quit {
+                    draw_quit_confirm(frame, area);
+                }
             })?;
 
-            // Handle events with timeout
-            if event::poll(self.tick_rate)? {
-                match event::read()? {
+            if self.transition.as_ref().is_some_and(Transition::is_done) {
+                self.transition = None;
+            }
+
+            // Handle events with timeout, redrawing faster while a transition plays
+            let timeout = if self.transition.is_some() {
+                TRANSITION_FRAME
+            } else {
+                self.tick_rate
+            };
+            if event::poll(timeout)? {
+                let event = match event::read()? {
+                    Event::Key(key) if self.keymap_applies() => {
+                        Event::Key(self.keymap.translate(key))
+                    }
+                    event => event,
+                };
+                match event {
+                    Event::Key(key) if key.kind == KeyEventKind::Press && self.confirm_quit => {
+                        self.confirm_quit = false;
+                        if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                            self.should_quit = true;
+                        }
+                    }
+                    Event::Key(key) if key.kind == KeyEventKind::Press && self.show_help => {
+                        // Any key dismisses the help overlay
+                        self.show_help = false;
+                    }
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press && self.log_tail.is_some() =>
+                    {
+                        // Any key dismisses the log overlay
+                        self.log_tail = None;
+                    }
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press && self.node_switcher.is_some() =>
+                    {
+                        let action = match &mut self.node_switcher {
+                            Some(switcher) => switcher.handle_key_event(key)?,
+                            None => None,
+                        };
+                        if let Some(action) = action {
+                            self.node_switcher = None;
+                            if !matches!(action, Action::Back) {
+                                self.handle_action(action).await?;
+                            }
+                        }
+                    }
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press
+                            && key.code == KeyCode::Char('?')
+                            && !self
+                                .active_component()
+                                .is_some_and(|c| c.is_capturing_input()) =>
+                    {
+                        self.show_help = true;
+                    }
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press
+                            && key.code == KeyCode::Char('~')
+                            && !self
+                                .active_component()
+                                .is_some_and(|c| c.is_capturing_input()) =>
+                    {
+                        self.open_log_tail();
+                    }
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press
+                            && key.code == KeyCode::Char('R')
+                            && self.reconnect_key_available() =>
+                    {
+                        self.handle_action(Action::Reconnect).await?;
+                    }
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press
+                            && key.code == KeyCode::Char('p')
+                            && key.modifiers.contains(KeyModifiers::CONTROL)
+                            && self.node_switcher_available() =>
+                    {
+                        let current = self
+                            .current_view_node()
+                            .map(|n| n.hostname.clone())
+                            .unwrap_or_default();
+                        self.node_switcher = Some(NodeSwitcherComponent::new(
+                            self.cluster.switcher_nodes(),
+                            current,
+                        ));
+                    }
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press
+                            && key.code == KeyCode::Char('t')
+                            && key.modifiers.contains(KeyModifiers::CONTROL)
+                            && self.set_log_level.is_some() =>
+                    {
+                        self.cycle_log_level();
+                    }
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press
+                            && key.code == KeyCode::Char('z')
+                            && key.modifiers.contains(KeyModifiers::CONTROL) =>
+                    {
+                        self.suspend_request = Some(ExternalProgram::Shell);
+                    }
                     Event::Key(key) if key.kind == KeyEventKind::Press => {
                         let action = match self.view {
                             View::Cluster => self.cluster.handle_key_event(key)?,
@@ -589,6 +1541,13 @@ impl App {
                                     None
                                 }
                             }
+                            View::NetworkGroup => {
+                                if let Some(network_group) = &mut self.network_group {
+                                    network_group.handle_key_event(key)?
+                                } else {
+                                    None
+                                }
+                            }
                             View::Diagnostics => {
                                 if let Some(diagnostics) = &mut self.diagnostics {
                                     diagnostics.handle_key_event(key)?
@@ -596,6 +1555,20 @@ impl App {
                                     None
                                 }
                             }
+                            View::DiagnosticsCompare => {
+                                if let Some(compare) = &mut self.diagnostics_compare {
+                                    compare.handle_key_event(key)?
+                                } else {
+                                    None
+                                }
+                            }
+                            View::Events => {
+                                if let Some(events) = &mut self.events {
+                                    events.handle_key_event(key)?
+                                } else {
+                                    None
+                                }
+                            }
                             View::Security => {
                                 if let Some(security) = &mut self.security {
                                     security.handle_key_event(key)?
@@ -617,6 +1590,13 @@ impl App {
                                     None
                                 }
                             }
+                            View::PodLogs => {
+                                if let Some(pod_logs) = &mut self.pod_logs {
+                                    pod_logs.handle_key_event(key)?
+                                } else {
+                                    None
+                                }
+                            }
                             View::Storage => {
                                 if let Some(storage) = &mut self.storage {
                                     storage.handle_key_event(key)?
@@ -624,6 +1604,13 @@ impl App {
                                     None
                                 }
                             }
+                            View::MachineConfig => {
+                                if let Some(machine_config) = &mut self.machine_config {
+                                    machine_config.handle_key_event(key)?
+                                } else {
+                                    None
+                                }
+                            }
                             View::NodeOperations => {
                                 if let Some(node_ops) = &mut self.node_operations {
                                     node_ops.handle_key_event(key)?
@@ -648,11 +1635,16 @@ impl App {
                     }
                     _ => {}
                 }
-            } else {
+            } else if last_tick.elapsed() >= self.tick_rate {
                 // Tick for animations
+                last_tick = Instant::now();
                 self.handle_action(Action::Tick).await?;
             }
 
+            if let Some(program) = self.suspend_request.take() {
+                self.run_external(terminal, program)?;
+            }
+
             // Check async results (non-blocking)
             while let Ok(result) = self.action_rx.try_recv() {
                 match result {
@@ -671,9 +1663,22 @@ impl App {
                             multi_logs.set_error(e);
                         }
                     }
+                    AsyncResult::ConfigChanged => {
+                        self.config_changed_at = Some(Instant::now());
+                    }
                 }
             }
 
+            // Reload once the burst of events from a single save has settled
+            if self
+                .config_changed_at
+                .is_some_and(|t| t.elapsed() >= CONFIG_RELOAD_DEBOUNCE)
+            {
+                self.config_changed_at = None;
+                tracing::info!("talosconfig changed, reloading contexts");
+                self.cluster.reload_config().await?;
+            }
+
             // Check if we should quit
             if self.should_quit {
                 break;
@@ -683,10 +1688,68 @@ impl App {
         Ok(())
     }
 
+    /// Hand the terminal to `program` and return to the TUI once it exits
+    ///
+    /// A failing program is reported rather than ending the session.
+    fn run_external(&mut self, terminal: &mut Tui, program: ExternalProgram) -> Result<()> {
+        match program {
+            ExternalProgram::Shell => {
+                if let Err(e) = tui::shell(terminal) {
+                    tracing::error!("Shell failed: {:?}", e);
+                }
+            }
+            ExternalProgram::EditMachineConfig => {
+                let Some(machine_config) = &mut self.machine_config else {
+                    return Ok(());
+                };
+                let Some(text) = machine_config.editor_text() else {
+                    return Ok(());
+                };
+                let edited = tui::edit_text(terminal, &text, "machine-config.yaml");
+                machine_config.set_edited(edited);
+            }
+        }
+        Ok(())
+    }
+
     /// Handle an action
     async fn handle_action(&mut self, action: Action) -> Result<()> {
         match action {
             Action::Quit => {
+                if self.open_components().iter().any(|c| c.is_busy()) {
+                    self.confirm_quit = true;
+                } else {
+                    self.should_quit = true;
+                }
+            }
+            Action::Reconnect => {
+                // A plain refresh can't recover a dead channel, so dial again
+                self.cluster.reconnect().await;
+                if let Some(client) = self.cluster.client().cloned() {
+                    self.rebind_clients(&client);
+                }
+                if self.view != View::Cluster {
+                    Box::pin(self.handle_action(Action::Refresh)).await?;
+                }
+            }
+            Action::ToggleRateUnit => {
+                self.rate_unit = self.rate_unit.toggle();
+                if let Some(network) = &mut self.network {
+                    network.set_rate_unit(self.rate_unit);
+                }
+                if let Some(network_group) = &mut self.network_group {
+                    network_group.set_rate_unit(self.rate_unit);
+                }
+            }
+            Action::ToggleRateSmoothing => {
+                self.rate_smoothing = !self.rate_smoothing;
+                if let Some(network) = &mut self.network {
+                    network.set_rate_smoothing(self.rate_smoothing);
+                }
+            }
+            Action::StartWizard(endpoint) => {
+                tracing::info!("Switching to the bootstrap wizard for {}", endpoint);
+                self.switch_to_wizard = Some(endpoint);
                 self.should_quit = true;
             }
             Action::Back => {
@@ -706,9 +1769,29 @@ impl App {
                     }
                     View::Network => {
                         self.network = None;
+                        // Return to the all-nodes view if we drilled down from it
+                        if self.network_group.is_some() {
+                            self.view = View::NetworkGroup;
+                            return Ok(());
+                        }
+                    }
+                    View::NetworkGroup => {
+                        self.network_group = None;
                     }
                     View::Diagnostics => {
-                        self.diagnostics = None;
+                        // Keep a fix that is still applying so quitting can warn about it
+                        if !self.diagnostics.as_ref().is_some_and(|d| d.is_busy()) {
+                            self.diagnostics = None;
+                        }
+                    }
+                    View::DiagnosticsCompare => {
+                        self.diagnostics_compare = None;
+                    }
+                    View::Events => {
+                        // Events are opened from diagnostics, so return there
+                        self.events = None;
+                        self.view = View::Diagnostics;
+                        return Ok(());
                     }
                     View::Security => {
                         self.security = None;
@@ -719,9 +1802,20 @@ impl App {
                     View::Workloads => {
                         self.workloads = None;
                     }
+                    View::PodLogs => {
+                        // Pod logs are opened from workloads, so return there
+                        self.pod_logs = None;
+                        if self.workloads.is_some() {
+                            self.view = View::Workloads;
+                            return Ok(());
+                        }
+                    }
                     View::Storage => {
                         self.storage = None;
                     }
+                    View::MachineConfig => {
+                        self.machine_config = None;
+                    }
                     View::NodeOperations => {
                         self.node_operations = None;
                     }
@@ -734,6 +1828,7 @@ impl App {
                 self.view = View::Cluster;
             }
             Action::Tick => {
+                self.toasts.prune(Instant::now());
                 // Update animations, etc.
                 match self.view {
                     View::Cluster => {
@@ -773,6 +1868,13 @@ impl App {
                             Box::pin(self.handle_action(next_action)).await?;
                         }
                     }
+                    View::NetworkGroup => {
+                        if let Some(network_group) = &mut self.network_group
+                            && let Some(next_action) = network_group.update(Action::Tick)?
+                        {
+                            Box::pin(self.handle_action(next_action)).await?;
+                        }
+                    }
                     View::Diagnostics => {
                         if let Some(diagnostics) = &mut self.diagnostics
                             && let Some(next_action) = diagnostics.update(Action::Tick)?
@@ -780,6 +1882,20 @@ impl App {
                             Box::pin(self.handle_action(next_action)).await?;
                         }
                     }
+                    View::DiagnosticsCompare => {
+                        if let Some(compare) = &mut self.diagnostics_compare
+                            && let Some(next_action) = compare.update(Action::Tick)?
+                        {
+                            Box::pin(self.handle_action(next_action)).await?;
+                        }
+                    }
+                    View::Events => {
+                        if let Some(events) = &mut self.events
+                            && let Some(next_action) = events.update(Action::Tick)?
+                        {
+                            Box::pin(self.handle_action(next_action)).await?;
+                        }
+                    }
                     View::Security => {
                         if let Some(security) = &mut self.security
                             && let Some(next_action) = security.update(Action::Tick)?
@@ -801,6 +1917,13 @@ impl App {
                             Box::pin(self.handle_action(next_action)).await?;
                         }
                     }
+                    View::PodLogs => {
+                        if let Some(pod_logs) = &mut self.pod_logs
+                            && let Some(next_action) = pod_logs.update(Action::Tick)?
+                        {
+                            Box::pin(self.handle_action(next_action)).await?;
+                        }
+                    }
                     View::Storage => {
                         if let Some(storage) = &mut self.storage
                             && let Some(next_action) = storage.update(Action::Tick)?
@@ -808,6 +1931,13 @@ impl App {
                             Box::pin(self.handle_action(next_action)).await?;
                         }
                     }
+                    View::MachineConfig => {
+                        if let Some(machine_config) = &mut self.machine_config
+                            && let Some(next_action) = machine_config.update(Action::Tick)?
+                        {
+                            Box::pin(self.handle_action(next_action)).await?;
+                        }
+                    }
                     View::NodeOperations => {
                         if let Some(node_ops) = &mut self.node_operations
                             && let Some(next_action) = node_ops.update(Action::Tick)?
@@ -837,14 +1967,14 @@ impl App {
                         if let Some(etcd) = &mut self.etcd
                             && let Err(e) = etcd.refresh().await
                         {
-                            etcd.set_error(e.to_string());
+                            etcd.set_error(report_error(&e));
                         }
                     }
                     View::Processes => {
                         if let Some(processes) = &mut self.processes
                             && let Err(e) = processes.refresh().await
                         {
-                            processes.set_error(e.to_string());
+                            processes.set_error(report_error(&e));
                         }
                     }
                     View::Network => {
@@ -862,10 +1992,17 @@ impl App {
                                 network.start_capture_async().await;
                             }
                             if let Err(e) = network.refresh().await {
-                                network.set_error(e.to_string());
+                                network.set_error(report_error(&e));
                             }
                         }
                     }
+                    View::NetworkGroup => {
+                        if let Some(network_group) = &mut self.network_group
+                            && let Err(e) = network_group.refresh().await
+                        {
+                            network_group.set_error(report_error(&e));
+                        }
+                    }
                     View::MultiLogs => {
                         // Multi-logs handles its own streaming refresh
                     }
@@ -873,42 +2010,70 @@ impl App {
                         if let Some(diagnostics) = &mut self.diagnostics
                             && let Err(e) = diagnostics.refresh().await
                         {
-                            diagnostics.set_error(e.to_string());
+                            diagnostics.set_error(report_error(&e));
+                        }
+                    }
+                    View::DiagnosticsCompare => {
+                        if let Some(compare) = &mut self.diagnostics_compare
+                            && let Err(e) = compare.refresh().await
+                        {
+                            compare.set_error(report_error(&e));
+                        }
+                    }
+                    View::Events => {
+                        if let Some(events) = &mut self.events
+                            && let Err(e) = events.refresh().await
+                        {
+                            events.set_error(report_error(&e));
                         }
                     }
                     View::Security => {
                         if let Some(security) = &mut self.security
                             && let Err(e) = security.refresh().await
                         {
-                            security.set_error(e.to_string());
+                            security.set_error(report_error(&e));
                         }
                     }
                     View::Lifecycle => {
                         if let Some(lifecycle) = &mut self.lifecycle
                             && let Err(e) = lifecycle.refresh().await
                         {
-                            lifecycle.set_error(e.to_string());
+                            lifecycle.set_error(report_error(&e));
                         }
                     }
                     View::Workloads => {
                         if let Some(workloads) = &mut self.workloads
                             && let Err(e) = workloads.refresh().await
                         {
-                            workloads.set_error(e.to_string());
+                            workloads.set_error(report_error(&e));
+                        }
+                    }
+                    View::PodLogs => {
+                        if let Some(pod_logs) = &mut self.pod_logs
+                            && let Err(e) = pod_logs.refresh().await
+                        {
+                            pod_logs.set_error(report_error(&e));
                         }
                     }
                     View::Storage => {
                         if let Some(storage) = &mut self.storage
                             && let Err(e) = storage.refresh().await
                         {
-                            storage.set_error(e.to_string());
+                            storage.set_error(report_error(&e));
+                        }
+                    }
+                    View::MachineConfig => {
+                        if let Some(machine_config) = &mut self.machine_config
+                            && let Err(e) = machine_config.refresh().await
+                        {
+                            machine_config.set_error(report_error(&e));
                         }
                     }
                     View::NodeOperations => {
                         if let Some(node_ops) = &mut self.node_operations
                             && let Err(e) = node_ops.refresh().await
                         {
-                            node_ops.set_error(e.to_string());
+                            node_ops.set_error(report_error(&e));
                         }
                     }
                     View::RollingOperations => {
@@ -945,7 +2110,7 @@ impl App {
                             multi_logs.start_streaming();
                         }
                         Err(e) => {
-                            multi_logs.set_error(e.to_string());
+                            multi_logs.set_error(ComponentError::from(&e));
                         }
                     }
                 }
@@ -956,26 +2121,31 @@ impl App {
             Action::ShowNodeDetails(_, _) => {
                 // Legacy - no longer used, we use ShowMultiLogs now
             }
-            Action::ShowDiagnostics(hostname, address, role, cp_endpoint) => {
+            Action::ShowDiagnostics(hostname, address, role, cp_endpoints) => {
                 // Switch to diagnostics view for a node
                 tracing::info!(
-                    "ShowDiagnostics: hostname='{}', address='{}', role='{}', cp_endpoint={:?}",
+                    "ShowDiagnostics: hostname='{}', address='{}', role='{}', cp_endpoints={:?}",
                     hostname,
                     address,
                     role,
-                    cp_endpoint
+                    cp_endpoints
                 );
 
                 // Create diagnostics component
+                let node_info = self.cluster.node_info(&hostname, &address);
+                let boot_history = self.cluster.boot_history(&hostname);
                 let mut diagnostics = DiagnosticsComponent::new(
                     hostname,
                     address.clone(),
                     role,
                     self.config_path.clone(),
                 );
+                diagnostics.set_node_info(node_info);
+                diagnostics.set_boot_history(boot_history);
+                diagnostics.set_context(self.cluster.current_context_name().map(String::from));
 
-                // Set the control plane endpoint for worker nodes to fetch kubeconfig
-                diagnostics.set_controlplane_endpoint(cp_endpoint);
+                // Set the control plane endpoints for worker nodes to fetch kubeconfig
+                diagnostics.set_controlplane_endpoints(cp_endpoints);
 
                 // Set the client and refresh data
                 if let Some(client) = self.cluster.client() {
@@ -984,24 +2154,92 @@ impl App {
                     diagnostics.set_client(node_client);
                     if let Err(e) = diagnostics.refresh().await {
                         tracing::error!("Diagnostics refresh error: {:?}", e);
-                        diagnostics.set_error(e.to_string());
+                        diagnostics.set_error(report_error(&e));
                     }
                 }
 
                 self.diagnostics = Some(diagnostics);
                 self.view = View::Diagnostics;
             }
-            Action::ApplyDiagnosticFix => {
-                // Apply a diagnostic fix (from confirmation dialog)
-                if let Some(diagnostics) = &mut self.diagnostics {
-                    if let Err(e) = diagnostics.apply_pending_fix().await {
-                        diagnostics.set_error(e.to_string());
+            Action::ShowDiagnosticsCompare(left, right, cp_endpoints) => {
+                // Switch to side-by-side diagnostics for two nodes
+                tracing::info!(
+                    "ShowDiagnosticsCompare: '{}' vs '{}', cp_endpoints={:?}",
+                    left.1,
+                    right.1,
+                    cp_endpoints
+                );
+
+                let [left, right] = [left, right].map(|(hostname, address, role)| CompareNode {
+                    hostname,
+                    address,
+                    role,
+                });
+                let mut compare =
+                    DiagnosticsCompareComponent::new(left, right, self.config_path.clone());
+                compare.set_controlplane_endpoints(cp_endpoints);
+
+                if let Some(client) = self.cluster.client() {
+                    compare.set_client(client.clone());
+                    if let Err(e) = compare.refresh().await {
+                        tracing::error!("Diagnostics compare refresh error: {:?}", e);
+                        compare.set_error(report_error(&e));
                     }
-                    // Refresh after applying fix
-                    if let Err(e) = diagnostics.refresh().await {
-                        diagnostics.set_error(e.to_string());
+                }
+
+                self.diagnostics_compare = Some(compare);
+                self.view = View::DiagnosticsCompare;
+            }
+            Action::ApplyDiagnosticFix => {
+                // Apply a diagnostic fix (from confirmation dialog); the component
+                // refreshes itself once the background apply completes
+                if let Some(diagnostics) = &mut self.diagnostics
+                    && let Err(e) = diagnostics.apply_pending_fix().await
+                {
+                    diagnostics.set_error(report_error(&e));
+                }
+            }
+            Action::ShowEvents => {
+                // Switch to Kubernetes warning events view (from diagnostics)
+                tracing::info!("Viewing Kubernetes warning events");
+
+                let Some(diagnostics) = &self.diagnostics else {
+                    return Ok(());
+                };
+                let mut events = EventsComponent::new();
+
+                // Reuse the diagnostics K8s status rather than retrying a known failure
+                if let Some(k8s_error) = diagnostics.k8s_error() {
+                    events.set_k8s_unavailable(k8s_error.to_string());
+                } else if let Some(client) = diagnostics.client() {
+                    match crate::components::diagnostics::k8s::create_k8s_client_via_controlplanes(
+                        client,
+                        diagnostics.controlplane_endpoints(),
+                    )
+                    .await
+                    {
+                        Ok((k8s_client, _)) => events.set_k8s_client(k8s_client),
+                        Err(e) => {
+                            tracing::error!("Failed to create K8s client: {:?}", e);
+                            events.set_k8s_unavailable(e.to_string());
+                        }
                     }
                 }
+
+                if let Err(e) = events.refresh().await {
+                    tracing::error!("Events refresh error: {:?}", e);
+                    events.set_error(report_error(&e));
+                }
+
+                self.events = Some(events);
+                self.view = View::Events;
+            }
+            Action::LoadDiagnosticPodLogs => {
+                if let Some(diagnostics) = &mut self.diagnostics
+                    && let Err(e) = diagnostics.load_pod_logs().await
+                {
+                    diagnostics.set_error(report_error(&e));
+                }
             }
             Action::ShowEtcd => {
                 // Switch to etcd status view
@@ -1014,7 +2252,7 @@ impl App {
                 if let Some(client) = self.cluster.client() {
                     etcd.set_client(client.clone());
                     if let Err(e) = etcd.refresh().await {
-                        etcd.set_error(e.to_string());
+                        etcd.set_error(report_error(&e));
                     }
                 }
 
@@ -1030,7 +2268,9 @@ impl App {
                 );
 
                 // Create processes component
+                let node_info = self.cluster.node_info(&hostname, &address);
                 let mut processes = ProcessesComponent::new(hostname, address.clone());
+                processes.set_node_info(node_info);
 
                 // Set the client and refresh data
                 if let Some(client) = self.cluster.client() {
@@ -1040,7 +2280,7 @@ impl App {
                     processes.set_client(node_client);
                     if let Err(e) = processes.refresh().await {
                         tracing::error!("Process refresh error: {:?}", e);
-                        processes.set_error(e.to_string());
+                        processes.set_error(report_error(&e));
                     }
                 }
 
@@ -1056,7 +2296,14 @@ impl App {
                 );
 
                 // Create network component
+                let node_info = self.cluster.node_info(&hostname, &address);
                 let mut network = NetworkStatsComponent::new(hostname, address.clone());
+                network.set_node_info(node_info);
+                network.set_rate_unit(self.rate_unit);
+                network.set_rate_smoothing(self.rate_smoothing);
+                network.set_smoothing_alpha(self.smoothing_alpha);
+                network.set_connection_thresholds(self.conn_thresholds);
+                network.set_key_ports(self.key_ports.clone());
 
                 // Set the client and refresh data
                 if let Some(client) = self.cluster.client() {
@@ -1066,13 +2313,42 @@ impl App {
                     network.set_client(node_client);
                     if let Err(e) = network.refresh().await {
                         tracing::error!("Network refresh error: {:?}", e);
-                        network.set_error(e.to_string());
+                        network.set_error(report_error(&e));
                     }
                 }
 
                 self.network = Some(network);
                 self.view = View::Network;
             }
+            Action::ShowNetworkConnections(hostname, address) => {
+                // Network view opened straight into its connections drill-down
+                Box::pin(self.handle_action(Action::ShowNetwork(hostname, address))).await?;
+                if let Some(network) = &mut self.network {
+                    network.open_all_connections();
+                }
+            }
+            Action::ShowNetworkGroup(nodes) => {
+                // Switch to aggregated network stats across all nodes
+                tracing::info!("ShowNetworkGroup: {} nodes", nodes.len());
+
+                let mut network_group = NetworkGroupComponent::new(nodes);
+                network_group.set_rate_unit(self.rate_unit);
+                network_group.set_key_ports(self.key_ports.clone());
+                network_group.set_connection_thresholds(self.conn_thresholds);
+
+                if let Some(client) = self.cluster.client() {
+                    // One client targeting every node, responses aggregated by apid
+                    let group_client = client.with_node_list(&network_group.node_addresses());
+                    network_group.set_client(group_client);
+                    if let Err(e) = network_group.refresh().await {
+                        tracing::error!("Network group refresh error: {:?}", e);
+                        network_group.set_error(report_error(&e));
+                    }
+                }
+
+                self.network_group = Some(network_group);
+                self.view = View::NetworkGroup;
+            }
             Action::ShowSecurity => {
                 // Switch to security/certificates view
                 tracing::info!("Viewing security/certificates");
@@ -1087,7 +2363,7 @@ impl App {
 
                 if let Err(e) = security.refresh().await {
                     tracing::error!("Security refresh error: {:?}", e);
-                    security.set_error(e.to_string());
+                    security.set_error(report_error(&e));
                 }
 
                 self.security = Some(security);
@@ -1108,7 +2384,7 @@ impl App {
 
                 if let Err(e) = lifecycle.refresh().await {
                     tracing::error!("Lifecycle refresh error: {:?}", e);
-                    lifecycle.set_error(e.to_string());
+                    lifecycle.set_error(report_error(&e));
                 }
 
                 self.lifecycle = Some(lifecycle);
@@ -1137,12 +2413,30 @@ impl App {
 
                 if let Err(e) = workloads.refresh().await {
                     tracing::error!("Workloads refresh error: {:?}", e);
-                    workloads.set_error(e.to_string());
+                    workloads.set_error(report_error(&e));
                 }
 
                 self.workloads = Some(workloads);
                 self.view = View::Workloads;
             }
+            Action::ShowPodLogs(namespace, pod) => {
+                // Switch to pod logs, reusing the workloads K8s client
+                tracing::info!("Viewing pod logs: {:?}/{:?}", namespace, pod);
+
+                let Some(k8s_client) = self.workloads.as_ref().and_then(|w| w.k8s_client()) else {
+                    return Ok(());
+                };
+                let mut pod_logs = PodLogsComponent::new(self.tail_lines, namespace, pod);
+                pod_logs.set_k8s_client(k8s_client.clone());
+
+                if let Err(e) = pod_logs.refresh().await {
+                    tracing::error!("Pod logs refresh error: {:?}", e);
+                    pod_logs.set_error(report_error(&e));
+                }
+
+                self.pod_logs = Some(pod_logs);
+                self.view = View::PodLogs;
+            }
             Action::ShowStorage(hostname, address) => {
                 // Switch to storage view for a node
                 tracing::info!(
@@ -1156,8 +2450,10 @@ impl App {
                 let config_path = self.cluster.config_path().map(|s| s.to_string());
 
                 // Create storage component with context for authentication
+                let node_info = self.cluster.node_info(&hostname, &address);
                 let mut storage =
                     StorageComponent::new(hostname, address.clone(), context, config_path);
+                storage.set_node_info(node_info);
 
                 // Set the client and refresh data
                 if let Some(client) = self.cluster.client() {
@@ -1166,13 +2462,40 @@ impl App {
                     storage.set_client(node_client);
                     if let Err(e) = storage.refresh().await {
                         tracing::error!("Storage refresh error: {:?}", e);
-                        storage.set_error(e.to_string());
+                        storage.set_error(report_error(&e));
                     }
                 }
 
                 self.storage = Some(storage);
                 self.view = View::Storage;
             }
+            Action::ShowMachineConfig(hostname, address) => {
+                tracing::info!(
+                    "ShowMachineConfig: hostname='{}', address='{}'",
+                    hostname,
+                    address
+                );
+
+                let node_info = self.cluster.node_info(&hostname, &address);
+                let mut machine_config = MachineConfigComponent::new(hostname, address.clone());
+                machine_config.set_node_info(node_info);
+                if let Some(client) = self.cluster.client() {
+                    machine_config.set_client(client.with_node(&address));
+                    if let Err(e) = machine_config.refresh().await {
+                        tracing::error!("Machine config refresh error: {:?}", e);
+                        machine_config.set_error(report_error(&e));
+                    }
+                }
+
+                self.machine_config = Some(machine_config);
+                self.view = View::MachineConfig;
+            }
+            Action::Suspend(program) => {
+                self.suspend_request = Some(program);
+            }
+            Action::Notify(message, level) => {
+                self.toasts.push(message, level);
+            }
             Action::ShowNodeOperations(hostname, address, is_controlplane) => {
                 // Show node operations overlay
                 tracing::info!("Viewing node operations for: {} ({})", hostname, address);
@@ -1188,12 +2511,43 @@ impl App {
                 // Refresh to load safety checks
                 if let Err(e) = node_ops.refresh().await {
                     tracing::error!("Node operations refresh error: {:?}", e);
-                    node_ops.set_error(e.to_string());
+                    node_ops.set_error(report_error(&e));
                 }
 
                 self.node_operations = Some(node_ops);
                 self.view = View::NodeOperations;
             }
+            Action::RetargetNode(hostname, address) => {
+                // Rebuild the current view for the chosen node, the same way
+                // opening it from the overview does
+                tracing::info!(
+                    "Switching {:?} to node {} ({})",
+                    self.view,
+                    hostname,
+                    address
+                );
+                let show = match self.view {
+                    View::Processes => Action::ShowProcesses(hostname, address),
+                    View::Network => Action::ShowNetwork(hostname, address),
+                    View::Storage => Action::ShowStorage(hostname, address),
+                    View::MachineConfig => Action::ShowMachineConfig(hostname, address),
+                    View::Diagnostics => {
+                        let role = self
+                            .cluster
+                            .node_info(&hostname, &address)
+                            .role
+                            .unwrap_or_else(|| "worker".to_string());
+                        let cp_endpoints = if role == "worker" {
+                            self.cluster.get_controlplane_endpoints()
+                        } else {
+                            Vec::new()
+                        };
+                        Action::ShowDiagnostics(hostname, address, role, cp_endpoints)
+                    }
+                    _ => return Ok(()),
+                };
+                Box::pin(self.handle_action(show)).await?;
+            }
             Action::ShowRollingOperations(nodes) => {
                 // Show rolling operations overlay
                 tracing::info!("Viewing rolling operations for {} nodes", nodes.len());
@@ -1265,6 +2619,13 @@ impl App {
                             Box::pin(self.handle_action(next_action)).await?;
                         }
                     }
+                    View::NetworkGroup => {
+                        if let Some(network_group) = &mut self.network_group
+                            && let Some(next_action) = network_group.update(action)?
+                        {
+                            Box::pin(self.handle_action(next_action)).await?;
+                        }
+                    }
                     View::Diagnostics => {
                         if let Some(diagnostics) = &mut self.diagnostics
                             && let Some(next_action) = diagnostics.update(action)?
@@ -1272,6 +2633,20 @@ impl App {
                             Box::pin(self.handle_action(next_action)).await?;
                         }
                     }
+                    View::DiagnosticsCompare => {
+                        if let Some(compare) = &mut self.diagnostics_compare
+                            && let Some(next_action) = compare.update(action)?
+                        {
+                            Box::pin(self.handle_action(next_action)).await?;
+                        }
+                    }
+                    View::Events => {
+                        if let Some(events) = &mut self.events
+                            && let Some(next_action) = events.update(action)?
+                        {
+                            Box::pin(self.handle_action(next_action)).await?;
+                        }
+                    }
                     View::Security => {
                         if let Some(security) = &mut self.security
                             && let Some(next_action) = security.update(action)?
@@ -1293,6 +2668,13 @@ impl App {
                             Box::pin(self.handle_action(next_action)).await?;
                         }
                     }
+                    View::PodLogs => {
+                        if let Some(pod_logs) = &mut self.pod_logs
+                            && let Some(next_action) = pod_logs.update(action)?
+                        {
+                            Box::pin(self.handle_action(next_action)).await?;
+                        }
+                    }
                     View::Storage => {
                         if let Some(storage) = &mut self.storage
                             && let Some(next_action) = storage.update(action)?
@@ -1300,6 +2682,13 @@ impl App {
                             Box::pin(self.handle_action(next_action)).await?;
                         }
                     }
+                    View::MachineConfig => {
+                        if let Some(machine_config) = &mut self.machine_config
+                            && let Some(next_action) = machine_config.update(action)?
+                        {
+                            Box::pin(self.handle_action(next_action)).await?;
+                        }
+                    }
                     View::NodeOperations => {
                         if let Some(node_ops) = &mut self.node_operations
                             && let Some(next_action) = node_ops.update(action)?
@@ -1320,3 +2709,338 @@ impl App {
         Ok(())
     }
 }
+
+/// Draw the red "verification disabled" banner across the top of the screen
+fn draw_skip_verify_banner(frame: &mut Frame, area: Rect, context: Option<&str>) {
+    let target = match context {
+        Some(context) => format!("context '{}'", context),
+        None => "the selected context".to_string(),
+    };
+    let text = format!(
+        " {} TLS CERTIFICATE VERIFICATION DISABLED for {} (--insecure-skip-verify) - the node's identity is not checked",
+        glyphs().warning,
+        target
+    );
+    let banner = Paragraph::new(Line::from(Span::styled(
+        text,
+        Style::default()
+            .fg(Color::White)
+            .bg(Color::Red)
+            .add_modifier(Modifier::BOLD),
+    )))
+    .style(Style::default().bg(Color::Red));
+    frame.render_widget(banner, area);
+}
+
+/// Seconds without a successful API call before the heartbeat shows as stale
+const HEARTBEAT_STALE_SECS: u64 = 30;
+
+/// How long the heartbeat stays bright after a successful API call
+const HEARTBEAT_PULSE: Duration = Duration::from_millis(600);
+
+/// Heartbeat text and style for the time since the last successful API call
+fn heartbeat_label(since: Duration) -> (String, Style) {
+    if since >= Duration::from_secs(HEARTBEAT_STALE_SECS) {
+        (
+            format!("{} API stale {}s ", glyphs().hollow, since.as_secs()),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )
+    } else if since < HEARTBEAT_PULSE {
+        (
+            format!("{} API ", glyphs().filled),
+            Style::default()
+                .fg(Color::LightGreen)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        (
+            format!("{} API ", glyphs().filled),
+            Style::default().fg(Color::Green),
+        )
+    }
+}
+
+/// Draw the API heartbeat in the top-right corner of the screen
+///
+/// Nothing is shown until the first successful call, so startup does not
+/// flash a stale warning.
+fn draw_heartbeat(frame: &mut Frame, area: Rect, since: Option<Duration>) {
+    let Some(since) = since else {
+        return;
+    };
+    let (text, style) = heartbeat_label(since);
+    let width = (text.chars().count() as u16).min(area.width);
+    let corner = Rect::new(area.right().saturating_sub(width), area.y, width, 1);
+    frame.render_widget(Clear, corner);
+    frame.render_widget(Paragraph::new(Span::styled(text, style)), corner);
+}
+
+/// Number of trailing log lines loaded into the `~` overlay
+const LOG_TAIL_LINES: usize = 500;
+
+/// Quiet period after a talosconfig change before it is reloaded
+const CONFIG_RELOAD_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Whether a watcher event on the talosconfig directory touches the config file
+///
+/// Reads (access events) are ignored so the reload itself does not retrigger.
+fn is_config_event(event: &notify::Event, file_name: &std::ffi::OsStr) -> bool {
+    !matches!(event.kind, notify::EventKind::Access(_))
+        && event.paths.iter().any(|p| p.file_name() == Some(file_name))
+}
+
+/// How much of the log file is read at a time, backwards from its end
+const LOG_TAIL_CHUNK: u64 = 64 * 1024;
+
+/// Read the last `max_lines` lines of the log file, without ANSI colour codes
+fn read_log_tail(path: &Path, max_lines: usize) -> Vec<String> {
+    match tail_bytes(path, max_lines) {
+        Ok(bytes) => {
+            let text = sanitize_log_bytes(&bytes);
+            let lines: Vec<&str> = text.lines().collect();
+            lines[lines.len().saturating_sub(max_lines)..]
+                .iter()
+                .map(|l| l.to_string())
+                .collect()
+        }
+        Err(e) => vec![format!("Failed to read {}: {}", path.display(), e)],
+    }
+}
+
+/// The end of a file holding at least its last `max_lines` lines
+///
+/// The log is never rotated and can reach hundreds of MB at trace level, so
+/// it is read backwards a chunk at a time until enough newlines are found.
+fn tail_bytes(path: &Path, max_lines: usize) -> std::io::Result<Vec<u8>> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = std::fs::File::open(path)?;
+    let mut pos = file.metadata()?.len();
+    let mut tail = Vec::new();
+    let mut newlines = 0;
+    // One newline more than lines wanted, as the last line ends in one and the
+    // first may be cut off
+    while pos > 0 && newlines <= max_lines {
+        let len = LOG_TAIL_CHUNK.min(pos);
+        pos -= len;
+        let mut chunk = vec![0; len as usize];
+        file.seek(SeekFrom::Start(pos))?;
+        file.read_exact(&mut chunk)?;
+        newlines += chunk.iter().filter(|&&b| b == b'\n').count();
+        chunk.extend_from_slice(&tail);
+        tail = chunk;
+    }
+    Ok(tail)
+}
+
+/// Draw the tail of talos-pilot's own log over the current view
+fn draw_log_overlay(frame: &mut Frame, area: Rect, path: Option<&Path>, lines: &[String]) {
+    let width = (area.width * 9 / 10).max(20).min(area.width);
+    let height = (area.height * 8 / 10).max(6).min(area.height);
+    let x = area.x + area.width.saturating_sub(width) / 2;
+    let y = area.y + area.height.saturating_sub(height) / 2;
+    let popup = Rect::new(x, y, width, height);
+
+    // Newest lines at the bottom, leaving room for borders and the hint line
+    let visible = height.saturating_sub(3) as usize;
+    let mut content: Vec<Line> = lines[lines.len().saturating_sub(visible)..]
+        .iter()
+        .map(|l| Line::raw(l.as_str()))
+        .collect();
+    if content.is_empty() {
+        content.push(Line::from(Span::styled(
+            " Log is empty",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    content.push(Line::from(Span::styled(
+        " Press any key to close",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let title = match path {
+        Some(path) => format!(" Log: {} ", path.display()),
+        None => " Log ".to_string(),
+    };
+
+    frame.render_widget(Clear, popup);
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    frame.render_widget(Paragraph::new(content).block(block), popup);
+}
+
+fn draw_quit_confirm(frame: &mut Frame, area: Rect) {
+    let width = 48.min(area.width.saturating_sub(4));
+    let height = 6.min(area.height.saturating_sub(2));
+    let popup = Rect::new(
+        area.x + area.width.saturating_sub(width) / 2,
+        area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    );
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(" Operation in progress — quit anyway?"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(" [y]", Style::default().fg(Color::Cyan)),
+            Span::raw(" Quit  "),
+            Span::styled("[any key]", Style::default().fg(Color::Cyan)),
+            Span::raw(" Keep running"),
+        ]),
+    ];
+
+    frame.render_widget(Clear, popup);
+    let block = Block::default()
+        .title(" Quit ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    frame.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+/// Draw the `?` key help overlay centered over the current view
+fn draw_help_overlay(frame: &mut Frame, area: Rect, entries: &[(String, String)]) {
+    let key_width = entries
+        .iter()
+        .map(|(key, _)| key.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut lines: Vec<Line> = entries
+        .iter()
+        .map(|(key, desc)| {
+            Line::from(vec![
+                Span::styled(
+                    format!(" {:<width$}  ", key, width = key_width),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(desc.as_str()),
+            ])
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            " No key bindings for this view",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Press any key to close",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    // Size to content (plus borders and right padding), centered in the view
+    let content_width = lines.iter().map(|l| l.width()).max().unwrap_or(0) as u16;
+    let width = (content_width + 3).min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let x = area.x + area.width.saturating_sub(width) / 2;
+    let y = area.y + area.height.saturating_sub(height) / 2;
+    let popup = Rect::new(x, y, width, height);
+
+    frame.render_widget(Clear, popup);
+    let block = Block::default()
+        .title(" Keys ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    frame.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heartbeat_label() {
+        let (text, style) = heartbeat_label(Duration::from_millis(100));
+        assert!(text.ends_with("API "));
+        assert_eq!(style.fg, Some(Color::LightGreen));
+
+        let (_, style) = heartbeat_label(Duration::from_secs(5));
+        assert_eq!(style.fg, Some(Color::Green));
+
+        let (text, style) = heartbeat_label(Duration::from_secs(42));
+        assert!(text.contains("stale 42s"));
+        assert_eq!(style.fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_log_level_cycle() {
+        let level = LogLevel::default();
+        assert_eq!(level, LogLevel::Info);
+        assert_eq!(level.next(), LogLevel::Debug);
+        assert_eq!(level.next().next(), LogLevel::Trace);
+        assert_eq!(level.next().next().next(), LogLevel::Info);
+    }
+
+    #[test]
+    fn test_read_log_tail_keeps_last_lines() {
+        let path =
+            std::env::temp_dir().join(format!("talos-pilot-tail-{}.log", std::process::id()));
+        std::fs::write(
+            &path,
+            "one\ntwo\n\u{1b}[2m2026-01-01T00:00:00Z\u{1b}[0m \u{1b}[32m INFO\u{1b}[0m Starting\n",
+        )
+        .unwrap();
+        assert_eq!(
+            read_log_tail(&path, 2),
+            vec!["two", "2026-01-01T00:00:00Z  INFO Starting"]
+        );
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(read_log_tail(&path, 2).len(), 1);
+    }
+
+    #[test]
+    fn test_read_log_tail_of_large_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("talos-pilot.log");
+        let text: String = (0..20_000).map(|i| format!("line {}\n", i)).collect();
+        assert!(text.len() as u64 > 2 * LOG_TAIL_CHUNK);
+        std::fs::write(&path, text).unwrap();
+
+        assert_eq!(
+            read_log_tail(&path, 3),
+            vec!["line 19997", "line 19998", "line 19999"]
+        );
+        let all = read_log_tail(&path, 50_000);
+        assert_eq!(all.len(), 20_000);
+        assert_eq!(all[0], "line 0");
+    }
+
+    #[test]
+    fn test_is_config_event_matches_file_name() {
+        use notify::event::{AccessKind, CreateKind, ModifyKind, RenameMode};
+        use notify::{Event, EventKind};
+
+        let config = std::ffi::OsStr::new("config");
+        let event = |kind, path: &str| Event::new(kind).add_path(PathBuf::from(path));
+
+        assert!(is_config_event(
+            &event(EventKind::Modify(ModifyKind::Any), "/home/me/.talos/config"),
+            config
+        ));
+        // Editors that save via rename report the new name as the target
+        assert!(is_config_event(
+            &event(
+                EventKind::Modify(ModifyKind::Name(RenameMode::To)),
+                "/home/me/.talos/config"
+            ),
+            config
+        ));
+        assert!(!is_config_event(
+            &event(
+                EventKind::Create(CreateKind::File),
+                "/home/me/.talos/config.swp"
+            ),
+            config
+        ));
+        assert!(!is_config_event(
+            &event(EventKind::Access(AccessKind::Any), "/home/me/.talos/config"),
+            config
+        ));
+    }
+}
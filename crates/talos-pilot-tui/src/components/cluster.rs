@@ -1,7 +1,11 @@
 //! Cluster component - displays cluster overview with nodes
 
 use crate::action::Action;
-use crate::components::Component;
+use crate::components::diagnostics::core::record_boot_time;
+use crate::components::node_switcher::SwitcherNode;
+use crate::components::{Component, help_entries};
+use crate::state::UiState;
+use crate::ui_ext::{NodeInfo, glyphs};
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -9,14 +13,21 @@ use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
 };
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use talos_rs::{
-    DiscoveryMember, EtcdMemberInfo, MemInfo, NodeCpuInfo, NodeLoadAvg, NodeMemory, NodeServices,
-    ServiceInfo, TalosClient, TalosConfig, VersionInfo, get_discovery_members_with_retry,
+    Context, DiscoveryMember, EtcdMemberInfo, MemInfo, NodeCpuInfo, NodeLoadAvg, NodeMemory,
+    NodeServices, ProxyConfig, ServiceInfo, TalosClient, TalosConfig, VersionInfo,
+    discover_members, endpoint_target,
 };
 
+/// How long to wait for an endpoint to answer the maintenance API
+const MAINTENANCE_PROBE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// How long a talosconfig reload notice stays in the header
+const CONFIG_NOTICE_DURATION: std::time::Duration = std::time::Duration::from_secs(5);
+
 /// Simple etcd status for header display
 #[derive(Debug, Clone, Default)]
 struct EtcdSummary {
@@ -28,6 +39,63 @@ struct EtcdSummary {
     has_quorum: bool,
 }
 
+/// Node health shown in the overview, worst first so it sorts ahead
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum NodeHealth {
+    /// The version probe got no answer
+    Unreachable,
+    /// A service is unhealthy or memory is nearly full
+    Degraded,
+    /// Responding with all services healthy
+    Ready,
+}
+
+impl NodeHealth {
+    fn symbol(&self) -> &'static str {
+        let g = glyphs();
+        match self {
+            NodeHealth::Unreachable => g.cross,
+            NodeHealth::Degraded => g.partial,
+            NodeHealth::Ready => g.filled,
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            NodeHealth::Unreachable => Color::Red,
+            NodeHealth::Degraded => Color::Yellow,
+            NodeHealth::Ready => Color::Green,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            NodeHealth::Unreachable => "Unreachable",
+            NodeHealth::Degraded => "Degraded",
+            NodeHealth::Ready => "Ready",
+        }
+    }
+}
+
+/// Sort order for nodes within the control plane and worker groups
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NodeSort {
+    #[default]
+    Role, // Grouped by role, in discovery order
+    Name,      // Hostname ascending
+    Readiness, // Unreachable, then degraded, then ready
+}
+
+impl NodeSort {
+    pub fn label(&self) -> &'static str {
+        match self {
+            NodeSort::Role => "ROLE",
+            NodeSort::Name => "NAME",
+            NodeSort::Readiness => "READY",
+        }
+    }
+}
+
 /// Which pane is currently focused
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum FocusedPane {
@@ -46,19 +114,21 @@ pub enum NavMenuItem {
     Storage,
     Processes,
     Diagnostics,
+    Config,
     Certs,
     Lifecycle,
     Workloads,
 }
 
 impl NavMenuItem {
-    const ALL: [NavMenuItem; 9] = [
+    const ALL: [NavMenuItem; 10] = [
         NavMenuItem::Logs,
         NavMenuItem::Etcd,
         NavMenuItem::Network,
         NavMenuItem::Storage,
         NavMenuItem::Processes,
         NavMenuItem::Diagnostics,
+        NavMenuItem::Config,
         NavMenuItem::Certs,
         NavMenuItem::Lifecycle,
         NavMenuItem::Workloads,
@@ -72,6 +142,7 @@ impl NavMenuItem {
             NavMenuItem::Storage => "Stor",
             NavMenuItem::Processes => "Proc",
             NavMenuItem::Diagnostics => "Diag",
+            NavMenuItem::Config => "Conf",
             NavMenuItem::Certs => "Certs",
             NavMenuItem::Lifecycle => "Life",
             NavMenuItem::Workloads => "Work",
@@ -86,6 +157,7 @@ impl NavMenuItem {
             NavMenuItem::Storage => "s",
             NavMenuItem::Processes => "p",
             NavMenuItem::Diagnostics => "d",
+            NavMenuItem::Config => "m",
             NavMenuItem::Certs => "c",
             NavMenuItem::Lifecycle => "y",
             NavMenuItem::Workloads => "w",
@@ -129,14 +201,25 @@ struct ClusterData {
     load_avg: Vec<NodeLoadAvg>,
     /// CPU info from nodes
     cpu_info: Vec<NodeCpuInfo>,
+    /// Boot time (seconds since the Unix epoch) by node name
+    boot_times: HashMap<String, u64>,
+    /// Distinct boot times seen across refreshes by node name, oldest first
+    boot_history: HashMap<String, Vec<u64>>,
     /// Etcd members (control plane nodes only)
     etcd_members: Vec<EtcdMemberInfo>,
     /// Discovery members (ALL cluster nodes)
     discovery_members: Vec<DiscoveryMember>,
+    /// Endpoints that failed member discovery on the last refresh, with
+    /// their errors (empty when discovery went through the VIP)
+    discovery_failures: Vec<(String, String)>,
     /// Etcd summary for header
     etcd_summary: Option<EtcdSummary>,
     /// Node hostname to IP mapping
     node_ips: HashMap<String, String>,
+    /// Nodes that did not answer the version probe on the last refresh
+    unreachable: HashSet<String>,
+    /// Endpoint found in maintenance mode when no configured node answered
+    maintenance_endpoint: Option<String>,
     /// Whether this cluster accordion is expanded
     expanded: bool,
     /// Whether control plane group is expanded
@@ -169,16 +252,42 @@ pub struct ClusterComponent {
     config_path: Option<String>,
     /// Specific context to use (from --context flag)
     context_filter: Option<String>,
+    /// Proxy to tunnel the Talos API connection through (from --proxy flag)
+    proxy: Option<ProxyConfig>,
+    /// Skip server certificate verification for the selected context (from --insecure-skip-verify)
+    skip_verify: bool,
+    /// Context currently connected without certificate verification
+    unverified_context: Option<String>,
+    /// Persistent UI state (pinned nodes)
+    ui_state: UiState,
+    /// Node marked for a diagnostics comparison: (hostname, address, role)
+    compare_mark: Option<(String, String, String)>,
+    /// Nodes picked for an ad-hoc group view: (cluster name, node name)
+    group_selection: BTreeSet<(String, String)>,
+    /// Sort order for the node list
+    node_sort: NodeSort,
+    /// Maintenance mode endpoint the user is being asked about
+    maintenance_prompt: Option<String>,
+    /// Maintenance mode endpoints the user declined the wizard for
+    maintenance_dismissed: HashSet<String>,
+    /// Contexts as last loaded, to tell which ones changed on reload
+    contexts: HashMap<String, Context>,
+    /// Result of the last talosconfig reload or reconnect, shown briefly in the header
+    config_notice: Option<(String, std::time::Instant)>,
 }
 
 impl Default for ClusterComponent {
     fn default() -> Self {
-        Self::new(None, None)
+        Self::new(None, None, None)
     }
 }
 
 impl ClusterComponent {
-    pub fn new(config_path: Option<String>, context_filter: Option<String>) -> Self {
+    pub fn new(
+        config_path: Option<String>,
+        context_filter: Option<String>,
+        proxy: Option<ProxyConfig>,
+    ) -> Self {
         Self {
             clusters: Vec::new(),
             active_cluster: 0,
@@ -191,41 +300,189 @@ impl ClusterComponent {
             selected_item: NodeListItem::ClusterHeader(0),
             config_path,
             context_filter,
+            proxy,
+            skip_verify: false,
+            unverified_context: None,
+            ui_state: UiState::load(),
+            compare_mark: None,
+            group_selection: BTreeSet::new(),
+            node_sort: NodeSort::default(),
+            maintenance_prompt: None,
+            maintenance_dismissed: HashSet::new(),
+            contexts: HashMap::new(),
+            config_notice: None,
+        }
+    }
+
+    /// Connect to the selected context without verifying its server certificate
+    ///
+    /// Only the `--context` context (or the talosconfig's current one) is
+    /// affected; every other context is still verified.
+    pub fn set_skip_verify(&mut self, skip_verify: bool) {
+        self.skip_verify = skip_verify;
+    }
+
+    /// Only load `context` (or every context if `None`) on the next connect
+    pub fn set_context_filter(&mut self, context: Option<String>) {
+        self.context_filter = context;
+    }
+
+    /// Context connected without certificate verification, if any
+    pub fn unverified_context(&self) -> Option<&str> {
+        self.unverified_context.as_deref()
+    }
+
+    /// Check if a node is marked as the first side of a diagnostics comparison
+    fn is_compare_marked(&self, node_name: &str) -> bool {
+        self.compare_mark
+            .as_ref()
+            .is_some_and(|(name, _, _)| name == node_name)
+    }
+
+    /// Check if a node is part of the ad-hoc group selection
+    fn is_group_selected(&self, cluster_idx: usize, node_name: &str) -> bool {
+        self.clusters.get(cluster_idx).is_some_and(|c| {
+            self.group_selection
+                .contains(&(c.name.clone(), node_name.to_string()))
+        })
+    }
+
+    /// Add or remove the selected node from the ad-hoc group
+    fn toggle_group_selected(&mut self) {
+        let (NodeListItem::ControlPlaneNode(cluster_idx, _)
+        | NodeListItem::WorkerNode(cluster_idx, _)) = self.selected_item
+        else {
+            return;
+        };
+        let (Some(cluster), Some(node_name)) =
+            (self.clusters.get(cluster_idx), self.current_node_name())
+        else {
+            return;
+        };
+        let key = (cluster.name.clone(), node_name);
+        if !self.group_selection.remove(&key) {
+            self.group_selection.insert(key);
+        }
+    }
+
+    /// Nodes of the active cluster that group views run over
+    ///
+    /// This is the ad-hoc selection when there is one, otherwise every node.
+    fn group_nodes(&self) -> Vec<&VersionInfo> {
+        let Some(cluster) = self.clusters.get(self.active_cluster) else {
+            return Vec::new();
+        };
+        let selected: Vec<_> = cluster
+            .versions
+            .iter()
+            .filter(|v| self.is_group_selected(self.active_cluster, &v.node))
+            .collect();
+        if selected.is_empty() {
+            cluster.versions.iter().collect()
+        } else {
+            selected
         }
     }
 
+    /// Check if a node is pinned in a specific cluster
+    fn is_pinned(&self, cluster_idx: usize, node_name: &str) -> bool {
+        self.clusters
+            .get(cluster_idx)
+            .is_some_and(|c| self.ui_state.is_pinned(&c.name, node_name))
+    }
+
+    /// Whether a node is a control plane node
+    ///
+    /// Nodes running etcd are control plane. An unreachable node reports no
+    /// services, so etcd membership and discovery data decide instead.
+    fn is_controlplane_node(&self, cluster_idx: usize, node_name: &str) -> bool {
+        let Some(cluster) = self.clusters.get(cluster_idx) else {
+            return false;
+        };
+        match self.get_node_services_for(cluster_idx, node_name) {
+            Some(services) => services.iter().any(|svc| svc.id == "etcd"),
+            None => {
+                cluster.etcd_members.iter().any(|m| m.hostname == node_name)
+                    || cluster
+                        .discovery_members
+                        .iter()
+                        .any(|m| m.hostname == node_name && m.machine_type == "controlplane")
+            }
+        }
+    }
+
+    /// Overview health of a node
+    fn node_health(&self, cluster_idx: usize, node_name: &str) -> NodeHealth {
+        if self
+            .clusters
+            .get(cluster_idx)
+            .is_some_and(|c| c.unreachable.contains(node_name))
+        {
+            return NodeHealth::Unreachable;
+        }
+        let mem_pct = self
+            .get_node_memory_for(cluster_idx, node_name)
+            .map(|m| m.usage_percent())
+            .unwrap_or(0.0);
+        let svc_healthy = self
+            .get_node_services_for(cluster_idx, node_name)
+            .map(|services| {
+                services
+                    .iter()
+                    .all(|s| s.health.as_ref().map(|h| h.healthy).unwrap_or(true))
+            })
+            .unwrap_or(true);
+        if svc_healthy && mem_pct < 90.0 {
+            NodeHealth::Ready
+        } else {
+            NodeHealth::Degraded
+        }
+    }
+
+    /// Order nodes by the selected sort, with pinned nodes on top
+    fn sort_nodes(&self, cluster_idx: usize, nodes: &mut [(usize, &VersionInfo)]) {
+        match self.node_sort {
+            NodeSort::Role => {}
+            NodeSort::Name => nodes.sort_by(|(_, a), (_, b)| a.node.cmp(&b.node)),
+            NodeSort::Readiness => {
+                nodes.sort_by_key(|(_, v)| self.node_health(cluster_idx, &v.node))
+            }
+        }
+        nodes.sort_by_key(|(_, v)| !self.is_pinned(cluster_idx, &v.node));
+    }
+
     /// Get control plane nodes for a cluster (nodes with etcd service)
+    ///
+    /// Pinned nodes are sorted to the top.
     fn controlplane_nodes_for(&self, cluster_idx: usize) -> Vec<(usize, &VersionInfo)> {
         let Some(cluster) = self.clusters.get(cluster_idx) else {
             return Vec::new();
         };
-        cluster
+        let mut nodes: Vec<_> = cluster
             .versions
             .iter()
             .enumerate()
-            .filter(|(_, v)| {
-                self.get_node_services_for(cluster_idx, &v.node)
-                    .map(|s| s.iter().any(|svc| svc.id == "etcd"))
-                    .unwrap_or(false)
-            })
-            .collect()
+            .filter(|(_, v)| self.is_controlplane_node(cluster_idx, &v.node))
+            .collect();
+        self.sort_nodes(cluster_idx, &mut nodes);
+        nodes
     }
 
     /// Get worker nodes for a cluster (nodes without etcd service)
+    ///
+    /// Pinned nodes are sorted to the top.
     fn worker_nodes_for(&self, cluster_idx: usize) -> Vec<(usize, &VersionInfo)> {
         let Some(cluster) = self.clusters.get(cluster_idx) else {
             return Vec::new();
         };
-        cluster
+        let mut nodes: Vec<_> = cluster
             .versions
             .iter()
             .enumerate()
-            .filter(|(_, v)| {
-                self.get_node_services_for(cluster_idx, &v.node)
-                    .map(|s| !s.iter().any(|svc| svc.id == "etcd"))
-                    .unwrap_or(true)
-            })
-            .collect()
+            .filter(|(_, v)| !self.is_controlplane_node(cluster_idx, &v.node))
+            .collect();
+        self.sort_nodes(cluster_idx, &mut nodes);
+        nodes
     }
 
     /// Build the visible list of items based on expand/collapse state
@@ -334,30 +591,63 @@ impl ClusterComponent {
         }
     }
 
+    /// Toggle the pinned state of the selected node and persist it
+    ///
+    /// The selection follows the node to its new position in the list.
+    fn toggle_pin_selected(&mut self) {
+        let Some(node_name) = self.current_node_name() else {
+            return;
+        };
+        let Some(cluster_idx) = self.selected_cluster_index() else {
+            return;
+        };
+        let Some(context) = self.clusters.get(cluster_idx).map(|c| c.name.clone()) else {
+            return;
+        };
+
+        // Reload first so state saved by other views isn't overwritten
+        self.ui_state = UiState::load();
+        self.ui_state.toggle_pin(&context, &node_name);
+        self.ui_state.save();
+
+        self.reselect_node(cluster_idx, &node_name);
+    }
+
+    /// Switch the node sort order, keeping the selected node selected
+    fn set_node_sort(&mut self, sort: NodeSort) {
+        let selected = self.current_node_name().zip(self.selected_cluster_index());
+        self.node_sort = sort;
+        if let Some((node_name, cluster_idx)) = selected {
+            self.reselect_node(cluster_idx, &node_name);
+        }
+    }
+
+    /// Point the selection at a node's new position after the list was reordered
+    fn reselect_node(&mut self, cluster_idx: usize, node_name: &str) {
+        self.selected_item = match &self.selected_item {
+            NodeListItem::ControlPlaneNode(_, _) => self
+                .controlplane_nodes_for(cluster_idx)
+                .iter()
+                .position(|(_, v)| v.node == node_name)
+                .map(|idx| NodeListItem::ControlPlaneNode(cluster_idx, idx))
+                .unwrap_or(NodeListItem::ControlPlaneHeader(cluster_idx)),
+            NodeListItem::WorkerNode(_, _) => self
+                .worker_nodes_for(cluster_idx)
+                .iter()
+                .position(|(_, v)| v.node == node_name)
+                .map(|idx| NodeListItem::WorkerNode(cluster_idx, idx))
+                .unwrap_or(NodeListItem::WorkersHeader(cluster_idx)),
+            other => other.clone(),
+        };
+    }
+
     /// Initialize connection to all Talos clusters from talosconfig
     pub async fn connect(&mut self) -> Result<()> {
         // Install crypto provider (needed for rustls)
         let _ = rustls::crypto::ring::default_provider().install_default();
 
-        // Load talosconfig - use custom path if provided via --config flag
-        let config = match &self.config_path {
-            Some(path) => {
-                let path_buf = std::path::PathBuf::from(path);
-                match TalosConfig::load_from(&path_buf) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        tracing::error!("Failed to load talosconfig from {}: {}", path, e);
-                        return Ok(());
-                    }
-                }
-            }
-            None => match TalosConfig::load_default() {
-                Ok(c) => c,
-                Err(e) => {
-                    tracing::error!("Failed to load talosconfig: {}", e);
-                    return Ok(());
-                }
-            },
+        let Some(config) = self.load_config() else {
+            return Ok(());
         };
 
         // Determine which contexts to load based on --context flag
@@ -378,60 +668,369 @@ impl ClusterComponent {
             None => config.contexts.keys().cloned().collect(),
         };
 
+        let skip_verify_context = self.skip_verify_context(&config);
+        self.unverified_context = None;
+
         // Create ClusterData for each context
         self.clusters.clear();
         for (idx, name) in context_names.iter().enumerate() {
-            let mut cluster = ClusterData {
-                name: name.clone(),
-                expanded: idx == 0, // Expand first cluster by default
-                controlplane_expanded: true,
-                workers_expanded: true,
-                ..Default::default()
-            };
+            let mut cluster = self
+                .connect_context(&config, name, skip_verify_context.as_ref())
+                .await;
+            cluster.expanded = idx == 0; // Expand first cluster by default
+            self.clusters.push(cluster);
+        }
+        self.contexts = config.contexts;
+
+        // Refresh all connected clusters
+        self.refresh().await?;
+
+        // Set initial selection
+        if !self.clusters.is_empty() {
+            self.selected_item = NodeListItem::ClusterHeader(0);
+            self.active_cluster = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Load talosconfig - use custom path if provided via --config flag
+    fn load_config(&self) -> Option<TalosConfig> {
+        match &self.config_path {
+            Some(path) => {
+                let path_buf = std::path::PathBuf::from(path);
+                match TalosConfig::load_from(&path_buf) {
+                    Ok(c) => Some(c),
+                    Err(e) => {
+                        tracing::error!("Failed to load talosconfig from {}: {}", path, e);
+                        None
+                    }
+                }
+            }
+            None => match TalosConfig::load_default() {
+                Ok(c) => Some(c),
+                Err(e) => {
+                    tracing::error!("Failed to load talosconfig: {}", e);
+                    None
+                }
+            },
+        }
+    }
+
+    /// Certificate verification is only ever skipped for one context
+    fn skip_verify_context(&self, config: &TalosConfig) -> Option<String> {
+        self.skip_verify.then(|| {
+            self.context_filter
+                .clone()
+                .unwrap_or_else(|| config.context.clone())
+        })
+    }
 
-            // Try to connect to each cluster using the loaded config
-            match config.get_context(name) {
-                Ok(ctx) => match TalosClient::from_context(ctx).await {
+    /// Create a client for one context, recording any connection error
+    async fn connect_context(
+        &mut self,
+        config: &TalosConfig,
+        name: &str,
+        skip_verify_context: Option<&String>,
+    ) -> ClusterData {
+        let mut cluster = ClusterData {
+            name: name.to_string(),
+            controlplane_expanded: true,
+            workers_expanded: true,
+            ..Default::default()
+        };
+
+        // Try to connect to each cluster using the loaded config
+        match config.get_context(name) {
+            Ok(ctx) => {
+                let skip_verify = skip_verify_context.is_some_and(|c| c == name);
+                match TalosClient::from_context_with_options(ctx, self.proxy.as_ref(), skip_verify)
+                    .await
+                {
                     Ok(client) => {
                         cluster.client = Some(client);
                         cluster.connected = true;
+                        if skip_verify {
+                            self.unverified_context = Some(name.to_string());
+                        }
                     }
                     Err(e) => {
                         cluster.error = Some(e.to_string());
                         cluster.connected = false;
                     }
-                },
-                Err(e) => {
-                    cluster.error = Some(e.to_string());
-                    cluster.connected = false;
                 }
             }
+            Err(e) => {
+                cluster.error = Some(e.to_string());
+                cluster.connected = false;
+            }
+        }
 
-            self.clusters.push(cluster);
+        cluster
+    }
+
+    /// Re-read talosconfig after it changed on disk
+    ///
+    /// Contexts whose settings are unchanged keep their client and data;
+    /// added or edited contexts are (re)connected and refreshed. If the
+    /// `--context` context was removed, every remaining context is shown
+    /// instead, and if the selected cluster is gone the selection moves to
+    /// the first one. An unreadable file (e.g. half-written by an editor)
+    /// leaves the current clusters untouched.
+    pub async fn reload_config(&mut self) -> Result<()> {
+        let Some(config) = self.load_config() else {
+            self.set_config_notice("talosconfig unreadable, keeping current contexts");
+            return Ok(());
+        };
+
+        let mut notice = None;
+        if let Some(filter) = &self.context_filter
+            && !config.contexts.contains_key(filter)
+        {
+            tracing::warn!(
+                "Context '{}' was removed from talosconfig, showing all contexts",
+                filter
+            );
+            notice = Some(format!(
+                "Context '{}' removed, showing all contexts",
+                filter
+            ));
+            self.context_filter = None;
         }
 
-        // Refresh all connected clusters
-        self.refresh().await?;
+        let context_names: Vec<String> = match &self.context_filter {
+            Some(ctx_name) => vec![ctx_name.clone()],
+            None => {
+                let mut names: Vec<String> = config.contexts.keys().cloned().collect();
+                // Keep existing clusters in place so the list doesn't jump around
+                names.sort_by_key(|n| {
+                    self.clusters
+                        .iter()
+                        .position(|c| &c.name == n)
+                        .unwrap_or(usize::MAX)
+                });
+                names
+            }
+        };
 
-        // Set initial selection
-        if !self.clusters.is_empty() {
-            self.selected_item = NodeListItem::ClusterHeader(0);
-            self.active_cluster = 0;
+        let selected_name = self.current_context_name().map(str::to_string);
+        let skip_verify_context = self.skip_verify_context(&config);
+        let previous_unverified = self.unverified_context.take();
+
+        let mut previous: HashMap<String, ClusterData> = self
+            .clusters
+            .drain(..)
+            .map(|c| (c.name.clone(), c))
+            .collect();
+        let mut reconnected = Vec::new();
+        for (idx, name) in context_names.iter().enumerate() {
+            let was_unverified = previous_unverified.as_ref() == Some(name);
+            let unchanged = self.contexts.get(name) == config.contexts.get(name)
+                && was_unverified == (skip_verify_context.as_ref() == Some(name));
+            let cluster = match previous.remove(name) {
+                Some(existing) if unchanged => {
+                    if was_unverified {
+                        self.unverified_context = Some(name.clone());
+                    }
+                    existing
+                }
+                existing => {
+                    tracing::info!("Connecting to context '{}' after talosconfig change", name);
+                    let mut cluster = self
+                        .connect_context(&config, name, skip_verify_context.as_ref())
+                        .await;
+                    cluster.expanded = existing.map_or(idx == 0, |c| c.expanded);
+                    reconnected.push(idx);
+                    cluster
+                }
+            };
+            self.clusters.push(cluster);
+        }
+        for name in previous.keys() {
+            tracing::info!("Context '{}' no longer in talosconfig", name);
         }
+        self.contexts = config.contexts;
 
+        for &idx in &reconnected {
+            self.refresh_cluster(idx).await;
+            self.detect_maintenance(idx).await;
+        }
+        self.prompt_for_maintenance();
+
+        // Follow the selected cluster to its new position, or fall back to the first
+        let selected_idx = selected_name
+            .as_ref()
+            .and_then(|name| self.clusters.iter().position(|c| &c.name == name));
+        match selected_idx {
+            Some(idx) => {
+                // Node indices may be stale if the cluster moved or was reconnected
+                if idx != self.active_cluster || reconnected.contains(&idx) {
+                    self.selected_item = NodeListItem::ClusterHeader(idx);
+                }
+                self.active_cluster = idx;
+            }
+            None => {
+                if notice.is_none()
+                    && let Some(removed) = &selected_name
+                {
+                    notice = Some(match self.clusters.first() {
+                        Some(first) => {
+                            format!(
+                                "Context '{}' removed, switched to '{}'",
+                                removed, first.name
+                            )
+                        }
+                        None => format!("Context '{}' removed, no contexts left", removed),
+                    });
+                }
+                self.active_cluster = 0;
+                self.selected_item = NodeListItem::ClusterHeader(0);
+            }
+        }
+
+        self.set_config_notice(notice.as_deref().unwrap_or("talosconfig reloaded"));
         Ok(())
     }
 
+    /// Rebuild the active context's client and refresh its nodes
+    ///
+    /// A refresh reuses the existing gRPC channel, so once that channel is
+    /// broken every refresh fails the same way. This dials the context's
+    /// endpoints again from talosconfig instead.
+    pub async fn reconnect(&mut self) {
+        let idx = self.active_cluster;
+        let Some(name) = self.clusters.get(idx).map(|c| c.name.clone()) else {
+            return;
+        };
+        let Some(config) = self.load_config() else {
+            self.set_config_notice("talosconfig unreadable, cannot reconnect");
+            return;
+        };
+
+        tracing::info!("Reconnecting to context '{}'", name);
+        let selected = self.current_node_name().zip(self.selected_cluster_index());
+        let skip_verify_context = self.skip_verify_context(&config);
+        let fresh = self
+            .connect_context(&config, &name, skip_verify_context.as_ref())
+            .await;
+        let notice = match &fresh.error {
+            Some(e) => format!("Reconnect to '{}' failed: {}", name, e),
+            None => format!("Reconnected to '{}'", name),
+        };
+        if let Some(cluster) = self.clusters.get_mut(idx) {
+            cluster.client = fresh.client;
+            cluster.connected = fresh.connected;
+            cluster.error = fresh.error;
+        }
+        if let Some(ctx) = config.contexts.get(&name) {
+            self.contexts.insert(name, ctx.clone());
+        }
+
+        self.refresh_cluster(idx).await;
+        self.detect_maintenance(idx).await;
+        self.prompt_for_maintenance();
+        if let Some((node_name, cluster_idx)) = selected {
+            self.reselect_node(cluster_idx, &node_name);
+        }
+        self.last_refresh = Some(std::time::Instant::now());
+        self.set_config_notice(&notice);
+    }
+
+    /// Show a talosconfig reload or reconnect result in the header
+    fn set_config_notice(&mut self, message: &str) {
+        self.config_notice = Some((message.to_string(), std::time::Instant::now()));
+    }
+
     /// Refresh all cluster data
     pub async fn refresh(&mut self) -> Result<()> {
+        // Health may reorder the list, so remember which node was selected
+        let selected = self.current_node_name().zip(self.selected_cluster_index());
+
         // Refresh each cluster
         for cluster_idx in 0..self.clusters.len() {
             self.refresh_cluster(cluster_idx).await;
+            self.detect_maintenance(cluster_idx).await;
+        }
+        self.prompt_for_maintenance();
+        if let Some((node_name, cluster_idx)) = selected {
+            self.reselect_node(cluster_idx, &node_name);
         }
         self.last_refresh = Some(std::time::Instant::now());
         Ok(())
     }
 
+    /// Check whether a cluster that returned no nodes is in maintenance mode
+    ///
+    /// The version RPC names maintenance mode outright when the connection
+    /// gets that far. Usually it doesn't, since the maintenance API has no
+    /// cert signed by the talosconfig CA, so each endpoint is also asked
+    /// without client auth: a configured node never answers that, so any
+    /// answer means the node is waiting for a machine config. Endpoints are
+    /// probed at once, through the proxy if one is set.
+    async fn detect_maintenance(&mut self, cluster_idx: usize) {
+        let Some(cluster) = self.clusters.get(cluster_idx) else {
+            return;
+        };
+        let answered = cluster
+            .versions
+            .iter()
+            .any(|v| !cluster.unreachable.contains(&v.node));
+        if answered || !cluster.etcd_members.is_empty() {
+            if let Some(cluster) = self.clusters.get_mut(cluster_idx) {
+                cluster.maintenance_endpoint = None;
+            }
+            return;
+        }
+
+        let hosts: Vec<String> = self
+            .contexts
+            .get(&cluster.name)
+            .map(|ctx| ctx.endpoints.iter().map(|e| endpoint_target(e)).collect())
+            .unwrap_or_default();
+        let client = cluster.client.clone();
+
+        let mut found = None;
+        if let Some(client) = client
+            && let Err(e) = client.version().await
+            && e.is_maintenance_mode()
+        {
+            found = hosts.first().cloned();
+        }
+        if found.is_none() {
+            let proxy = self.proxy.as_ref();
+            let probes = hosts.iter().map(|host| async move {
+                let client = TalosClient::maintenance(host, proxy).ok()?;
+                let probe = tokio::time::timeout(MAINTENANCE_PROBE_TIMEOUT, client.version());
+                matches!(probe.await, Ok(Ok(_))).then(|| host.clone())
+            });
+            // First in context order among those that answered
+            found = futures::future::join_all(probes)
+                .await
+                .into_iter()
+                .flatten()
+                .next();
+        }
+
+        if let Some(host) = &found {
+            tracing::info!("Endpoint {} is in maintenance mode", host);
+        }
+        if let Some(cluster) = self.clusters.get_mut(cluster_idx) {
+            cluster.maintenance_endpoint = found;
+        }
+    }
+
+    /// Offer the wizard for the first maintenance mode endpoint not yet declined
+    fn prompt_for_maintenance(&mut self) {
+        if self.maintenance_prompt.is_some() {
+            return;
+        }
+        self.maintenance_prompt = self
+            .clusters
+            .iter()
+            .filter_map(|c| c.maintenance_endpoint.clone())
+            .find(|endpoint| !self.maintenance_dismissed.contains(endpoint));
+    }
+
     /// Refresh a single cluster's data
     async fn refresh_cluster(&mut self, cluster_idx: usize) {
         let Some(cluster) = self.clusters.get_mut(cluster_idx) else {
@@ -471,21 +1070,24 @@ impl ClusterComponent {
             .iter()
             .filter_map(|m| m.ip_address())
             .collect();
-        match get_discovery_members_with_retry(
-            &context_name,
-            self.config_path.as_deref(),
-            &fallback_ips,
-        )
-        .await
-        {
-            Ok(members) => {
+        match discover_members(&context_name, self.config_path.as_deref(), &fallback_ips).await {
+            Ok(discovery) => {
+                for (endpoint, e) in &discovery.failed_endpoints {
+                    tracing::warn!(
+                        "Discovery via {} failed for {}: {} (showing partial results)",
+                        endpoint,
+                        cluster.name,
+                        e
+                    );
+                }
                 cluster.node_ips.clear();
-                for member in &members {
+                for member in &discovery.members {
                     if let Some(ip) = member.addresses.first() {
                         cluster.node_ips.insert(member.hostname.clone(), ip.clone());
                     }
                 }
-                cluster.discovery_members = members;
+                cluster.discovery_members = discovery.members;
+                cluster.discovery_failures = discovery.failed_endpoints;
             }
             Err(e) => {
                 tracing::warn!(
@@ -499,7 +1101,7 @@ impl ClusterComponent {
 
         // Determine which nodes to query
         let nodes_to_query: Vec<(String, String)> = if !cluster.discovery_members.is_empty() {
-            cluster
+            let mut nodes: Vec<(String, String)> = cluster
                 .discovery_members
                 .iter()
                 .filter_map(|m| {
@@ -512,7 +1114,23 @@ impl ClusterComponent {
                         (name, ip.clone())
                     })
                 })
-                .collect()
+                .collect();
+            // Partial discovery may miss control planes etcd still knows
+            // about; keep them listed so the probe can mark them unreachable
+            for m in &cluster.etcd_members {
+                if let Some(ip) = m.ip_address()
+                    && !nodes.iter().any(|(_, known)| *known == ip)
+                {
+                    let name = if !m.hostname.is_empty() {
+                        m.hostname.clone()
+                    } else {
+                        ip.clone()
+                    };
+                    cluster.node_ips.entry(name.clone()).or_insert(ip.clone());
+                    nodes.push((name, ip));
+                }
+            }
+            nodes
         } else if !cluster.etcd_members.is_empty() {
             cluster
                 .etcd_members
@@ -557,15 +1175,29 @@ impl ClusterComponent {
             let mut memory = Vec::new();
             let mut load_avg = Vec::new();
             let mut cpu_info = Vec::new();
+            let mut boot_times = HashMap::new();
+            let mut unreachable = HashSet::new();
 
             for (node_name, ip) in &nodes_to_query {
                 let node_client = client.with_node(ip);
 
-                if let Ok(mut nv) = node_client.version().await {
-                    for v in &mut nv {
-                        v.node = node_name.clone();
+                match node_client.version().await {
+                    Ok(mut nv) => {
+                        for v in &mut nv {
+                            v.node = node_name.clone();
+                        }
+                        versions.extend(nv);
+                    }
+                    Err(e) => {
+                        // Keep the node listed so it shows up as unreachable
+                        tracing::warn!("Node {} did not answer version probe: {}", node_name, e);
+                        unreachable.insert(node_name.clone());
+                        versions.push(VersionInfo {
+                            node: node_name.clone(),
+                            ..Default::default()
+                        });
+                        continue;
                     }
-                    versions.extend(nv);
                 }
                 if let Ok(mut ns) = node_client.services().await {
                     for s in &mut ns {
@@ -591,15 +1223,28 @@ impl ClusterComponent {
                     }
                     cpu_info.extend(nc);
                 }
+                if let Ok(stats) = node_client.system_stat().await {
+                    for stat in stats {
+                        boot_times.insert(node_name.clone(), stat.boot_time);
+                    }
+                }
             }
 
             // Need to re-borrow cluster mutably after async calls
             if let Some(cluster) = self.clusters.get_mut(cluster_idx) {
                 cluster.versions = versions;
+                cluster.unreachable = unreachable;
                 cluster.services = services;
                 cluster.memory = memory;
                 cluster.load_avg = load_avg;
                 cluster.cpu_info = cpu_info;
+                for (node, &boot_time) in &boot_times {
+                    let history = cluster.boot_history.entry(node.clone()).or_default();
+                    if record_boot_time(history, boot_time) {
+                        tracing::warn!("Node {} rebooted since the last refresh", node);
+                    }
+                }
+                cluster.boot_times = boot_times;
 
                 // Fetch etcd status for header summary (target all control planes)
                 if let Some(client) = &cluster.client {
@@ -681,6 +1326,10 @@ impl ClusterComponent {
             }
         }
 
+        if self.node_sort == NodeSort::Readiness {
+            self.reselect_node(cluster_idx, &node_name);
+        }
+
         self.last_auto_refresh = Some(std::time::Instant::now());
         Ok(())
     }
@@ -771,6 +1420,60 @@ impl ClusterComponent {
         self.clusters.get(self.active_cluster)?.client.as_ref()
     }
 
+    /// Boot times seen for a node of the active cluster, oldest first
+    pub fn boot_history(&self, hostname: &str) -> Vec<u64> {
+        self.clusters
+            .get(self.active_cluster)
+            .and_then(|c| c.boot_history.get(hostname))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// What the overview knows about a node in the active cluster, for the
+    /// header of the views opened on it
+    pub fn node_info(&self, hostname: &str, address: &str) -> NodeInfo {
+        let mut info = NodeInfo::new(hostname, address);
+        let Some(cluster) = self.clusters.get(self.active_cluster) else {
+            return info;
+        };
+        let Some(version) = cluster
+            .versions
+            .iter()
+            .find(|v| v.node == hostname && !v.version.is_empty())
+        else {
+            return info;
+        };
+
+        let role = if self.is_controlplane_node(self.active_cluster, hostname) {
+            "controlplane"
+        } else {
+            "worker"
+        };
+        info.role = Some(role.to_string());
+        info.version = Some(version.version.clone());
+        info.platform = Some(version.platform.clone());
+        info.boot_time = cluster.boot_times.get(hostname).copied();
+        info
+    }
+
+    /// Nodes of the active cluster in overview order, for the node switcher
+    pub fn switcher_nodes(&self) -> Vec<SwitcherNode> {
+        let cluster_idx = self.active_cluster;
+        let node_ips = self.node_ips();
+        let cp_nodes = self.controlplane_nodes_for(cluster_idx);
+        let worker_nodes = self.worker_nodes_for(cluster_idx);
+        cp_nodes
+            .iter()
+            .map(|(_, v)| (v, "controlplane"))
+            .chain(worker_nodes.iter().map(|(_, v)| (v, "worker")))
+            .map(|(v, role)| SwitcherNode {
+                hostname: v.node.clone(),
+                address: node_ips.get(&v.node).cloned().unwrap_or(v.node.clone()),
+                role: role.to_string(),
+            })
+            .collect()
+    }
+
     /// Get context name for active cluster
     pub fn current_context_name(&self) -> Option<&str> {
         self.clusters
@@ -792,15 +1495,13 @@ impl ClusterComponent {
             .unwrap_or_else(|| EMPTY.get_or_init(HashMap::new))
     }
 
-    /// Get a control plane node IP from the active cluster
-    /// Used to fetch kubeconfig when diagnosing worker nodes
-    fn get_controlplane_endpoint(&self) -> Option<String> {
-        let cp_nodes = self.controlplane_nodes_for(self.active_cluster);
-        if let Some((_, node)) = cp_nodes.first() {
-            self.node_ips().get(&node.node).cloned()
-        } else {
-            None
-        }
+    /// Get the control plane node IPs of the active cluster
+    /// Used to fetch kubeconfig when diagnosing worker nodes, trying each in turn
+    pub fn get_controlplane_endpoints(&self) -> Vec<String> {
+        self.controlplane_nodes_for(self.active_cluster)
+            .iter()
+            .filter_map(|(_, node)| self.node_ips().get(&node.node).cloned())
+            .collect()
     }
 
     /// Get service count for current node
@@ -848,6 +1549,17 @@ impl ClusterComponent {
         }
     }
 
+    /// Open the machine config of the selected node
+    fn machine_config_action(&self) -> Option<Action> {
+        let node_name = self.current_node_name()?;
+        let node_ip = self
+            .node_ips()
+            .get(&node_name)
+            .cloned()
+            .unwrap_or(node_name.clone());
+        Some(Action::ShowMachineConfig(node_name, node_ip))
+    }
+
     /// Navigate to the currently selected menu item (1-based index, 0 = on node)
     fn navigate_to_selected_menu(&self) -> Result<Option<Action>> {
         if self.selected_menu_item == 0 || self.selected_menu_item > NavMenuItem::ALL.len() {
@@ -925,21 +1637,22 @@ impl ClusterComponent {
                         .unwrap_or(node_name.clone());
                     let node_role = self.current_node_role();
                     // For worker nodes, provide a control plane endpoint to fetch kubeconfig from
-                    let cp_endpoint = if node_role == "worker" {
-                        self.get_controlplane_endpoint()
+                    let cp_endpoints = if node_role == "worker" {
+                        self.get_controlplane_endpoints()
                     } else {
-                        None
+                        Vec::new()
                     };
                     Ok(Some(Action::ShowDiagnostics(
                         node_name,
                         node_ip,
                         node_role,
-                        cp_endpoint,
+                        cp_endpoints,
                     )))
                 } else {
                     Ok(None)
                 }
             }
+            NavMenuItem::Config => Ok(self.machine_config_action()),
             NavMenuItem::Certs => Ok(Some(Action::ShowSecurity)),
             NavMenuItem::Lifecycle => Ok(Some(Action::ShowLifecycle)),
             NavMenuItem::Workloads => Ok(Some(Action::ShowWorkloads)),
@@ -948,10 +1661,78 @@ impl ClusterComponent {
 }
 
 impl Component for ClusterComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Navigate"),
+            ("Tab/Shift+Tab", "Switch pane"),
+            ("Enter", "Select / expand group / show logs"),
+            (
+                "Space",
+                "Expand/collapse group / select node for group views",
+            ),
+            ("l", "Logs for all node services"),
+            ("e", "Etcd status"),
+            ("p", "Processes"),
+            ("n", "Network"),
+            ("N", "Network (selected or all nodes)"),
+            ("s", "Storage"),
+            ("d", "Diagnostics"),
+            ("D", "Mark node / compare diagnostics"),
+            ("m", "Machine config (view / edit)"),
+            ("c", "Certificates"),
+            ("y", "Lifecycle / versions"),
+            ("w", "Workload health"),
+            ("o", "Node operations"),
+            ("O", "Rolling operations (selected or all nodes)"),
+            ("P", "Pin/unpin node"),
+            ("W", "Bootstrap wizard (maintenance mode)"),
+            ("1", "Sort nodes by role"),
+            ("2", "Sort nodes by name"),
+            ("3", "Sort nodes by readiness"),
+            ("a", "Toggle auto-refresh"),
+            ("r", "Refresh"),
+            ("q/Esc", "Quit"),
+        ])
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        // Maintenance mode prompt takes all keys while open
+        if let Some(endpoint) = &self.maintenance_prompt {
+            return Ok(match key.code {
+                KeyCode::Enter | KeyCode::Char('y') => {
+                    let endpoint = endpoint.clone();
+                    self.maintenance_prompt = None;
+                    Some(Action::StartWizard(endpoint))
+                }
+                KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => {
+                    self.maintenance_dismissed.insert(endpoint.clone());
+                    self.maintenance_prompt = None;
+                    None
+                }
+                _ => None,
+            });
+        }
+
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => Ok(Some(Action::Quit)),
             KeyCode::Char('r') => Ok(Some(Action::Refresh)),
+            KeyCode::Char('W') => Ok(self
+                .clusters
+                .get(self.active_cluster)
+                .and_then(|c| c.maintenance_endpoint.clone())
+                .map(Action::StartWizard)),
+            KeyCode::Char('1') => {
+                self.set_node_sort(NodeSort::Role);
+                Ok(None)
+            }
+            KeyCode::Char('2') => {
+                self.set_node_sort(NodeSort::Name);
+                Ok(None)
+            }
+            KeyCode::Char('3') => {
+                self.set_node_sort(NodeSort::Readiness);
+                Ok(None)
+            }
 
             // Vertical navigation within focused pane
             KeyCode::Up | KeyCode::Char('k') => {
@@ -994,10 +1775,11 @@ impl Component for ClusterComponent {
                 Ok(None)
             }
 
-            // Space: toggle expand/collapse on group headers
+            // Space: toggle expand/collapse on group headers, select nodes
             KeyCode::Char(' ') => {
                 if self.focused_pane == FocusedPane::Nodes {
                     self.toggle_expand();
+                    self.toggle_group_selected();
                 }
                 Ok(None)
             }
@@ -1147,6 +1929,26 @@ impl Component for ClusterComponent {
                     Ok(None)
                 }
             }
+            KeyCode::Char('N') => {
+                // Show aggregated network stats for the selected (or all) nodes
+                let nodes: Vec<(String, String)> = self
+                    .group_nodes()
+                    .into_iter()
+                    .map(|v| {
+                        let ip = self
+                            .node_ips()
+                            .get(&v.node)
+                            .cloned()
+                            .unwrap_or_else(|| v.node.clone());
+                        (v.node.clone(), ip)
+                    })
+                    .collect();
+                if !nodes.is_empty() {
+                    Ok(Some(Action::ShowNetworkGroup(nodes)))
+                } else {
+                    Ok(None)
+                }
+            }
             KeyCode::Char('s') => {
                 if let Some(node_name) = self.current_node_name() {
                     let node_ip = self
@@ -1168,21 +1970,55 @@ impl Component for ClusterComponent {
                         .unwrap_or(node_name.clone());
                     let node_role = self.current_node_role();
                     // For worker nodes, provide a control plane endpoint to fetch kubeconfig from
-                    let cp_endpoint = if node_role == "worker" {
-                        self.get_controlplane_endpoint()
+                    let cp_endpoints = if node_role == "worker" {
+                        self.get_controlplane_endpoints()
                     } else {
-                        None
+                        Vec::new()
                     };
                     Ok(Some(Action::ShowDiagnostics(
                         node_name,
                         node_ip,
                         node_role,
-                        cp_endpoint,
+                        cp_endpoints,
                     )))
                 } else {
                     Ok(None)
                 }
             }
+            KeyCode::Char('D') => {
+                // First press marks a node, second press on another node compares them
+                let Some(node_name) = self.current_node_name() else {
+                    return Ok(None);
+                };
+                let node_ip = self
+                    .node_ips()
+                    .get(&node_name)
+                    .cloned()
+                    .unwrap_or_else(|| node_name.clone());
+                let node = (node_name, node_ip, self.current_node_role());
+
+                match self.compare_mark.take() {
+                    None => {
+                        self.compare_mark = Some(node);
+                        Ok(None)
+                    }
+                    // Pressing again on the marked node clears the mark
+                    Some(marked) if marked.0 == node.0 => Ok(None),
+                    Some(marked) => {
+                        let cp_endpoints = if marked.2 == "worker" || node.2 == "worker" {
+                            self.get_controlplane_endpoints()
+                        } else {
+                            Vec::new()
+                        };
+                        Ok(Some(Action::ShowDiagnosticsCompare(
+                            marked,
+                            node,
+                            cp_endpoints,
+                        )))
+                    }
+                }
+            }
+            KeyCode::Char('m') => Ok(self.machine_config_action()),
             KeyCode::Char('c') => Ok(Some(Action::ShowSecurity)),
             KeyCode::Char('y') => Ok(Some(Action::ShowLifecycle)),
             KeyCode::Char('w') => Ok(Some(Action::ShowWorkloads)),
@@ -1205,31 +2041,26 @@ impl Component for ClusterComponent {
                 }
             }
             KeyCode::Char('O') => {
-                // Show rolling operations overlay with all nodes from active cluster
+                // Show rolling operations overlay with the selected (or all) nodes
                 let cluster_idx = self.active_cluster;
-                let nodes: Vec<(String, String, bool)> =
-                    if let Some(cluster) = self.clusters.get(cluster_idx) {
-                        cluster
-                            .versions
-                            .iter()
-                            .map(|v| {
-                                let hostname = v.node.clone();
-                                let ip = cluster
-                                    .node_ips
-                                    .get(&hostname)
-                                    .cloned()
-                                    .unwrap_or_else(|| hostname.clone());
-                                // Check if node has etcd service (controlplane)
-                                let is_controlplane = self
-                                    .get_node_services_for(cluster_idx, &hostname)
-                                    .map(|s| s.iter().any(|svc| svc.id == "etcd"))
-                                    .unwrap_or(false);
-                                (hostname, ip, is_controlplane)
-                            })
-                            .collect()
-                    } else {
-                        Vec::new()
-                    };
+                let nodes: Vec<(String, String, bool)> = self
+                    .group_nodes()
+                    .into_iter()
+                    .map(|v| {
+                        let hostname = v.node.clone();
+                        let ip = self
+                            .node_ips()
+                            .get(&hostname)
+                            .cloned()
+                            .unwrap_or_else(|| hostname.clone());
+                        // Check if node has etcd service (controlplane)
+                        let is_controlplane = self
+                            .get_node_services_for(cluster_idx, &hostname)
+                            .map(|s| s.iter().any(|svc| svc.id == "etcd"))
+                            .unwrap_or(false);
+                        (hostname, ip, is_controlplane)
+                    })
+                    .collect();
                 if !nodes.is_empty() {
                     Ok(Some(Action::ShowRollingOperations(nodes)))
                 } else {
@@ -1243,6 +2074,14 @@ impl Component for ClusterComponent {
                 Ok(None)
             }
 
+            // Pin/unpin selected node to the top of its group
+            KeyCode::Char('P') => {
+                if self.focused_pane == FocusedPane::Nodes {
+                    self.toggle_pin_selected();
+                }
+                Ok(None)
+            }
+
             _ => Ok(None),
         }
     }
@@ -1303,6 +2142,9 @@ impl Component for ClusterComponent {
             Span::styled("[O]", Style::default().fg(Color::Yellow)),
             Span::styled(" rolling", Style::default().dim()),
             Span::raw("  "),
+            Span::styled("[P]", Style::default().fg(Color::Yellow)),
+            Span::styled(" pin", Style::default().dim()),
+            Span::raw("  "),
             Span::styled("[r]", Style::default().fg(Color::Yellow)),
             Span::styled(" refresh", Style::default().dim()),
             Span::raw("  "),
@@ -1320,35 +2162,87 @@ impl Component for ClusterComponent {
         );
         frame.render_widget(footer, layout[2]);
 
+        self.draw_maintenance_prompt(frame, area);
+
         Ok(())
     }
 }
 
 impl ClusterComponent {
+    /// Draw the "maintenance mode detected" prompt (if showing)
+    fn draw_maintenance_prompt(&self, frame: &mut Frame, area: Rect) {
+        let Some(endpoint) = &self.maintenance_prompt else {
+            return;
+        };
+
+        let dialog_width = 56.min(area.width.saturating_sub(4));
+        let dialog_height = 7.min(area.height.saturating_sub(2));
+        let dialog_area = Rect::new(
+            area.x + (area.width.saturating_sub(dialog_width)) / 2,
+            area.y + (area.height.saturating_sub(dialog_height)) / 2,
+            dialog_width,
+            dialog_height,
+        );
+
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Maintenance Mode Detected ")
+            .border_style(Style::default().fg(Color::Yellow));
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let lines = vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::raw(" "),
+                Span::styled(endpoint.as_str(), Style::default().fg(Color::White).bold()),
+                Span::raw(" has no machine config yet."),
+            ]),
+            Line::from(" Open the bootstrap wizard for this node?"),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(" [Enter]", Style::default().fg(Color::Cyan)),
+                Span::raw(" Open wizard  "),
+                Span::styled("[Esc]", Style::default().fg(Color::Cyan)),
+                Span::raw(" Not now (W opens it later)"),
+            ]),
+        ];
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
     /// Draw compact header with status indicators
     fn draw_header(&self, frame: &mut Frame, area: Rect) {
         // Count connected clusters
         let connected_count = self.clusters.iter().filter(|c| c.connected).count();
         let total_count = self.clusters.len();
+        let g = glyphs();
 
         let (status_indicator, status_text) = if connected_count == total_count && total_count > 0 {
             (
-                Span::styled(" ● ", Style::default().fg(Color::Green)),
+                Span::styled(format!(" {} ", g.filled), Style::default().fg(Color::Green)),
                 "Connected",
             )
         } else if connected_count > 0 {
             (
-                Span::styled(" ◐ ", Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    format!(" {} ", g.partial),
+                    Style::default().fg(Color::Yellow),
+                ),
                 "Partial",
             )
         } else if total_count == 0 {
             (
-                Span::styled(" ○ ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!(" {} ", g.hollow),
+                    Style::default().fg(Color::DarkGray),
+                ),
                 "No clusters",
             )
         } else {
             (
-                Span::styled(" ✗ ", Style::default().fg(Color::Red)),
+                Span::styled(format!(" {} ", g.cross), Style::default().fg(Color::Red)),
                 "Disconnected",
             )
         };
@@ -1377,6 +2271,15 @@ impl ClusterComponent {
             Span::styled(status_text, Style::default().dim()),
         ];
         header_spans.extend(cluster_count_span);
+        if let Some((notice, at)) = &self.config_notice
+            && at.elapsed() < CONFIG_NOTICE_DURATION
+        {
+            header_spans.push(Span::raw("   "));
+            header_spans.push(Span::styled(
+                notice.clone(),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
 
         // Active cluster name on the right
         let active_name = self
@@ -1410,6 +2313,111 @@ impl ClusterComponent {
         frame.render_widget(Paragraph::new(right_content), right_area);
     }
 
+    /// Node name shown in the list, with its pin, compare and group markers
+    fn node_label(
+        &self,
+        cluster_idx: usize,
+        node_name: &str,
+    ) -> (String, String, String, &'static str) {
+        let name = if node_name.is_empty() {
+            "node".to_string()
+        } else {
+            node_name.to_string()
+        };
+        let g = glyphs();
+        let pin_marker = if self.is_pinned(cluster_idx, node_name) {
+            format!(" {}", g.pin)
+        } else {
+            String::new()
+        };
+        let compare_marker = if self.is_compare_marked(node_name) {
+            format!(" {}", g.compare)
+        } else {
+            String::new()
+        };
+        let group_marker = if self.is_group_selected(cluster_idx, node_name) {
+            " +"
+        } else {
+            ""
+        };
+        (name, pin_marker, compare_marker, group_marker)
+    }
+
+    /// Display width of a node's name and markers
+    fn node_label_width(&self, cluster_idx: usize, node_name: &str) -> usize {
+        let (name, pin_marker, compare_marker, group_marker) =
+            self.node_label(cluster_idx, node_name);
+        [pin_marker.as_str(), compare_marker.as_str(), group_marker]
+            .iter()
+            .map(|m| m.chars().count())
+            .sum::<usize>()
+            + name.chars().count()
+    }
+
+    /// One row of the node list: health, name, then status and version columns
+    fn node_line(
+        &self,
+        cluster_idx: usize,
+        version: &VersionInfo,
+        is_selected: bool,
+        name_width: usize,
+    ) -> Line<'static> {
+        let health = self.node_health(cluster_idx, &version.node);
+        let (name, pin_marker, compare_marker, group_marker) =
+            self.node_label(cluster_idx, &version.node);
+        let padding = name_width.saturating_sub(self.node_label_width(cluster_idx, &version.node));
+
+        let selector = if is_selected && self.focused_pane == FocusedPane::Nodes {
+            glyphs().selector
+        } else {
+            " "
+        };
+        let name_style = if is_selected {
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        Line::from(vec![
+            Span::raw("     "),
+            Span::styled(
+                format!("{} {} ", selector, health.symbol()),
+                Style::default().fg(health.color()),
+            ),
+            Span::styled(name, name_style),
+            Span::styled(pin_marker, Style::default().fg(Color::Yellow)),
+            Span::styled(compare_marker, Style::default().fg(Color::Cyan)),
+            Span::styled(group_marker, Style::default().fg(Color::Magenta)),
+            Span::raw(" ".repeat(padding + 2)),
+            Span::styled(
+                format!("{:<11}", health.label()),
+                Style::default().fg(health.color()),
+            ),
+            Span::styled(format!(" {}", version.version), Style::default().dim()),
+        ])
+    }
+
+    /// Nodes pane title: sort order, plus the ad-hoc group size if any
+    fn nodes_pane_title(&self) -> String {
+        let selected = self.clusters.get(self.active_cluster).map_or(0, |c| {
+            c.versions
+                .iter()
+                .filter(|v| self.is_group_selected(self.active_cluster, &v.node))
+                .count()
+        });
+        if selected > 0 {
+            format!(
+                " Nodes [{}▼] {} selected ",
+                self.node_sort.label(),
+                selected
+            )
+        } else {
+            format!(" Nodes [{}▼] ", self.node_sort.label())
+        }
+    }
+
     /// Draw the nodes pane (left column) with navigation menu below
     fn draw_nodes_pane(&self, frame: &mut Frame, area: Rect) {
         // Focus indication - cyan border when focused
@@ -1420,7 +2428,7 @@ impl ClusterComponent {
         };
 
         let block = Block::default()
-            .title(" Nodes ")
+            .title(self.nodes_pane_title())
             .title_style(
                 Style::default().fg(if self.focused_pane == FocusedPane::Nodes {
                     Color::Cyan
@@ -1457,15 +2465,24 @@ impl ClusterComponent {
                 // Cluster header
                 let is_cluster_selected =
                     self.selected_item == NodeListItem::ClusterHeader(cluster_idx);
-                let expand_icon = if cluster.expanded { "▼" } else { "▶" };
+                let g = glyphs();
+                let expand_icon = if cluster.expanded {
+                    g.expanded
+                } else {
+                    g.collapsed
+                };
                 let selector = if is_cluster_selected && nodes_focused {
-                    "▸"
+                    g.selector
                 } else {
                     " "
                 };
 
                 // Status indicator
-                let status_symbol = if cluster.connected { "●" } else { "○" };
+                let status_symbol = if cluster.connected {
+                    glyphs().filled
+                } else {
+                    glyphs().hollow
+                };
                 let status_color = if cluster.connected {
                     Color::Green
                 } else {
@@ -1485,11 +2502,11 @@ impl ClusterComponent {
                 // Build etcd status for this cluster
                 let etcd_spans: Vec<Span> = if let Some(etcd) = &cluster.etcd_summary {
                     let (indicator, color) = if etcd.has_quorum && etcd.healthy == etcd.total {
-                        ("●", Color::Green)
+                        (g.filled, Color::Green)
                     } else if etcd.has_quorum {
-                        ("◐", Color::Yellow)
+                        (g.partial, Color::Yellow)
                     } else {
-                        ("✗", Color::Red)
+                        (g.cross, Color::Red)
                     };
                     vec![
                         Span::styled("  etcd ", Style::default().dim()),
@@ -1520,18 +2537,25 @@ impl ClusterComponent {
 
                 let cp_nodes = self.controlplane_nodes_for(cluster_idx);
                 let worker_nodes = self.worker_nodes_for(cluster_idx);
+                // Align the status column across both groups
+                let name_width = cluster
+                    .versions
+                    .iter()
+                    .map(|v| self.node_label_width(cluster_idx, &v.node))
+                    .max()
+                    .unwrap_or(0);
 
                 // Control Plane section
                 if !cp_nodes.is_empty() {
                     let is_selected =
                         self.selected_item == NodeListItem::ControlPlaneHeader(cluster_idx);
                     let expand_icon = if cluster.controlplane_expanded {
-                        "▼"
+                        g.expanded
                     } else {
-                        "▶"
+                        g.collapsed
                     };
                     let selector = if is_selected && nodes_focused {
-                        "▸"
+                        g.selector
                     } else {
                         " "
                     };
@@ -1552,59 +2576,14 @@ impl ClusterComponent {
                     // Show control plane nodes if expanded
                     if cluster.controlplane_expanded {
                         for (idx, (_, v)) in cp_nodes.iter().enumerate() {
-                            let node_name = if v.node.is_empty() {
-                                "node".to_string()
-                            } else {
-                                v.node.clone()
-                            };
                             let is_node_selected = self.selected_item
                                 == NodeListItem::ControlPlaneNode(cluster_idx, idx);
-
-                            // Health indicator
-                            let mem_pct = self
-                                .get_node_memory_for(cluster_idx, &v.node)
-                                .map(|m| m.usage_percent())
-                                .unwrap_or(0.0);
-                            let svc_healthy = self
-                                .get_node_services_for(cluster_idx, &v.node)
-                                .map(|services| {
-                                    services.iter().all(|s| {
-                                        s.health.as_ref().map(|h| h.healthy).unwrap_or(true)
-                                    })
-                                })
-                                .unwrap_or(true);
-                            let health_symbol = if svc_healthy && mem_pct < 90.0 {
-                                "●"
-                            } else {
-                                "◐"
-                            };
-                            let health_color = if svc_healthy && mem_pct < 90.0 {
-                                Color::Green
-                            } else {
-                                Color::Yellow
-                            };
-
-                            let selector = if is_node_selected && nodes_focused {
-                                "▸"
-                            } else {
-                                " "
-                            };
-                            let name_style = if is_node_selected {
-                                Style::default()
-                                    .fg(Color::White)
-                                    .add_modifier(Modifier::BOLD)
-                            } else {
-                                Style::default().fg(Color::White)
-                            };
-
-                            lines.push(Line::from(vec![
-                                Span::raw("     "),
-                                Span::styled(
-                                    format!("{} {} ", selector, health_symbol),
-                                    Style::default().fg(health_color),
-                                ),
-                                Span::styled(node_name, name_style),
-                            ]));
+                            lines.push(self.node_line(
+                                cluster_idx,
+                                v,
+                                is_node_selected,
+                                name_width,
+                            ));
                         }
                     }
                 }
@@ -1614,12 +2593,12 @@ impl ClusterComponent {
                     let is_selected =
                         self.selected_item == NodeListItem::WorkersHeader(cluster_idx);
                     let expand_icon = if cluster.workers_expanded {
-                        "▼"
+                        g.expanded
                     } else {
-                        "▶"
+                        g.collapsed
                     };
                     let selector = if is_selected && nodes_focused {
-                        "▸"
+                        g.selector
                     } else {
                         " "
                     };
@@ -1640,59 +2619,14 @@ impl ClusterComponent {
                     // Show worker nodes if expanded
                     if cluster.workers_expanded {
                         for (idx, (_, v)) in worker_nodes.iter().enumerate() {
-                            let node_name = if v.node.is_empty() {
-                                "node".to_string()
-                            } else {
-                                v.node.clone()
-                            };
                             let is_node_selected =
                                 self.selected_item == NodeListItem::WorkerNode(cluster_idx, idx);
-
-                            // Health indicator
-                            let mem_pct = self
-                                .get_node_memory_for(cluster_idx, &v.node)
-                                .map(|m| m.usage_percent())
-                                .unwrap_or(0.0);
-                            let svc_healthy = self
-                                .get_node_services_for(cluster_idx, &v.node)
-                                .map(|services| {
-                                    services.iter().all(|s| {
-                                        s.health.as_ref().map(|h| h.healthy).unwrap_or(true)
-                                    })
-                                })
-                                .unwrap_or(true);
-                            let health_symbol = if svc_healthy && mem_pct < 90.0 {
-                                "●"
-                            } else {
-                                "◐"
-                            };
-                            let health_color = if svc_healthy && mem_pct < 90.0 {
-                                Color::Green
-                            } else {
-                                Color::Yellow
-                            };
-
-                            let selector = if is_node_selected && nodes_focused {
-                                "▸"
-                            } else {
-                                " "
-                            };
-                            let name_style = if is_node_selected {
-                                Style::default()
-                                    .fg(Color::White)
-                                    .add_modifier(Modifier::BOLD)
-                            } else {
-                                Style::default().fg(Color::White)
-                            };
-
-                            lines.push(Line::from(vec![
-                                Span::raw("     "),
-                                Span::styled(
-                                    format!("{} {} ", selector, health_symbol),
-                                    Style::default().fg(health_color),
-                                ),
-                                Span::styled(node_name, name_style),
-                            ]));
+                            lines.push(self.node_line(
+                                cluster_idx,
+                                v,
+                                is_node_selected,
+                                name_width,
+                            ));
                         }
                     }
                 }
@@ -1732,7 +2666,11 @@ impl ClusterComponent {
             let is_selected = menu_index == self.selected_menu_item;
             let show_selector = is_selected && menu_focused;
 
-            let selector = if show_selector { "▸" } else { " " };
+            let selector = if show_selector {
+                glyphs().selector
+            } else {
+                " "
+            };
 
             let hotkey_style = if show_selector {
                 Style::default().fg(Color::Black).bg(Color::Cyan)
@@ -1795,6 +2733,38 @@ impl ClusterComponent {
         let cluster_idx = self.active_cluster;
         let cluster = self.clusters.get(cluster_idx);
 
+        if let Some(endpoint) = cluster.and_then(|c| c.maintenance_endpoint.as_deref()) {
+            let block = Block::default()
+                .title(" Maintenance Mode ")
+                .title_style(Style::default().fg(Color::Yellow))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(border_color));
+            let lines = vec![
+                Line::from(""),
+                Line::from(Span::styled(
+                    format!("  {} is in maintenance mode.", endpoint),
+                    Style::default().fg(Color::Yellow),
+                )),
+                Line::from(""),
+                Line::from(Span::styled(
+                    "  It has no machine config yet, so the",
+                    Style::default().dim(),
+                )),
+                Line::from(Span::styled(
+                    "  regular API has nothing to report.",
+                    Style::default().dim(),
+                )),
+                Line::from(""),
+                Line::from(vec![
+                    Span::raw("  Press "),
+                    Span::styled("W", Style::default().fg(Color::Cyan)),
+                    Span::raw(" to open the bootstrap wizard."),
+                ]),
+            ];
+            frame.render_widget(Paragraph::new(lines).block(block), area);
+            return;
+        }
+
         // Check if cluster is connected but has no etcd members (not bootstrapped)
         let needs_bootstrap = cluster
             .map(|c| c.connected && c.etcd_members.is_empty() && c.versions.is_empty())
@@ -1968,6 +2938,18 @@ impl ClusterComponent {
             Span::styled(&node_ip, Style::default().fg(Color::DarkGray)),
         ])];
 
+        // The error this node gave when asked for cluster members
+        if let Some((_, err)) = cluster
+            .into_iter()
+            .flat_map(|c| &c.discovery_failures)
+            .find(|(endpoint, _)| *endpoint == node_ip)
+        {
+            resource_lines.push(Line::from(vec![
+                Span::styled(" Discovery: ", Style::default().dim()),
+                Span::styled(err.clone(), Style::default().fg(Color::Red)),
+            ]));
+        }
+
         // Memory bar
         if let Some(mem) = self.get_node_memory(&node_name) {
             let pct = mem.usage_percent();
@@ -2033,15 +3015,11 @@ impl ClusterComponent {
             )])];
 
             for (i, svc) in services.iter().enumerate() {
-                let health_symbol = svc
-                    .health
-                    .as_ref()
-                    .map(|h| if h.healthy { "●" } else { "○" })
-                    .unwrap_or("●");
-                let health_color = if health_symbol == "●" {
-                    Color::Green
+                let healthy = svc.health.as_ref().is_none_or(|h| h.healthy);
+                let (health_symbol, health_color) = if healthy {
+                    (glyphs().filled, Color::Green)
                 } else {
-                    Color::Red
+                    (glyphs().hollow, Color::Red)
                 };
 
                 // Highlight selected service when services pane is focused
@@ -2054,7 +3032,7 @@ impl ClusterComponent {
                 } else {
                     Style::default().fg(Color::White)
                 };
-                let selector = if is_selected { "▸" } else { " " };
+                let selector = if is_selected { glyphs().selector } else { " " };
 
                 svc_lines.push(Line::from(vec![
                     Span::raw(format!(" {}", selector)),
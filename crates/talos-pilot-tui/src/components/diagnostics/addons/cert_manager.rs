@@ -11,6 +11,7 @@ use kube::{
     Client,
     api::{Api, ListParams},
 };
+use talos_pilot_core::constants::HIGH_RESTART_THRESHOLD;
 
 /// Run cert-manager diagnostic checks
 pub async fn run_checks(client: &Client) -> Vec<DiagnosticCheck> {
@@ -44,6 +45,7 @@ async fn check_pod_health(client: &Client) -> DiagnosticCheck {
             let total = pod_list.items.len();
             let mut healthy = 0;
             let mut unhealthy_pods = Vec::new();
+            let mut flapping_pods = Vec::new();
 
             for pod in &pod_list.items {
                 let name = pod.metadata.name.clone().unwrap_or_default();
@@ -62,14 +64,42 @@ async fn check_pod_health(client: &Client) -> DiagnosticCheck {
                     })
                     .unwrap_or(false);
 
+                let restarts: i32 = status
+                    .and_then(|s| s.container_statuses.as_ref())
+                    .map(|containers| containers.iter().map(|c| c.restart_count).sum())
+                    .unwrap_or(0);
+
                 if phase == "Running" && ready {
                     healthy += 1;
+                    if restarts >= HIGH_RESTART_THRESHOLD {
+                        flapping_pods.push(format!("{}: {} restarts", name, restarts));
+                    }
                 } else {
-                    unhealthy_pods.push(format!("{}: {} (ready: {})", name, phase, ready));
+                    unhealthy_pods.push(format!(
+                        "{}: {} (ready: {}, {} restarts)",
+                        name, phase, ready, restarts
+                    ));
                 }
             }
 
-            if healthy == total {
+            if healthy == total && !flapping_pods.is_empty() {
+                DiagnosticCheck::warn(
+                    "cert_manager_pods",
+                    "cert-manager Pods",
+                    &format!(
+                        "{}/{} healthy, {} restarting repeatedly",
+                        healthy,
+                        total,
+                        flapping_pods.len()
+                    ),
+                )
+                .with_details(&format!(
+                    "Running but restarting repeatedly ({}+ restarts):\n{}\n\n\
+                     Check why with: kubectl logs -n cert-manager <pod> --previous",
+                    HIGH_RESTART_THRESHOLD,
+                    flapping_pods.join("\n")
+                ))
+            } else if healthy == total {
                 DiagnosticCheck::pass(
                     "cert_manager_pods",
                     "cert-manager Pods",
@@ -16,12 +16,13 @@ use kube::{
     Client,
     api::{Api, ListParams},
 };
+use serde::{Deserialize, Serialize};
 use talos_pilot_core::constants::{
     ARGOCD_CRDS, CERT_MANAGER_CRDS, EXTERNAL_SECRETS_CRDS, FLUX_CRDS, KYVERNO_CRDS,
 };
 
 /// Detected addons in the cluster
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DetectedAddons {
     pub cert_manager: bool,
     pub external_secrets: bool,
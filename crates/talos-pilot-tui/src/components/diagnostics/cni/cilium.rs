@@ -10,6 +10,7 @@
 //! Philosophy: Check actual K8s API state, not logs. The pod status
 //! and readiness conditions are the definitive indicators of health.
 
+use super::flapping_details;
 use crate::components::diagnostics::types::{
     CniInfo, CniPodInfo, DiagnosticCheck, DiagnosticContext,
 };
@@ -108,6 +109,20 @@ fn check_cilium_agents_per_node(cni_info: &CniInfo) -> DiagnosticCheck {
             format!("{}/{} agents ready", healthy.len(), total)
         };
 
+        let flapping: Vec<&CniPodInfo> = agent_pods
+            .iter()
+            .copied()
+            .filter(|p| p.is_flapping())
+            .collect();
+        if !flapping.is_empty() {
+            return DiagnosticCheck::warn(
+                "cilium_agents",
+                "Cilium Agents",
+                &format!("{}, {} restarting repeatedly", message, flapping.len()),
+            )
+            .with_details(&flapping_details(&flapping));
+        }
+
         let details = format_agent_details(&agent_pods);
         DiagnosticCheck::pass("cilium_agents", "Cilium Agents", &message).with_details(&details)
     } else {
@@ -153,7 +168,10 @@ fn format_agent_details_with_issues(
     lines.push("Unhealthy:".to_string());
     for pod in unhealthy {
         let node = pod.node_name.as_deref().unwrap_or("unknown");
-        let status = format!("{} (ready: {})", pod.phase, pod.ready);
+        let status = format!(
+            "{} (ready: {}, {} restarts)",
+            pod.phase, pod.ready, pod.restart_count
+        );
         lines.push(format!("  {} on {} - {}", pod.name, node, status));
     }
 
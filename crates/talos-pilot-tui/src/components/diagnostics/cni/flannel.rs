@@ -3,16 +3,38 @@
 //! Flannel-specific checks:
 //! - br_netfilter kernel module (required for iptables-based networking)
 //! - subnet.env file existence (primary source of truth for CNI health)
+//! - subnet.env pod CIDR matches the cluster's configured pod subnets
+//! - VXLAN UDP port is bound and the flannel.1 device isn't dropping traffic
 //! - Flannel pod health
 //!
 //! Philosophy: Check actual state, not logs. Logs can contain stale errors
 //! from before issues were fixed. The subnet.env file is the definitive
 //! indicator of whether Flannel has successfully initialized.
 
+use super::{cidr_contains, cidr_eq, flapping_details};
 use crate::components::diagnostics::types::{
     DiagnosticCheck, DiagnosticContext, DiagnosticFix, FixAction,
 };
-use talos_rs::TalosClient;
+use talos_rs::{NetstatFilter, TalosClient};
+
+/// Path of the file flanneld writes once it has leased a node subnet
+const SUBNET_ENV_PATH: &str = "/run/flannel/subnet.env";
+
+/// Path of the machine config on Talos nodes
+const MACHINE_CONFIG_PATH: &str = "/system/state/config.yaml";
+
+/// Pod subnet Talos uses when `cluster.network.podSubnets` is not set
+const DEFAULT_POD_SUBNET: &str = "10.244.0.0/16";
+
+/// VXLAN UDP ports Flannel may use (IANA port used by Talos' manifest,
+/// and the Linux kernel default used by upstream Flannel)
+const VXLAN_PORTS: [u32; 2] = [4789, 8472];
+
+/// Name of the VXLAN device created by Flannel
+const VXLAN_DEVICE: &str = "flannel.1";
+
+/// Dropped packet ratio on the VXLAN device above which we warn
+const VXLAN_DROP_WARN_RATIO: f64 = 0.01;
 
 /// Run Flannel-specific diagnostic checks
 pub async fn run_flannel_checks(
@@ -35,11 +57,244 @@ pub async fn run_flannel_checks(
 
     // Check CNI health from kubelet logs
     let cni_check = check_flannel_cni(client, ctx, br_netfilter_ok).await;
+    let cni_ok = cni_check.status == crate::components::diagnostics::types::CheckStatus::Pass;
     checks.push(cni_check);
 
+    // Subnet and VXLAN checks only make sense once Flannel has initialized
+    if cni_ok {
+        checks.push(check_flannel_subnet(client).await);
+        checks.push(check_flannel_vxlan(client).await);
+    }
+
     checks
 }
 
+/// Contents of /run/flannel/subnet.env
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FlannelSubnetEnv {
+    /// Cluster-wide pod network (FLANNEL_NETWORK)
+    network: String,
+    /// Subnet leased to this node (FLANNEL_SUBNET)
+    subnet: String,
+}
+
+/// Parse subnet.env, returning None if the required keys are missing
+fn parse_subnet_env(content: &str) -> Option<FlannelSubnetEnv> {
+    let value = |key: &str| {
+        content.lines().find_map(|line| {
+            let (k, v) = line.trim().split_once('=')?;
+            (k == key).then(|| v.trim().to_string())
+        })
+    };
+    Some(FlannelSubnetEnv {
+        network: value("FLANNEL_NETWORK")?,
+        subnet: value("FLANNEL_SUBNET")?,
+    })
+}
+
+/// Extract `cluster.network.podSubnets` from a (multi-document) machine config
+///
+/// Falls back to the Talos default when the config has no pod subnets set.
+fn parse_pod_subnets(config: &str) -> Option<Vec<String>> {
+    use serde::Deserialize;
+
+    for doc in serde_yaml::Deserializer::from_str(config) {
+        let Ok(value) = serde_yaml::Value::deserialize(doc) else {
+            continue;
+        };
+        let Some(cluster) = value.get("cluster") else {
+            continue;
+        };
+        let subnets: Vec<String> = cluster
+            .get("network")
+            .and_then(|n| n.get("podSubnets"))
+            .and_then(|s| s.as_sequence())
+            .map(|seq| {
+                seq.iter()
+                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        return Some(if subnets.is_empty() {
+            vec![DEFAULT_POD_SUBNET.to_string()]
+        } else {
+            subnets
+        });
+    }
+    None
+}
+
+/// Check that the Flannel network in subnet.env matches the cluster pod CIDR
+///
+/// A mismatch between Flannel's net-conf.json and `cluster.network.podSubnets`
+/// makes pod networking fail in ways that look random (some routes work,
+/// kube-proxy masquerading doesn't).
+async fn check_flannel_subnet(client: &TalosClient) -> DiagnosticCheck {
+    let env = match client.read_file(SUBNET_ENV_PATH).await {
+        Ok(content) => parse_subnet_env(&content),
+        Err(_) => None,
+    };
+    let Some(env) = env else {
+        return DiagnosticCheck::unknown("flannel_subnet", "Flannel Subnet")
+            .with_details("Could not read FLANNEL_NETWORK/FLANNEL_SUBNET from subnet.env.");
+    };
+
+    // The node's lease must come from the Flannel network
+    if !cidr_contains(&env.network, &env.subnet) {
+        return DiagnosticCheck::fail(
+            "flannel_subnet",
+            "Flannel Subnet",
+            "Node subnet outside network",
+            None,
+        )
+        .with_details(&format!(
+            "FLANNEL_SUBNET={} is not within FLANNEL_NETWORK={}.\n\n\
+             The node's subnet lease is stale or was allocated from a different network.\n\
+             Delete the node's flannel pod so it re-reads the lease:\n  \
+             kubectl delete pod -n kube-flannel -l app=flannel --field-selector spec.nodeName=<node>",
+            env.subnet, env.network
+        ));
+    }
+
+    let pod_subnets = match client.read_file(MACHINE_CONFIG_PATH).await {
+        Ok(config) => parse_pod_subnets(&config),
+        Err(e) => {
+            tracing::debug!("Failed to read machine config: {}", e);
+            None
+        }
+    };
+    let Some(pod_subnets) = pod_subnets else {
+        // Can't compare against the cluster config, but the lease itself is consistent
+        return DiagnosticCheck::pass("flannel_subnet", "Flannel Subnet", &env.subnet)
+            .with_details(&format!(
+                "FLANNEL_NETWORK={}\nFLANNEL_SUBNET={}\n\n\
+                 Cluster pod subnets could not be read to cross-check.",
+                env.network, env.subnet
+            ));
+    };
+
+    if pod_subnets.iter().any(|cidr| cidr_eq(cidr, &env.network)) {
+        DiagnosticCheck::pass("flannel_subnet", "Flannel Subnet", &env.subnet).with_details(
+            &format!(
+                "FLANNEL_NETWORK={}\nFLANNEL_SUBNET={}\nCluster pod subnets: {}",
+                env.network,
+                env.subnet,
+                pod_subnets.join(", ")
+            ),
+        )
+    } else {
+        DiagnosticCheck::fail(
+            "flannel_subnet",
+            "Flannel Subnet",
+            "Pod CIDR mismatch",
+            None,
+        )
+        .with_details(&format!(
+            "Flannel is using network {} but the cluster pod subnets are: {}\n\n\
+             Pods will get addresses kube-proxy and the API server don't expect,\n\
+             which shows up as intermittent pod-to-pod and service failures.\n\n\
+             Fix: make the \"Network\" in the kube-flannel-cfg ConfigMap (net-conf.json)\n\
+             match cluster.network.podSubnets, then restart the flannel DaemonSet:\n  \
+             kubectl -n kube-flannel edit configmap kube-flannel-cfg\n  \
+             kubectl -n kube-flannel rollout restart daemonset kube-flannel-ds",
+            env.network,
+            pod_subnets.join(", ")
+        ))
+    }
+}
+
+/// Check that the VXLAN UDP port is bound and traffic isn't being dropped
+async fn check_flannel_vxlan(client: &TalosClient) -> DiagnosticCheck {
+    let (conn_result, dev_result) = tokio::join!(
+        client.netstat(NetstatFilter::All),
+        client.network_device_stats()
+    );
+
+    let connections = match conn_result {
+        Ok(nodes) => nodes
+            .into_iter()
+            .next()
+            .map(|n| n.connections)
+            .unwrap_or_default(),
+        Err(e) => {
+            return DiagnosticCheck::unknown("flannel_vxlan", "Flannel VXLAN")
+                .with_details(&format!("Failed to fetch netstat: {}", e));
+        }
+    };
+
+    let bound_port = connections
+        .iter()
+        .filter(|c| c.protocol.starts_with("udp"))
+        .map(|c| c.local_port)
+        .find(|port| VXLAN_PORTS.contains(port));
+
+    let Some(port) = bound_port else {
+        return DiagnosticCheck::warn("flannel_vxlan", "Flannel VXLAN", "VXLAN port not bound")
+            .with_details(&format!(
+                "No UDP socket is bound on the VXLAN ports ({}).\n\n\
+             Without the VXLAN socket, cross-node pod traffic cannot flow.\n\
+             Check the flannel pod on this node:\n  \
+             kubectl logs -n kube-flannel -l app=flannel",
+                VXLAN_PORTS.map(|p| format!("{}/udp", p)).join(", ")
+            ));
+    };
+
+    // Cross-check the VXLAN device counters for drops
+    let vxlan_dev = dev_result.ok().and_then(|nodes| {
+        nodes
+            .into_iter()
+            .next()?
+            .devices
+            .into_iter()
+            .find(|d| d.name == VXLAN_DEVICE)
+    });
+    let Some(dev) = vxlan_dev else {
+        return DiagnosticCheck::warn("flannel_vxlan", "Flannel VXLAN", "No flannel.1 device")
+            .with_details(&format!(
+                "UDP port {} is bound but the {} device was not found.\n\
+                 Flannel may be using a non-VXLAN backend.",
+                port, VXLAN_DEVICE
+            ));
+    };
+
+    let packets = dev.rx_packets + dev.tx_packets;
+    let dropped = dev.total_dropped() + dev.total_errors();
+    let drop_ratio = if packets == 0 {
+        0.0
+    } else {
+        dropped as f64 / packets as f64
+    };
+
+    if drop_ratio > VXLAN_DROP_WARN_RATIO {
+        DiagnosticCheck::warn(
+            "flannel_vxlan",
+            "Flannel VXLAN",
+            &format!("{:.1}% dropped", drop_ratio * 100.0),
+        )
+        .with_details(&format!(
+            "{} has dropped or errored {} of {} packets (rx dropped {}, tx dropped {}, \
+             rx errors {}, tx errors {}).\n\n\
+             Make sure UDP port {} is allowed between all nodes (cloud security\n\
+             groups, host firewalls, Talos ingress firewall rules), and that the\n\
+             underlay MTU leaves room for the 50-byte VXLAN header.",
+            VXLAN_DEVICE,
+            dropped,
+            packets,
+            dev.rx_dropped,
+            dev.tx_dropped,
+            dev.rx_errors,
+            dev.tx_errors,
+            port
+        ))
+    } else {
+        DiagnosticCheck::pass(
+            "flannel_vxlan",
+            "Flannel VXLAN",
+            &format!("UDP {} bound", port),
+        )
+    }
+}
+
 /// Check Flannel pod health from K8s API
 fn check_flannel_pods(
     cni_info: &crate::components::diagnostics::types::CniInfo,
@@ -52,7 +307,15 @@ fn check_flannel_pods(
     let healthy = cni_info.are_pods_healthy();
     let summary = cni_info.pod_health_summary();
 
-    if healthy {
+    let flapping = cni_info.flapping_pods();
+    if healthy && !flapping.is_empty() {
+        DiagnosticCheck::warn(
+            "flannel_pods",
+            "Flannel Pods",
+            &format!("{}, {} restarting repeatedly", summary, flapping.len()),
+        )
+        .with_details(&flapping_details(&flapping))
+    } else if healthy {
         DiagnosticCheck::pass("flannel_pods", "Flannel Pods", &summary)
     } else {
         // Find unhealthy pods
@@ -64,7 +327,12 @@ fn check_flannel_pods(
 
         let details = unhealthy
             .iter()
-            .map(|p| format!("  {} - {} (ready: {})", p.name, p.phase, p.ready))
+            .map(|p| {
+                format!(
+                    "  {} - {} (ready: {}, {} restarts)",
+                    p.name, p.phase, p.ready, p.restart_count
+                )
+            })
             .collect::<Vec<_>>()
             .join("\n");
 
@@ -225,3 +493,53 @@ async fn check_flannel_cni(
         DiagnosticCheck::fail("cni", "CNI (Flannel)", message, fix).with_details(details)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_subnet_env() {
+        let content = "FLANNEL_NETWORK=10.244.0.0/16\n\
+                       FLANNEL_SUBNET=10.244.1.1/24\n\
+                       FLANNEL_MTU=1450\n\
+                       FLANNEL_IPMASQ=true\n";
+        let env = parse_subnet_env(content).unwrap();
+        assert_eq!(env.network, "10.244.0.0/16");
+        assert_eq!(env.subnet, "10.244.1.1/24");
+
+        assert!(parse_subnet_env("FLANNEL_MTU=1450\n").is_none());
+    }
+
+    #[test]
+    fn test_parse_pod_subnets() {
+        let config = r#"
+version: v1alpha1
+machine:
+  type: worker
+cluster:
+  network:
+    podSubnets:
+      - 10.42.0.0/16
+    serviceSubnets:
+      - 10.96.0.0/12
+---
+apiVersion: v1alpha1
+kind: HostnameConfig
+hostname: worker-1
+"#;
+        assert_eq!(
+            parse_pod_subnets(config),
+            Some(vec!["10.42.0.0/16".to_string()])
+        );
+
+        // Talos default when podSubnets isn't set
+        let config = "version: v1alpha1\ncluster:\n  clusterName: test\n";
+        assert_eq!(
+            parse_pod_subnets(config),
+            Some(vec![DEFAULT_POD_SUBNET.to_string()])
+        );
+
+        assert_eq!(parse_pod_subnets("machine:\n  type: worker\n"), None);
+    }
+}
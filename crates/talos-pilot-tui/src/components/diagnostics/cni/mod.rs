@@ -11,9 +11,11 @@ mod flannel;
 pub use cilium::detect_ebpf_mode;
 pub use flannel::run_flannel_checks;
 
-use super::k8s;
+use super::k8s::{self, NodePodCidrs};
 use super::types::{CniInfo, CniPodInfo, CniType, DiagnosticCheck, DiagnosticContext};
 use kube::Client;
+use std::net::IpAddr;
+use talos_pilot_core::constants::HIGH_RESTART_THRESHOLD;
 use talos_rs::TalosClient;
 
 /// Detect which CNI is installed in the cluster using a pre-created K8s client
@@ -101,14 +103,130 @@ pub async fn run_cni_checks(
     ctx: &DiagnosticContext,
     k8s_client: Option<&Client>,
 ) -> Vec<DiagnosticCheck> {
-    match ctx.cni_type {
+    let mut checks = match ctx.cni_type {
         CniType::Flannel => flannel::run_flannel_checks(client, ctx).await,
         CniType::Cilium => cilium::run_cilium_checks(ctx, k8s_client).await,
         CniType::Calico => run_calico_checks(client, ctx).await,
         CniType::Unknown | CniType::None => run_generic_cni_checks(client, ctx).await,
+    };
+
+    // Node ranges come from the K8s API, so this covers the whole cluster
+    if let Some(ref nodes) = ctx.node_pod_cidrs {
+        checks.extend(check_pod_cidr_overlap(nodes));
+    }
+
+    checks
+}
+
+/// Parse "addr/prefix" into an address and prefix length
+fn parse_cidr(cidr: &str) -> Option<(IpAddr, u8)> {
+    let (addr, prefix) = cidr.trim().split_once('/')?;
+    let addr: IpAddr = addr.parse().ok()?;
+    let prefix: u8 = prefix.parse().ok()?;
+    let max = if addr.is_ipv4() { 32 } else { 128 };
+    (prefix <= max).then_some((addr, prefix))
+}
+
+/// Network portion of an address as a u128, for comparing CIDRs
+fn network_bits(addr: IpAddr, prefix: u8) -> u128 {
+    let (bits, width) = match addr {
+        IpAddr::V4(v4) => (u128::from(v4.to_bits()), 32),
+        IpAddr::V6(v6) => (v6.to_bits(), 128),
+    };
+    let host_bits = width - u32::from(prefix);
+    if host_bits >= 128 {
+        0
+    } else {
+        bits >> host_bits
+    }
+}
+
+/// Whether two CIDRs describe the same network
+fn cidr_eq(a: &str, b: &str) -> bool {
+    match (parse_cidr(a), parse_cidr(b)) {
+        (Some((a_addr, a_len)), Some((b_addr, b_len))) => {
+            a_addr.is_ipv4() == b_addr.is_ipv4()
+                && a_len == b_len
+                && network_bits(a_addr, a_len) == network_bits(b_addr, b_len)
+        }
+        _ => false,
+    }
+}
+
+/// Whether `inner` lies entirely within `outer`
+fn cidr_contains(outer: &str, inner: &str) -> bool {
+    match (parse_cidr(outer), parse_cidr(inner)) {
+        (Some((o_addr, o_len)), Some((i_addr, i_len))) => {
+            o_addr.is_ipv4() == i_addr.is_ipv4()
+                && i_len >= o_len
+                && network_bits(o_addr, o_len) == network_bits(i_addr, o_len)
+        }
+        _ => false,
+    }
+}
+
+/// Whether two CIDRs share any address
+fn cidrs_overlap(a: &str, b: &str) -> bool {
+    match (parse_cidr(a), parse_cidr(b)) {
+        (Some((a_addr, a_len)), Some((b_addr, b_len))) => {
+            let len = a_len.min(b_len);
+            a_addr.is_ipv4() == b_addr.is_ipv4()
+                && network_bits(a_addr, len) == network_bits(b_addr, len)
+        }
+        _ => false,
     }
 }
 
+/// Check that no two nodes were allocated overlapping pod CIDRs
+///
+/// Overlapping node ranges give two pods the same IP, so traffic goes to
+/// whichever node the route happens to prefer. Returns None when fewer than
+/// two nodes have a pod CIDR (e.g. Cilium with cluster-pool IPAM).
+fn check_pod_cidr_overlap(nodes: &[NodePodCidrs]) -> Option<DiagnosticCheck> {
+    let node_count = nodes.iter().filter(|n| !n.cidrs.is_empty()).count();
+    if node_count < 2 {
+        return None;
+    }
+
+    let allocated: Vec<(&str, &str)> = nodes
+        .iter()
+        .flat_map(|n| n.cidrs.iter().map(|c| (n.node.as_str(), c.as_str())))
+        .collect();
+
+    let overlaps: Vec<String> = allocated
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| allocated[i + 1..].iter().map(move |b| (a, b)))
+        .filter(|((a_node, a_cidr), (b_node, b_cidr))| {
+            a_node != b_node && cidrs_overlap(a_cidr, b_cidr)
+        })
+        .map(|((a_node, a_cidr), (b_node, b_cidr))| {
+            format!("  {} ({}) overlaps {} ({})", a_node, a_cidr, b_node, b_cidr)
+        })
+        .collect();
+
+    Some(if overlaps.is_empty() {
+        DiagnosticCheck::pass(
+            "pod_cidr_overlap",
+            "Pod CIDRs",
+            &format!("{} nodes, no overlap", node_count),
+        )
+    } else {
+        DiagnosticCheck::fail(
+            "pod_cidr_overlap",
+            "Pod CIDRs",
+            &format!("{} overlapping node range(s)", overlaps.len()),
+            None,
+        )
+        .with_details(&format!(
+            "Nodes were allocated overlapping pod CIDRs (spec.podCIDRs):\n{}\n\n\
+             Pods on these nodes can get the same IP. Check the controller manager's\n\
+             --cluster-cidr / --node-cidr-mask-size and any manually set podCIDRs.",
+            overlaps.join("\n")
+        ))
+    })
+}
+
 /// Generic CNI checks when we don't know the CNI type
 async fn run_generic_cni_checks(
     client: &TalosClient,
@@ -167,7 +285,15 @@ fn check_cni_pods(name: &str, cni_info: &CniInfo) -> DiagnosticCheck {
     let healthy = cni_info.are_pods_healthy();
     let summary = cni_info.pod_health_summary();
 
-    if healthy {
+    let flapping = cni_info.flapping_pods();
+    if healthy && !flapping.is_empty() {
+        DiagnosticCheck::warn(
+            "cni_pods",
+            name,
+            &format!("{}, {} restarting repeatedly", summary, flapping.len()),
+        )
+        .with_details(&flapping_details(&flapping))
+    } else if healthy {
         DiagnosticCheck::pass("cni_pods", name, &summary)
     } else {
         // Find unhealthy pods
@@ -179,7 +305,12 @@ fn check_cni_pods(name: &str, cni_info: &CniInfo) -> DiagnosticCheck {
 
         let details = unhealthy
             .iter()
-            .map(|p| format!("  {} - {} (ready: {})", p.name, p.phase, p.ready))
+            .map(|p| {
+                format!(
+                    "  {} - {} (ready: {}, {} restarts)",
+                    p.name, p.phase, p.ready, p.restart_count
+                )
+            })
             .collect::<Vec<_>>()
             .join("\n");
 
@@ -187,3 +318,124 @@ fn check_cni_pods(name: &str, cni_info: &CniInfo) -> DiagnosticCheck {
             .with_details(&format!("Unhealthy pods:\n{}", details))
     }
 }
+
+/// Details for CNI pods that are up but keep restarting
+fn flapping_details(pods: &[&CniPodInfo]) -> String {
+    let lines: Vec<String> = pods
+        .iter()
+        .map(|p| {
+            let node = p.node_name.as_deref().unwrap_or("unknown");
+            format!("  {} on {} - {} restarts", p.name, node, p.restart_count)
+        })
+        .collect();
+    format!(
+        "Running but restarting repeatedly ({}+ restarts):\n{}\n\n\
+         Pod networking on these nodes drops each time the pod restarts.\n\
+         Check why with: kubectl logs -n <namespace> <pod> --previous",
+        HIGH_RESTART_THRESHOLD,
+        lines.join("\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::diagnostics::types::CheckStatus;
+
+    fn node(name: &str, cidrs: &[&str]) -> NodePodCidrs {
+        NodePodCidrs {
+            node: name.to_string(),
+            cidrs: cidrs.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    fn pod(name: &str, ready: bool, restart_count: i32) -> CniPodInfo {
+        CniPodInfo {
+            name: name.to_string(),
+            node_name: Some("worker-1".to_string()),
+            phase: "Running".to_string(),
+            ready,
+            restart_count,
+        }
+    }
+
+    #[test]
+    fn test_cni_pods_flag_restarts() {
+        let info = |pods| CniInfo {
+            cni_type: CniType::Calico,
+            pods,
+        };
+
+        let check = check_cni_pods("Calico Pods", &info(vec![pod("calico-node-a", true, 1)]));
+        assert_eq!(check.status, CheckStatus::Pass);
+
+        // Running now, but crash-looping between refreshes
+        let check = check_cni_pods(
+            "Calico Pods",
+            &info(vec![
+                pod("calico-node-a", true, 0),
+                pod("calico-node-b", true, HIGH_RESTART_THRESHOLD + 3),
+            ]),
+        );
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert!(check.message.contains("1 restarting repeatedly"));
+        let details = check.details.unwrap();
+        assert!(details.contains(&format!(
+            "calico-node-b on worker-1 - {} restarts",
+            HIGH_RESTART_THRESHOLD + 3
+        )));
+
+        // Down pods still fail, with their restart count
+        let check = check_cni_pods("Calico Pods", &info(vec![pod("calico-node-a", false, 9)]));
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.details.unwrap().contains("9 restarts"));
+    }
+
+    #[test]
+    fn test_cidr_matching() {
+        assert!(cidr_eq("10.244.0.0/16", "10.244.0.0/16"));
+        assert!(!cidr_eq("10.244.0.0/16", "10.42.0.0/16"));
+        assert!(!cidr_eq("10.244.0.0/16", "10.244.0.0/24"));
+
+        assert!(cidr_contains("10.244.0.0/16", "10.244.1.1/24"));
+        assert!(!cidr_contains("10.244.0.0/16", "10.42.1.1/24"));
+        assert!(!cidr_contains("10.244.0.0/24", "10.244.0.0/16"));
+        assert!(cidr_contains("fd00:10:244::/56", "fd00:10:244:1::/64"));
+        assert!(!cidr_contains("10.244.0.0/16", "fd00:10:244:1::/64"));
+        assert!(!cidr_contains("not-a-cidr", "10.244.1.1/24"));
+    }
+
+    #[test]
+    fn test_cidrs_overlap() {
+        assert!(cidrs_overlap("10.244.0.0/16", "10.244.1.0/24"));
+        assert!(cidrs_overlap("10.244.1.0/24", "10.244.0.0/16"));
+        assert!(!cidrs_overlap("10.244.0.0/24", "10.244.1.0/24"));
+        assert!(!cidrs_overlap("10.244.0.0/24", "fd00::/64"));
+    }
+
+    #[test]
+    fn test_pod_cidr_overlap_check() {
+        let distinct = [
+            node("cp-1", &["10.244.0.0/24", "fd00:10:244::/64"]),
+            node("worker-1", &["10.244.1.0/24", "fd00:10:244:1::/64"]),
+        ];
+        let check = check_pod_cidr_overlap(&distinct).unwrap();
+        assert_eq!(check.status, CheckStatus::Pass);
+
+        let overlapping = [
+            node("cp-1", &["10.244.0.0/24"]),
+            node("worker-1", &["10.244.1.0/24"]),
+            node("worker-2", &["10.244.0.0/23"]),
+        ];
+        let check = check_pod_cidr_overlap(&overlapping).unwrap();
+        assert_eq!(check.status, CheckStatus::Fail);
+        let details = check.details.unwrap();
+        assert!(details.contains("cp-1 (10.244.0.0/24) overlaps worker-2 (10.244.0.0/23)"));
+        assert!(details.contains("worker-1 (10.244.1.0/24) overlaps worker-2"));
+
+        // Nothing to compare without node ranges (e.g. Cilium cluster-pool IPAM)
+        assert!(
+            check_pod_cidr_overlap(&[node("cp-1", &["10.244.0.0/24"]), node("w", &[])]).is_none()
+        );
+    }
+}
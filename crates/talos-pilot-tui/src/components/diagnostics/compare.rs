@@ -0,0 +1,565 @@
+//! Side-by-side diagnostics comparison of two nodes
+//!
+//! Runs the full diagnostics pipeline on a suspect node and a healthy peer
+//! and lines their checks up, so a missing kernel module or divergent cert
+//! state on one node stands out.
+
+use super::{CheckStatus, DiagnosticCheck, DiagnosticContext, DiagnosticsData, run_diagnostics};
+use crate::action::Action;
+use crate::components::{Component, help_entries};
+use crate::ui_ext::{CheckStatusExt, manual_refresh};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
+};
+use std::collections::BTreeSet;
+use talos_pilot_core::{AsyncState, ComponentError};
+use talos_rs::TalosClient;
+
+/// A node taking part in the comparison
+#[derive(Debug, Clone)]
+pub struct CompareNode {
+    pub hostname: String,
+    pub address: String,
+    pub role: String,
+}
+
+impl CompareNode {
+    /// Empty diagnostics data seeded with this node's identity
+    fn initial_data(&self) -> DiagnosticsData {
+        let mut context = DiagnosticContext::new();
+        context.node_role = self.role.clone();
+        context.hostname = self.hostname.clone();
+        context.node_endpoint = Some(self.address.clone());
+        DiagnosticsData {
+            hostname: self.hostname.clone(),
+            address: self.address.clone(),
+            context,
+            ..Default::default()
+        }
+    }
+}
+
+/// One check, as seen on both nodes
+#[derive(Debug, Clone)]
+pub struct CompareRow {
+    /// Category title (e.g. "System Health")
+    pub category: &'static str,
+    /// Check name
+    pub name: String,
+    /// Check on the left node, if it ran there
+    pub left: Option<DiagnosticCheck>,
+    /// Check on the right node, if it ran there
+    pub right: Option<DiagnosticCheck>,
+}
+
+impl CompareRow {
+    /// Whether the two nodes disagree on this check
+    pub fn differs(&self) -> bool {
+        match (&self.left, &self.right) {
+            (Some(l), Some(r)) => l.status != r.status || l.message != r.message,
+            _ => true,
+        }
+    }
+}
+
+/// Checks of a node grouped by category, in display order
+fn categories(data: &DiagnosticsData) -> [(&'static str, &[DiagnosticCheck]); 5] {
+    [
+        ("System Health", data.system_checks.as_slice()),
+        ("Kubernetes Components", data.kubernetes_checks.as_slice()),
+        ("Services", data.service_checks.as_slice()),
+        ("CNI", data.cni_checks.as_slice()),
+        ("Addons", data.addon_checks.as_slice()),
+    ]
+}
+
+/// Line up the checks of two nodes by category and check id
+///
+/// Checks follow the left node's order; checks only the right node ran are
+/// appended to the end of their category.
+pub fn compare_checks(left: &DiagnosticsData, right: &DiagnosticsData) -> Vec<CompareRow> {
+    let mut rows = Vec::new();
+
+    for ((category, left_checks), (_, right_checks)) in
+        categories(left).into_iter().zip(categories(right))
+    {
+        for check in left_checks {
+            rows.push(CompareRow {
+                category,
+                name: check.name.clone(),
+                left: Some(check.clone()),
+                right: right_checks.iter().find(|c| c.id == check.id).cloned(),
+            });
+        }
+        for check in right_checks {
+            if !left_checks.iter().any(|c| c.id == check.id) {
+                rows.push(CompareRow {
+                    category,
+                    name: check.name.clone(),
+                    left: None,
+                    right: Some(check.clone()),
+                });
+            }
+        }
+    }
+
+    rows
+}
+
+/// Loaded diagnostics for both nodes
+#[derive(Debug, Clone, Default)]
+pub struct CompareData {
+    pub left: DiagnosticsData,
+    pub right: DiagnosticsData,
+    /// Set if diagnostics could not be completed on a node
+    pub left_error: Option<String>,
+    pub right_error: Option<String>,
+}
+
+/// Diagnostics comparison component
+pub struct DiagnosticsCompareComponent {
+    left_node: CompareNode,
+    right_node: CompareNode,
+
+    /// Async state for loaded data
+    state: AsyncState<CompareData>,
+
+    /// Show only rows where the nodes differ
+    only_differences: bool,
+    /// Selected row index
+    selected: usize,
+    /// Table state for rendering
+    table_state: TableState,
+
+    /// Client for API calls (targets each node in turn)
+    client: Option<TalosClient>,
+    /// Control plane endpoints for fetching kubeconfig on worker nodes, tried in order
+    controlplane_endpoints: Vec<String>,
+    /// Custom talosconfig path
+    config_path: Option<String>,
+}
+
+impl DiagnosticsCompareComponent {
+    pub fn new(left: CompareNode, right: CompareNode, config_path: Option<String>) -> Self {
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+
+        Self {
+            left_node: left,
+            right_node: right,
+            state: AsyncState::new(),
+            only_differences: false,
+            selected: 0,
+            table_state,
+            client: None,
+            controlplane_endpoints: Vec::new(),
+            config_path,
+        }
+    }
+
+    /// Set the client for API calls
+    pub fn set_client(&mut self, client: TalosClient) {
+        self.client = Some(client);
+    }
+
+    /// Set the control plane endpoints for fetching kubeconfig (used for worker nodes)
+    pub fn set_controlplane_endpoints(&mut self, endpoints: Vec<String>) {
+        self.controlplane_endpoints = endpoints;
+    }
+
+    /// Set an error message
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
+        self.state.set_error(error);
+    }
+
+    /// Run diagnostics on both nodes concurrently
+    pub async fn refresh(&mut self) -> Result<()> {
+        let Some(client) = self.client.clone() else {
+            self.set_error("No client configured".to_string());
+            return Ok(());
+        };
+
+        self.state.start_loading();
+
+        let cp_endpoints_for = |node: &CompareNode| {
+            if node.role == "controlplane" {
+                &[][..]
+            } else {
+                self.controlplane_endpoints.as_slice()
+            }
+        };
+
+        let mut left = self.left_node.initial_data();
+        let mut right = self.right_node.initial_data();
+        let left_client = client.with_node(&self.left_node.address);
+        let right_client = client.with_node(&self.right_node.address);
+        let config_path = self.config_path.as_deref();
+        // Compare everything, regardless of the categories hidden in the diagnostics view
+        let all_categories = BTreeSet::new();
+
+        let (left_result, right_result) = tokio::join!(
+            run_diagnostics(
+                &left_client,
+                &mut left,
+                cp_endpoints_for(&self.left_node),
+                config_path,
+                &all_categories
+            ),
+            run_diagnostics(
+                &right_client,
+                &mut right,
+                cp_endpoints_for(&self.right_node),
+                config_path,
+                &all_categories
+            ),
+        );
+
+        self.state.set_data(CompareData {
+            left,
+            right,
+            left_error: left_result.err().map(|e| e.to_string()),
+            right_error: right_result.err().map(|e| e.to_string()),
+        });
+
+        let len = self.rows().len();
+        self.selected = self.selected.min(len.saturating_sub(1));
+        self.table_state.select(Some(self.selected));
+
+        Ok(())
+    }
+
+    /// Rows to display, honoring the differences filter
+    fn rows(&self) -> Vec<CompareRow> {
+        let Some(data) = self.state.data() else {
+            return Vec::new();
+        };
+        let mut rows = compare_checks(&data.left, &data.right);
+        if self.only_differences {
+            rows.retain(CompareRow::differs);
+        }
+        rows
+    }
+
+    fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+        self.table_state.select(Some(self.selected));
+    }
+
+    fn select_next(&mut self) {
+        let len = self.rows().len();
+        if len > 0 {
+            self.selected = (self.selected + 1).min(len - 1);
+        }
+        self.table_state.select(Some(self.selected));
+    }
+
+    /// Status cell for one side of a row
+    fn status_cell(check: Option<&DiagnosticCheck>) -> Cell<'static> {
+        match check {
+            Some(check) => {
+                let (indicator, color) = check.status.indicator();
+                Cell::from(Line::from(vec![
+                    Span::styled(format!("{} ", indicator), Style::default().fg(color)),
+                    Span::raw(check.message.clone()),
+                ]))
+            }
+            None => Cell::from(Span::styled(
+                "not run",
+                Style::default().fg(Color::DarkGray),
+            )),
+        }
+    }
+
+    fn draw_header(&self, frame: &mut Frame, area: Rect) {
+        let (total, differing) = self
+            .state
+            .data()
+            .map(|d| {
+                let rows = compare_checks(&d.left, &d.right);
+                (rows.len(), rows.iter().filter(|r| r.differs()).count())
+            })
+            .unwrap_or((0, 0));
+
+        let diff_style = if differing > 0 {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default().fg(Color::Green)
+        };
+
+        let mut spans = vec![
+            Span::styled(" Compare: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(&self.left_node.hostname, Style::default().fg(Color::Cyan)),
+            Span::raw(" ⇄ "),
+            Span::styled(&self.right_node.hostname, Style::default().fg(Color::Cyan)),
+            Span::raw("   "),
+            Span::styled(
+                format!("{} of {} checks differ", differing, total),
+                diff_style,
+            ),
+        ];
+        if self.only_differences {
+            spans.push(Span::styled(
+                "  [differences only]",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    fn draw_table(&mut self, frame: &mut Frame, area: Rect) {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let header = Row::new(vec![
+            Cell::from("CATEGORY").style(bold),
+            Cell::from("CHECK").style(bold),
+            Cell::from(self.left_node.hostname.clone()).style(bold),
+            Cell::from(self.right_node.hostname.clone()).style(bold),
+        ]);
+
+        let rows: Vec<Row> = self
+            .rows()
+            .into_iter()
+            .map(|row| {
+                let marker_style = if row.differs() {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                let row_style = if row.differs() {
+                    Style::default().add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Row::new(vec![
+                    Cell::from(row.category).style(Style::default().fg(Color::DarkGray)),
+                    Cell::from(Line::from(vec![
+                        Span::styled(if row.differs() { "≠ " } else { "  " }, marker_style),
+                        Span::raw(row.name.clone()),
+                    ])),
+                    Self::status_cell(row.left.as_ref()),
+                    Self::status_cell(row.right.as_ref()),
+                ])
+                .style(row_style)
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(22),
+            Constraint::Length(24),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(" Checks "))
+            .row_highlight_style(Style::default().bg(Color::DarkGray));
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+
+    /// Details of the selected check on both nodes
+    fn draw_details(&self, frame: &mut Frame, area: Rect) {
+        let halves = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+
+        let row = self.rows().into_iter().nth(self.selected);
+        let data = self.state.data();
+        let sides = [
+            (
+                &self.left_node,
+                row.as_ref().and_then(|r| r.left.as_ref()),
+                data.and_then(|d| d.left_error.as_ref()),
+            ),
+            (
+                &self.right_node,
+                row.as_ref().and_then(|r| r.right.as_ref()),
+                data.and_then(|d| d.right_error.as_ref()),
+            ),
+        ];
+
+        for ((node, check, error), area) in sides.into_iter().zip(halves.iter()) {
+            let mut lines = Vec::new();
+            if let Some(err) = error {
+                lines.push(Line::styled(
+                    format!("Diagnostics incomplete: {}", err),
+                    Style::default().fg(Color::Red),
+                ));
+            }
+            match check {
+                Some(check) => {
+                    let (indicator, color) = check.status.indicator();
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("{} ", indicator), Style::default().fg(color)),
+                        Span::raw(check.message.clone()),
+                    ]));
+                    if let Some(details) = &check.details {
+                        lines.extend(details.lines().map(|l| Line::raw(l.to_string())));
+                    }
+                }
+                None if row.is_some() => lines.push(Line::styled(
+                    "Check did not run on this node",
+                    Style::default().fg(Color::DarkGray),
+                )),
+                None => {}
+            }
+
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", node.hostname));
+            frame.render_widget(
+                Paragraph::new(lines)
+                    .block(block)
+                    .wrap(Wrap { trim: false }),
+                *area,
+            );
+        }
+    }
+}
+
+impl Component for DiagnosticsCompareComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Navigate"),
+            ("d", "Show only differences"),
+            ("r", "Refresh"),
+            ("q/Esc", "Back"),
+        ])
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(Some(Action::Back)),
+            KeyCode::Up | KeyCode::Char('k') => self.select_prev(),
+            KeyCode::Down | KeyCode::Char('j') => self.select_next(),
+            KeyCode::Char('d') => {
+                self.only_differences = !self.only_differences;
+                self.selected = 0;
+                self.table_state.select(Some(0));
+            }
+            KeyCode::Char('r') => {
+                return Ok(manual_refresh(&self.state));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, _action: Action) -> Result<Option<Action>> {
+        // Diagnostics are expensive - only refresh on request
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        if self.state.is_loading() && !self.state.has_data() {
+            let loading = Paragraph::new(format!(
+                "Running diagnostics on {} and {}...",
+                self.left_node.hostname, self.right_node.hostname
+            ))
+            .style(Style::default().fg(Color::DarkGray));
+            frame.render_widget(loading, area);
+            return Ok(());
+        }
+
+        if let Some(err) = self.state.error() {
+            let error =
+                Paragraph::new(format!("Error: {}", err)).style(Style::default().fg(Color::Red));
+            frame.render_widget(error, area);
+            return Ok(());
+        }
+
+        let chunks = Layout::vertical([
+            Constraint::Length(1), // Header
+            Constraint::Min(5),    // Table
+            Constraint::Length(8), // Details
+            Constraint::Length(1), // Help
+        ])
+        .split(area);
+
+        self.draw_header(frame, chunks[0]);
+        self.draw_table(frame, chunks[1]);
+        self.draw_details(frame, chunks[2]);
+
+        let help = Line::from(vec![
+            Span::styled(" ↑/↓", Style::default().fg(Color::Cyan)),
+            Span::raw(" navigate  "),
+            Span::styled("d", Style::default().fg(Color::Cyan)),
+            Span::raw(" differences only  "),
+            Span::styled("r", Style::default().fg(Color::Cyan)),
+            Span::raw(" refresh  "),
+            Span::styled("Esc", Style::default().fg(Color::Cyan)),
+            Span::raw(" back"),
+        ]);
+        frame.render_widget(
+            Paragraph::new(help).style(Style::default().fg(Color::DarkGray)),
+            chunks[3],
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(id: &str, status: CheckStatus, message: &str) -> DiagnosticCheck {
+        DiagnosticCheck {
+            id: id.to_string(),
+            name: id.to_string(),
+            status,
+            message: message.to_string(),
+            details: None,
+            fix: None,
+        }
+    }
+
+    #[test]
+    fn test_compare_checks_highlights_differences() {
+        let left = DiagnosticsData {
+            system_checks: vec![
+                check("memory", CheckStatus::Pass, "2 GB / 4 GB"),
+                check("br_netfilter", CheckStatus::Fail, "Module not loaded"),
+            ],
+            cni_checks: vec![check("cni", CheckStatus::Pass, "Flannel")],
+            ..Default::default()
+        };
+        let right = DiagnosticsData {
+            system_checks: vec![
+                check("memory", CheckStatus::Pass, "2 GB / 4 GB"),
+                check("br_netfilter", CheckStatus::Pass, "Loaded"),
+                check("cpu", CheckStatus::Pass, "4 cores"),
+            ],
+            cni_checks: vec![check("cni", CheckStatus::Pass, "Flannel")],
+            ..Default::default()
+        };
+
+        let rows = compare_checks(&left, &right);
+        let ids: Vec<_> = rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(ids, ["memory", "br_netfilter", "cpu", "cni"]);
+
+        let differs: Vec<_> = rows.iter().map(CompareRow::differs).collect();
+        assert_eq!(differs, [false, true, true, false]);
+
+        // Check only the right node ran
+        assert!(rows[2].left.is_none());
+        assert_eq!(rows[3].category, "CNI");
+    }
+
+    #[test]
+    fn test_compare_row_message_difference() {
+        let row = CompareRow {
+            category: "Kubernetes Components",
+            name: "pki".to_string(),
+            left: Some(check("pki", CheckStatus::Warn, "Expires in 20 days")),
+            right: Some(check("pki", CheckStatus::Warn, "Expires in 300 days")),
+        };
+        assert!(row.differs());
+    }
+}
@@ -2,10 +2,52 @@
 //!
 //! These checks are CNI-agnostic and addon-agnostic.
 
+use super::k8s::{NodeSchedulingInfo, PendingCsrInfo};
 use super::pki::{self, CertStatus, CertificateInfo};
 use super::types::{DiagnosticCheck, DiagnosticContext, DiagnosticFix, FixAction};
+use crate::components::machine_config::MACHINE_CONFIG_PATH;
+use talos_pilot_core::{format_bytes, format_duration_detailed, format_duration_human, pluralize};
 use talos_rs::TalosClient;
 
+/// Lets kubelet start with swap on while keeping pods off it
+const KUBELET_NO_SWAP_PATCH: &str = "\
+machine:
+  kubelet:
+    extraConfig:
+      failSwapOn: false
+      memorySwap:
+        swapBehavior: NoSwap
+";
+
+/// Boot times this close are the same boot: the kernel reports boot time
+/// relative to the wall clock, which NTP may step after boot
+const BOOT_TIME_TOLERANCE_SECS: u64 = 60;
+/// Boot times kept per node
+const BOOT_HISTORY_LEN: usize = 10;
+/// How far back reboots count toward a reboot loop
+const REBOOT_LOOP_WINDOW_SECS: u64 = 30 * 60;
+/// Reboots within the window that make a loop
+const REBOOT_LOOP_MIN_REBOOTS: usize = 2;
+
+/// Add a node's boot time from the latest refresh to its history (oldest
+/// first), returning whether the node rebooted since the previous one
+pub fn record_boot_time(history: &mut Vec<u64>, boot_time: u64) -> bool {
+    // Nodes that don't report a boot time send 0
+    if boot_time == 0
+        || history
+            .last()
+            .is_some_and(|&last| boot_time.abs_diff(last) <= BOOT_TIME_TOLERANCE_SECS)
+    {
+        return false;
+    }
+    let rebooted = !history.is_empty();
+    history.push(boot_time);
+    if history.len() > BOOT_HISTORY_LEN {
+        history.remove(0);
+    }
+    rebooted
+}
+
 /// Run all core system health checks
 pub async fn run_system_checks(
     client: &TalosClient,
@@ -13,7 +55,7 @@ pub async fn run_system_checks(
 ) -> Vec<DiagnosticCheck> {
     let mut checks = Vec::new();
 
-    // Memory check
+    // Memory and swap checks
     match client.memory().await {
         Ok(mem_list) => {
             if let Some(mem) = mem_list.first()
@@ -31,6 +73,20 @@ pub async fn run_system_checks(
                 } else {
                     checks.push(DiagnosticCheck::pass("memory", "Memory", &msg));
                 }
+
+                // Only worth reading the config when swap is actually on
+                let kubelet = if info.swap_total == 0 {
+                    KubeletSwap::Unknown
+                } else {
+                    match client.read_file(MACHINE_CONFIG_PATH).await {
+                        Ok(config) => parse_kubelet_swap(&config),
+                        Err(e) => {
+                            tracing::debug!("Failed to read machine config: {}", e);
+                            KubeletSwap::Unknown
+                        }
+                    }
+                };
+                checks.push(check_swap(info.swap_total, info.swap_free, kubelet));
             }
         }
         Err(e) => {
@@ -67,6 +123,13 @@ pub async fn run_system_checks(
         }
     }
 
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let node = ctx.node_endpoint.as_deref().unwrap_or(&ctx.hostname);
+    checks.push(check_reboot_loop(&ctx.boot_times, now, node));
+
     // TODO: Add disk usage check
     // This would check ephemeral and state partition usage
 
@@ -221,9 +284,312 @@ pub async fn run_kubernetes_checks(
         checks.push(DiagnosticCheck::unknown("pod_health", "Pod Health").with_details(&details));
     }
 
+    if let Some(ref csrs) = ctx.pending_kubelet_csrs {
+        checks.push(check_kubelet_csrs(csrs, &ctx.hostname));
+    }
+
+    if let Some(ref scheduling) = ctx.node_scheduling {
+        checks.push(check_node_taints(scheduling.as_ref(), &ctx.hostname));
+    }
+
     checks
 }
 
+/// Taints the node lifecycle controllers add and should remove again, with
+/// what usually keeps them in place
+const STUCK_TAINTS: [(&str, &str); 4] = [
+    (
+        "node.kubernetes.io/not-ready",
+        "kubelet reports NotReady, often because the CNI isn't running yet",
+    ),
+    (
+        "node.kubernetes.io/unreachable",
+        "the node controller lost contact with the kubelet",
+    ),
+    (
+        "node.kubernetes.io/network-unavailable",
+        "the CNI hasn't set up the node's network",
+    ),
+    (
+        "node.cloudprovider.kubernetes.io/uninitialized",
+        "kubelet runs with an external cloud provider, but no cloud controller \
+         manager has initialized the node",
+    ),
+];
+
+/// Labels worth showing when scheduling goes wrong
+const KEY_LABEL_PREFIXES: [&str; 5] = [
+    "node-role.kubernetes.io/",
+    "kubernetes.io/arch",
+    "kubernetes.io/os",
+    "topology.kubernetes.io/",
+    "node.kubernetes.io/instance-type",
+];
+
+/// Check the node's taints for ones that keep pods away by accident
+///
+/// `info` is None when the node isn't registered with Kubernetes at all,
+/// which is where a worker that never joined ends up.
+fn check_node_taints(info: Option<&NodeSchedulingInfo>, hostname: &str) -> DiagnosticCheck {
+    let Some(info) = info else {
+        return DiagnosticCheck::warn("node_taints", "Node Taints", "Node not registered")
+            .with_details(&format!(
+                "No Kubernetes node named {} exists.\n\n\
+                 The kubelet hasn't registered the node yet. Check the kubelet\n\
+                 service logs and any pending kubelet CSRs.",
+                hostname
+            ));
+    };
+
+    let mut details = String::from("Taints:\n");
+    if info.taints.is_empty() {
+        details.push_str("  (none)\n");
+    }
+    for taint in &info.taints {
+        match &taint.value {
+            Some(value) => {
+                details.push_str(&format!("  {}={}:{}\n", taint.key, value, taint.effect))
+            }
+            None => details.push_str(&format!("  {}:{}\n", taint.key, taint.effect)),
+        }
+    }
+    details.push_str("\nLabels:\n");
+    for (key, value) in info
+        .labels
+        .iter()
+        .filter(|(k, _)| KEY_LABEL_PREFIXES.iter().any(|p| k.starts_with(p)))
+    {
+        details.push_str(&format!("  {}={}\n", key, value));
+    }
+
+    let stuck: Vec<(&str, &str)> = STUCK_TAINTS
+        .iter()
+        .filter(|(key, _)| info.taints.iter().any(|t| t.key == *key))
+        .copied()
+        .collect();
+
+    let mut problems: Vec<String> = stuck
+        .iter()
+        .map(|(key, _)| key.rsplit('/').next().unwrap_or(key).to_string())
+        .collect();
+    if info.unschedulable {
+        problems.push("cordoned".to_string());
+    }
+    if problems.is_empty() {
+        let message = match info.taints.len() {
+            0 => "No taints".to_string(),
+            n => pluralize(n, "taint", "taints"),
+        };
+        return DiagnosticCheck::pass("node_taints", "Node Taints", &message)
+            .with_details(details.trim_end());
+    }
+
+    details.push('\n');
+    for (key, cause) in &stuck {
+        details.push_str(&format!("{}: {}.\n", key, cause));
+    }
+    if info.unschedulable {
+        details.push_str(&format!(
+            "The node is cordoned; once maintenance is done:\n  kubectl uncordon {}\n",
+            hostname
+        ));
+    }
+
+    DiagnosticCheck::warn("node_taints", "Node Taints", &problems.join(", "))
+        .with_details(details.trim_end())
+}
+
+/// Check for kubelet serving certificate requests waiting for approval
+///
+/// With `rotate-server-certificates` enabled, each kubelet requests its
+/// serving cert through a CSR that something must approve. Until then the
+/// API server can't verify the kubelet, which shows up as `kubectl logs`
+/// and `exec` failing and metrics-server being unable to scrape.
+fn check_kubelet_csrs(csrs: &[PendingCsrInfo], hostname: &str) -> DiagnosticCheck {
+    if csrs.is_empty() {
+        return DiagnosticCheck::pass("kubelet_csr", "Kubelet Serving Certs", "No pending CSRs");
+    }
+
+    let this_node = format!("system:node:{}", hostname);
+    let pending = pluralize(csrs.len(), "pending CSR", "pending CSRs");
+    let message = if csrs.iter().any(|c| c.requestor == this_node) {
+        format!("{}, including this node", pending)
+    } else {
+        pending
+    };
+
+    let name_width = csrs.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    let mut details = String::from(
+        "Kubelet serving certificate requests are waiting for approval.\n\
+         Until approved, kubectl logs/exec and metrics-server can't reach\n\
+         the requesting kubelets.\n\n\
+         Pending:\n",
+    );
+    for csr in csrs {
+        details.push_str(&format!(
+            "  {:width$}  {}\n",
+            csr.name,
+            csr.requestor,
+            width = name_width
+        ));
+    }
+    details.push_str(
+        "\nApprove with:\n  kubectl certificate approve <name>\n\n\
+         To approve them automatically, deploy kubelet-serving-cert-approver.",
+    );
+
+    DiagnosticCheck::warn("kubelet_csr", "Kubelet Serving Certs", &message).with_details(&details)
+}
+
+/// Whether kubelet will run on a node with swap on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KubeletSwap {
+    /// `failSwapOn` is unset or true: kubelet exits at startup
+    Refused,
+    /// `failSwapOn: false` in the kubelet extra config
+    Allowed,
+    /// The machine config could not be read
+    Unknown,
+}
+
+/// Read `machine.kubelet.extraConfig.failSwapOn` from the machine config
+fn parse_kubelet_swap(config: &str) -> KubeletSwap {
+    use serde::Deserialize;
+
+    for doc in serde_yaml::Deserializer::from_str(config) {
+        let Ok(value) = serde_yaml::Value::deserialize(doc) else {
+            continue;
+        };
+        let Some(machine) = value.get("machine") else {
+            continue;
+        };
+        let fail_swap_on = machine
+            .get("kubelet")
+            .and_then(|k| k.get("extraConfig"))
+            .and_then(|c| c.get("failSwapOn"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+        return if fail_swap_on {
+            KubeletSwap::Refused
+        } else {
+            KubeletSwap::Allowed
+        };
+    }
+    KubeletSwap::Unknown
+}
+
+/// Uptime, failing when it keeps resetting
+///
+/// `boot_times` is the node's boot history across refreshes, oldest first,
+/// so every entry after the first is a reboot seen while watching it. A
+/// single reboot only warns: upgrades and config applies reboot too.
+fn check_reboot_loop(boot_times: &[u64], now: u64, node: &str) -> DiagnosticCheck {
+    let Some(&current) = boot_times.last() else {
+        return DiagnosticCheck::unknown("reboot_loop", "Uptime")
+            .with_details("The node did not report its boot time.");
+    };
+    let duration = |secs: u64| chrono::Duration::seconds(secs as i64);
+    let uptime = format_duration_detailed(duration(now.saturating_sub(current)));
+
+    let since = now.saturating_sub(REBOOT_LOOP_WINDOW_SECS);
+    let reboots = boot_times[1..].iter().filter(|&&b| b >= since).count();
+    if reboots == 0 {
+        return DiagnosticCheck::pass("reboot_loop", "Uptime", &format!("Up {}", uptime));
+    }
+
+    let history = boot_times
+        .iter()
+        .filter_map(|&b| chrono::DateTime::from_timestamp(b as i64, 0))
+        .map(|t| {
+            format!(
+                "  booted {}",
+                t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    if reboots < REBOOT_LOOP_MIN_REBOOTS {
+        return DiagnosticCheck::warn("reboot_loop", "Uptime", &format!("Rebooted, up {}", uptime))
+            .with_details(&format!(
+                "The node rebooted while talos-pilot was watching it. This is\n\
+             expected after an upgrade or a config apply.\n\n{}",
+                history
+            ));
+    }
+
+    let first = boot_times[boot_times.len() - reboots];
+    let cadence = format_duration_human(duration((current - first) / (reboots as u64 - 1)));
+    DiagnosticCheck::fail(
+        "reboot_loop",
+        "Uptime",
+        &format!(
+            "Reboot loop: {} reboots in {}, every ~{}",
+            reboots,
+            format_duration_human(duration(now.saturating_sub(first))),
+            cadence
+        ),
+        None,
+    )
+    .with_details(&format!(
+        "The node's uptime keeps resetting.\n\n{}\n\n\
+         Look for what takes it down in the kernel log and on the console:\n\
+         kernel panics, watchdog resets, a failing install or a config that\n\
+         triggers a reboot on every boot.\n\n  talosctl -n {} dmesg",
+        history, node
+    ))
+}
+
+/// Check for active swap, which kubelet refuses by default
+///
+/// Self-built images and `SwapVolumeConfig` documents can turn swap on
+/// without the kubelet being told, and kubelet then fails to start with
+/// "running with swap on is not supported".
+fn check_swap(swap_total: u64, swap_free: u64, kubelet: KubeletSwap) -> DiagnosticCheck {
+    if swap_total == 0 {
+        return DiagnosticCheck::pass("swap", "Swap", "Off");
+    }
+
+    let used = swap_total.saturating_sub(swap_free);
+    let usage = format!("{} / {} used", format_bytes(used), format_bytes(swap_total));
+    let remove_hint = "To turn swap off instead, remove the SwapVolumeConfig document or\n\
+                       the swap partition from the image and reboot.";
+
+    match kubelet {
+        KubeletSwap::Allowed => DiagnosticCheck::warn("swap", "Swap", &format!("On, {}", usage))
+            .with_details(&format!(
+                "Kubelet has failSwapOn: false, so it runs with swap on.\n\
+                 Check machine.kubelet.extraConfig.memorySwap.swapBehavior to\n\
+                 control whether pods may use it (NoSwap keeps them off).\n\n{}",
+                remove_hint
+            )),
+        KubeletSwap::Refused => DiagnosticCheck::fail(
+            "swap",
+            "Swap",
+            "On, kubelet refuses swap",
+            Some(DiagnosticFix {
+                description: "Let kubelet run with swap on, keeping pods off it".to_string(),
+                action: FixAction::ApplyConfigPatch {
+                    yaml: KUBELET_NO_SWAP_PATCH.to_string(),
+                    requires_reboot: false,
+                },
+            }),
+        )
+        .with_details(&format!(
+            "Swap is on ({}) but kubelet still has the default\n\
+             failSwapOn: true, so it exits with \"running with swap on is not\n\
+             supported\" and the node never becomes Ready.\n\n\
+             The fix sets failSwapOn: false with swapBehavior: NoSwap.\n\n{}",
+            usage, remove_hint
+        )),
+        KubeletSwap::Unknown => DiagnosticCheck::warn("swap", "Swap", &format!("On, {}", usage))
+            .with_details(&format!(
+                "The machine config could not be read, so it is unknown whether\n\
+                 kubelet allows swap (failSwapOn defaults to true).\n\n{}",
+                remove_hint
+            )),
+    }
+}
+
 /// Check if CNI is working (generic check via file existence)
 ///
 /// This checks for CNI-specific config files rather than parsing logs.
@@ -467,3 +833,118 @@ fn cert_to_diagnostic_check(id: &str, name: &str, cert: &CertificateInfo) -> Dia
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use talos_pilot_core::CheckStatus;
+
+    #[test]
+    fn test_parse_kubelet_swap() {
+        let config = "version: v1alpha1\nmachine:\n  type: worker\n";
+        assert_eq!(parse_kubelet_swap(config), KubeletSwap::Refused);
+
+        let config = "machine:\n  kubelet:\n    extraConfig:\n      failSwapOn: false\n";
+        assert_eq!(parse_kubelet_swap(config), KubeletSwap::Allowed);
+
+        assert_eq!(
+            parse_kubelet_swap("kind: HostnameConfig\nhostname: w1\n"),
+            KubeletSwap::Unknown
+        );
+    }
+
+    #[test]
+    fn test_check_node_taints() {
+        use crate::components::diagnostics::k8s::NodeTaint;
+
+        let taint = |key: &str| NodeTaint {
+            key: key.to_string(),
+            value: None,
+            effect: "NoSchedule".to_string(),
+        };
+        let mut info = NodeSchedulingInfo::default();
+        info.labels.insert(
+            "node-role.kubernetes.io/control-plane".to_string(),
+            String::new(),
+        );
+        info.taints
+            .push(taint("node-role.kubernetes.io/control-plane"));
+        let check = check_node_taints(Some(&info), "cp-1");
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert_eq!(check.message, "1 taint");
+
+        info.taints
+            .push(taint("node.cloudprovider.kubernetes.io/uninitialized"));
+        info.unschedulable = true;
+        let check = check_node_taints(Some(&info), "cp-1");
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert_eq!(check.message, "uninitialized, cordoned");
+        let details = check.details.unwrap();
+        assert!(details.contains("cloud controller"));
+        assert!(details.contains("kubectl uncordon cp-1"));
+
+        let check = check_node_taints(None, "worker-3");
+        assert_eq!(check.status, CheckStatus::Warn);
+        assert_eq!(check.message, "Node not registered");
+    }
+
+    #[test]
+    fn test_reboot_loop_detection() {
+        let now = 1_700_000_000;
+        let mut history = Vec::new();
+        assert!(!record_boot_time(&mut history, now - 86_400));
+        // Same boot, shifted a little by NTP
+        assert!(!record_boot_time(&mut history, now - 86_400 + 5));
+        assert!(!record_boot_time(&mut history, 0));
+        let check = check_reboot_loop(&history, now, "10.0.0.2");
+        assert_eq!(check.status, CheckStatus::Pass);
+        assert_eq!(check.message, "Up 1d");
+
+        assert!(record_boot_time(&mut history, now - 1_200));
+        let check = check_reboot_loop(&history, now, "10.0.0.2");
+        assert_eq!(check.status, CheckStatus::Warn);
+
+        assert!(record_boot_time(&mut history, now - 600));
+        let check = check_reboot_loop(&history, now, "10.0.0.2");
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert_eq!(check.message, "Reboot loop: 2 reboots in 20m, every ~10m");
+        assert!(
+            check
+                .details
+                .unwrap()
+                .contains("talosctl -n 10.0.0.2 dmesg")
+        );
+
+        // Old reboots don't make a loop
+        let check = check_reboot_loop(&history, now + 86_400, "10.0.0.2");
+        assert_eq!(check.status, CheckStatus::Pass);
+
+        assert_eq!(
+            check_reboot_loop(&[], now, "10.0.0.2").status,
+            CheckStatus::Unknown
+        );
+    }
+
+    #[test]
+    fn test_check_swap() {
+        assert_eq!(
+            check_swap(0, 0, KubeletSwap::Refused).status,
+            CheckStatus::Pass
+        );
+
+        let gib = 1 << 30;
+        let refused = check_swap(2 * gib, gib, KubeletSwap::Refused);
+        assert_eq!(refused.status, CheckStatus::Fail);
+        let fix = refused.fix.expect("refused swap offers a fix");
+        assert!(matches!(
+            fix.action,
+            FixAction::ApplyConfigPatch { ref yaml, requires_reboot: false }
+                if yaml.contains("failSwapOn: false")
+        ));
+
+        assert_eq!(
+            check_swap(2 * gib, gib, KubeletSwap::Allowed).status,
+            CheckStatus::Warn
+        );
+    }
+}
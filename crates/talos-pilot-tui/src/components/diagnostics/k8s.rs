@@ -2,13 +2,16 @@
 //!
 //! Creates a K8s client from Talos-provided kubeconfig.
 
+use futures::{AsyncBufReadExt, TryStreamExt};
+use k8s_openapi::api::certificates::v1::CertificateSigningRequest;
 use k8s_openapi::api::core::v1::{Node, Pod};
 use k8s_openapi::api::policy::v1::PodDisruptionBudget;
 use k8s_openapi::serde_json::json;
 use kube::{
     Client, Config,
-    api::{Api, EvictParams, ListParams, Patch, PatchParams},
+    api::{Api, EvictParams, ListParams, LogParams, Patch, PatchParams},
 };
+use serde::{Deserialize, Serialize};
 use talos_rs::TalosClient;
 
 /// Error type for K8s operations
@@ -113,6 +116,14 @@ pub async fn create_k8s_client_with_source(
     }
 }
 
+/// Create a K8s client only from the kubeconfig Talos serves
+///
+/// Unlike [`create_k8s_client`], the environment's kubeconfig is ignored, so
+/// a freshly bootstrapped cluster is never confused with the user's current one.
+pub async fn create_k8s_client_from_talos(talos_client: &TalosClient) -> Result<Client, K8sError> {
+    fetch_kubeconfig_from_client(talos_client).await
+}
+
 /// Fetch kubeconfig from a specific Talos client and create a K8s client
 async fn fetch_kubeconfig_from_client(client: &TalosClient) -> Result<Client, K8sError> {
     // Get kubeconfig from Talos
@@ -147,6 +158,46 @@ pub async fn create_k8s_client_with_kubeconfig_source(
     Ok(client)
 }
 
+/// Create a Kubernetes client, fetching kubeconfig from the first of several
+/// control plane nodes that serves it
+///
+/// Worker nodes don't serve kubeconfig, so trying each control plane in
+/// order keeps one down node from breaking worker diagnostics. Returns the
+/// endpoint that served the kubeconfig, if one did.
+pub async fn create_k8s_client_via_controlplanes(
+    talos_client: &TalosClient,
+    controlplane_endpoints: &[String],
+) -> Result<(Client, Option<String>), K8sError> {
+    if controlplane_endpoints.is_empty() {
+        return create_k8s_client_with_kubeconfig_source(talos_client, None)
+            .await
+            .map(|client| (client, None));
+    }
+
+    // Same precedence as create_k8s_client_with_source
+    if let Ok(config) = Config::infer().await
+        && let Ok(client) = Client::try_from(config)
+    {
+        tracing::debug!("Using kubeconfig from environment (KUBECONFIG or default path)");
+        return Ok((client, None));
+    }
+
+    for endpoint in controlplane_endpoints {
+        tracing::debug!("Targeting control plane node {} for kubeconfig", endpoint);
+        match fetch_kubeconfig_from_client(&talos_client.with_node(endpoint)).await {
+            Ok(client) => return Ok((client, Some(endpoint.clone()))),
+            Err(e) => {
+                tracing::warn!("Failed to fetch kubeconfig from node {}: {}", endpoint, e);
+            }
+        }
+    }
+
+    // Last resort: the main client (may fail with multiple nodes configured)
+    fetch_kubeconfig_from_client(talos_client)
+        .await
+        .map(|client| (client, None))
+}
+
 /// Detected CNI information from K8s
 #[derive(Debug, Clone, Default)]
 pub struct CniInfo {
@@ -283,12 +334,16 @@ pub struct UnhealthyPodInfo {
     pub name: String,
     /// Pod namespace
     pub namespace: String,
+    /// Name of the unhealthy container (empty for pending pods)
+    pub container: String,
     /// Container state (e.g., "CrashLoopBackOff", "ImagePullBackOff")
     pub state: String,
     /// Number of restarts
     pub restart_count: i32,
     /// Last termination reason (if any)
     pub last_reason: Option<String>,
+    /// Why the container is waiting, e.g. the image pull error
+    pub message: Option<String>,
 }
 
 /// Pod health summary from K8s API
@@ -369,9 +424,11 @@ pub async fn check_pod_health(client: &Client) -> Result<PodHealthInfo, K8sError
                     let pod_info = UnhealthyPodInfo {
                         name: name.clone(),
                         namespace: namespace.clone(),
+                        container: cs.name.clone(),
                         state: reason.clone(),
                         restart_count,
                         last_reason,
+                        message: waiting.message.clone(),
                     };
 
                     match reason.as_str() {
@@ -402,9 +459,11 @@ pub async fn check_pod_health(client: &Client) -> Result<PodHealthInfo, K8sError
                 info.pending.push(UnhealthyPodInfo {
                     name,
                     namespace,
+                    container: String::new(),
                     state: "Pending".to_string(),
                     restart_count: 0,
                     last_reason: None,
+                    message: None,
                 });
             }
         }
@@ -413,6 +472,257 @@ pub async fn check_pod_health(client: &Client) -> Result<PodHealthInfo, K8sError
     Ok(info)
 }
 
+/// Fetch the last `tail_lines` log lines of a pod's container
+///
+/// With `previous` set, returns logs from the previous (terminated) instance of
+/// the container, which is where the crash reason lives for CrashLoopBackOff.
+pub async fn fetch_pod_logs(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    container: &str,
+    tail_lines: i64,
+    previous: bool,
+) -> Result<String, K8sError> {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let params = LogParams {
+        container: (!container.is_empty()).then(|| container.to_string()),
+        tail_lines: Some(tail_lines),
+        previous,
+        ..Default::default()
+    };
+    pods.logs(name, &params)
+        .await
+        .map_err(|e| K8sError::ApiError(e.to_string()))
+}
+
+/// A pod and the containers it runs, for picking a log source
+#[derive(Debug, Clone)]
+pub struct PodContainers {
+    pub namespace: String,
+    pub name: String,
+    /// Pod phase (e.g., "Running", "Pending")
+    pub phase: String,
+    /// Init containers first, then regular containers, in spec order
+    pub containers: Vec<String>,
+}
+
+impl PodContainers {
+    /// Build from a pod, skipping pods without a name
+    pub fn from_pod(pod: &Pod) -> Option<Self> {
+        let name = pod.metadata.name.clone()?;
+        let spec = pod.spec.as_ref();
+        let containers = spec
+            .and_then(|s| s.init_containers.as_ref())
+            .into_iter()
+            .flatten()
+            .chain(spec.map(|s| s.containers.iter()).into_iter().flatten())
+            .map(|c| c.name.clone())
+            .collect();
+        Some(Self {
+            namespace: pod.metadata.namespace.clone().unwrap_or_default(),
+            name,
+            phase: pod
+                .status
+                .as_ref()
+                .and_then(|s| s.phase.clone())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            containers,
+        })
+    }
+}
+
+/// List every pod in the cluster with its containers, sorted by namespace then name
+pub async fn list_pod_containers(client: &Client) -> Result<Vec<PodContainers>, K8sError> {
+    let pods: Api<Pod> = Api::all(client.clone());
+    let list = pods
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| K8sError::ApiError(e.to_string()))?;
+    let mut result: Vec<PodContainers> = list
+        .items
+        .iter()
+        .filter_map(PodContainers::from_pod)
+        .collect();
+    result.sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
+    Ok(result)
+}
+
+/// Follow a pod container's log, sending each line to `tx`
+///
+/// Starts with the last `tail_lines` lines and returns when the container
+/// stops, the API closes the stream or the receiver is dropped.
+pub async fn stream_pod_logs(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    container: &str,
+    tail_lines: i64,
+    tx: tokio::sync::mpsc::UnboundedSender<String>,
+) -> Result<(), K8sError> {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let params = LogParams {
+        container: (!container.is_empty()).then(|| container.to_string()),
+        tail_lines: Some(tail_lines),
+        follow: true,
+        ..Default::default()
+    };
+    let stream = pods
+        .log_stream(name, &params)
+        .await
+        .map_err(|e| K8sError::ApiError(e.to_string()))?;
+
+    let mut lines = stream.lines();
+    while let Some(line) = lines
+        .try_next()
+        .await
+        .map_err(|e| K8sError::ApiError(e.to_string()))?
+    {
+        if tx.send(line).is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Signer for kubelet serving certificates (issued via CSR when the kubelet
+/// runs with `rotate-server-certificates`)
+const KUBELET_SERVING_SIGNER: &str = "kubernetes.io/kubelet-serving";
+
+/// A kubelet serving certificate request that nobody has approved yet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingCsrInfo {
+    /// CSR name (e.g., "csr-7x2lq")
+    pub name: String,
+    /// Requesting user (e.g., "system:node:worker-1")
+    pub requestor: String,
+}
+
+/// List kubelet serving CSRs that are neither approved nor denied
+pub async fn list_pending_kubelet_csrs(client: &Client) -> Result<Vec<PendingCsrInfo>, K8sError> {
+    let csrs: Api<CertificateSigningRequest> = Api::all(client.clone());
+
+    let csr_list = csrs
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| K8sError::ApiError(e.to_string()))?;
+
+    Ok(csr_list
+        .items
+        .iter()
+        .filter(|csr| is_pending_kubelet_serving_csr(csr))
+        .map(|csr| PendingCsrInfo {
+            name: csr.metadata.name.clone().unwrap_or_default(),
+            requestor: csr.spec.username.clone().unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Pod CIDRs allocated to a node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodePodCidrs {
+    /// Node name
+    pub node: String,
+    /// Ranges from `spec.podCIDRs` (one per IP family), empty if unallocated
+    pub cidrs: Vec<String>,
+}
+
+/// List the pod CIDRs allocated to every node in the cluster
+pub async fn list_node_pod_cidrs(client: &Client) -> Result<Vec<NodePodCidrs>, K8sError> {
+    let nodes: Api<Node> = Api::all(client.clone());
+
+    let node_list = nodes
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| K8sError::ApiError(e.to_string()))?;
+
+    Ok(node_list
+        .items
+        .iter()
+        .map(|node| {
+            let spec = node.spec.as_ref();
+            // podCIDR predates dual-stack podCIDRs; fall back for old clusters
+            let cidrs = spec
+                .and_then(|s| s.pod_cidrs.clone())
+                .filter(|c| !c.is_empty())
+                .or_else(|| spec.and_then(|s| s.pod_cidr.clone()).map(|c| vec![c]))
+                .unwrap_or_default();
+            NodePodCidrs {
+                node: node.metadata.name.clone().unwrap_or_default(),
+                cidrs,
+            }
+        })
+        .collect())
+}
+
+/// A taint on a node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeTaint {
+    /// Taint key (e.g., "node.kubernetes.io/not-ready")
+    pub key: String,
+    /// Taint value, if any
+    pub value: Option<String>,
+    /// NoSchedule, PreferNoSchedule or NoExecute
+    pub effect: String,
+}
+
+/// What decides whether pods land on a node
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NodeSchedulingInfo {
+    /// Node labels
+    pub labels: std::collections::BTreeMap<String, String>,
+    /// Node taints
+    pub taints: Vec<NodeTaint>,
+    /// Whether the node is cordoned (`spec.unschedulable`)
+    pub unschedulable: bool,
+}
+
+/// Labels, taints and cordon state of a node, or None if it isn't registered
+pub async fn get_node_scheduling(
+    client: &Client,
+    node_name: &str,
+) -> Result<Option<NodeSchedulingInfo>, K8sError> {
+    let nodes: Api<Node> = Api::all(client.clone());
+
+    let Some(node) = nodes
+        .get_opt(node_name)
+        .await
+        .map_err(|e| K8sError::ApiError(e.to_string()))?
+    else {
+        return Ok(None);
+    };
+
+    let spec = node.spec.unwrap_or_default();
+    Ok(Some(NodeSchedulingInfo {
+        labels: node.metadata.labels.unwrap_or_default(),
+        taints: spec
+            .taints
+            .unwrap_or_default()
+            .into_iter()
+            .map(|t| NodeTaint {
+                key: t.key,
+                value: t.value,
+                effect: t.effect,
+            })
+            .collect(),
+        unschedulable: spec.unschedulable.unwrap_or(false),
+    }))
+}
+
+/// Whether a CSR is for a kubelet serving cert and still awaiting a decision
+fn is_pending_kubelet_serving_csr(csr: &CertificateSigningRequest) -> bool {
+    let decided = csr
+        .status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|conditions| {
+            conditions
+                .iter()
+                .any(|c| matches!(c.type_.as_str(), "Approved" | "Denied" | "Failed"))
+        });
+    csr.spec.signer_name == KUBELET_SERVING_SIGNER && !decided
+}
+
 /// Information about a PodDisruptionBudget
 #[derive(Debug, Clone)]
 pub struct PdbInfo {
@@ -1005,6 +1315,31 @@ pub async fn get_node_ready_status(
     Ok(NodeConditionStatus::Unknown)
 }
 
+/// Count Ready nodes: (ready, total)
+pub async fn count_ready_nodes(client: &Client) -> Result<(usize, usize), K8sError> {
+    let nodes: Api<Node> = Api::all(client.clone());
+    let list = nodes
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| K8sError::ApiError(format!("Failed to list nodes: {}", e)))?;
+
+    let ready = list
+        .items
+        .iter()
+        .filter(|node| {
+            node.status
+                .as_ref()
+                .and_then(|s| s.conditions.as_ref())
+                .is_some_and(|conditions| {
+                    conditions
+                        .iter()
+                        .any(|c| c.type_ == "Ready" && c.status == "True")
+                })
+        })
+        .count();
+    Ok((ready, list.items.len()))
+}
+
 /// Check if a node exists and is reachable in the cluster
 pub async fn node_exists(client: &Client, node_name: &str) -> bool {
     let nodes: Api<Node> = Api::all(client.clone());
@@ -1135,6 +1470,9 @@ pub async fn wait_for_node_ready(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use k8s_openapi::api::certificates::v1::{
+        CertificateSigningRequestCondition, CertificateSigningRequestStatus,
+    };
     use std::io::Write;
     use tempfile::NamedTempFile;
     use tokio::sync::Mutex;
@@ -1244,4 +1582,77 @@ users:
             "Config::infer() should fail with non-existent kubeconfig"
         );
     }
+
+    fn csr(signer: &str, conditions: &[&str]) -> CertificateSigningRequest {
+        let mut csr = CertificateSigningRequest::default();
+        csr.spec.signer_name = signer.to_string();
+        if !conditions.is_empty() {
+            csr.status = Some(CertificateSigningRequestStatus {
+                conditions: Some(
+                    conditions
+                        .iter()
+                        .map(|type_| CertificateSigningRequestCondition {
+                            type_: type_.to_string(),
+                            status: "True".to_string(),
+                            ..Default::default()
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            });
+        }
+        csr
+    }
+
+    #[test]
+    fn test_pending_kubelet_serving_csr() {
+        assert!(is_pending_kubelet_serving_csr(&csr(
+            KUBELET_SERVING_SIGNER,
+            &[]
+        )));
+        assert!(!is_pending_kubelet_serving_csr(&csr(
+            KUBELET_SERVING_SIGNER,
+            &["Approved"]
+        )));
+        assert!(!is_pending_kubelet_serving_csr(&csr(
+            KUBELET_SERVING_SIGNER,
+            &["Denied"]
+        )));
+        // Client certs are approved by a different controller
+        assert!(!is_pending_kubelet_serving_csr(&csr(
+            "kubernetes.io/kube-apiserver-client-kubelet",
+            &[]
+        )));
+    }
+
+    #[test]
+    fn test_pod_containers_lists_init_containers_first() {
+        use k8s_openapi::api::core::v1::{Container, PodSpec, PodStatus};
+        let container = |name: &str| Container {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        let mut pod = Pod::default();
+        pod.metadata.name = Some("cilium-x7k2p".to_string());
+        pod.metadata.namespace = Some("kube-system".to_string());
+        pod.spec = Some(PodSpec {
+            init_containers: Some(vec![container("install-cni-binaries")]),
+            containers: vec![container("cilium-agent")],
+            ..Default::default()
+        });
+        pod.status = Some(PodStatus {
+            phase: Some("Running".to_string()),
+            ..Default::default()
+        });
+
+        let info = PodContainers::from_pod(&pod).unwrap();
+        assert_eq!(info.namespace, "kube-system");
+        assert_eq!(info.phase, "Running");
+        assert_eq!(
+            info.containers,
+            vec!["install-cni-binaries", "cilium-agent"]
+        );
+
+        assert!(PodContainers::from_pod(&Pod::default()).is_none());
+    }
 }
@@ -7,17 +7,27 @@
 //! - `core.rs` - Core checks that run on any Talos cluster
 //! - `cni/` - CNI-specific checks (Flannel, Cilium, Calico)
 //! - `addons/` - Addon-specific checks (cert-manager, etc.)
+//! - `compare.rs` - Side-by-side comparison of two nodes
+//! - `report.rs` - Markdown bug report generation
+//! - `snapshot.rs` - Saving results to a file and loading them back
 //! - `types.rs` - Shared types
 
 pub mod addons;
 pub mod cni;
+pub mod compare;
 pub mod core;
 pub mod k8s;
 pub mod pki;
+pub mod preview;
+pub mod report;
+pub mod snapshot;
 pub mod types;
 
 use crate::action::Action;
-use crate::components::Component;
+use crate::audit::{audit_failure, audit_start, audit_success};
+use crate::components::machine_config::MACHINE_CONFIG_PATH;
+use crate::components::{Component, help_entries};
+use crate::state::UiState;
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -25,20 +35,36 @@ use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph, Row, Table, TableState},
+    widgets::{Block, Borders, Clear, Paragraph, Row, Table, TableState, Wrap},
 };
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use talos_pilot_core::AsyncState;
-use talos_rs::{ApplyConfigResult, ApplyMode, TalosClient};
+use talos_pilot_core::{AsyncState, ComponentError, format_talos_error};
+use talos_rs::{ApplyConfigResult, ApplyMode, TalosClient, talosctl_env};
+use tokio::task::JoinHandle;
 
-use crate::ui_ext::CheckStatusExt;
+use crate::ui_ext::{
+    CheckStatusExt, ComponentErrorExt, NodeInfo, NotifyLevel, empty_state, glyphs,
+    last_updated_span, manual_refresh, node_info_spans,
+};
+use preview::CurrentValue;
+use snapshot::DiagnosticsSnapshot;
 pub use types::*;
 
 /// Default auto-refresh interval in seconds
 const AUTO_REFRESH_INTERVAL_SECS: u64 = 10;
 
+/// How long a recovered/regressed marker stays next to a check, in seconds
+const CHANGE_MARKER_SECS: u64 = 8;
+
+/// Number of log lines fetched per container in the pod logs drill-down
+const POD_LOG_TAIL_LINES: i64 = 50;
+
 /// Data loaded asynchronously for the diagnostics component
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DiagnosticsData {
     /// Node hostname
     pub hostname: String,
@@ -62,6 +88,569 @@ pub struct DiagnosticsData {
     pub detected_addons: addons::DetectedAddons,
 }
 
+/// Order in which check categories are displayed
+const CATEGORY_ORDER: [CheckCategory; 5] = [
+    CheckCategory::System,
+    CheckCategory::Kubernetes,
+    CheckCategory::Cni,
+    CheckCategory::Services,
+    CheckCategory::Addons,
+];
+
+impl DiagnosticsData {
+    /// Checks in a category
+    pub fn checks(&self, category: CheckCategory) -> &[DiagnosticCheck] {
+        match category {
+            CheckCategory::System => &self.system_checks,
+            CheckCategory::Kubernetes => &self.kubernetes_checks,
+            CheckCategory::Services => &self.service_checks,
+            CheckCategory::Cni => &self.cni_checks,
+            CheckCategory::Addons => &self.addon_checks,
+        }
+    }
+}
+
+/// The entry of a per-node API response that belongs to this node
+///
+/// A client targeting several nodes gets one entry per node, reported by
+/// hostname (or by the requested address when the API omits it). A lone
+/// entry is this node's; with several and none matching, nothing is picked
+/// rather than attributing another node's values.
+fn entry_for_node<'a, T>(
+    entries: &'a [T],
+    node_of: impl Fn(&T) -> &str,
+    hostname: &str,
+    address: &str,
+) -> Option<&'a T> {
+    if let [only] = entries {
+        return Some(only);
+    }
+    let entry = entries.iter().find(|e| {
+        let node = node_of(e);
+        !node.is_empty() && (node == hostname || node == address)
+    });
+    if entry.is_none() && !entries.is_empty() {
+        tracing::warn!(
+            "No entry for {} ({}) among {} node responses",
+            hostname,
+            address,
+            entries.len()
+        );
+    }
+    entry
+}
+
+/// Run the full diagnostics pipeline for a single node
+///
+/// Detects the node context (platform, CPU count, CNI, pod health, addons)
+/// into `data.context` and then runs every check category not in `disabled`.
+/// Detection only needed by disabled categories is skipped, and disabled
+/// categories are left empty. For worker nodes, the kubeconfig is fetched
+/// from the first of `controlplane_endpoints` that serves it. Checks are only
+/// replaced if they all complete within the timeout.
+pub async fn run_diagnostics(
+    client: &TalosClient,
+    data: &mut DiagnosticsData,
+    controlplane_endpoints: &[String],
+    config_path: Option<&str>,
+    disabled: &BTreeSet<CheckCategory>,
+) -> Result<()> {
+    let timeout = std::time::Duration::from_secs(15);
+    let enabled = |category: CheckCategory| !disabled.contains(&category);
+
+    // Fetch platform info first
+    if let Ok(versions) = client.version().await
+        && let Some(v) = entry_for_node(&versions, |v| &v.node, &data.hostname, &data.address)
+    {
+        data.context.talos_version = v.version.clone();
+        data.context.platform = v.platform.clone();
+        data.context.is_container = v.platform == "container";
+        tracing::info!("Detected platform: {}", data.context.platform);
+    }
+
+    // Get CPU count for load threshold scaling
+    if let Ok(cpu_info) = client.cpu_info().await
+        && let Some(info) = entry_for_node(&cpu_info, |c| &c.node, &data.hostname, &data.address)
+    {
+        data.context.cpu_count = info.cpu_count.max(1);
+        tracing::info!("Detected {} CPUs", data.context.cpu_count);
+    }
+
+    // Boot time, kept across refreshes to spot reboot loops
+    if enabled(CheckCategory::System)
+        && let Ok(stats) = client.system_stat().await
+        && let Some(stat) = entry_for_node(&stats, |s| &s.node, &data.hostname, &data.address)
+        && core::record_boot_time(&mut data.context.boot_times, stat.boot_time)
+    {
+        tracing::warn!("Node {} rebooted since the last refresh", data.hostname);
+    }
+
+    // Only categories backed by the K8s API need a client
+    let needs_k8s = [
+        CheckCategory::Kubernetes,
+        CheckCategory::Cni,
+        CheckCategory::Addons,
+    ]
+    .into_iter()
+    .any(enabled);
+
+    // Try to create K8s client once for all K8s-based checks
+    // For worker nodes, fetch the kubeconfig through a control plane
+    let k8s_client = if !needs_k8s {
+        None
+    } else {
+        match k8s::create_k8s_client_via_controlplanes(client, controlplane_endpoints).await {
+            Ok((client, endpoint)) => {
+                tracing::info!("K8s client created successfully");
+                data.context.kubeconfig_endpoint = endpoint;
+                data.context.k8s_error = None;
+                Some(client)
+            }
+            Err(e) => {
+                let error_msg = format!("{}", e);
+                tracing::warn!(
+                    "Failed to create K8s client: {} - K8s-based checks will be limited",
+                    error_msg
+                );
+                data.context.kubeconfig_endpoint = None;
+                data.context.k8s_error = Some(error_msg);
+                None
+            }
+        }
+    };
+
+    // Detect CNI type (uses K8s API if available, falls back to file checks)
+    if enabled(CheckCategory::Cni) {
+        let (cni_type, cni_info) = cni::detect_cni_with_client(client, k8s_client.as_ref()).await;
+        data.context.cni_type = cni_type;
+        data.context.cni_info = cni_info;
+        tracing::info!("Detected CNI: {:?}", data.context.cni_type);
+    }
+
+    // Pod CIDRs of every node, to catch overlapping allocations
+    if let Some(ref kc) = k8s_client
+        && enabled(CheckCategory::Cni)
+    {
+        data.context.node_pod_cidrs = match k8s::list_node_pod_cidrs(kc).await {
+            Ok(nodes) => Some(nodes),
+            Err(e) => {
+                tracing::warn!("Failed to list node pod CIDRs via K8s API: {}", e);
+                None
+            }
+        };
+    }
+
+    // Get pod health from K8s API (reusing the same client)
+    if let Some(ref kc) = k8s_client
+        && enabled(CheckCategory::Kubernetes)
+    {
+        match k8s::check_pod_health(kc).await {
+            Ok(health) => {
+                // Convert k8s::PodHealthInfo to types::PodHealthInfo
+                let pod_health = PodHealthInfo {
+                    crashing: health
+                        .crashing
+                        .iter()
+                        .map(|p| UnhealthyPodInfo {
+                            name: p.name.clone(),
+                            namespace: p.namespace.clone(),
+                            container: p.container.clone(),
+                            state: p.state.clone(),
+                            restart_count: p.restart_count,
+                            message: p.message.clone(),
+                        })
+                        .collect(),
+                    image_pull_errors: health
+                        .image_pull_errors
+                        .iter()
+                        .map(|p| UnhealthyPodInfo {
+                            name: p.name.clone(),
+                            namespace: p.namespace.clone(),
+                            container: p.container.clone(),
+                            state: p.state.clone(),
+                            restart_count: p.restart_count,
+                            message: p.message.clone(),
+                        })
+                        .collect(),
+                    total_pods: health.total_pods,
+                };
+                data.context.pod_health = Some(pod_health);
+                tracing::info!("Pod health check complete: {} pods", health.total_pods);
+            }
+            Err(e) => {
+                tracing::warn!("Failed to check pod health via K8s API: {}", e);
+            }
+        }
+    }
+
+    // Unapproved kubelet serving certs break logs/exec and metrics-server
+    if let Some(ref kc) = k8s_client
+        && enabled(CheckCategory::Kubernetes)
+    {
+        data.context.pending_kubelet_csrs = match k8s::list_pending_kubelet_csrs(kc).await {
+            Ok(csrs) => Some(csrs),
+            Err(e) => {
+                tracing::warn!("Failed to list CSRs via K8s API: {}", e);
+                None
+            }
+        };
+    }
+
+    // Taints and labels of this node, for pods that won't schedule onto it
+    if let Some(ref kc) = k8s_client
+        && enabled(CheckCategory::Kubernetes)
+    {
+        data.context.node_scheduling = match k8s::get_node_scheduling(kc, &data.hostname).await {
+            Ok(info) => Some(info),
+            Err(e) => {
+                tracing::warn!("Failed to get node {} via K8s API: {}", data.hostname, e);
+                None
+            }
+        };
+    }
+
+    // Detect installed addons
+    if let Some(ref kc) = k8s_client
+        && enabled(CheckCategory::Addons)
+    {
+        data.detected_addons = addons::detect_addons(kc).await;
+    }
+
+    let context = &data.context;
+    let detected_addons = &data.detected_addons;
+
+    let result = tokio::time::timeout(timeout, async {
+        // Run core checks, with certificate checks as part of system health
+        let mut system_checks = Vec::new();
+        if enabled(CheckCategory::System) {
+            system_checks = core::run_system_checks(client, context).await;
+            let cert_checks = core::run_certificate_checks(client, context, config_path).await;
+            system_checks.extend(cert_checks);
+        }
+
+        let kubernetes_checks = if enabled(CheckCategory::Kubernetes) {
+            core::run_kubernetes_checks(client, context).await
+        } else {
+            Vec::new()
+        };
+
+        let service_checks = if enabled(CheckCategory::Services) {
+            core::run_service_checks(client, context).await
+        } else {
+            Vec::new()
+        };
+
+        // Run CNI-specific checks
+        let cni_checks = if enabled(CheckCategory::Cni) {
+            cni::run_cni_checks(client, context, k8s_client.as_ref()).await
+        } else {
+            Vec::new()
+        };
+
+        // Run addon-specific checks
+        let addon_checks = if enabled(CheckCategory::Addons) {
+            addons::run_addon_checks(k8s_client.as_ref(), detected_addons, context).await
+        } else {
+            Vec::new()
+        };
+
+        (
+            system_checks,
+            kubernetes_checks,
+            service_checks,
+            cni_checks,
+            addon_checks,
+        )
+    })
+    .await;
+
+    let (system, kubernetes, services, cni, addons_result) =
+        result.map_err(|_| color_eyre::eyre::eyre!("Timeout fetching diagnostics"))?;
+    data.system_checks = system;
+    data.kubernetes_checks = kubernetes;
+    data.service_checks = services;
+    data.cni_checks = cni;
+    data.addon_checks = addons_result;
+    Ok(())
+}
+
+/// Move the control plane endpoint that last served the kubeconfig to the
+/// front, so later fetches don't wait on a node that is down
+pub fn promote_endpoint(endpoints: &mut [String], working: Option<&str>) {
+    if let Some(pos) = working.and_then(|w| endpoints.iter().position(|e| e == w)) {
+        endpoints[..=pos].rotate_right(1);
+    }
+}
+
+/// `talosctl` command that fetches the kubeconfig the way diagnostics does
+///
+/// Arguments are shell-quoted so the command can be pasted as is.
+fn kubeconfig_command(target: &str, config_path: Option<&str>, context: Option<&str>) -> String {
+    let mut command = "talosctl".to_string();
+    if let Some(path) = config_path {
+        command.push_str(&format!(" --talosconfig {}", shell_quote(path)));
+    }
+    if let Some(context) = context {
+        command.push_str(&format!(" --context {}", shell_quote(context)));
+    }
+    command.push_str(&format!(" -n {} kubeconfig", shell_quote(target)));
+    command
+}
+
+/// Quote `arg` for a POSIX shell unless it is made only of safe characters
+fn shell_quote(arg: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "-_./=:@,+%".contains(c);
+    if !arg.is_empty() && arg.chars().all(safe) {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+/// Toast reporting the outcome of a clipboard copy
+fn copy_notification(result: Result<(), String>, success: String) -> Action {
+    match result {
+        Ok(()) => Action::Notify(success, NotifyLevel::Success),
+        Err(e) => Action::Notify(format!("Copy failed: {}", e), NotifyLevel::Error),
+    }
+}
+
+/// Find the position (category, check) of the next Fail/Warn check after
+/// `current` that isn't acknowledged, scanning all categories in order and
+/// wrapping around
+fn next_issue_position(
+    categories: &[&[DiagnosticCheck]],
+    current: (usize, usize),
+    forward: bool,
+    acknowledged: impl Fn(&DiagnosticCheck) -> bool,
+) -> Option<(usize, usize)> {
+    let positions: Vec<(usize, usize)> = categories
+        .iter()
+        .enumerate()
+        .flat_map(|(cat, checks)| (0..checks.len()).map(move |idx| (cat, idx)))
+        .collect();
+    if positions.is_empty() {
+        return None;
+    }
+
+    let is_issue = |&(cat, idx): &(usize, usize)| {
+        let check = &categories[cat][idx];
+        matches!(check.status, CheckStatus::Fail | CheckStatus::Warn) && !acknowledged(check)
+    };
+
+    // Start from the current position, or where it would be if it's gone
+    // (e.g. an empty category is selected)
+    let (start, exact) = match positions.binary_search(&current) {
+        Ok(i) => (i, true),
+        Err(i) => (i, false),
+    };
+    let len = positions.len();
+    (0..len)
+        .map(|step| {
+            if forward {
+                (start + step + usize::from(exact)) % len
+            } else {
+                (start + len * 2 - 1 - step) % len
+            }
+        })
+        .map(|i| positions[i])
+        .find(is_issue)
+}
+
+/// Checks whose status changed between two diagnostics runs, keyed by
+/// category and check id
+fn status_changes(
+    old: &DiagnosticsData,
+    new: &DiagnosticsData,
+) -> Vec<((CheckCategory, String), StatusChange)> {
+    CATEGORY_ORDER
+        .into_iter()
+        .flat_map(|category| {
+            new.checks(category).iter().filter_map(move |check| {
+                let previous = old.checks(category).iter().find(|c| c.id == check.id)?;
+                let change = StatusChange::between(&previous.status, &check.status)?;
+                Some(((category, check.id.clone()), change))
+            })
+        })
+        .collect()
+}
+
+/// How long to wait for the node's config when previewing a fix
+const CURRENT_CONFIG_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a drained node may take to come back Ready after the fix's reboot
+const DRAIN_REBOOT_READY_TIMEOUT_SECS: u64 = 600;
+
+/// A fix being applied in the background
+struct ApplyTask {
+    /// Description of the fix, shown while applying and in the result
+    description: String,
+    /// When the apply started
+    started: Instant,
+    /// Current step, set while draining around a reboot
+    progress: Arc<Mutex<Option<String>>>,
+    /// Background task handle
+    handle: JoinHandle<Result<Vec<ApplyConfigResult>, String>>,
+}
+
+/// Report the current step of a drain-and-reboot apply
+fn set_apply_progress(progress: &Arc<Mutex<Option<String>>>, message: String) {
+    if let Ok(mut p) = progress.lock() {
+        *p = Some(message);
+    }
+}
+
+/// Uncordon `hostname`, recording the outcome of an already started
+/// UNCORDON audit entry
+async fn finish_uncordon(kc: &kube::Client, hostname: &str) -> Result<(), String> {
+    let result = match k8s::uncordon_node(kc, hostname).await {
+        Ok(result) if result.success => Ok(()),
+        Ok(result) => Err(result.error.unwrap_or_default()),
+        Err(e) => Err(e.to_string()),
+    };
+    match &result {
+        Ok(()) => audit_success("UNCORDON", hostname, "Node uncordoned"),
+        Err(e) => audit_failure("UNCORDON", hostname, e),
+    }
+    result
+}
+
+/// Put a drained node back into service after a failed drain or apply
+async fn uncordon_after_failure(kc: &kube::Client, hostname: &str) {
+    audit_start(
+        "UNCORDON",
+        hostname,
+        "Restoring node after failed drain & reboot",
+    );
+    let _ = finish_uncordon(kc, hostname).await;
+}
+
+/// Cordon and drain `hostname` ahead of a fix that reboots it
+///
+/// Evictions respect PodDisruptionBudgets, retrying each pod up to the drain
+/// options' per-pod timeout. If the drain fails the node is uncordoned again
+/// and nothing is applied. Each step goes to the audit log.
+async fn drain_before_reboot(
+    client: &TalosClient,
+    controlplane_endpoints: &[String],
+    hostname: &str,
+    progress: &Arc<Mutex<Option<String>>>,
+) -> Result<kube::Client, String> {
+    audit_start("CORDON", hostname, "Cordoning before fix reboot");
+    set_apply_progress(progress, "Connecting to Kubernetes...".to_string());
+    let kc = match k8s::create_k8s_client_via_controlplanes(client, controlplane_endpoints).await {
+        Ok((kc, _)) => kc,
+        Err(e) => {
+            let msg = format!("Kubernetes unavailable, nothing applied: {}", e);
+            audit_failure("CORDON", hostname, &msg);
+            return Err(msg);
+        }
+    };
+
+    set_apply_progress(progress, format!("Cordoning {}...", hostname));
+    let cordon_error = match k8s::cordon_node(&kc, hostname).await {
+        Ok(result) if result.success => None,
+        Ok(result) => Some(result.error.unwrap_or_default()),
+        Err(e) => Some(e.to_string()),
+    };
+    if let Some(e) = cordon_error {
+        let msg = format!("Failed to cordon {}: {}", hostname, e);
+        audit_failure("CORDON", hostname, &msg);
+        return Err(msg);
+    }
+    audit_success("CORDON", hostname, "Node cordoned");
+
+    audit_start("DRAIN", hostname, "Draining before fix reboot");
+    set_apply_progress(progress, format!("Draining {}...", hostname));
+    let drain_progress = progress.clone();
+    let callback: k8s::DrainProgressCallback = Box::new(move |msg: &str| {
+        set_apply_progress(&drain_progress, format!("Draining: {}", msg));
+    });
+    let error = match k8s::drain_node_with_progress(
+        &kc,
+        hostname,
+        &k8s::DrainOptions::default(),
+        Some(callback),
+    )
+    .await
+    {
+        Ok(result) if result.success => {
+            audit_success(
+                "DRAIN",
+                hostname,
+                &format!("Evicted {} pods", result.pods_evicted),
+            );
+            return Ok(kc);
+        }
+        Ok(result) => format!("could not evict {}", result.failed_pods.join(", ")),
+        Err(e) => e.to_string(),
+    };
+    audit_failure("DRAIN", hostname, &error);
+
+    uncordon_after_failure(&kc, hostname).await;
+    Err(format!(
+        "Drain failed, node uncordoned and nothing applied: {}",
+        error
+    ))
+}
+
+/// Wait for a drained node to come back Ready after the reboot, then uncordon it
+///
+/// A node that doesn't come back is left cordoned.
+async fn uncordon_after_reboot(
+    kc: &kube::Client,
+    hostname: &str,
+    progress: &Arc<Mutex<Option<String>>>,
+) -> Result<(), String> {
+    audit_start("UNCORDON", hostname, "Waiting for node Ready after reboot");
+    set_apply_progress(
+        progress,
+        format!("Waiting for {} to come back...", hostname),
+    );
+    let ready_progress = progress.clone();
+    let callback: k8s::NodeReadyProgressCallback = Box::new(move |msg: &str| {
+        set_apply_progress(&ready_progress, msg.to_string());
+    });
+    let ready = k8s::wait_for_node_ready(
+        kc,
+        hostname,
+        DRAIN_REBOOT_READY_TIMEOUT_SECS,
+        true,
+        Some(callback),
+    )
+    .await
+    .map_err(|e| e.to_string())
+    .and_then(|result| {
+        if result.success {
+            Ok(())
+        } else {
+            Err(result.error.unwrap_or_else(|| "timed out".to_string()))
+        }
+    });
+    if let Err(e) = ready {
+        let msg = format!(
+            "Applied, but {} didn't become Ready (left cordoned): {}",
+            hostname, e
+        );
+        audit_failure("UNCORDON", hostname, &msg);
+        return Err(msg);
+    }
+
+    set_apply_progress(progress, format!("Uncordoning {}...", hostname));
+    finish_uncordon(kc, hostname)
+        .await
+        .map_err(|e| format!("Applied, but failed to uncordon {}: {}", hostname, e))
+}
+
+/// A loaded snapshot and which of its nodes is shown
+struct SnapshotView {
+    snapshot: DiagnosticsSnapshot,
+    /// File the snapshot was loaded from
+    path: PathBuf,
+    /// Index into `snapshot.nodes`
+    current: usize,
+}
+
 /// Diagnostics component for node health checks
 pub struct DiagnosticsComponent {
     /// Async state for loaded data
@@ -76,12 +665,13 @@ pub struct DiagnosticsComponent {
 
     /// Pending action (waiting for confirmation)
     pending_action: Option<PendingAction>,
+    /// Background fetch of the node's current value for the pending fix
+    current_value_task: Option<JoinHandle<Result<CurrentValue, String>>>,
     /// Whether we're in the confirmation dialog
     show_confirmation: bool,
-    /// Confirmation dialog selection (0 = Cancel, 1 = Apply; for host commands: 0 = Copy, 1 = Close)
+    /// Confirmation dialog selection (index into `FixAction::confirm_choices`, 0 = Cancel;
+    /// for host commands: 0 = Copy, 1 = Close)
     confirmation_selection: usize,
-    /// Time when command was copied (for showing feedback)
-    copy_feedback_until: Option<Instant>,
 
     /// Whether we're showing a details popup (for checks without fixes)
     show_details: bool,
@@ -90,18 +680,51 @@ pub struct DiagnosticsComponent {
     /// Content of the details popup
     details_content: String,
 
+    /// Pod logs drill-down for the pod health check (if showing)
+    pod_logs: Option<PodLogsView>,
+
+    /// Bug report preview (if showing)
+    bug_report: Option<report::BugReportView>,
+
+    /// Categories skipped and hidden (persisted in the state file)
+    disabled_categories: BTreeSet<CheckCategory>,
+    /// Talosconfig context of the node; acknowledgements are stored under
+    /// it (none for snapshots)
+    context: Option<String>,
+    /// Acknowledged check ids on this node and the status they were
+    /// acknowledged at
+    acknowledged: BTreeMap<String, CheckStatus>,
+    /// Cursor in the category toggle popup (if showing)
+    category_menu: Option<usize>,
+    /// Whether categories were toggled since the popup opened
+    categories_changed: bool,
+
     /// Whether we're applying a fix
     applying_fix: bool,
+    /// Background apply in progress
+    apply_task: Option<ApplyTask>,
+    /// Spinner frame for the apply progress indicator
+    spinner_frame: usize,
     /// Result of the last apply
     apply_result: Option<Result<Vec<ApplyConfigResult>, String>>,
 
     /// Auto-refresh enabled
     auto_refresh: bool,
+    /// Checks whose status changed on a recent refresh, and when
+    status_changes: HashMap<(CheckCategory, String), (StatusChange, Instant)>,
+
+    /// Node summary shown in the header
+    node_info: NodeInfo,
+
+    /// Loaded snapshot; the view is read-only while set
+    snapshot: Option<SnapshotView>,
+    /// Whether a run has finished (sections show "Loading..." until then)
+    checks_ran: bool,
 
     /// Client for API calls
     client: Option<TalosClient>,
-    /// Control plane endpoint for fetching kubeconfig (used for worker nodes)
-    controlplane_endpoint: Option<String>,
+    /// Control plane endpoints for fetching kubeconfig, tried in order (worker nodes)
+    controlplane_endpoints: Vec<String>,
     /// Custom config file path (from --config flag)
     config_path: Option<String>,
 }
@@ -119,6 +742,7 @@ impl DiagnosticsComponent {
         node_role: String,
         config_path: Option<String>,
     ) -> Self {
+        let node_info = NodeInfo::new(hostname.clone(), address.clone());
         let mut table_state = TableState::default();
         table_state.select(Some(0));
 
@@ -140,17 +764,30 @@ impl DiagnosticsComponent {
             selected_check: 0,
             table_state,
             pending_action: None,
+            current_value_task: None,
             show_confirmation: false,
             confirmation_selection: 1,
-            copy_feedback_until: None,
             show_details: false,
             details_title: String::new(),
             details_content: String::new(),
+            pod_logs: None,
+            bug_report: None,
+            disabled_categories: UiState::load().disabled_diagnostics,
+            context: None,
+            acknowledged: BTreeMap::new(),
+            category_menu: None,
+            categories_changed: false,
             applying_fix: false,
+            apply_task: None,
+            spinner_frame: 0,
             apply_result: None,
             auto_refresh: true,
+            status_changes: HashMap::new(),
+            node_info,
+            snapshot: None,
+            checks_ran: false,
             client: None,
-            controlplane_endpoint: None,
+            controlplane_endpoints: Vec::new(),
             config_path,
         }
     }
@@ -165,34 +802,271 @@ impl DiagnosticsComponent {
         self.state.data_mut()
     }
 
+    /// Set the node summary shown in the header
+    pub fn set_node_info(&mut self, info: NodeInfo) {
+        self.node_info = info;
+    }
+
+    /// Set the context whose acknowledged checks for this node apply
+    pub fn set_context(&mut self, context: Option<String>) {
+        self.acknowledged = context
+            .as_deref()
+            .map(|ctx| UiState::load().acknowledged_for(ctx, self.hostname()))
+            .unwrap_or_default();
+        self.context = context;
+    }
+
+    /// Hostname of the diagnosed node
+    fn hostname(&self) -> &str {
+        self.data().map(|d| d.hostname.as_str()).unwrap_or_default()
+    }
+
+    /// Seed the boot history with what the overview saw before this view
+    /// opened, so reboot loops show up on the first run
+    pub fn set_boot_history(&mut self, boot_times: Vec<u64>) {
+        if let Some(data) = self.data_mut() {
+            data.context.boot_times = boot_times;
+        }
+    }
+
+    /// Node this view shows
+    pub fn node_info(&self) -> &NodeInfo {
+        &self.node_info
+    }
+
+    /// Whether the view shows a loaded snapshot rather than live results
+    pub fn is_snapshot(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    /// Save the results to `path`, or next to bug reports in the temp dir
+    ///
+    /// A loaded snapshot is saved again as-is, with all its nodes. Returns
+    /// the path written.
+    pub fn save_snapshot(&self, path: Option<&Path>) -> Result<PathBuf> {
+        let snapshot = match &self.snapshot {
+            Some(view) => view.snapshot.clone(),
+            None => {
+                let data = self
+                    .data()
+                    .filter(|_| self.state.last_refresh().is_some())
+                    .ok_or_else(|| color_eyre::eyre::eyre!("No diagnostics results to save"))?;
+                DiagnosticsSnapshot::new(vec![data.clone()])
+            }
+        };
+        let path = path
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| snapshot.default_path());
+        snapshot.save(&path)?;
+        Ok(path)
+    }
+
+    /// Show the snapshot saved at `path` instead of live results
+    ///
+    /// The view becomes read-only: refreshing, fixes and anything else that
+    /// needs the node are disabled, and `[`/`]` step through the nodes.
+    pub fn load_snapshot(&mut self, path: &Path) -> Result<()> {
+        let snapshot = DiagnosticsSnapshot::load(path)?;
+        self.client = None;
+        self.auto_refresh = false;
+        self.status_changes.clear();
+        self.snapshot = Some(SnapshotView {
+            snapshot,
+            path: path.to_path_buf(),
+            current: 0,
+        });
+        self.show_snapshot_node(0);
+        Ok(())
+    }
+
+    /// Show the snapshot's node at `idx`
+    fn show_snapshot_node(&mut self, idx: usize) {
+        let Some(view) = &mut self.snapshot else {
+            return;
+        };
+        let Some(data) = view.snapshot.nodes.get(idx).cloned() else {
+            return;
+        };
+        view.current = idx;
+
+        let ctx = &data.context;
+        let non_empty = |s: &str| (!s.is_empty()).then(|| s.to_string());
+        self.node_info = NodeInfo {
+            role: non_empty(&ctx.node_role),
+            version: non_empty(&ctx.talos_version),
+            platform: non_empty(&ctx.platform),
+            ..NodeInfo::new(data.hostname.clone(), data.address.clone())
+        };
+        self.state.set_data(data);
+        self.checks_ran = true;
+        self.ensure_valid_selection();
+    }
+
+    /// Step to the next or previous node of a loaded snapshot
+    fn cycle_snapshot_node(&mut self, forward: bool) {
+        let Some(view) = &self.snapshot else {
+            return;
+        };
+        let count = view.snapshot.nodes.len();
+        let idx = if forward {
+            (view.current + 1) % count
+        } else {
+            (view.current + count - 1) % count
+        };
+        self.show_snapshot_node(idx);
+    }
+
+    /// Save a snapshot to the temp dir and report where
+    fn save_snapshot_to_temp(&self) -> Action {
+        match self.save_snapshot(None) {
+            Ok(path) => Action::Notify(
+                format!("Saved snapshot to {}", path.display()),
+                NotifyLevel::Success,
+            ),
+            Err(e) => Action::Notify(format!("Save failed: {}", e), NotifyLevel::Error),
+        }
+    }
+
     /// Set the client for making API calls
     pub fn set_client(&mut self, client: TalosClient) {
         self.client = Some(client);
     }
 
-    /// Set the control plane endpoint for fetching kubeconfig (used for worker nodes)
-    pub fn set_controlplane_endpoint(&mut self, endpoint: Option<String>) {
-        self.controlplane_endpoint = endpoint;
+    /// Set the control plane endpoints for fetching kubeconfig (used for worker nodes)
+    pub fn set_controlplane_endpoints(&mut self, endpoints: Vec<String>) {
+        self.controlplane_endpoints = endpoints;
     }
 
     /// Set an error message
-    pub fn set_error(&mut self, error: String) {
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
         self.state.set_error(error);
     }
 
+    /// Client for the diagnosed node
+    pub fn client(&self) -> Option<&TalosClient> {
+        self.client.as_ref()
+    }
+
+    /// Control plane endpoints used for fetching kubeconfig (worker nodes),
+    /// the one that last worked first
+    pub fn controlplane_endpoints(&self) -> &[String] {
+        &self.controlplane_endpoints
+    }
+
+    /// Error from the last attempt to create a K8s client, if any
+    pub fn k8s_error(&self) -> Option<&str> {
+        self.data().and_then(|d| d.context.k8s_error.as_deref())
+    }
+
+    /// Copy the `talosctl kubeconfig` command diagnostics uses to the clipboard
+    ///
+    /// Worker nodes fetch the kubeconfig through the control plane, so the
+    /// command targets that endpoint rather than the diagnosed node.
+    fn copy_kubeconfig_command(&self) -> Option<Action> {
+        let target = match (self.controlplane_endpoints.first(), self.data()) {
+            (Some(endpoint), _) => endpoint.clone(),
+            (None, Some(data)) => data.address.clone(),
+            (None, None) => return None,
+        };
+        let command = kubeconfig_command(
+            &target,
+            self.config_path.as_deref(),
+            self.context.as_deref(),
+        );
+        Some(copy_notification(
+            crate::clipboard::copy_to_clipboard(command.clone()),
+            format!("Copied: {}", command),
+        ))
+    }
+
+    /// Copy the open details popup, or else the selected check's details
+    fn copy_check_details(&self) -> Option<Action> {
+        let (text, label) = if self.show_details {
+            (
+                Some(self.details_content.clone()),
+                self.details_title.clone(),
+            )
+        } else {
+            let check = self.selected_check()?;
+            (check.details.clone(), check.name.clone())
+        };
+        let Some(text) = text else {
+            return Some(Action::Notify(
+                format!("{} has no details", label),
+                NotifyLevel::Info,
+            ));
+        };
+        Some(copy_notification(
+            crate::clipboard::copy_to_clipboard(text),
+            format!("Copied details of {}", label),
+        ))
+    }
+
     /// Get all checks in the current category
     fn current_checks(&self) -> &[DiagnosticCheck] {
+        self.category_checks(self.selected_category)
+    }
+
+    /// Enabled categories in display order
+    fn enabled_categories(&self) -> impl Iterator<Item = CheckCategory> + '_ {
+        CATEGORY_ORDER
+            .into_iter()
+            .filter(|c| !self.disabled_categories.contains(c))
+    }
+
+    /// Enabled categories that have checks to show, in display order
+    ///
+    /// Empty sections (e.g. CNI when the K8s API is unreachable, or Addons
+    /// when none are detected) are skipped in both drawing and navigation.
+    fn visible_categories(&self) -> Vec<CheckCategory> {
         let Some(data) = self.data() else {
-            return &[];
+            return Vec::new();
         };
-        match self.selected_category {
-            0 => &data.system_checks,
-            1 => &data.kubernetes_checks,
-            2 => &data.cni_checks,
-            3 => &data.service_checks,
-            4 => &data.addon_checks,
-            _ => &[],
+        let any_addons = data.detected_addons.any_detected();
+        let enabled: Vec<CheckCategory> = self
+            .enabled_categories()
+            .filter(|&c| c != CheckCategory::Addons || any_addons)
+            .collect();
+        // Until the first run finishes every section is empty and shows "Loading..."
+        if enabled.iter().all(|&c| data.checks(c).is_empty()) {
+            return enabled;
+        }
+        enabled
+            .into_iter()
+            .filter(|&c| !data.checks(c).is_empty())
+            .collect()
+    }
+
+    /// Why there are no checks to show, once that is known
+    ///
+    /// `None` while there are checks, or until the first run finishes.
+    fn no_checks_reason(&self) -> Option<String> {
+        let data = self.data()?;
+        if self.enabled_categories().next().is_none() {
+            return Some("All check categories are disabled - press C to enable some".to_string());
+        }
+        if !self.checks_ran
+            || CATEGORY_ORDER
+                .iter()
+                .any(|&category| !data.checks(category).is_empty())
+        {
+            return None;
         }
+        Some(match &data.context.k8s_error {
+            Some(e) => format!("K8s API unavailable: {}", e),
+            None => "The last run returned no results - press r to retry".to_string(),
+        })
+    }
+
+    /// Get all checks in a category (by position among visible categories)
+    fn category_checks(&self, idx: usize) -> &[DiagnosticCheck] {
+        let Some(data) = self.data() else {
+            return &[];
+        };
+        self.visible_categories()
+            .get(idx)
+            .map(|&category| data.checks(category))
+            .unwrap_or(&[])
     }
 
     /// Get the currently selected check
@@ -200,22 +1074,91 @@ impl DiagnosticsComponent {
         self.current_checks().get(self.selected_check)
     }
 
-    /// Get total number of categories
+    /// Whether a check was acknowledged at its current status
+    ///
+    /// An acknowledgement only covers the status it was made at, so a check
+    /// that gets worse (or changes at all) shows up as a problem again.
+    fn is_acknowledged(&self, check: &DiagnosticCheck) -> bool {
+        self.acknowledged.get(&check.id) == Some(&check.status)
+    }
+
+    /// Acknowledge the selected warning or failure, or take the
+    /// acknowledgement back, and persist it for the context and node
+    fn toggle_acknowledged(&mut self) -> Option<Action> {
+        let check = self.selected_check()?;
+        let (message, level) = if check.status == CheckStatus::Pass {
+            (
+                format!("{} is passing, nothing to acknowledge", check.name),
+                NotifyLevel::Info,
+            )
+        } else if let Some(context) = &self.context {
+            let (id, name, status) = (check.id.clone(), check.name.clone(), check.status.clone());
+            // Reload first so state saved by other views isn't overwritten
+            let hostname = self.hostname();
+            let mut ui_state = UiState::load();
+            let acknowledged = ui_state.toggle_acknowledged(context, hostname, &id, &status);
+            ui_state.save();
+            self.acknowledged = ui_state.acknowledged_for(context, hostname);
+            if acknowledged {
+                (format!("Acknowledged {}", name), NotifyLevel::Success)
+            } else {
+                (
+                    format!("{} is no longer acknowledged", name),
+                    NotifyLevel::Info,
+                )
+            }
+        } else {
+            (
+                "Acknowledging checks needs a live cluster context".to_string(),
+                NotifyLevel::Warning,
+            )
+        };
+        Some(Action::Notify(message, level))
+    }
+
+    /// Get number of visible categories
     fn category_count(&self) -> usize {
-        5 // System, Kubernetes, CNI, Services, Addons
+        self.visible_categories().len()
+    }
+
+    /// Toggle the category under the popup cursor and persist the choice
+    ///
+    /// The last enabled category can't be disabled.
+    fn toggle_category(&mut self, category: CheckCategory) {
+        if !self.disabled_categories.contains(&category) && self.enabled_categories().count() == 1 {
+            return;
+        }
+
+        // Reload first so state saved by other views isn't overwritten
+        let mut ui_state = UiState::load();
+        ui_state.disabled_diagnostics = self.disabled_categories.clone();
+        ui_state.toggle_diagnostic_category(category);
+        ui_state.save();
+        self.disabled_categories = ui_state.disabled_diagnostics;
+        self.categories_changed = true;
+
+        self.ensure_valid_selection();
     }
 
     /// Select next category
     fn next_category(&mut self) {
-        self.selected_category = (self.selected_category + 1) % self.category_count();
+        let count = self.category_count();
+        if count == 0 {
+            return;
+        }
+        self.selected_category = (self.selected_category + 1) % count;
         self.selected_check = 0;
         self.update_table_state();
     }
 
     /// Select previous category
     fn prev_category(&mut self) {
+        let count = self.category_count();
+        if count == 0 {
+            return;
+        }
         self.selected_category = if self.selected_category == 0 {
-            self.category_count() - 1
+            count - 1
         } else {
             self.selected_category - 1
         };
@@ -230,30 +1173,228 @@ impl DiagnosticsComponent {
             self.selected_check += 1;
             self.update_table_state();
         }
-    }
+    }
+
+    /// Select previous check in current category (clamps at start, no wrapping)
+    fn prev_check(&mut self) {
+        if self.selected_check > 0 {
+            self.selected_check -= 1;
+            self.update_table_state();
+        }
+    }
+
+    /// Jump to the next (or previous) Fail/Warn check, crossing categories
+    /// and wrapping around
+    fn jump_to_issue(&mut self, forward: bool) {
+        let categories: Vec<&[DiagnosticCheck]> = (0..self.category_count())
+            .map(|idx| self.category_checks(idx))
+            .collect();
+        if let Some((category, check)) = next_issue_position(
+            &categories,
+            (self.selected_category, self.selected_check),
+            forward,
+            |check| self.is_acknowledged(check),
+        ) {
+            self.selected_category = category;
+            self.selected_check = check;
+            self.update_table_state();
+        }
+    }
+
+    /// Update table state to match selection
+    fn update_table_state(&mut self) {
+        self.table_state.select(Some(self.selected_check));
+    }
+
+    /// Ensure selected_check is within bounds for current category
+    fn ensure_valid_selection(&mut self) {
+        // Categories come and go as they gain or lose checks
+        self.selected_category = self
+            .selected_category
+            .min(self.category_count().saturating_sub(1));
+        let count = self.current_checks().len();
+        if count == 0 {
+            self.selected_check = 0;
+        } else if self.selected_check >= count {
+            self.selected_check = count - 1;
+        }
+        self.update_table_state();
+    }
+
+    /// Markdown bug report for the current results
+    fn bug_report_text(&self, redact: bool) -> Option<String> {
+        self.data()
+            .map(|data| report::format_bug_report(data, &self.disabled_categories, redact))
+    }
+
+    /// Copy the bug report to the clipboard
+    fn copy_bug_report(&mut self) {
+        let Some(view) = &self.bug_report else {
+            return;
+        };
+        let Some(text) = self.bug_report_text(view.redact) else {
+            return;
+        };
+        let status = match crate::clipboard::copy_to_clipboard(text) {
+            Ok(()) => "Copied bug report to clipboard".to_string(),
+            Err(e) => format!("Copy failed: {}", e),
+        };
+        if let Some(view) = &mut self.bug_report {
+            view.status = Some(status);
+        }
+    }
+
+    /// Write the bug report to a markdown file in the temp directory
+    fn save_bug_report(&mut self) {
+        let Some(view) = &self.bug_report else {
+            return;
+        };
+        let Some(text) = self.bug_report_text(view.redact) else {
+            return;
+        };
+        let host = if view.redact {
+            "node".to_string()
+        } else {
+            self.data().map(|d| d.hostname.clone()).unwrap_or_default()
+        };
+
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let filename = format!("talos-pilot-bugreport-{}_{}.md", host, timestamp);
+        let path = std::env::temp_dir().join(&filename);
+
+        let status = match std::fs::write(&path, text) {
+            Ok(_) => format!("Saved bug report to {}", path.display()),
+            Err(e) => format!("Save failed: {}", e),
+        };
+        if let Some(view) = &mut self.bug_report {
+            view.status = Some(status);
+        }
+    }
+
+    /// Open the pod logs drill-down if the selected check is the pod health
+    /// check and it reported unhealthy pods
+    fn open_pod_logs(&mut self) -> bool {
+        if self.selected_check().map(|c| c.id.as_str()) != Some("pod_health") {
+            return false;
+        }
+        let Some(health) = self.data().and_then(|d| d.context.pod_health.as_ref()) else {
+            return false;
+        };
+        if !health.has_issues() {
+            return false;
+        }
+
+        let pods = health
+            .crashing
+            .iter()
+            .chain(health.image_pull_errors.iter())
+            .cloned()
+            .collect();
+        self.pod_logs = Some(PodLogsView {
+            pods,
+            ..Default::default()
+        });
+        true
+    }
+
+    /// Select another pod in the logs drill-down, wrapping around
+    fn cycle_pod_logs(&mut self, forward: bool) -> Option<Action> {
+        let view = self.pod_logs.as_mut()?;
+        let count = view.pods.len();
+        if count < 2 {
+            return None;
+        }
+        view.selected = if forward {
+            (view.selected + 1) % count
+        } else {
+            (view.selected + count - 1) % count
+        };
+        view.logs.clear();
+        view.scroll = 0;
+        Some(Action::LoadDiagnosticPodLogs)
+    }
+
+    /// Fetch logs for the pod selected in the logs drill-down
+    ///
+    /// For CrashLoopBackOff pods the previous container's logs are shown
+    /// first, since that is the instance that actually crashed. A container
+    /// whose image can't be pulled never started and has no logs, so its
+    /// pull error is shown instead.
+    pub async fn load_pod_logs(&mut self) -> Result<()> {
+        let Some(view) = &mut self.pod_logs else {
+            return Ok(());
+        };
+        let Some(pod) = view.selected_pod().cloned() else {
+            return Ok(());
+        };
+        if pod.is_image_pull_error() {
+            let mut lines = vec!["── image pull error ──".to_string()];
+            match &pod.message {
+                Some(message) => lines.extend(message.lines().map(String::from)),
+                None => lines.push("(no error reported by the kubelet)".to_string()),
+            }
+            view.logs.set_data(lines);
+            return Ok(());
+        }
 
-    /// Select previous check in current category (clamps at start, no wrapping)
-    fn prev_check(&mut self) {
-        if self.selected_check > 0 {
-            self.selected_check -= 1;
-            self.update_table_state();
-        }
-    }
+        let Some(client) = self.client.clone() else {
+            return Ok(());
+        };
+        view.logs.start_loading();
+        let controlplane_endpoints = self.controlplane_endpoints.clone();
+
+        let result = async {
+            let (kc, _) =
+                k8s::create_k8s_client_via_controlplanes(&client, &controlplane_endpoints).await?;
+            let mut lines = Vec::new();
+
+            if pod.state == "CrashLoopBackOff" {
+                lines.push("── previous container ──".to_string());
+                match k8s::fetch_pod_logs(
+                    &kc,
+                    &pod.namespace,
+                    &pod.name,
+                    &pod.container,
+                    POD_LOG_TAIL_LINES,
+                    true,
+                )
+                .await
+                {
+                    Ok(logs) => lines.extend(logs.lines().map(String::from)),
+                    Err(e) => lines.push(format!("(unavailable: {})", e)),
+                }
+                lines.push(String::new());
+                lines.push("── current container ──".to_string());
+            }
 
-    /// Update table state to match selection
-    fn update_table_state(&mut self) {
-        self.table_state.select(Some(self.selected_check));
-    }
+            let logs = k8s::fetch_pod_logs(
+                &kc,
+                &pod.namespace,
+                &pod.name,
+                &pod.container,
+                POD_LOG_TAIL_LINES,
+                false,
+            )
+            .await?;
+            if logs.is_empty() {
+                lines.push("(no log output)".to_string());
+            } else {
+                lines.extend(logs.lines().map(String::from));
+            }
+            Ok::<_, k8s::K8sError>(lines)
+        }
+        .await;
 
-    /// Ensure selected_check is within bounds for current category
-    fn ensure_valid_selection(&mut self) {
-        let count = self.current_checks().len();
-        if count == 0 {
-            self.selected_check = 0;
-        } else if self.selected_check >= count {
-            self.selected_check = count - 1;
+        if let Some(view) = &mut self.pod_logs {
+            match result {
+                Ok(lines) => view.logs.set_data(lines),
+                Err(e) => view.logs.set_error(e.to_string()),
+            }
         }
-        self.update_table_state();
+        Ok(())
     }
 
     /// Initiate a fix action or show details for the currently selected check
@@ -269,6 +1410,18 @@ impl DiagnosticsComponent {
         });
 
         if let Some((check_id, check_name, fix_opt, details_opt)) = check_info {
+            // A snapshot has no node to apply to, so only describe the fix
+            if self.snapshot.is_some() {
+                let fix_line = fix_opt.map(|fix| format!("Fix: {}", fix.description));
+                let content: Vec<String> = details_opt.into_iter().chain(fix_line).collect();
+                if !content.is_empty() {
+                    self.details_title = check_name;
+                    self.details_content = content.join("\n\n");
+                    self.show_details = true;
+                }
+                return;
+            }
+
             if let Some(fix) = fix_opt {
                 // Has a fix - show confirmation dialog
                 let preview = match &fix.action {
@@ -282,14 +1435,29 @@ impl DiagnosticsComponent {
 
                 let is_host_cmd = fix.action.is_host_command();
 
+                // Show what the node has now next to the change
+                if let Some(task) = self.current_value_task.take() {
+                    task.abort();
+                }
+                let current = match (&preview, &self.client) {
+                    (Some(patch), Some(client)) => {
+                        self.current_value_task =
+                            Some(Self::spawn_current_value(client.clone(), patch.clone()));
+                        None
+                    }
+                    (Some(_), None) => Some(Err("No client configured".to_string())),
+                    (None, _) => None,
+                };
+
                 self.pending_action = Some(PendingAction {
                     check_id,
+                    choice: fix.action.confirm_choices()[1],
                     fix,
                     preview,
+                    current,
                 });
                 self.show_confirmation = true;
                 self.confirmation_selection = if is_host_cmd { 0 } else { 1 };
-                self.copy_feedback_until = None;
             } else if let Some(details) = details_opt {
                 // No fix but has details - show details popup
                 self.details_title = check_name;
@@ -299,7 +1467,46 @@ impl DiagnosticsComponent {
         }
     }
 
-    /// Apply the pending fix action
+    /// Read the node's machine config and compare it against `patch`
+    fn spawn_current_value(
+        client: TalosClient,
+        patch: String,
+    ) -> JoinHandle<Result<CurrentValue, String>> {
+        tokio::spawn(async move {
+            let config = tokio::time::timeout(
+                CURRENT_CONFIG_TIMEOUT,
+                client.read_file(MACHINE_CONFIG_PATH),
+            )
+            .await
+            .map_err(|_| "Timed out reading machine config".to_string())?
+            .map_err(|e| format_talos_error(&e))?;
+            preview::current_value(&config, &patch)
+        })
+    }
+
+    /// Hand a finished current-value fetch to the pending action
+    fn poll_current_value(&mut self) {
+        let Some(task) = &self.current_value_task else {
+            return;
+        };
+        if !task.is_finished() {
+            return;
+        }
+        let task = self.current_value_task.take().unwrap();
+        // Won't block since is_finished() was true
+        let result = match futures::executor::block_on(task) {
+            Ok(result) => result,
+            Err(e) => Err(format!("Task error: {}", e)),
+        };
+        if let Some(pending) = &mut self.pending_action {
+            pending.current = Some(result);
+        }
+    }
+
+    /// Start applying the pending fix action in a background task
+    ///
+    /// Completion is picked up by `poll_apply` on each Tick, so the UI keeps
+    /// drawing progress while long applies (or reboots) run.
     pub async fn apply_pending_fix(&mut self) -> Result<()> {
         tracing::info!("apply_pending_fix called");
 
@@ -308,7 +1515,7 @@ impl DiagnosticsComponent {
             return Ok(());
         };
 
-        let Some(client) = &self.client else {
+        let Some(client) = self.client.clone() else {
             tracing::error!("No client configured");
             self.set_error("No client configured".to_string());
             return Ok(());
@@ -316,93 +1523,195 @@ impl DiagnosticsComponent {
 
         // Get address for talosctl commands
         let address = self.data().map(|d| d.address.clone()).unwrap_or_default();
+        let hostname = self.data().map(|d| d.hostname.clone()).unwrap_or_default();
+        let controlplane_endpoints = self.controlplane_endpoints.clone();
 
-        self.applying_fix = true;
         self.show_confirmation = false;
 
-        match &pending.fix.action {
-            FixAction::AddKernelModule(name) => {
-                tracing::info!("Applying kernel module fix: {}", name);
-                let patch_yaml =
-                    format!("machine:\n  kernel:\n    modules:\n      - name: {}", name);
+        let description = pending.fix.description.clone();
+        let audit_details = format!("Applying fix: {}", description);
+        let progress = Arc::new(Mutex::new(None));
+        let task_progress = progress.clone();
+        let task = tokio::spawn(async move {
+            let drained = if pending.choice == ConfirmChoice::DrainAndReboot {
+                Some(
+                    drain_before_reboot(
+                        &client,
+                        &controlplane_endpoints,
+                        &hostname,
+                        &task_progress,
+                    )
+                    .await?,
+                )
+            } else {
+                None
+            };
+            if drained.is_some() {
+                audit_start("REBOOT", &hostname, &audit_details);
+                set_apply_progress(&task_progress, "Applying and rebooting...".to_string());
+            }
+
+            let result = async {
+                match &pending.fix.action {
+                    FixAction::AddKernelModule(name) => {
+                        tracing::info!("Applying kernel module fix: {}", name);
+                        let patch_yaml =
+                            format!("machine:\n  kernel:\n    modules:\n      - name: {}", name);
 
-                let patch_file = "/tmp/talos-pilot-patch.yaml";
-                if let Err(e) = std::fs::write(patch_file, &patch_yaml) {
-                    tracing::error!("Failed to write patch file: {}", e);
-                    self.apply_result = Some(Err(format!("Failed to write patch file: {}", e)));
-                } else {
-                    let output = std::process::Command::new("talosctl")
-                        .args([
-                            "-n",
-                            &address,
-                            "patch",
-                            "machineconfig",
-                            "--mode=reboot",
-                            "-p",
-                            &format!("@{}", patch_file),
-                        ])
-                        .output();
-
-                    match output {
-                        Ok(result) => {
-                            if result.status.success() {
+                        let mode_arg = if pending.choice == ConfirmChoice::Stage {
+                            "--mode=staged"
+                        } else {
+                            "--mode=reboot"
+                        };
+                        let patch_file = "/tmp/talos-pilot-patch.yaml";
+                        if let Err(e) = std::fs::write(patch_file, &patch_yaml) {
+                            tracing::error!("Failed to write patch file: {}", e);
+                            return Err(format!("Failed to write patch file: {}", e));
+                        }
+
+                        let output = tokio::process::Command::new("talosctl")
+                            .envs(talosctl_env())
+                            .args([
+                                "-n",
+                                &address,
+                                "patch",
+                                "machineconfig",
+                                mode_arg,
+                                "-p",
+                                &format!("@{}", patch_file),
+                            ])
+                            .output()
+                            .await;
+                        let _ = std::fs::remove_file(patch_file);
+
+                        match output {
+                            Ok(result) if result.status.success() => {
                                 let stdout = String::from_utf8_lossy(&result.stdout);
                                 tracing::info!("Patch succeeded: {}", stdout);
-                                self.apply_result = Some(Ok(vec![]));
-                            } else {
+                                Ok(vec![])
+                            }
+                            Ok(result) => {
                                 let stderr = String::from_utf8_lossy(&result.stderr);
                                 tracing::error!("Patch failed: {}", stderr);
-                                self.apply_result = Some(Err(stderr.to_string()));
+                                Err(stderr.to_string())
+                            }
+                            Err(e) => {
+                                tracing::error!("Failed to run talosctl: {}", e);
+                                Err(format!("Failed to run talosctl: {}", e))
                             }
-                        }
-                        Err(e) => {
-                            tracing::error!("Failed to run talosctl: {}", e);
-                            self.apply_result = Some(Err(format!("Failed to run talosctl: {}", e)));
                         }
                     }
-
-                    let _ = std::fs::remove_file(patch_file);
+                    FixAction::ApplyConfigPatch {
+                        yaml,
+                        requires_reboot,
+                    } => {
+                        let fallback = if *requires_reboot {
+                            ApplyMode::Reboot
+                        } else {
+                            ApplyMode::Auto
+                        };
+                        let mode = pending.choice.apply_mode().unwrap_or(fallback);
+                        client
+                            .apply_configuration(yaml, mode, false)
+                            .await
+                            .map_err(|e| e.to_string())
+                    }
+                    FixAction::RestartService(service) => client
+                        .service_restart(service)
+                        .await
+                        .map(|_| vec![])
+                        .map_err(|e| e.to_string()),
+                    FixAction::ShowDetails(_)
+                    | FixAction::InstallCilium
+                    | FixAction::HostCommand { .. } => {
+                        // These don't apply directly
+                        Ok(vec![])
+                    }
                 }
             }
-            FixAction::ApplyConfigPatch {
-                yaml,
-                requires_reboot,
-            } => {
-                let mode = if *requires_reboot {
-                    ApplyMode::Reboot
-                } else {
-                    ApplyMode::Auto
-                };
-                match client.apply_configuration(yaml, mode, false).await {
-                    Ok(results) => {
-                        self.apply_result = Some(Ok(results));
+            .await;
+
+            if let Some(kc) = drained {
+                match &result {
+                    Ok(_) => {
+                        audit_success("REBOOT", &hostname, &audit_details);
+                        uncordon_after_reboot(&kc, &hostname, &task_progress).await?
                     }
                     Err(e) => {
-                        self.apply_result = Some(Err(e.to_string()));
+                        audit_failure("REBOOT", &hostname, e);
+                        uncordon_after_failure(&kc, &hostname).await;
                     }
                 }
             }
-            FixAction::RestartService(service) => match client.service_restart(service).await {
-                Ok(_) => {
-                    self.apply_result = Some(Ok(vec![]));
-                }
-                Err(e) => {
-                    self.apply_result = Some(Err(e.to_string()));
+            result
+        });
+
+        self.apply_task = Some(ApplyTask {
+            description,
+            started: Instant::now(),
+            progress,
+            handle: task,
+        });
+        self.applying_fix = true;
+        Ok(())
+    }
+
+    /// Poll the background apply and show its result once finished
+    ///
+    /// Returns true when the apply completed on this call.
+    fn poll_apply(&mut self) -> bool {
+        let Some(task) = &self.apply_task else {
+            return false;
+        };
+        if !task.handle.is_finished() {
+            return false;
+        }
+
+        let task = self.apply_task.take().unwrap();
+        let elapsed = task.started.elapsed().as_secs();
+
+        // Won't block since is_finished() was true
+        let result = match futures::executor::block_on(task.handle) {
+            Ok(result) => result,
+            Err(e) => Err(format!("Task error: {}", e)),
+        };
+
+        self.details_content = match &result {
+            Ok(results) if results.is_empty() => {
+                format!("{}\n\nCompleted in {}s.", task.description, elapsed)
+            }
+            Ok(results) => {
+                let mut content = format!("{}\n", task.description);
+                for r in results {
+                    content.push_str(&format!("\n{}: {}", r.node, r.mode_result));
+                    for warning in &r.warnings {
+                        content.push_str(&format!("\n  warning: {}", warning));
+                    }
                 }
-            },
-            FixAction::ShowDetails(_)
-            | FixAction::InstallCilium
-            | FixAction::HostCommand { .. } => {
-                // These don't apply directly
+                content.push_str(&format!("\n\nCompleted in {}s.", elapsed));
+                content
             }
-        }
+            Err(e) => format!("{}\n\nFailed after {}s:\n{}", task.description, elapsed, e),
+        };
+        self.details_title = if result.is_ok() {
+            "Fix Applied".to_string()
+        } else {
+            "Fix Failed".to_string()
+        };
+        self.show_details = true;
 
+        self.apply_result = Some(result);
         self.applying_fix = false;
-        Ok(())
+        true
     }
 
     /// Refresh diagnostics data from the node
     pub async fn refresh(&mut self) -> Result<()> {
+        // Snapshot results are fixed
+        if self.snapshot.is_some() {
+            return Ok(());
+        }
+
         let client = match &self.client {
             Some(c) => c.clone(),
             None => {
@@ -413,168 +1722,43 @@ impl DiagnosticsComponent {
 
         self.state.start_loading();
 
-        let timeout = std::time::Duration::from_secs(15);
-
-        // Fetch platform info first
-        if let Ok(versions) = client.version().await
-            && let Some(v) = versions.first()
-            && let Some(data) = self.data_mut()
-        {
-            data.context.platform = v.platform.clone();
-            data.context.is_container = v.platform == "container";
-            tracing::info!("Detected platform: {}", data.context.platform);
-        }
-
-        // Get CPU count for load threshold scaling
-        if let Ok(cpu_info) = client.cpu_info().await
-            && let Some(info) = cpu_info.first()
-            && let Some(data) = self.data_mut()
-        {
-            data.context.cpu_count = info.cpu_count.max(1);
-            tracing::info!("Detected {} CPUs", data.context.cpu_count);
-        }
-
-        // Try to create K8s client once for all K8s-based checks
-        // For worker nodes, use the control plane endpoint to fetch kubeconfig
-        let kubeconfig_client = if let Some(ref cp_endpoint) = self.controlplane_endpoint {
-            tracing::info!(
-                "Worker node: using control plane {} for kubeconfig",
-                cp_endpoint
-            );
-            Some(client.with_node(cp_endpoint))
-        } else {
-            None
-        };
-
-        let k8s_client = match k8s::create_k8s_client_with_kubeconfig_source(
+        let mut data = self.data().cloned().unwrap_or_default();
+        let result = run_diagnostics(
             &client,
-            kubeconfig_client.as_ref(),
+            &mut data,
+            &self.controlplane_endpoints,
+            self.config_path.as_deref(),
+            &self.disabled_categories,
         )
-        .await
-        {
-            Ok(client) => {
-                tracing::info!("K8s client created successfully");
-                if let Some(data) = self.data_mut() {
-                    data.context.k8s_error = None;
-                }
-                Some(client)
-            }
-            Err(e) => {
-                let error_msg = format!("{}", e);
-                tracing::warn!(
-                    "Failed to create K8s client: {} - K8s-based checks will be limited",
-                    error_msg
-                );
-                if let Some(data) = self.data_mut() {
-                    data.context.k8s_error = Some(error_msg);
-                }
-                None
-            }
-        };
-
-        // Detect CNI type (uses K8s API if available, falls back to file checks)
-        let (cni_type, cni_info) = cni::detect_cni_with_client(&client, k8s_client.as_ref()).await;
-        if let Some(data) = self.data_mut() {
-            data.context.cni_type = cni_type;
-            data.context.cni_info = cni_info.clone();
-            tracing::info!("Detected CNI: {:?}", data.context.cni_type);
-        }
-
-        // Get pod health from K8s API (reusing the same client)
-        if let Some(ref kc) = k8s_client {
-            match k8s::check_pod_health(kc).await {
-                Ok(health) => {
-                    // Convert k8s::PodHealthInfo to types::PodHealthInfo
-                    let pod_health = PodHealthInfo {
-                        crashing: health
-                            .crashing
-                            .iter()
-                            .map(|p| UnhealthyPodInfo {
-                                name: p.name.clone(),
-                                namespace: p.namespace.clone(),
-                                state: p.state.clone(),
-                                restart_count: p.restart_count,
-                            })
-                            .collect(),
-                        image_pull_errors: health
-                            .image_pull_errors
-                            .iter()
-                            .map(|p| UnhealthyPodInfo {
-                                name: p.name.clone(),
-                                namespace: p.namespace.clone(),
-                                state: p.state.clone(),
-                                restart_count: p.restart_count,
-                            })
-                            .collect(),
-                        total_pods: health.total_pods,
-                    };
-                    if let Some(data) = self.data_mut() {
-                        data.context.pod_health = Some(pod_health);
-                    }
-                    tracing::info!("Pod health check complete: {} pods", health.total_pods);
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to check pod health via K8s API: {}", e);
-                }
-            }
-
-            // Detect installed addons
-            let detected_addons = addons::detect_addons(kc).await;
-            if let Some(data) = self.data_mut() {
-                data.detected_addons = detected_addons;
-            }
-        }
-
-        // Get context and detected_addons for use in async block
-        let context = self.data().map(|d| d.context.clone()).unwrap_or_default();
-        let detected_addons = self
+        .await;
+        promote_endpoint(
+            &mut self.controlplane_endpoints,
+            data.context.kubeconfig_endpoint.as_deref(),
+        );
+        let changes = self
             .data()
-            .map(|d| d.detected_addons.clone())
+            .map(|previous| status_changes(previous, &data))
             .unwrap_or_default();
-
-        let result = tokio::time::timeout(timeout, async {
-            // Run core checks
-            let mut system_checks = core::run_system_checks(&client, &context).await;
-            let kubernetes_checks = core::run_kubernetes_checks(&client, &context).await;
-            let service_checks = core::run_service_checks(&client, &context).await;
-
-            // Run certificate checks and add to system checks
-            let cert_checks =
-                core::run_certificate_checks(&client, &context, self.config_path.as_deref()).await;
-            system_checks.extend(cert_checks);
-
-            // Run CNI-specific checks
-            let cni_checks = cni::run_cni_checks(&client, &context, k8s_client.as_ref()).await;
-
-            // Run addon-specific checks
-            let addon_checks =
-                addons::run_addon_checks(k8s_client.as_ref(), &detected_addons, &context).await;
-
-            (
-                system_checks,
-                kubernetes_checks,
-                service_checks,
-                cni_checks,
-                addon_checks,
-            )
-        })
-        .await;
+        if let Some(current) = self.data_mut() {
+            *current = data;
+        }
 
         match result {
-            Ok((system, kubernetes, services, cni, addons_result)) => {
-                if let Some(data) = self.data_mut() {
-                    data.system_checks = system;
-                    data.kubernetes_checks = kubernetes;
-                    data.service_checks = services;
-                    data.cni_checks = cni;
-                    data.addon_checks = addons_result;
-                }
+            Ok(()) => {
+                let now = Instant::now();
+                self.status_changes.extend(
+                    changes
+                        .into_iter()
+                        .map(|(key, change)| (key, (change, now))),
+                );
                 // Ensure selection is valid after checks change
                 self.ensure_valid_selection();
+                self.checks_ran = true;
                 self.state.mark_loaded();
             }
-            Err(_) => {
-                self.set_error("Timeout fetching diagnostics".to_string());
+            Err(e) => {
+                // run_diagnostics only fails when the checks time out
+                self.set_error(ComponentError::Timeout(e.to_string()));
             }
         }
 
@@ -582,11 +1766,9 @@ impl DiagnosticsComponent {
     }
 
     /// Get category title
-    fn category_title(&self, idx: usize) -> &'static str {
-        match idx {
-            0 => "System Health",
-            1 => "Kubernetes Components",
-            2 => {
+    fn category_title(&self, category: CheckCategory) -> &'static str {
+        match category {
+            CheckCategory::Cni => {
                 let cni_type = self
                     .data()
                     .map(|d| d.context.cni_type.clone())
@@ -598,9 +1780,7 @@ impl DiagnosticsComponent {
                     _ => "CNI",
                 }
             }
-            3 => "Services",
-            4 => "Addons",
-            _ => "Unknown",
+            other => other.title(),
         }
     }
 
@@ -609,13 +1789,13 @@ impl DiagnosticsComponent {
         &self,
         frame: &mut Frame,
         area: Rect,
-        category_idx: usize,
+        category: CheckCategory,
         checks: &[DiagnosticCheck],
         is_selected: bool,
     ) {
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(self.category_title(category_idx))
+            .title(self.category_title(category))
             .border_style(if is_selected {
                 Style::default().fg(Color::Cyan)
             } else {
@@ -636,7 +1816,21 @@ impl DiagnosticsComponent {
             .enumerate()
             .map(|(i, check)| {
                 let (indicator, color) = check.status.indicator();
+                let acknowledged = self.is_acknowledged(check);
                 let is_current = is_selected && i == self.selected_check;
+                let marker = match self
+                    .status_changes
+                    .get(&(category, check.id.clone()))
+                    .map(|(change, _)| change)
+                {
+                    Some(StatusChange::Recovered) => {
+                        Span::styled(glyphs().up, Style::default().fg(Color::Green))
+                    }
+                    Some(StatusChange::Regressed) => {
+                        Span::styled(glyphs().down, Style::default().fg(Color::Red))
+                    }
+                    None => Span::raw(""),
+                };
 
                 let style = if is_current {
                     Style::default().bg(Color::DarkGray)
@@ -644,31 +1838,48 @@ impl DiagnosticsComponent {
                     Style::default()
                 };
 
+                // Acknowledged issues stay listed, but dimmed
+                let message_color = if acknowledged {
+                    Color::DarkGray
+                } else if check.status == CheckStatus::Pass {
+                    Color::Green
+                } else if check.status == CheckStatus::Fail {
+                    Color::Red
+                } else if check.status == CheckStatus::Warn {
+                    Color::Yellow
+                } else {
+                    Color::DarkGray
+                };
+                let mut message = vec![Span::styled(
+                    check.message.clone(),
+                    Style::default().fg(message_color),
+                )];
+                if acknowledged {
+                    message.push(Span::styled(
+                        " (acknowledged)",
+                        Style::default()
+                            .fg(Color::DarkGray)
+                            .add_modifier(Modifier::ITALIC),
+                    ));
+                }
+
                 Row::new(vec![
-                    ratatui::widgets::Cell::from(Span::styled(
-                        indicator,
-                        Style::default().fg(color),
-                    )),
+                    ratatui::widgets::Cell::from(Line::from(vec![
+                        Span::styled(
+                            indicator,
+                            Style::default().fg(if acknowledged { Color::DarkGray } else { color }),
+                        ),
+                        marker,
+                    ])),
                     ratatui::widgets::Cell::from(check.name.clone()),
-                    ratatui::widgets::Cell::from(Span::styled(
-                        check.message.clone(),
-                        Style::default().fg(if check.status == CheckStatus::Pass {
-                            Color::Green
-                        } else if check.status == CheckStatus::Fail {
-                            Color::Red
-                        } else if check.status == CheckStatus::Warn {
-                            Color::Yellow
-                        } else {
-                            Color::DarkGray
-                        }),
-                    )),
+                    ratatui::widgets::Cell::from(Line::from(message)),
                 ])
                 .style(style)
             })
             .collect();
 
         let widths = [
-            Constraint::Length(2),
+            Constraint::Length(3),
             Constraint::Length(20),
             Constraint::Fill(1),
         ];
@@ -684,7 +1895,7 @@ impl DiagnosticsComponent {
         };
 
         let dialog_width = 70.min(area.width.saturating_sub(4));
-        let dialog_height = 15.min(area.height.saturating_sub(4));
+        let dialog_height = 24.min(area.height.saturating_sub(4));
         let dialog_x = (area.width.saturating_sub(dialog_width)) / 2;
         let dialog_y = (area.height.saturating_sub(dialog_height)) / 2;
 
@@ -733,8 +1944,41 @@ impl DiagnosticsComponent {
             }
             lines.push(Line::from(""));
         } else if let Some(preview) = &pending.preview {
-            lines.push(Line::from("This will apply the following configuration:"));
+            lines.push(Line::from("Node currently has:"));
+            match &pending.current {
+                None => lines.push(Line::from(Span::styled(
+                    "  Reading machine config...",
+                    Style::default().fg(Color::DarkGray),
+                ))),
+                Some(Err(e)) => lines.push(Line::from(Span::styled(
+                    format!("  Unavailable: {}", e),
+                    Style::default().fg(Color::Red),
+                ))),
+                Some(Ok(current)) => match &current.fragment {
+                    Some(fragment) => {
+                        for line in fragment.lines().take(5) {
+                            lines.push(Line::from(Span::styled(
+                                format!("  {}", line),
+                                Style::default().fg(Color::DarkGray),
+                            )));
+                        }
+                    }
+                    None => lines.push(Line::from(Span::styled(
+                        "  (not set)",
+                        Style::default().fg(Color::DarkGray),
+                    ))),
+                },
+            }
+            if let Some(Ok(current)) = &pending.current
+                && current.already_applied
+            {
+                lines.push(Line::from(Span::styled(
+                    format!("{} The node already has this setting.", glyphs().warning),
+                    Style::default().fg(Color::Yellow),
+                )));
+            }
             lines.push(Line::from(""));
+            lines.push(Line::from("This will apply the following configuration:"));
             for line in preview.lines().take(5) {
                 lines.push(Line::from(Span::styled(
                     format!("  {}", line),
@@ -746,24 +1990,31 @@ impl DiagnosticsComponent {
 
         if !is_host_command && pending.fix.action.requires_reboot() {
             lines.push(Line::from(Span::styled(
-                "⚠ This requires a node reboot to take effect.",
+                format!(
+                    "{} This requires a node reboot to take effect.",
+                    glyphs().warning
+                ),
                 Style::default().fg(Color::Yellow),
             )));
             lines.push(Line::from(""));
         }
 
-        if is_host_command {
-            let show_copied = self
-                .copy_feedback_until
-                .map(|t| t.elapsed() < std::time::Duration::from_secs(2))
-                .unwrap_or(false);
+        let choices = pending.fix.action.confirm_choices();
+        if choices.contains(&ConfirmChoice::Stage) {
+            lines.push(Line::from(Span::styled(
+                "Stage applies the change on the node's next reboot.",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        if choices.contains(&ConfirmChoice::DrainAndReboot) {
+            lines.push(Line::from(Span::styled(
+                "Drain & Reboot evicts pods first and uncordons once Ready.",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
 
-            let copy_style = if show_copied {
-                Style::default()
-                    .bg(Color::Green)
-                    .fg(Color::Black)
-                    .add_modifier(Modifier::BOLD)
-            } else if self.confirmation_selection == 0 {
+        if is_host_command {
+            let copy_style = if self.confirmation_selection == 0 {
                 Style::default()
                     .bg(Color::Cyan)
                     .fg(Color::Black)
@@ -772,56 +2023,315 @@ impl DiagnosticsComponent {
                 Style::default().fg(Color::Cyan)
             };
 
-            let close_style = if self.confirmation_selection == 1 {
-                Style::default()
-                    .bg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD)
+            let close_style = if self.confirmation_selection == 1 {
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            lines.push(Line::from(vec![
+                Span::raw("         "),
+                Span::styled(" Copy ", copy_style),
+                Span::raw("     "),
+                Span::styled(" Close ", close_style),
+            ]));
+        } else {
+            let mut buttons = vec![Span::raw("   ")];
+            for (i, choice) in choices.iter().enumerate() {
+                let selected = i == self.confirmation_selection;
+                let style = match (choice, selected) {
+                    (ConfirmChoice::Cancel, true) => Style::default()
+                        .bg(Color::DarkGray)
+                        .add_modifier(Modifier::BOLD),
+                    (ConfirmChoice::Cancel, false) => Style::default(),
+                    (ConfirmChoice::Stage, true) => Style::default()
+                        .bg(Color::Cyan)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::BOLD),
+                    (ConfirmChoice::Stage, false) => Style::default().fg(Color::Cyan),
+                    (_, true) => Style::default()
+                        .bg(Color::Green)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::BOLD),
+                    (_, false) => Style::default().fg(Color::Green),
+                };
+                buttons.push(Span::styled(choice.label(), style));
+                buttons.push(Span::raw("  "));
+            }
+            lines.push(Line::from(buttons));
+        }
+
+        let content = Paragraph::new(lines);
+        frame.render_widget(content, inner);
+    }
+
+    /// Render the pod logs drill-down popup
+    fn render_pod_logs(&self, frame: &mut Frame, area: Rect) {
+        let Some(view) = &self.pod_logs else {
+            return;
+        };
+        let Some(pod) = view.selected_pod() else {
+            return;
+        };
+
+        let dialog_width = (area.width * 9 / 10).max(40).min(area.width);
+        let dialog_height = (area.height * 8 / 10).max(10).min(area.height);
+        let dialog_area = Rect::new(
+            area.x + (area.width.saturating_sub(dialog_width)) / 2,
+            area.y + (area.height.saturating_sub(dialog_height)) / 2,
+            dialog_width,
+            dialog_height,
+        );
+
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                " Pod Logs: {}/{} [{}/{}] ",
+                pod.namespace,
+                pod.name,
+                view.selected + 1,
+                view.pods.len()
+            ))
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let chunks = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+        let header = Paragraph::new(Line::from(vec![
+            Span::raw(" Container: "),
+            Span::styled(&pod.container, Style::default().fg(Color::White)),
+            Span::raw("  State: "),
+            Span::styled(&pod.state, Style::default().fg(Color::Red)),
+            Span::raw(format!("  Restarts: {}", pod.restart_count)),
+        ]));
+        frame.render_widget(header, chunks[0]);
+
+        let wrap = pod.is_image_pull_error();
+        let body = if view.logs.is_loading() {
+            Paragraph::new(" Loading logs...").style(Style::default().fg(Color::DarkGray))
+        } else if let Some(error) = view.logs.error() {
+            Paragraph::new(format!(" Failed to fetch logs: {}", error))
+                .style(Style::default().fg(Color::Red))
+        } else {
+            let lines: Vec<Line> = view
+                .logs
+                .data()
+                .map(|lines| {
+                    lines
+                        .iter()
+                        .map(|l| {
+                            if l.starts_with("──") {
+                                Line::from(Span::styled(
+                                    format!(" {}", l),
+                                    Style::default().fg(Color::Yellow),
+                                ))
+                            } else {
+                                Line::from(format!(" {}", l))
+                            }
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            let body = Paragraph::new(lines).scroll((view.scroll, 0));
+            // Pull errors are one long line; log lines keep their layout
+            if wrap {
+                body.wrap(Wrap { trim: false })
+            } else {
+                body
+            }
+        };
+        frame.render_widget(body, chunks[1]);
+
+        let mut hints = vec![
+            Span::styled("[j/k]", Style::default().fg(Color::Cyan)),
+            Span::raw(" Scroll  "),
+        ];
+        if view.pods.len() > 1 {
+            hints.push(Span::styled("[Tab]", Style::default().fg(Color::Cyan)));
+            hints.push(Span::raw(" Next pod  "));
+        }
+        hints.push(Span::styled("[r]", Style::default().fg(Color::Cyan)));
+        hints.push(Span::raw(" Reload  "));
+        hints.push(Span::styled("[Esc]", Style::default().fg(Color::Cyan)));
+        hints.push(Span::raw(" Close"));
+        frame.render_widget(Paragraph::new(Line::from(hints)), chunks[2]);
+    }
+
+    /// Render the bug report preview
+    fn render_bug_report(&self, frame: &mut Frame, area: Rect) {
+        let Some(view) = &self.bug_report else {
+            return;
+        };
+        let text = self.bug_report_text(view.redact).unwrap_or_default();
+
+        let dialog_width = (area.width * 9 / 10).max(40).min(area.width);
+        let dialog_height = (area.height * 8 / 10).max(10).min(area.height);
+        let dialog_area = Rect::new(
+            area.x + (area.width.saturating_sub(dialog_width)) / 2,
+            area.y + (area.height.saturating_sub(dialog_height)) / 2,
+            dialog_width,
+            dialog_height,
+        );
+
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(if view.redact {
+                " Bug Report (redacted) "
             } else {
-                Style::default()
-            };
+                " Bug Report "
+            })
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
 
-            let copy_text = if show_copied { " Copied! " } else { " Copy " };
+        let chunks = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).split(inner);
 
-            lines.push(Line::from(vec![
-                Span::raw("         "),
-                Span::styled(copy_text, copy_style),
-                Span::raw("     "),
-                Span::styled(" Close ", close_style),
-            ]));
+        let lines: Vec<Line> = text
+            .lines()
+            .map(|l| Line::from(format!(" {}", l)))
+            .collect();
+        frame.render_widget(Paragraph::new(lines).scroll((view.scroll, 0)), chunks[0]);
+
+        let footer = if let Some(status) = &view.status {
+            Line::from(Span::styled(
+                format!(" {}", status),
+                Style::default().fg(Color::Green),
+            ))
         } else {
-            let cancel_style = if self.confirmation_selection == 0 {
-                Style::default()
-                    .bg(Color::DarkGray)
-                    .add_modifier(Modifier::BOLD)
+            Line::from(vec![
+                Span::styled("[c]", Style::default().fg(Color::Cyan)),
+                Span::raw(" Copy  "),
+                Span::styled("[s]", Style::default().fg(Color::Cyan)),
+                Span::raw(" Save  "),
+                Span::styled("[x]", Style::default().fg(Color::Cyan)),
+                Span::raw(if view.redact {
+                    " Show hosts/IPs  "
+                } else {
+                    " Redact hosts/IPs  "
+                }),
+                Span::styled("[Esc]", Style::default().fg(Color::Cyan)),
+                Span::raw(" Close"),
+            ])
+        };
+        frame.render_widget(Paragraph::new(footer), chunks[1]);
+    }
+
+    /// Render the category toggle popup
+    fn render_category_menu(&self, frame: &mut Frame, area: Rect) {
+        let Some(cursor) = self.category_menu else {
+            return;
+        };
+
+        let dialog_width = 44.min(area.width.saturating_sub(4));
+        let dialog_height = (CATEGORY_ORDER.len() as u16 + 5).min(area.height.saturating_sub(4));
+        let dialog_area = Rect::new(
+            area.x + (area.width.saturating_sub(dialog_width)) / 2,
+            area.y + (area.height.saturating_sub(dialog_height)) / 2,
+            dialog_width,
+            dialog_height,
+        );
+
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Check Categories ")
+            .border_style(Style::default().fg(Color::Cyan));
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let mut lines = vec![Line::from("")];
+        for (idx, category) in CATEGORY_ORDER.iter().enumerate() {
+            let enabled = !self.disabled_categories.contains(category);
+            let style = if idx == cursor {
+                Style::default().bg(Color::DarkGray)
             } else {
                 Style::default()
             };
-            let apply_style = if self.confirmation_selection == 1 {
-                Style::default()
-                    .bg(Color::Green)
-                    .fg(Color::Black)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(Color::Green)
-            };
-
             lines.push(Line::from(vec![
-                Span::raw("         "),
-                Span::styled(" Cancel ", cancel_style),
-                Span::raw("     "),
                 Span::styled(
-                    if pending.fix.action.requires_reboot() {
-                        " Apply & Reboot "
+                    if enabled { " [x] " } else { " [ ] " },
+                    Style::default().fg(if enabled {
+                        Color::Green
                     } else {
-                        " Apply "
-                    },
-                    apply_style,
+                        Color::DarkGray
+                    }),
                 ),
+                Span::styled(category.title(), style),
             ]));
         }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            " Space: toggle  Esc: close",
+            Style::default().fg(Color::DarkGray),
+        )));
 
-        let content = Paragraph::new(lines);
-        frame.render_widget(content, inner);
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Render the spinner and elapsed time while a fix is being applied
+    fn render_apply_progress(&self, frame: &mut Frame, area: Rect) {
+        let Some(task) = &self.apply_task else {
+            return;
+        };
+
+        let dialog_width = (task.description.len() as u16 + 8)
+            .clamp(40, 70)
+            .min(area.width.saturating_sub(4));
+        let dialog_height = 6.min(area.height.saturating_sub(2));
+        let dialog_area = Rect::new(
+            area.x + (area.width.saturating_sub(dialog_width)) / 2,
+            area.y + (area.height.saturating_sub(dialog_height)) / 2,
+            dialog_width,
+            dialog_height,
+        );
+
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Applying Fix ")
+            .border_style(Style::default().fg(Color::Yellow));
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let step = task
+            .progress
+            .lock()
+            .ok()
+            .and_then(|p| p.clone())
+            .unwrap_or_else(|| "Waiting for the node to respond...".to_string());
+        let frames = glyphs().spinner;
+        let spinner = frames[self.spinner_frame % frames.len()];
+        let lines = vec![
+            Line::from(vec![
+                Span::styled(format!(" {} ", spinner), Style::default().fg(Color::Yellow)),
+                Span::raw(task.description.clone()),
+            ]),
+            Line::from(Span::styled(
+                format!(" Elapsed: {}s", task.started.elapsed().as_secs()),
+                Style::default().fg(Color::DarkGray),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                format!(" {}", step),
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+        frame.render_widget(Paragraph::new(lines), inner);
     }
 
     /// Render the details popup
@@ -870,10 +2380,13 @@ impl DiagnosticsComponent {
         }
 
         lines.push(Line::from(""));
-        lines.push(Line::from(Span::styled(
-            " Press Enter or Esc to close ",
-            Style::default().fg(Color::DarkGray),
-        )));
+        lines.push(Line::from(vec![
+            Span::styled(
+                " Press Enter or Esc to close, ",
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled("y to copy ", Style::default().fg(Color::DarkGray)),
+        ]));
 
         let content = Paragraph::new(lines);
         frame.render_widget(content, inner);
@@ -881,7 +2394,104 @@ impl DiagnosticsComponent {
 }
 
 impl Component for DiagnosticsComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Navigate checks"),
+            ("Tab/Shift+Tab", "Switch category"),
+            ("n/N", "Next/previous failing check"),
+            ("a", "Acknowledge/unacknowledge check"),
+            ("Enter", "Apply fix / show pod logs"),
+            ("e", "Kubernetes warning events"),
+            ("C", "Choose check categories"),
+            ("B", "Bug report (copy/save markdown)"),
+            ("K", "Copy talosctl kubeconfig command"),
+            ("y", "Copy check details"),
+            ("S", "Save snapshot to a file"),
+            ("[/]", "Previous/next node (snapshot)"),
+            ("r", "Refresh"),
+            ("q/Esc", "Back"),
+        ])
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        // Handle pod logs drill-down (if showing)
+        if let Some(view) = &mut self.pod_logs {
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    self.pod_logs = None;
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    let max = view.logs.data().map(|l| l.len()).unwrap_or(0);
+                    view.scroll = (view.scroll + 1).min(max.saturating_sub(1) as u16);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    view.scroll = view.scroll.saturating_sub(1);
+                }
+                KeyCode::Char('G') => {
+                    let max = view.logs.data().map(|l| l.len()).unwrap_or(0);
+                    view.scroll = max.saturating_sub(1) as u16;
+                }
+                KeyCode::Char('g') => {
+                    view.scroll = 0;
+                }
+                KeyCode::Tab => return Ok(self.cycle_pod_logs(true)),
+                KeyCode::BackTab => return Ok(self.cycle_pod_logs(false)),
+                KeyCode::Char('r') if !view.logs.is_loading() => {
+                    return Ok(Some(Action::LoadDiagnosticPodLogs));
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+
+        // Handle bug report preview (if showing)
+        if let Some(view) = &mut self.bug_report {
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => self.bug_report = None,
+                KeyCode::Down | KeyCode::Char('j') => view.scroll = view.scroll.saturating_add(1),
+                KeyCode::Up | KeyCode::Char('k') => view.scroll = view.scroll.saturating_sub(1),
+                KeyCode::Char('x') => {
+                    view.redact = !view.redact;
+                    view.status = None;
+                }
+                KeyCode::Char('c') | KeyCode::Char('y') => self.copy_bug_report(),
+                KeyCode::Char('s') => self.save_bug_report(),
+                _ => {}
+            }
+            return Ok(None);
+        }
+
+        // Only allow leaving the view while a fix is being applied
+        if self.applying_fix {
+            return Ok(
+                matches!(key.code, KeyCode::Esc | KeyCode::Char('q')).then_some(Action::Back)
+            );
+        }
+
+        // Handle category toggle popup (if showing)
+        if let Some(cursor) = self.category_menu {
+            match key.code {
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.category_menu = Some((cursor + 1).min(CATEGORY_ORDER.len() - 1));
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.category_menu = Some(cursor.saturating_sub(1));
+                }
+                KeyCode::Char(' ') | KeyCode::Enter => {
+                    self.toggle_category(CATEGORY_ORDER[cursor]);
+                }
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('C') => {
+                    self.category_menu = None;
+                    // Re-run so newly enabled categories get checked
+                    if std::mem::take(&mut self.categories_changed) {
+                        return Ok(Some(Action::Refresh));
+                    }
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+
         // Handle details popup (if showing)
         if self.show_details {
             match key.code {
@@ -889,6 +2499,9 @@ impl Component for DiagnosticsComponent {
                     self.show_details = false;
                     return Ok(None);
                 }
+                KeyCode::Char('y') => {
+                    return Ok(self.copy_check_details());
+                }
                 _ => return Ok(None),
             }
         }
@@ -900,13 +2513,24 @@ impl Component for DiagnosticsComponent {
                 .map(|p| p.fix.action.is_host_command())
                 .unwrap_or(false);
 
+            let choices = self
+                .pending_action
+                .as_ref()
+                .map(|p| p.fix.action.confirm_choices())
+                .unwrap_or_default();
+            let last = if is_host_command {
+                1
+            } else {
+                choices.len().saturating_sub(1)
+            };
+
             match key.code {
                 KeyCode::Left | KeyCode::Char('h') => {
-                    self.confirmation_selection = 0;
+                    self.confirmation_selection = self.confirmation_selection.saturating_sub(1);
                     return Ok(None);
                 }
                 KeyCode::Right | KeyCode::Char('l') => {
-                    self.confirmation_selection = 1;
+                    self.confirmation_selection = (self.confirmation_selection + 1).min(last);
                     return Ok(None);
                 }
                 KeyCode::Enter => {
@@ -925,17 +2549,28 @@ impl Component for DiagnosticsComponent {
                                         std::thread::sleep(std::time::Duration::from_millis(100));
                                     }
                                 });
-                                self.copy_feedback_until = Some(Instant::now());
+                                return Ok(Some(Action::Notify(
+                                    "Copied command".to_string(),
+                                    NotifyLevel::Success,
+                                )));
                             }
                         } else {
                             self.show_confirmation = false;
                             self.pending_action = None;
                         }
-                    } else if self.confirmation_selection == 0 {
-                        self.show_confirmation = false;
-                        self.pending_action = None;
                     } else {
-                        return Ok(Some(Action::ApplyDiagnosticFix));
+                        match choices.get(self.confirmation_selection) {
+                            Some(ConfirmChoice::Cancel) | None => {
+                                self.show_confirmation = false;
+                                self.pending_action = None;
+                            }
+                            Some(choice) => {
+                                if let Some(pending) = &mut self.pending_action {
+                                    pending.choice = *choice;
+                                }
+                                return Ok(Some(Action::ApplyDiagnosticFix));
+                            }
+                        }
                     }
                     return Ok(None);
                 }
@@ -952,8 +2587,42 @@ impl Component for DiagnosticsComponent {
             KeyCode::Char('q') | KeyCode::Esc => {
                 return Ok(Some(Action::Back));
             }
-            KeyCode::Char('r') => {
-                return Ok(Some(Action::Refresh));
+            KeyCode::Char('r') if self.snapshot.is_none() => {
+                return Ok(manual_refresh(&self.state));
+            }
+            KeyCode::Char('e') if self.snapshot.is_none() => {
+                return Ok(Some(Action::ShowEvents));
+            }
+            KeyCode::Char('S') => {
+                return Ok(Some(self.save_snapshot_to_temp()));
+            }
+            KeyCode::Char(']') => {
+                self.cycle_snapshot_node(true);
+            }
+            KeyCode::Char('[') => {
+                self.cycle_snapshot_node(false);
+            }
+            KeyCode::Char('B') if self.data().is_some() => {
+                self.bug_report = Some(report::BugReportView::new());
+            }
+            KeyCode::Char('K') => {
+                return Ok(self.copy_kubeconfig_command());
+            }
+            KeyCode::Char('y') => {
+                return Ok(self.copy_check_details());
+            }
+            KeyCode::Char('C') => {
+                self.category_menu = Some(0);
+                self.categories_changed = false;
+            }
+            KeyCode::Char('a') => {
+                return Ok(self.toggle_acknowledged());
+            }
+            KeyCode::Char('n') => {
+                self.jump_to_issue(true);
+            }
+            KeyCode::Char('N') => {
+                self.jump_to_issue(false);
             }
             KeyCode::Down | KeyCode::Char('j') => {
                 self.next_check();
@@ -968,6 +2637,9 @@ impl Component for DiagnosticsComponent {
                 self.prev_category();
             }
             KeyCode::Enter => {
+                if self.snapshot.is_none() && self.open_pod_logs() {
+                    return Ok(Some(Action::LoadDiagnosticPodLogs));
+                }
                 self.initiate_fix();
             }
             _ => {}
@@ -978,6 +2650,18 @@ impl Component for DiagnosticsComponent {
 
     fn update(&mut self, action: Action) -> Result<Option<Action>> {
         if let Action::Tick = action {
+            let marker_ttl = Duration::from_secs(CHANGE_MARKER_SECS);
+            self.status_changes
+                .retain(|_, (_, changed_at)| changed_at.elapsed() < marker_ttl);
+
+            self.poll_current_value();
+
+            if self.applying_fix {
+                self.spinner_frame = (self.spinner_frame + 1) % glyphs().spinner.len();
+                // Reload checks once the fix has landed
+                return Ok(self.poll_apply().then_some(Action::Refresh));
+            }
+
             let interval = Duration::from_secs(AUTO_REFRESH_INTERVAL_SECS);
             if self.state.should_auto_refresh(self.auto_refresh, interval) {
                 return Ok(Some(Action::Refresh));
@@ -995,109 +2679,153 @@ impl Component for DiagnosticsComponent {
         .split(area);
 
         // Get header info from data
-        let (hostname, address, cni_label) = self
+        let cni_label = self
             .data()
-            .map(|d| {
-                (
-                    d.hostname.clone(),
-                    d.address.clone(),
-                    d.context.cni_type.name(),
-                )
-            })
-            .unwrap_or_else(|| (String::new(), String::new(), "Unknown"));
+            .map(|d| d.context.cni_type.name())
+            .unwrap_or("Unknown");
+
+        // Fall back to the platform detection found when the overview had none
+        let mut node_info = self.node_info.clone();
+        if node_info.platform.as_deref().is_none_or(str::is_empty)
+            && let Some(data) = self.data()
+            && !data.context.platform.is_empty()
+        {
+            node_info.platform = Some(data.context.platform.clone());
+        }
 
         // Header
-        let header_text = format!(" Diagnostics: {} ({}) [{}] ", hostname, address, cni_label);
-        let header = Paragraph::new(header_text)
-            .style(Style::default().add_modifier(Modifier::BOLD))
-            .block(Block::default().borders(Borders::BOTTOM));
+        let mut spans = vec![Span::styled(
+            " Diagnostics: ",
+            Style::default().add_modifier(Modifier::BOLD),
+        )];
+        spans.extend(node_info_spans(&node_info));
+        spans.push(Span::styled(
+            format!("  [{}]", cni_label),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        if self.snapshot.is_none()
+            && let Some(age) = self.state.elapsed_since_refresh()
+        {
+            let interval = Duration::from_secs(AUTO_REFRESH_INTERVAL_SECS);
+            spans.extend([Span::raw("  "), last_updated_span(age, interval)]);
+        }
+        if let Some(view) = &self.snapshot {
+            let mut label = format!(
+                "  SNAPSHOT {}",
+                view.snapshot
+                    .taken_at
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+            );
+            if view.snapshot.nodes.len() > 1 {
+                label.push_str(&format!(
+                    " (node {}/{})",
+                    view.current + 1,
+                    view.snapshot.nodes.len()
+                ));
+            }
+            spans.push(Span::styled(
+                label,
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        let header =
+            Paragraph::new(Line::from(spans)).block(Block::default().borders(Borders::BOTTOM));
         frame.render_widget(header, chunks[0]);
 
-        if let Some(error) = self.state.error() {
-            let error_msg =
-                Paragraph::new(format!("Error: {}", error)).style(Style::default().fg(Color::Red));
+        if let Some(error) = self.state.component_error() {
+            let error_msg = Paragraph::new(format!("Error: {}", error.describe(self.auto_refresh)))
+                .style(Style::default().fg(error.color()));
             frame.render_widget(error_msg, chunks[1]);
+        } else if let Some(reason) = self.no_checks_reason() {
+            frame.render_widget(empty_state("No checks ran", Some(&reason)), chunks[1]);
         } else if let Some(data) = self.data() {
-            // Dynamically size Addons section based on whether addons are detected
-            let addons_height = if data.detected_addons.any_detected() {
-                Constraint::Length(5)
-            } else {
-                Constraint::Length(0)
-            };
-
-            let content_chunks = Layout::vertical([
-                Constraint::Length(7), // System Health (Memory, CPU, 3 certs = 5 items + 2 border)
-                Constraint::Length(4), // Kubernetes Components (etcd, pod_health = 2 items + 2 border)
-                Constraint::Length(5), // CNI
-                Constraint::Fill(1),   // Services
-                addons_height,         // Addons (if any)
-            ])
-            .split(chunks[1]);
+            let categories = self.visible_categories();
+            let constraints: Vec<Constraint> = categories
+                .iter()
+                .map(|category| match category {
+                    CheckCategory::System => Constraint::Length(9), // Memory, swap, CPU, uptime, 3 certs + border
+                    CheckCategory::Kubernetes => Constraint::Length(4), // etcd, pod_health + border
+                    CheckCategory::Cni => Constraint::Length(5),
+                    CheckCategory::Services => Constraint::Fill(1),
+                    CheckCategory::Addons => Constraint::Length(5),
+                })
+                .collect();
+            let content_chunks = Layout::vertical(constraints).split(chunks[1]);
 
             // Clone checks for rendering to avoid borrow issues
-            let system_checks = data.system_checks.clone();
-            let kubernetes_checks = data.kubernetes_checks.clone();
-            let cni_checks = data.cni_checks.clone();
-            let service_checks = data.service_checks.clone();
-            let addon_checks = data.addon_checks.clone();
-            let any_addons = data.detected_addons.any_detected();
-
-            self.render_category(
-                frame,
-                content_chunks[0],
-                0,
-                &system_checks,
-                self.selected_category == 0,
-            );
-            self.render_category(
-                frame,
-                content_chunks[1],
-                1,
-                &kubernetes_checks,
-                self.selected_category == 1,
-            );
-            self.render_category(
-                frame,
-                content_chunks[2],
-                2,
-                &cni_checks,
-                self.selected_category == 2,
-            );
-            self.render_category(
-                frame,
-                content_chunks[3],
-                3,
-                &service_checks,
-                self.selected_category == 3,
-            );
+            let category_checks: Vec<Vec<DiagnosticCheck>> = categories
+                .iter()
+                .map(|&category| data.checks(category).to_vec())
+                .collect();
 
-            // Only render Addons section if addons are detected
-            if any_addons {
+            for (idx, (&category, checks)) in categories.iter().zip(&category_checks).enumerate() {
                 self.render_category(
                     frame,
-                    content_chunks[4],
-                    4,
-                    &addon_checks,
-                    self.selected_category == 4,
+                    content_chunks[idx],
+                    category,
+                    checks,
+                    self.selected_category == idx,
                 );
             }
         }
 
         // Footer
-        let footer = Paragraph::new(Line::from(vec![
+        let mut spans = vec![
             Span::styled("[j/k]", Style::default().fg(Color::Cyan)),
             Span::raw(" Navigate  "),
             Span::styled("[Tab]", Style::default().fg(Color::Cyan)),
             Span::raw(" Section  "),
+            Span::styled("[n/N]", Style::default().fg(Color::Cyan)),
+            Span::raw(" Next issue  "),
             Span::styled("[Enter]", Style::default().fg(Color::Cyan)),
             Span::raw(" Details/Fix  "),
+            Span::styled("[e]", Style::default().fg(Color::Cyan)),
+            Span::raw(" Events  "),
+            Span::styled("[C]", Style::default().fg(Color::Cyan)),
+            Span::raw(" Categories  "),
+            Span::styled("[B]", Style::default().fg(Color::Cyan)),
+            Span::raw(" Bug report  "),
+            Span::styled("[S]", Style::default().fg(Color::Cyan)),
+            Span::raw(" Snapshot  "),
             Span::styled("[r]", Style::default().fg(Color::Cyan)),
             Span::raw(" Refresh  "),
             Span::styled("[q]", Style::default().fg(Color::Cyan)),
             Span::raw(" Back"),
-        ]));
+        ];
+        if let Some(view) = &self.snapshot {
+            spans = vec![
+                Span::styled("[j/k]", Style::default().fg(Color::Cyan)),
+                Span::raw(" Navigate  "),
+                Span::styled("[Tab]", Style::default().fg(Color::Cyan)),
+                Span::raw(" Section  "),
+                Span::styled("[Enter]", Style::default().fg(Color::Cyan)),
+                Span::raw(" Details  "),
+            ];
+            if view.snapshot.nodes.len() > 1 {
+                spans.push(Span::styled("[[/]]", Style::default().fg(Color::Cyan)));
+                spans.push(Span::raw(" Node  "));
+            }
+            spans.push(Span::styled("[q]", Style::default().fg(Color::Cyan)));
+            spans.push(Span::raw(" Back  "));
+            spans.push(Span::styled(
+                view.path.display().to_string(),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        if self.k8s_error().is_some() {
+            // Point at the way to reproduce the failing K8s access by hand
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled("[K]", Style::default().fg(Color::Yellow)));
+            spans.push(Span::raw(" Copy kubeconfig cmd"));
+        }
+        let footer = Paragraph::new(Line::from(spans));
         frame.render_widget(footer, chunks[2]);
 
+        self.render_category_menu(frame, area);
+
         if self.show_confirmation {
             self.render_confirmation(frame, area);
         }
@@ -1106,6 +2834,319 @@ impl Component for DiagnosticsComponent {
             self.render_details(frame, area);
         }
 
+        self.render_apply_progress(frame, area);
+        self.render_pod_logs(frame, area);
+        self.render_bug_report(frame, area);
+
         Ok(())
     }
+
+    fn is_busy(&self) -> bool {
+        // Checked from outside the view too, where ticks no longer clear `applying_fix`
+        self.apply_task
+            .as_ref()
+            .is_some_and(|t| !t.handle.is_finished())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(id: &str, status: CheckStatus) -> DiagnosticCheck {
+        DiagnosticCheck {
+            status,
+            ..DiagnosticCheck::unknown(id, id)
+        }
+    }
+
+    #[test]
+    fn test_kubeconfig_command() {
+        assert_eq!(
+            kubeconfig_command("10.5.0.2", None, None),
+            "talosctl -n 10.5.0.2 kubeconfig"
+        );
+        assert_eq!(
+            kubeconfig_command("10.5.0.2", Some("/tmp/talosconfig"), Some("prod")),
+            "talosctl --talosconfig /tmp/talosconfig --context prod -n 10.5.0.2 kubeconfig"
+        );
+        assert_eq!(
+            kubeconfig_command(
+                "fd00::2",
+                Some("/home/me/My Configs/talosconfig"),
+                Some("it's-prod")
+            ),
+            "talosctl --talosconfig '/home/me/My Configs/talosconfig' \
+             --context 'it'\\''s-prod' -n fd00::2 kubeconfig"
+        );
+    }
+
+    #[test]
+    fn test_promote_endpoint() {
+        let mut endpoints: Vec<String> = ["10.5.0.2", "10.5.0.3", "10.5.0.4"]
+            .map(String::from)
+            .to_vec();
+        promote_endpoint(&mut endpoints, Some("10.5.0.4"));
+        assert_eq!(endpoints, ["10.5.0.4", "10.5.0.2", "10.5.0.3"]);
+
+        // Unknown or missing endpoints leave the order alone
+        promote_endpoint(&mut endpoints, Some("10.5.0.9"));
+        promote_endpoint(&mut endpoints, None);
+        assert_eq!(endpoints, ["10.5.0.4", "10.5.0.2", "10.5.0.3"]);
+    }
+
+    #[test]
+    fn test_next_issue_crosses_categories() {
+        let system = vec![
+            check("memory", CheckStatus::Pass),
+            check("cpu", CheckStatus::Warn),
+        ];
+        let kubernetes = vec![check("etcd", CheckStatus::Pass)];
+        let cni = vec![check("cni", CheckStatus::Fail)];
+        let categories: [&[DiagnosticCheck]; 5] = [&system, &kubernetes, &cni, &[], &[]];
+
+        assert_eq!(
+            next_issue_position(&categories, (0, 0), true, |_| false),
+            Some((0, 1))
+        );
+        assert_eq!(
+            next_issue_position(&categories, (0, 1), true, |_| false),
+            Some((2, 0))
+        );
+        // Wraps around to the start
+        assert_eq!(
+            next_issue_position(&categories, (2, 0), true, |_| false),
+            Some((0, 1))
+        );
+        assert_eq!(
+            next_issue_position(&categories, (2, 0), false, |_| false),
+            Some((0, 1))
+        );
+        assert_eq!(
+            next_issue_position(&categories, (0, 1), false, |_| false),
+            Some((2, 0))
+        );
+    }
+
+    #[test]
+    fn test_next_issue_none_when_all_pass() {
+        let system = vec![check("memory", CheckStatus::Pass)];
+        let categories: [&[DiagnosticCheck]; 5] = [&system, &[], &[], &[], &[]];
+        assert_eq!(
+            next_issue_position(&categories, (0, 0), true, |_| false),
+            None
+        );
+        assert_eq!(next_issue_position(&[], (0, 0), false, |_| false), None);
+    }
+
+    #[test]
+    fn test_next_issue_from_empty_category() {
+        let kubernetes = vec![check("etcd", CheckStatus::Fail)];
+        let categories: [&[DiagnosticCheck]; 5] = [&[], &kubernetes, &[], &[], &[]];
+        assert_eq!(
+            next_issue_position(&categories, (0, 0), true, |_| false),
+            Some((1, 0))
+        );
+        assert_eq!(
+            next_issue_position(&categories, (3, 0), true, |_| false),
+            Some((1, 0))
+        );
+
+        let cni = vec![
+            check("flannel", CheckStatus::Warn),
+            check("bridge", CheckStatus::Fail),
+        ];
+        let categories: [&[DiagnosticCheck]; 5] = [&[], &[], &cni, &[], &[]];
+        assert_eq!(
+            next_issue_position(&categories, (1, 0), true, |_| false),
+            Some((2, 0))
+        );
+        assert_eq!(
+            next_issue_position(&categories, (1, 0), false, |_| false),
+            Some((2, 1))
+        );
+    }
+
+    #[test]
+    fn test_next_issue_skips_acknowledged() {
+        let system = vec![
+            check("memory", CheckStatus::Warn),
+            check("cpu", CheckStatus::Fail),
+        ];
+        let categories: [&[DiagnosticCheck]; 5] = [&system, &[], &[], &[], &[]];
+        let memory_acked = |c: &DiagnosticCheck| c.id == "memory";
+        assert_eq!(
+            next_issue_position(&categories, (0, 1), true, memory_acked),
+            Some((0, 1))
+        );
+        let all_acked = |_: &DiagnosticCheck| true;
+        assert_eq!(
+            next_issue_position(&categories, (0, 0), true, all_acked),
+            None
+        );
+    }
+
+    #[test]
+    fn test_status_change_between() {
+        use CheckStatus::*;
+        assert_eq!(
+            StatusChange::between(&Fail, &Pass),
+            Some(StatusChange::Recovered)
+        );
+        assert_eq!(
+            StatusChange::between(&Fail, &Warn),
+            Some(StatusChange::Recovered)
+        );
+        assert_eq!(
+            StatusChange::between(&Pass, &Warn),
+            Some(StatusChange::Regressed)
+        );
+        assert_eq!(StatusChange::between(&Warn, &Warn), None);
+        // No verdict on either side is not a change
+        assert_eq!(StatusChange::between(&Unknown, &Fail), None);
+        assert_eq!(StatusChange::between(&Pass, &Checking), None);
+    }
+
+    #[test]
+    fn test_status_changes_matches_checks_by_id() {
+        let old = DiagnosticsData {
+            system_checks: vec![
+                check("memory", CheckStatus::Pass),
+                check("cpu", CheckStatus::Fail),
+            ],
+            service_checks: vec![check("etcd", CheckStatus::Pass)],
+            ..Default::default()
+        };
+        let new = DiagnosticsData {
+            // Reordered and with a check that did not exist before
+            system_checks: vec![
+                check("cpu", CheckStatus::Pass),
+                check("disk", CheckStatus::Fail),
+                check("memory", CheckStatus::Pass),
+            ],
+            service_checks: vec![check("etcd", CheckStatus::Warn)],
+            ..Default::default()
+        };
+        assert_eq!(
+            status_changes(&old, &new),
+            vec![
+                (
+                    (CheckCategory::System, "cpu".to_string()),
+                    StatusChange::Recovered
+                ),
+                (
+                    (CheckCategory::Services, "etcd".to_string()),
+                    StatusChange::Regressed
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_categories_are_skipped() {
+        let mut diagnostics = DiagnosticsComponent::default();
+        diagnostics.disabled_categories.clear();
+        // No addons detected, the other four show "Loading..." until a run finishes
+        assert_eq!(diagnostics.category_count(), 4);
+
+        diagnostics.state.set_data(DiagnosticsData {
+            system_checks: vec![check("memory", CheckStatus::Pass)],
+            service_checks: vec![check("etcd", CheckStatus::Pass)],
+            ..Default::default()
+        });
+        assert_eq!(
+            diagnostics.visible_categories(),
+            [CheckCategory::System, CheckCategory::Services]
+        );
+
+        diagnostics
+            .handle_key_event(KeyEvent::from(KeyCode::Tab))
+            .unwrap();
+        assert_eq!(diagnostics.current_checks()[0].id, "etcd");
+        diagnostics
+            .handle_key_event(KeyEvent::from(KeyCode::Tab))
+            .unwrap();
+        assert_eq!(diagnostics.current_checks()[0].id, "memory");
+    }
+
+    #[test]
+    fn test_no_checks_reason() {
+        let mut diagnostics = DiagnosticsComponent::default();
+        diagnostics.disabled_categories.clear();
+        // Nothing to explain before the first run
+        assert_eq!(diagnostics.no_checks_reason(), None);
+
+        diagnostics.checks_ran = true;
+        if let Some(data) = diagnostics.data_mut() {
+            data.context.k8s_error = Some("connection refused".to_string());
+        }
+        assert_eq!(
+            diagnostics.no_checks_reason().as_deref(),
+            Some("K8s API unavailable: connection refused")
+        );
+
+        if let Some(data) = diagnostics.data_mut() {
+            data.system_checks = vec![check("memory", CheckStatus::Pass)];
+        }
+        assert_eq!(diagnostics.no_checks_reason(), None);
+
+        diagnostics.disabled_categories = CATEGORY_ORDER.into_iter().collect();
+        assert!(diagnostics.no_checks_reason().unwrap().contains("disabled"));
+    }
+
+    #[test]
+    fn test_entry_for_node_matches_hostname_or_address() {
+        fn node<'a>(entry: &'a (&str, &str)) -> &'a str {
+            entry.0
+        }
+        let entries = [("cp-1", "metal"), ("10.0.0.21", "aws")];
+
+        let pick = |hostname, address| entry_for_node(&entries, node, hostname, address);
+        assert_eq!(pick("cp-1", "10.0.0.11").map(|e| e.1), Some("metal"));
+        assert_eq!(pick("worker-1", "10.0.0.21").map(|e| e.1), Some("aws"));
+        // Several entries and no match: don't guess
+        assert!(pick("worker-2", "10.0.0.22").is_none());
+
+        // A single entry belongs to the node whatever it reports
+        let single = [("talos-abc", "container")];
+        assert_eq!(
+            entry_for_node(&single, node, "worker-2", "10.0.0.22").map(|e| e.1),
+            Some("container")
+        );
+    }
+
+    #[test]
+    fn test_snapshot_view_is_read_only() {
+        let node = |hostname: &str| DiagnosticsData {
+            hostname: hostname.to_string(),
+            system_checks: vec![check("memory", CheckStatus::Warn)],
+            ..Default::default()
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshot.json");
+        DiagnosticsSnapshot::new(vec![node("cp-1"), node("worker-1")])
+            .save(&path)
+            .unwrap();
+
+        let mut diagnostics = DiagnosticsComponent::default();
+        diagnostics.load_snapshot(&path).unwrap();
+        assert!(diagnostics.is_snapshot());
+        assert_eq!(diagnostics.node_info().hostname, "cp-1");
+        assert!(
+            diagnostics
+                .handle_key_event(KeyEvent::from(KeyCode::Char('r')))
+                .unwrap()
+                .is_none()
+        );
+
+        diagnostics
+            .handle_key_event(KeyEvent::from(KeyCode::Char(']')))
+            .unwrap();
+        assert_eq!(diagnostics.node_info().hostname, "worker-1");
+
+        // Saving again keeps every node of the loaded snapshot
+        let resaved = dir.path().join("resaved.json");
+        diagnostics.save_snapshot(Some(&resaved)).unwrap();
+        assert_eq!(DiagnosticsSnapshot::load(&resaved).unwrap().nodes.len(), 2);
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Parses X.509 certificates to check expiry dates and provide warnings.
 
+use crate::ui_ext::glyphs;
 use chrono::{DateTime, Utc};
 use x509_parser::prelude::*;
 
@@ -22,11 +23,12 @@ impl CertStatus {
     /// Get display indicator and color
     pub fn indicator(&self) -> (&'static str, ratatui::style::Color) {
         use ratatui::style::Color;
+        let g = glyphs();
         match self {
-            CertStatus::Valid => ("●", Color::Green),
-            CertStatus::Warning => ("⚠", Color::Yellow),
+            CertStatus::Valid => (g.filled, Color::Green),
+            CertStatus::Warning => (g.warning, Color::Yellow),
             CertStatus::Critical => ("!", Color::Red),
-            CertStatus::Expired => ("✗", Color::Red),
+            CertStatus::Expired => (g.cross, Color::Red),
         }
     }
 
@@ -296,10 +298,11 @@ impl EncryptionProvider {
     /// Get indicator color
     pub fn indicator(&self) -> (&'static str, ratatui::style::Color) {
         use ratatui::style::Color;
+        let g = glyphs();
         match self {
-            EncryptionProvider::None => ("○", Color::DarkGray),
-            EncryptionProvider::Static | EncryptionProvider::NodeID => ("◐", Color::Yellow),
-            EncryptionProvider::Tpm | EncryptionProvider::Kms => ("●", Color::Green),
+            EncryptionProvider::None => (g.hollow, Color::DarkGray),
+            EncryptionProvider::Static | EncryptionProvider::NodeID => (g.partial, Color::Yellow),
+            EncryptionProvider::Tpm | EncryptionProvider::Kms => (g.filled, Color::Green),
             EncryptionProvider::Unknown(_) => ("?", Color::DarkGray),
         }
     }
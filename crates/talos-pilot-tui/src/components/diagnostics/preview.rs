@@ -0,0 +1,160 @@
+//! Before/after preview for config fixes
+//!
+//! Picks out the part of the node's running machine config that a fix's
+//! patch touches, so the confirmation dialog can show the current value next
+//! to the change and warn when the node already has it.
+
+use serde::Deserialize;
+use serde_yaml::{Mapping, Value};
+
+/// The node's current value for the fields a patch touches
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrentValue {
+    /// Running config restricted to the patch's keys, as YAML; `None` when
+    /// none of them are set
+    pub fragment: Option<String>,
+    /// Whether the node already has everything the patch adds
+    pub already_applied: bool,
+}
+
+/// Compare a patch against the node's (possibly multi-document) config
+///
+/// The patch applies to the first document that has one of its top-level
+/// keys, which for `machine`/`cluster` patches is the v1alpha1 document.
+pub fn current_value(config_yaml: &str, patch_yaml: &str) -> Result<CurrentValue, String> {
+    let patch: Value =
+        serde_yaml::from_str(patch_yaml).map_err(|e| format!("Invalid patch: {}", e))?;
+    let Some(patch_keys) = patch.as_mapping() else {
+        return Err("Patch is not a YAML mapping".to_string());
+    };
+
+    let mut document = None;
+    for doc in serde_yaml::Deserializer::from_str(config_yaml) {
+        let value =
+            Value::deserialize(doc).map_err(|e| format!("Invalid machine config: {}", e))?;
+        if patch_keys.keys().any(|k| value.get(k).is_some()) {
+            document = Some(value);
+            break;
+        }
+    }
+    let Some(current) = document else {
+        return Ok(CurrentValue {
+            fragment: None,
+            already_applied: false,
+        });
+    };
+
+    let fragment = project(&current, &patch)
+        .map(|value| serde_yaml::to_string(&value))
+        .transpose()
+        .map_err(|e| e.to_string())?;
+    Ok(CurrentValue {
+        fragment,
+        already_applied: is_subset(&patch, &current),
+    })
+}
+
+/// The parts of `current` at the paths `patch` sets
+///
+/// Below a key the patch sets to a non-mapping value, the whole current
+/// value is kept, so a list shows every existing entry.
+fn project(current: &Value, patch: &Value) -> Option<Value> {
+    match (current, patch) {
+        (Value::Mapping(current), Value::Mapping(patch)) => {
+            let projected: Mapping = patch
+                .iter()
+                .filter_map(|(key, patch_value)| {
+                    let value = project(current.get(key)?, patch_value)?;
+                    Some((key.clone(), value))
+                })
+                .collect();
+            (!projected.is_empty()).then_some(Value::Mapping(projected))
+        }
+        (Value::Null, _) => None,
+        _ => Some(current.clone()),
+    }
+}
+
+/// Whether `current` already contains everything in `patch`
+///
+/// List entries only need to appear somewhere in the current list, matching
+/// how machine config patches append to lists.
+fn is_subset(patch: &Value, current: &Value) -> bool {
+    match (patch, current) {
+        (Value::Mapping(patch), Value::Mapping(current)) => patch
+            .iter()
+            .all(|(key, value)| current.get(key).is_some_and(|c| is_subset(value, c))),
+        (Value::Sequence(patch), Value::Sequence(current)) => patch
+            .iter()
+            .all(|item| current.iter().any(|c| is_subset(item, c))),
+        _ => patch == current,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = "\
+version: v1alpha1
+machine:
+  type: worker
+  kernel:
+    modules:
+      - name: br_netfilter
+        parameters:
+          - nf_conntrack_max=131072
+  sysctls:
+    net.core.somaxconn: \"65535\"
+cluster:
+  clusterName: demo
+---
+apiVersion: v1alpha1
+kind: HostnameConfig
+hostname: worker-1
+";
+
+    #[test]
+    fn test_current_value_shows_existing_list() {
+        let current = current_value(
+            CONFIG,
+            "machine:\n  kernel:\n    modules:\n      - name: nvme_tcp",
+        )
+        .unwrap();
+        let fragment = current.fragment.unwrap();
+        assert!(fragment.contains("br_netfilter"));
+        assert!(!fragment.contains("sysctls"));
+        assert!(!fragment.contains("clusterName"));
+        assert!(!current.already_applied);
+    }
+
+    #[test]
+    fn test_current_value_detects_already_applied() {
+        let current = current_value(
+            CONFIG,
+            "machine:\n  kernel:\n    modules:\n      - name: br_netfilter",
+        )
+        .unwrap();
+        assert!(current.already_applied);
+
+        let current = current_value(
+            CONFIG,
+            "machine:\n  sysctls:\n    net.core.somaxconn: \"1024\"",
+        )
+        .unwrap();
+        let fragment = current.fragment.unwrap();
+        assert!(fragment.contains("net.core.somaxconn"));
+        assert!(fragment.contains("65535"));
+        assert!(!fragment.contains("kernel"));
+        assert!(!current.already_applied);
+    }
+
+    #[test]
+    fn test_current_value_unset_field() {
+        let current =
+            current_value(CONFIG, "machine:\n  network:\n    hostname: worker-1").unwrap();
+        assert_eq!(current.fragment, None);
+        assert!(!current.already_applied);
+        assert!(current_value(CONFIG, "- not a mapping").is_err());
+    }
+}
@@ -0,0 +1,375 @@
+//! Bug report generation
+//!
+//! Assembles the node context and the failing/warning checks into a markdown
+//! block that can be pasted straight into an issue. Identifying details
+//! (hostname, node address, IP addresses) can optionally be masked.
+
+use super::{CATEGORY_ORDER, CheckCategory, CheckStatus, DiagnosticsData};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// Placeholder for the node hostname in redacted reports
+const REDACTED_HOST: &str = "<node>";
+/// Placeholder for IP addresses in redacted reports
+const REDACTED_IP: &str = "<ip>";
+
+/// Bug report popup state
+#[derive(Debug, Clone)]
+pub struct BugReportView {
+    /// Whether hostnames and IPs are masked
+    pub redact: bool,
+    /// Scroll offset in lines
+    pub scroll: u16,
+    /// Result of the last copy/save
+    pub status: Option<String>,
+}
+
+impl BugReportView {
+    pub fn new() -> Self {
+        Self {
+            redact: true,
+            scroll: 0,
+            status: None,
+        }
+    }
+}
+
+impl Default for BugReportView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Format a markdown bug report from diagnostics results
+///
+/// Only failing and warning checks are listed; categories in `disabled`
+/// are noted as skipped so a missing section is not mistaken for a pass.
+pub fn format_bug_report(
+    data: &DiagnosticsData,
+    disabled: &BTreeSet<CheckCategory>,
+    redact: bool,
+) -> String {
+    let ctx = &data.context;
+    let or_unknown = |s: &str| {
+        if s.is_empty() {
+            "unknown".to_string()
+        } else {
+            s.to_string()
+        }
+    };
+
+    let mut out = String::new();
+    let _ = writeln!(out, "### talos-pilot diagnostics report");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "| | |");
+    let _ = writeln!(out, "|---|---|");
+    let _ = writeln!(out, "| Node | {} ({}) |", data.hostname, data.address);
+    let _ = writeln!(out, "| Role | {} |", or_unknown(&ctx.node_role));
+    let _ = writeln!(out, "| Talos | {} |", or_unknown(&ctx.talos_version));
+    let _ = writeln!(out, "| Platform | {} |", or_unknown(&ctx.platform));
+    let _ = writeln!(out, "| CNI | {} |", ctx.cni_type.name());
+    let _ = writeln!(out, "| CPUs | {} |", ctx.cpu_count);
+    let _ = writeln!(out, "| talos-pilot | {} |", env!("CARGO_PKG_VERSION"));
+
+    if let Some(err) = &ctx.k8s_error {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "**Kubernetes client error:**");
+        let _ = writeln!(out);
+        let _ = writeln!(out, "```");
+        let _ = writeln!(out, "{}", err.trim_end());
+        let _ = writeln!(out, "```");
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "#### Issues");
+    let mut any_issue = false;
+    for category in CATEGORY_ORDER {
+        if disabled.contains(&category) {
+            continue;
+        }
+        let issues: Vec<_> = data
+            .checks(category)
+            .iter()
+            .filter(|c| matches!(c.status, CheckStatus::Fail | CheckStatus::Warn))
+            .collect();
+        if issues.is_empty() {
+            continue;
+        }
+        any_issue = true;
+        let _ = writeln!(out);
+        let _ = writeln!(out, "**{}**", category.title());
+        for check in issues {
+            let label = if check.status == CheckStatus::Fail {
+                "FAIL"
+            } else {
+                "WARN"
+            };
+            let _ = writeln!(out, "- `{}` {}: {}", label, check.name, check.message);
+            if let Some(details) = &check.details {
+                for line in details.lines().filter(|l| !l.trim().is_empty()) {
+                    let _ = writeln!(out, "  > {}", line.trim_end());
+                }
+            }
+        }
+    }
+    if !any_issue {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "No failing or warning checks.");
+    }
+
+    if !disabled.is_empty() {
+        let skipped: Vec<_> = CATEGORY_ORDER
+            .iter()
+            .filter(|c| disabled.contains(c))
+            .map(|c| c.title())
+            .collect();
+        let _ = writeln!(out);
+        let _ = writeln!(out, "_Skipped categories: {}_", skipped.join(", "));
+    }
+
+    if redact {
+        let mut known = vec![data.hostname.as_str(), ctx.hostname.as_str()];
+        known.extend(ctx.node_endpoint.as_deref());
+        out = redact_text(&out, &known);
+    }
+
+    // A four-backtick fence so the ``` blocks inside don't close it early
+    format!("````markdown\n{}````\n", out)
+}
+
+/// Mask the known hostnames and every IP address in `text`
+///
+/// Hostnames are only replaced as whole words, so a node called `talos`
+/// does not mangle the `talos-pilot` line. Longer names go first so a
+/// hostname that is a prefix of another does not leave a fragment behind.
+fn redact_text(text: &str, hostnames: &[&str]) -> String {
+    let mut names: Vec<&str> = hostnames
+        .iter()
+        .copied()
+        .filter(|h| !h.is_empty() && h.parse::<std::net::IpAddr>().is_err())
+        .collect();
+    names.sort_by_key(|h| std::cmp::Reverse(h.len()));
+    names.dedup();
+
+    let mut out = text.to_string();
+    for name in names {
+        out = replace_word(&out, name, REDACTED_HOST);
+    }
+    // IPv6 first, so an IPv4-mapped address is masked as a whole
+    redact_ipv4(&redact_ipv6(&out))
+}
+
+/// Replace occurrences of `word` not embedded in a longer name
+fn replace_word(text: &str, word: &str, with: &str) -> String {
+    let is_name_char = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find(word) {
+        let end = pos + word.len();
+        let before_ok = !rest[..pos].chars().next_back().is_some_and(is_name_char);
+        let after_ok = !rest[end..].chars().next().is_some_and(is_name_char);
+        out.push_str(&rest[..pos]);
+        out.push_str(if before_ok && after_ok {
+            with
+        } else {
+            &rest[pos..end]
+        });
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Replace every dotted-quad IPv4 address with a placeholder
+fn redact_ipv4(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let starts_token =
+            i == 0 || !(bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b'.');
+        if starts_token && bytes[i].is_ascii_digit() {
+            let end = bytes[i..]
+                .iter()
+                .position(|b| !(b.is_ascii_digit() || *b == b'.'))
+                .map_or(bytes.len(), |p| i + p);
+            let candidate = text[i..end].trim_end_matches('.');
+            let followed_by_word = bytes
+                .get(i + candidate.len())
+                .is_some_and(|b| b.is_ascii_alphabetic());
+            if is_ipv4(candidate) && !followed_by_word {
+                out.push_str(REDACTED_IP);
+                i += candidate.len();
+                continue;
+            }
+            out.push_str(&text[i..end]);
+            i = end;
+            continue;
+        }
+        let ch = text[i..].chars().next().unwrap_or_default();
+        out.push(ch);
+        i += ch.len_utf8().max(1);
+    }
+    out
+}
+
+/// Replace every IPv6 address (including compressed and IPv4-mapped forms)
+/// with a placeholder
+fn redact_ipv6(text: &str) -> String {
+    let is_addr_byte = |b: u8| b.is_ascii_hexdigit() || b == b':' || b == b'.';
+    let bytes = text.as_bytes();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let starts_token =
+            i == 0 || !(bytes[i - 1].is_ascii_alphanumeric() || is_addr_byte(bytes[i - 1]));
+        if starts_token && is_addr_byte(bytes[i]) {
+            let end = bytes[i..]
+                .iter()
+                .position(|b| !is_addr_byte(*b))
+                .map_or(bytes.len(), |p| i + p);
+            let run = &text[i..end];
+            // A trailing `.` or `:` may be punctuation rather than part of the address
+            let candidate = if is_ipv6(run) {
+                run
+            } else {
+                run.trim_end_matches(['.', ':'])
+            };
+            let followed_by_word = bytes
+                .get(i + candidate.len())
+                .is_some_and(|b| b.is_ascii_alphabetic());
+            if is_ipv6(candidate) && !followed_by_word {
+                out.push_str(REDACTED_IP);
+                i += candidate.len();
+                continue;
+            }
+            out.push_str(run);
+            i = end;
+            continue;
+        }
+        let ch = text[i..].chars().next().unwrap_or_default();
+        out.push(ch);
+        i += ch.len_utf8().max(1);
+    }
+    out
+}
+
+/// Whether `s` is an IPv6 address with at least one hex digit (so a bare
+/// `::` or a clock time is left alone)
+fn is_ipv6(s: &str) -> bool {
+    s.bytes().any(|b| b.is_ascii_hexdigit()) && s.parse::<std::net::Ipv6Addr>().is_ok()
+}
+
+/// Whether `s` is exactly a dotted-quad IPv4 address
+fn is_ipv4(s: &str) -> bool {
+    s.parse::<std::net::Ipv4Addr>().is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{DiagnosticCheck, DiagnosticContext};
+    use super::*;
+
+    fn check(id: &str, status: CheckStatus, message: &str) -> DiagnosticCheck {
+        DiagnosticCheck {
+            id: id.to_string(),
+            name: id.to_string(),
+            status,
+            message: message.to_string(),
+            details: None,
+            fix: None,
+        }
+    }
+
+    fn sample() -> DiagnosticsData {
+        let mut context = DiagnosticContext::new();
+        context.hostname = "cp-1".to_string();
+        context.node_endpoint = Some("10.5.0.2".to_string());
+        context.node_role = "controlplane".to_string();
+        context.talos_version = "v1.9.2".to_string();
+        context.platform = "metal".to_string();
+        context.k8s_error = Some("dial tcp 10.5.0.2:6443: connection refused".to_string());
+        DiagnosticsData {
+            hostname: "cp-1".to_string(),
+            address: "10.5.0.2".to_string(),
+            context,
+            system_checks: vec![
+                check("memory", CheckStatus::Pass, "2 GB / 4 GB"),
+                check("br_netfilter", CheckStatus::Fail, "Module not loaded"),
+            ],
+            service_checks: vec![check("etcd", CheckStatus::Warn, "peer 10.5.0.3 slow")],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_format_bug_report_lists_context_and_issues() {
+        let report = format_bug_report(&sample(), &BTreeSet::new(), false);
+        assert!(report.starts_with("````markdown\n"));
+        assert!(report.ends_with("````\n"));
+        // The inner error block doesn't end the outer fence
+        assert_eq!(report.matches("\n````").count(), 1);
+        assert!(report.contains("| Talos | v1.9.2 |"));
+        assert!(report.contains("| Platform | metal |"));
+        assert!(report.contains("| Node | cp-1 (10.5.0.2) |"));
+        assert!(report.contains("- `FAIL` br_netfilter: Module not loaded"));
+        assert!(report.contains("- `WARN` etcd: peer 10.5.0.3 slow"));
+        assert!(report.contains("connection refused"));
+        assert!(!report.contains("memory"));
+    }
+
+    #[test]
+    fn test_format_bug_report_redacts_hosts_and_ips() {
+        let report = format_bug_report(&sample(), &BTreeSet::new(), true);
+        assert!(!report.contains("cp-1"));
+        assert!(!report.contains("10.5.0."));
+        assert!(report.contains("| Node | <node> (<ip>) |"));
+        assert!(report.contains("dial tcp <ip>:6443"));
+        // Versions are not mistaken for addresses
+        assert!(report.contains("v1.9.2"));
+    }
+
+    #[test]
+    fn test_format_bug_report_notes_skipped_categories() {
+        let disabled = BTreeSet::from([CheckCategory::Services]);
+        let report = format_bug_report(&sample(), &disabled, false);
+        assert!(!report.contains("etcd"));
+        assert!(report.contains("_Skipped categories: Services_"));
+    }
+
+    #[test]
+    fn test_redact_text_whole_words_only() {
+        assert_eq!(
+            redact_text("talos-pilot on talos (talos.lan)", &["talos"]),
+            "talos-pilot on <node> (<node>.lan)"
+        );
+    }
+
+    #[test]
+    fn test_redact_ipv4_boundaries() {
+        assert_eq!(redact_ipv4("a 192.168.1.10."), "a <ip>.");
+        assert_eq!(redact_ipv4("1.2.3.4.5"), "1.2.3.4.5");
+        assert_eq!(redact_ipv4("v10.0.0.1"), "v10.0.0.1");
+        assert_eq!(redact_ipv4("[10.0.0.1]:50000"), "[<ip>]:50000");
+        assert_eq!(redact_ipv4("999.1.1.1 ok"), "999.1.1.1 ok");
+    }
+
+    #[test]
+    fn test_redact_ipv6() {
+        assert_eq!(
+            redact_text("dial tcp [fd00::2]:6443: refused", &[]),
+            "dial tcp [<ip>]:6443: refused"
+        );
+        assert_eq!(
+            redact_text("peer 2001:db8:0:0:0:0:0:1, fe80::1.", &[]),
+            "peer <ip>, <ip>."
+        );
+        assert_eq!(redact_text("mapped ::ffff:10.0.0.1", &[]), "mapped <ip>");
+        // Times, bare `::` and hex words are not addresses
+        assert_eq!(
+            redact_text("at 12:30:45 on [::] with cafe", &[]),
+            "at 12:30:45 on [::] with cafe"
+        );
+        assert_eq!(redact_text("node fd00::5", &["fd00::5"]), "node <ip>");
+    }
+}
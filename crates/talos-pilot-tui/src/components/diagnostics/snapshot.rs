@@ -0,0 +1,134 @@
+//! Diagnostics snapshots
+//!
+//! Saves diagnostics results to a JSON file and loads them back into a
+//! read-only view, for before/after comparisons around a change. Unlike the
+//! `--check` JSON output, a snapshot keeps the full `DiagnosticsData` (node
+//! context, fixes, detected addons) so the view looks the same on reload.
+
+use super::DiagnosticsData;
+use chrono::{DateTime, Utc};
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Snapshot file format version, bumped on incompatible changes
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Diagnostics results of one or more nodes, as saved to disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticsSnapshot {
+    /// File format version
+    pub version: u32,
+    /// When the snapshot was taken
+    pub taken_at: DateTime<Utc>,
+    /// Results per node, in display order
+    pub nodes: Vec<DiagnosticsData>,
+}
+
+impl DiagnosticsSnapshot {
+    /// Snapshot of `nodes` taken now
+    pub fn new(nodes: Vec<DiagnosticsData>) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            taken_at: Utc::now(),
+            nodes,
+        }
+    }
+
+    /// Parse a snapshot, rejecting empty ones and unknown format versions
+    pub fn from_json(json: &str) -> Result<Self> {
+        let snapshot: Self = serde_json::from_str(json)?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(eyre!(
+                "unsupported snapshot version {} (expected {})",
+                snapshot.version,
+                SNAPSHOT_VERSION
+            ));
+        }
+        if snapshot.nodes.is_empty() {
+            return Err(eyre!("snapshot contains no nodes"));
+        }
+        Ok(snapshot)
+    }
+
+    /// Write the snapshot to `path` as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).map_err(|e| eyre!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// Read a snapshot saved by [`DiagnosticsSnapshot::save`]
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| eyre!("Failed to read {}: {}", path.display(), e))?;
+        Self::from_json(&json).map_err(|e| eyre!("Invalid snapshot {}: {}", path.display(), e))
+    }
+
+    /// Default file for the snapshot, in the temp dir next to bug reports
+    pub fn default_path(&self) -> PathBuf {
+        let host = match self.nodes.as_slice() {
+            [node] => node.hostname.as_str(),
+            _ => "group",
+        };
+        let filename = format!(
+            "talos-pilot-diagnostics-{}_{}.json",
+            host,
+            self.taken_at.timestamp()
+        );
+        std::env::temp_dir().join(filename)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::diagnostics::{CheckStatus, DiagnosticCheck, DiagnosticFix, FixAction};
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let mut data = DiagnosticsData {
+            hostname: "worker-1".to_string(),
+            address: "10.0.0.21".to_string(),
+            ..Default::default()
+        };
+        data.context.platform = "metal".to_string();
+        data.system_checks.push(DiagnosticCheck::fail(
+            "br_netfilter",
+            "br_netfilter",
+            "Missing",
+            Some(DiagnosticFix {
+                description: "Add br_netfilter kernel module".to_string(),
+                action: FixAction::AddKernelModule("br_netfilter".to_string()),
+            }),
+        ));
+        let snapshot = DiagnosticsSnapshot::new(vec![data]);
+        assert!(snapshot.default_path().ends_with(format!(
+            "talos-pilot-diagnostics-worker-1_{}.json",
+            snapshot.taken_at.timestamp()
+        )));
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let loaded = DiagnosticsSnapshot::from_json(&json).unwrap();
+        assert_eq!(loaded.taken_at, snapshot.taken_at);
+        let node = &loaded.nodes[0];
+        assert_eq!(node.context.platform, "metal");
+        let check = &node.system_checks[0];
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(matches!(
+            check.fix.as_ref().map(|f| &f.action),
+            Some(FixAction::AddKernelModule(m)) if m == "br_netfilter"
+        ));
+    }
+
+    #[test]
+    fn test_snapshot_rejects_unknown_version() {
+        let mut snapshot = DiagnosticsSnapshot::new(vec![DiagnosticsData::default()]);
+        snapshot.version = SNAPSHOT_VERSION + 1;
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert!(DiagnosticsSnapshot::from_json(&json).is_err());
+
+        let empty = serde_json::to_string(&DiagnosticsSnapshot::new(Vec::new())).unwrap();
+        assert!(DiagnosticsSnapshot::from_json(&empty).is_err());
+    }
+}
@@ -3,13 +3,18 @@
 //! Core types (CheckStatus, CniType, etc.) are imported from talos-pilot-core.
 //! This module contains UI-specific types for fixes and diagnostic context.
 
+use super::k8s::{NodePodCidrs, NodeSchedulingInfo, PendingCsrInfo};
+use super::preview::CurrentValue;
+use serde::{Deserialize, Serialize};
+use talos_rs::ApplyMode;
+
 // Re-export core types for use by diagnostics modules
 pub use talos_pilot_core::{
     CheckCategory, CheckStatus, CniInfo, CniPodInfo, CniType, PodHealthInfo, UnhealthyPodInfo,
 };
 
 /// Action that can be taken to fix an issue
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum FixAction {
     /// Add a kernel module via machine config
     AddKernelModule(String),
@@ -65,10 +70,76 @@ impl FixAction {
     pub fn is_host_command(&self) -> bool {
         matches!(self, FixAction::HostCommand { .. })
     }
+
+    /// Buttons offered in the confirmation dialog, Cancel first
+    ///
+    /// Machine config changes can also be staged for the next reboot, so the
+    /// user decides when the disruption happens.
+    pub fn confirm_choices(&self) -> Vec<ConfirmChoice> {
+        match self {
+            FixAction::AddKernelModule(_)
+            | FixAction::ApplyConfigPatch {
+                requires_reboot: true,
+                ..
+            } => vec![
+                ConfirmChoice::Cancel,
+                ConfirmChoice::ApplyAndReboot,
+                ConfirmChoice::DrainAndReboot,
+                ConfirmChoice::Stage,
+            ],
+            FixAction::ApplyConfigPatch { .. } => vec![
+                ConfirmChoice::Cancel,
+                ConfirmChoice::Apply,
+                ConfirmChoice::ApplyAndReboot,
+                ConfirmChoice::Stage,
+            ],
+            _ => vec![ConfirmChoice::Cancel, ConfirmChoice::Apply],
+        }
+    }
+}
+
+/// A button in the fix confirmation dialog
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmChoice {
+    /// Close the dialog without changes
+    Cancel,
+    /// Apply now, rebooting only if Talos decides it must
+    Apply,
+    /// Apply and reboot immediately
+    ApplyAndReboot,
+    /// Cordon and drain the node, apply and reboot, uncordon once it's Ready
+    DrainAndReboot,
+    /// Stage the change; it takes effect on the next reboot
+    Stage,
+}
+
+impl ConfirmChoice {
+    /// Button label
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConfirmChoice::Cancel => " Cancel ",
+            ConfirmChoice::Apply => " Apply ",
+            ConfirmChoice::ApplyAndReboot => " Apply & Reboot ",
+            ConfirmChoice::DrainAndReboot => " Drain & Reboot ",
+            ConfirmChoice::Stage => " Stage ",
+        }
+    }
+
+    /// Config apply mode for this choice (None for Cancel)
+    pub fn apply_mode(&self) -> Option<ApplyMode> {
+        match self {
+            ConfirmChoice::Cancel => None,
+            ConfirmChoice::Apply => Some(ApplyMode::Auto),
+            ConfirmChoice::ApplyAndReboot | ConfirmChoice::DrainAndReboot => {
+                Some(ApplyMode::Reboot)
+            }
+            ConfirmChoice::Stage => Some(ApplyMode::Staged),
+        }
+    }
 }
 
 /// A diagnostic fix with description
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiagnosticFix {
     /// Description of what this fix does
     pub description: String,
@@ -76,8 +147,37 @@ pub struct DiagnosticFix {
     pub action: FixAction,
 }
 
+/// How a check's status moved between two refreshes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusChange {
+    /// Moved towards passing (e.g. Fail -> Warn, Warn -> Pass)
+    Recovered,
+    /// Moved away from passing (e.g. Pass -> Warn, Warn -> Fail)
+    Regressed,
+}
+
+impl StatusChange {
+    /// Compare two statuses of the same check
+    ///
+    /// `Unknown` and `Checking` carry no verdict, so transitions to or
+    /// from them are not reported.
+    pub fn between(old: &CheckStatus, new: &CheckStatus) -> Option<Self> {
+        let severity = |status: &CheckStatus| match status {
+            CheckStatus::Pass => Some(0),
+            CheckStatus::Warn => Some(1),
+            CheckStatus::Fail => Some(2),
+            CheckStatus::Unknown | CheckStatus::Checking => None,
+        };
+        match severity(old)?.cmp(&severity(new)?) {
+            std::cmp::Ordering::Greater => Some(StatusChange::Recovered),
+            std::cmp::Ordering::Less => Some(StatusChange::Regressed),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
+
 /// A single diagnostic check result
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiagnosticCheck {
     /// Unique identifier for this check
     pub id: String,
@@ -164,11 +264,38 @@ pub struct PendingAction {
     pub fix: DiagnosticFix,
     /// Preview YAML (for config patches)
     pub preview: Option<String>,
+    /// The node's current value for the fields the preview sets, once
+    /// fetched
+    pub current: Option<Result<CurrentValue, String>>,
+    /// How to apply it, set when the user confirms
+    pub choice: ConfirmChoice,
+}
+
+/// Logs drill-down for unhealthy pods from the pod health check
+#[derive(Debug, Clone, Default)]
+pub struct PodLogsView {
+    /// Unhealthy pods (crashing first, then image pull errors)
+    pub pods: Vec<UnhealthyPodInfo>,
+    /// Index of the pod whose logs are shown
+    pub selected: usize,
+    /// Log lines for the selected pod
+    pub logs: talos_pilot_core::AsyncState<Vec<String>>,
+    /// Scroll offset in lines
+    pub scroll: u16,
+}
+
+impl PodLogsView {
+    /// Currently selected pod
+    pub fn selected_pod(&self) -> Option<&UnhealthyPodInfo> {
+        self.pods.get(self.selected)
+    }
 }
 
 /// Context passed to diagnostic providers
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiagnosticContext {
+    /// Talos version reported by the node (e.g., "v1.9.2")
+    pub talos_version: String,
     /// Platform type (e.g., "container", "metal", "aws")
     pub platform: String,
     /// Whether running in a container environment
@@ -185,8 +312,21 @@ pub struct DiagnosticContext {
     pub cni_info: Option<CniInfo>,
     /// Pod health information from K8s API (if available)
     pub pod_health: Option<PodHealthInfo>,
+    /// Pending kubelet serving CSRs from K8s API (if available)
+    pub pending_kubelet_csrs: Option<Vec<PendingCsrInfo>>,
+    /// Pod CIDRs of every node from K8s API (if available)
+    pub node_pod_cidrs: Option<Vec<NodePodCidrs>>,
+    /// This node's labels and taints from K8s API (if available);
+    /// `Some(None)` means the node isn't registered
+    pub node_scheduling: Option<Option<NodeSchedulingInfo>>,
     /// Number of CPU cores (for load threshold scaling)
     pub cpu_count: usize,
+    /// Distinct boot times seen across refreshes, oldest first
+    #[serde(default)]
+    pub boot_times: Vec<u64>,
+    /// Control plane endpoint that served the kubeconfig (worker nodes)
+    #[serde(default)]
+    pub kubeconfig_endpoint: Option<String>,
     /// K8s client error (if client creation failed)
     pub k8s_error: Option<String>,
 }
@@ -194,6 +334,7 @@ pub struct DiagnosticContext {
 impl DiagnosticContext {
     pub fn new() -> Self {
         Self {
+            talos_version: String::new(),
             platform: String::new(),
             is_container: false,
             cni_type: CniType::Unknown,
@@ -202,7 +343,12 @@ impl DiagnosticContext {
             node_endpoint: None,
             cni_info: None,
             pod_health: None,
+            pending_kubelet_csrs: None,
+            node_pod_cidrs: None,
+            node_scheduling: None,
             cpu_count: 1,
+            boot_times: Vec::new(),
+            kubeconfig_endpoint: None,
             k8s_error: None,
         }
     }
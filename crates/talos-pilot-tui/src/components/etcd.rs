@@ -3,8 +3,8 @@
 //! Displays etcd cluster health with member list and details.
 
 use crate::action::Action;
-use crate::components::Component;
-use crate::ui_ext::QuorumStateExt;
+use crate::components::{Component, help_entries};
+use crate::ui_ext::{QuorumStateExt, empty_state, glyphs, manual_refresh};
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -16,7 +16,7 @@ use ratatui::{
 };
 use std::time::Duration;
 use talos_pilot_core::{
-    AsyncState, QuorumState, SelectableList, format_bytes_signed, format_talos_error,
+    AsyncState, ComponentError, QuorumState, SelectableList, format_bytes_signed,
 };
 use talos_rs::{EtcdAlarm, EtcdMemberInfo, EtcdMemberStatus, TalosClient};
 
@@ -87,7 +87,7 @@ impl EtcdComponent {
     }
 
     /// Set an error message
-    pub fn set_error(&mut self, error: String) {
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
         self.state.set_error(error);
     }
 
@@ -113,16 +113,14 @@ impl EtcdComponent {
         let member_infos = match members_result {
             Ok(Ok(members)) => members,
             Ok(Err(e)) => {
-                let msg = format_talos_error(&e);
-                self.state
-                    .set_error_with_retry(format!("Failed to fetch members: {}", msg));
+                self.state.set_error_with_retry(
+                    ComponentError::from(&e).context("Failed to fetch members"),
+                );
                 return Ok(());
             }
             Err(_) => {
-                self.state.set_error_with_retry(format!(
-                    "Request timed out after {}s",
-                    timeout.as_secs()
-                ));
+                self.state
+                    .set_error_with_retry(ComponentError::timeout(timeout.as_secs()));
                 return Ok(());
             }
         };
@@ -141,10 +139,8 @@ impl EtcdComponent {
         let (status_result, alarms_result) = match fetch_result {
             Ok(results) => results,
             Err(_) => {
-                self.state.set_error_with_retry(format!(
-                    "Request timed out after {}s",
-                    timeout.as_secs()
-                ));
+                self.state
+                    .set_error_with_retry(ComponentError::timeout(timeout.as_secs()));
                 return Ok(());
             }
         };
@@ -295,6 +291,14 @@ impl EtcdComponent {
         let Some(data) = self.data() else {
             return;
         };
+        if data.members.is_empty() {
+            let empty = empty_state(
+                "No etcd members found",
+                Some("etcd may not be bootstrapped on this cluster yet"),
+            );
+            frame.render_widget(empty, area);
+            return;
+        }
 
         let rows: Vec<Row> = data
             .members
@@ -521,7 +525,10 @@ impl EtcdComponent {
                 .iter()
                 .flat_map(|a| {
                     vec![
-                        Span::styled("⚠ ", Style::default().fg(Color::Yellow)),
+                        Span::styled(
+                            format!("{} ", glyphs().warning),
+                            Style::default().fg(Color::Yellow),
+                        ),
                         Span::styled(
                             format!("{}: {} ", a.alarm_type.as_str(), a.node),
                             Style::default().fg(Color::Yellow),
@@ -568,6 +575,17 @@ impl EtcdComponent {
 }
 
 impl Component for EtcdComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Navigate"),
+            ("Enter", "Etcd logs for selected member"),
+            ("l", "Etcd logs for all members"),
+            ("a", "Toggle auto-refresh"),
+            ("r", "Refresh"),
+            ("q/Esc", "Back"),
+        ])
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => Ok(Some(Action::Back)),
@@ -579,7 +597,7 @@ impl Component for EtcdComponent {
                 self.select_prev();
                 Ok(None)
             }
-            KeyCode::Char('r') => Ok(Some(Action::Refresh)),
+            KeyCode::Char('r') => Ok(manual_refresh(&self.state)),
             KeyCode::Char('a') => {
                 // Toggle auto-refresh
                 self.auto_refresh = !self.auto_refresh;
@@ -0,0 +1,453 @@
+//! Kubernetes events component
+//!
+//! Displays recent Warning-type Kubernetes events (FailedScheduling, BackOff,
+//! Unhealthy, ...) across all namespaces, most recently seen first.
+//! Complements the pod health check in diagnostics.
+
+use crate::action::Action;
+use crate::components::{Component, help_entries};
+use crate::ui_ext::manual_refresh;
+use chrono::{DateTime, Utc};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use k8s_openapi::api::core::v1::Event;
+use kube::{
+    Client,
+    api::{Api, ListParams},
+};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
+};
+use std::time::Duration;
+use talos_pilot_core::{AsyncState, ComponentError};
+
+/// Auto-refresh interval in seconds
+const AUTO_REFRESH_INTERVAL_SECS: u64 = 15;
+
+/// A Warning-type Kubernetes event
+#[derive(Debug, Clone)]
+pub struct WarningEvent {
+    pub namespace: String,
+    /// Involved object as "Kind/name"
+    pub object: String,
+    pub reason: String,
+    pub message: String,
+    /// Number of times the event occurred
+    pub count: i32,
+    /// When the event was last observed
+    pub last_seen: Option<DateTime<Utc>>,
+}
+
+impl WarningEvent {
+    /// Convert a K8s event, using the most specific timestamp available
+    pub fn from_event(event: &Event) -> Self {
+        let object = match (&event.involved_object.kind, &event.involved_object.name) {
+            (Some(kind), Some(name)) => format!("{}/{}", kind, name),
+            (None, Some(name)) => name.clone(),
+            _ => "unknown".to_string(),
+        };
+        let last_seen = event
+            .last_timestamp
+            .as_ref()
+            .map(|t| t.0)
+            .or_else(|| event.event_time.as_ref().map(|t| t.0))
+            .or_else(|| event.first_timestamp.as_ref().map(|t| t.0))
+            .or_else(|| event.metadata.creation_timestamp.as_ref().map(|t| t.0));
+
+        Self {
+            namespace: event.metadata.namespace.clone().unwrap_or_default(),
+            object,
+            reason: event.reason.clone().unwrap_or_default(),
+            message: event.message.clone().unwrap_or_default(),
+            count: event.count.unwrap_or(1),
+            last_seen,
+        }
+    }
+}
+
+/// Sort events by last-seen time, newest first (events without a time last)
+pub fn sort_by_last_seen(events: &mut [WarningEvent]) {
+    events.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+}
+
+/// Kubernetes warning events component
+pub struct EventsComponent {
+    /// Async state for loaded events
+    state: AsyncState<Vec<WarningEvent>>,
+
+    /// Kubernetes client
+    k8s_client: Option<Client>,
+    /// Why the Kubernetes API is unavailable (if it is)
+    k8s_error: Option<String>,
+
+    /// Selected event index
+    selected: usize,
+    /// Table state for rendering
+    table_state: TableState,
+
+    /// Auto-refresh enabled
+    auto_refresh: bool,
+}
+
+impl Default for EventsComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventsComponent {
+    pub fn new() -> Self {
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+
+        Self {
+            state: AsyncState::new(),
+            k8s_client: None,
+            k8s_error: None,
+            selected: 0,
+            table_state,
+            auto_refresh: true,
+        }
+    }
+
+    /// Set the Kubernetes client
+    pub fn set_k8s_client(&mut self, client: Client) {
+        self.k8s_client = Some(client);
+        self.k8s_error = None;
+    }
+
+    /// Mark the Kubernetes API as unavailable with the reason
+    pub fn set_k8s_unavailable(&mut self, error: String) {
+        self.k8s_client = None;
+        self.k8s_error = Some(error);
+    }
+
+    /// Set an error message
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
+        self.state.set_error(error);
+    }
+
+    /// Refresh warning events from the cluster
+    pub async fn refresh(&mut self) -> Result<()> {
+        let Some(client) = &self.k8s_client else {
+            let reason = self
+                .k8s_error
+                .clone()
+                .unwrap_or_else(|| "No Kubernetes client configured".to_string());
+            self.set_error(format!("Kubernetes API unavailable: {}", reason));
+            return Ok(());
+        };
+
+        self.state.start_loading();
+
+        let events_api: Api<Event> = Api::all(client.clone());
+        let params = ListParams::default().fields("type=Warning");
+        let timeout = Duration::from_secs(15);
+
+        let events = match tokio::time::timeout(timeout, events_api.list(&params)).await {
+            Ok(Ok(list)) => list,
+            Ok(Err(e)) => {
+                self.set_error(format!("Failed to fetch events: {}", e));
+                return Ok(());
+            }
+            Err(_) => {
+                self.set_error(format!("Request timed out after {}s", timeout.as_secs()));
+                return Ok(());
+            }
+        };
+
+        let mut warnings: Vec<WarningEvent> =
+            events.items.iter().map(WarningEvent::from_event).collect();
+        sort_by_last_seen(&mut warnings);
+
+        self.selected = self.selected.min(warnings.len().saturating_sub(1));
+        self.table_state.select(Some(self.selected));
+        self.state.set_data(warnings);
+        Ok(())
+    }
+
+    fn event_count(&self) -> usize {
+        self.state.data().map(|e| e.len()).unwrap_or(0)
+    }
+
+    fn select_next(&mut self) {
+        let count = self.event_count();
+        if count > 0 {
+            self.selected = (self.selected + 1).min(count - 1);
+            self.table_state.select(Some(self.selected));
+        }
+    }
+
+    fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+        self.table_state.select(Some(self.selected));
+    }
+
+    /// Format age from timestamp
+    fn format_age(ts: Option<&DateTime<Utc>>) -> String {
+        let Some(ts) = ts else {
+            return "-".to_string();
+        };
+        let duration = Utc::now().signed_duration_since(*ts);
+
+        if duration.num_days() > 0 {
+            format!("{}d", duration.num_days())
+        } else if duration.num_hours() > 0 {
+            format!("{}h", duration.num_hours())
+        } else if duration.num_minutes() > 0 {
+            format!("{}m", duration.num_minutes())
+        } else {
+            format!("{}s", duration.num_seconds().max(0))
+        }
+    }
+
+    /// Draw the events table
+    fn draw_table(&mut self, frame: &mut Frame, area: Rect) {
+        let Some(events) = self.state.data() else {
+            return;
+        };
+
+        let rows: Vec<Row> = events
+            .iter()
+            .map(|e| {
+                Row::new(vec![
+                    Cell::from(Self::format_age(e.last_seen.as_ref())),
+                    Cell::from(e.namespace.clone()),
+                    Cell::from(e.object.clone()),
+                    Cell::from(e.reason.clone()).style(Style::default().fg(Color::Yellow)),
+                    Cell::from(format!("{}", e.count)),
+                    Cell::from(e.message.lines().next().unwrap_or("").to_string()),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(5),
+            Constraint::Length(16),
+            Constraint::Percentage(25),
+            Constraint::Length(20),
+            Constraint::Length(5),
+            Constraint::Fill(1),
+        ];
+
+        let header = Row::new(vec![
+            Cell::from("AGE"),
+            Cell::from("NAMESPACE"),
+            Cell::from("OBJECT"),
+            Cell::from("REASON"),
+            Cell::from("COUNT"),
+            Cell::from("MESSAGE"),
+        ])
+        .style(Style::default().add_modifier(Modifier::DIM))
+        .bottom_margin(1);
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .row_highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("> ");
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+
+    /// Draw the full message of the selected event
+    fn draw_message(&self, frame: &mut Frame, area: Rect) {
+        let Some(event) = self.state.data().and_then(|e| e.get(self.selected)) else {
+            return;
+        };
+        let para = Paragraph::new(event.message.clone())
+            .wrap(Wrap { trim: true })
+            .block(Block::default().borders(Borders::TOP).title(" Message "));
+        frame.render_widget(para, area);
+    }
+
+    /// Draw the footer with key hints
+    fn draw_footer(&self, frame: &mut Frame, area: Rect) {
+        let auto_status = if self.auto_refresh {
+            Span::styled("ON ", Style::default().fg(Color::Green))
+        } else {
+            Span::styled("OFF", Style::default().fg(Color::DarkGray))
+        };
+
+        let line = Line::from(vec![
+            Span::styled("[j/k]", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" select  "),
+            Span::styled("[r]", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" refresh  "),
+            Span::styled("[a]", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" auto:"),
+            auto_status,
+            Span::raw("  "),
+            Span::styled("[q]", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" back"),
+        ]);
+        frame.render_widget(Paragraph::new(line), area);
+    }
+}
+
+impl Component for EventsComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Navigate"),
+            ("a", "Toggle auto-refresh"),
+            ("r", "Refresh"),
+            ("q/Esc", "Back"),
+        ])
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => Ok(Some(Action::Back)),
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.select_next();
+                Ok(None)
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.select_prev();
+                Ok(None)
+            }
+            KeyCode::Char('r') => Ok(manual_refresh(&self.state)),
+            KeyCode::Char('a') => {
+                self.auto_refresh = !self.auto_refresh;
+                Ok(None)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        if let Action::Tick = action {
+            // No point polling while the Kubernetes API is unavailable
+            let interval = Duration::from_secs(AUTO_REFRESH_INTERVAL_SECS);
+            if self.k8s_client.is_some()
+                && self.state.should_auto_refresh(self.auto_refresh, interval)
+            {
+                return Ok(Some(Action::Refresh));
+            }
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        let block = Block::default()
+            .title(" Kubernetes Warning Events ")
+            .borders(Borders::ALL);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::vertical([
+            Constraint::Fill(1),   // Table
+            Constraint::Length(4), // Selected message
+            Constraint::Length(1), // Footer
+        ])
+        .split(inner);
+
+        self.draw_footer(frame, chunks[2]);
+
+        if self.state.is_loading() && !self.state.has_data() {
+            let loading =
+                Paragraph::new("Loading events...").style(Style::default().fg(Color::DarkGray));
+            frame.render_widget(loading, chunks[0]);
+            return Ok(());
+        }
+
+        if let Some(err) = self.state.error() {
+            let error = Paragraph::new(format!("Error: {}", err))
+                .style(Style::default().fg(Color::Red))
+                .wrap(Wrap { trim: true });
+            frame.render_widget(error, chunks[0]);
+            return Ok(());
+        }
+
+        if self.event_count() == 0 {
+            let empty =
+                Paragraph::new("No warning events").style(Style::default().fg(Color::Green));
+            frame.render_widget(empty, chunks[0]);
+            return Ok(());
+        }
+
+        self.draw_table(frame, chunks[0]);
+        self.draw_message(frame, chunks[1]);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+    fn event(name: &str, minutes_ago: Option<i64>) -> WarningEvent {
+        WarningEvent {
+            namespace: "default".to_string(),
+            object: format!("Pod/{}", name),
+            reason: "BackOff".to_string(),
+            message: "Back-off restarting failed container".to_string(),
+            count: 1,
+            last_seen: minutes_ago.map(|m| Utc::now() - chrono::Duration::minutes(m)),
+        }
+    }
+
+    #[test]
+    fn test_refresh_key_waits_for_load() {
+        let mut events = EventsComponent::new();
+        let r = || KeyEvent::from(KeyCode::Char('r'));
+
+        // The initial load is still in flight
+        assert!(events.handle_key_event(r()).unwrap().is_none());
+
+        // A failed load can be retried right away
+        events.state.set_error("boom");
+        assert!(matches!(
+            events.handle_key_event(r()).unwrap(),
+            Some(Action::Refresh)
+        ));
+
+        // Just refreshed: say so instead of refreshing again
+        events.state.set_data(Vec::new());
+        assert!(matches!(
+            events.handle_key_event(r()).unwrap(),
+            Some(Action::Notify(..))
+        ));
+    }
+
+    #[test]
+    fn test_sort_by_last_seen() {
+        let mut events = vec![
+            event("old", Some(30)),
+            event("untimed", None),
+            event("new", Some(1)),
+        ];
+        sort_by_last_seen(&mut events);
+        let order: Vec<&str> = events.iter().map(|e| e.object.as_str()).collect();
+        assert_eq!(order, vec!["Pod/new", "Pod/old", "Pod/untimed"]);
+    }
+
+    #[test]
+    fn test_from_event() {
+        let now = Utc::now();
+        let mut k8s_event = Event::default();
+        k8s_event.metadata.namespace = Some("kube-system".to_string());
+        k8s_event.involved_object.kind = Some("Pod".to_string());
+        k8s_event.involved_object.name = Some("coredns-abc".to_string());
+        k8s_event.reason = Some("Unhealthy".to_string());
+        k8s_event.message = Some("Readiness probe failed".to_string());
+        k8s_event.count = Some(4);
+        k8s_event.last_timestamp = Some(Time(now));
+
+        let warning = WarningEvent::from_event(&k8s_event);
+        assert_eq!(warning.namespace, "kube-system");
+        assert_eq!(warning.object, "Pod/coredns-abc");
+        assert_eq!(warning.reason, "Unhealthy");
+        assert_eq!(warning.count, 4);
+        assert_eq!(warning.last_seen, Some(now));
+    }
+}
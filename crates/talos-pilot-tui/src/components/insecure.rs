@@ -6,6 +6,11 @@
 
 use crate::action::Action;
 use crate::components::Component;
+use crate::components::wizard::{
+    cluster_output_dir, edit_cluster_name, explicit_port, maintenance_candidates,
+};
+use crate::state::default_config_dir;
+use crate::ui_ext::manual_refresh;
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -18,7 +23,8 @@ use ratatui::{
 use talos_pilot_core::AsyncState;
 use talos_rs::{
     DiskInfo, GenConfigResult, InsecureVersionInfo, VolumeStatus, apply_config_insecure,
-    gen_config, get_disks_insecure, get_version_insecure, get_volume_status_insecure,
+    existing_config_files, gen_config, get_disks_insecure, get_version_insecure,
+    get_volume_status_insecure,
 };
 
 /// View mode for the insecure component
@@ -49,6 +55,8 @@ pub enum DialogMode {
         k8s_endpoint: String,
         output_dir: String,
         active_field: usize, // 0=cluster_name, 1=k8s_endpoint, 2=output_dir
+        /// Config files already in output_dir; Enter again overwrites them
+        overwrite: Vec<&'static str>,
     },
     /// Apply config dialog with field: config_path
     ApplyConfig {
@@ -108,8 +116,12 @@ pub enum ConfirmAction {
 /// Data loaded in insecure mode
 #[derive(Debug, Clone, Default)]
 pub struct InsecureData {
-    /// Endpoint address
+    /// Endpoint address (host:port that answered)
     pub endpoint: String,
+    /// Port the maintenance API answered on
+    pub port: Option<u16>,
+    /// Whether the port was found by probing rather than given explicitly
+    pub port_detected: bool,
     /// Version info (if available)
     pub version: Option<InsecureVersionInfo>,
     /// Physical disks
@@ -142,6 +154,9 @@ pub struct InsecureComponent {
 
     /// Last generated config result (for apply default path)
     last_gen_result: Option<GenConfigResult>,
+
+    /// Directory configs are generated under, one subdirectory per cluster
+    output_base: String,
 }
 
 impl InsecureComponent {
@@ -164,6 +179,7 @@ impl InsecureComponent {
             disk_table_state,
             volume_table_state,
             last_gen_result: None,
+            output_base: default_config_dir(),
         }
     }
 
@@ -185,16 +201,58 @@ impl InsecureComponent {
         Self::endpoint_for_talosctl(&self.endpoint)
     }
 
+    /// Endpoint (with port) that answered on the last connect
+    fn connected_endpoint(&self) -> String {
+        self.data()
+            .filter(|d| d.connected)
+            .map(|d| d.endpoint.clone())
+            .unwrap_or_else(|| self.endpoint_ip())
+    }
+
     /// Connect and load data
     pub async fn connect(&mut self) -> Result<()> {
         self.state.start_loading();
 
-        let endpoint = Self::endpoint_for_talosctl(&self.endpoint);
+        let candidates = maintenance_candidates(&self.endpoint);
+        let port_detected = explicit_port(&self.endpoint).is_none();
         let mut data = InsecureData {
-            endpoint: endpoint.clone(),
+            endpoint: self.endpoint.clone(),
+            port_detected,
             ..Default::default()
         };
 
+        // Disks are available in maintenance mode, so they double as the reachability probe
+        let mut last_error = None;
+        for (endpoint, port) in &candidates {
+            match get_disks_insecure(endpoint).await {
+                Ok(disks) => {
+                    tracing::info!("Maintenance API answered on {}", endpoint);
+                    data.endpoint = endpoint.clone();
+                    data.port = Some(*port);
+                    data.disks = disks;
+                    data.connected = true;
+                    break;
+                }
+                Err(e) => {
+                    tracing::debug!("No maintenance API on {}: {}", endpoint, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        if !data.connected {
+            let ports: Vec<String> = candidates.iter().map(|(_, p)| p.to_string()).collect();
+            let error = last_error.map(|e| e.to_string()).unwrap_or_default();
+            self.state.set_error(if candidates.len() > 1 {
+                format!("Failed to connect on ports {}: {}", ports.join(", "), error)
+            } else {
+                format!("Failed to connect: {}", error)
+            });
+            return Ok(());
+        }
+
+        let endpoint = data.endpoint.clone();
+
         match get_version_insecure(&endpoint).await {
             Ok(version) => {
                 data.version = Some(version);
@@ -204,17 +262,6 @@ impl InsecureComponent {
             }
         }
 
-        match get_disks_insecure(&endpoint).await {
-            Ok(disks) => {
-                data.disks = disks;
-                data.connected = true;
-            }
-            Err(e) => {
-                self.state.set_error(format!("Failed to connect: {}", e));
-                return Ok(());
-            }
-        }
-
         match get_volume_status_insecure(&endpoint).await {
             Ok(volumes) => {
                 data.volumes = volumes;
@@ -271,7 +318,7 @@ impl InsecureComponent {
 
     /// Apply config to the node
     pub async fn do_apply_config(&mut self, config_path: &str) {
-        let endpoint = self.endpoint_ip();
+        let endpoint = self.connected_endpoint();
 
         match apply_config_insecure(&endpoint, config_path).await {
             Ok(result) => {
@@ -298,11 +345,13 @@ impl InsecureComponent {
     /// Open generate config dialog with smart defaults
     fn open_generate_dialog(&mut self) {
         let endpoint_ip = self.endpoint_ip();
+        let cluster_name = "talos-cluster".to_string();
         self.dialog_mode = DialogMode::GenerateConfig {
-            cluster_name: "talos-cluster".to_string(),
+            output_dir: cluster_output_dir(&self.output_base, &cluster_name),
+            cluster_name,
             k8s_endpoint: format!("https://{}:6443", endpoint_ip),
-            output_dir: ".".to_string(),
             active_field: 0,
+            overwrite: Vec::new(),
         };
     }
 
@@ -392,6 +441,7 @@ impl InsecureComponent {
                 k8s_endpoint,
                 output_dir,
                 active_field,
+                overwrite,
             } => {
                 match key.code {
                     KeyCode::Esc => {
@@ -411,6 +461,13 @@ impl InsecureComponent {
                         None
                     }
                     KeyCode::Enter => {
+                        // Existing files hold the cluster's secrets; ask once
+                        if overwrite.is_empty() {
+                            *overwrite = existing_config_files(output_dir);
+                            if !overwrite.is_empty() {
+                                return None;
+                            }
+                        }
                         // Trigger generate action
                         Some(Action::InsecureGenConfig(
                             cluster_name.clone(),
@@ -419,21 +476,37 @@ impl InsecureComponent {
                         ))
                     }
                     KeyCode::Char(c) => {
-                        let field = match *active_field {
-                            0 => cluster_name,
-                            1 => k8s_endpoint,
-                            _ => output_dir,
-                        };
-                        field.push(c);
+                        overwrite.clear();
+                        match *active_field {
+                            0 => edit_cluster_name(
+                                cluster_name,
+                                output_dir,
+                                &self.output_base,
+                                |name| name.push(c),
+                            ),
+                            1 => k8s_endpoint.push(c),
+                            _ => output_dir.push(c),
+                        }
                         None
                     }
                     KeyCode::Backspace => {
-                        let field = match *active_field {
-                            0 => cluster_name,
-                            1 => k8s_endpoint,
-                            _ => output_dir,
-                        };
-                        field.pop();
+                        overwrite.clear();
+                        match *active_field {
+                            0 => edit_cluster_name(
+                                cluster_name,
+                                output_dir,
+                                &self.output_base,
+                                |name| {
+                                    name.pop();
+                                },
+                            ),
+                            1 => {
+                                k8s_endpoint.pop();
+                            }
+                            _ => {
+                                output_dir.pop();
+                            }
+                        }
                         None
                     }
                     _ => None,
@@ -553,13 +626,24 @@ impl InsecureComponent {
                     })
                     .unwrap_or_else(|| "Maintenance Mode".to_string());
 
-                Line::from(vec![
+                let mut spans = vec![
                     Span::styled("Endpoint: ", Style::default().fg(Color::DarkGray)),
                     Span::styled(&d.endpoint, Style::default().fg(Color::White)),
+                ];
+                if d.port_detected
+                    && let Some(port) = d.port
+                {
+                    spans.push(Span::styled(
+                        format!(" (port {} auto-detected)", port),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                spans.extend([
                     Span::raw("  |  "),
                     Span::styled("Status: ", Style::default().fg(Color::DarkGray)),
                     Span::styled(version_str, Style::default().fg(Color::Green)),
-                ])
+                ]);
+                Line::from(spans)
             } else {
                 Line::from(vec![
                     Span::styled("Endpoint: ", Style::default().fg(Color::DarkGray)),
@@ -759,6 +843,7 @@ impl InsecureComponent {
                 k8s_endpoint,
                 output_dir,
                 active_field,
+                overwrite,
             } => {
                 let dialog_area = centered_rect(60, 14, area);
                 frame.render_widget(Clear, dialog_area);
@@ -777,7 +862,7 @@ impl InsecureComponent {
                     Constraint::Length(2), // Cluster name
                     Constraint::Length(2), // K8s endpoint
                     Constraint::Length(2), // Output dir
-                    Constraint::Length(1), // Spacer
+                    Constraint::Length(1), // Overwrite warning
                     Constraint::Length(1), // Help
                 ])
                 .split(inner);
@@ -838,8 +923,21 @@ impl InsecureComponent {
                 ]));
                 frame.render_widget(dir_field, layout[4]);
 
-                let help = Paragraph::new("[Tab] Next field  [Enter] Generate  [Esc] Cancel")
-                    .style(Style::default().fg(Color::DarkGray));
+                if !overwrite.is_empty() {
+                    let warning = Paragraph::new(format!(
+                        "{} exist - their secrets will be lost",
+                        overwrite.join(", ")
+                    ))
+                    .style(Style::default().fg(Color::Red));
+                    frame.render_widget(warning, layout[5]);
+                }
+
+                let help = if overwrite.is_empty() {
+                    "[Tab] Next field  [Enter] Generate  [Esc] Cancel"
+                } else {
+                    "[Enter] Overwrite  [Esc] Cancel  (or edit the output dir)"
+                };
+                let help = Paragraph::new(help).style(Style::default().fg(Color::DarkGray));
                 frame.render_widget(help, layout[6]);
             }
 
@@ -1000,7 +1098,7 @@ impl Component for InsecureComponent {
         // Normal mode key handling
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => Ok(Some(Action::Quit)),
-            KeyCode::Char('r') => Ok(Some(Action::Refresh)),
+            KeyCode::Char('r') => Ok(manual_refresh(&self.state)),
             KeyCode::Char('g') => {
                 self.open_generate_dialog();
                 Ok(None)
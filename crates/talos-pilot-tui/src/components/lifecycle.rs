@@ -3,12 +3,12 @@
 //! Provides a consolidated view of cluster lifecycle operations.
 
 use crate::action::Action;
-use crate::components::Component;
 use crate::components::diagnostics::k8s::{
     KubeconfigSource, PdbHealthInfo, PodHealthInfo, check_pdb_health, check_pod_health,
     create_k8s_client_with_source,
 };
-use crate::ui_ext::HealthIndicatorExt;
+use crate::components::{Component, help_entries};
+use crate::ui_ext::{HealthIndicatorExt, empty_state, glyphs, manual_refresh};
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use kube::Client;
@@ -20,7 +20,7 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Row, Table, TableState},
 };
 use std::time::Duration;
-use talos_pilot_core::{AsyncState, HasHealth, HealthIndicator, SelectableList};
+use talos_pilot_core::{AsyncState, ComponentError, HasHealth, HealthIndicator, SelectableList};
 use talos_rs::{
     DiscoveryMember, NodeTimeInfo, TalosClient, TalosConfig, VersionInfo,
     get_discovery_members_with_retry,
@@ -202,7 +202,7 @@ impl LifecycleComponent {
     }
 
     /// Set error message
-    pub fn set_error(&mut self, error: String) {
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
         self.state.set_error(error);
     }
 
@@ -597,12 +597,18 @@ impl LifecycleComponent {
         let version = self.current_talos_version();
         let k8s_range = self.k8s_support_range();
 
-        // Get platform from first node
-        let platform = self
+        // Nodes can run on different platforms, so list each one seen
+        let mut platforms: Vec<&str> = self
             .data()
-            .and_then(|d| d.versions.first())
-            .map(|v| v.platform.as_str())
-            .unwrap_or("unknown");
+            .map(|d| d.versions.iter().map(|v| v.platform.as_str()).collect())
+            .unwrap_or_default();
+        platforms.sort_unstable();
+        platforms.dedup();
+        let platform = if platforms.is_empty() {
+            "unknown".to_string()
+        } else {
+            platforms.join(", ")
+        };
 
         let lines = vec![
             Line::from(""),
@@ -707,6 +713,12 @@ impl LifecycleComponent {
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::DarkGray));
 
+        if node_items.is_empty() {
+            let empty = empty_state("No nodes found", None).block(block);
+            frame.render_widget(empty, area);
+            return;
+        }
+
         let table = Table::new(rows, widths)
             .header(header)
             .block(block)
@@ -757,7 +769,7 @@ impl LifecycleComponent {
 
         // Overall status indicator
         let (overall_indicator, overall_color) = if pre_op_checks.all_passed {
-            ("✓", Color::Green)
+            (glyphs().check, Color::Green)
         } else {
             ("!", Color::Yellow)
         };
@@ -779,11 +791,11 @@ impl LifecycleComponent {
         // etcd quorum check
         if let Some(ref etcd) = pre_op_checks.etcd_quorum {
             let (indicator, color) = if etcd.is_healthy && etcd.can_lose > 0 {
-                ("✓", Color::Green)
+                (glyphs().check, Color::Green)
             } else if etcd.is_healthy {
                 ("!", Color::Yellow)
             } else {
-                ("✗", Color::Red)
+                (glyphs().cross, Color::Red)
             };
 
             lines.push(Line::from(vec![
@@ -828,7 +840,7 @@ impl LifecycleComponent {
             Span::raw("  "),
             Span::styled(
                 if self.k8s_client.is_some() {
-                    "✓"
+                    glyphs().check
                 } else {
                     "?"
                 },
@@ -842,11 +854,11 @@ impl LifecycleComponent {
         if let Some(ref pods) = pre_op_checks.pod_health {
             let has_issues = pods.has_issues();
             let (indicator, color) = if !has_issues && pods.pending.is_empty() {
-                ("✓", Color::Green)
+                (glyphs().check, Color::Green)
             } else if !has_issues {
-                ("◐", Color::Yellow)
+                (glyphs().partial, Color::Yellow)
             } else {
-                ("✗", Color::Red)
+                (glyphs().cross, Color::Red)
             };
 
             let running = pods.total_pods
@@ -886,9 +898,9 @@ impl LifecycleComponent {
         // PDB check
         if let Some(ref pdbs) = pre_op_checks.pdb_health {
             let (indicator, color) = if !pdbs.has_blocking_pdbs() {
-                ("✓", Color::Green)
+                (glyphs().check, Color::Green)
             } else {
-                ("◐", Color::Yellow)
+                (glyphs().partial, Color::Yellow)
             };
 
             lines.push(Line::from(vec![
@@ -941,6 +953,10 @@ impl LifecycleComponent {
 }
 
 impl Component for LifecycleComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[("j/k ↑/↓", "Navigate"), ("r", "Refresh"), ("q/Esc", "Back")])
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => Ok(Some(Action::Back)),
@@ -952,7 +968,7 @@ impl Component for LifecycleComponent {
                 self.select_prev();
                 Ok(None)
             }
-            KeyCode::Char('r') => Ok(Some(Action::Refresh)),
+            KeyCode::Char('r') => Ok(manual_refresh(&self.state)),
             _ => Ok(None),
         }
     }
@@ -1,7 +1,7 @@
 //! Logs component - displays service logs with visual hierarchy and search
 
 use crate::action::Action;
-use crate::components::Component;
+use crate::components::{Component, help_entries};
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -414,6 +414,19 @@ impl LogsComponent {
 }
 
 impl Component for LogsComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Scroll"),
+            ("/", "Search"),
+            ("n/N", "Next/previous match"),
+            ("q/Esc", "Back"),
+        ])
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.search_mode == SearchMode::Input
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         let max_scroll = self.entries.len().saturating_sub(1) as u16;
 
@@ -653,23 +666,22 @@ impl Component for LogsComponent {
 
                     // Continuation lines with indent
                     let indent = "                    "; // 20 chars to align with message
-                    let remaining: String = entry.message.chars().skip(available_width).collect();
-
-                    for chunk in remaining.as_bytes().chunks(available_width.max(1)) {
-                        if let Ok(chunk_str) = std::str::from_utf8(chunk) {
-                            let mut cont_spans = vec![Span::raw(indent)];
-                            if is_match && !self.search_query.is_empty() {
-                                cont_spans.extend(
-                                    self.render_message_with_highlight(chunk_str, is_current_match),
-                                );
-                            } else {
-                                cont_spans.push(Span::styled(
-                                    chunk_str.to_string(),
-                                    Style::default().fg(Color::Gray),
-                                ));
-                            }
-                            lines.push(Line::from(cont_spans));
+                    let remaining: Vec<char> =
+                        entry.message.chars().skip(available_width).collect();
+
+                    // Split by characters, not bytes, so multi-byte text isn't dropped
+                    for chunk in remaining.chunks(available_width.max(1)) {
+                        let chunk_str: String = chunk.iter().collect();
+                        let mut cont_spans = vec![Span::raw(indent)];
+                        if is_match && !self.search_query.is_empty() {
+                            cont_spans.extend(
+                                self.render_message_with_highlight(&chunk_str, is_current_match),
+                            );
+                        } else {
+                            cont_spans
+                                .push(Span::styled(chunk_str, Style::default().fg(Color::Gray)));
                         }
+                        lines.push(Line::from(cont_spans));
                     }
                 }
             }
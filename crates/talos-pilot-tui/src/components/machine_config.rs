@@ -0,0 +1,950 @@
+//! Machine config component
+//!
+//! Shows the running machine config of a node with YAML highlighting, and
+//! lets it be edited in `$EDITOR` and applied back through the Talos API.
+//!
+//! Talos has no config rollback call (its Rollback RPC reverts upgrades), so
+//! the config running before an apply is kept and re-applied to undo it.
+//! The node is probed for a while after an apply so a rollback can be
+//! offered as soon as it stops answering.
+
+use crate::action::{Action, ExternalProgram};
+use crate::components::diagnostics::ConfirmChoice;
+use crate::components::{Component, help_entries};
+use crate::ui_ext::{NodeInfo, glyphs, manual_refresh, node_info_spans};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+use std::time::{Duration, Instant};
+use talos_pilot_core::{AsyncState, ComponentError, format_talos_error};
+use talos_rs::{ApplyConfigResult, TalosClient};
+use tokio::task::JoinHandle;
+
+/// Where Talos keeps the machine config it booted with
+pub(crate) const MACHINE_CONFIG_PATH: &str = "/system/state/config.yaml";
+
+/// How long after an apply the node is watched for trouble
+const ROLLBACK_WATCH: Duration = Duration::from_secs(300);
+
+/// Interval between health probes while watching
+const PROBE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Timeout for a single health probe
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Buttons in the apply confirmation dialog
+const APPLY_CHOICES: [ConfirmChoice; 4] = [
+    ConfirmChoice::Cancel,
+    ConfirmChoice::Apply,
+    ConfirmChoice::ApplyAndReboot,
+    ConfirmChoice::Stage,
+];
+
+/// An edited config waiting for the user to confirm how to apply it
+struct PendingEdit {
+    /// Edited YAML
+    yaml: String,
+    /// Selected button (index into `APPLY_CHOICES`)
+    selection: usize,
+    /// Output of the last dry run, if one was requested
+    dry_run: Option<Result<Vec<ApplyConfigResult>, String>>,
+}
+
+/// What a background apply is doing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ApplyKind {
+    /// Validate the pending config without applying it
+    DryRun,
+    /// Apply the pending config
+    Apply,
+    /// Re-apply the config that was running before the last apply
+    Rollback,
+}
+
+/// An apply (or dry run) running in the background
+struct ApplyTask {
+    kind: ApplyKind,
+    /// When the apply started
+    started: Instant,
+    /// Background task handle
+    handle: JoinHandle<Result<Vec<ApplyConfigResult>, String>>,
+}
+
+/// Config that was running before the last apply, kept to undo it
+struct RollbackPoint {
+    /// Previous running config
+    yaml: String,
+    /// When the apply was started
+    applied_at: Instant,
+    /// The last health probe got no answer from the node
+    unreachable: bool,
+}
+
+/// Machine config viewer/editor for a single node
+pub struct MachineConfigComponent {
+    /// Node hostname
+    hostname: String,
+    /// Node address
+    address: String,
+
+    /// Running machine config YAML
+    state: AsyncState<String>,
+    /// Scroll offset in lines
+    scroll: u16,
+    /// Height of the config pane at the last draw
+    viewport_height: u16,
+
+    /// Edited config that failed validation or apply, reopened by the next edit
+    draft: Option<String>,
+    /// Edited config awaiting confirmation
+    pending: Option<PendingEdit>,
+    /// Apply in progress
+    apply_task: Option<ApplyTask>,
+    /// Spinner frame for the apply progress indicator
+    spinner_frame: usize,
+    /// Outcome of the last edit or apply
+    status: Option<(String, Color)>,
+
+    /// Config to restore if the last apply went wrong
+    rollback: Option<RollbackPoint>,
+    /// Rollback confirmation dialog is open
+    confirm_rollback: bool,
+    /// Health probe running in the background
+    probe_task: Option<JoinHandle<bool>>,
+    /// When the last health probe was started
+    last_probe: Option<Instant>,
+
+    /// Node summary shown in the header
+    node_info: NodeInfo,
+
+    /// Client for API calls
+    client: Option<TalosClient>,
+}
+
+impl MachineConfigComponent {
+    pub fn new(hostname: String, address: String) -> Self {
+        let node_info = NodeInfo::new(hostname.clone(), address.clone());
+        Self {
+            hostname,
+            address,
+            state: AsyncState::new(),
+            scroll: 0,
+            viewport_height: 20,
+            draft: None,
+            pending: None,
+            apply_task: None,
+            spinner_frame: 0,
+            status: None,
+            rollback: None,
+            confirm_rollback: false,
+            probe_task: None,
+            last_probe: None,
+            node_info,
+            client: None,
+        }
+    }
+
+    /// Set the node summary shown in the header
+    pub fn set_node_info(&mut self, info: NodeInfo) {
+        self.node_info = info;
+    }
+
+    /// Node this view shows
+    pub fn node_info(&self) -> &NodeInfo {
+        &self.node_info
+    }
+
+    /// Whether there are edits that have not been applied yet
+    pub fn has_unapplied_edits(&self) -> bool {
+        self.draft.is_some()
+    }
+
+    /// Set the client for making API calls
+    pub fn set_client(&mut self, client: TalosClient) {
+        self.client = Some(client);
+    }
+
+    /// Client targeting this node
+    pub fn client(&self) -> Option<&TalosClient> {
+        self.client.as_ref()
+    }
+
+    /// Set an error message
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
+        self.state.set_error(error);
+    }
+
+    /// Read the running machine config from the node
+    pub async fn refresh(&mut self) -> Result<()> {
+        let Some(client) = &self.client else {
+            self.state.set_error("No client configured");
+            return Ok(());
+        };
+
+        self.state.start_loading();
+
+        let timeout = Duration::from_secs(10);
+        match tokio::time::timeout(timeout, client.read_file(MACHINE_CONFIG_PATH)).await {
+            Ok(Ok(yaml)) => self.state.set_data(yaml),
+            Ok(Err(e)) => self.state.set_error_with_retry(
+                ComponentError::from(&e).context("Failed to read machine config"),
+            ),
+            Err(_) => self.state.set_error_with_retry(ComponentError::Timeout(
+                "Timed out reading machine config".to_string(),
+            )),
+        }
+
+        Ok(())
+    }
+
+    /// Text to open in the editor: the unapplied draft, else the running config
+    pub fn editor_text(&self) -> Option<String> {
+        self.draft.clone().or_else(|| self.state.data().cloned())
+    }
+
+    /// Take the result of an editor session
+    ///
+    /// Unchanged configs are dropped; anything else is checked to be valid
+    /// YAML and held for confirmation.
+    pub fn set_edited(&mut self, edited: Result<String>) {
+        let yaml = match edited {
+            Ok(yaml) => yaml,
+            Err(e) => {
+                self.status = Some((format!("Editor failed: {}", e), Color::Red));
+                return;
+            }
+        };
+
+        if self
+            .state
+            .data()
+            .is_some_and(|running| running.trim_end() == yaml.trim_end())
+        {
+            self.draft = None;
+            self.status = Some(("No changes".to_string(), Color::DarkGray));
+            return;
+        }
+
+        if let Err(e) = validate_yaml(&yaml) {
+            self.status = Some((format!("Invalid YAML, press e to fix: {}", e), Color::Red));
+            self.draft = Some(yaml);
+            return;
+        }
+
+        self.status = None;
+        self.draft = Some(yaml.clone());
+        self.pending = Some(PendingEdit {
+            yaml,
+            selection: 0,
+            dry_run: None,
+        });
+    }
+
+    /// Start applying the pending config, or the rollback point, in the background
+    fn start_apply(&mut self, kind: ApplyKind) {
+        let Some(client) = &self.client else {
+            return;
+        };
+        let (yaml, mode) = match kind {
+            ApplyKind::Rollback => {
+                let Some(rollback) = &self.rollback else {
+                    return;
+                };
+                (rollback.yaml.clone(), talos_rs::ApplyMode::Auto)
+            }
+            ApplyKind::DryRun | ApplyKind::Apply => {
+                let Some(pending) = &self.pending else {
+                    return;
+                };
+                let Some(mode) = APPLY_CHOICES[pending.selection]
+                    .apply_mode()
+                    .or((kind == ApplyKind::DryRun).then_some(talos_rs::ApplyMode::Auto))
+                else {
+                    return;
+                };
+                (pending.yaml.clone(), mode)
+            }
+        };
+
+        if kind == ApplyKind::Apply
+            && let Some(running) = self.state.data()
+        {
+            self.rollback = Some(RollbackPoint {
+                yaml: running.clone(),
+                applied_at: Instant::now(),
+                unreachable: false,
+            });
+            self.last_probe = None;
+        }
+
+        let client = client.clone();
+        let dry_run = kind == ApplyKind::DryRun;
+        tracing::info!(
+            "Applying machine config to {} ({:?}, mode {:?})",
+            self.address,
+            kind,
+            mode
+        );
+        let handle = tokio::spawn(async move {
+            client
+                .apply_configuration(&yaml, mode, dry_run)
+                .await
+                .map_err(|e| format_talos_error(&e))
+        });
+        self.apply_task = Some(ApplyTask {
+            kind,
+            started: Instant::now(),
+            handle,
+        });
+    }
+
+    /// Collect a finished apply, returning true if the config should be reloaded
+    fn poll_apply(&mut self) -> bool {
+        if !self
+            .apply_task
+            .as_ref()
+            .is_some_and(|t| t.handle.is_finished())
+        {
+            return false;
+        }
+        let task = self.apply_task.take().unwrap();
+        let elapsed = task.started.elapsed().as_secs();
+
+        // Won't block since is_finished() was true
+        let result = match futures::executor::block_on(task.handle) {
+            Ok(result) => result,
+            Err(e) => Err(format!("Task error: {}", e)),
+        };
+
+        match task.kind {
+            ApplyKind::DryRun => {
+                if let Some(pending) = &mut self.pending {
+                    pending.dry_run = Some(result);
+                }
+                return false;
+            }
+            ApplyKind::Rollback => {
+                return match result {
+                    Ok(_) => {
+                        tracing::info!("Rolled back machine config on {}", self.address);
+                        self.rollback = None;
+                        self.draft = None;
+                        self.status = Some((
+                            format!("Rolled back to the previous config in {}s", elapsed),
+                            Color::Green,
+                        ));
+                        true
+                    }
+                    Err(e) => {
+                        self.status = Some((format!("Rollback failed: {}", e), Color::Red));
+                        false
+                    }
+                };
+            }
+            ApplyKind::Apply => {}
+        }
+
+        self.pending = None;
+        match result {
+            Ok(results) => {
+                let summary = results
+                    .iter()
+                    .map(|r| r.mode_result.trim())
+                    .filter(|s| !s.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                self.draft = None;
+                self.status = Some((
+                    format!("Applied in {}s. {}", elapsed, summary),
+                    Color::Green,
+                ));
+                true
+            }
+            Err(e) => {
+                // The apply may have half-happened (or the node rebooted mid-call)
+                self.status = Some((
+                    format!(
+                        "Apply failed, press u to roll back or e to edit again: {}",
+                        e
+                    ),
+                    Color::Red,
+                ));
+                false
+            }
+        }
+    }
+
+    /// Probe the node in the background while an apply is being watched
+    ///
+    /// Only reachability is checked, so a slow reboot after an apply shows
+    /// up as trouble until the node answers again.
+    fn poll_probe(&mut self) {
+        if let Some(task) = &self.probe_task {
+            if !task.is_finished() {
+                return;
+            }
+            let task = self.probe_task.take().unwrap();
+            // Won't block since is_finished() was true
+            let reachable = futures::executor::block_on(task).unwrap_or(false);
+            if let Some(rollback) = &mut self.rollback {
+                rollback.unreachable = !reachable;
+            }
+        }
+
+        let (Some(rollback), Some(client)) = (&self.rollback, &self.client) else {
+            return;
+        };
+        let watching = rollback.applied_at.elapsed() < ROLLBACK_WATCH || rollback.unreachable;
+        let due = self
+            .last_probe
+            .is_none_or(|t| t.elapsed() >= PROBE_INTERVAL);
+        if !watching || !due || self.apply_task.is_some() {
+            return;
+        }
+
+        let client = client.clone();
+        self.last_probe = Some(Instant::now());
+        self.probe_task = Some(tokio::spawn(async move {
+            matches!(
+                tokio::time::timeout(PROBE_TIMEOUT, client.version()).await,
+                Ok(Ok(_))
+            )
+        }));
+    }
+
+    fn scroll_by(&mut self, delta: i32) {
+        let lines = self.state.data().map_or(0, |yaml| yaml.lines().count());
+        let max = lines.saturating_sub(self.viewport_height as usize) as i32;
+        self.scroll = (self.scroll as i32 + delta).clamp(0, max) as u16;
+    }
+
+    /// Draw the header with the node and config summary
+    fn draw_header(&self, frame: &mut Frame, area: Rect) {
+        let mut spans = vec![Span::styled(
+            "Machine Config: ",
+            Style::default().add_modifier(Modifier::BOLD),
+        )];
+        spans.extend(node_info_spans(&self.node_info));
+        if let Some(yaml) = self.state.data() {
+            spans.push(Span::styled(
+                format!("  {} lines", yaml.lines().count()),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        if self.draft.is_some() && self.pending.is_none() {
+            spans.push(Span::styled(
+                "  [unapplied edits]",
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        let para =
+            Paragraph::new(Line::from(spans)).block(Block::default().borders(Borders::BOTTOM));
+        frame.render_widget(para, area);
+    }
+
+    /// Draw the highlighted config
+    fn draw_config(&mut self, frame: &mut Frame, area: Rect) {
+        self.viewport_height = area.height;
+
+        let Some(yaml) = self.state.data() else {
+            let text = match self.state.error() {
+                Some(err) => {
+                    Paragraph::new(format!("Error: {}", err)).style(Style::default().fg(Color::Red))
+                }
+                None => Paragraph::new("Loading...").style(Style::default().fg(Color::DarkGray)),
+            };
+            frame.render_widget(text, area);
+            return;
+        };
+
+        let lines: Vec<Line> = yaml
+            .lines()
+            .skip(self.scroll as usize)
+            .take(area.height as usize)
+            .map(highlight_yaml_line)
+            .collect();
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+
+    /// Draw the status line and key hints
+    fn draw_footer(&self, frame: &mut Frame, area: Rect) {
+        let status = if let Some(task) = &self.apply_task {
+            let spinner = glyphs().spinner[self.spinner_frame % glyphs().spinner.len()];
+            let verb = match task.kind {
+                ApplyKind::DryRun => "Dry run",
+                ApplyKind::Apply => "Applying",
+                ApplyKind::Rollback => "Rolling back",
+            };
+            Line::from(Span::styled(
+                format!(
+                    "{} {}... {}s",
+                    spinner,
+                    verb,
+                    task.started.elapsed().as_secs()
+                ),
+                Style::default().fg(Color::Yellow),
+            ))
+        } else if self.rollback.as_ref().is_some_and(|r| r.unreachable) {
+            Line::from(Span::styled(
+                format!(
+                    "{} {} is not responding since the last apply, press u to roll back",
+                    glyphs().warning,
+                    self.hostname
+                ),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ))
+        } else if let Some((message, color)) = &self.status {
+            Line::from(Span::styled(message.clone(), Style::default().fg(*color)))
+        } else if let Some(err) = self.state.error() {
+            Line::from(Span::styled(
+                format!("Reload failed: {}", err),
+                Style::default().fg(Color::Red),
+            ))
+        } else {
+            Line::from("")
+        };
+
+        let mut hints = vec![
+            Span::styled("[j/k]", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" scroll  "),
+            Span::styled("[e]", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" edit  "),
+            Span::styled("[r]", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" reload  "),
+        ];
+        if self.rollback.is_some() {
+            hints.push(Span::styled(
+                "[u]",
+                Style::default().add_modifier(Modifier::BOLD),
+            ));
+            hints.push(Span::raw(" undo apply  "));
+        }
+        hints.push(Span::styled(
+            "[q]",
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        hints.push(Span::raw(" back"));
+        let hints = Line::from(hints);
+
+        frame.render_widget(
+            Paragraph::new(vec![status, hints]).block(Block::default().borders(Borders::TOP)),
+            area,
+        );
+    }
+
+    /// Draw the rollback confirmation dialog
+    fn draw_rollback_confirmation(&self, frame: &mut Frame, area: Rect) {
+        let Some(rollback) = self.rollback.as_ref().filter(|_| self.confirm_rollback) else {
+            return;
+        };
+
+        let dialog_width = 64.min(area.width.saturating_sub(4));
+        let dialog_height = 9.min(area.height.saturating_sub(4));
+        let dialog_area = Rect::new(
+            area.x + (area.width.saturating_sub(dialog_width)) / 2,
+            area.y + (area.height.saturating_sub(dialog_height)) / 2,
+            dialog_width,
+            dialog_height,
+        );
+        frame.render_widget(Clear, dialog_area);
+
+        let lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!(
+                    "Roll {} back to the config from before the last apply?",
+                    self.hostname
+                ),
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Line::from(format!(
+                "Applied {}s ago. Talos reboots the node if the change needs it.",
+                rollback.applied_at.elapsed().as_secs()
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(
+                    "[y]",
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" Roll back    "),
+                Span::styled(
+                    "[n]",
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" Cancel"),
+            ]),
+        ];
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Roll Back Machine Config ")
+            .border_style(Style::default().fg(Color::Red));
+        frame.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(block),
+            dialog_area,
+        );
+    }
+
+    /// Draw the apply confirmation dialog
+    fn draw_confirmation(&self, frame: &mut Frame, area: Rect) {
+        let Some(pending) = &self.pending else {
+            return;
+        };
+
+        let dialog_width = 80.min(area.width.saturating_sub(4));
+        let dialog_height = 20.min(area.height.saturating_sub(4));
+        let dialog_area = Rect::new(
+            area.x + (area.width.saturating_sub(dialog_width)) / 2,
+            area.y + (area.height.saturating_sub(dialog_height)) / 2,
+            dialog_width,
+            dialog_height,
+        );
+        frame.render_widget(Clear, dialog_area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Apply Machine Config ")
+            .border_style(Style::default().fg(Color::Yellow));
+        let inner = block.inner(dialog_area);
+        frame.render_widget(block, dialog_area);
+
+        let [body, buttons] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(2)]).areas(inner);
+
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("Apply the edited config to {}?", self.hostname),
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+        ];
+        match &pending.dry_run {
+            None => lines.push(Line::from(Span::styled(
+                "Press d for a dry run to see what Talos would change.",
+                Style::default().fg(Color::DarkGray),
+            ))),
+            Some(Ok(results)) => {
+                lines.push(Line::from(Span::styled(
+                    "Dry run:",
+                    Style::default().fg(Color::Cyan),
+                )));
+                for r in results {
+                    for line in r.mode_result.lines() {
+                        lines.push(Line::from(format!("  {}", line)));
+                    }
+                    for warning in &r.warnings {
+                        lines.push(Line::from(Span::styled(
+                            format!("  {} {}", glyphs().warning, warning),
+                            Style::default().fg(Color::Yellow),
+                        )));
+                    }
+                }
+            }
+            Some(Err(e)) => {
+                lines.push(Line::from(Span::styled(
+                    format!("Dry run failed: {}", e),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+        }
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), body);
+
+        let mut spans = Vec::new();
+        for (i, choice) in APPLY_CHOICES.iter().enumerate() {
+            let style = if i == pending.selection {
+                Style::default()
+                    .bg(Color::Cyan)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            spans.push(Span::styled(choice.label(), style));
+            spans.push(Span::raw("  "));
+        }
+        spans.push(Span::styled(
+            "[d] dry run",
+            Style::default().fg(Color::DarkGray),
+        ));
+        frame.render_widget(Paragraph::new(Line::from(spans)), buttons);
+    }
+}
+
+/// Check that every document in `yaml` parses
+fn validate_yaml(yaml: &str) -> std::result::Result<(), serde_yaml::Error> {
+    use serde::Deserialize;
+    for document in serde_yaml::Deserializer::from_str(yaml) {
+        serde_yaml::Value::deserialize(document)?;
+    }
+    Ok(())
+}
+
+/// Colour one line of YAML: comments, keys, list markers and scalar values
+fn highlight_yaml_line(line: &str) -> Line<'static> {
+    let comment = Style::default().fg(Color::DarkGray);
+    let trimmed = line.trim_start();
+    if trimmed.starts_with('#') || trimmed == "---" {
+        return Line::from(Span::styled(line.to_string(), comment));
+    }
+
+    let indent = &line[..line.len() - trimmed.len()];
+    let mut spans = vec![Span::raw(indent.to_string())];
+    let mut rest = trimmed;
+    if let Some(item) = rest.strip_prefix("- ").or((rest == "-").then_some("")) {
+        spans.push(Span::styled("- ", Style::default().fg(Color::Yellow)));
+        rest = item;
+    }
+
+    let key_end = rest
+        .find(": ")
+        .or_else(|| rest.ends_with(':').then(|| rest.len() - 1))
+        .filter(|&end| !rest[..end].contains(" #") && !rest.starts_with(['"', '\'', '{', '[']));
+    if let Some(end) = key_end {
+        spans.push(Span::styled(
+            rest[..end].to_string(),
+            Style::default().fg(Color::Cyan),
+        ));
+        spans.push(Span::raw(":"));
+        rest = &rest[end + 1..];
+    }
+
+    if !rest.is_empty() {
+        let value = rest.trim_start();
+        let space = &rest[..rest.len() - value.len()];
+        spans.push(Span::raw(space.to_string()));
+        spans.push(Span::styled(value.to_string(), scalar_style(value)));
+    }
+    Line::from(spans)
+}
+
+/// Style for a scalar value by its apparent type
+fn scalar_style(value: &str) -> Style {
+    match value {
+        "true" | "false" | "null" | "~" => Style::default().fg(Color::Magenta),
+        v if v.starts_with(['"', '\'']) => Style::default().fg(Color::Green),
+        v if v.starts_with(['|', '>']) => Style::default().fg(Color::Yellow),
+        v if v.parse::<f64>().is_ok() => Style::default().fg(Color::Magenta),
+        _ => Style::default(),
+    }
+}
+
+impl Component for MachineConfigComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Scroll"),
+            ("PgUp/PgDn", "Scroll by page"),
+            ("g/G", "Top / bottom"),
+            ("e", "Edit in $EDITOR, then confirm apply"),
+            ("←/→ Enter", "Choose apply mode (in confirmation)"),
+            ("d", "Dry run (in confirmation)"),
+            ("u", "Roll back the last apply"),
+            ("r", "Reload config"),
+            ("q/Esc", "Back"),
+        ])
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        if self.apply_task.is_some() {
+            // Nothing to do until the apply finishes
+            return Ok(None);
+        }
+
+        if let Some(pending) = &mut self.pending {
+            match key.code {
+                KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => {
+                    pending.selection =
+                        (pending.selection + APPLY_CHOICES.len() - 1) % APPLY_CHOICES.len();
+                }
+                KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
+                    pending.selection = (pending.selection + 1) % APPLY_CHOICES.len();
+                }
+                KeyCode::Char('d') => self.start_apply(ApplyKind::DryRun),
+                KeyCode::Enter if APPLY_CHOICES[pending.selection] != ConfirmChoice::Cancel => {
+                    self.start_apply(ApplyKind::Apply);
+                }
+                KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q') => {
+                    self.pending = None;
+                    self.status = Some((
+                        "Not applied, press e to keep editing".to_string(),
+                        Color::DarkGray,
+                    ));
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+
+        if self.confirm_rollback {
+            self.confirm_rollback = false;
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                self.start_apply(ApplyKind::Rollback);
+            }
+            return Ok(None);
+        }
+
+        let page = self.viewport_height.max(1) as i32;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(Some(Action::Back)),
+            KeyCode::Char('j') | KeyCode::Down => self.scroll_by(1),
+            KeyCode::Char('k') | KeyCode::Up => self.scroll_by(-1),
+            KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_by(page),
+            KeyCode::PageUp => self.scroll_by(-page),
+            KeyCode::Char('g') | KeyCode::Home => self.scroll = 0,
+            KeyCode::Char('G') | KeyCode::End => self.scroll_by(i32::MAX / 2),
+            KeyCode::Char('e') if self.state.has_data() => {
+                return Ok(Some(Action::Suspend(ExternalProgram::EditMachineConfig)));
+            }
+            KeyCode::Char('r') => {
+                return Ok(manual_refresh(&self.state));
+            }
+            KeyCode::Char('u') if self.rollback.is_some() => self.confirm_rollback = true,
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        if let Action::Tick = action {
+            self.poll_probe();
+            if self.apply_task.is_some() {
+                self.spinner_frame = (self.spinner_frame + 1) % glyphs().spinner.len();
+                return Ok(self.poll_apply().then_some(Action::Refresh));
+            }
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        let [header, body, footer] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(3),
+        ])
+        .areas(area);
+
+        self.draw_header(frame, header);
+        self.draw_config(frame, body);
+        self.draw_footer(frame, footer);
+        self.draw_confirmation(frame, area);
+        self.draw_rollback_confirmation(frame, area);
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.pending.is_some() || self.confirm_rollback
+    }
+
+    fn is_busy(&self) -> bool {
+        self.apply_task
+            .as_ref()
+            .is_some_and(|t| t.kind != ApplyKind::DryRun)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span_texts(line: &Line) -> Vec<String> {
+        line.spans.iter().map(|s| s.content.to_string()).collect()
+    }
+
+    #[test]
+    fn test_highlight_yaml_key_value() {
+        let line = highlight_yaml_line("    hostname: cp-1");
+        assert_eq!(span_texts(&line), ["    ", "hostname", ":", " ", "cp-1"]);
+        assert_eq!(line.spans[1].style.fg, Some(Color::Cyan));
+
+        let line = highlight_yaml_line("  - name: br_netfilter");
+        assert_eq!(
+            span_texts(&line),
+            ["  ", "- ", "name", ":", " ", "br_netfilter"]
+        );
+
+        let line = highlight_yaml_line("machine:");
+        assert_eq!(span_texts(&line), ["", "machine", ":"]);
+    }
+
+    #[test]
+    fn test_highlight_yaml_scalars_and_comments() {
+        let line = highlight_yaml_line("  # a comment: not a key");
+        assert_eq!(line.spans.len(), 1);
+        assert_eq!(line.spans[0].style.fg, Some(Color::DarkGray));
+
+        let line = highlight_yaml_line("  - \"10.0.0.0/8\"");
+        assert_eq!(span_texts(&line), ["  ", "- ", "", "\"10.0.0.0/8\""]);
+        assert_eq!(line.spans[3].style.fg, Some(Color::Green));
+
+        let line = highlight_yaml_line("  disabled: false");
+        assert_eq!(line.spans.last().unwrap().style.fg, Some(Color::Magenta));
+
+        // URLs are values, not keys
+        let line = highlight_yaml_line("  - https://example.com/a.yaml");
+        assert_eq!(line.spans[1].content, "- ");
+        assert_eq!(
+            line.spans.last().unwrap().content,
+            "https://example.com/a.yaml"
+        );
+    }
+
+    #[test]
+    fn test_set_edited_requires_change_and_valid_yaml() {
+        let mut component = MachineConfigComponent::new("cp-1".into(), "10.0.0.1".into());
+        component.state.set_data("version: v1alpha1\n".to_string());
+
+        component.set_edited(Ok("version: v1alpha1".to_string()));
+        assert!(component.pending.is_none());
+        assert!(component.draft.is_none());
+
+        component.set_edited(Ok("version: [unclosed\n".to_string()));
+        assert!(component.pending.is_none());
+        assert_eq!(
+            component.editor_text().as_deref(),
+            Some("version: [unclosed\n")
+        );
+
+        component.set_edited(Ok(
+            "version: v1alpha1\n---\nkind: HostnameConfig\n".to_string()
+        ));
+        assert!(component.pending.is_some());
+    }
+
+    #[test]
+    fn test_rollback_key_needs_rollback_point() {
+        let mut component = MachineConfigComponent::new("cp-1".into(), "10.0.0.1".into());
+        component.state.set_data("version: v1alpha1\n".to_string());
+
+        let u = KeyEvent::from(KeyCode::Char('u'));
+        component.handle_key_event(u).unwrap();
+        assert!(!component.confirm_rollback);
+
+        component.rollback = Some(RollbackPoint {
+            yaml: "version: v1alpha1\n".to_string(),
+            applied_at: Instant::now(),
+            unreachable: true,
+        });
+        component.handle_key_event(u).unwrap();
+        assert!(component.confirm_rollback);
+        assert!(component.is_capturing_input());
+
+        // Anything but y cancels
+        component
+            .handle_key_event(KeyEvent::from(KeyCode::Esc))
+            .unwrap();
+        assert!(!component.confirm_rollback);
+        assert!(component.apply_task.is_none());
+    }
+}
@@ -5,13 +5,18 @@
 pub mod cluster;
 pub mod diagnostics;
 pub mod etcd;
+pub mod events;
 pub mod home;
 pub mod insecure;
 pub mod lifecycle;
 pub mod logs;
+pub mod machine_config;
 pub mod multi_logs;
 pub mod network;
+pub mod network_group;
 pub mod node_operations;
+pub mod node_switcher;
+pub mod pod_logs;
 pub mod processes;
 pub mod rolling_operations;
 pub mod security;
@@ -21,14 +26,20 @@ pub mod workloads;
 
 pub use cluster::ClusterComponent;
 pub use diagnostics::DiagnosticsComponent;
+pub use diagnostics::compare::DiagnosticsCompareComponent;
 pub use etcd::EtcdComponent;
+pub use events::EventsComponent;
 pub use home::HomeComponent;
 pub use insecure::InsecureComponent;
 pub use lifecycle::LifecycleComponent;
 pub use logs::LogsComponent;
+pub use machine_config::MachineConfigComponent;
 pub use multi_logs::MultiLogsComponent;
 pub use network::NetworkStatsComponent;
+pub use network_group::NetworkGroupComponent;
 pub use node_operations::NodeOperationsComponent;
+pub use node_switcher::NodeSwitcherComponent;
+pub use pod_logs::PodLogsComponent;
 pub use processes::ProcessesComponent;
 pub use rolling_operations::RollingOperationsComponent;
 pub use security::SecurityComponent;
@@ -66,4 +77,29 @@ pub trait Component {
 
     /// Render the component to the frame
     fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()>;
+
+    /// Full key map shown in the `?` help overlay, as (key, description) pairs
+    fn key_help(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    /// Whether the component is currently capturing typed text (e.g. a search
+    /// prompt), in which case global shortcuts like `?` are passed through
+    fn is_capturing_input(&self) -> bool {
+        false
+    }
+
+    /// Whether the component is in the middle of changing cluster state
+    /// (applying config, rebooting nodes), so quitting now would cut it off
+    fn is_busy(&self) -> bool {
+        false
+    }
+}
+
+/// Build a `key_help` list from static (key, description) pairs
+pub fn help_entries(entries: &[(&str, &str)]) -> Vec<(String, String)> {
+    entries
+        .iter()
+        .map(|(key, desc)| (key.to_string(), desc.to_string()))
+        .collect()
 }
@@ -1,7 +1,8 @@
 //! Multi-service logs component - Stern-style interleaved log viewer
 
 use crate::action::Action;
-use crate::components::Component;
+use crate::components::{Component, help_entries};
+use crate::ui_ext::glyphs;
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
@@ -15,8 +16,8 @@ use ratatui::{
     },
 };
 use std::collections::HashSet;
-use talos_pilot_core::AsyncState;
 use talos_pilot_core::constants::MAX_LOG_ENTRIES;
+use talos_pilot_core::{AsyncState, ComponentError};
 
 /// Maximum lines to process per tick during streaming
 /// Higher = more responsive but could block UI if too high
@@ -329,6 +330,11 @@ impl MultiLogsComponent {
         self.tail_lines = tail_lines;
     }
 
+    /// Client targeting the node whose logs are shown
+    pub fn client(&self) -> Option<&talos_rs::TalosClient> {
+        self.client.as_ref()
+    }
+
     /// Get reference to data (if loaded)
     fn data(&self) -> Option<&MultiLogsData> {
         self.state.data()
@@ -941,7 +947,7 @@ impl MultiLogsComponent {
     }
 
     /// Set error message
-    pub fn set_error(&mut self, error: String) {
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
         self.state.set_error(error);
     }
 
@@ -1316,7 +1322,11 @@ impl MultiLogsComponent {
         let items: Vec<ListItem> = filtered_services
             .iter()
             .map(|(_, s)| {
-                let indicator = if s.active { "●" } else { "○" };
+                let indicator = if s.active {
+                    glyphs().filled
+                } else {
+                    glyphs().hollow
+                };
                 let style = Style::default().fg(s.color);
 
                 ListItem::new(Line::from(vec![
@@ -1370,7 +1380,11 @@ impl MultiLogsComponent {
             .levels
             .iter()
             .map(|l| {
-                let indicator = if l.active { "●" } else { "○" };
+                let indicator = if l.active {
+                    glyphs().filled
+                } else {
+                    glyphs().hollow
+                };
                 let style = Style::default().fg(l.level.color());
 
                 ListItem::new(Line::from(vec![
@@ -1586,6 +1600,33 @@ impl MultiLogsComponent {
 }
 
 impl Component for MultiLogsComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Move cursor"),
+            ("PgUp/PgDn", "Page up/down"),
+            ("Ctrl+U/Ctrl+D", "Half page up/down"),
+            ("g/G", "Jump to top/bottom"),
+            ("Tab", "Switch services/levels pane"),
+            ("s", "Toggle services pane"),
+            ("l", "Toggle levels pane"),
+            ("Space", "Toggle item in pane"),
+            ("a/n", "All/none in pane"),
+            ("/", "Search"),
+            ("n/N", "Next/previous match"),
+            ("f", "Toggle follow mode"),
+            ("F", "Toggle streaming"),
+            ("w", "Toggle line wrapping"),
+            ("V", "Visual line selection"),
+            ("y", "Copy selection or line"),
+            ("q", "Back"),
+            ("Esc", "Close pane / clear search / back"),
+        ])
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.search_mode == SearchMode::Input
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         // Handle search input mode
         if self.search_mode == SearchMode::Input {
@@ -1926,11 +1967,20 @@ impl Component for MultiLogsComponent {
                 2 | 6 => Color::Rgb(100, 255, 100), // Brighter green
                 _ => Color::Green,
             };
-            Span::styled("● LIVE ", Style::default().fg(pulse_color).bold())
+            Span::styled(
+                format!("{} LIVE ", glyphs().filled),
+                Style::default().fg(pulse_color).bold(),
+            )
         } else if self.following {
-            Span::styled("● LIVE ", Style::default().fg(Color::Green).bold())
+            Span::styled(
+                format!("{} LIVE ", glyphs().filled),
+                Style::default().fg(Color::Green).bold(),
+            )
         } else {
-            Span::styled("○ PAUSED ", Style::default().fg(Color::DarkGray))
+            Span::styled(
+                format!("{} PAUSED ", glyphs().hollow),
+                Style::default().fg(Color::DarkGray),
+            )
         };
 
         let mut header_spans = vec![
@@ -3,7 +3,11 @@
 //! "Is the network the problem?"
 
 use crate::action::Action;
-use crate::components::Component;
+use crate::components::{Component, help_entries};
+use crate::ui_ext::{
+    ComponentErrorExt, NodeInfo, NotifyLevel, column_widths, empty_state, glyphs,
+    last_updated_span, manual_refresh, node_info_spans, wrap_row,
+};
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
@@ -13,13 +17,16 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
 };
-use std::collections::HashMap;
-use std::time::Instant;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
 use talos_pilot_core::constants::MAX_CAPTURE_SIZE;
-use talos_pilot_core::{AsyncState, format_bytes};
+use talos_pilot_core::metrics::{NodeNetworkMetrics, format_prometheus};
+use talos_pilot_core::{AsyncState, ComponentError, format_bytes, reselect_index};
 use talos_rs::{
-    ConnectionCounts, ConnectionInfo, ConnectionState, KubeSpanPeerStatus, NetDevRate, NetDevStats,
-    NetstatFilter, ServiceInfo, TalosClient, get_kubespan_peers, is_kubespan_enabled,
+    ConnectionCounts, ConnectionInfo, ConnectionState, ConnectionThresholds, InterfaceKind,
+    KubeSpanPeerStatus, LinkStatus, NetDevRate, NetDevStats, NetstatFilter, ServiceInfo,
+    TalosClient, get_kubespan_peers, get_link_status, is_kubespan_enabled,
 };
 
 /// Well-known Talos/Kubernetes service ports
@@ -27,9 +34,67 @@ fn port_to_service(port: u32) -> Option<&'static str> {
     talos_pilot_core::network::port_to_service_u32(port)
 }
 
+/// Stable identity of a connection across refreshes (protocol + 4-tuple)
+fn connection_key(conn: &ConnectionInfo) -> (String, String, u32, String, u32) {
+    (
+        conn.protocol.clone(),
+        conn.local_ip.clone(),
+        conn.local_port,
+        conn.remote_ip.clone(),
+        conn.remote_port,
+    )
+}
+
+/// Control plane ports shown in the service health row by default
+const DEFAULT_KEY_PORTS: [(&str, u16); 5] = [
+    ("API", 6443),
+    ("Etcd", 2379),
+    ("Kubelet", 10250),
+    ("Scheduler", 10259),
+    ("Controller", 10257),
+];
+
+/// A port whose listening state is shown in the service health row
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyPort {
+    /// Short name shown next to the port
+    pub label: String,
+    pub port: u16,
+}
+
+/// The default key ports plus user-configured ones
+///
+/// An entry whose label matches a default (e.g. `API` on 443) moves that
+/// service to the new port instead of adding a second indicator.
+pub fn key_ports(extra: &[KeyPort]) -> Vec<KeyPort> {
+    let mut ports: Vec<KeyPort> = DEFAULT_KEY_PORTS
+        .iter()
+        .map(|(label, port)| KeyPort {
+            label: label.to_string(),
+            port: *port,
+        })
+        .collect();
+    for key_port in extra {
+        match ports
+            .iter_mut()
+            .find(|p| p.label.eq_ignore_ascii_case(&key_port.label))
+        {
+            Some(existing) => existing.port = key_port.port,
+            None => ports.push(key_port.clone()),
+        }
+    }
+    ports
+}
+
 /// Auto-refresh interval in seconds (faster than processes for responsive rates)
 const AUTO_REFRESH_INTERVAL_SECS: u64 = 2;
 
+/// Weight of the newest sample in smoothed rates, unless configured
+pub const DEFAULT_SMOOTHING_ALPHA: f64 = 0.3;
+
+/// Rates older than this many refresh intervals are shown as stale
+const STALE_RATE_INTERVALS: u32 = 2;
+
 /// Sort order for device list
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SortBy {
@@ -47,6 +112,247 @@ impl SortBy {
     }
 }
 
+/// Column set shown in the device table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColumnPreset {
+    /// Rates and totals only, for narrow terminals
+    Basic,
+    /// Link details and error/drop counters as well
+    #[default]
+    Errors,
+    /// Everything, including packet counts
+    Full,
+}
+
+impl ColumnPreset {
+    pub fn next(&self) -> Self {
+        match self {
+            ColumnPreset::Basic => ColumnPreset::Errors,
+            ColumnPreset::Errors => ColumnPreset::Full,
+            ColumnPreset::Full => ColumnPreset::Basic,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColumnPreset::Basic => "basic",
+            ColumnPreset::Errors => "errors",
+            ColumnPreset::Full => "full",
+        }
+    }
+
+    /// Columns in display order
+    pub fn columns(&self) -> &'static [DeviceColumn] {
+        use DeviceColumn::*;
+        match self {
+            ColumnPreset::Basic => &[Interface, RxRate, TxRate, RxTotal, TxTotal],
+            ColumnPreset::Errors => &[
+                Interface, Mtu, Speed, RxRate, TxRate, RxTotal, TxTotal, RxErr, TxErr, RxDrop,
+                TxDrop,
+            ],
+            ColumnPreset::Full => &[
+                Interface, Mtu, Speed, RxRate, TxRate, RxTotal, TxTotal, RxPackets, TxPackets,
+                RxErr, TxErr, RxDrop, TxDrop,
+            ],
+        }
+    }
+}
+
+/// Interfaces shown in the device table and counted in the totals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InterfaceFilter {
+    /// Real NICs only, hiding loopback and CNI/tunnel devices
+    #[default]
+    Physical,
+    /// Everything except loopback
+    NoLoopback,
+    /// Every interface
+    All,
+}
+
+impl InterfaceFilter {
+    pub fn next(&self) -> Self {
+        match self {
+            InterfaceFilter::Physical => InterfaceFilter::NoLoopback,
+            InterfaceFilter::NoLoopback => InterfaceFilter::All,
+            InterfaceFilter::All => InterfaceFilter::Physical,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InterfaceFilter::Physical => "real",
+            InterfaceFilter::NoLoopback => "no lo",
+            InterfaceFilter::All => "all",
+        }
+    }
+
+    /// Whether the device table shows this interface
+    pub fn shows(&self, name: &str) -> bool {
+        match (self, InterfaceKind::of(name)) {
+            (InterfaceFilter::All, _) => true,
+            (InterfaceFilter::NoLoopback, kind) => kind != InterfaceKind::Loopback,
+            (InterfaceFilter::Physical, kind) => kind == InterfaceKind::Physical,
+        }
+    }
+
+    /// Whether this interface's rate counts toward the totals
+    ///
+    /// Loopback never does, even when shown.
+    pub fn counts(&self, name: &str) -> bool {
+        self.shows(name) && InterfaceKind::of(name) != InterfaceKind::Loopback
+    }
+}
+
+/// A column of the device table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceColumn {
+    Interface,
+    Mtu,
+    Speed,
+    RxRate,
+    TxRate,
+    RxTotal,
+    TxTotal,
+    RxPackets,
+    TxPackets,
+    RxErr,
+    TxErr,
+    RxDrop,
+    TxDrop,
+}
+
+impl DeviceColumn {
+    /// Header text, marking the column the table is sorted by
+    fn header(&self, sort_by: SortBy) -> &'static str {
+        match self {
+            DeviceColumn::Interface => "INTERFACE",
+            DeviceColumn::Mtu => "MTU",
+            DeviceColumn::Speed => "SPEED",
+            DeviceColumn::RxRate if sort_by == SortBy::Traffic => "RX RATE▼",
+            DeviceColumn::RxRate => "RX RATE",
+            DeviceColumn::TxRate => "TX RATE",
+            DeviceColumn::RxTotal => "RX TOTAL",
+            DeviceColumn::TxTotal => "TX TOTAL",
+            DeviceColumn::RxPackets => "RX PKTS",
+            DeviceColumn::TxPackets => "TX PKTS",
+            DeviceColumn::RxErr if sort_by == SortBy::Errors => "RX ERR▼",
+            DeviceColumn::RxErr => "RX ERR",
+            DeviceColumn::TxErr => "TX ERR",
+            DeviceColumn::RxDrop => "RX DROP",
+            DeviceColumn::TxDrop => "TX DROP",
+        }
+    }
+
+    fn width(&self) -> Constraint {
+        Constraint::Length(match self {
+            DeviceColumn::Interface => 14,
+            DeviceColumn::Mtu => 6,
+            DeviceColumn::Speed => 9,
+            DeviceColumn::RxRate | DeviceColumn::TxRate => 12,
+            DeviceColumn::RxTotal | DeviceColumn::TxTotal => 10,
+            DeviceColumn::RxPackets | DeviceColumn::TxPackets => 9,
+            DeviceColumn::RxErr
+            | DeviceColumn::TxErr
+            | DeviceColumn::RxDrop
+            | DeviceColumn::TxDrop => 8,
+        })
+    }
+}
+
+/// Packet count with a decimal suffix (e.g. "1.2M")
+fn format_packets(count: u64) -> String {
+    match count {
+        0..1_000 => count.to_string(),
+        1_000..1_000_000 => format!("{:.1}K", count as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1}M", count as f64 / 1e6),
+        _ => format!("{:.1}G", count as f64 / 1e9),
+    }
+}
+
+/// Unit used for transfer rates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateUnit {
+    /// Bytes per second, binary prefixes (KB/s = 1024 B/s)
+    #[default]
+    Bytes,
+    /// Bytes per second, decimal prefixes (kB/s = 1000 B/s)
+    SiBytes,
+    /// Bits per second, decimal prefixes (Kb/s = 1000 bit/s)
+    Bits,
+}
+
+impl RateUnit {
+    /// Cycle through binary bytes, SI bytes and bits
+    pub fn toggle(&self) -> Self {
+        match self {
+            RateUnit::Bytes => RateUnit::SiBytes,
+            RateUnit::SiBytes => RateUnit::Bits,
+            RateUnit::Bits => RateUnit::Bytes,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RateUnit::Bytes => "B/s",
+            RateUnit::SiBytes => "B/s SI",
+            RateUnit::Bits => "bit/s",
+        }
+    }
+
+    /// Format a byte rate in this unit
+    pub fn format(&self, bytes_per_sec: u64) -> String {
+        match self {
+            RateUnit::Bytes => NetDevStats::format_rate(bytes_per_sec),
+            RateUnit::SiBytes => NetDevStats::format_rate_si(bytes_per_sec),
+            RateUnit::Bits => NetDevStats::format_rate_bits(bytes_per_sec),
+        }
+    }
+
+    /// Format a rate that may not be known yet
+    ///
+    /// Rates need two samples, so until then show "-" rather than a
+    /// misleading zero.
+    pub fn format_opt(&self, bytes_per_sec: Option<u64>) -> String {
+        bytes_per_sec
+            .map(|r| self.format(r))
+            .unwrap_or_else(|| "-".to_string())
+    }
+}
+
+/// Exponential moving average of a device's RX/TX rates
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SmoothedRate {
+    /// Smoothed RX bytes per second
+    pub rx_bytes_per_sec: f64,
+    /// Smoothed TX bytes per second
+    pub tx_bytes_per_sec: f64,
+}
+
+impl SmoothedRate {
+    /// Start the average at the first measured rate
+    pub fn new(rate: &NetDevRate) -> Self {
+        Self {
+            rx_bytes_per_sec: rate.rx_bytes_per_sec as f64,
+            tx_bytes_per_sec: rate.tx_bytes_per_sec as f64,
+        }
+    }
+
+    /// Fold in a new sample; `alpha` is the weight of the new sample
+    pub fn update(&mut self, rate: &NetDevRate, alpha: f64) {
+        self.rx_bytes_per_sec += alpha * (rate.rx_bytes_per_sec as f64 - self.rx_bytes_per_sec);
+        self.tx_bytes_per_sec += alpha * (rate.tx_bytes_per_sec as f64 - self.tx_bytes_per_sec);
+    }
+
+    pub fn rx(&self) -> u64 {
+        self.rx_bytes_per_sec.round() as u64
+    }
+
+    pub fn tx(&self) -> u64 {
+        self.tx_bytes_per_sec.round() as u64
+    }
+}
+
 /// View mode for the network component
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ViewMode {
@@ -92,7 +398,147 @@ impl ViewMode {
 pub enum ConnSortBy {
     #[default]
     State, // Sort by connection state
-    Port, // Sort by local port
+    Port,    // Sort by local port
+    Process, // Sort by owning process name
+}
+
+/// Transport protocol filter for the connection list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProtocolFilter {
+    #[default]
+    All,
+    Tcp,
+    Udp,
+}
+
+impl ProtocolFilter {
+    /// Next filter in the All -> TCP -> UDP cycle
+    pub fn next(self) -> Self {
+        match self {
+            ProtocolFilter::All => ProtocolFilter::Tcp,
+            ProtocolFilter::Tcp => ProtocolFilter::Udp,
+            ProtocolFilter::Udp => ProtocolFilter::All,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ProtocolFilter::All => "all",
+            ProtocolFilter::Tcp => "TCP",
+            ProtocolFilter::Udp => "UDP",
+        }
+    }
+
+    /// Whether a netstat `l4proto` (e.g. "tcp6", "udp") passes the filter
+    pub fn matches(self, protocol: &str) -> bool {
+        match self {
+            ProtocolFilter::All => true,
+            ProtocolFilter::Tcp => protocol.starts_with("tcp"),
+            ProtocolFilter::Udp => protocol.starts_with("udp"),
+        }
+    }
+}
+
+/// How the connection list is collapsed into per-peer rows ("top talkers")
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnGrouping {
+    /// One row per connection
+    #[default]
+    Off,
+    /// One row per remote IP
+    RemoteIp,
+    /// One row per remote IP:port
+    RemoteEndpoint,
+}
+
+impl ConnGrouping {
+    /// Next grouping in the off -> IP -> IP:port cycle
+    pub fn next(self) -> Self {
+        match self {
+            ConnGrouping::Off => ConnGrouping::RemoteIp,
+            ConnGrouping::RemoteIp => ConnGrouping::RemoteEndpoint,
+            ConnGrouping::RemoteEndpoint => ConnGrouping::Off,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ConnGrouping::Off => "off",
+            ConnGrouping::RemoteIp => "by IP",
+            ConnGrouping::RemoteEndpoint => "by IP:port",
+        }
+    }
+
+    /// Peer a connection is counted under, `None` for sockets without one
+    fn peer(self, conn: &ConnectionInfo) -> Option<String> {
+        if conn.remote_port == 0 {
+            return None;
+        }
+        match self {
+            ConnGrouping::Off => None,
+            ConnGrouping::RemoteIp => Some(conn.remote_ip.clone()),
+            ConnGrouping::RemoteEndpoint if conn.remote_ip.contains(':') => {
+                Some(format!("[{}]:{}", conn.remote_ip, conn.remote_port))
+            }
+            ConnGrouping::RemoteEndpoint => {
+                Some(format!("{}:{}", conn.remote_ip, conn.remote_port))
+            }
+        }
+    }
+}
+
+/// Connections to one remote peer
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PeerGroup {
+    /// Remote IP, or IP:port
+    pub peer: String,
+    pub count: usize,
+    pub established: usize,
+    pub time_wait: usize,
+    pub close_wait: usize,
+    /// Processes owning the connections, sorted
+    pub processes: Vec<String>,
+    /// Local ports the peer is connected to, sorted
+    pub local_ports: Vec<u32>,
+}
+
+/// Collapse connections into per-peer groups, busiest first
+///
+/// Listening sockets and others without a remote end are left out.
+pub fn group_connections(conns: &[ConnectionInfo], grouping: ConnGrouping) -> Vec<PeerGroup> {
+    let mut groups: HashMap<String, PeerGroup> = HashMap::new();
+    for conn in conns {
+        let Some(peer) = grouping.peer(conn) else {
+            continue;
+        };
+        let group = groups.entry(peer.clone()).or_insert_with(|| PeerGroup {
+            peer,
+            ..Default::default()
+        });
+        group.count += 1;
+        match conn.state {
+            ConnectionState::Established => group.established += 1,
+            ConnectionState::TimeWait => group.time_wait += 1,
+            ConnectionState::CloseWait => group.close_wait += 1,
+            _ => {}
+        }
+        if let Some(name) = &conn.process_name
+            && !group.processes.contains(name)
+        {
+            group.processes.push(name.clone());
+        }
+        if !group.local_ports.contains(&conn.local_port) {
+            group.local_ports.push(conn.local_port);
+        }
+    }
+
+    let mut groups: Vec<PeerGroup> = groups.into_values().collect();
+    for group in &mut groups {
+        group.processes.sort();
+        group.local_ports.sort_unstable();
+    }
+    groups.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.peer.cmp(&b.peer)));
+    groups
 }
 
 /// Pending action requiring confirmation
@@ -174,12 +620,16 @@ impl Default for CaptureData {
 /// Async-loaded network data
 #[derive(Debug, Clone, Default)]
 pub struct NetworkData {
-    /// Current device statistics
+    /// Current device statistics, after the interface filter
     pub devices: Vec<NetDevStats>,
+    /// Every device in the latest sample
+    pub all_devices: Vec<NetDevStats>,
     /// Previous device stats (for rate calculation)
     pub prev_devices: HashMap<String, NetDevStats>,
     /// Calculated rates per device
     pub rates: HashMap<String, NetDevRate>,
+    /// Smoothed rates per device, shown instead of `rates` when smoothing is on
+    pub smoothed_rates: HashMap<String, SmoothedRate>,
     /// Time of last sample
     pub last_sample: Option<Instant>,
 
@@ -205,6 +655,57 @@ pub struct NetworkData {
     pub kubespan_peers: Vec<KubeSpanPeerStatus>,
     /// KubeSpan enabled status
     pub kubespan_enabled: Option<bool>,
+
+    /// Link status per interface (MTU, speed, duplex)
+    pub links: HashMap<String, LinkStatus>,
+}
+
+/// Overlay link whose full-size frames don't fit through an uplink once encapsulated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MtuShortfall {
+    /// Overlay interface name
+    pub overlay: String,
+    /// Uplink MTU the overlay needs
+    pub required: u32,
+    /// Largest overlay MTU the uplink can carry
+    pub max_overlay_mtu: u32,
+}
+
+impl NetworkData {
+    /// The overlay needing the most headroom, if this uplink is too small for it
+    ///
+    /// An overlay needs its own MTU plus its encapsulation overhead on the
+    /// uplink, so the default flannel.1 at 1450 over a 1500 uplink is fine.
+    pub fn mtu_shortfall(&self, iface: &str) -> Option<MtuShortfall> {
+        let uplink = self
+            .links
+            .get(iface)
+            .filter(|l| l.is_uplink() && l.mtu > 0)?;
+        self.links
+            .values()
+            .filter(|l| l.mtu > 0)
+            .filter_map(|l| Some((l, l.encapsulation_overhead()?)))
+            .filter(|(l, overhead)| l.mtu + overhead > uplink.mtu)
+            .max_by(|(a, a_overhead), (b, b_overhead)| {
+                (a.mtu + a_overhead)
+                    .cmp(&(b.mtu + b_overhead))
+                    .then_with(|| b.id.cmp(&a.id))
+            })
+            .map(|(l, overhead)| MtuShortfall {
+                overlay: l.id.clone(),
+                required: l.mtu + overhead,
+                max_overlay_mtu: uplink.mtu.saturating_sub(overhead),
+            })
+    }
+
+    /// Age of the rate sample, if it is older than `STALE_RATE_INTERVALS` refreshes
+    ///
+    /// Rates are only recomputed on refresh, so with auto-refresh paused (or
+    /// fetches failing) they would otherwise keep looking current.
+    pub fn rate_staleness(&self, now: Instant, interval: Duration) -> Option<Duration> {
+        let age = now.saturating_duration_since(self.last_sample?);
+        (age > interval * STALE_RATE_INTERVALS).then_some(age)
+    }
 }
 
 /// Network stats component for viewing node network interfaces
@@ -223,9 +724,23 @@ pub struct NetworkStatsComponent {
     table_state: TableState,
     /// Current sort order
     sort_by: SortBy,
+    /// Columns shown in the device table
+    column_preset: ColumnPreset,
+    /// Interfaces shown in the device table
+    interface_filter: InterfaceFilter,
 
     /// Auto-refresh enabled
     auto_refresh: bool,
+    /// Unit for RX/TX rates
+    rate_unit: RateUnit,
+    /// Show smoothed instead of instantaneous RX/TX rates
+    rate_smoothing: bool,
+    /// Weight of the newest sample in smoothed rates
+    smoothing_alpha: f64,
+    /// Connection counts above which TIME_WAIT/CLOSE_WAIT are flagged
+    conn_thresholds: ConnectionThresholds,
+    /// Ports shown in the service health row
+    key_ports: Vec<KeyPort>,
 
     /// Current view mode (Interfaces or Connections drill-down)
     view_mode: ViewMode,
@@ -241,8 +756,17 @@ pub struct NetworkStatsComponent {
     conn_sort_by: ConnSortBy,
     /// Filter to listening only
     listening_only: bool,
+    /// Filter by transport protocol
+    protocol_filter: ProtocolFilter,
     /// Show all connections (bypass interface filter)
     show_all_connections: bool,
+    /// Only show connections owned by this process name
+    process_filter: Option<String>,
+    /// Collapse connections into per-peer rows
+    conn_grouping: ConnGrouping,
+
+    /// Show the selected connection's cells in full instead of truncated
+    wrap_selected: bool,
 
     /// Visual selection anchor (for V mode) - stores connection index
     conn_selection_start: Option<usize>,
@@ -251,8 +775,8 @@ pub struct NetworkStatsComponent {
 
     /// Pending action requiring confirmation
     pending_action: Option<PendingAction>,
-    /// Status message to show (e.g., "Service restarted successfully")
-    status_message: Option<(String, Instant)>,
+    /// Messages waiting to be shown as toasts, handed to the app on Tick
+    notifications: VecDeque<(String, NotifyLevel)>,
     /// Service ID pending restart (to be executed in update)
     pending_restart_service: Option<String>,
 
@@ -267,6 +791,9 @@ pub struct NetworkStatsComponent {
     /// Packet capture state and data
     capture: CaptureData,
 
+    /// Node summary shown in the header
+    node_info: NodeInfo,
+
     /// Client for API calls
     client: Option<TalosClient>,
 
@@ -284,6 +811,7 @@ impl Default for NetworkStatsComponent {
 
 impl NetworkStatsComponent {
     pub fn new(hostname: String, address: String) -> Self {
+        let node_info = NodeInfo::new(hostname.clone(), address.clone());
         let mut table_state = TableState::default();
         table_state.select(Some(0));
         let mut conn_table_state = TableState::default();
@@ -296,7 +824,14 @@ impl NetworkStatsComponent {
             selected: 0,
             table_state,
             sort_by: SortBy::Traffic,
+            column_preset: ColumnPreset::default(),
+            interface_filter: InterfaceFilter::default(),
             auto_refresh: true,
+            rate_unit: RateUnit::default(),
+            rate_smoothing: false,
+            smoothing_alpha: DEFAULT_SMOOTHING_ALPHA,
+            conn_thresholds: ConnectionThresholds::default(),
+            key_ports: key_ports(&[]),
             view_mode: ViewMode::Interfaces,
             selected_interface: None,
             filtered_connections: Vec::new(),
@@ -304,16 +839,21 @@ impl NetworkStatsComponent {
             conn_table_state,
             conn_sort_by: ConnSortBy::State,
             listening_only: false,
+            protocol_filter: ProtocolFilter::All,
             show_all_connections: false,
+            process_filter: None,
+            conn_grouping: ConnGrouping::Off,
+            wrap_selected: false,
             conn_selection_start: None,
             conn_viewport_height: 20, // Will be updated on draw
             pending_action: None,
-            status_message: None,
+            notifications: VecDeque::new(),
             pending_restart_service: None,
             show_output_pane: false,
             command_output: None,
             file_viewer: None,
             capture: CaptureData::default(),
+            node_info,
             client: None,
             kubespan_selected: 0,
             kubespan_table_state: {
@@ -334,16 +874,95 @@ impl NetworkStatsComponent {
         self.state.data_mut()
     }
 
+    /// Set the node summary shown in the header
+    pub fn set_node_info(&mut self, info: NodeInfo) {
+        self.node_info = info;
+    }
+
+    /// Node this view shows
+    pub fn node_info(&self) -> &NodeInfo {
+        &self.node_info
+    }
+
     /// Set the client for API calls
     pub fn set_client(&mut self, client: TalosClient) {
         self.client = Some(client);
     }
 
+    /// Queue a toast, handed to the app on the next tick
+    fn notify(&mut self, message: impl Into<String>, level: NotifyLevel) {
+        self.notifications.push_back((message.into(), level));
+    }
+
+    /// Client targeting this node
+    pub fn client(&self) -> Option<&TalosClient> {
+        self.client.as_ref()
+    }
+
+    /// Whether the connections drill-down is open (where `R` restarts a service)
+    pub fn in_connections_view(&self) -> bool {
+        self.view_mode == ViewMode::Connections
+    }
+
+    /// Set the unit for RX/TX rates
+    pub fn set_rate_unit(&mut self, unit: RateUnit) {
+        self.rate_unit = unit;
+    }
+
+    /// Set the TIME_WAIT/CLOSE_WAIT counts that trigger a warning
+    pub fn set_connection_thresholds(&mut self, thresholds: ConnectionThresholds) {
+        self.conn_thresholds = thresholds;
+    }
+
+    /// Show smoothed instead of instantaneous RX/TX rates
+    pub fn set_rate_smoothing(&mut self, enabled: bool) {
+        self.rate_smoothing = enabled;
+    }
+
+    /// Set the weight of the newest sample in smoothed rates
+    pub fn set_smoothing_alpha(&mut self, alpha: f64) {
+        self.smoothing_alpha = alpha;
+    }
+
+    /// RX/TX rates shown for a device: smoothed if enabled, else instantaneous
+    fn displayed_rate(&self, data: &NetworkData, name: &str) -> Option<(u64, u64)> {
+        if self.rate_smoothing
+            && let Some(smoothed) = data.smoothed_rates.get(name)
+        {
+            return Some((smoothed.rx(), smoothed.tx()));
+        }
+        data.rates
+            .get(name)
+            .map(|r| (r.rx_bytes_per_sec, r.tx_bytes_per_sec))
+    }
+
+    /// Set the ports shown in the service health row
+    pub fn set_key_ports(&mut self, key_ports: Vec<KeyPort>) {
+        self.key_ports = key_ports;
+    }
+
     /// Set error message
-    pub fn set_error(&mut self, error: String) {
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
         self.state.set_error(error);
     }
 
+    /// How long ago rates were sampled, if they are too old to trust
+    fn rate_staleness(&self) -> Option<Duration> {
+        let interval = Duration::from_secs(AUTO_REFRESH_INTERVAL_SECS);
+        self.data()?.rate_staleness(Instant::now(), interval)
+    }
+
+    /// Style for a rate value, dimmed once the sample is stale
+    fn rate_style(&self, color: Color) -> Style {
+        if self.rate_staleness().is_some() {
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::DIM)
+        } else {
+            Style::default().fg(color)
+        }
+    }
+
     /// Refresh network data from the node
     pub async fn refresh(&mut self) -> Result<()> {
         let Some(client) = self.client.clone() else {
@@ -358,6 +977,14 @@ impl NetworkStatsComponent {
             self.state.set_data(NetworkData::default());
         }
 
+        // Remember what was selected so it survives reordering
+        let prev_device = self.selected_device_name();
+        let prev_conn = self.selected_connection().as_ref().map(connection_key);
+        let prev_peer = self
+            .conn_groups()
+            .get(self.conn_selected)
+            .map(|g| g.peer.clone());
+
         let timeout = std::time::Duration::from_secs(10);
 
         // Fetch interface stats, netstat data, and services concurrently
@@ -378,18 +1005,17 @@ impl NetworkStatsComponent {
                     self.update_devices(node_data.devices);
                 } else if let Some(data) = self.data_mut() {
                     data.devices.clear();
+                    data.all_devices.clear();
                     data.rates.clear();
                 }
             }
             Ok(Err(e)) => {
-                self.set_error(format!(
-                    "Failed to fetch network stats: {} (node: {})",
-                    e, self.address
-                ));
+                let context = format!("Failed to fetch network stats (node: {})", self.address);
+                self.set_error(ComponentError::from(&e).context(&context));
                 return Ok(());
             }
             Err(_) => {
-                self.set_error(format!("Request timed out after {}s", timeout.as_secs()));
+                self.set_error(ComponentError::timeout(timeout.as_secs()));
                 return Ok(());
             }
         }
@@ -446,14 +1072,40 @@ impl NetworkStatsComponent {
         // Update service health based on connection data
         self.update_service_health();
 
-        // Fetch KubeSpan data via talosctl (runs synchronously in blocking task)
+        // Keep the drill-down list in sync with the new connections
+        if self.view_mode == ViewMode::Connections {
+            self.filter_connections_for_interface();
+        }
+        self.conn_selected = if self.conn_grouping == ConnGrouping::Off {
+            reselect_index(
+                &self.get_filtered_connections(),
+                prev_conn.as_ref(),
+                self.conn_selected,
+                connection_key,
+            )
+        } else {
+            reselect_index(
+                &self.conn_groups(),
+                prev_peer.as_ref(),
+                self.conn_selected,
+                |g| g.peer.clone(),
+            )
+        };
+        self.conn_table_state.select(Some(self.conn_selected));
+
+        // Fetch KubeSpan and link data via talosctl (runs synchronously in blocking task)
         self.refresh_kubespan_data().await;
+        self.refresh_link_data().await;
 
-        // Reset selection if needed
-        let device_count = self.data().map(|d| d.devices.len()).unwrap_or(0);
-        if device_count > 0 && self.selected >= device_count {
-            self.selected = 0;
-        }
+        // Restore selection by device name, falling back to the old position
+        self.selected = self
+            .data()
+            .map(|data| {
+                reselect_index(&data.devices, prev_device.as_ref(), self.selected, |d| {
+                    d.name.clone()
+                })
+            })
+            .unwrap_or(self.selected);
         self.table_state.select(Some(self.selected));
 
         self.state.mark_loaded();
@@ -464,6 +1116,10 @@ impl NetworkStatsComponent {
     /// Refresh KubeSpan peer data via talosctl
     async fn refresh_kubespan_data(&mut self) {
         let node = self.address.clone();
+        let prev_peer = self
+            .data()
+            .and_then(|d| d.kubespan_peers.get(self.kubespan_selected))
+            .map(|p| p.id.clone());
 
         // Run talosctl commands in a blocking task
         let result = tokio::task::spawn_blocking(move || {
@@ -483,16 +1139,15 @@ impl NetworkStatsComponent {
 
         match result {
             Ok((enabled, peers)) => {
+                // Restore selection by peer ID, falling back to the old position
+                self.kubespan_selected =
+                    reselect_index(&peers, prev_peer.as_ref(), self.kubespan_selected, |p| {
+                        p.id.clone()
+                    });
                 if let Some(data) = self.data_mut() {
                     data.kubespan_enabled = enabled;
                     data.kubespan_peers = peers;
                 }
-
-                // Reset selection if needed
-                let peer_count = self.data().map(|d| d.kubespan_peers.len()).unwrap_or(0);
-                if peer_count > 0 && self.kubespan_selected >= peer_count {
-                    self.kubespan_selected = 0;
-                }
                 self.kubespan_table_state
                     .select(Some(self.kubespan_selected));
             }
@@ -502,6 +1157,19 @@ impl NetworkStatsComponent {
         }
     }
 
+    /// Refresh link status (MTU, speed, duplex) via talosctl
+    async fn refresh_link_data(&mut self) {
+        let node = self.address.clone();
+        let result = tokio::task::spawn_blocking(move || get_link_status(&node)).await;
+
+        // Link info is supplementary - keep the previous data on failure
+        if let Ok(Ok(links)) = result
+            && let Some(data) = self.data_mut()
+        {
+            data.links = links.into_iter().map(|l| (l.id.clone(), l)).collect();
+        }
+    }
+
     /// Update connections and calculate counts
     fn update_connections(&mut self, connections: Vec<ConnectionInfo>) {
         if let Some(data) = self.data_mut() {
@@ -512,25 +1180,25 @@ impl NetworkStatsComponent {
 
     /// Update service health indicators based on connection data
     fn update_service_health(&mut self) {
+        let ports: Vec<u16> = self.key_ports.iter().map(|k| k.port).collect();
         let Some(data) = self.data_mut() else { return };
         data.service_health.clear();
 
-        // Key ports to monitor for Kubernetes
-        let key_ports: &[u16] = &[6443, 2379, 10250, 10259, 10257];
-
-        for port in key_ports {
+        for port in ports {
             // Check if port is listening
             let is_listening = data
                 .connections
                 .iter()
-                .any(|c| c.local_port == *port as u32 && c.state == ConnectionState::Listen);
-            data.service_health.insert(*port, is_listening);
+                .any(|c| c.local_port == port as u32 && c.state == ConnectionState::Listen);
+            data.service_health.insert(port, is_listening);
         }
     }
 
     /// Update devices and calculate rates
     fn update_devices(&mut self, new_devices: Vec<NetDevStats>) {
         let sort_by = self.sort_by;
+        let filter = self.interface_filter;
+        let alpha = self.smoothing_alpha;
         let Some(data) = self.data_mut() else { return };
 
         let now = Instant::now();
@@ -544,6 +1212,10 @@ impl NetworkStatsComponent {
             for dev in &new_devices {
                 if let Some(prev) = data.prev_devices.get(&dev.name) {
                     let rate = NetDevRate::from_delta(prev, dev, elapsed_secs);
+                    data.smoothed_rates
+                        .entry(dev.name.clone())
+                        .and_modify(|s| s.update(&rate, alpha))
+                        .or_insert_with(|| SmoothedRate::new(&rate));
                     data.rates.insert(dev.name.clone(), rate);
                 }
             }
@@ -556,17 +1228,43 @@ impl NetworkStatsComponent {
         }
         data.last_sample = Some(now);
 
-        // Calculate totals
-        data.total_rx_rate = data.rates.values().map(|r| r.rx_bytes_per_sec).sum();
-        data.total_tx_rate = data.rates.values().map(|r| r.tx_bytes_per_sec).sum();
-        data.total_errors = new_devices.iter().map(|d| d.total_errors()).sum();
-        data.total_dropped = new_devices.iter().map(|d| d.total_dropped()).sum();
+        data.all_devices = new_devices;
+        Self::filter_devices(data, filter, sort_by);
+    }
+
+    /// Rebuild the shown devices and the totals from the latest sample
+    fn filter_devices(data: &mut NetworkData, filter: InterfaceFilter, sort_by: SortBy) {
+        data.devices = data
+            .all_devices
+            .iter()
+            .filter(|d| filter.shows(&d.name))
+            .cloned()
+            .collect();
+
+        // Loopback would count local traffic on top of the real thing
+        let counted = || data.rates.values().filter(|r| filter.counts(&r.name));
+        data.total_rx_rate = counted().map(|r| r.rx_bytes_per_sec).sum();
+        data.total_tx_rate = counted().map(|r| r.tx_bytes_per_sec).sum();
+        data.total_errors = data.devices.iter().map(|d| d.total_errors()).sum();
+        data.total_dropped = data.devices.iter().map(|d| d.total_dropped()).sum();
 
-        // Sort and store devices
-        data.devices = new_devices;
         Self::sort_devices_by(data, sort_by);
     }
 
+    /// Switch to the next interface filter, keeping the selected device if
+    /// it is still shown
+    fn cycle_interface_filter(&mut self) {
+        self.interface_filter = self.interface_filter.next();
+        let prev_device = self.selected_device_name();
+        let (filter, sort_by, selected) = (self.interface_filter, self.sort_by, self.selected);
+        let Some(data) = self.data_mut() else { return };
+        Self::filter_devices(data, filter, sort_by);
+        self.selected = reselect_index(&data.devices, prev_device.as_ref(), selected, |d| {
+            d.name.clone()
+        });
+        self.table_state.select(Some(self.selected));
+    }
+
     /// Sort devices based on sort order (static helper to avoid borrow issues)
     fn sort_devices_by(data: &mut NetworkData, sort_by: SortBy) {
         match sort_by {
@@ -661,6 +1359,12 @@ impl NetworkStatsComponent {
         let mut conns: Vec<_> = source
             .into_iter()
             .filter(|c| !self.listening_only || c.state == ConnectionState::Listen)
+            .filter(|c| self.protocol_filter.matches(&c.protocol))
+            .filter(|c| {
+                self.process_filter
+                    .as_ref()
+                    .is_none_or(|p| c.process_name.as_ref() == Some(p))
+            })
             .collect();
 
         match self.conn_sort_by {
@@ -683,14 +1387,53 @@ impl NetworkStatsComponent {
             ConnSortBy::Port => {
                 conns.sort_by(|a, b| a.local_port.cmp(&b.local_port));
             }
+            ConnSortBy::Process => {
+                // Unattributed sockets go last
+                conns.sort_by(|a, b| {
+                    let key = |c: &ConnectionInfo| {
+                        (
+                            c.process_name.is_none(),
+                            c.process_name.as_deref().map(str::to_lowercase),
+                            c.process_pid,
+                        )
+                    };
+                    key(a)
+                        .cmp(&key(b))
+                        .then_with(|| a.local_port.cmp(&b.local_port))
+                });
+            }
         }
 
         conns
     }
 
+    /// Filtered connections grouped by peer, when grouping is on
+    fn conn_groups(&self) -> Vec<PeerGroup> {
+        group_connections(&self.get_filtered_connections(), self.conn_grouping)
+    }
+
+    /// Rows in the connection table: peer groups or single connections
+    fn conn_row_count(&self) -> usize {
+        if self.conn_grouping == ConnGrouping::Off {
+            self.get_filtered_connections().len()
+        } else {
+            self.conn_groups().len()
+        }
+    }
+
+    /// Connection under the cursor; none while rows are peer groups
+    fn selected_connection(&self) -> Option<ConnectionInfo> {
+        if self.conn_grouping != ConnGrouping::Off {
+            return None;
+        }
+        self.get_filtered_connections()
+            .get(self.conn_selected)
+            .cloned()
+    }
+
     /// Navigate to previous connection
     fn conn_select_prev(&mut self) {
-        let count = self.get_filtered_connections().len();
+        let count = self.conn_row_count();
         if count > 0 && self.conn_selected > 0 {
             self.conn_selected -= 1;
             self.conn_table_state.select(Some(self.conn_selected));
@@ -699,7 +1442,7 @@ impl NetworkStatsComponent {
 
     /// Navigate to next connection
     fn conn_select_next(&mut self) {
-        let count = self.get_filtered_connections().len();
+        let count = self.conn_row_count();
         if count > 0 {
             self.conn_selected = (self.conn_selected + 1).min(count - 1);
             self.conn_table_state.select(Some(self.conn_selected));
@@ -708,7 +1451,7 @@ impl NetworkStatsComponent {
 
     /// Jump to first connection
     fn conn_select_first(&mut self) {
-        let count = self.get_filtered_connections().len();
+        let count = self.conn_row_count();
         if count > 0 {
             self.conn_selected = 0;
             self.conn_table_state.select(Some(self.conn_selected));
@@ -717,7 +1460,7 @@ impl NetworkStatsComponent {
 
     /// Jump to last connection
     fn conn_select_last(&mut self) {
-        let count = self.get_filtered_connections().len();
+        let count = self.conn_row_count();
         if count > 0 {
             self.conn_selected = count - 1;
             self.conn_table_state.select(Some(self.conn_selected));
@@ -737,6 +1480,16 @@ impl NetworkStatsComponent {
         self.conn_table_state.select(Some(0));
     }
 
+    /// Open the connections drill-down with every connection on the node
+    pub fn open_all_connections(&mut self) {
+        self.selected_interface = None;
+        self.filter_connections_for_interface();
+        self.show_all_connections = true;
+        self.view_mode = ViewMode::Connections;
+        self.conn_selected = 0;
+        self.conn_table_state.select(Some(0));
+    }
+
     /// Filter connections based on the selected interface
     fn filter_connections_for_interface(&mut self) {
         let Some(data) = self.data() else {
@@ -798,6 +1551,21 @@ impl NetworkStatsComponent {
         self.selected_interface = None;
         self.filtered_connections.clear();
         self.show_all_connections = false;
+        self.process_filter = None;
+        self.conn_selection_start = None;
+    }
+
+    /// Show only the selected connection's process, or clear that filter
+    fn toggle_process_filter(&mut self) {
+        if self.process_filter.take().is_none() {
+            // Nothing to filter on for sockets without an owning process
+            self.process_filter = self.selected_connection().and_then(|c| c.process_name);
+            if self.process_filter.is_none() {
+                return;
+            }
+        }
+        self.conn_selected = 0;
+        self.conn_table_state.select(Some(0));
         self.conn_selection_start = None;
     }
 
@@ -870,19 +1638,26 @@ impl NetworkStatsComponent {
 
     /// Yank (copy) selected connections or current connection to clipboard
     fn yank_conn_selection(&self) -> (bool, usize) {
-        let conns = self.get_filtered_connections();
+        let rows: Vec<String> = if self.conn_grouping == ConnGrouping::Off {
+            self.get_filtered_connections()
+                .iter()
+                .map(Self::format_connection)
+                .collect()
+        } else {
+            self.conn_groups()
+                .iter()
+                .map(|g| format!("{:>6} {}", g.count, g.peer))
+                .collect()
+        };
 
         let lines: Vec<String> = if let Some((start, end)) = self.conn_selection_range() {
-            // Yank all selected connections
+            // Yank all selected rows
             (start..=end)
-                .filter_map(|idx| conns.get(idx).map(Self::format_connection))
+                .filter_map(|idx| rows.get(idx).cloned())
                 .collect()
         } else {
-            // Yank current connection only
-            conns
-                .get(self.conn_selected)
-                .map(|c| vec![Self::format_connection(c)])
-                .unwrap_or_default()
+            // Yank current row only
+            rows.get(self.conn_selected).cloned().into_iter().collect()
         };
 
         if lines.is_empty() {
@@ -907,7 +1682,7 @@ impl NetworkStatsComponent {
 
     /// Page down in connection list
     fn conn_page_down(&mut self) {
-        let count = self.get_filtered_connections().len();
+        let count = self.conn_row_count();
         let page_size = self.conn_viewport_height.saturating_sub(2) as usize;
         if count > 0 {
             self.conn_selected = (self.conn_selected + page_size).min(count - 1);
@@ -924,7 +1699,7 @@ impl NetworkStatsComponent {
 
     /// Half page down in connection list
     fn conn_half_page_down(&mut self) {
-        let count = self.get_filtered_connections().len();
+        let count = self.conn_row_count();
         let half = (self.conn_viewport_height / 2).max(1) as usize;
         if count > 0 {
             self.conn_selected = (self.conn_selected + half).min(count - 1);
@@ -934,12 +1709,22 @@ impl NetworkStatsComponent {
 
     /// Draw the header
     fn draw_header(&self, frame: &mut Frame, area: Rect) {
-        let device_count = format!(
-            "{} ifaces",
-            self.data().map(|d| d.devices.len()).unwrap_or(0)
-        );
+        let (shown, hidden) = self
+            .data()
+            .map(|d| (d.devices.len(), d.all_devices.len() - d.devices.len()))
+            .unwrap_or((0, 0));
+        let device_count = if hidden > 0 {
+            format!("{} ifaces ({} hidden)", shown, hidden)
+        } else {
+            format!("{} ifaces", shown)
+        };
 
         let auto_indicator = if self.auto_refresh { "" } else { " [AUTO:OFF]" };
+        let smoothing_indicator = if self.rate_smoothing {
+            " [SMOOTHED]"
+        } else {
+            ""
+        };
 
         // Build tab bar for right side (Connections is a subscreen, not a tab)
         let tab_ifaces = if self.view_mode == ViewMode::Interfaces {
@@ -963,20 +1748,27 @@ impl NetworkStatsComponent {
             Span::raw("")
         };
 
-        let spans = vec![
-            Span::styled("Network: ", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(&self.hostname),
-            Span::styled(" (", Style::default().fg(Color::DarkGray)),
-            Span::raw(&self.address),
-            Span::styled(")", Style::default().fg(Color::DarkGray)),
+        let mut spans = vec![Span::styled(
+            "Network: ",
+            Style::default().add_modifier(Modifier::BOLD),
+        )];
+        spans.extend(node_info_spans(&self.node_info));
+        spans.extend([
             Span::raw("  "),
             Span::styled(&device_count, Style::default().fg(Color::DarkGray)),
+        ]);
+        if let Some(age) = self.state.elapsed_since_refresh() {
+            let interval = Duration::from_secs(AUTO_REFRESH_INTERVAL_SECS);
+            spans.extend([Span::raw("  "), last_updated_span(age, interval)]);
+        }
+        spans.extend([
             Span::styled(auto_indicator, Style::default().fg(Color::Yellow)),
+            Span::styled(smoothing_indicator, Style::default().fg(Color::Cyan)),
             Span::raw("  │ "),
             tab_ifaces,
             conns_indicator,
             tab_kubespan,
-        ];
+        ]);
 
         let header = Paragraph::new(Line::from(spans));
         frame.render_widget(header, area);
@@ -987,20 +1779,29 @@ impl NetworkStatsComponent {
         let (total_errors, total_dropped, total_rx_rate, total_tx_rate) = self
             .data()
             .map(|d| {
+                let has_rates = !d.rates.is_empty();
+                let (rx, tx) = if self.rate_smoothing {
+                    d.smoothed_rates
+                        .iter()
+                        .filter(|(name, _)| self.interface_filter.counts(name))
+                        .fold((0, 0), |(rx, tx), (_, s)| (rx + s.rx(), tx + s.tx()))
+                } else {
+                    (d.total_rx_rate, d.total_tx_rate)
+                };
                 (
                     d.total_errors,
                     d.total_dropped,
-                    d.total_rx_rate,
-                    d.total_tx_rate,
+                    has_rates.then_some(rx),
+                    has_rates.then_some(tx),
                 )
             })
-            .unwrap_or((0, 0, 0, 0));
+            .unwrap_or((0, 0, None, None));
 
         let has_errors = total_errors > 0 || total_dropped > 0;
         let warning = if has_errors { "! " } else { "" };
 
-        let rx_rate = NetDevStats::format_rate(total_rx_rate);
-        let tx_rate = NetDevStats::format_rate(total_tx_rate);
+        let rx_rate = self.rate_unit.format_opt(total_rx_rate);
+        let tx_rate = self.rate_unit.format_opt(total_tx_rate);
 
         let mut spans = vec![
             Span::styled(
@@ -1011,12 +1812,21 @@ impl NetworkStatsComponent {
             ),
             Span::styled("Total:  ", Style::default().add_modifier(Modifier::BOLD)),
             Span::styled("RX ", Style::default().fg(Color::Green)),
-            Span::raw(&rx_rate),
+            Span::styled(&rx_rate, self.rate_style(Color::Reset)),
             Span::raw("  "),
             Span::styled("TX ", Style::default().fg(Color::Blue)),
-            Span::raw(&tx_rate),
+            Span::styled(&tx_rate, self.rate_style(Color::Reset)),
         ];
 
+        if total_rx_rate.is_some()
+            && let Some(age) = self.rate_staleness()
+        {
+            spans.push(Span::styled(
+                format!("  (stale, sampled {}s ago)", age.as_secs()),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+
         // Add errors/dropped if any
         spans.push(Span::raw("   "));
         let errors_style = if total_errors > 0 {
@@ -1050,7 +1860,7 @@ impl NetworkStatsComponent {
             .data()
             .map(|d| d.conn_counts.clone())
             .unwrap_or_default();
-        let has_warnings = cc.has_warnings();
+        let has_warnings = cc.has_warnings(&self.conn_thresholds);
         let warning = if has_warnings { "! " } else { "" };
 
         let mut spans = vec![
@@ -1079,8 +1889,8 @@ impl NetworkStatsComponent {
         spans.push(Span::styled("LISTEN", Style::default().fg(Color::DarkGray)));
         spans.push(Span::raw("  "));
 
-        // TIME_WAIT count (yellow if > 100)
-        let tw_style = if cc.time_wait > 100 {
+        // TIME_WAIT count (yellow above the threshold)
+        let tw_style = if cc.high_time_wait(&self.conn_thresholds) {
             Style::default().fg(Color::Yellow)
         } else {
             Style::default()
@@ -1092,8 +1902,8 @@ impl NetworkStatsComponent {
         ));
         spans.push(Span::raw("  "));
 
-        // CLOSE_WAIT count (red if > 0)
-        let cw_style = if cc.close_wait > 0 {
+        // CLOSE_WAIT count (red above the threshold)
+        let cw_style = if cc.high_close_wait(&self.conn_thresholds) {
             Style::default().fg(Color::Red)
         } else {
             Style::default()
@@ -1110,15 +1920,6 @@ impl NetworkStatsComponent {
 
     /// Draw service health indicators
     fn draw_service_health(&self, frame: &mut Frame, area: Rect) {
-        // Define services with their expected ports
-        let services = [
-            ("API", 6443_u16),
-            ("Etcd", 2379),
-            ("Kubelet", 10250),
-            ("Scheduler", 10259),
-            ("Controller", 10257),
-        ];
-
         let service_health = self
             .data()
             .map(|d| d.service_health.clone())
@@ -1126,9 +1927,13 @@ impl NetworkStatsComponent {
 
         let mut spans = Vec::new();
 
-        for (name, port) in services {
-            let is_healthy = service_health.get(&port).copied().unwrap_or(false);
-            let indicator = if is_healthy { "●" } else { "○" };
+        for KeyPort { label, port } in &self.key_ports {
+            let is_healthy = service_health.get(port).copied().unwrap_or(false);
+            let indicator = if is_healthy {
+                glyphs().filled
+            } else {
+                glyphs().hollow
+            };
             let color = if is_healthy { Color::Green } else { Color::Red };
 
             if !spans.is_empty() {
@@ -1136,7 +1941,7 @@ impl NetworkStatsComponent {
             }
 
             spans.push(Span::styled(
-                format!("{}:{} ", name, port),
+                format!("{}:{} ", label, port),
                 Style::default().fg(Color::DarkGray),
             ));
             spans.push(Span::styled(indicator, Style::default().fg(color)));
@@ -1164,10 +1969,10 @@ impl NetworkStatsComponent {
         }
 
         // Connection warnings
-        if conn_counts.time_wait > 100 {
+        if conn_counts.high_time_wait(&self.conn_thresholds) {
             messages.push(format!("High TIME_WAIT ({})", conn_counts.time_wait));
         }
-        if conn_counts.close_wait > 0 {
+        if conn_counts.high_close_wait(&self.conn_thresholds) {
             messages.push(format!("CLOSE_WAIT ({})", conn_counts.close_wait));
         }
         if conn_counts.syn_sent > 0 {
@@ -1190,30 +1995,13 @@ impl NetworkStatsComponent {
 
     /// Draw the device table
     fn draw_device_table(&mut self, frame: &mut Frame, area: Rect) {
-        // Build column headers with sort indicators
-        let rx_rate_header = if self.sort_by == SortBy::Traffic {
-            "RX RATE▼"
-        } else {
-            "RX RATE"
-        };
-        let rx_err_header = if self.sort_by == SortBy::Errors {
-            "RX ERR▼"
-        } else {
-            "RX ERR"
-        };
+        let columns = self.column_preset.columns();
 
-        let header_cells = [
-            Cell::from("INTERFACE"),
-            Cell::from(rx_rate_header),
-            Cell::from("TX RATE"),
-            Cell::from(rx_err_header),
-            Cell::from("TX ERR"),
-            Cell::from("RX DROP"),
-            Cell::from("TX DROP"),
-        ];
-        let header = Row::new(header_cells)
+        // Build column headers with sort indicators
+        let header = Row::new(columns.iter().map(|c| Cell::from(c.header(self.sort_by))))
             .style(Style::default().add_modifier(Modifier::DIM))
             .bottom_margin(1);
+        let widths: Vec<Constraint> = columns.iter().map(DeviceColumn::width).collect();
 
         // Get data for building rows
         let Some(data) = self.data() else {
@@ -1221,19 +2009,40 @@ impl NetworkStatsComponent {
             frame.render_stateful_widget(table, area, &mut self.table_state);
             return;
         };
+        if data.devices.is_empty() {
+            let hidden = data.all_devices.len();
+            let reason = (hidden > 0).then(|| {
+                format!(
+                    "{} hidden by the '{}' filter - press i to show more",
+                    hidden,
+                    self.interface_filter.label()
+                )
+            });
+            let message = if hidden > 0 {
+                "No network interfaces to show"
+            } else {
+                "No network interfaces found"
+            };
+            frame.render_widget(empty_state(message, reason.as_deref()), area);
+            return;
+        }
 
+        let rate_style = |color| self.rate_style(color);
+        let dim = Style::default().fg(Color::DarkGray);
+        // Counters that should be zero turn `color` once they are not
+        let counter_style = |count: u64, color| {
+            if count > 0 {
+                Style::default().fg(color)
+            } else {
+                dim
+            }
+        };
         let rows: Vec<Row> = data
             .devices
             .iter()
             .enumerate()
             .map(|(idx, dev)| {
-                let rate = data.rates.get(&dev.name);
-                let rx_rate = rate
-                    .map(|r| NetDevStats::format_rate(r.rx_bytes_per_sec))
-                    .unwrap_or_else(|| "0 B/s".to_string());
-                let tx_rate = rate
-                    .map(|r| NetDevStats::format_rate(r.tx_bytes_per_sec))
-                    .unwrap_or_else(|| "0 B/s".to_string());
+                let rate = self.displayed_rate(data, &dev.name);
 
                 let has_errors = dev.has_errors();
                 let is_selected = idx == self.selected;
@@ -1247,50 +2056,67 @@ impl NetworkStatsComponent {
                     Style::default()
                 };
 
-                // Error column styles
-                let rx_err_style = if dev.rx_errors > 0 {
-                    Style::default().fg(Color::Red)
-                } else {
-                    Style::default().fg(Color::DarkGray)
-                };
-                let tx_err_style = if dev.tx_errors > 0 {
-                    Style::default().fg(Color::Red)
-                } else {
-                    Style::default().fg(Color::DarkGray)
-                };
-                let rx_drop_style = if dev.rx_dropped > 0 {
-                    Style::default().fg(Color::Yellow)
-                } else {
-                    Style::default().fg(Color::DarkGray)
-                };
-                let tx_drop_style = if dev.tx_dropped > 0 {
-                    Style::default().fg(Color::Yellow)
-                } else {
-                    Style::default().fg(Color::DarkGray)
-                };
+                // Link columns, flagging uplinks too small for an overlay
+                let link = data.links.get(&dev.name);
 
-                Row::new([
-                    Cell::from(dev.name.clone()).style(row_style),
-                    Cell::from(rx_rate).style(Style::default().fg(Color::Green)),
-                    Cell::from(tx_rate).style(Style::default().fg(Color::Blue)),
-                    Cell::from(dev.rx_errors.to_string()).style(rx_err_style),
-                    Cell::from(dev.tx_errors.to_string()).style(tx_err_style),
-                    Cell::from(dev.rx_dropped.to_string()).style(rx_drop_style),
-                    Cell::from(dev.tx_dropped.to_string()).style(tx_drop_style),
-                ])
+                Row::new(columns.iter().map(|column| {
+                    match column {
+                        DeviceColumn::Interface => Cell::from(dev.name.clone()).style(row_style),
+                        DeviceColumn::Mtu => Cell::from(
+                            link.map(|l| l.mtu.to_string())
+                                .unwrap_or_else(|| "-".to_string()),
+                        )
+                        .style(
+                            if data.mtu_shortfall(&dev.name).is_some() {
+                                Style::default().fg(Color::Yellow)
+                            } else {
+                                dim
+                            },
+                        ),
+                        DeviceColumn::Speed => Cell::from(
+                            link.map(|l| l.format_speed())
+                                .unwrap_or_else(|| "-".to_string()),
+                        )
+                        .style(
+                            if link.is_some_and(|l| l.is_half_duplex()) {
+                                Style::default().fg(Color::Yellow)
+                            } else {
+                                dim
+                            },
+                        ),
+                        DeviceColumn::RxRate => {
+                            Cell::from(self.rate_unit.format_opt(rate.map(|(rx, _)| rx)))
+                                .style(rate_style(Color::Green))
+                        }
+                        DeviceColumn::TxRate => {
+                            Cell::from(self.rate_unit.format_opt(rate.map(|(_, tx)| tx)))
+                                .style(rate_style(Color::Blue))
+                        }
+                        DeviceColumn::RxTotal => {
+                            Cell::from(NetDevStats::format_bytes(dev.rx_bytes)).style(dim)
+                        }
+                        DeviceColumn::TxTotal => {
+                            Cell::from(NetDevStats::format_bytes(dev.tx_bytes)).style(dim)
+                        }
+                        DeviceColumn::RxPackets => {
+                            Cell::from(format_packets(dev.rx_packets)).style(dim)
+                        }
+                        DeviceColumn::TxPackets => {
+                            Cell::from(format_packets(dev.tx_packets)).style(dim)
+                        }
+                        DeviceColumn::RxErr => Cell::from(dev.rx_errors.to_string())
+                            .style(counter_style(dev.rx_errors, Color::Red)),
+                        DeviceColumn::TxErr => Cell::from(dev.tx_errors.to_string())
+                            .style(counter_style(dev.tx_errors, Color::Red)),
+                        DeviceColumn::RxDrop => Cell::from(dev.rx_dropped.to_string())
+                            .style(counter_style(dev.rx_dropped, Color::Yellow)),
+                        DeviceColumn::TxDrop => Cell::from(dev.tx_dropped.to_string())
+                            .style(counter_style(dev.tx_dropped, Color::Yellow)),
+                    }
+                }))
             })
             .collect();
 
-        let widths = [
-            Constraint::Length(14), // INTERFACE
-            Constraint::Length(12), // RX RATE
-            Constraint::Length(12), // TX RATE
-            Constraint::Length(8),  // RX ERR
-            Constraint::Length(8),  // TX ERR
-            Constraint::Length(8),  // RX DROP
-            Constraint::Length(8),  // TX DROP
-        ];
-
         let table = Table::new(rows, widths)
             .header(header)
             .row_highlight_style(
@@ -1313,13 +2139,14 @@ impl NetworkStatsComponent {
             return;
         };
 
-        let rate = data.rates.get(&dev.name);
-        let rx_rate = rate
-            .map(|r| NetDevStats::format_rate(r.rx_bytes_per_sec))
-            .unwrap_or_else(|| "0 B/s".to_string());
-        let tx_rate = rate
-            .map(|r| NetDevStats::format_rate(r.tx_bytes_per_sec))
-            .unwrap_or_else(|| "0 B/s".to_string());
+        let rate = self.displayed_rate(data, &dev.name);
+        let rx_rate = self.rate_unit.format_opt(rate.map(|(rx, _)| rx));
+        let tx_rate = self.rate_unit.format_opt(rate.map(|(_, tx)| tx));
+        let stale = if rate.is_some() && self.rate_staleness().is_some() {
+            ", stale"
+        } else {
+            ""
+        };
 
         let rx_total = NetDevStats::format_bytes(dev.rx_bytes);
         let tx_total = NetDevStats::format_bytes(dev.tx_bytes);
@@ -1341,7 +2168,7 @@ impl NetworkStatsComponent {
                 ),
                 Span::raw(format!("{} total", rx_total)),
                 Span::styled(
-                    format!(" ({})", rx_rate),
+                    format!(" ({}{})", rx_rate, stale),
                     Style::default().fg(Color::DarkGray),
                 ),
                 Span::raw(format!("    Packets: {}M", dev.rx_packets / 1_000_000)),
@@ -1373,7 +2200,7 @@ impl NetworkStatsComponent {
                 ),
                 Span::raw(format!("{} total", tx_total)),
                 Span::styled(
-                    format!(" ({})", tx_rate),
+                    format!(" ({}{})", tx_rate, stale),
                     Style::default().fg(Color::DarkGray),
                 ),
                 Span::raw(format!("    Packets: {}M", dev.tx_packets / 1_000_000)),
@@ -1398,6 +2225,31 @@ impl NetworkStatsComponent {
             ]),
         ];
 
+        // Add link line if talosctl reported the interface
+        if let Some(link) = data.links.get(&dev.name) {
+            let mut spans = vec![
+                Span::styled("Link: ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw(format!("{}  MTU {}", link.format_speed(), link.mtu)),
+            ];
+            if !link.duplex.is_empty() && !link.duplex.eq_ignore_ascii_case("unknown") {
+                spans.push(Span::styled(
+                    format!("  {} duplex", link.duplex),
+                    if link.is_half_duplex() {
+                        Style::default().fg(Color::Yellow)
+                    } else {
+                        Style::default().fg(Color::DarkGray)
+                    },
+                ));
+            }
+            if !link.kind.is_empty() {
+                spans.push(Span::styled(
+                    format!("  ({})", link.kind),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+
         // Add connection summary line if we have connection data
         if !data.connections.is_empty() {
             let cc = &data.conn_counts;
@@ -1415,22 +2267,59 @@ impl NetworkStatsComponent {
                 Span::styled("LISTEN  ", Style::default().fg(Color::DarkGray)),
                 Span::styled(
                     format!("{} ", cc.time_wait),
-                    if cc.time_wait > 100 {
+                    if cc.high_time_wait(&self.conn_thresholds) {
                         Style::default().fg(Color::Yellow)
                     } else {
                         Style::default()
                     },
                 ),
-                Span::styled("TIME_WAIT  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("TIME_WAIT  ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!("{} ", cc.close_wait),
+                    if cc.high_close_wait(&self.conn_thresholds) {
+                        Style::default().fg(Color::Red)
+                    } else {
+                        Style::default()
+                    },
+                ),
+                Span::styled("CLOSE_WAIT", Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+
+        // Warn when the uplink cannot carry full-size pod frames through the overlay
+        if let Some(shortfall) = data.mtu_shortfall(&dev.name) {
+            let mtu = data.links.get(&dev.name).map(|l| l.mtu).unwrap_or(0);
+            lines.push(Line::from(vec![
+                Span::styled(
+                    "! ",
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(
+                        "MTU {} below {} needed by {} - its MTU must be {} or less",
+                        mtu, shortfall.required, shortfall.overlay, shortfall.max_overlay_mtu
+                    ),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]));
+        } else if data
+            .links
+            .get(&dev.name)
+            .is_some_and(|l| l.is_half_duplex())
+        {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    "! ",
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
                 Span::styled(
-                    format!("{} ", cc.close_wait),
-                    if cc.close_wait > 0 {
-                        Style::default().fg(Color::Red)
-                    } else {
-                        Style::default()
-                    },
+                    "Half duplex link - check switch port / autonegotiation",
+                    Style::default().fg(Color::Yellow),
                 ),
-                Span::styled("CLOSE_WAIT", Style::default().fg(Color::DarkGray)),
             ]));
         }
 
@@ -1491,6 +2380,14 @@ impl NetworkStatsComponent {
             Span::raw(" "),
             Span::styled(bpf_label, Style::default().fg(bpf_color)),
             Span::raw("  "),
+            Span::styled("[M]", Style::default().fg(Color::Cyan)),
+            Span::raw(" metrics  "),
+            Span::styled("[b]", Style::default().fg(Color::Cyan)),
+            Span::raw(format!(" {}  ", self.rate_unit.label())),
+            Span::styled("[C]", Style::default().fg(Color::Cyan)),
+            Span::raw(format!(" cols:{}  ", self.column_preset.label())),
+            Span::styled("[i]", Style::default().fg(Color::Cyan)),
+            Span::raw(format!(" ifaces:{}  ", self.interface_filter.label())),
             Span::styled("[a]", Style::default().fg(Color::Cyan)),
             Span::raw(format!(" {}  ", auto_label)),
             Span::styled("[q]", Style::default().fg(Color::Cyan)),
@@ -1546,6 +2443,28 @@ impl NetworkStatsComponent {
             ),
             Span::styled(filter_label, Style::default().fg(Color::Yellow)),
         ];
+        if self.protocol_filter != ProtocolFilter::All {
+            spans.push(Span::styled(
+                format!(" [{} ONLY]", self.protocol_filter.label()),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        if let Some(process) = &self.process_filter {
+            spans.push(Span::styled(
+                format!(" [PROCESS: {}]", process),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        if self.conn_grouping != ConnGrouping::Off {
+            spans.push(Span::styled(
+                format!(
+                    " [TOP TALKERS {}: {} peers]",
+                    self.conn_grouping.label(),
+                    self.conn_groups().len()
+                ),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
 
         // Show visual mode indicator
         if let Some((start, end)) = self.conn_selection_range() {
@@ -1582,7 +2501,7 @@ impl NetworkStatsComponent {
             Span::raw("   "),
             Span::styled(
                 format!("{} ", cc.time_wait),
-                if cc.time_wait > 100 {
+                if cc.high_time_wait(&self.conn_thresholds) {
                     Style::default().fg(Color::Yellow)
                 } else {
                     Style::default()
@@ -1592,7 +2511,7 @@ impl NetworkStatsComponent {
             Span::raw("   "),
             Span::styled(
                 format!("{} ", cc.close_wait),
-                if cc.close_wait > 0 {
+                if cc.high_close_wait(&self.conn_thresholds) {
                     Style::default().fg(Color::Red)
                 } else {
                     Style::default()
@@ -1609,6 +2528,16 @@ impl NetworkStatsComponent {
     fn draw_conn_table(&mut self, frame: &mut Frame, area: Rect) {
         // Update viewport height for page navigation
         self.conn_viewport_height = area.height;
+        if self.get_filtered_connections().is_empty() {
+            let total = self.data().map(|d| d.connections.len()).unwrap_or(0);
+            let reason = (total > 0).then(|| format!("{} hidden by the current filters", total));
+            frame.render_widget(empty_state("No connections found", reason.as_deref()), area);
+            return;
+        }
+        if self.conn_grouping != ConnGrouping::Off {
+            self.draw_conn_groups(frame, area);
+            return;
+        }
 
         // Build column headers with sort indicators
         let state_header = if self.conn_sort_by == ConnSortBy::State {
@@ -1621,13 +2550,18 @@ impl NetworkStatsComponent {
         } else {
             "LOCAL"
         };
+        let process_header = if self.conn_sort_by == ConnSortBy::Process {
+            "PROCESS▼"
+        } else {
+            "PROCESS"
+        };
 
         let header_cells = [
             Cell::from("PROTO"),
             Cell::from(local_header),
             Cell::from("REMOTE"),
             Cell::from(state_header),
-            Cell::from("PROCESS"),
+            Cell::from(process_header),
         ];
         let header = Row::new(header_cells)
             .style(Style::default().add_modifier(Modifier::DIM))
@@ -1637,9 +2571,21 @@ impl NetworkStatsComponent {
         let in_visual = self.conn_in_visual_mode();
 
         // Extract data needed for the closure
-        let time_wait_count = self.data().map(|d| d.conn_counts.time_wait).unwrap_or(0);
+        let high_time_wait = self
+            .data()
+            .is_some_and(|d| d.conn_counts.high_time_wait(&self.conn_thresholds));
         let services = self.data().map(|d| d.services.clone()).unwrap_or_default();
 
+        let widths = [
+            Constraint::Length(6),  // PROTO
+            Constraint::Length(22), // LOCAL (IP:port)
+            Constraint::Length(24), // REMOTE
+            Constraint::Length(12), // STATE
+            Constraint::Min(16),    // PROCESS (takes remaining space)
+        ];
+        // Less the "> " highlight symbol
+        let cell_widths = column_widths(&widths, area.width.saturating_sub(2));
+
         let rows: Vec<Row> = conns
             .iter()
             .enumerate()
@@ -1666,7 +2612,7 @@ impl NetworkStatsComponent {
                     ConnectionState::Listen => ("LISTEN", Color::Cyan),
                     ConnectionState::TimeWait => (
                         "TIME_WAIT",
-                        if time_wait_count > 100 {
+                        if high_time_wait {
                             Color::Yellow
                         } else {
                             Color::White
@@ -1730,25 +2676,114 @@ impl NetworkStatsComponent {
                     Style::default()
                 };
 
+                let values = vec![
+                    conn.protocol.clone(),
+                    local,
+                    remote,
+                    state_str.to_string(),
+                    owner_text,
+                ];
+                let (values, height) = if self.wrap_selected && idx == self.conn_selected {
+                    wrap_row(values, &cell_widths)
+                } else {
+                    (values, 1)
+                };
+                let styles = [
+                    Style::default(),
+                    Style::default(),
+                    Style::default(),
+                    Style::default().fg(state_color),
+                    Style::default().fg(owner_color),
+                ];
+
+                Row::new(
+                    values
+                        .into_iter()
+                        .zip(styles)
+                        .map(|(value, style)| Cell::from(value).style(style)),
+                )
+                .height(height)
+                .style(row_style)
+            })
+            .collect();
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .row_highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("> ");
+
+        frame.render_stateful_widget(table, area, &mut self.conn_table_state);
+    }
+
+    /// Draw the connection table collapsed into per-peer rows
+    fn draw_conn_groups(&mut self, frame: &mut Frame, area: Rect) {
+        let header = Row::new([
+            Cell::from("CONNS▼"),
+            Cell::from(if self.conn_grouping == ConnGrouping::RemoteIp {
+                "REMOTE IP"
+            } else {
+                "REMOTE"
+            }),
+            Cell::from("ESTAB"),
+            Cell::from("TIME_WAIT"),
+            Cell::from("CLOSE_WAIT"),
+            Cell::from("PROCESS"),
+        ])
+        .style(Style::default().add_modifier(Modifier::DIM))
+        .bottom_margin(1);
+
+        let groups = self.conn_groups();
+        let in_visual = self.conn_in_visual_mode();
+        let count_style = |n: usize, color: Color| {
+            if n > 0 {
+                Style::default().fg(color)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            }
+        };
+
+        let rows: Vec<Row> = groups
+            .iter()
+            .enumerate()
+            .map(|(idx, group)| {
+                let row_style = if in_visual && self.is_conn_selected(idx) {
+                    Style::default().bg(Color::Rgb(60, 20, 60)) // Dark magenta
+                } else {
+                    Style::default()
+                };
+                let processes = if group.processes.is_empty() {
+                    "-".to_string()
+                } else {
+                    group.processes.join(", ")
+                };
                 Row::new([
-                    Cell::from(conn.protocol.clone()),
-                    Cell::from(local),
-                    Cell::from(remote),
-                    Cell::from(state_str).style(Style::default().fg(state_color)),
-                    Cell::from(owner_text).style(Style::default().fg(owner_color)),
+                    Cell::from(group.count.to_string())
+                        .style(Style::default().add_modifier(Modifier::BOLD)),
+                    Cell::from(group.peer.clone()),
+                    Cell::from(group.established.to_string())
+                        .style(count_style(group.established, Color::Green)),
+                    Cell::from(group.time_wait.to_string())
+                        .style(count_style(group.time_wait, Color::Yellow)),
+                    Cell::from(group.close_wait.to_string())
+                        .style(count_style(group.close_wait, Color::Red)),
+                    Cell::from(processes).style(Style::default().fg(Color::Yellow)),
                 ])
                 .style(row_style)
             })
             .collect();
 
         let widths = [
-            Constraint::Length(6),  // PROTO
-            Constraint::Length(22), // LOCAL (IP:port)
-            Constraint::Length(24), // REMOTE
-            Constraint::Length(12), // STATE
-            Constraint::Min(16),    // PROCESS (takes remaining space)
+            Constraint::Length(7),  // CONNS
+            Constraint::Length(47), // REMOTE (fits [IPv6]:port)
+            Constraint::Length(6),  // ESTAB
+            Constraint::Length(10), // TIME_WAIT
+            Constraint::Length(11), // CLOSE_WAIT
+            Constraint::Min(16),    // PROCESS
         ];
-
         let table = Table::new(rows, widths)
             .header(header)
             .row_highlight_style(
@@ -1764,6 +2799,11 @@ impl NetworkStatsComponent {
     /// Draw footer for connection view
     fn draw_conn_footer(&self, frame: &mut Frame, area: Rect) {
         let listen_label = if self.listening_only { "all" } else { "listen" };
+        let process_label = if self.process_filter.is_some() {
+            "all procs"
+        } else {
+            "process"
+        };
         let all_label = if self.show_all_connections {
             "iface"
         } else {
@@ -1802,8 +2842,20 @@ impl NetworkStatsComponent {
                 Span::raw("  "),
                 Span::styled("[l]", Style::default().fg(Color::Cyan)),
                 Span::raw(format!(" {}  ", listen_label)),
+                Span::styled("[P]", Style::default().fg(Color::Cyan)),
+                Span::raw(format!(" {}  ", self.protocol_filter.next().label())),
                 Span::styled("[a]", Style::default().fg(Color::Cyan)),
                 Span::raw(format!(" {}  ", all_label)),
+                Span::styled("[p]", Style::default().fg(Color::Cyan)),
+                Span::raw(format!(" {}  ", process_label)),
+                Span::styled("[T]", Style::default().fg(Color::Cyan)),
+                Span::raw(format!(" top {}  ", self.conn_grouping.next().label())),
+                Span::styled("[w]", Style::default().fg(Color::Cyan)),
+                Span::raw(if self.wrap_selected {
+                    " truncate  "
+                } else {
+                    " wrap  "
+                }),
                 Span::styled("[q/Tab]", Style::default().fg(Color::Cyan)),
                 Span::raw(" back"),
             ]
@@ -1815,10 +2867,14 @@ impl NetworkStatsComponent {
 
     /// Draw the selected connection detail section
     fn draw_conn_detail(&self, frame: &mut Frame, area: Rect) {
-        let conns = self.get_filtered_connections();
-        let Some(conn) = conns.get(self.conn_selected) else {
+        if self.conn_grouping != ConnGrouping::Off {
+            self.draw_conn_group_detail(frame, area);
+            return;
+        }
+        let Some(conn) = self.selected_connection() else {
             return;
         };
+        let conn = &conn;
         let services = self.data().map(|d| d.services.clone()).unwrap_or_default();
 
         // Format local address
@@ -1974,6 +3030,72 @@ impl NetworkStatsComponent {
         frame.render_widget(detail, area);
     }
 
+    /// Draw the selected peer group's detail section
+    fn draw_conn_group_detail(&self, frame: &mut Frame, area: Rect) {
+        let groups = self.conn_groups();
+        let Some(group) = groups.get(self.conn_selected) else {
+            return;
+        };
+        let total: usize = groups.iter().map(|g| g.count).sum();
+        let share = group.count * 100 / total.max(1);
+        let ports = group
+            .local_ports
+            .iter()
+            .map(|p| match port_to_service(*p) {
+                Some(service) => format!("{} ({})", p, service),
+                None => p.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+
+        let lines = vec![
+            Line::from(vec![
+                Span::styled("Peer: ", bold),
+                Span::styled(
+                    &group.peer,
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("   "),
+                Span::styled("Connections: ", bold),
+                Span::raw(format!(
+                    "{} ({}% of {} with a peer)",
+                    group.count, share, total
+                )),
+            ]),
+            Line::from(vec![Span::styled("Local ports: ", bold), Span::raw(ports)]),
+            Line::from(vec![
+                Span::styled("Processes: ", bold),
+                Span::styled(
+                    if group.processes.is_empty() {
+                        "-".to_string()
+                    } else {
+                        group.processes.join(", ")
+                    },
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Actions: ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[y]", Style::default().fg(Color::Cyan)),
+                Span::styled(" yank  ", Style::default().fg(Color::DarkGray)),
+                Span::styled("[T]", Style::default().fg(Color::Cyan)),
+                Span::styled(
+                    format!(" group {}", self.conn_grouping.next().label()),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]),
+        ];
+
+        let block = Block::default()
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(Color::DarkGray))
+            .title(Span::styled(" Selected Peer ", bold));
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
     /// Draw the connection drill-down view
     fn draw_connections_view(&mut self, frame: &mut Frame, area: Rect) {
         let chunks = Layout::vertical([
@@ -2353,7 +3475,7 @@ impl NetworkStatsComponent {
                 self.sort_devices();
                 Ok(None)
             }
-            KeyCode::Char('r') => Ok(Some(Action::Refresh)),
+            KeyCode::Char('r') => Ok(manual_refresh(&self.state)),
             KeyCode::Char('a') => {
                 self.auto_refresh = !self.auto_refresh;
                 Ok(None)
@@ -2380,16 +3502,63 @@ impl NetworkStatsComponent {
                 self.toggle_bpf_filter();
                 Ok(None)
             }
+            // Export current metrics in Prometheus text format
+            KeyCode::Char('M') => {
+                self.save_metrics();
+                Ok(None)
+            }
+            // Rate unit is session-wide, so the app applies it to every network view
+            KeyCode::Char('b') => Ok(Some(Action::ToggleRateUnit)),
+            // Smoothing is session-wide too, like the rate unit
+            KeyCode::Char('e') => Ok(Some(Action::ToggleRateSmoothing)),
+            KeyCode::Char('C') => {
+                self.column_preset = self.column_preset.next();
+                Ok(None)
+            }
+            KeyCode::Char('i') => {
+                self.cycle_interface_filter();
+                Ok(None)
+            }
             _ => Ok(None),
         }
     }
 
+    /// Write current interface stats and connection counts to a file
+    /// in Prometheus text exposition format
+    fn save_metrics(&mut self) {
+        let Some(data) = self.data() else {
+            self.notify("No network data to export", NotifyLevel::Warning);
+            return;
+        };
+
+        let metrics = format_prometheus(&[NodeNetworkMetrics {
+            node: &self.hostname,
+            devices: &data.all_devices,
+            connections: &data.conn_counts,
+        }]);
+
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let filename = format!("talos-pilot-metrics-{}_{}.prom", self.hostname, timestamp);
+        let path = std::env::temp_dir().join(&filename);
+
+        match std::fs::write(&path, metrics) {
+            Ok(_) => self.notify(
+                format!("Saved metrics to {}", path.display()),
+                NotifyLevel::Success,
+            ),
+            Err(e) => self.notify(format!("Metrics export failed: {}", e), NotifyLevel::Error),
+        }
+    }
+
     /// Handle key events in KubeSpan view
     fn handle_kubespan_key(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         let peer_count = self.data().map(|d| d.kubespan_peers.len()).unwrap_or(0);
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => Ok(Some(Action::Back)),
-            KeyCode::Char('r') => Ok(Some(Action::Refresh)),
+            KeyCode::Char('r') => Ok(manual_refresh(&self.state)),
             KeyCode::Char('j') | KeyCode::Down => {
                 if peer_count > 0 {
                     self.kubespan_selected = (self.kubespan_selected + 1) % peer_count;
@@ -2507,6 +3676,19 @@ impl NetworkStatsComponent {
                 self.conn_selection_start = None;
                 Ok(None)
             }
+            KeyCode::Char('3') => {
+                self.conn_sort_by = ConnSortBy::Process;
+                self.conn_selected = 0;
+                self.conn_table_state.select(Some(0));
+                self.conn_selection_start = None;
+                Ok(None)
+            }
+
+            // Show the selected row's truncated cells in full
+            KeyCode::Char('w') => {
+                self.wrap_selected = !self.wrap_selected;
+                Ok(None)
+            }
 
             // Filter
             KeyCode::Char('l') => {
@@ -2517,6 +3699,30 @@ impl NetworkStatsComponent {
                 Ok(None)
             }
 
+            // Cycle protocol filter (All -> TCP -> UDP), composes with `l`
+            KeyCode::Char('P') => {
+                self.protocol_filter = self.protocol_filter.next();
+                self.conn_selected = 0;
+                self.conn_table_state.select(Some(0));
+                self.conn_selection_start = None;
+                Ok(None)
+            }
+
+            // Filter to the selected connection's process
+            KeyCode::Char('p') => {
+                self.toggle_process_filter();
+                Ok(None)
+            }
+
+            // Top talkers: collapse into per-peer rows (off -> IP -> IP:port)
+            KeyCode::Char('T') => {
+                self.conn_grouping = self.conn_grouping.next();
+                self.conn_selected = 0;
+                self.conn_table_state.select(Some(0));
+                self.conn_selection_start = None;
+                Ok(None)
+            }
+
             // Toggle show all connections (bypass interface filter)
             KeyCode::Char('a') => {
                 self.show_all_connections = !self.show_all_connections;
@@ -2548,7 +3754,7 @@ impl NetworkStatsComponent {
             }
 
             // Refresh
-            KeyCode::Char('r') => Ok(Some(Action::Refresh)),
+            KeyCode::Char('r') => Ok(manual_refresh(&self.state)),
 
             // Open service logs (for known service ports)
             KeyCode::Char('o') => self.open_service_logs(),
@@ -2617,8 +3823,7 @@ impl NetworkStatsComponent {
 
     /// Open logs for the currently selected connection's service
     fn open_service_logs(&self) -> Result<Option<Action>> {
-        let conns = self.get_filtered_connections();
-        let Some(conn) = conns.get(self.conn_selected) else {
+        let Some(conn) = self.selected_connection() else {
             return Ok(None);
         };
 
@@ -2639,8 +3844,7 @@ impl NetworkStatsComponent {
 
     /// Initiate service restart - sets pending_action for confirmation
     fn initiate_service_restart(&mut self) {
-        let conns = self.get_filtered_connections();
-        let Some(conn) = conns.get(self.conn_selected) else {
+        let Some(conn) = self.selected_connection() else {
             return;
         };
 
@@ -2655,10 +3859,10 @@ impl NetworkStatsComponent {
             .map(|d| d.services.contains_key(service_name))
             .unwrap_or(false);
         if !service_exists {
-            self.status_message = Some((
+            self.notify(
                 format!("Service '{}' not found", service_name),
-                Instant::now(),
-            ));
+                NotifyLevel::Warning,
+            );
             return;
         }
 
@@ -2673,9 +3877,8 @@ impl NetworkStatsComponent {
     fn execute_pending_action(&mut self, action: PendingAction) -> Result<Option<Action>> {
         match action {
             PendingAction::RestartService(service_id, service_name) => {
-                // Show status message while waiting
-                self.status_message =
-                    Some((format!("Restarting {}...", service_name), Instant::now()));
+                // Let the user know while waiting
+                self.notify(format!("Restarting {}...", service_name), NotifyLevel::Info);
 
                 // Store that we need to restart - will be executed on next refresh
                 self.pending_restart_service = Some(service_id);
@@ -2695,7 +3898,7 @@ impl NetworkStatsComponent {
                 (
                     d.total_errors,
                     d.total_dropped,
-                    d.conn_counts.has_warnings(),
+                    d.conn_counts.has_warnings(&self.conn_thresholds),
                     d.connections.is_empty(),
                 )
             })
@@ -2723,7 +3926,7 @@ impl NetworkStatsComponent {
         }
 
         constraints.push(Constraint::Min(5)); // Device table (takes remaining space)
-        constraints.push(Constraint::Length(4)); // Detail section
+        constraints.push(Constraint::Length(6)); // Detail section
         constraints.push(Constraint::Length(1)); // Footer
 
         let chunks = Layout::vertical(constraints).split(area);
@@ -2772,6 +3975,64 @@ impl NetworkStatsComponent {
 }
 
 impl Component for NetworkStatsComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        match self.view_mode {
+            ViewMode::Interfaces => help_entries(&[
+                ("j/k ↑/↓", "Navigate"),
+                ("g/G", "Jump to top/bottom"),
+                ("Enter", "Connections on interface"),
+                ("Tab/Shift+Tab", "Switch view"),
+                ("1", "Sort by traffic"),
+                ("2", "Sort by errors"),
+                ("c", "Start/stop packet capture"),
+                ("s", "Save capture"),
+                ("f", "Toggle BPF filter (exclude API port)"),
+                ("M", "Export Prometheus metrics"),
+                ("b", "Cycle B/s / SI B/s / bit/s"),
+                ("e", "Toggle smoothed rates"),
+                ("C", "Cycle columns (basic/errors/full)"),
+                ("i", "Cycle interfaces (real/no lo/all)"),
+                ("a", "Toggle auto-refresh"),
+                ("r", "Refresh"),
+                ("q/Esc", "Back"),
+            ]),
+            ViewMode::Connections => help_entries(&[
+                ("j/k ↑/↓", "Navigate"),
+                ("g/G", "Jump to top/bottom"),
+                ("PgUp/PgDn", "Page up/down"),
+                ("Ctrl+U/Ctrl+D", "Half page up/down"),
+                ("1", "Sort by state"),
+                ("2", "Sort by port"),
+                ("3", "Sort by process"),
+                ("l", "Toggle listening only"),
+                ("P", "Cycle protocol filter (all/TCP/UDP)"),
+                ("p", "Filter to selected process"),
+                ("T", "Top talkers: group by remote IP / IP:port"),
+                ("a", "Toggle all interfaces"),
+                ("w", "Wrap selected row (show full values)"),
+                ("V", "Visual line selection"),
+                ("y", "Copy selection or line"),
+                ("o", "Open service logs"),
+                ("R", "Restart service"),
+                ("d", "Show DNS config"),
+                ("t", "Show routing table"),
+                ("c", "Start/stop packet capture"),
+                ("s", "Save capture"),
+                ("f", "Toggle BPF filter (exclude API port)"),
+                ("r", "Refresh"),
+                ("Tab/Esc", "Back to interfaces"),
+                ("q", "Back"),
+            ]),
+            ViewMode::KubeSpan => help_entries(&[
+                ("j/k ↑/↓", "Navigate"),
+                ("g/G", "Jump to top/bottom"),
+                ("Tab/Shift+Tab", "Switch view"),
+                ("r", "Refresh"),
+                ("q/Esc", "Back"),
+            ]),
+        }
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         // If file viewer is shown, handle its keys
         if self.file_viewer.is_some() {
@@ -2798,21 +4059,20 @@ impl Component for NetworkStatsComponent {
 
     fn update(&mut self, action: Action) -> Result<Option<Action>> {
         if let Action::Tick = action {
-            // Clear old status messages (after 3 seconds)
-            if let Some((_, time)) = &self.status_message
-                && time.elapsed() > std::time::Duration::from_secs(3)
-            {
-                self.status_message = None;
-            }
-
             // Poll packet capture for new data
             if self.is_capturing() {
                 self.poll_capture();
             }
 
+            // Hand queued messages to the app, one per tick
+            if let Some((message, level)) = self.notifications.pop_front() {
+                return Ok(Some(Action::Notify(message, level)));
+            }
+
             // Check for auto-refresh
             if self.auto_refresh
                 && !self.state.is_loading()
+                && !self.state.component_error().is_some_and(|e| e.is_fatal())
                 && let Some(last) = self.state.last_refresh()
             {
                 let interval = std::time::Duration::from_secs(AUTO_REFRESH_INTERVAL_SECS);
@@ -2832,9 +4092,9 @@ impl Component for NetworkStatsComponent {
             return Ok(());
         }
 
-        if let Some(err) = self.state.error() {
-            let error =
-                Paragraph::new(format!("Error: {}", err)).style(Style::default().fg(Color::Red));
+        if let Some(err) = self.state.component_error() {
+            let error = Paragraph::new(format!("Error: {}", err.describe(self.auto_refresh)))
+                .style(Style::default().fg(err.color()));
             frame.render_widget(error, area);
             return Ok(());
         }
@@ -2867,11 +4127,6 @@ impl Component for NetworkStatsComponent {
             self.draw_confirmation_dialog(frame, area, action);
         }
 
-        // Draw status message overlay if present
-        if let Some((ref msg, _)) = self.status_message {
-            self.draw_status_message(frame, area, msg);
-        }
-
         // Draw file viewer overlay if open
         if self.file_viewer.is_some() {
             self.draw_file_viewer(frame, area);
@@ -2929,7 +4184,6 @@ impl NetworkStatsComponent {
                 };
                 self.command_output = Some(output);
                 self.show_output_pane = true;
-                self.status_message = None; // Clear status message, using output pane instead
 
                 Ok(Some(format!("Service '{}' restarted", service_id)))
             }
@@ -2950,7 +4204,6 @@ impl NetworkStatsComponent {
                 };
                 self.command_output = Some(output);
                 self.show_output_pane = true;
-                self.status_message = None;
 
                 Ok(Some(format!("Failed to restart '{}': {}", service_id, e)))
             }
@@ -3021,43 +4274,6 @@ impl NetworkStatsComponent {
         frame.render_widget(paragraph, inner);
     }
 
-    /// Draw the status message as a floating notification
-    fn draw_status_message(&self, frame: &mut Frame, area: Rect, message: &str) {
-        // Determine the color based on message content
-        let (fg_color, border_color) = if message.contains("Failed") || message.contains("Error") {
-            (Color::White, Color::Red)
-        } else if message.contains("successfully") {
-            (Color::White, Color::Green)
-        } else {
-            (Color::Black, Color::Yellow)
-        };
-
-        // Create a floating notification centered near the top
-        let msg_width = (message.len() as u16 + 4).min(area.width.saturating_sub(4));
-        let msg_height = 3u16;
-        let x = area.x + (area.width.saturating_sub(msg_width)) / 2;
-        let y = area.y + 2; // Near the top, below header
-        let msg_area = Rect::new(x, y, msg_width, msg_height);
-
-        // Clear the area first
-        frame.render_widget(Clear, msg_area);
-
-        // Draw the notification box
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(border_color))
-            .style(Style::default().bg(border_color));
-
-        let inner = block.inner(msg_area);
-
-        let status = Paragraph::new(message)
-            .style(Style::default().fg(fg_color).add_modifier(Modifier::BOLD))
-            .alignment(ratatui::layout::Alignment::Center);
-
-        frame.render_widget(block, msg_area);
-        frame.render_widget(status, inner);
-    }
-
     /// Draw the command output pane at the bottom
     fn draw_output_pane(&self, frame: &mut Frame, area: Rect) {
         let Some(ref output) = self.command_output else {
@@ -3073,7 +4289,11 @@ impl NetworkStatsComponent {
 
         let title = format!(
             " {} {} ",
-            if output.success { "✓" } else { "✗" },
+            if output.success {
+                glyphs().check
+            } else {
+                glyphs().cross
+            },
             output.command
         );
 
@@ -3496,13 +4716,13 @@ impl NetworkStatsComponent {
                 self.capture.receiver = None;
 
                 let bytes_str = NetDevStats::format_bytes(bytes as u64);
-                self.status_message = Some((
+                self.notify(
                     format!(
                         "Capture stopped: {} - press 's' to save, open in Wireshark",
                         bytes_str
                     ),
-                    Instant::now(),
-                ));
+                    NotifyLevel::Info,
+                );
             }
         }
     }
@@ -3515,8 +4735,7 @@ impl NetworkStatsComponent {
             }
 
             let Some(client) = &self.client else {
-                self.status_message =
-                    Some(("Error: No client configured".to_string(), Instant::now()));
+                self.notify("No client configured", NotifyLevel::Error);
                 self.capture.state = CaptureState::Idle;
                 return;
             };
@@ -3540,16 +4759,14 @@ impl NetworkStatsComponent {
                     } else {
                         " [NO filter]"
                     };
-                    self.status_message = Some((
-                        format!(
-                            "Capturing on {}{} (save with 's')",
-                            interface, filter_status
-                        ),
-                        Instant::now(),
-                    ));
+                    let message = format!(
+                        "Capturing on {}{} (save with 's')",
+                        interface, filter_status
+                    );
+                    self.notify(message, NotifyLevel::Info);
                 }
                 Err(e) => {
-                    self.status_message = Some((format!("Capture failed: {}", e), Instant::now()));
+                    self.notify(format!("Capture failed: {}", e), NotifyLevel::Error);
                     self.capture.state = CaptureState::Idle;
                 }
             }
@@ -3573,10 +4790,10 @@ impl NetworkStatsComponent {
                     let bytes_str = NetDevStats::format_bytes(self.capture.data.len() as u64);
                     self.capture.state = CaptureState::Idle;
                     self.capture.receiver = None;
-                    self.status_message = Some((
+                    self.notify(
                         format!("Capture auto-stopped at {} - press 's' to save", bytes_str),
-                        Instant::now(),
-                    ));
+                        NotifyLevel::Warning,
+                    );
                     return;
                 }
             }
@@ -3603,7 +4820,7 @@ impl NetworkStatsComponent {
         }
 
         if self.capture.data.is_empty() {
-            self.status_message = Some(("No capture data to save".to_string(), Instant::now()));
+            self.notify("No capture data to save", NotifyLevel::Warning);
             return;
         }
 
@@ -3625,15 +4842,15 @@ impl NetworkStatsComponent {
                 } else {
                     format!("{} B", bytes_saved)
                 };
-                self.status_message = Some((
+                self.notify(
                     format!("Saved {} to {}", size_str, path.display()),
-                    Instant::now(),
-                ));
+                    NotifyLevel::Success,
+                );
                 // Clear capture data after successful save
                 self.capture.data.clear();
             }
             Err(e) => {
-                self.status_message = Some((format!("Save failed: {}", e), Instant::now()));
+                self.notify(format!("Save failed: {}", e), NotifyLevel::Error);
             }
         }
     }
@@ -3642,20 +4859,22 @@ impl NetworkStatsComponent {
     fn toggle_bpf_filter(&mut self) {
         // Only allow toggle when not capturing
         if self.is_capturing() {
-            self.status_message = Some((
-                "Stop capture first before changing filter setting".to_string(),
-                Instant::now(),
-            ));
+            self.notify(
+                "Stop capture first before changing filter setting",
+                NotifyLevel::Warning,
+            );
             return;
         }
 
         self.capture.use_bpf_filter = !self.capture.use_bpf_filter;
-        let status = if self.capture.use_bpf_filter {
-            "BPF filter ON - excludes API port 50000"
+        if self.capture.use_bpf_filter {
+            self.notify("BPF filter ON - excludes API port 50000", NotifyLevel::Info);
         } else {
-            "BPF filter OFF - WARNING: may cause feedback loop on mgmt interface"
-        };
-        self.status_message = Some((status.to_string(), Instant::now()));
+            self.notify(
+                "BPF filter OFF - may cause feedback loop on mgmt interface",
+                NotifyLevel::Warning,
+            );
+        }
     }
 
     /// Draw capture status bar at the top of connections view
@@ -3669,7 +4888,7 @@ impl NetworkStatsComponent {
 
             let status = Line::from(vec![
                 Span::styled(
-                    " ● ",
+                    format!(" {} ", glyphs().filled),
                     Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
@@ -3696,3 +4915,163 @@ impl NetworkStatsComponent {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(id: &str, kind: &str, mtu: u32) -> LinkStatus {
+        LinkStatus {
+            id: id.to_string(),
+            link_type: "ether".to_string(),
+            kind: kind.to_string(),
+            mtu,
+            speed_mbit: None,
+            duplex: String::new(),
+            operational_state: "up".to_string(),
+        }
+    }
+
+    fn data_with_links(links: Vec<LinkStatus>) -> NetworkData {
+        NetworkData {
+            links: links.into_iter().map(|l| (l.id.clone(), l)).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_mtu_shortfall_healthy_defaults() {
+        // Flannel sizes its VXLAN device to fit the uplink
+        let data = data_with_links(vec![
+            link("eth0", "", 1500),
+            link("flannel.1", "vxlan", 1450),
+        ]);
+        assert_eq!(data.mtu_shortfall("eth0"), None);
+
+        // Same for KubeSpan over WireGuard
+        let data = data_with_links(vec![
+            link("eth0", "", 1500),
+            link("kubespan", "wireguard", 1420),
+        ]);
+        assert_eq!(data.mtu_shortfall("eth0"), None);
+    }
+
+    #[test]
+    fn test_mtu_shortfall_overlay_too_large() {
+        let data = data_with_links(vec![
+            link("eth0", "", 1450),
+            link("flannel.1", "vxlan", 1400),
+            link("kubespan", "wireguard", 1420),
+        ]);
+        assert_eq!(
+            data.mtu_shortfall("eth0"),
+            Some(MtuShortfall {
+                overlay: "kubespan".to_string(),
+                required: 1500,
+                max_overlay_mtu: 1370,
+            })
+        );
+
+        // Only uplinks are checked
+        assert_eq!(data.mtu_shortfall("flannel.1"), None);
+    }
+
+    fn conn(
+        remote: &str,
+        remote_port: u32,
+        local_port: u32,
+        state: ConnectionState,
+    ) -> ConnectionInfo {
+        ConnectionInfo {
+            protocol: "tcp".to_string(),
+            local_ip: "10.5.0.2".to_string(),
+            local_port,
+            remote_ip: remote.to_string(),
+            remote_port,
+            state,
+            rx_queue: 0,
+            tx_queue: 0,
+            process_pid: None,
+            process_name: None,
+            netns: None,
+        }
+    }
+
+    #[test]
+    fn test_group_connections_by_peer() {
+        use ConnectionState::*;
+        let conns = vec![
+            conn("10.5.0.3", 2380, 43210, Established),
+            conn("10.5.0.3", 2379, 43211, TimeWait),
+            conn("10.5.0.4", 2380, 43212, Established),
+            conn("fd00::5", 6443, 43213, CloseWait),
+        ];
+
+        let by_ip = group_connections(&conns, ConnGrouping::RemoteIp);
+        let peers: Vec<&str> = by_ip.iter().map(|g| g.peer.as_str()).collect();
+        assert_eq!(peers, ["10.5.0.3", "10.5.0.4", "fd00::5"]);
+        assert_eq!(
+            (by_ip[0].count, by_ip[0].established, by_ip[0].time_wait),
+            (2, 1, 1)
+        );
+        assert_eq!(by_ip[0].local_ports, [43210, 43211]);
+        assert_eq!(by_ip[2].close_wait, 1);
+
+        let by_endpoint = group_connections(&conns, ConnGrouping::RemoteEndpoint);
+        assert_eq!(by_endpoint.len(), 4);
+        assert!(by_endpoint.iter().any(|g| g.peer == "[fd00::5]:6443"));
+
+        assert!(group_connections(&conns, ConnGrouping::Off).is_empty());
+    }
+
+    #[test]
+    fn test_group_connections_busiest_first() {
+        use ConnectionState::*;
+        let conns = vec![
+            conn("10.5.0.9", 443, 40000, Established),
+            conn("10.5.0.3", 2380, 40001, Established),
+            conn("10.5.0.3", 2380, 40002, Established),
+            conn("10.5.0.3", 2380, 40003, Established),
+            conn("10.5.0.4", 2380, 40004, Established),
+        ];
+        let groups = group_connections(&conns, ConnGrouping::RemoteIp);
+        let order: Vec<(&str, usize)> = groups.iter().map(|g| (g.peer.as_str(), g.count)).collect();
+        // Ties are broken by peer so the order is stable between refreshes
+        assert_eq!(order, [("10.5.0.3", 3), ("10.5.0.4", 1), ("10.5.0.9", 1)]);
+    }
+
+    #[test]
+    fn test_group_connections_skips_listen_sockets() {
+        let conns = vec![
+            conn("0.0.0.0", 0, 50000, ConnectionState::Listen),
+            conn("::", 0, 6443, ConnectionState::Listen),
+            conn("10.5.0.3", 2380, 40001, ConnectionState::Established),
+        ];
+        let groups = group_connections(&conns, ConnGrouping::RemoteIp);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].peer, "10.5.0.3");
+    }
+
+    #[test]
+    fn test_rate_staleness() {
+        let interval = Duration::from_secs(2);
+        let sampled = Instant::now();
+        let data = NetworkData {
+            last_sample: Some(sampled),
+            ..Default::default()
+        };
+
+        // Up to STALE_RATE_INTERVALS refreshes old, the rates still count as fresh
+        assert_eq!(data.rate_staleness(sampled + interval * 2, interval), None);
+
+        // Older than that, the age is reported
+        let now = sampled + Duration::from_secs(5);
+        assert_eq!(
+            data.rate_staleness(now, interval),
+            Some(Duration::from_secs(5))
+        );
+
+        // Nothing sampled yet is not stale
+        assert_eq!(NetworkData::default().rate_staleness(now, interval), None);
+    }
+}
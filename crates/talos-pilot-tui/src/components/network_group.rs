@@ -0,0 +1,990 @@
+//! Network group component - cluster-wide network traffic across all nodes
+//!
+//! "Which node is moving the traffic?"
+//!
+//! The Connections layout trades devices for one row per node with its
+//! socket state counts and key port indicators, for a quick cluster-wide
+//! read of connection health.
+
+use crate::action::Action;
+use crate::components::network::{KeyPort, RateUnit};
+use crate::components::{Component, help_entries};
+use crate::ui_ext::{empty_state, glyphs, manual_refresh};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use talos_pilot_core::{AsyncState, ComponentError, reselect_index};
+use talos_rs::{
+    ConnectionCounts, ConnectionState, ConnectionThresholds, NetDevRate, NetDevStats,
+    NetstatFilter, NodeConnections, NodeNetworkStats, TalosClient,
+};
+
+/// Auto-refresh interval in seconds (matches the single-node network view)
+const AUTO_REFRESH_INTERVAL_SECS: u64 = 2;
+
+/// How rows from multiple nodes are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupViewMode {
+    /// Node summary rows, each followed by that node's devices
+    #[default]
+    ByNode,
+    /// All devices from all nodes in one list, busiest first
+    Interleaved,
+    /// One row per node with connection counts and key port health
+    Connections,
+}
+
+impl GroupViewMode {
+    /// Cycle through the layouts
+    pub fn toggle(&self) -> Self {
+        match self {
+            GroupViewMode::ByNode => GroupViewMode::Interleaved,
+            GroupViewMode::Interleaved => GroupViewMode::Connections,
+            GroupViewMode::Connections => GroupViewMode::ByNode,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GroupViewMode::ByNode => "By Node",
+            GroupViewMode::Interleaved => "Interleaved",
+            GroupViewMode::Connections => "Connections",
+        }
+    }
+}
+
+/// Per-node network summary
+#[derive(Debug, Clone, Default)]
+pub struct NodeNetworkSummary {
+    /// Node hostname (as reported by the API)
+    pub hostname: String,
+    /// Node address used to drill down into the single-node view
+    pub address: String,
+    /// Current device statistics
+    pub devices: Vec<NetDevStats>,
+    /// Calculated rates per device
+    pub rates: HashMap<String, NetDevRate>,
+    /// Total RX rate (bytes/sec)
+    pub rx_rate: u64,
+    /// Total TX rate (bytes/sec)
+    pub tx_rate: u64,
+    /// Total errors across all devices
+    pub errors: u64,
+    /// Total dropped across all devices
+    pub dropped: u64,
+    /// Connection counts by state (None until netstat has been fetched)
+    pub conn_counts: Option<ConnectionCounts>,
+    /// Whether each key port is listening
+    pub service_health: HashMap<u16, bool>,
+}
+
+impl NodeNetworkSummary {
+    /// Recalculate node totals from devices and rates
+    ///
+    /// Rates leave out the loopback device, which would otherwise count the
+    /// node's local traffic as throughput.
+    fn update_totals(&mut self) {
+        let real = || self.rates.values().filter(|r| !r.is_loopback());
+        self.rx_rate = real().map(|r| r.rx_bytes_per_sec).sum();
+        self.tx_rate = real().map(|r| r.tx_bytes_per_sec).sum();
+        self.errors = self.devices.iter().map(|d| d.total_errors()).sum();
+        self.dropped = self.devices.iter().map(|d| d.total_dropped()).sum();
+    }
+
+    /// Rate of a device on this node (0 until a second sample arrives)
+    fn device_rate(&self, name: &str) -> u64 {
+        self.rates.get(name).map(|r| r.total_rate()).unwrap_or(0)
+    }
+}
+
+/// Async-loaded cluster network data
+#[derive(Debug, Clone, Default)]
+pub struct NetworkGroupData {
+    /// Per-node summaries, in the order nodes were requested
+    pub nodes: Vec<NodeNetworkSummary>,
+    /// Previous device stats keyed by (hostname, device) for rate calculation
+    pub prev_devices: HashMap<(String, String), NetDevStats>,
+    /// Time of last sample
+    pub last_sample: Option<Instant>,
+}
+
+impl NetworkGroupData {
+    /// Merge a new sample from all nodes, computing per-node rates
+    pub fn update(&mut self, stats: Vec<NodeNetworkStats>, addresses: &HashMap<String, String>) {
+        let now = Instant::now();
+        let elapsed_secs = self
+            .last_sample
+            .map(|t| now.duration_since(t).as_secs_f64())
+            .unwrap_or(0.0);
+
+        let mut nodes = Vec::with_capacity(stats.len());
+        let mut prev_devices = HashMap::new();
+
+        for node in stats {
+            let mut summary = NodeNetworkSummary {
+                address: addresses
+                    .get(&node.hostname)
+                    .cloned()
+                    .unwrap_or_else(|| node.hostname.clone()),
+                hostname: node.hostname,
+                ..Default::default()
+            };
+
+            // Carry rates forward so a too-quick refresh doesn't zero them,
+            // and connection data, which is only fetched in the Connections layout
+            if let Some(old) = self.nodes.iter().find(|n| n.hostname == summary.hostname) {
+                summary.rates = old.rates.clone();
+                summary.conn_counts = old.conn_counts.clone();
+                summary.service_health = old.service_health.clone();
+            }
+
+            for dev in &node.devices {
+                let key = (summary.hostname.clone(), dev.name.clone());
+                if elapsed_secs > 0.1
+                    && let Some(prev) = self.prev_devices.get(&key)
+                {
+                    let rate = NetDevRate::from_delta(prev, dev, elapsed_secs);
+                    summary.rates.insert(dev.name.clone(), rate);
+                }
+                prev_devices.insert(key, dev.clone());
+            }
+            summary
+                .rates
+                .retain(|name, _| node.devices.iter().any(|d| &d.name == name));
+
+            let rate_of = |name: &str| summary.device_rate(name);
+            let mut devices = node.devices;
+            devices.sort_by(|a, b| {
+                rate_of(&b.name)
+                    .cmp(&rate_of(&a.name))
+                    .then_with(|| b.total_traffic().cmp(&a.total_traffic()))
+            });
+            summary.devices = devices;
+            summary.update_totals();
+            nodes.push(summary);
+        }
+
+        self.nodes = nodes;
+        self.prev_devices = prev_devices;
+        self.last_sample = Some(now);
+    }
+
+    /// Merge netstat results, counting states and checking which key ports listen
+    ///
+    /// Nodes missing from the response lose their previous counts rather than
+    /// showing stale ones.
+    pub fn update_connections(&mut self, connections: &[NodeConnections], ports: &[u16]) {
+        for node in &mut self.nodes {
+            let Some(conns) = connections.iter().find(|c| c.hostname == node.hostname) else {
+                node.conn_counts = None;
+                node.service_health.clear();
+                continue;
+            };
+            node.conn_counts = Some(conns.count_by_state());
+            node.service_health = ports
+                .iter()
+                .map(|&port| {
+                    let listening = conns
+                        .connections
+                        .iter()
+                        .any(|c| c.local_port == port as u32 && c.state == ConnectionState::Listen);
+                    (port, listening)
+                })
+                .collect();
+        }
+    }
+
+    /// Whether any node has had a second sample to compute rates from
+    pub fn has_rates(&self) -> bool {
+        self.nodes.iter().any(|n| !n.rates.is_empty())
+    }
+
+    /// Cluster-wide totals: (rx_rate, tx_rate, errors, dropped)
+    pub fn totals(&self) -> (u64, u64, u64, u64) {
+        self.nodes.iter().fold((0, 0, 0, 0), |acc, n| {
+            (
+                acc.0 + n.rx_rate,
+                acc.1 + n.tx_rate,
+                acc.2 + n.errors,
+                acc.3 + n.dropped,
+            )
+        })
+    }
+}
+
+/// A row in the group table
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum GroupRow {
+    /// Node summary row (node index)
+    Node(usize),
+    /// Device row (node index, device index)
+    Device(usize, usize),
+}
+
+/// Cluster-wide network view aggregating all nodes
+pub struct NetworkGroupComponent {
+    /// Nodes to query: (hostname, address)
+    nodes: Vec<(String, String)>,
+
+    /// Async state for loaded data
+    state: AsyncState<NetworkGroupData>,
+
+    /// Row layout
+    view_mode: GroupViewMode,
+    /// Selected row index
+    selected: usize,
+    /// Table state for rendering
+    table_state: TableState,
+
+    /// Auto-refresh enabled
+    auto_refresh: bool,
+    /// Ports shown as listening indicators in the Connections layout
+    key_ports: Vec<KeyPort>,
+    /// TIME_WAIT/CLOSE_WAIT counts that are highlighted
+    conn_thresholds: ConnectionThresholds,
+
+    /// Unit for RX/TX rates
+    rate_unit: RateUnit,
+
+    /// Client targeting all nodes
+    client: Option<TalosClient>,
+}
+
+impl Default for NetworkGroupComponent {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl NetworkGroupComponent {
+    pub fn new(nodes: Vec<(String, String)>) -> Self {
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+
+        Self {
+            nodes,
+            state: AsyncState::new(),
+            view_mode: GroupViewMode::default(),
+            selected: 0,
+            table_state,
+            auto_refresh: true,
+            rate_unit: RateUnit::default(),
+            key_ports: Vec::new(),
+            conn_thresholds: ConnectionThresholds::default(),
+            client: None,
+        }
+    }
+
+    /// Addresses of the nodes in this group
+    pub fn node_addresses(&self) -> Vec<String> {
+        self.nodes.iter().map(|(_, addr)| addr.clone()).collect()
+    }
+
+    /// Set the client for API calls (should target every node in the group)
+    pub fn set_client(&mut self, client: TalosClient) {
+        self.client = Some(client);
+    }
+
+    /// Client targeting every node in the group
+    pub fn client(&self) -> Option<&TalosClient> {
+        self.client.as_ref()
+    }
+
+    /// Set the unit for RX/TX rates
+    pub fn set_rate_unit(&mut self, unit: RateUnit) {
+        self.rate_unit = unit;
+    }
+
+    /// Set the ports shown as listening indicators
+    pub fn set_key_ports(&mut self, key_ports: Vec<KeyPort>) {
+        self.key_ports = key_ports;
+    }
+
+    /// Set the TIME_WAIT/CLOSE_WAIT counts that are highlighted
+    pub fn set_connection_thresholds(&mut self, thresholds: ConnectionThresholds) {
+        self.conn_thresholds = thresholds;
+    }
+
+    /// Set error message
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
+        self.state.set_error(error);
+    }
+
+    fn data(&self) -> Option<&NetworkGroupData> {
+        self.state.data()
+    }
+
+    /// Refresh network stats from all nodes
+    pub async fn refresh(&mut self) -> Result<()> {
+        let Some(client) = self.client.clone() else {
+            self.set_error("No client configured".to_string());
+            return Ok(());
+        };
+
+        self.state.start_loading();
+
+        let prev_row = self.row_key(self.selected);
+        let timeout = Duration::from_secs(10);
+
+        // Netstat is heavier than device stats, so only fetch it when shown
+        let fetch_connections = self.view_mode == GroupViewMode::Connections;
+        let connections = async {
+            if fetch_connections {
+                Some(client.netstat(NetstatFilter::All).await)
+            } else {
+                None
+            }
+        };
+        let request = async { tokio::join!(client.network_device_stats(), connections) };
+
+        match tokio::time::timeout(timeout, request).await {
+            Ok((Ok(stats), connections)) => {
+                // The API reports either hostname or address, so map both
+                let addresses: HashMap<String, String> = self
+                    .nodes
+                    .iter()
+                    .flat_map(|(host, addr)| {
+                        [(host.clone(), addr.clone()), (addr.clone(), addr.clone())]
+                    })
+                    .collect();
+
+                let mut data = self.state.take_data().unwrap_or_default();
+                data.update(stats, &addresses);
+                match connections {
+                    Some(Ok(connections)) => {
+                        let ports: Vec<u16> = self.key_ports.iter().map(|k| k.port).collect();
+                        data.update_connections(&connections, &ports);
+                    }
+                    // Connection data is supplementary - keep the traffic view usable
+                    Some(Err(e)) => tracing::warn!("Failed to fetch connections: {}", e),
+                    None => {}
+                }
+                self.state.set_data(data);
+            }
+            Ok((Err(e), _)) => {
+                self.set_error(format!("Failed to fetch network stats: {}", e));
+                return Ok(());
+            }
+            Err(_) => {
+                self.set_error(format!("Request timed out after {}s", timeout.as_secs()));
+                return Ok(());
+            }
+        }
+
+        // Restore selection by node/device identity
+        let keys: Vec<_> = (0..self.rows().len())
+            .filter_map(|i| self.row_key(i))
+            .collect();
+        self.selected = reselect_index(&keys, prev_row.as_ref(), self.selected, |k| k.clone());
+        self.table_state.select(Some(self.selected));
+
+        Ok(())
+    }
+
+    /// Build table rows for the current view mode
+    fn rows(&self) -> Vec<GroupRow> {
+        let Some(data) = self.data() else {
+            return Vec::new();
+        };
+
+        match self.view_mode {
+            GroupViewMode::ByNode => data
+                .nodes
+                .iter()
+                .enumerate()
+                .flat_map(|(n, node)| {
+                    std::iter::once(GroupRow::Node(n))
+                        .chain((0..node.devices.len()).map(move |d| GroupRow::Device(n, d)))
+                })
+                .collect(),
+            GroupViewMode::Interleaved => {
+                let mut rows: Vec<GroupRow> = data
+                    .nodes
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(n, node)| (0..node.devices.len()).map(move |d| (n, d)))
+                    .map(|(n, d)| GroupRow::Device(n, d))
+                    .collect();
+                rows.sort_by_key(|row| match row {
+                    GroupRow::Device(n, d) => {
+                        let node = &data.nodes[*n];
+                        std::cmp::Reverse(node.device_rate(&node.devices[*d].name))
+                    }
+                    GroupRow::Node(_) => std::cmp::Reverse(0),
+                });
+                rows
+            }
+            GroupViewMode::Connections => (0..data.nodes.len()).map(GroupRow::Node).collect(),
+        }
+    }
+
+    /// Stable identity of a row: (hostname, device name)
+    fn row_key(&self, index: usize) -> Option<(String, Option<String>)> {
+        let data = self.data()?;
+        match self.rows().get(index)? {
+            GroupRow::Node(n) => Some((data.nodes[*n].hostname.clone(), None)),
+            GroupRow::Device(n, d) => {
+                let node = &data.nodes[*n];
+                Some((node.hostname.clone(), Some(node.devices[*d].name.clone())))
+            }
+        }
+    }
+
+    /// Node (hostname, address) for the selected row
+    fn selected_node(&self) -> Option<(String, String)> {
+        let data = self.data()?;
+        let n = match self.rows().get(self.selected)? {
+            GroupRow::Node(n) | GroupRow::Device(n, _) => *n,
+        };
+        let node = &data.nodes[n];
+        Some((node.hostname.clone(), node.address.clone()))
+    }
+
+    fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+        self.table_state.select(Some(self.selected));
+    }
+
+    fn select_next(&mut self) {
+        let len = self.rows().len();
+        if len > 0 {
+            self.selected = (self.selected + 1).min(len - 1);
+        }
+        self.table_state.select(Some(self.selected));
+    }
+
+    /// Draw cluster-wide totals
+    fn draw_summary_bar(&self, frame: &mut Frame, area: Rect) {
+        let (rx, tx, errors, dropped) = self.data().map(|d| d.totals()).unwrap_or_default();
+        let has_rates = self.data().is_some_and(|d| d.has_rates());
+        let node_count = self.data().map(|d| d.nodes.len()).unwrap_or(0);
+
+        let error_style = if errors > 0 || dropped > 0 {
+            Style::default().fg(Color::Red)
+        } else {
+            Style::default().fg(Color::Green)
+        };
+
+        let line = Line::from(vec![
+            Span::styled(" Cluster ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!("({} nodes)  ", node_count),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled("RX: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                self.rate_unit.format_opt(has_rates.then_some(rx)),
+                Style::default().fg(Color::Green),
+            ),
+            Span::raw("  "),
+            Span::styled("TX: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                self.rate_unit.format_opt(has_rates.then_some(tx)),
+                Style::default().fg(Color::Blue),
+            ),
+            Span::raw("  "),
+            Span::styled("Errors: ", Style::default().fg(Color::Gray)),
+            Span::styled(errors.to_string(), error_style),
+            Span::raw("  "),
+            Span::styled("Dropped: ", Style::default().fg(Color::Gray)),
+            Span::styled(dropped.to_string(), error_style),
+            Span::raw("  "),
+            Span::styled(
+                format!("[{}]", self.view_mode.label()),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]);
+
+        frame.render_widget(Paragraph::new(line), area);
+    }
+
+    /// Draw one row per node with connection counts and key port health
+    fn draw_connections_table(&mut self, frame: &mut Frame, area: Rect) {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let header = Row::new(vec![
+            Cell::from("NODE").style(bold),
+            Cell::from("ESTAB").style(bold),
+            Cell::from("LISTEN").style(bold),
+            Cell::from("TIME_WAIT").style(bold),
+            Cell::from("CLOSE_WAIT").style(bold),
+            Cell::from("KEY PORTS").style(bold),
+        ])
+        .height(1);
+
+        let thresholds = &self.conn_thresholds;
+        let rows: Vec<Row> = self
+            .data()
+            .map(|data| data.nodes.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|node| {
+                let Some(cc) = &node.conn_counts else {
+                    return Row::new(vec![
+                        Cell::from(node.hostname.clone()).style(Style::default().fg(Color::Cyan)),
+                        Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+                    ]);
+                };
+
+                let tw_style = if cc.high_time_wait(thresholds) {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default()
+                };
+                let cw_style = if cc.high_close_wait(thresholds) {
+                    Style::default().fg(Color::Red)
+                } else {
+                    Style::default()
+                };
+
+                let mut ports = Vec::new();
+                for KeyPort { label, port } in &self.key_ports {
+                    let listening = node.service_health.get(port).copied().unwrap_or(false);
+                    let (indicator, color) = if listening {
+                        (glyphs().filled, Color::Green)
+                    } else {
+                        (glyphs().hollow, Color::Red)
+                    };
+                    ports.push(Span::styled(indicator, Style::default().fg(color)));
+                    ports.push(Span::styled(
+                        format!("{}  ", label),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+
+                Row::new(vec![
+                    Cell::from(node.hostname.clone()).style(Style::default().fg(Color::Cyan)),
+                    Cell::from(cc.established.to_string()).style(Style::default().fg(Color::Green)),
+                    Cell::from(cc.listen.to_string()).style(Style::default().fg(Color::Cyan)),
+                    Cell::from(cc.time_wait.to_string()).style(tw_style),
+                    Cell::from(cc.close_wait.to_string()).style(cw_style),
+                    Cell::from(Line::from(ports)),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(20),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(11),
+            Constraint::Length(11),
+            Constraint::Min(0),
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Connections - All Nodes ")
+                    .title_style(Style::default().fg(Color::Cyan)),
+            )
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+
+    /// Draw the node/device table
+    fn draw_table(&mut self, frame: &mut Frame, area: Rect) {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let header = Row::new(vec![
+            Cell::from("NODE").style(bold),
+            Cell::from("DEVICE").style(bold),
+            Cell::from("RX RATE").style(bold),
+            Cell::from("TX RATE").style(bold),
+            Cell::from("ERRORS").style(bold),
+            Cell::from("DROPPED").style(bold),
+        ])
+        .height(1);
+
+        let rows: Vec<Row> = match self.data() {
+            Some(data) => self
+                .rows()
+                .iter()
+                .map(|row| match row {
+                    GroupRow::Node(n) => {
+                        let node = &data.nodes[*n];
+                        let issue_color = if node.errors > 0 || node.dropped > 0 {
+                            Color::Red
+                        } else {
+                            Color::DarkGray
+                        };
+                        let has_rates = !node.rates.is_empty();
+                        Row::new(vec![
+                            Cell::from(node.hostname.clone())
+                                .style(Style::default().fg(Color::Cyan)),
+                            Cell::from(format!("{} devices", node.devices.len()))
+                                .style(Style::default().fg(Color::DarkGray)),
+                            Cell::from(
+                                self.rate_unit.format_opt(has_rates.then_some(node.rx_rate)),
+                            )
+                            .style(Style::default().fg(Color::Green)),
+                            Cell::from(
+                                self.rate_unit.format_opt(has_rates.then_some(node.tx_rate)),
+                            )
+                            .style(Style::default().fg(Color::Blue)),
+                            Cell::from(node.errors.to_string())
+                                .style(Style::default().fg(issue_color)),
+                            Cell::from(node.dropped.to_string())
+                                .style(Style::default().fg(issue_color)),
+                        ])
+                        .style(Style::default().add_modifier(Modifier::BOLD))
+                    }
+                    GroupRow::Device(n, d) => {
+                        let node = &data.nodes[*n];
+                        let dev = &node.devices[*d];
+                        let rate = node.rates.get(&dev.name);
+                        let issue_color = if dev.has_errors() {
+                            Color::Red
+                        } else {
+                            Color::DarkGray
+                        };
+                        // Node name is implied by the summary row in ByNode layout
+                        let node_cell = match self.view_mode {
+                            GroupViewMode::ByNode => String::new(),
+                            GroupViewMode::Interleaved | GroupViewMode::Connections => {
+                                node.hostname.clone()
+                            }
+                        };
+                        Row::new(vec![
+                            Cell::from(node_cell),
+                            Cell::from(format!("  {}", dev.name)),
+                            Cell::from(self.rate_unit.format_opt(rate.map(|r| r.rx_bytes_per_sec)))
+                                .style(Style::default().fg(Color::Green)),
+                            Cell::from(self.rate_unit.format_opt(rate.map(|r| r.tx_bytes_per_sec)))
+                                .style(Style::default().fg(Color::Blue)),
+                            Cell::from(dev.total_errors().to_string())
+                                .style(Style::default().fg(issue_color)),
+                            Cell::from(dev.total_dropped().to_string())
+                                .style(Style::default().fg(issue_color)),
+                        ])
+                    }
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let widths = [
+            Constraint::Length(20),
+            Constraint::Length(18),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Length(8),
+            Constraint::Length(8),
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Network - All Nodes ")
+                    .title_style(Style::default().fg(Color::Cyan)),
+            )
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+}
+
+impl Component for NetworkGroupComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Navigate"),
+            ("v", "Cycle By Node/Interleaved/Connections"),
+            ("Enter", "Open node network (or connections) view"),
+            ("b", "Cycle B/s / SI B/s / bit/s"),
+            ("a", "Toggle auto-refresh"),
+            ("r", "Refresh"),
+            ("q/Esc", "Back"),
+        ])
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(Some(Action::Back)),
+            KeyCode::Up | KeyCode::Char('k') => self.select_prev(),
+            KeyCode::Down | KeyCode::Char('j') => self.select_next(),
+            KeyCode::Char('v') => {
+                let key = self.row_key(self.selected);
+                self.view_mode = self.view_mode.toggle();
+                // Device rows drop to their node's row in the Connections layout
+                let key = key.map(|(hostname, device)| match self.view_mode {
+                    GroupViewMode::Connections => (hostname, None),
+                    _ => (hostname, device),
+                });
+                let keys: Vec<_> = (0..self.rows().len())
+                    .filter_map(|i| self.row_key(i))
+                    .collect();
+                self.selected = reselect_index(&keys, key.as_ref(), 0, |k| k.clone());
+                self.table_state.select(Some(self.selected));
+                // Connection counts are only fetched while the layout is shown
+                if self.view_mode == GroupViewMode::Connections && self.state.can_refresh() {
+                    return Ok(Some(Action::Refresh));
+                }
+            }
+            KeyCode::Enter => {
+                if let Some((hostname, address)) = self.selected_node() {
+                    let show = match self.view_mode {
+                        GroupViewMode::Connections => Action::ShowNetworkConnections,
+                        _ => Action::ShowNetwork,
+                    };
+                    return Ok(Some(show(hostname, address)));
+                }
+            }
+            KeyCode::Char('a') => self.auto_refresh = !self.auto_refresh,
+            KeyCode::Char('b') => return Ok(Some(Action::ToggleRateUnit)),
+            KeyCode::Char('r') => {
+                return Ok(manual_refresh(&self.state));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        if let Action::Tick = action {
+            let interval = Duration::from_secs(AUTO_REFRESH_INTERVAL_SECS);
+            if self.state.should_auto_refresh(self.auto_refresh, interval) {
+                return Ok(Some(Action::Refresh));
+            }
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        if self.state.is_loading() && !self.state.has_data() {
+            let loading = Paragraph::new("Loading cluster network stats...")
+                .style(Style::default().fg(Color::DarkGray));
+            frame.render_widget(loading, area);
+            return Ok(());
+        }
+
+        if let Some(err) = self.state.error() {
+            let error =
+                Paragraph::new(format!("Error: {}", err)).style(Style::default().fg(Color::Red));
+            frame.render_widget(error, area);
+            return Ok(());
+        }
+
+        let chunks = Layout::vertical([
+            Constraint::Length(1), // Summary
+            Constraint::Min(0),    // Table
+            Constraint::Length(1), // Help
+        ])
+        .split(area);
+
+        self.draw_summary_bar(frame, chunks[0]);
+        if self.data().is_some_and(|d| d.nodes.is_empty()) {
+            frame.render_widget(empty_state("No nodes in this group", None), chunks[1]);
+        } else {
+            match self.view_mode {
+                GroupViewMode::Connections => self.draw_connections_table(frame, chunks[1]),
+                _ => self.draw_table(frame, chunks[1]),
+            }
+        }
+
+        let auto = if self.auto_refresh { "on" } else { "off" };
+        let help = Line::from(vec![
+            Span::styled(" v", Style::default().fg(Color::Cyan)),
+            Span::raw(" layout  "),
+            Span::styled("Enter", Style::default().fg(Color::Cyan)),
+            Span::raw(" node view  "),
+            Span::styled("b", Style::default().fg(Color::Cyan)),
+            Span::raw(format!(" {}  ", self.rate_unit.label())),
+            Span::styled("a", Style::default().fg(Color::Cyan)),
+            Span::raw(format!(" auto-refresh ({})  ", auto)),
+            Span::styled("r", Style::default().fg(Color::Cyan)),
+            Span::raw(" refresh  "),
+            Span::styled("Esc", Style::default().fg(Color::Cyan)),
+            Span::raw(" back"),
+        ]);
+        frame.render_widget(
+            Paragraph::new(help).style(Style::default().fg(Color::DarkGray)),
+            chunks[2],
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use talos_rs::ConnectionInfo;
+
+    fn dev(name: &str, rx: u64, tx: u64, errors: u64) -> NetDevStats {
+        NetDevStats {
+            name: name.to_string(),
+            rx_bytes: rx,
+            rx_packets: 0,
+            rx_errors: errors,
+            rx_dropped: 0,
+            tx_bytes: tx,
+            tx_packets: 0,
+            tx_errors: 0,
+            tx_dropped: 1,
+        }
+    }
+
+    fn sample(hostname: &str, devices: Vec<NetDevStats>) -> NodeNetworkStats {
+        NodeNetworkStats {
+            hostname: hostname.to_string(),
+            total: None,
+            devices,
+        }
+    }
+
+    #[test]
+    fn test_group_update_aggregates_nodes() {
+        let addresses = HashMap::from([("cp1".to_string(), "10.0.0.1".to_string())]);
+        let mut data = NetworkGroupData::default();
+
+        data.update(
+            vec![
+                sample("cp1", vec![dev("eth0", 1000, 500, 0)]),
+                sample("w1", vec![dev("eth0", 0, 0, 2), dev("cni0", 0, 0, 0)]),
+            ],
+            &addresses,
+        );
+
+        // No rates on the first sample, but error totals are available
+        assert!(!data.has_rates());
+        assert_eq!(data.nodes.len(), 2);
+        assert_eq!(data.nodes[0].address, "10.0.0.1");
+        assert_eq!(data.nodes[1].address, "w1");
+        assert_eq!(data.totals(), (0, 0, 2, 3));
+
+        // Second sample yields per-node rates that sum into the cluster total
+        data.last_sample = Some(Instant::now() - Duration::from_secs(1));
+        data.update(
+            vec![
+                sample("cp1", vec![dev("eth0", 3000, 1500, 0)]),
+                sample("w1", vec![dev("eth0", 1000, 0, 2), dev("cni0", 0, 0, 0)]),
+            ],
+            &addresses,
+        );
+
+        assert!(data.has_rates());
+        let (rx, tx, errors, dropped) = data.totals();
+        assert!((2500..=3000).contains(&rx), "rx = {}", rx);
+        assert!((800..=1000).contains(&tx), "tx = {}", tx);
+        assert_eq!((errors, dropped), (2, 3));
+
+        // Busiest device first within a node
+        assert_eq!(data.nodes[1].devices[0].name, "eth0");
+    }
+
+    #[test]
+    fn test_totals_exclude_loopback() {
+        let addresses = HashMap::new();
+        let mut data = NetworkGroupData::default();
+        data.update(
+            vec![sample(
+                "cp1",
+                vec![dev("eth0", 0, 0, 0), dev("lo", 0, 0, 0)],
+            )],
+            &addresses,
+        );
+        data.last_sample = Some(Instant::now() - Duration::from_secs(1));
+        data.update(
+            vec![sample(
+                "cp1",
+                vec![dev("eth0", 1000, 1000, 0), dev("lo", 500_000, 500_000, 0)],
+            )],
+            &addresses,
+        );
+
+        let (rx, tx, _, _) = data.totals();
+        assert!((800..=1000).contains(&rx), "rx = {}", rx);
+        assert!((800..=1000).contains(&tx), "tx = {}", tx);
+        // lo is still listed with its own rate
+        assert!(data.nodes[0].device_rate("lo") > 0);
+    }
+
+    #[test]
+    fn test_rate_unit_scaling() {
+        assert_eq!(RateUnit::Bytes.format(1_048_576), "1.0 MB/s");
+        assert_eq!(RateUnit::SiBytes.format(1_048_576), "1.0 MB/s");
+        assert_eq!(RateUnit::SiBytes.format(1_500_000), "1.5 MB/s");
+        assert_eq!(RateUnit::Bytes.format(1_500_000), "1.4 MB/s");
+        assert_eq!(RateUnit::Bits.format(125_000_000), "1.0 Gb/s");
+        assert_eq!(RateUnit::Bytes.toggle(), RateUnit::SiBytes);
+        assert_eq!(RateUnit::SiBytes.toggle(), RateUnit::Bits);
+        assert_eq!(RateUnit::Bits.toggle(), RateUnit::Bytes);
+    }
+
+    #[test]
+    fn test_group_view_mode_toggle() {
+        assert_eq!(GroupViewMode::ByNode.toggle(), GroupViewMode::Interleaved);
+        assert_eq!(
+            GroupViewMode::Interleaved.toggle(),
+            GroupViewMode::Connections
+        );
+        assert_eq!(GroupViewMode::Connections.toggle(), GroupViewMode::ByNode);
+    }
+
+    fn conn(port: u32, state: ConnectionState) -> ConnectionInfo {
+        ConnectionInfo {
+            protocol: "tcp".to_string(),
+            local_ip: "0.0.0.0".to_string(),
+            local_port: port,
+            remote_ip: String::new(),
+            remote_port: 0,
+            state,
+            rx_queue: 0,
+            tx_queue: 0,
+            process_pid: None,
+            process_name: None,
+            netns: None,
+        }
+    }
+
+    #[test]
+    fn test_group_update_connections() {
+        let mut data = NetworkGroupData::default();
+        data.update(
+            vec![sample("cp1", vec![]), sample("w1", vec![])],
+            &HashMap::new(),
+        );
+
+        let connections = vec![NodeConnections {
+            hostname: "cp1".to_string(),
+            connections: vec![
+                conn(6443, ConnectionState::Listen),
+                conn(50000, ConnectionState::Established),
+                conn(50001, ConnectionState::TimeWait),
+                conn(2379, ConnectionState::Established),
+            ],
+        }];
+        data.update_connections(&connections, &[6443, 2379]);
+
+        let cp = &data.nodes[0];
+        let counts = cp.conn_counts.as_ref().unwrap();
+        assert_eq!(
+            (counts.established, counts.listen, counts.time_wait),
+            (2, 1, 1)
+        );
+        // Only a LISTEN socket counts as the port being up
+        assert_eq!(cp.service_health.get(&6443), Some(&true));
+        assert_eq!(cp.service_health.get(&2379), Some(&false));
+
+        // Nodes missing from the response have no counts
+        assert!(data.nodes[1].conn_counts.is_none());
+
+        // Counts survive a device stats refresh
+        data.update(
+            vec![sample("cp1", vec![]), sample("w1", vec![])],
+            &HashMap::new(),
+        );
+        assert!(data.nodes[0].conn_counts.is_some());
+    }
+}
@@ -1,15 +1,17 @@
 //! Node operations overlay component
 //!
-//! Shows safety checks and available operations for a selected node.
+//! Shows safety checks and available operations for a selected node:
+//! reboot, drain, and in-place Talos upgrades.
 
 use crate::action::Action;
-use crate::components::Component;
 use crate::components::diagnostics::k8s::{
     DrainOptions, PdbHealthInfo, check_pdb_health, create_k8s_client,
 };
-use crate::ui_ext::SafetyStatusExt;
+use crate::components::wizard::WaitLimits;
+use crate::components::{Component, help_entries};
+use crate::ui_ext::{SafetyStatusExt, glyphs};
 use color_eyre::Result;
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use kube::Client;
 use ratatui::{
     Frame,
@@ -19,10 +21,27 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph},
 };
 use std::sync::{Arc, Mutex};
-use talos_pilot_core::{AsyncState, SafetyStatus};
-use talos_rs::TalosClient;
+use std::time::{Duration, Instant};
+use talos_pilot_core::{AsyncState, ComponentError, SafetyStatus};
+use talos_rs::{ServiceInfo, TalosClient};
 use tokio::task::JoinHandle;
 
+/// Installer image repository suggested for upgrades
+const INSTALLER_REPO: &str = "ghcr.io/siderolabs/installer";
+
+/// How long to wait for an upgraded node to come back healthy; installing
+/// the image and rebooting takes longer than the wizard's waits
+const UPGRADE_WAIT_LIMITS: WaitLimits = WaitLimits {
+    max_attempts: 180,
+    max_elapsed: Duration::from_secs(900),
+};
+
+/// Delay between health polls while waiting for an upgraded node
+const UPGRADE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Timeout for a single health poll, since calls to a rebooting node can hang
+const UPGRADE_POLL_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// etcd quorum information for the node
 #[derive(Debug, Clone, Default)]
 pub struct NodeEtcdInfo {
@@ -62,6 +81,8 @@ impl NodeEtcdInfo {
 pub enum OperationState {
     /// Showing safety checks, ready for operation selection
     Ready,
+    /// Editing the installer image for an upgrade
+    SelectingImage,
     /// Confirming an operation
     Confirming(OperationType),
     /// Executing an operation
@@ -75,6 +96,7 @@ pub enum OperationState {
 pub enum OperationType {
     Drain,
     Reboot,
+    Upgrade,
 }
 
 impl OperationType {
@@ -82,6 +104,7 @@ impl OperationType {
         match self {
             OperationType::Drain => "Drain",
             OperationType::Reboot => "Reboot",
+            OperationType::Upgrade => "Upgrade",
         }
     }
 }
@@ -460,6 +483,203 @@ async fn run_reboot_operation(
     }
 }
 
+/// Run an in-place Talos upgrade in background
+///
+/// Talos cordons and drains the node itself before installing the image, so
+/// unlike reboot there is no drain step here. Unless the upgrade is staged,
+/// waits for the node to reboot and report healthy services again.
+async fn run_upgrade_operation(
+    progress: Arc<Mutex<OperationProgress>>,
+    hostname: String,
+    address: String,
+    talos_client: Option<TalosClient>,
+    image: String,
+    stage: bool,
+) -> OperationResult {
+    use crate::audit::{audit_failure, audit_start, audit_success};
+
+    let mode = if stage { "staged" } else { "immediate" };
+    audit_start(
+        "UPGRADE",
+        &hostname,
+        &format!("Starting {} upgrade to {}", mode, image),
+    );
+
+    let Some(client) = talos_client else {
+        audit_failure("UPGRADE", &hostname, "No Talos client available");
+        return OperationResult {
+            success: false,
+            message: "No Talos client available".to_string(),
+        };
+    };
+
+    {
+        let mut p = progress.lock().unwrap();
+        p.message = "Sending upgrade request...".to_string();
+    }
+
+    let node_client = client.with_node(&address);
+    if let Err(e) = node_client.upgrade(&image, stage).await {
+        let msg = format!(
+            "Upgrade request failed: {}",
+            talos_pilot_core::format_talos_error(&e)
+        );
+        audit_failure("UPGRADE", &hostname, &msg);
+        return OperationResult {
+            success: false,
+            message: msg,
+        };
+    }
+
+    if stage {
+        let msg = format!("Upgrade to {} staged, applies on next reboot", image);
+        audit_success("UPGRADE", &hostname, &msg);
+        return OperationResult {
+            success: true,
+            message: msg,
+        };
+    }
+
+    let result = wait_for_upgrade(&progress, &node_client, &image).await;
+    if result.success {
+        audit_success("UPGRADE", &hostname, &result.message);
+    } else {
+        audit_failure("UPGRADE", &hostname, &result.message);
+    }
+    result
+}
+
+/// Wait for an upgraded node to go down, come back and report healthy
+///
+/// The node keeps answering on the old version while it installs the image,
+/// so it only counts as back once it has been unreachable at least once.
+async fn wait_for_upgrade(
+    progress: &Arc<Mutex<OperationProgress>>,
+    node_client: &TalosClient,
+    image: &str,
+) -> OperationResult {
+    let limits = UPGRADE_WAIT_LIMITS;
+    let started = Instant::now();
+    let mut went_down = false;
+
+    for attempt in 1..=limits.max_attempts {
+        if started.elapsed() >= limits.max_elapsed {
+            break;
+        }
+        tokio::time::sleep(UPGRADE_POLL_INTERVAL).await;
+
+        let version = tokio::time::timeout(UPGRADE_POLL_TIMEOUT, node_client.version()).await;
+        let status = match version {
+            Ok(Ok(versions)) if went_down => {
+                let services = tokio::time::timeout(UPGRADE_POLL_TIMEOUT, node_client.services())
+                    .await
+                    .ok()
+                    .and_then(Result::ok)
+                    .and_then(|nodes| nodes.into_iter().next())
+                    .map(|n| n.services)
+                    .unwrap_or_default();
+                if services_healthy(&services) {
+                    let running = versions
+                        .into_iter()
+                        .next()
+                        .map(|v| v.version)
+                        .unwrap_or_default();
+                    return upgrade_outcome(image, &running, started.elapsed());
+                }
+                "Node is back, waiting for services to become healthy"
+            }
+            Ok(Ok(_)) => "Installing image, waiting for node to reboot",
+            Ok(Err(_)) | Err(_) => {
+                went_down = true;
+                "Node is rebooting"
+            }
+        };
+
+        let mut p = progress.lock().unwrap();
+        p.message = format!(
+            "{} ({}/{}, {}s)",
+            status,
+            attempt,
+            limits.max_attempts,
+            started.elapsed().as_secs()
+        );
+    }
+
+    OperationResult {
+        success: false,
+        message: format!(
+            "Upgrade sent, but the node was not healthy after {}s",
+            started.elapsed().as_secs()
+        ),
+    }
+}
+
+/// Judge a finished upgrade by the version the node came back on
+///
+/// Talos boots the previous version again if the new one fails to start, so
+/// a node that comes back on another version than requested rolled back.
+fn upgrade_outcome(image: &str, running: &str, elapsed: Duration) -> OperationResult {
+    match image_tag(image) {
+        Some(target) if target != running => OperationResult {
+            success: false,
+            message: format!(
+                "Node came back on {} instead of {} (upgrade rolled back?)",
+                running, target
+            ),
+        },
+        _ => OperationResult {
+            success: true,
+            message: format!(
+                "Upgraded to {} in {}s, services healthy",
+                running,
+                elapsed.as_secs()
+            ),
+        },
+    }
+}
+
+/// Whether every service that reports health is healthy
+fn services_healthy(services: &[ServiceInfo]) -> bool {
+    !services.is_empty()
+        && services
+            .iter()
+            .all(|s| s.health.as_ref().is_none_or(|h| h.unknown || h.healthy))
+}
+
+/// Tag of a container image reference, ignoring registry ports and digests
+fn image_tag(image: &str) -> Option<&str> {
+    let name = image.split('@').next().unwrap_or(image);
+    let last = name.rsplit('/').next().unwrap_or(name);
+    last.split_once(':')
+        .map(|(_, tag)| tag)
+        .filter(|t| !t.is_empty())
+}
+
+/// Installer image for the next patch release after `version` (e.g. v1.9.2
+/// suggests `installer:v1.9.3`), or for `version` itself if it is not a
+/// plain release tag
+fn suggested_installer_image(version: &str) -> String {
+    let bumped = version.strip_prefix('v').and_then(|v| {
+        let mut parts = v.split('.');
+        let (major, minor, patch) = (parts.next()?, parts.next()?, parts.next()?);
+        if parts.next().is_some()
+            || !major
+                .bytes()
+                .chain(minor.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        let patch: u32 = patch.parse().ok()?;
+        Some(format!("v{}.{}.{}", major, minor, patch + 1))
+    });
+    format!(
+        "{}:{}",
+        INSTALLER_REPO,
+        bumped.as_deref().unwrap_or(version)
+    )
+}
+
 /// Async-loaded data for node operations
 #[derive(Debug, Clone, Default)]
 pub struct NodeOperationsData {
@@ -471,6 +691,8 @@ pub struct NodeOperationsData {
     pub reboot_safety: SafetyStatus,
     /// Overall drain safety status
     pub drain_safety: SafetyStatus,
+    /// Talos version the node is running
+    pub talos_version: Option<String>,
 }
 
 /// Node operations overlay component
@@ -503,6 +725,11 @@ pub struct NodeOperationsComponent {
 
     /// Drain options (configurable timeouts, force delete, etc.)
     drain_options: DrainOptions,
+
+    /// Installer image for the upgrade, pre-filled from the running version
+    upgrade_image: String,
+    /// Whether the confirmed upgrade only stages the image for next reboot
+    upgrade_stage: bool,
 }
 
 impl Default for NodeOperationsComponent {
@@ -525,6 +752,8 @@ impl NodeOperationsComponent {
             operation_task: None,
             operation_progress: Arc::new(Mutex::new(OperationProgress::default())),
             drain_options: DrainOptions::default(),
+            upgrade_image: String::new(),
+            upgrade_stage: false,
         }
     }
 
@@ -549,7 +778,7 @@ impl NodeOperationsComponent {
     }
 
     /// Set error message
-    pub fn set_error(&mut self, error: String) {
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
         self.state.set_error(error);
     }
 
@@ -605,6 +834,8 @@ impl NodeOperationsComponent {
         let k8s_client = self.k8s_client.clone();
         let talos_client = self.client.clone();
         let drain_options = self.drain_options.clone();
+        let image = self.upgrade_image.trim().to_string();
+        let stage = self.upgrade_stage;
 
         let task = tokio::spawn(async move {
             match op_type {
@@ -622,6 +853,10 @@ impl NodeOperationsComponent {
                     )
                     .await
                 }
+                OperationType::Upgrade => {
+                    run_upgrade_operation(progress, hostname, address, talos_client, image, stage)
+                        .await
+                }
             }
         });
 
@@ -665,6 +900,8 @@ impl NodeOperationsComponent {
         // Fetch PDB info
         self.fetch_pdb_info(&client).await;
 
+        self.fetch_version(&client).await;
+
         // Calculate overall safety status
         self.calculate_safety_status();
 
@@ -741,6 +978,34 @@ impl NodeOperationsComponent {
         }
     }
 
+    /// Fetch the running Talos version and suggest an upgrade image from it
+    async fn fetch_version(&mut self, client: &TalosClient) {
+        let version = match client.with_node(&self.address).version().await {
+            Ok(versions) => versions.into_iter().next().map(|v| v.version),
+            Err(e) => {
+                tracing::warn!("Failed to fetch Talos version: {}", e);
+                None
+            }
+        };
+
+        if self.upgrade_image.is_empty() {
+            self.upgrade_image = match &version {
+                Some(v) => suggested_installer_image(v),
+                None => format!("{}:", INSTALLER_REPO),
+            };
+        }
+        if let Some(data) = self.data_mut() {
+            data.talos_version = version;
+        }
+    }
+
+    /// Whether reboot-like operations are blocked by the safety checks
+    fn reboot_unsafe(&self) -> bool {
+        self.data()
+            .map(|d| matches!(d.reboot_safety, SafetyStatus::Unsafe(_)))
+            .unwrap_or(true)
+    }
+
     /// Fetch PDB information
     async fn fetch_pdb_info(&mut self, client: &TalosClient) {
         // Initialize K8s client if needed
@@ -811,6 +1076,9 @@ impl NodeOperationsComponent {
     fn draw_overlay(&self, frame: &mut Frame, area: Rect) {
         // Handle different states with different overlays
         match &self.operation_state {
+            OperationState::SelectingImage => {
+                self.draw_image_dialog(frame, area);
+            }
             OperationState::Confirming(op_type) => {
                 self.draw_confirmation_dialog(frame, area, *op_type);
             }
@@ -826,10 +1094,63 @@ impl NodeOperationsComponent {
         }
     }
 
+    /// Draw the installer image prompt for an upgrade
+    fn draw_image_dialog(&self, frame: &mut Frame, area: Rect) {
+        let overlay_width = 64.min(area.width.saturating_sub(4));
+        let overlay_height = 11.min(area.height.saturating_sub(4));
+        let x = (area.width.saturating_sub(overlay_width)) / 2;
+        let y = (area.height.saturating_sub(overlay_height)) / 2;
+        let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
+
+        frame.render_widget(Clear, overlay_area);
+
+        let current = self
+            .data()
+            .and_then(|d| d.talos_version.as_deref())
+            .unwrap_or("unknown");
+
+        let lines = vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::raw("  Running: "),
+                Span::styled(current, Style::default().fg(Color::Cyan)),
+            ]),
+            Line::from(""),
+            Line::from("  Installer image:"),
+            Line::from(vec![
+                Span::styled("  > ", Style::default().fg(Color::Yellow)),
+                Span::raw(&self.upgrade_image),
+                Span::styled("█", Style::default().fg(Color::Yellow)),
+            ]),
+            Line::from(""),
+            Line::from(vec![Span::styled(
+                "  Image Factory: factory.talos.dev/installer/<schematic>:<ver>",
+                Style::default().fg(Color::DarkGray),
+            )]),
+            Line::from(""),
+            Line::from(vec![Span::styled(
+                "  [Enter] Continue    [Esc] Cancel",
+                Style::default().fg(Color::DarkGray),
+            )]),
+        ];
+
+        let block = Block::default()
+            .title(format!(" Upgrade {} ", self.hostname))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let paragraph = Paragraph::new(lines).block(block);
+        frame.render_widget(paragraph, overlay_area);
+    }
+
     /// Draw confirmation dialog
     fn draw_confirmation_dialog(&self, frame: &mut Frame, area: Rect, op_type: OperationType) {
-        let overlay_width = 50.min(area.width.saturating_sub(4));
-        let overlay_height = 10.min(area.height.saturating_sub(4));
+        let (width, height) = match op_type {
+            OperationType::Upgrade => (64, 13),
+            _ => (50, 10),
+        };
+        let overlay_width = width.min(area.width.saturating_sub(4));
+        let overlay_height = height.min(area.height.saturating_sub(4));
         let x = (area.width.saturating_sub(overlay_width)) / 2;
         let y = (area.height.saturating_sub(overlay_height)) / 2;
         let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
@@ -841,7 +1162,7 @@ impl NodeOperationsComponent {
 
         let op_name = op_type.name();
         let warning_color = match op_type {
-            OperationType::Reboot => Color::Red,
+            OperationType::Reboot | OperationType::Upgrade => Color::Red,
             OperationType::Drain => Color::Yellow,
         };
 
@@ -882,23 +1203,46 @@ impl NodeOperationsComponent {
                     Style::default().fg(Color::DarkGray),
                 )]));
             }
+            OperationType::Upgrade => {
+                lines.push(Line::from(vec![
+                    Span::raw("  Image: "),
+                    Span::styled(self.upgrade_image.trim(), Style::default().fg(Color::Cyan)),
+                ]));
+                lines.push(Line::from(""));
+                lines.push(Line::from(vec![Span::raw("  Now: Talos will")]));
+                lines.push(Line::from(vec![Span::styled(
+                    "    cordon and drain the node, install, and reboot",
+                    Style::default().fg(Color::DarkGray),
+                )]));
+                lines.push(Line::from(vec![Span::raw(
+                    "  Staged: nothing restarts now",
+                )]));
+                lines.push(Line::from(vec![Span::styled(
+                    "    the image is installed on the next reboot",
+                    Style::default().fg(Color::DarkGray),
+                )]));
+            }
         }
 
+        let key_style = |color: Color| Style::default().fg(color).add_modifier(Modifier::BOLD);
         lines.push(Line::from(""));
-        lines.push(Line::from(vec![
-            Span::styled(
-                "  [y]",
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(" Confirm    "),
-            Span::styled(
-                "[n]",
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(" Cancel"),
-        ]));
+        if op_type == OperationType::Upgrade {
+            lines.push(Line::from(vec![
+                Span::styled("  [y]", key_style(Color::Green)),
+                Span::raw(" Upgrade now    "),
+                Span::styled("[s]", key_style(Color::Yellow)),
+                Span::raw(" Stage    "),
+                Span::styled("[n]", key_style(Color::Red)),
+                Span::raw(" Cancel"),
+            ]));
+        } else {
+            lines.push(Line::from(vec![
+                Span::styled("  [y]", key_style(Color::Green)),
+                Span::raw(" Confirm    "),
+                Span::styled("[n]", key_style(Color::Red)),
+                Span::raw(" Cancel"),
+            ]));
+        }
 
         let title = format!(" Confirm {} ", op_name);
         let block = Block::default()
@@ -973,9 +1317,9 @@ impl NodeOperationsComponent {
         frame.render_widget(Clear, overlay_area);
 
         let (status_icon, status_color, status_text) = if success {
-            ("✓", Color::Green, "Completed")
+            (glyphs().check, Color::Green, "Completed")
         } else {
-            ("✗", Color::Red, "Failed")
+            (glyphs().cross, Color::Red, "Failed")
         };
 
         let mut lines = Vec::new();
@@ -1012,7 +1356,7 @@ impl NodeOperationsComponent {
     fn draw_ready_overlay(&self, frame: &mut Frame, area: Rect) {
         // Calculate centered overlay position
         let overlay_width = 50.min(area.width.saturating_sub(4));
-        let overlay_height = 18.min(area.height.saturating_sub(4));
+        let overlay_height = 20.min(area.height.saturating_sub(4));
         let x = (area.width.saturating_sub(overlay_width)) / 2;
         let y = (area.height.saturating_sub(overlay_height)) / 2;
 
@@ -1048,6 +1392,14 @@ impl NodeOperationsComponent {
                 }),
             ),
         ]));
+        lines.push(Line::from(vec![
+            Span::raw("  Talos: "),
+            Span::styled(
+                data.and_then(|d| d.talos_version.as_deref())
+                    .unwrap_or("unknown"),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]));
 
         // etcd info (for control plane)
         if self.is_controlplane {
@@ -1193,6 +1545,20 @@ impl NodeOperationsComponent {
             Span::styled(" [d] Drain only", drain_style),
         ]));
 
+        // Upgrade operation (reboots the node, so it shares reboot safety)
+        let upgrade_style = if self.selected_op == 2 {
+            Style::default()
+                .fg(reboot_color)
+                .add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().fg(reboot_color)
+        };
+        lines.push(Line::from(vec![
+            Span::raw("    "),
+            Span::styled(reboot_ind, Style::default().fg(reboot_color)),
+            Span::styled(" [u] Upgrade Talos", upgrade_style),
+        ]));
+
         // Footer
         lines.push(Line::from(""));
         lines.push(Line::from(vec![Span::styled(
@@ -1213,6 +1579,19 @@ impl NodeOperationsComponent {
 }
 
 impl Component for NodeOperationsComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Navigate"),
+            ("Enter", "Run selected operation"),
+            ("r", "Reboot (blocked when unsafe)"),
+            ("d", "Drain"),
+            ("u", "Upgrade Talos (blocked when unsafe)"),
+            ("y/n", "Confirm/cancel operation"),
+            ("s", "Stage upgrade for next reboot"),
+            ("q/Esc", "Close"),
+        ])
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         match &self.operation_state {
             OperationState::Ready => {
@@ -1225,17 +1604,14 @@ impl Component for NodeOperationsComponent {
                         Ok(None)
                     }
                     KeyCode::Down | KeyCode::Char('j') => {
-                        if self.selected_op < 1 {
+                        if self.selected_op < 2 {
                             self.selected_op += 1;
                         }
                         Ok(None)
                     }
                     KeyCode::Enter => {
                         // Trigger the selected operation
-                        let reboot_unsafe = self
-                            .data()
-                            .map(|d| matches!(d.reboot_safety, SafetyStatus::Unsafe(_)))
-                            .unwrap_or(true);
+                        let reboot_unsafe = self.reboot_unsafe();
                         match self.selected_op {
                             0 => {
                                 // Reboot
@@ -1254,16 +1630,21 @@ impl Component for NodeOperationsComponent {
                                     OperationState::Confirming(OperationType::Drain);
                                 Ok(None)
                             }
+                            2 => {
+                                // Upgrade
+                                if reboot_unsafe {
+                                    tracing::warn!("Upgrade blocked due to unsafe status");
+                                } else {
+                                    self.operation_state = OperationState::SelectingImage;
+                                }
+                                Ok(None)
+                            }
                             _ => Ok(None),
                         }
                     }
                     KeyCode::Char('r') => {
                         // Check safety before allowing
-                        let reboot_unsafe = self
-                            .data()
-                            .map(|d| matches!(d.reboot_safety, SafetyStatus::Unsafe(_)))
-                            .unwrap_or(true);
-                        if reboot_unsafe {
+                        if self.reboot_unsafe() {
                             // Don't allow unsafe operations without explicit override
                             tracing::warn!("Reboot blocked due to unsafe status");
                             Ok(None)
@@ -1277,17 +1658,54 @@ impl Component for NodeOperationsComponent {
                         self.operation_state = OperationState::Confirming(OperationType::Drain);
                         Ok(None)
                     }
+                    KeyCode::Char('u') => {
+                        if self.reboot_unsafe() {
+                            tracing::warn!("Upgrade blocked due to unsafe status");
+                        } else {
+                            self.operation_state = OperationState::SelectingImage;
+                        }
+                        Ok(None)
+                    }
                     _ => Ok(None),
                 }
             }
+            OperationState::SelectingImage => {
+                match key.code {
+                    KeyCode::Enter if !self.upgrade_image.trim().is_empty() => {
+                        self.operation_state = OperationState::Confirming(OperationType::Upgrade);
+                    }
+                    KeyCode::Esc => {
+                        self.operation_state = OperationState::Ready;
+                    }
+                    KeyCode::Backspace => {
+                        self.upgrade_image.pop();
+                    }
+                    KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.upgrade_image.clear();
+                    }
+                    KeyCode::Char(c) => {
+                        self.upgrade_image.push(c);
+                    }
+                    _ => {}
+                }
+                Ok(None)
+            }
             OperationState::Confirming(op_type) => {
                 match key.code {
                     KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
                         // User confirmed, start the operation in background
                         let op = *op_type;
+                        self.upgrade_stage = false;
                         self.start_operation(op);
                         Ok(None)
                     }
+                    KeyCode::Char('s') | KeyCode::Char('S')
+                        if *op_type == OperationType::Upgrade =>
+                    {
+                        self.upgrade_stage = true;
+                        self.start_operation(OperationType::Upgrade);
+                        Ok(None)
+                    }
                     KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc | KeyCode::Char('q') => {
                         self.operation_state = OperationState::Ready;
                         Ok(None)
@@ -1344,4 +1762,71 @@ impl Component for NodeOperationsComponent {
         self.draw_overlay(frame, area);
         Ok(())
     }
+
+    fn is_busy(&self) -> bool {
+        matches!(self.operation_state, OperationState::Executing(_, _))
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.operation_state == OperationState::SelectingImage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use talos_rs::ServiceHealth;
+
+    #[test]
+    fn test_suggested_installer_image_bumps_patch() {
+        assert_eq!(
+            suggested_installer_image("v1.9.2"),
+            "ghcr.io/siderolabs/installer:v1.9.3"
+        );
+        assert_eq!(
+            suggested_installer_image("v1.10.0-beta.1"),
+            "ghcr.io/siderolabs/installer:v1.10.0-beta.1"
+        );
+    }
+
+    #[test]
+    fn test_image_tag() {
+        assert_eq!(
+            image_tag("ghcr.io/siderolabs/installer:v1.9.3"),
+            Some("v1.9.3")
+        );
+        assert_eq!(image_tag("registry:5000/installer:v1.9.3"), Some("v1.9.3"));
+        assert_eq!(image_tag("registry:5000/installer"), None);
+        assert_eq!(image_tag("installer:v1.9.3@sha256:abc"), Some("v1.9.3"));
+    }
+
+    #[test]
+    fn test_upgrade_outcome_detects_rollback() {
+        let image = "ghcr.io/siderolabs/installer:v1.9.3";
+        assert!(upgrade_outcome(image, "v1.9.3", Duration::from_secs(200)).success);
+        assert!(!upgrade_outcome(image, "v1.9.2", Duration::from_secs(200)).success);
+        assert!(upgrade_outcome("mirror/installer", "v1.9.2", Duration::ZERO).success);
+    }
+
+    #[test]
+    fn test_services_healthy() {
+        let service = |id: &str, healthy: bool| ServiceInfo {
+            id: id.to_string(),
+            state: "Running".to_string(),
+            health: Some(ServiceHealth {
+                unknown: false,
+                healthy,
+                last_message: String::new(),
+            }),
+        };
+        assert!(!services_healthy(&[]));
+        assert!(services_healthy(&[
+            service("apid", true),
+            service("kubelet", true)
+        ]));
+        assert!(!services_healthy(&[
+            service("apid", true),
+            service("kubelet", false)
+        ]));
+    }
 }
@@ -0,0 +1,275 @@
+//! Node switcher overlay
+//!
+//! Ctrl+P from a node view lists the cluster's nodes, narrows them with a
+//! fuzzy filter as you type, and points the view at the chosen node.
+
+use crate::action::Action;
+use crate::components::{Component, help_entries};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Most nodes listed at once
+const MAX_VISIBLE: usize = 12;
+
+/// A node offered by the switcher
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwitcherNode {
+    pub hostname: String,
+    pub address: String,
+    /// "controlplane" or "worker"
+    pub role: String,
+}
+
+/// Whether every character of `query` appears in `text` in order
+///
+/// Case-insensitive. Returns a score where lower is better: an exact
+/// substring match scores by its position, and scattered matches rank after
+/// every substring match.
+fn fuzzy_score(query: &str, text: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    let text = text.to_lowercase();
+    if let Some(pos) = text.find(&query) {
+        return Some(pos);
+    }
+
+    let mut chars = text.chars();
+    query
+        .chars()
+        .all(|q| chars.any(|c| c == q))
+        .then_some(usize::MAX)
+}
+
+/// Fuzzy node picker shown over a node view
+pub struct NodeSwitcherComponent {
+    /// Nodes of the active cluster, in overview order
+    nodes: Vec<SwitcherNode>,
+    /// Hostname of the node the view currently shows
+    current: String,
+    /// Filter typed so far
+    query: String,
+    /// Selected index into the filtered list
+    selected: usize,
+}
+
+impl NodeSwitcherComponent {
+    pub fn new(nodes: Vec<SwitcherNode>, current: String) -> Self {
+        Self {
+            nodes,
+            current,
+            query: String::new(),
+            selected: 0,
+        }
+    }
+
+    /// Nodes matching the filter, best match first
+    fn matches(&self) -> Vec<&SwitcherNode> {
+        let mut scored: Vec<(usize, &SwitcherNode)> = self
+            .nodes
+            .iter()
+            .filter_map(|node| {
+                let score = [&node.hostname, &node.address]
+                    .into_iter()
+                    .filter_map(|text| fuzzy_score(&self.query, text))
+                    .min()?;
+                Some((score, node))
+            })
+            .collect();
+        // Stable, so equal scores keep overview order
+        scored.sort_by_key(|(score, _)| *score);
+        scored.into_iter().map(|(_, node)| node).collect()
+    }
+
+    fn select_prev(&mut self) {
+        let count = self.matches().len();
+        if count > 0 {
+            self.selected = (self.selected + count - 1) % count;
+        }
+    }
+
+    fn select_next(&mut self) {
+        let count = self.matches().len();
+        if count > 0 {
+            self.selected = (self.selected + 1) % count;
+        }
+    }
+}
+
+impl Component for NodeSwitcherComponent {
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => return Ok(Some(Action::Back)),
+            KeyCode::Enter => {
+                let target = self
+                    .matches()
+                    .get(self.selected)
+                    .map(|node| Action::RetargetNode(node.hostname.clone(), node.address.clone()));
+                // Enter on an empty list just closes the switcher
+                return Ok(Some(target.unwrap_or(Action::Back)));
+            }
+            KeyCode::Up => self.select_prev(),
+            KeyCode::Down => self.select_next(),
+            KeyCode::Char('p') if ctrl => self.select_prev(),
+            KeyCode::Char('n') if ctrl => self.select_next(),
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.selected = 0;
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.query.push(c);
+                self.selected = 0;
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, _action: Action) -> Result<Option<Action>> {
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        let matches = self.matches();
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(" > ", Style::default().fg(Color::Cyan)),
+                Span::raw(self.query.as_str()),
+                Span::styled("_", Style::default().fg(Color::DarkGray)),
+            ]),
+            Line::from(""),
+        ];
+
+        // Scroll so the selection stays in view
+        let offset = (self.selected + 1).saturating_sub(MAX_VISIBLE);
+        for (i, node) in matches.iter().enumerate().skip(offset).take(MAX_VISIBLE) {
+            let mut style = Style::default();
+            if i == self.selected {
+                style = style.bg(Color::DarkGray).add_modifier(Modifier::BOLD);
+            }
+            let marker = if node.hostname == self.current {
+                "*"
+            } else {
+                " "
+            };
+            let role_color = if node.role == "controlplane" {
+                Color::Cyan
+            } else {
+                Color::Green
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {} ", marker), style.fg(Color::Yellow)),
+                Span::styled(format!("{:<24}", node.hostname), style),
+                Span::styled(format!("{:<18}", node.address), style.fg(Color::Gray)),
+                Span::styled(format!("{:<12}", node.role), style.fg(role_color)),
+            ]));
+        }
+        if matches.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "   No matching nodes",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            " Enter switch  ↑/↓ select  Esc cancel",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        let width = 62.min(area.width);
+        let height = (lines.len() as u16 + 2).min(area.height);
+        let popup = Rect::new(
+            area.x + area.width.saturating_sub(width) / 2,
+            area.y + area.height.saturating_sub(height) / 3,
+            width,
+            height,
+        );
+
+        frame.render_widget(Clear, popup);
+        let block = Block::default()
+            .title(format!(
+                " Switch node ({}/{}) ",
+                matches.len(),
+                self.nodes.len()
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan));
+        frame.render_widget(Paragraph::new(lines).block(block), popup);
+        Ok(())
+    }
+
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("type", "Filter nodes"),
+            ("↑/↓ Ctrl+P/N", "Select"),
+            ("Enter", "Switch to node"),
+            ("Esc", "Cancel"),
+        ])
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(hostname: &str, address: &str) -> SwitcherNode {
+        SwitcherNode {
+            hostname: hostname.to_string(),
+            address: address.to_string(),
+            role: "worker".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_filter_ranks_substring_first() {
+        let mut switcher = NodeSwitcherComponent::new(
+            vec![
+                node("worker-a1", "10.0.0.21"),
+                node("cp-1", "10.0.0.11"),
+                node("worker-1", "10.0.0.20"),
+            ],
+            "cp-1".to_string(),
+        );
+        for c in "wk1".chars() {
+            switcher
+                .handle_key_event(KeyEvent::from(KeyCode::Char(c)))
+                .unwrap();
+        }
+        let hostnames: Vec<_> = switcher
+            .matches()
+            .iter()
+            .map(|n| n.hostname.as_str())
+            .collect();
+        assert_eq!(hostnames, ["worker-a1", "worker-1"]);
+
+        switcher.query = "r-1".to_string();
+        let hostnames: Vec<_> = switcher
+            .matches()
+            .iter()
+            .map(|n| n.hostname.as_str())
+            .collect();
+        assert_eq!(hostnames, ["worker-1", "worker-a1"]);
+
+        // Addresses match too
+        switcher.query = "0.11".to_string();
+        switcher.selected = 0;
+        let action = switcher
+            .handle_key_event(KeyEvent::from(KeyCode::Enter))
+            .unwrap();
+        assert!(matches!(
+            action,
+            Some(Action::RetargetNode(h, a)) if h == "cp-1" && a == "10.0.0.11"
+        ));
+    }
+}
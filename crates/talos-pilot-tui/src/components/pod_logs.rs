@@ -0,0 +1,715 @@
+//! Pod logs component
+//!
+//! Follows the log of a single Kubernetes pod container, picked by
+//! namespace, pod and container. Unlike the Talos service logs view this
+//! goes through the Kubernetes API, so it reaches CNI, CoreDNS and
+//! application pods.
+
+use crate::action::Action;
+use crate::components::diagnostics::k8s::{
+    K8sError, PodContainers, fetch_pod_logs, list_pod_containers, stream_pod_logs,
+};
+use crate::components::{Component, help_entries};
+use crate::ui_ext::{empty_state, manual_refresh};
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyEvent};
+use kube::Client;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+};
+use std::collections::VecDeque;
+use std::time::Duration;
+use talos_pilot_core::{AsyncState, ComponentError};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// Maximum log lines kept in memory
+const MAX_LOG_LINES: usize = 5000;
+
+/// Timeout for listing pods
+const LIST_TIMEOUT_SECS: u64 = 15;
+
+/// Which picker (or the log itself) is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Namespace,
+    Pod,
+    Container,
+    Logs,
+}
+
+/// Kubernetes pod logs component
+pub struct PodLogsComponent {
+    /// Pods and their containers, loaded on refresh
+    state: AsyncState<Vec<PodContainers>>,
+
+    /// Kubernetes client
+    k8s_client: Option<Client>,
+    /// Lines of history to request when starting a log
+    tail_lines: i64,
+
+    /// Current step
+    step: Step,
+    /// Chosen namespace
+    namespace: Option<String>,
+    /// Chosen pod
+    pod: Option<String>,
+    /// Chosen container
+    container: Option<String>,
+    /// Selection in the current picker
+    list_state: ListState,
+
+    /// Received log lines (oldest first)
+    lines: VecDeque<String>,
+    /// First visible line when not following
+    scroll: usize,
+    /// Keep the newest line in view
+    follow: bool,
+    /// Showing the previous (terminated) container instance
+    previous: bool,
+    /// Lines from the running log task
+    stream_rx: Option<mpsc::UnboundedReceiver<String>>,
+    /// Task fetching or following the log
+    stream_task: Option<JoinHandle<Result<(), K8sError>>>,
+    /// Why the log stopped, once it has
+    stream_status: Option<String>,
+    /// Log area height from the last draw, for paging
+    visible_height: usize,
+}
+
+impl PodLogsComponent {
+    /// Create the component, skipping the pickers for what is already known
+    pub fn new(tail_lines: i32, namespace: Option<String>, pod: Option<String>) -> Self {
+        let step = match (&namespace, &pod) {
+            (Some(_), Some(_)) => Step::Container,
+            (Some(_), None) => Step::Pod,
+            _ => Step::Namespace,
+        };
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        Self {
+            state: AsyncState::new(),
+            k8s_client: None,
+            tail_lines: i64::from(tail_lines),
+            step,
+            namespace,
+            pod,
+            container: None,
+            list_state,
+            lines: VecDeque::new(),
+            scroll: 0,
+            follow: true,
+            previous: false,
+            stream_rx: None,
+            stream_task: None,
+            stream_status: None,
+            visible_height: 0,
+        }
+    }
+
+    /// Set the Kubernetes client
+    pub fn set_k8s_client(&mut self, client: Client) {
+        self.k8s_client = Some(client);
+    }
+
+    /// Set an error message
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
+        self.state.set_error(error);
+    }
+
+    /// Reload the pod list from the cluster
+    pub async fn refresh(&mut self) -> Result<()> {
+        let Some(client) = &self.k8s_client else {
+            self.set_error("No Kubernetes client configured".to_string());
+            return Ok(());
+        };
+
+        self.state.start_loading();
+        let timeout = Duration::from_secs(LIST_TIMEOUT_SECS);
+        match tokio::time::timeout(timeout, list_pod_containers(client)).await {
+            Ok(Ok(pods)) => self.state.set_data(pods),
+            Ok(Err(e)) => self.set_error(format!("Failed to list pods: {}", e)),
+            Err(_) => self.set_error(format!("Request timed out after {}s", timeout.as_secs())),
+        }
+
+        // A pod chosen up front with a single container goes straight to its log
+        if self.step == Step::Container && self.lines.is_empty() {
+            self.enter_container_step();
+        }
+        Ok(())
+    }
+
+    /// Namespaces that have at least one pod
+    fn namespaces(&self) -> Vec<&str> {
+        let mut namespaces: Vec<&str> = self
+            .state
+            .data()
+            .map(|pods| pods.iter().map(|p| p.namespace.as_str()).collect())
+            .unwrap_or_default();
+        namespaces.dedup();
+        namespaces
+    }
+
+    /// Pods in the chosen namespace
+    fn pods(&self) -> Vec<&PodContainers> {
+        let Some(namespace) = &self.namespace else {
+            return Vec::new();
+        };
+        self.state
+            .data()
+            .map(|pods| pods.iter().filter(|p| &p.namespace == namespace).collect())
+            .unwrap_or_default()
+    }
+
+    /// Containers of the chosen pod
+    fn containers(&self) -> Vec<String> {
+        self.pods()
+            .into_iter()
+            .find(|p| Some(&p.name) == self.pod.as_ref())
+            .map(|p| p.containers.clone())
+            .unwrap_or_default()
+    }
+
+    /// Number of entries in the current picker
+    fn item_count(&self) -> usize {
+        match self.step {
+            Step::Namespace => self.namespaces().len(),
+            Step::Pod => self.pods().len(),
+            Step::Container => self.containers().len(),
+            Step::Logs => 0,
+        }
+    }
+
+    /// Switch step, preselecting `current` in the new picker if present
+    fn set_step(&mut self, step: Step, current: Option<String>) {
+        self.step = step;
+        let index = current
+            .and_then(|current| match step {
+                Step::Namespace => self.namespaces().iter().position(|n| *n == current),
+                Step::Pod => self.pods().iter().position(|p| p.name == current),
+                Step::Container => self.containers().iter().position(|c| *c == current),
+                Step::Logs => None,
+            })
+            .unwrap_or(0);
+        self.list_state.select(Some(index));
+    }
+
+    /// Show the container picker, or the log when there is only one container
+    fn enter_container_step(&mut self) {
+        let containers = self.containers();
+        if let [only] = containers.as_slice() {
+            self.container = Some(only.clone());
+            self.start_log(false);
+        } else {
+            self.set_step(Step::Container, self.container.clone());
+        }
+    }
+
+    /// Accept the highlighted picker entry
+    fn confirm(&mut self) {
+        let index = self.list_state.selected().unwrap_or(0);
+        match self.step {
+            Step::Namespace => {
+                let Some(namespace) = self.namespaces().get(index).map(|n| n.to_string()) else {
+                    return;
+                };
+                self.namespace = Some(namespace);
+                self.set_step(Step::Pod, self.pod.clone());
+            }
+            Step::Pod => {
+                let Some(pod) = self.pods().get(index).map(|p| p.name.clone()) else {
+                    return;
+                };
+                if self.pod.as_ref() != Some(&pod) {
+                    self.container = None;
+                }
+                self.pod = Some(pod);
+                self.enter_container_step();
+            }
+            Step::Container => {
+                let Some(container) = self.containers().get(index).cloned() else {
+                    return;
+                };
+                self.container = Some(container);
+                self.start_log(false);
+            }
+            Step::Logs => {}
+        }
+    }
+
+    /// Go back one step; returns true when already at the first picker
+    fn step_back(&mut self) -> bool {
+        match self.step {
+            Step::Logs => {
+                self.stop_log();
+                if self.containers().len() > 1 {
+                    self.set_step(Step::Container, self.container.clone());
+                } else {
+                    self.set_step(Step::Pod, self.pod.clone());
+                }
+            }
+            Step::Container => self.set_step(Step::Pod, self.pod.clone()),
+            Step::Pod => self.set_step(Step::Namespace, self.namespace.clone()),
+            Step::Namespace => return true,
+        }
+        false
+    }
+
+    /// Start following the chosen container, or fetch its previous instance
+    fn start_log(&mut self, previous: bool) {
+        let (Some(client), Some(namespace), Some(pod)) = (
+            self.k8s_client.clone(),
+            self.namespace.clone(),
+            self.pod.clone(),
+        ) else {
+            return;
+        };
+        let container = self.container.clone().unwrap_or_default();
+        let tail_lines = self.tail_lines;
+
+        self.stop_log();
+        self.step = Step::Logs;
+        self.previous = previous;
+        self.lines.clear();
+        self.scroll = 0;
+        self.follow = true;
+        self.stream_status = None;
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.stream_rx = Some(rx);
+        self.stream_task = Some(tokio::spawn(async move {
+            if previous {
+                let logs =
+                    fetch_pod_logs(&client, &namespace, &pod, &container, tail_lines, true).await?;
+                for line in logs.lines() {
+                    let _ = tx.send(line.to_string());
+                }
+                Ok(())
+            } else {
+                stream_pod_logs(&client, &namespace, &pod, &container, tail_lines, tx).await
+            }
+        }));
+    }
+
+    /// Stop the log task
+    fn stop_log(&mut self) {
+        if let Some(task) = self.stream_task.take() {
+            task.abort();
+        }
+        self.stream_rx = None;
+    }
+
+    /// Move received lines into the buffer and note when the task has ended
+    fn drain_log(&mut self) {
+        if let Some(rx) = &mut self.stream_rx {
+            while let Ok(line) = rx.try_recv() {
+                self.lines.push_back(line);
+            }
+            let excess = self.lines.len().saturating_sub(MAX_LOG_LINES);
+            if excess > 0 {
+                self.lines.drain(..excess);
+                self.scroll = self.scroll.saturating_sub(excess);
+            }
+        }
+
+        let finished = self
+            .stream_task
+            .as_ref()
+            .is_some_and(|task| task.is_finished());
+        if finished {
+            let task = self.stream_task.take().unwrap();
+            // Won't block since is_finished() was true
+            self.stream_status = Some(match futures::executor::block_on(task) {
+                Ok(Ok(())) if self.previous => "Previous instance loaded".to_string(),
+                Ok(Ok(())) => "Log stream ended".to_string(),
+                Ok(Err(e)) => format!("Error: {}", e),
+                Err(e) => format!("Task error: {}", e),
+            });
+        }
+    }
+
+    /// Highest scroll offset that still fills the view
+    fn max_scroll(&self) -> usize {
+        self.lines.len().saturating_sub(self.visible_height.max(1))
+    }
+
+    fn scroll_up(&mut self, amount: usize) {
+        if self.follow {
+            self.scroll = self.max_scroll();
+            self.follow = false;
+        }
+        self.scroll = self.scroll.saturating_sub(amount);
+    }
+
+    fn scroll_down(&mut self, amount: usize) {
+        if self.follow {
+            return;
+        }
+        self.scroll = (self.scroll + amount).min(self.max_scroll());
+        if self.scroll == self.max_scroll() {
+            self.follow = true;
+        }
+    }
+
+    /// "namespace / pod / container" for what has been chosen so far
+    fn breadcrumb(&self) -> String {
+        [&self.namespace, &self.pod, &self.container]
+            .into_iter()
+            .take(match self.step {
+                Step::Namespace => 0,
+                Step::Pod => 1,
+                Step::Container => 2,
+                Step::Logs => 3,
+            })
+            .flatten()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+
+    /// Draw the picker for the current step
+    fn draw_picker(&mut self, frame: &mut Frame, area: Rect) {
+        let (title, items): (&str, Vec<ListItem>) = match self.step {
+            Step::Namespace => (
+                " Namespace ",
+                self.namespaces()
+                    .into_iter()
+                    .map(|n| ListItem::new(n.to_string()))
+                    .collect(),
+            ),
+            Step::Pod => (
+                " Pod ",
+                self.pods()
+                    .into_iter()
+                    .map(|p| {
+                        let phase_color = match p.phase.as_str() {
+                            "Running" | "Succeeded" => Color::Green,
+                            "Pending" => Color::Yellow,
+                            _ => Color::Red,
+                        };
+                        ListItem::new(Line::from(vec![
+                            Span::raw(format!("{:<50} ", p.name)),
+                            Span::styled(p.phase.clone(), Style::default().fg(phase_color)),
+                        ]))
+                    })
+                    .collect(),
+            ),
+            Step::Container => (
+                " Container ",
+                self.containers().into_iter().map(ListItem::new).collect(),
+            ),
+            Step::Logs => return,
+        };
+
+        if items.is_empty() {
+            let message = match self.step {
+                Step::Namespace => "No pods found in any namespace",
+                Step::Pod => "No pods found in this namespace",
+                _ => "No containers found in this pod",
+            };
+            let empty = empty_state(message, None)
+                .block(Block::default().title(title).borders(Borders::ALL));
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        let list = List::new(items)
+            .block(Block::default().title(title).borders(Borders::ALL))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, area, &mut self.list_state);
+    }
+
+    /// Draw the log lines
+    fn draw_log(&mut self, frame: &mut Frame, area: Rect) {
+        let title = if self.previous {
+            " Log (previous instance) "
+        } else {
+            " Log "
+        };
+        let block = Block::default().title(title).borders(Borders::ALL);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        self.visible_height = inner.height as usize;
+        if self.follow {
+            self.scroll = self.max_scroll();
+        }
+
+        if self.lines.is_empty() {
+            let text = match &self.stream_status {
+                Some(status) => status.clone(),
+                None => "Waiting for log lines...".to_string(),
+            };
+            let para = Paragraph::new(text).style(Style::default().fg(Color::DarkGray));
+            frame.render_widget(para, inner);
+            return;
+        }
+
+        let lines: Vec<Line> = self
+            .lines
+            .iter()
+            .skip(self.scroll)
+            .take(self.visible_height)
+            .map(|l| Line::raw(l.as_str()))
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// Draw the footer with key hints
+    fn draw_footer(&self, frame: &mut Frame, area: Rect) {
+        let key = |k: &'static str| Span::styled(k, Style::default().add_modifier(Modifier::BOLD));
+        let mut spans = if self.step == Step::Logs {
+            let follow = if self.follow {
+                Span::styled("ON ", Style::default().fg(Color::Green))
+            } else {
+                Span::styled("OFF", Style::default().fg(Color::DarkGray))
+            };
+            vec![
+                key("[j/k]"),
+                Span::raw(" scroll  "),
+                key("[G]"),
+                Span::raw(" follow:"),
+                follow,
+                Span::raw("  "),
+                key("[p]"),
+                Span::raw(if self.previous {
+                    " current  "
+                } else {
+                    " previous  "
+                }),
+                key("[r]"),
+                Span::raw(" restart  "),
+            ]
+        } else {
+            vec![
+                key("[j/k]"),
+                Span::raw(" select  "),
+                key("[Enter]"),
+                Span::raw(" choose  "),
+                key("[r]"),
+                Span::raw(" refresh  "),
+            ]
+        };
+        spans.extend([key("[q]"), Span::raw(" back")]);
+        if let Some(status) = self
+            .stream_status
+            .as_ref()
+            .filter(|_| !self.lines.is_empty())
+        {
+            spans.push(Span::styled(
+                format!("  {}", status),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+}
+
+impl Drop for PodLogsComponent {
+    fn drop(&mut self) {
+        self.stop_log();
+    }
+}
+
+impl Component for PodLogsComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        if self.step == Step::Logs {
+            help_entries(&[
+                ("j/k ↑/↓", "Scroll"),
+                ("PgUp/PgDn", "Page"),
+                ("g/G", "Top / follow"),
+                ("p", "Toggle previous container instance"),
+                ("r", "Restart log"),
+                ("q/Esc", "Back to picker"),
+            ])
+        } else {
+            help_entries(&[
+                ("j/k ↑/↓", "Navigate"),
+                ("Enter", "Choose"),
+                ("r", "Reload pods"),
+                ("q/Esc", "Back"),
+            ])
+        }
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => Ok(self.step_back().then_some(Action::Back)),
+            _ if self.step == Step::Logs => {
+                let page = self.visible_height.max(1);
+                match key.code {
+                    KeyCode::Char('j') | KeyCode::Down => self.scroll_down(1),
+                    KeyCode::Char('k') | KeyCode::Up => self.scroll_up(1),
+                    KeyCode::PageDown => self.scroll_down(page),
+                    KeyCode::PageUp => self.scroll_up(page),
+                    KeyCode::Char('g') | KeyCode::Home => {
+                        self.follow = false;
+                        self.scroll = 0;
+                    }
+                    KeyCode::Char('G') | KeyCode::End => self.follow = true,
+                    KeyCode::Char('p') => self.start_log(!self.previous),
+                    KeyCode::Char('r') => self.start_log(self.previous),
+                    _ => {}
+                }
+                Ok(None)
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                let count = self.item_count();
+                if count > 0 {
+                    let next = self
+                        .list_state
+                        .selected()
+                        .map_or(0, |i| (i + 1).min(count - 1));
+                    self.list_state.select(Some(next));
+                }
+                Ok(None)
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                let prev = self
+                    .list_state
+                    .selected()
+                    .map_or(0, |i| i.saturating_sub(1));
+                self.list_state.select(Some(prev));
+                Ok(None)
+            }
+            KeyCode::Enter => {
+                self.confirm();
+                Ok(None)
+            }
+            KeyCode::Char('r') => Ok(manual_refresh(&self.state)),
+            _ => Ok(None),
+        }
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        if let Action::Tick = action {
+            self.drain_log();
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        let breadcrumb = self.breadcrumb();
+        let title = if breadcrumb.is_empty() {
+            " Pod Logs ".to_string()
+        } else {
+            format!(" Pod Logs: {} ", breadcrumb)
+        };
+        let block = Block::default().title(title).borders(Borders::ALL);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::vertical([
+            Constraint::Fill(1),   // Picker or log
+            Constraint::Length(1), // Footer
+        ])
+        .split(inner);
+
+        self.draw_footer(frame, chunks[1]);
+
+        if self.step == Step::Logs {
+            self.draw_log(frame, chunks[0]);
+            return Ok(());
+        }
+
+        if self.state.is_loading() && !self.state.has_data() {
+            let loading =
+                Paragraph::new("Loading pods...").style(Style::default().fg(Color::DarkGray));
+            frame.render_widget(loading, chunks[0]);
+            return Ok(());
+        }
+
+        if let Some(err) = self.state.error() {
+            let error = Paragraph::new(format!("Error: {}", err))
+                .style(Style::default().fg(Color::Red))
+                .wrap(Wrap { trim: true });
+            frame.render_widget(error, chunks[0]);
+            return Ok(());
+        }
+
+        self.draw_picker(frame, chunks[0]);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pod(namespace: &str, name: &str, containers: &[&str]) -> PodContainers {
+        PodContainers {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            phase: "Running".to_string(),
+            containers: containers.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    fn component(namespace: Option<&str>, pod_name: Option<&str>) -> PodLogsComponent {
+        let mut c = PodLogsComponent::new(
+            100,
+            namespace.map(str::to_string),
+            pod_name.map(str::to_string),
+        );
+        c.state.set_data(vec![
+            pod("default", "web-1", &["app"]),
+            pod(
+                "kube-system",
+                "cilium-abc",
+                &["install-cni", "cilium-agent"],
+            ),
+            pod("kube-system", "coredns-xyz", &["coredns"]),
+        ]);
+        c
+    }
+
+    #[test]
+    fn test_initial_step_skips_known_choices() {
+        assert_eq!(component(None, None).step, Step::Namespace);
+        assert_eq!(component(Some("kube-system"), None).step, Step::Pod);
+        assert_eq!(
+            component(Some("kube-system"), Some("cilium-abc")).step,
+            Step::Container
+        );
+    }
+
+    #[test]
+    fn test_pickers_follow_choices() {
+        let mut c = component(None, None);
+        assert_eq!(c.namespaces(), vec!["default", "kube-system"]);
+
+        c.list_state.select(Some(1));
+        c.confirm();
+        assert_eq!(c.step, Step::Pod);
+        assert_eq!(c.namespace.as_deref(), Some("kube-system"));
+        assert_eq!(c.item_count(), 2);
+
+        c.list_state.select(Some(0));
+        c.confirm();
+        assert_eq!(c.step, Step::Container);
+        assert_eq!(c.containers(), vec!["install-cni", "cilium-agent"]);
+        assert_eq!(c.breadcrumb(), "kube-system / cilium-abc");
+    }
+
+    #[test]
+    fn test_step_back_reselects_previous_choice() {
+        let mut c = component(Some("kube-system"), Some("coredns-xyz"));
+        assert!(!c.step_back());
+        assert_eq!(c.step, Step::Pod);
+        assert_eq!(c.list_state.selected(), Some(1));
+        assert!(!c.step_back());
+        assert_eq!(c.step, Step::Namespace);
+        assert_eq!(c.list_state.selected(), Some(1));
+        assert!(c.step_back());
+    }
+}
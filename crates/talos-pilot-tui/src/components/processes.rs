@@ -3,7 +3,8 @@
 //! "Show me what's wrong in 5 seconds"
 
 use crate::action::Action;
-use crate::components::Component;
+use crate::components::{Component, help_entries};
+use crate::ui_ext::{NodeInfo, empty_state, glyphs, manual_refresh, node_info_spans};
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -15,7 +16,7 @@ use ratatui::{
 };
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
-use talos_pilot_core::{AsyncState, format_bytes_compact};
+use talos_pilot_core::{AsyncState, ComponentError, format_bytes_compact};
 use talos_rs::{CpuStat, ProcessInfo, ProcessState, TalosClient};
 
 /// Auto-refresh interval in seconds
@@ -140,6 +141,9 @@ pub struct ProcessesComponent {
     /// Auto-refresh enabled
     auto_refresh: bool,
 
+    /// Node summary shown in the header
+    node_info: NodeInfo,
+
     /// Client for API calls
     client: Option<TalosClient>,
 }
@@ -152,6 +156,7 @@ impl Default for ProcessesComponent {
 
 impl ProcessesComponent {
     pub fn new(hostname: String, address: String) -> Self {
+        let node_info = NodeInfo::new(hostname.clone(), address.clone());
         let mut table_state = TableState::default();
         table_state.select(Some(0));
 
@@ -176,17 +181,33 @@ impl ProcessesComponent {
             filter_input: String::new(),
             filter: None,
             auto_refresh: true,
+            node_info,
             client: None,
         }
     }
 
+    /// Set the node summary shown in the header
+    pub fn set_node_info(&mut self, info: NodeInfo) {
+        self.node_info = info;
+    }
+
+    /// Node this view shows
+    pub fn node_info(&self) -> &NodeInfo {
+        &self.node_info
+    }
+
     /// Set the client for API calls
     pub fn set_client(&mut self, client: TalosClient) {
         self.client = Some(client);
     }
 
+    /// Client targeting this node
+    pub fn client(&self) -> Option<&TalosClient> {
+        self.client.as_ref()
+    }
+
     /// Set error message
-    pub fn set_error(&mut self, error: String) {
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
         self.state.set_error(error);
     }
 
@@ -847,13 +868,11 @@ impl ProcessesComponent {
             String::new()
         };
 
-        let mut spans = vec![
-            Span::styled("Processes: ", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(&data.hostname),
-            Span::styled(" (", Style::default().fg(Color::DarkGray)),
-            Span::raw(&data.address),
-            Span::styled(")", Style::default().fg(Color::DarkGray)),
-        ];
+        let mut spans = vec![Span::styled(
+            "Processes: ",
+            Style::default().add_modifier(Modifier::BOLD),
+        )];
+        spans.extend(node_info_spans(&self.node_info));
 
         // Add system info
         if !cpu_info.is_empty() || !mem_info.is_empty() {
@@ -1078,7 +1097,8 @@ impl ProcessesComponent {
             return false;
         }
 
-        let warning_text = format!("⚠ {}", warnings.join(" • "));
+        let separator = format!(" {} ", glyphs().bullet);
+        let warning_text = format!("{} {}", glyphs().warning, warnings.join(&*separator));
         let para = Paragraph::new(warning_text).style(Style::default().fg(Color::Yellow));
         frame.render_widget(para, area);
         true
@@ -1200,6 +1220,12 @@ impl ProcessesComponent {
             Some(data) => data,
             None => return,
         };
+        if row_data.is_empty() {
+            let total = self.data().map(|d| d.processes.len()).unwrap_or(0);
+            let reason = (total > 0).then(|| format!("{} hidden by the current filters", total));
+            frame.render_widget(empty_state("No processes found", reason.as_deref()), area);
+            return;
+        }
 
         let rows: Vec<Row> = row_data
             .into_iter()
@@ -1429,6 +1455,28 @@ impl ProcessesComponent {
 }
 
 impl Component for ProcessesComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Navigate"),
+            ("g/G", "Jump to top/bottom"),
+            ("1", "Sort by CPU (toggle % / time)"),
+            ("2", "Sort by memory"),
+            ("/", "Filter by name"),
+            ("t", "Subtree of selected process"),
+            ("T", "Toggle full process tree"),
+            ("z", "Show zombies only"),
+            ("d", "Show disk-wait only"),
+            ("y", "Copy command to clipboard"),
+            ("a", "Toggle auto-refresh"),
+            ("r", "Refresh"),
+            ("q/Esc", "Back"),
+        ])
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.mode == Mode::Filtering
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         match self.mode {
             Mode::Normal => self.handle_normal_key(key),
@@ -1547,7 +1595,7 @@ impl ProcessesComponent {
                 self.filter_input = self.filter.clone().unwrap_or_default();
                 Ok(None)
             }
-            KeyCode::Char('r') => Ok(Some(Action::Refresh)),
+            KeyCode::Char('r') => Ok(manual_refresh(&self.state)),
             KeyCode::Char('a') => {
                 self.auto_refresh = !self.auto_refresh;
                 Ok(None)
@@ -5,11 +5,11 @@
 
 use crate::action::Action;
 use crate::audit::{audit_failure, audit_start, audit_success};
-use crate::components::Component;
 use crate::components::diagnostics::k8s::{
     DrainOptions, DrainProgressCallback, NodeReadyProgressCallback, cordon_node,
     drain_node_with_progress, uncordon_node, wait_for_node_ready,
 };
+use crate::components::{Component, help_entries};
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use kube::Client;
@@ -24,6 +24,9 @@ use std::sync::{Arc, Mutex};
 use talos_rs::TalosClient;
 use tokio::task::JoinHandle;
 
+/// Seconds between etcd health polls after a control plane reboot
+const ETCD_POLL_INTERVAL_SECS: u64 = 5;
+
 /// Node information for rolling operations
 #[derive(Debug, Clone)]
 pub struct RollingNodeInfo {
@@ -85,11 +88,59 @@ pub struct RollingOperationResult {
     pub message: String,
 }
 
+/// Where a node is in a rolling operation
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum NodeStep {
+    #[default]
+    Pending,
+    Cordoning,
+    Draining,
+    Rebooting,
+    WaitingReady,
+    WaitingEtcd,
+    Done,
+    Failed,
+}
+
+impl NodeStep {
+    fn label(&self) -> &'static str {
+        match self {
+            NodeStep::Pending => "pending",
+            NodeStep::Cordoning => "cordoning",
+            NodeStep::Draining => "draining",
+            NodeStep::Rebooting => "rebooting",
+            NodeStep::WaitingReady => "waiting for Ready",
+            NodeStep::WaitingEtcd => "waiting for etcd",
+            NodeStep::Done => "done",
+            NodeStep::Failed => "failed",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            NodeStep::Pending => Color::DarkGray,
+            NodeStep::Done => Color::Green,
+            NodeStep::Failed => Color::Red,
+            _ => Color::Yellow,
+        }
+    }
+}
+
 /// Shared progress for rolling operations
 #[derive(Debug, Clone, Default)]
 pub struct RollingProgress {
     pub current_node_idx: usize,
     pub message: String,
+    /// Step of each node, in processing order
+    pub steps: Vec<NodeStep>,
+}
+
+impl RollingProgress {
+    fn set_step(&mut self, idx: usize, step: NodeStep) {
+        if let Some(s) = self.steps.get_mut(idx) {
+            *s = step;
+        }
+    }
 }
 
 /// Rolling operations component
@@ -110,6 +161,8 @@ pub struct RollingOperationsComponent {
     operation_task: Option<JoinHandle<RollingOperationResult>>,
     /// Shared progress state
     operation_progress: Arc<Mutex<RollingProgress>>,
+    /// Step of each selected node, copied from the shared progress
+    node_steps: Vec<NodeStep>,
     /// K8s client
     k8s_client: Option<Client>,
     /// Talos client
@@ -133,6 +186,7 @@ impl RollingOperationsComponent {
             stop_on_failure: true,
             operation_task: None,
             operation_progress: Arc::new(Mutex::new(RollingProgress::default())),
+            node_steps: Vec::new(),
             k8s_client: None,
             talos_client: None,
         }
@@ -165,6 +219,14 @@ impl RollingOperationsComponent {
         selected
     }
 
+    /// Number of selected control plane nodes
+    fn selected_controlplane_count(&self) -> usize {
+        self.nodes
+            .iter()
+            .filter(|n| n.selection_order.is_some() && n.is_controlplane)
+            .count()
+    }
+
     /// Get the count of selected nodes
     fn selected_count(&self) -> usize {
         self.nodes
@@ -220,6 +282,7 @@ impl RollingOperationsComponent {
             let mut progress = self.operation_progress.lock().unwrap();
             progress.current_node_idx = 0;
             progress.message = "Starting...".to_string();
+            progress.steps = vec![NodeStep::Pending; selected.len()];
         }
 
         let progress = self.operation_progress.clone();
@@ -244,6 +307,7 @@ impl RollingOperationsComponent {
         });
 
         self.operation_task = Some(task);
+        self.node_steps = vec![NodeStep::Pending; self.selected_count()];
         self.state = RollingState::InProgress {
             operation,
             current_node_idx: 0,
@@ -254,6 +318,7 @@ impl RollingOperationsComponent {
     /// Poll the background operation
     fn poll_operation(&mut self) {
         if let Some(task) = &mut self.operation_task {
+            self.node_steps = self.operation_progress.lock().unwrap().steps.clone();
             if task.is_finished() {
                 let task = self.operation_task.take().unwrap();
                 match futures::executor::block_on(task) {
@@ -336,6 +401,7 @@ async fn run_rolling_operation(
             let mut p = progress.lock().unwrap();
             p.current_node_idx = idx;
             p.message = format!("Processing {}/{}: {}", idx + 1, total, node.hostname);
+            p.set_step(idx, NodeStep::Cordoning);
         }
 
         // Step 1: Cordon
@@ -346,6 +412,7 @@ async fn run_rolling_operation(
 
         if let Err(e) = cordon_node(&k8s, &node.hostname).await {
             let msg = format!("Failed to cordon {}: {}", node.hostname, e);
+            progress.lock().unwrap().set_step(idx, NodeStep::Failed);
             audit_failure(op_name, &node.hostname, &msg);
             if stop_on_failure {
                 return RollingOperationResult {
@@ -362,6 +429,7 @@ async fn run_rolling_operation(
         {
             let mut p = progress.lock().unwrap();
             p.message = format!("Draining {} ({}/{})", node.hostname, idx + 1, total);
+            p.set_step(idx, NodeStep::Draining);
         }
 
         let progress_clone = progress.clone();
@@ -381,6 +449,7 @@ async fn run_rolling_operation(
                     "Drain failed for {}: {:?}",
                     node.hostname, result.failed_pods
                 );
+                progress.lock().unwrap().set_step(idx, NodeStep::Failed);
                 audit_failure(op_name, &node.hostname, &msg);
                 if stop_on_failure {
                     return RollingOperationResult {
@@ -395,6 +464,7 @@ async fn run_rolling_operation(
             Err(e) => {
                 let _ = uncordon_node(&k8s, &node.hostname).await;
                 let msg = format!("Drain error for {}: {}", node.hostname, e);
+                progress.lock().unwrap().set_step(idx, NodeStep::Failed);
                 audit_failure(op_name, &node.hostname, &msg);
                 if stop_on_failure {
                     return RollingOperationResult {
@@ -414,6 +484,7 @@ async fn run_rolling_operation(
             {
                 let mut p = progress.lock().unwrap();
                 p.message = format!("Rebooting {} ({}/{})", node.hostname, idx + 1, total);
+                p.set_step(idx, NodeStep::Rebooting);
             }
 
             if let Some(ref client) = talos_client {
@@ -421,6 +492,7 @@ async fn run_rolling_operation(
                 if let Err(e) = node_client.reboot(RebootMode::Default).await {
                     let _ = uncordon_node(&k8s, &node.hostname).await;
                     let msg = format!("Reboot failed for {}: {}", node.hostname, e);
+                    progress.lock().unwrap().set_step(idx, NodeStep::Failed);
                     audit_failure(op_name, &node.hostname, &msg);
                     if stop_on_failure {
                         return RollingOperationResult {
@@ -443,6 +515,7 @@ async fn run_rolling_operation(
                             idx + 1,
                             total
                         );
+                        p.set_step(idx, NodeStep::WaitingReady);
                     }
 
                     let progress_clone = progress.clone();
@@ -462,39 +535,70 @@ async fn run_rolling_operation(
                     )
                     .await;
 
+                    // A node that doesn't come back always stops the operation,
+                    // whatever stop_on_failure says
                     match ready_result {
                         Ok(result) if !result.success => {
                             let msg = format!(
                                 "Node {} didn't become Ready: {:?}",
                                 node.hostname, result.error
                             );
+                            progress.lock().unwrap().set_step(idx, NodeStep::Failed);
                             audit_failure(op_name, &node.hostname, &msg);
-                            if stop_on_failure {
-                                return RollingOperationResult {
-                                    success: false,
-                                    completed_nodes: completed,
-                                    failed_node: Some(node.hostname.clone()),
-                                    message: msg,
-                                };
-                            }
-                            continue;
+                            return RollingOperationResult {
+                                success: false,
+                                completed_nodes: completed,
+                                failed_node: Some(node.hostname.clone()),
+                                message: msg,
+                            };
                         }
                         Err(e) => {
                             let msg = format!("Error waiting for {}: {}", node.hostname, e);
+                            progress.lock().unwrap().set_step(idx, NodeStep::Failed);
                             audit_failure(op_name, &node.hostname, &msg);
-                            if stop_on_failure {
-                                return RollingOperationResult {
-                                    success: false,
-                                    completed_nodes: completed,
-                                    failed_node: Some(node.hostname.clone()),
-                                    message: msg,
-                                };
-                            }
-                            continue;
+                            return RollingOperationResult {
+                                success: false,
+                                completed_nodes: completed,
+                                failed_node: Some(node.hostname.clone()),
+                                message: msg,
+                            };
                         }
                         _ => {}
                     }
 
+                    // Kubelet Ready says nothing about etcd; don't take the next
+                    // control plane member down until this one has rejoined
+                    if node.is_controlplane {
+                        {
+                            let mut p = progress.lock().unwrap();
+                            p.message = format!(
+                                "Waiting for etcd on {} ({}/{})",
+                                node.hostname,
+                                idx + 1,
+                                total
+                            );
+                            p.set_step(idx, NodeStep::WaitingEtcd);
+                        }
+
+                        if let Err(e) = wait_for_etcd_healthy(
+                            client,
+                            &node.address,
+                            options.post_reboot_timeout_secs,
+                        )
+                        .await
+                        {
+                            let msg = format!("etcd on {} not healthy: {}", node.hostname, e);
+                            progress.lock().unwrap().set_step(idx, NodeStep::Failed);
+                            audit_failure(op_name, &node.hostname, &msg);
+                            return RollingOperationResult {
+                                success: false,
+                                completed_nodes: completed,
+                                failed_node: Some(node.hostname.clone()),
+                                message: msg,
+                            };
+                        }
+                    }
+
                     // Uncordon after successful reboot
                     if options.uncordon_after_reboot {
                         let _ = uncordon_node(&k8s, &node.hostname).await;
@@ -503,6 +607,7 @@ async fn run_rolling_operation(
             } else {
                 let _ = uncordon_node(&k8s, &node.hostname).await;
                 let msg = "No Talos client for reboot".to_string();
+                progress.lock().unwrap().set_step(idx, NodeStep::Failed);
                 audit_failure(op_name, &node.hostname, &msg);
                 if stop_on_failure {
                     return RollingOperationResult {
@@ -517,6 +622,7 @@ async fn run_rolling_operation(
         }
 
         completed += 1;
+        progress.lock().unwrap().set_step(idx, NodeStep::Done);
         audit_success(
             op_name,
             &node.hostname,
@@ -544,7 +650,45 @@ async fn run_rolling_operation(
     }
 }
 
+/// Wait until etcd on a control plane node has a leader and reports no errors
+async fn wait_for_etcd_healthy(
+    client: &TalosClient,
+    address: &str,
+    timeout_secs: u64,
+) -> Result<(), String> {
+    let start = std::time::Instant::now();
+    let nodes = [address.to_string()];
+
+    loop {
+        let error = match client.etcd_status_for_nodes(&nodes).await {
+            Ok(statuses) => match statuses.first() {
+                Some(status) if !status.errors.is_empty() => status.errors.join(", "),
+                Some(status) if status.leader_id == 0 => "no leader".to_string(),
+                Some(_) => return Ok(()),
+                None => "no status reported".to_string(),
+            },
+            Err(e) => e.to_string(),
+        };
+
+        if start.elapsed().as_secs() >= timeout_secs {
+            return Err(error);
+        }
+        tokio::time::sleep(tokio::time::Duration::from_secs(ETCD_POLL_INTERVAL_SECS)).await;
+    }
+}
+
 impl Component for RollingOperationsComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Navigate"),
+            ("Space/Enter", "Toggle node selection"),
+            ("r", "Rolling reboot of selected nodes"),
+            ("d", "Rolling drain of selected nodes"),
+            ("y/n", "Confirm/cancel operation"),
+            ("q/Esc", "Close"),
+        ])
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         match &self.state {
             RollingState::Selecting => match key.code {
@@ -607,7 +751,10 @@ impl Component for RollingOperationsComponent {
 
     fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
         let overlay_width = 60.min(area.width.saturating_sub(4));
-        let overlay_height = 20.min(area.height.saturating_sub(4));
+        // Grow with the node list so every node's step stays visible
+        let overlay_height = (self.nodes.len() as u16 + 14)
+            .max(20)
+            .min(area.height.saturating_sub(4));
         let x = (area.width.saturating_sub(overlay_width)) / 2;
         let y = (area.height.saturating_sub(overlay_height)) / 2;
         let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
@@ -649,6 +796,10 @@ impl Component for RollingOperationsComponent {
 
         Ok(())
     }
+
+    fn is_busy(&self) -> bool {
+        matches!(self.state, RollingState::InProgress { .. })
+    }
 }
 
 impl RollingOperationsComponent {
@@ -733,6 +884,9 @@ impl RollingOperationsComponent {
 
     fn draw_confirmation(&self, frame: &mut Frame, area: Rect, op_type: RollingOperationType) {
         let selected = self.selected_nodes();
+        let cp_count = self.selected_controlplane_count();
+        let quorum_warning = op_type == RollingOperationType::Reboot && cp_count > 1;
+
         let mut lines = Vec::new();
         lines.push(Line::from(""));
         lines.push(Line::from(vec![Span::styled(
@@ -743,19 +897,49 @@ impl RollingOperationsComponent {
         )]));
         lines.push(Line::from(""));
 
-        for node in selected.iter().take(5) {
+        // List every node that fits, leaving room for the warning and buttons
+        let reserved = if quorum_warning { 12 } else { 7 };
+        let max_listed = (area.height as usize).saturating_sub(reserved).max(1);
+        let listed = if selected.len() > max_listed {
+            max_listed.saturating_sub(1).max(1)
+        } else {
+            selected.len()
+        };
+        for node in selected.iter().take(listed) {
+            let (role, role_color) = if node.is_controlplane {
+                ("CP", Color::Yellow)
+            } else {
+                ("W", Color::Green)
+            };
             lines.push(Line::from(vec![
                 Span::raw("    - "),
                 Span::styled(&node.hostname, Style::default().fg(Color::Cyan)),
+                Span::raw(" "),
+                Span::styled(format!("[{}]", role), Style::default().fg(role_color)),
             ]));
         }
-        if selected.len() > 5 {
+        if selected.len() > listed {
             lines.push(Line::from(vec![Span::styled(
-                format!("    ... and {} more", selected.len() - 5),
+                format!("    ... and {} more", selected.len() - listed),
                 Style::default().fg(Color::DarkGray),
             )]));
         }
 
+        if quorum_warning {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled(
+                format!("  ! {} control plane nodes will reboot", cp_count),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )]));
+            lines.push(Line::from(
+                "    Rebooting them together would lose etcd quorum.",
+            ));
+            lines.push(Line::from(
+                "    They go one at a time: each must be Ready with",
+            ));
+            lines.push(Line::from("    healthy etcd before the next one starts."));
+        }
+
         lines.push(Line::from(""));
         lines.push(Line::from(vec![
             Span::styled("  [y]", Style::default().fg(Color::Green)),
@@ -773,6 +957,26 @@ impl RollingOperationsComponent {
         frame.render_widget(paragraph, area);
     }
 
+    /// One line per selected node with its current step
+    fn node_step_lines(&self) -> Vec<Line<'_>> {
+        self.selected_nodes()
+            .into_iter()
+            .zip(&self.node_steps)
+            .map(|(node, step)| {
+                let role = if node.is_controlplane { "CP" } else { "W" };
+                Line::from(vec![
+                    Span::raw("    "),
+                    Span::styled(
+                        format!("{:<24}", node.hostname),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::raw(format!("{:<5}", format!("[{}]", role))),
+                    Span::styled(step.label(), Style::default().fg(step.color())),
+                ])
+            })
+            .collect()
+    }
+
     fn draw_progress(
         &self,
         frame: &mut Frame,
@@ -799,6 +1003,8 @@ impl RollingOperationsComponent {
             ),
         ]));
         lines.push(Line::from(""));
+        lines.extend(self.node_step_lines());
+        lines.push(Line::from(""));
         lines.push(Line::from(vec![Span::styled(
             format!("  {}", message),
             Style::default().fg(Color::White),
@@ -857,6 +1063,8 @@ impl RollingOperationsComponent {
             ]));
         }
 
+        lines.push(Line::from(""));
+        lines.extend(self.node_step_lines());
         lines.push(Line::from(""));
         lines.push(Line::from(vec![Span::raw(format!("  {}", message))]));
         lines.push(Line::from(""));
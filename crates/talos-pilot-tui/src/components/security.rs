@@ -3,11 +3,12 @@
 //! Provides a consolidated view of security-related cluster status.
 
 use crate::action::Action;
-use crate::components::Component;
 use crate::components::diagnostics::pki::{
     self, CertStatus, CertificateInfo, EncryptionProvider, EncryptionStatus, PkiStatus,
     VolumeEncryption,
 };
+use crate::components::{Component, help_entries};
+use crate::ui_ext::{glyphs, manual_refresh};
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -18,7 +19,7 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 use std::time::Duration;
-use talos_pilot_core::AsyncState;
+use talos_pilot_core::{AsyncState, ComponentError};
 use talos_rs::TalosClient;
 
 /// Auto-refresh interval in seconds
@@ -89,11 +90,12 @@ pub enum ItemStatus {
 
 impl ItemStatus {
     fn indicator(&self) -> (&'static str, Color) {
+        let g = glyphs();
         match self {
-            ItemStatus::Good => ("●", Color::Green),
-            ItemStatus::Warning => ("⚠", Color::Yellow),
+            ItemStatus::Good => (g.filled, Color::Green),
+            ItemStatus::Warning => (g.warning, Color::Yellow),
             ItemStatus::Critical => ("!", Color::Red),
-            ItemStatus::Info => ("○", Color::Cyan),
+            ItemStatus::Info => (g.hollow, Color::Cyan),
             ItemStatus::Header => ("", Color::Cyan),
         }
     }
@@ -130,7 +132,7 @@ impl SecurityComponent {
     }
 
     /// Set error message
-    pub fn set_error(&mut self, error: String) {
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
         self.state.set_error(error);
     }
 
@@ -587,10 +589,14 @@ fn cert_to_item(cert: &CertificateInfo) -> SecurityItem {
 }
 
 impl Component for SecurityComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[("j/k ↑/↓", "Navigate"), ("r", "Refresh"), ("q/Esc", "Back")])
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => Ok(Some(Action::Back)),
-            KeyCode::Char('r') => Ok(Some(Action::Refresh)),
+            KeyCode::Char('r') => Ok(manual_refresh(&self.state)),
             KeyCode::Up | KeyCode::Char('k') => {
                 self.select_prev();
                 Ok(None)
@@ -3,7 +3,11 @@
 //! Shows physical disks and Talos volume status for a node.
 
 use crate::action::Action;
-use crate::components::Component;
+use crate::components::{Component, help_entries};
+use crate::ui_ext::{
+    ComponentErrorExt, NodeInfo, column_widths, empty_state, glyphs, last_updated_span,
+    manual_refresh, node_info_spans, wrap_row,
+};
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -13,28 +17,36 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
 };
-use std::time::Duration;
-use talos_pilot_core::{AsyncState, format_bytes};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use talos_pilot_core::{AsyncState, ComponentError, format_bytes, reselect_index};
 use talos_rs::{
-    DiskInfo, TalosClient, VolumeStatus, get_disks_for_node, get_volume_status_for_node,
+    DiskInfo, DiskIoRate, DiskIoStats, MountInfo, NetDevStats, PartitionInfo, TalosClient,
+    VolumeEncryptionConfig, VolumeStatus, get_disks_for_node, get_partitions_for_node,
+    get_volume_encryption_for_node, get_volume_status_for_node,
 };
 
 /// Auto-refresh interval in seconds
 const AUTO_REFRESH_INTERVAL_SECS: u64 = 30;
 
+/// Auto-refresh interval on the I/O tab, where only disk counters are re-read
+const IO_REFRESH_INTERVAL_SECS: u64 = 5;
+
 /// View mode for the storage component
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum StorageViewMode {
     #[default]
     Disks,
     Volumes,
+    Io,
 }
 
 impl StorageViewMode {
     pub fn next(&self) -> Self {
         match self {
             StorageViewMode::Disks => StorageViewMode::Volumes,
-            StorageViewMode::Volumes => StorageViewMode::Disks,
+            StorageViewMode::Volumes => StorageViewMode::Io,
+            StorageViewMode::Io => StorageViewMode::Disks,
         }
     }
 
@@ -42,6 +54,39 @@ impl StorageViewMode {
         match self {
             StorageViewMode::Disks => "Disks",
             StorageViewMode::Volumes => "Volumes",
+            StorageViewMode::Io => "I/O",
+        }
+    }
+}
+
+/// Which volumes the volume table lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VolumeFilter {
+    #[default]
+    All,
+    /// Only volumes whose phase is not `ready`
+    NotReady,
+}
+
+impl VolumeFilter {
+    pub fn next(&self) -> Self {
+        match self {
+            VolumeFilter::All => VolumeFilter::NotReady,
+            VolumeFilter::NotReady => VolumeFilter::All,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            VolumeFilter::All => "all",
+            VolumeFilter::NotReady => "not ready",
+        }
+    }
+
+    fn matches(&self, volume: &VolumeStatus) -> bool {
+        match self {
+            VolumeFilter::All => true,
+            VolumeFilter::NotReady => volume.phase != "ready",
         }
     }
 }
@@ -57,6 +102,125 @@ pub struct StorageData {
     pub disks: Vec<DiskInfo>,
     /// Volume status
     pub volumes: Vec<VolumeStatus>,
+    /// Partitions across all disks
+    pub partitions: Vec<PartitionInfo>,
+    /// Mounted filesystems, used for partition usage
+    pub mounts: Vec<MountInfo>,
+    /// Encryption settings for encrypted volumes
+    pub encryption: Vec<VolumeEncryptionConfig>,
+    /// Block device I/O counters from the latest sample
+    pub io_devices: Vec<DiskIoStats>,
+    /// Previous I/O counters by device, for rate calculation
+    pub prev_io: HashMap<String, DiskIoStats>,
+    /// I/O rates by device, over the last sampling interval
+    pub io_rates: HashMap<String, DiskIoRate>,
+    /// When the I/O counters were last sampled
+    pub last_io_sample: Option<Instant>,
+    /// Length of the interval the current rates cover
+    pub io_interval: Option<Duration>,
+}
+
+impl StorageData {
+    /// Partitions belonging to the given disk
+    pub fn partitions_for(&self, disk: &DiskInfo) -> Vec<&PartitionInfo> {
+        self.partitions
+            .iter()
+            .filter(|p| p.parent == disk.id)
+            .collect()
+    }
+
+    /// Encryption settings for the given volume, if it is encrypted
+    pub fn encryption_for(&self, volume: &VolumeStatus) -> Option<&VolumeEncryptionConfig> {
+        self.encryption.iter().find(|e| e.id == volume.id)
+    }
+
+    /// Whether the volume is encrypted, per its status or its config
+    fn is_encrypted(&self, volume: &VolumeStatus) -> bool {
+        volume
+            .encryption_provider
+            .as_deref()
+            .is_some_and(|p| !p.is_empty() && p != "none")
+            || self.encryption_for(volume).is_some()
+    }
+
+    /// Whether STATE/EPHEMERAL is left unencrypted while other volumes on
+    /// the node are encrypted, i.e. encryption is expected but missing
+    pub fn is_unexpectedly_unencrypted(&self, volume: &VolumeStatus) -> bool {
+        matches!(volume.id.as_str(), "STATE" | "EPHEMERAL")
+            && !self.is_encrypted(volume)
+            && self.volumes.iter().any(|v| self.is_encrypted(v))
+    }
+
+    /// Number of volumes in each phase, `ready` first and the rest in the
+    /// order they are listed
+    pub fn volume_phase_counts(&self) -> Vec<(&str, usize)> {
+        let mut counts: Vec<(&str, usize)> = vec![("ready", 0)];
+        for volume in &self.volumes {
+            match counts.iter_mut().find(|(phase, _)| *phase == volume.phase) {
+                Some((_, n)) => *n += 1,
+                None => counts.push((&volume.phase, 1)),
+            }
+        }
+        counts.retain(|(_, n)| *n > 0);
+        counts
+    }
+
+    /// Volumes shown under `filter`, in listing order
+    pub fn filtered_volumes(&self, filter: VolumeFilter) -> Vec<&VolumeStatus> {
+        self.volumes.iter().filter(|v| filter.matches(v)).collect()
+    }
+
+    /// Take a new I/O sample, computing rates against the previous one
+    ///
+    /// Loop and RAM devices are left out since they only add noise.
+    pub fn update_io(&mut self, devices: Vec<DiskIoStats>, now: Instant) {
+        let devices: Vec<DiskIoStats> = devices
+            .into_iter()
+            .filter(|d| !d.name.starts_with("loop") && !d.name.starts_with("ram"))
+            .collect();
+
+        let elapsed = self.last_io_sample.map(|t| now.duration_since(t));
+        if let Some(elapsed) = elapsed.filter(|e| e.as_secs_f64() > 0.1) {
+            self.io_rates.clear();
+            for dev in &devices {
+                if let Some(prev) = self.prev_io.get(&dev.name) {
+                    let rate = DiskIoRate::from_delta(prev, dev, elapsed.as_secs_f64());
+                    self.io_rates.insert(dev.name.clone(), rate);
+                }
+            }
+            self.io_interval = Some(elapsed);
+        }
+
+        self.prev_io = devices
+            .iter()
+            .map(|d| (d.name.clone(), d.clone()))
+            .collect();
+        self.last_io_sample = Some(now);
+        self.io_devices = devices;
+    }
+
+    /// Mount backed by the given partition, if it is mounted
+    pub fn mount_for(&self, partition: &PartitionInfo) -> Option<&MountInfo> {
+        self.mounts
+            .iter()
+            .find(|m| !partition.dev_path.is_empty() && m.filesystem == partition.dev_path)
+    }
+}
+
+/// Color for a volume phase: green when ready, yellow while waiting
+fn phase_color(phase: &str) -> Color {
+    match phase {
+        "ready" => Color::Green,
+        "waiting" => Color::Yellow,
+        _ => Color::Red,
+    }
+}
+
+/// Draw `message` below the header of a bordered table that has no rows
+fn draw_empty_rows(frame: &mut Frame, table_area: Rect, message: Paragraph<'static>) {
+    let inner = Block::default().borders(Borders::ALL).inner(table_area);
+    let [_, body] = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(inner);
+    frame.render_widget(message, body);
 }
 
 /// Storage component for viewing disk and volume information
@@ -73,11 +237,22 @@ pub struct StorageComponent {
     /// Table state for volume list
     volume_table_state: TableState,
 
+    /// Which volumes the volume table lists
+    volume_filter: VolumeFilter,
+
+    /// Table state for the I/O list
+    io_table_state: TableState,
+
     /// Auto-refresh enabled
     auto_refresh: bool,
 
-    /// Client for API calls (unused but kept for consistency)
-    #[allow(dead_code)]
+    /// Show the selected row's cells in full instead of truncated
+    wrap_selected: bool,
+
+    /// Node summary shown in the header
+    node_info: NodeInfo,
+
+    /// Client for API calls (mount usage)
     client: Option<TalosClient>,
 
     /// Node address for talosctl commands
@@ -103,10 +278,13 @@ impl StorageComponent {
         context: Option<String>,
         config_path: Option<String>,
     ) -> Self {
+        let node_info = NodeInfo::new(hostname.clone(), address.clone());
         let mut disk_table_state = TableState::default();
         disk_table_state.select(Some(0));
         let mut volume_table_state = TableState::default();
         volume_table_state.select(Some(0));
+        let mut io_table_state = TableState::default();
+        io_table_state.select(Some(0));
 
         let initial_data = StorageData {
             hostname,
@@ -126,7 +304,11 @@ impl StorageComponent {
             view_mode: StorageViewMode::Disks,
             disk_table_state,
             volume_table_state,
+            volume_filter: VolumeFilter::All,
+            io_table_state,
             auto_refresh: true,
+            wrap_selected: false,
+            node_info,
             client: None,
             node_address,
             context,
@@ -134,13 +316,28 @@ impl StorageComponent {
         }
     }
 
+    /// Set the node summary shown in the header
+    pub fn set_node_info(&mut self, info: NodeInfo) {
+        self.node_info = info;
+    }
+
+    /// Node this view shows
+    pub fn node_info(&self) -> &NodeInfo {
+        &self.node_info
+    }
+
     /// Set the client for API calls
     pub fn set_client(&mut self, client: TalosClient) {
         self.client = Some(client);
     }
 
+    /// Client targeting this node
+    pub fn client(&self) -> Option<&TalosClient> {
+        self.client.as_ref()
+    }
+
     /// Set error message
-    pub fn set_error(&mut self, error: String) {
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
         self.state.set_error(error);
     }
 
@@ -166,16 +363,38 @@ impl StorageComponent {
         // Get or create data
         let mut data = self.state.take_data().unwrap_or_default();
 
-        // Fetch disk information using context-aware async function
-        match get_disks_for_node(context, node, self.config_path.as_deref()).await {
+        // The I/O tab only needs fresh counters once everything else is loaded
+        if self.view_mode == StorageViewMode::Io && data.last_io_sample.is_some() {
+            self.sample_io(&mut data).await;
+            self.state.set_data(data);
+            return Ok(());
+        }
+
+        // Remember what was selected so it survives reordering
+        let prev_disk = data
+            .disks
+            .get(self.selected_disk_index())
+            .map(|d| d.id.clone());
+        let prev_volume = data
+            .filtered_volumes(self.volume_filter)
+            .get(self.selected_volume_index())
+            .map(|v| v.id.clone());
+
+        // Fetch disk information using context-aware async function. A failure
+        // here usually means the node itself is unreachable, so it is shown
+        // as the view's error once the rest has been stored.
+        let disks_error = match get_disks_for_node(context, node, self.config_path.as_deref()).await
+        {
             Ok(disks) => {
                 data.disks = disks;
+                None
             }
             Err(e) => {
                 tracing::warn!("Failed to fetch disks: {}", e);
                 data.disks.clear();
+                Some(ComponentError::from(&e).context("Failed to fetch disks"))
             }
-        }
+        };
 
         // Fetch volume status using context-aware async function
         match get_volume_status_for_node(context, node, self.config_path.as_deref()).await {
@@ -188,11 +407,111 @@ impl StorageComponent {
             }
         }
 
+        // Fetch encryption settings (cipher, key slots) for encrypted volumes
+        match get_volume_encryption_for_node(context, node, self.config_path.as_deref()).await {
+            Ok(encryption) => {
+                data.encryption = encryption;
+            }
+            Err(e) => {
+                tracing::warn!("Failed to fetch volume encryption config: {}", e);
+                data.encryption.clear();
+            }
+        }
+
+        // Fetch partitions using context-aware async function
+        match get_partitions_for_node(context, node, self.config_path.as_deref()).await {
+            Ok(partitions) => {
+                data.partitions = partitions;
+            }
+            Err(e) => {
+                tracing::warn!("Failed to fetch partitions: {}", e);
+                data.partitions.clear();
+            }
+        }
+
+        // Fetch mount usage so partitions can show how full they are
+        if let Some(client) = &self.client {
+            match client.mounts().await {
+                Ok(nodes) => {
+                    data.mounts = nodes.into_iter().flat_map(|n| n.mounts).collect();
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to fetch mounts: {}", e);
+                    data.mounts.clear();
+                }
+            }
+        }
+
+        self.sample_io(&mut data).await;
+
+        // Restore selection by identity, falling back to the old position
+        self.disk_table_state.select(Some(reselect_index(
+            &data.disks,
+            prev_disk.as_ref(),
+            self.selected_disk_index(),
+            |d| d.id.clone(),
+        )));
+        self.volume_table_state.select(Some(reselect_index(
+            &data.filtered_volumes(self.volume_filter),
+            prev_volume.as_ref(),
+            self.selected_volume_index(),
+            |v| v.id.clone(),
+        )));
+
         // Store the data
         self.state.set_data(data);
+        if let Some(error) = disks_error {
+            self.state.set_error(error);
+        }
         Ok(())
     }
 
+    /// Read the node's block device counters into `data`
+    async fn sample_io(&mut self, data: &mut StorageData) {
+        let Some(client) = &self.client else {
+            return;
+        };
+        match client.disk_stats().await {
+            Ok(nodes) => {
+                let devices = nodes.into_iter().flat_map(|n| n.devices).collect();
+                data.update_io(devices, Instant::now());
+                let len = data.io_devices.len();
+                if self.selected_io_index() >= len {
+                    self.io_table_state.select(Some(len.saturating_sub(1)));
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Failed to fetch disk stats: {}", e);
+            }
+        }
+    }
+
+    /// Switch the volume filter, keeping the selected volume if it is still listed
+    fn cycle_volume_filter(&mut self) {
+        let prev_volume = self.selected_volume().map(|v| v.id.clone());
+        self.volume_filter = self.volume_filter.next();
+        let index = self
+            .data()
+            .map(|d| {
+                reselect_index(
+                    &d.filtered_volumes(self.volume_filter),
+                    prev_volume.as_ref(),
+                    0,
+                    |v| v.id.clone(),
+                )
+            })
+            .unwrap_or(0);
+        self.volume_table_state.select(Some(index));
+    }
+
+    /// Currently selected volume among the listed ones
+    fn selected_volume(&self) -> Option<&VolumeStatus> {
+        self.data()?
+            .filtered_volumes(self.volume_filter)
+            .get(self.selected_volume_index())
+            .copied()
+    }
+
     /// Get selected disk index
     fn selected_disk_index(&self) -> usize {
         self.disk_table_state.selected().unwrap_or(0)
@@ -203,6 +522,17 @@ impl StorageComponent {
         self.volume_table_state.selected().unwrap_or(0)
     }
 
+    /// Get selected I/O device index
+    fn selected_io_index(&self) -> usize {
+        self.io_table_state.selected().unwrap_or(0)
+    }
+
+    /// Number of volumes listed under the current filter
+    fn volume_count(&self) -> usize {
+        self.data()
+            .map_or(0, |d| d.filtered_volumes(self.volume_filter).len())
+    }
+
     /// Move selection up
     fn select_prev(&mut self) {
         match self.view_mode {
@@ -216,18 +546,21 @@ impl StorageComponent {
                 }
             }
             StorageViewMode::Volumes => {
-                if let Some(data) = self.data()
-                    && !data.volumes.is_empty()
-                {
+                let len = self.volume_count();
+                if len > 0 {
                     let i = self.selected_volume_index();
-                    let new_i = if i == 0 {
-                        data.volumes.len() - 1
-                    } else {
-                        i - 1
-                    };
+                    let new_i = if i == 0 { len - 1 } else { i - 1 };
                     self.volume_table_state.select(Some(new_i));
                 }
             }
+            StorageViewMode::Io => {
+                let len = self.data().map_or(0, |d| d.io_devices.len());
+                if len > 0 {
+                    let i = self.selected_io_index();
+                    let new_i = if i == 0 { len - 1 } else { i - 1 };
+                    self.io_table_state.select(Some(new_i));
+                }
+            }
         }
     }
 
@@ -244,22 +577,39 @@ impl StorageComponent {
                 }
             }
             StorageViewMode::Volumes => {
-                if let Some(data) = self.data()
-                    && !data.volumes.is_empty()
-                {
-                    let i = self.selected_volume_index();
-                    let new_i = (i + 1) % data.volumes.len();
+                let len = self.volume_count();
+                if len > 0 {
+                    let new_i = (self.selected_volume_index() + 1) % len;
                     self.volume_table_state.select(Some(new_i));
                 }
             }
+            StorageViewMode::Io => {
+                let len = self.data().map_or(0, |d| d.io_devices.len());
+                if len > 0 {
+                    let new_i = (self.selected_io_index() + 1) % len;
+                    self.io_table_state.select(Some(new_i));
+                }
+            }
         }
     }
 
     /// Draw the disks view
     fn draw_disks_view(&mut self, frame: &mut Frame, area: Rect) {
+        // Size the partition table to the selected disk, within limits
+        let partition_count = self
+            .data()
+            .and_then(|data| {
+                data.disks
+                    .get(self.selected_disk_index())
+                    .map(|disk| data.partitions_for(disk).len())
+            })
+            .unwrap_or(0);
+        let partition_height = (partition_count.max(1) as u16 + 3).min(10);
+
         let chunks = Layout::vertical([
-            Constraint::Min(5),    // Table
-            Constraint::Length(5), // Detail section
+            Constraint::Min(5),                   // Table
+            Constraint::Length(6),                // Detail section
+            Constraint::Length(partition_height), // Partitions
         ])
         .split(area);
 
@@ -273,10 +623,22 @@ impl StorageComponent {
         ])
         .height(1);
 
+        let widths = [
+            Constraint::Length(14),
+            Constraint::Length(10),
+            Constraint::Length(8),
+            Constraint::Length(12),
+            Constraint::Min(20),
+        ];
+        // Inside the borders
+        let cell_widths = column_widths(&widths, chunks[0].width.saturating_sub(2));
+        let selected = self.selected_disk_index();
+
         let rows: Vec<Row> = if let Some(data) = self.data() {
             data.disks
                 .iter()
-                .map(|disk| {
+                .enumerate()
+                .map(|(idx, disk)| {
                     let disk_type = if disk.cdrom {
                         "CD-ROM"
                     } else if disk.rotational {
@@ -293,26 +655,27 @@ impl StorageComponent {
                         Color::Green
                     };
 
-                    Row::new(vec![
-                        Cell::from(disk.dev_path.clone()),
-                        Cell::from(disk.size_pretty.clone()),
-                        Cell::from(disk_type).style(Style::default().fg(type_color)),
-                        Cell::from(disk.transport.clone().unwrap_or_default()),
-                        Cell::from(disk.model.clone().unwrap_or_default()),
-                    ])
+                    let values = vec![
+                        disk.dev_path.clone(),
+                        disk.size_pretty.clone(),
+                        disk_type.to_string(),
+                        disk.transport.clone().unwrap_or_default(),
+                        disk.model.clone().unwrap_or_default(),
+                    ];
+                    let styles = [
+                        Style::default(),
+                        Style::default(),
+                        Style::default().fg(type_color),
+                        Style::default(),
+                        Style::default(),
+                    ];
+                    self.table_row(values, styles, &cell_widths, idx == selected)
                 })
                 .collect()
         } else {
             vec![]
         };
-
-        let widths = [
-            Constraint::Length(14),
-            Constraint::Length(10),
-            Constraint::Length(8),
-            Constraint::Length(12),
-            Constraint::Min(20),
-        ];
+        let no_disks = rows.is_empty() && self.data().is_some();
 
         let table = Table::new(rows, widths)
             .header(header)
@@ -325,9 +688,127 @@ impl StorageComponent {
             .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
 
         frame.render_stateful_widget(table, chunks[0], &mut self.disk_table_state);
+        if no_disks {
+            draw_empty_rows(frame, chunks[0], empty_state("No disks found", None));
+        }
 
         // Draw detail section
         self.draw_disk_detail(frame, chunks[1]);
+
+        // Draw partitions of the selected disk
+        self.draw_disk_partitions(frame, chunks[2]);
+    }
+
+    /// Table row, wrapped to show every cell in full if it is the selected
+    /// row and wrapping is on
+    fn table_row<const N: usize>(
+        &self,
+        values: Vec<String>,
+        styles: [Style; N],
+        widths: &[u16],
+        is_selected: bool,
+    ) -> Row<'static> {
+        let (values, height) = if self.wrap_selected && is_selected {
+            wrap_row(values, widths)
+        } else {
+            (values, 1)
+        };
+        Row::new(
+            values
+                .into_iter()
+                .zip(styles)
+                .map(|(value, style)| Cell::from(value).style(style)),
+        )
+        .height(height)
+    }
+
+    /// Draw partition breakdown for the selected disk
+    fn draw_disk_partitions(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Partitions ")
+            .title_style(Style::default().fg(Color::Cyan));
+
+        let Some(data) = self.data() else {
+            frame.render_widget(Paragraph::new("Loading...").block(block), area);
+            return;
+        };
+
+        let Some(disk) = data.disks.get(self.selected_disk_index()) else {
+            frame.render_widget(Paragraph::new("No disk selected").block(block), area);
+            return;
+        };
+
+        let partitions = data.partitions_for(disk);
+        if partitions.is_empty() {
+            let paragraph = Paragraph::new(Line::from(Span::styled(
+                "No partitions",
+                Style::default().fg(Color::DarkGray),
+            )))
+            .block(block);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let header = Row::new(vec![
+            Cell::from("PARTITION").style(Style::default().add_modifier(Modifier::BOLD)),
+            Cell::from("SIZE").style(Style::default().add_modifier(Modifier::BOLD)),
+            Cell::from("FS").style(Style::default().add_modifier(Modifier::BOLD)),
+            Cell::from("LABEL").style(Style::default().add_modifier(Modifier::BOLD)),
+            Cell::from("USAGE").style(Style::default().add_modifier(Modifier::BOLD)),
+        ])
+        .height(1);
+
+        let rows: Vec<Row> = partitions
+            .iter()
+            .map(|part| {
+                let size = if part.size_pretty.is_empty() {
+                    format_bytes(part.size)
+                } else {
+                    part.size_pretty.clone()
+                };
+
+                let usage_cell = match data.mount_for(part) {
+                    Some(mount) => {
+                        let pct = mount.usage_percent();
+                        let color = if pct >= 90.0 {
+                            Color::Red
+                        } else if pct >= 75.0 {
+                            Color::Yellow
+                        } else {
+                            Color::Green
+                        };
+                        Cell::from(format!(
+                            "{:.0}% of {} ({})",
+                            pct,
+                            format_bytes(mount.size),
+                            mount.mounted_on
+                        ))
+                        .style(Style::default().fg(color))
+                    }
+                    None => Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+                };
+
+                Row::new(vec![
+                    Cell::from(part.dev_path.clone()),
+                    Cell::from(size),
+                    Cell::from(part.filesystem.clone().unwrap_or_else(|| "-".to_string())),
+                    Cell::from(part.label.clone().unwrap_or_default()),
+                    usage_cell,
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(16),
+            Constraint::Length(10),
+            Constraint::Length(8),
+            Constraint::Length(14),
+            Constraint::Min(20),
+        ];
+
+        let table = Table::new(rows, widths).header(header).block(block);
+        frame.render_widget(table, area);
     }
 
     /// Draw disk detail section
@@ -369,6 +850,10 @@ impl StorageComponent {
                                 .unwrap_or_else(|| "N/A".to_string()),
                         ),
                     ]),
+                    Line::from(vec![
+                        Span::styled("By-ID: ", Style::default().fg(Color::Gray)),
+                        Span::raw(disk.by_id_path().unwrap_or("N/A")),
+                    ]),
                 ];
 
                 if disk.readonly {
@@ -394,7 +879,7 @@ impl StorageComponent {
     fn draw_volumes_view(&mut self, frame: &mut Frame, area: Rect) {
         let chunks = Layout::vertical([
             Constraint::Min(5),    // Table
-            Constraint::Length(5), // Detail section
+            Constraint::Length(7), // Detail section
         ])
         .split(area);
 
@@ -409,60 +894,112 @@ impl StorageComponent {
         ])
         .height(1);
 
-        let rows: Vec<Row> = if let Some(data) = self.data() {
-            data.volumes
-                .iter()
-                .map(|vol| {
-                    let phase_color = match vol.phase.as_str() {
-                        "ready" => Color::Green,
-                        "waiting" => Color::Yellow,
-                        _ => Color::Red,
-                    };
+        let widths = [
+            Constraint::Length(12),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(6),
+            Constraint::Length(12),
+            Constraint::Min(15),
+        ];
+        // Inside the borders
+        let cell_widths = column_widths(&widths, chunks[0].width.saturating_sub(2));
+        let selected = self.selected_volume_index();
 
+        let rows: Vec<Row> = if let Some(data) = self.data() {
+            data.filtered_volumes(self.volume_filter)
+                .into_iter()
+                .enumerate()
+                .map(|(idx, vol)| {
                     let encryption = vol
                         .encryption_provider
                         .clone()
                         .unwrap_or_else(|| "none".to_string());
-                    let encryption_color = if encryption == "none" {
+                    let encryption_color = if data.is_unexpectedly_unencrypted(vol) {
+                        Color::Red
+                    } else if encryption == "none" {
                         Color::Yellow
                     } else {
                         Color::Green
                     };
 
-                    Row::new(vec![
-                        Cell::from(vol.id.clone()),
-                        Cell::from(vol.size.clone()),
-                        Cell::from(vol.phase.clone()).style(Style::default().fg(phase_color)),
-                        Cell::from(vol.filesystem.clone().unwrap_or_default()),
-                        Cell::from(encryption).style(Style::default().fg(encryption_color)),
-                        Cell::from(vol.mount_location.clone().unwrap_or_default()),
-                    ])
+                    let values = vec![
+                        vol.id.clone(),
+                        vol.size.clone(),
+                        vol.phase.clone(),
+                        vol.filesystem.clone().unwrap_or_default(),
+                        encryption,
+                        vol.mount_location.clone().unwrap_or_default(),
+                    ];
+                    let styles = [
+                        Style::default(),
+                        Style::default(),
+                        Style::default().fg(phase_color(&vol.phase)),
+                        Style::default(),
+                        Style::default().fg(encryption_color),
+                        Style::default(),
+                    ];
+                    self.table_row(values, styles, &cell_widths, idx == selected)
                 })
                 .collect()
         } else {
             vec![]
         };
 
-        let widths = [
-            Constraint::Length(12),
-            Constraint::Length(10),
-            Constraint::Length(10),
-            Constraint::Length(6),
-            Constraint::Length(12),
-            Constraint::Min(15),
-        ];
+        let unencrypted: Vec<&str> = self
+            .data()
+            .map(|d| {
+                d.volumes
+                    .iter()
+                    .filter(|v| d.is_unexpectedly_unencrypted(v))
+                    .map(|v| v.id.as_str())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let no_volumes = rows.is_empty() && self.data().is_some();
+        let mut title = vec![Span::styled(" Volumes ", Style::default().fg(Color::Cyan))];
+        if self.volume_filter != VolumeFilter::All {
+            title.push(Span::styled(
+                format!("[{}] ", self.volume_filter.label()),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        if !unencrypted.is_empty() {
+            title.push(Span::styled(
+                format!(
+                    "{} {} unencrypted ",
+                    glyphs().warning,
+                    unencrypted.join(", ")
+                ),
+                Style::default().fg(Color::Red),
+            ));
+        }
 
         let table = Table::new(rows, widths)
             .header(header)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(" Volumes ")
-                    .title_style(Style::default().fg(Color::Cyan)),
+                    .title(Line::from(title)),
             )
             .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
 
         frame.render_stateful_widget(table, chunks[0], &mut self.volume_table_state);
+        if no_volumes {
+            let total = self.data().map(|d| d.volumes.len()).unwrap_or(0);
+            let reason = (total > 0).then(|| {
+                format!(
+                    "{} hidden by the '{}' filter - press f to show all",
+                    total,
+                    self.volume_filter.label()
+                )
+            });
+            draw_empty_rows(
+                frame,
+                chunks[0],
+                empty_state("No volumes found", reason.as_deref()),
+            );
+        }
 
         // Draw detail section
         self.draw_volume_detail(frame, chunks[1]);
@@ -476,8 +1013,8 @@ impl StorageComponent {
             .title_style(Style::default().fg(Color::Yellow));
 
         let content = if let Some(data) = self.data() {
-            if let Some(vol) = data.volumes.get(self.selected_volume_index()) {
-                vec![
+            if let Some(vol) = self.selected_volume() {
+                let mut lines = vec![
                     Line::from(vec![
                         Span::styled("Volume: ", Style::default().fg(Color::Gray)),
                         Span::raw(&vol.id),
@@ -496,15 +1033,53 @@ impl StorageComponent {
                         Span::styled("Filesystem: ", Style::default().fg(Color::Gray)),
                         Span::raw(vol.filesystem.clone().unwrap_or_else(|| "N/A".to_string())),
                     ]),
-                    Line::from(vec![
-                        Span::styled("Encryption: ", Style::default().fg(Color::Gray)),
-                        Span::raw(
-                            vol.encryption_provider
-                                .clone()
-                                .unwrap_or_else(|| "none".to_string()),
+                ];
+
+                match data.encryption_for(vol) {
+                    Some(config) => {
+                        let key_size = config
+                            .key_size
+                            .map(|bits| format!("{} bits", bits))
+                            .unwrap_or_else(|| "default".to_string());
+                        lines.push(Line::from(vec![
+                            Span::styled("Encryption: ", Style::default().fg(Color::Gray)),
+                            Span::styled(&config.provider, Style::default().fg(Color::Green)),
+                            Span::raw("  "),
+                            Span::styled("Cipher: ", Style::default().fg(Color::Gray)),
+                            Span::raw(config.cipher_or_default()),
+                            Span::raw("  "),
+                            Span::styled("Key size: ", Style::default().fg(Color::Gray)),
+                            Span::raw(key_size),
+                        ]));
+                        lines.push(Line::from(vec![
+                            Span::styled("Key slots: ", Style::default().fg(Color::Gray)),
+                            Span::raw(config.key_sources()),
+                        ]));
+                    }
+                    None => {
+                        lines.push(Line::from(vec![
+                            Span::styled("Encryption: ", Style::default().fg(Color::Gray)),
+                            Span::raw(
+                                vol.encryption_provider
+                                    .clone()
+                                    .unwrap_or_else(|| "none".to_string()),
+                            ),
+                        ]));
+                    }
+                }
+
+                if data.is_unexpectedly_unencrypted(vol) {
+                    lines.push(Line::from(Span::styled(
+                        format!(
+                            "{} {} is unencrypted while other volumes on this node are encrypted",
+                            glyphs().warning,
+                            vol.id
                         ),
-                    ]),
-                ]
+                        Style::default().fg(Color::Red),
+                    )));
+                }
+
+                lines
             } else {
                 vec![Line::from("No volume selected")]
             }
@@ -516,9 +1091,109 @@ impl StorageComponent {
         frame.render_widget(paragraph, area);
     }
 
+    /// Draw the disk I/O view
+    fn draw_io_view(&mut self, frame: &mut Frame, area: Rect) {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        let header = Row::new(
+            [
+                "DEVICE", "R IOPS", "W IOPS", "READ", "WRITE", "R AWAIT", "W AWAIT", "UTIL",
+                "QUEUE",
+            ]
+            .map(|h| Cell::from(h).style(bold)),
+        )
+        .height(1);
+
+        let widths = [
+            Constraint::Length(14),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(11),
+            Constraint::Length(11),
+            Constraint::Length(9),
+            Constraint::Length(9),
+            Constraint::Length(6),
+            Constraint::Min(5),
+        ];
+
+        let rows: Vec<Row> = self
+            .data()
+            .map(|data| {
+                data.io_devices
+                    .iter()
+                    .map(|dev| {
+                        let Some(rate) = data.io_rates.get(&dev.name) else {
+                            // Rates need two samples
+                            return Row::new([dev.name.clone()]);
+                        };
+                        let util_color = if rate.util_percent >= 90.0 {
+                            Color::Red
+                        } else if rate.util_percent >= 60.0 {
+                            Color::Yellow
+                        } else {
+                            Color::Green
+                        };
+                        let await_cell = |ms: Option<f64>| {
+                            Cell::from(
+                                ms.map_or_else(|| "-".to_string(), |ms| format!("{:.1}ms", ms)),
+                            )
+                        };
+                        Row::new([
+                            Cell::from(dev.name.clone()),
+                            Cell::from(format!("{:.0}", rate.read_iops)),
+                            Cell::from(format!("{:.0}", rate.write_iops)),
+                            Cell::from(NetDevStats::format_rate(rate.read_bytes_per_sec))
+                                .style(Style::default().fg(Color::Green)),
+                            Cell::from(NetDevStats::format_rate(rate.write_bytes_per_sec))
+                                .style(Style::default().fg(Color::Blue)),
+                            await_cell(rate.read_await_ms),
+                            await_cell(rate.write_await_ms),
+                            Cell::from(format!("{:.0}%", rate.util_percent))
+                                .style(Style::default().fg(util_color)),
+                            Cell::from(rate.in_flight.to_string()),
+                        ])
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let no_devices = rows.is_empty() && self.data().is_some();
+        let interval = self
+            .data()
+            .and_then(|d| d.io_interval)
+            .map(|i| format!("over {}s ", i.as_secs()))
+            .unwrap_or_else(|| "waiting for a second sample ".to_string());
+        let title = Line::from(vec![
+            Span::styled(" Disk I/O ", Style::default().fg(Color::Cyan)),
+            Span::styled(interval, Style::default().fg(Color::DarkGray)),
+        ]);
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        frame.render_stateful_widget(table, area, &mut self.io_table_state);
+        if no_devices {
+            draw_empty_rows(frame, area, empty_state("No block devices found", None));
+        }
+    }
+
+    /// Auto-refresh interval for the current tab
+    fn refresh_interval(&self) -> Duration {
+        Duration::from_secs(if self.view_mode == StorageViewMode::Io {
+            IO_REFRESH_INTERVAL_SECS
+        } else {
+            AUTO_REFRESH_INTERVAL_SECS
+        })
+    }
+
     /// Draw tab bar
     fn draw_tabs(&self, frame: &mut Frame, area: Rect) {
-        let tabs = [StorageViewMode::Disks, StorageViewMode::Volumes];
+        let tabs = [
+            StorageViewMode::Disks,
+            StorageViewMode::Volumes,
+            StorageViewMode::Io,
+        ];
 
         let tab_spans: Vec<Span> = tabs
             .iter()
@@ -534,14 +1209,34 @@ impl StorageComponent {
             })
             .collect();
 
-        let hostname = self.data().map(|d| d.hostname.clone()).unwrap_or_default();
-
         let mut line_spans = tab_spans;
         line_spans.push(Span::raw("  "));
-        line_spans.push(Span::styled(
-            format!("Node: {}", hostname),
-            Style::default().fg(Color::DarkGray),
-        ));
+
+        // Volume health at a glance, so a stuck volume shows from the Disks tab
+        let counts = self
+            .data()
+            .map(|d| d.volume_phase_counts())
+            .unwrap_or_default();
+        if !counts.is_empty() {
+            line_spans.push(Span::styled("Volumes: ", Style::default().fg(Color::Gray)));
+            for (i, (phase, n)) in counts.iter().enumerate() {
+                if i > 0 {
+                    line_spans.push(Span::styled(", ", Style::default().fg(Color::Gray)));
+                }
+                line_spans.push(Span::styled(
+                    format!("{} {}", n, phase),
+                    Style::default().fg(phase_color(phase)),
+                ));
+            }
+            line_spans.push(Span::raw("  "));
+        }
+
+        line_spans.push(Span::styled("Node: ", Style::default().fg(Color::DarkGray)));
+        line_spans.extend(node_info_spans(&self.node_info));
+        if let Some(age) = self.state.elapsed_since_refresh() {
+            line_spans.push(Span::raw("  "));
+            line_spans.push(last_updated_span(age, self.refresh_interval()));
+        }
 
         let tabs_line = Line::from(line_spans);
         let paragraph = Paragraph::new(tabs_line);
@@ -550,6 +1245,17 @@ impl StorageComponent {
 }
 
 impl Component for StorageComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Navigate"),
+            ("Tab", "Switch Disks/Volumes/I/O"),
+            ("w", "Wrap selected row (show full values)"),
+            ("f", "Filter volumes (all / not ready)"),
+            ("r", "Refresh"),
+            ("q/Esc", "Back"),
+        ])
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         match key.code {
             KeyCode::Esc | KeyCode::Char('q') => {
@@ -564,8 +1270,14 @@ impl Component for StorageComponent {
             KeyCode::Down | KeyCode::Char('j') => {
                 self.select_next();
             }
+            KeyCode::Char('w') => {
+                self.wrap_selected = !self.wrap_selected;
+            }
+            KeyCode::Char('f') if self.view_mode == StorageViewMode::Volumes => {
+                self.cycle_volume_filter();
+            }
             KeyCode::Char('r') => {
-                return Ok(Some(Action::Refresh));
+                return Ok(manual_refresh(&self.state));
             }
             _ => {}
         }
@@ -575,8 +1287,10 @@ impl Component for StorageComponent {
     fn update(&mut self, action: Action) -> Result<Option<Action>> {
         if let Action::Tick = action {
             // Check for auto-refresh using AsyncState
-            let interval = Duration::from_secs(AUTO_REFRESH_INTERVAL_SECS);
-            if self.state.should_auto_refresh(self.auto_refresh, interval) {
+            if self
+                .state
+                .should_auto_refresh(self.auto_refresh, self.refresh_interval())
+            {
                 return Ok(Some(Action::Refresh));
             }
         }
@@ -592,9 +1306,9 @@ impl Component for StorageComponent {
             return Ok(());
         }
 
-        if let Some(err) = self.state.error() {
-            let error =
-                Paragraph::new(format!("Error: {}", err)).style(Style::default().fg(Color::Red));
+        if let Some(err) = self.state.component_error() {
+            let error = Paragraph::new(format!("Error: {}", err.describe(self.auto_refresh)))
+                .style(Style::default().fg(err.color()));
             frame.render_widget(error, area);
             return Ok(());
         }
@@ -613,22 +1327,117 @@ impl Component for StorageComponent {
         match self.view_mode {
             StorageViewMode::Disks => self.draw_disks_view(frame, chunks[1]),
             StorageViewMode::Volumes => self.draw_volumes_view(frame, chunks[1]),
+            StorageViewMode::Io => self.draw_io_view(frame, chunks[1]),
         }
 
         // Draw help line
-        let help = Line::from(vec![
+        let mut help_spans = vec![
             Span::styled(" Tab", Style::default().fg(Color::Cyan)),
             Span::raw(" switch view  "),
             Span::styled("↑/↓", Style::default().fg(Color::Cyan)),
             Span::raw(" navigate  "),
+            Span::styled("w", Style::default().fg(Color::Cyan)),
+            Span::raw(if self.wrap_selected {
+                " truncate  "
+            } else {
+                " wrap  "
+            }),
+        ];
+        if self.view_mode == StorageViewMode::Volumes {
+            help_spans.push(Span::styled("f", Style::default().fg(Color::Cyan)));
+            help_spans.push(Span::raw(format!(
+                " filter: {}  ",
+                self.volume_filter.label()
+            )));
+        }
+        help_spans.extend([
             Span::styled("r", Style::default().fg(Color::Cyan)),
             Span::raw(" refresh  "),
             Span::styled("Esc", Style::default().fg(Color::Cyan)),
             Span::raw(" back"),
         ]);
+        let help = Line::from(help_spans);
         let help_paragraph = Paragraph::new(help).style(Style::default().fg(Color::DarkGray));
         frame.render_widget(help_paragraph, chunks[2]);
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn volume(id: &str, phase: &str) -> VolumeStatus {
+        VolumeStatus {
+            id: id.to_string(),
+            encryption_provider: None,
+            phase: phase.to_string(),
+            size: String::new(),
+            filesystem: None,
+            mount_location: None,
+        }
+    }
+
+    #[test]
+    fn test_volume_phase_counts() {
+        let data = StorageData {
+            volumes: vec![
+                volume("META", "waiting"),
+                volume("STATE", "ready"),
+                volume("EPHEMERAL", "failed"),
+                volume("IMAGECACHE", "waiting"),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            data.volume_phase_counts(),
+            [("ready", 1), ("waiting", 2), ("failed", 1)]
+        );
+        assert!(StorageData::default().volume_phase_counts().is_empty());
+    }
+
+    #[test]
+    fn test_filtered_volumes() {
+        let data = StorageData {
+            volumes: vec![
+                volume("META", "ready"),
+                volume("STATE", "waiting"),
+                volume("EPHEMERAL", "failed"),
+            ],
+            ..Default::default()
+        };
+        let ids = |filter| -> Vec<&str> {
+            data.filtered_volumes(filter)
+                .iter()
+                .map(|v| v.id.as_str())
+                .collect()
+        };
+        assert_eq!(ids(VolumeFilter::All), ["META", "STATE", "EPHEMERAL"]);
+        assert_eq!(ids(VolumeFilter::NotReady), ["STATE", "EPHEMERAL"]);
+        assert_eq!(VolumeFilter::NotReady.next(), VolumeFilter::All);
+    }
+
+    #[test]
+    fn test_update_io_rates_between_samples() {
+        let stats = |name: &str, write_completed| DiskIoStats {
+            name: name.to_string(),
+            write_completed,
+            ..Default::default()
+        };
+        let start = Instant::now();
+        let mut data = StorageData::default();
+
+        data.update_io(vec![stats("sda", 100), stats("loop0", 5)], start);
+        assert_eq!(data.io_devices.len(), 1);
+        assert!(data.io_rates.is_empty());
+
+        data.update_io(
+            vec![stats("sda", 300), stats("nvme0n1", 10)],
+            start + Duration::from_secs(2),
+        );
+        assert_eq!(data.io_rates["sda"].write_iops, 100.0);
+        assert!(!data.io_rates.contains_key("nvme0n1"));
+        assert_eq!(data.io_interval, Some(Duration::from_secs(2)));
+    }
+}
@@ -11,6 +11,8 @@
 
 use crate::action::Action;
 use crate::components::Component;
+use crate::state::default_config_dir;
+use crate::ui_ext::glyphs;
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
@@ -20,8 +22,80 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
 };
-use std::time::Instant;
-use talos_rs::{DiskInfo, GenConfigResult, VolumeStatus};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use talos_rs::{DiskInfo, GenConfigResult, VolumeStatus, existing_config_files};
+use tokio::task::JoinHandle;
+
+/// How long each maintenance mode query may take before connecting gives up
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Ports the maintenance API is known to listen on, probed in order when
+/// the endpoint has no explicit port
+const MAINTENANCE_PORTS: [u16; 2] = [50000, 50001];
+
+/// Smallest install disk accepted by default (10 GiB)
+pub const DEFAULT_MIN_DISK_SIZE: u64 = 10 * GIB;
+
+const GIB: u64 = 1024 * 1024 * 1024;
+
+/// Explicit port in an endpoint, if one was given
+///
+/// Handles `host:port` and `[ipv6]:port`; a bare IPv6 address has no port.
+pub fn explicit_port(endpoint: &str) -> Option<u16> {
+    let (host, port) = endpoint.rsplit_once(':')?;
+    if host.contains(':') && !host.ends_with(']') {
+        return None;
+    }
+    port.parse().ok()
+}
+
+/// Maintenance API endpoints to try for `endpoint`, paired with their port
+///
+/// An explicit port is used as-is; otherwise each known maintenance port is probed.
+pub fn maintenance_candidates(endpoint: &str) -> Vec<(String, u16)> {
+    if let Some(port) = explicit_port(endpoint) {
+        return vec![(endpoint.to_string(), port)];
+    }
+
+    let host = endpoint.trim_start_matches('[').trim_end_matches(']');
+    MAINTENANCE_PORTS
+        .iter()
+        .map(|&port| {
+            let endpoint = if host.contains(':') {
+                format!("[{}]:{}", host, port)
+            } else {
+                format!("{}:{}", host, port)
+            };
+            (endpoint, port)
+        })
+        .collect()
+}
+
+/// Directory a cluster's configs are generated into: a subdirectory of
+/// `base` named after the cluster, so clusters don't overwrite each other's
+/// secrets
+pub fn cluster_output_dir(base: &str, cluster_name: &str) -> String {
+    std::path::Path::new(base)
+        .join(cluster_name)
+        .display()
+        .to_string()
+}
+
+/// Apply `edit` to a cluster name, moving the output directory along with
+/// it unless it was changed by hand
+pub fn edit_cluster_name(
+    cluster_name: &mut String,
+    output_dir: &mut String,
+    base: &str,
+    edit: impl FnOnce(&mut String),
+) {
+    let follows = *output_dir == cluster_output_dir(base, cluster_name);
+    edit(cluster_name);
+    if follows {
+        *output_dir = cluster_output_dir(base, cluster_name);
+    }
+}
 
 /// Wizard states
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -115,6 +189,50 @@ impl NodeType {
     }
 }
 
+/// Limits after which a wait state gives up and reports an error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaitLimits {
+    /// Maximum number of polls before giving up
+    pub max_attempts: u32,
+    /// Maximum time to wait before giving up
+    pub max_elapsed: Duration,
+}
+
+impl Default for WaitLimits {
+    fn default() -> Self {
+        Self {
+            max_attempts: 60,
+            max_elapsed: Duration::from_secs(300),
+        }
+    }
+}
+
+/// What the WaitingHealthy step has seen come up so far
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HealthChecklist {
+    /// `talosctl etcd status` succeeds
+    pub etcd: bool,
+    /// The Kubernetes API answers with the kubeconfig from Talos
+    pub api_server: bool,
+    /// At least one node reports Ready
+    pub node_ready: bool,
+}
+
+impl HealthChecklist {
+    pub fn is_complete(&self) -> bool {
+        self.etcd && self.api_server && self.node_ready
+    }
+
+    /// Items in order, with whether each is done
+    pub fn items(&self) -> [(&'static str, bool); 3] {
+        [
+            ("etcd running", self.etcd),
+            ("API server reachable", self.api_server),
+            ("Node ready", self.node_ready),
+        ]
+    }
+}
+
 /// Data accumulated through the wizard flow
 #[derive(Debug, Clone, Default)]
 pub struct WizardData {
@@ -122,22 +240,36 @@ pub struct WizardData {
     pub endpoint: String,
 
     // From Connecting state
+    /// Endpoint (with port) the maintenance API answered on
+    pub api_endpoint: Option<String>,
     pub disks: Vec<DiskInfo>,
     pub volumes: Vec<VolumeStatus>,
     pub connected: bool,
+    /// Disk the node is currently running Talos from, if any
+    pub system_disk: Option<DiskInfo>,
 
     // From SelectDisk state
     pub selected_disk: Option<DiskInfo>,
+    /// Disks smaller than this (bytes) can't be selected
+    pub min_disk_size: u64,
 
     // From ConfigureCluster state
     pub cluster_name: String,
     pub k8s_endpoint: String,
     pub node_type: NodeType,
     pub output_dir: String,
+    /// Directory the default output directory is a per-cluster subdirectory of
+    pub output_base: String,
+    /// Space-separated machine config patch files (optional)
+    pub patch_files: String,
+    /// Patches given with --config-patch: inline YAML or `@file`, passed as is
+    pub config_patches: Vec<String>,
 
     // From ConfigReady state (after generation)
     pub config_result: Option<GenConfigResult>,
     pub context_name: Option<String>,
+    /// Talosconfig the generated context was merged into
+    pub merged_into: Option<PathBuf>,
 
     // Timing for wait states
     pub wait_started: Option<Instant>,
@@ -145,6 +277,13 @@ pub struct WizardData {
     // Polling tracking
     pub poll_attempts: u32,
     pub last_poll_error: Option<String>,
+    pub wait_limits: WaitLimits,
+    /// Wait state that gave up, resumed on retry
+    pub failed_wait: Option<WizardState>,
+    /// Progress of the WaitingHealthy checks, from the latest poll
+    pub health: HealthChecklist,
+    /// Stop at ConfigReady: generate files, never merge or apply
+    pub dry_run: bool,
 
     // Spinner for animations
     pub spinner_frame: usize,
@@ -153,8 +292,74 @@ pub struct WizardData {
     pub last_error: Option<String>,
 }
 
-/// Spinner frames for wait states
-const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+/// What the Connecting step learns about the maintenance mode node
+#[derive(Debug, Clone, Default)]
+pub struct NodeInventory {
+    /// Endpoint (with port) that answered
+    pub endpoint: String,
+    pub disks: Vec<DiskInfo>,
+    pub volumes: Vec<VolumeStatus>,
+    /// ID of the disk the node is running Talos from, if any
+    pub system_disk_id: Option<String>,
+}
+
+/// Query the node's disks, volumes and system disk
+///
+/// Only the disk list is required; it doubles as the reachability probe
+/// when the endpoint has no port and each maintenance port is tried. Each
+/// query is bounded by `CONNECT_TIMEOUT` so an unreachable node fails
+/// instead of hanging.
+async fn fetch_node_inventory(endpoint: String) -> Result<NodeInventory, String> {
+    use talos_rs::{get_disks_insecure, get_system_disk_insecure, get_volume_status_insecure};
+
+    let candidates = maintenance_candidates(&endpoint);
+    let mut answered = None;
+    let mut last_error = String::new();
+    for (candidate, _) in &candidates {
+        match tokio::time::timeout(CONNECT_TIMEOUT, get_disks_insecure(candidate)).await {
+            Ok(Ok(disks)) => {
+                tracing::info!("Maintenance API answered on {}", candidate);
+                answered = Some((candidate.clone(), disks));
+                break;
+            }
+            Ok(Err(e)) => last_error = e.to_string(),
+            Err(_) => {
+                last_error = format!(
+                    "No response from {} after {}s",
+                    candidate,
+                    CONNECT_TIMEOUT.as_secs()
+                );
+            }
+        }
+        tracing::debug!("No maintenance API on {}: {}", candidate, last_error);
+    }
+    let Some((endpoint, disks)) = answered else {
+        if candidates.len() > 1 {
+            let ports: Vec<String> = candidates.iter().map(|(_, p)| p.to_string()).collect();
+            return Err(format!("tried ports {}: {}", ports.join(", "), last_error));
+        }
+        return Err(last_error);
+    };
+
+    // Volumes and the system disk are optional, don't fail if unavailable
+    let volumes = tokio::time::timeout(CONNECT_TIMEOUT, get_volume_status_insecure(&endpoint))
+        .await
+        .ok()
+        .and_then(|r| r.ok())
+        .unwrap_or_default();
+    let system_disk_id = tokio::time::timeout(CONNECT_TIMEOUT, get_system_disk_insecure(&endpoint))
+        .await
+        .ok()
+        .and_then(|r| r.ok())
+        .flatten();
+
+    Ok(NodeInventory {
+        endpoint,
+        disks,
+        volumes,
+        system_disk_id,
+    })
+}
 
 /// Format error messages with better descriptions for common issues
 fn format_poll_error(error: &str) -> String {
@@ -179,19 +384,165 @@ fn format_poll_error(error: &str) -> String {
     }
 }
 
+/// Check that a cluster name is a DNS-safe label (RFC 1123)
+fn validate_cluster_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Cluster name is required".to_string());
+    }
+    if name.len() > 63 {
+        return Err("Cluster name must be at most 63 characters".to_string());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err("Cluster name may only contain a-z, 0-9 and '-'".to_string());
+    }
+    if name.starts_with('-') || name.ends_with('-') {
+        return Err("Cluster name must start and end with a letter or digit".to_string());
+    }
+    Ok(())
+}
+
+/// Check that a Kubernetes endpoint is an `https://host:port` URL
+fn validate_k8s_endpoint(endpoint: &str) -> Result<(), String> {
+    let Some(rest) = endpoint.strip_prefix("https://") else {
+        return Err("K8s endpoint must start with https://".to_string());
+    };
+    let authority = rest.strip_suffix('/').unwrap_or(rest);
+    if authority.contains('/') {
+        return Err("K8s endpoint must not include a path".to_string());
+    }
+
+    // IPv6 hosts are bracketed: https://[fd00::1]:6443
+    let (host, port) = if let Some(v6) = authority.strip_prefix('[') {
+        let Some((host, port)) = v6.split_once("]:") else {
+            return Err("K8s endpoint must include a port (e.g. :6443)".to_string());
+        };
+        if host.parse::<std::net::Ipv6Addr>().is_err() {
+            return Err(format!("Invalid IPv6 address '{}'", host));
+        }
+        (host, port)
+    } else {
+        let Some((host, port)) = authority.rsplit_once(':') else {
+            return Err("K8s endpoint must include a port (e.g. :6443)".to_string());
+        };
+        let valid_host = !host.is_empty()
+            && host.split('.').all(|label| {
+                !label.is_empty()
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+                    && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            });
+        if !valid_host {
+            return Err(format!("Invalid host '{}' in K8s endpoint", host));
+        }
+        (host, port)
+    };
+
+    match port.parse::<u16>() {
+        Ok(p) if p > 0 && !host.is_empty() => Ok(()),
+        _ => Err(format!("Invalid port '{}' in K8s endpoint", port)),
+    }
+}
+
+/// Check that every patch file exists and parses as YAML
+fn validate_patch_files(files: &str) -> Result<(), String> {
+    for file in files.split_whitespace() {
+        let path = file.strip_prefix('@').unwrap_or(file);
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Can't read patch '{}': {}", path, e))?;
+        serde_yaml::from_str::<serde_yaml::Value>(&content)
+            .map_err(|e| format!("Patch '{}' is not valid YAML: {}", path, e))?;
+    }
+    Ok(())
+}
+
+/// Check that the output directory exists and is writable, or can be created
+fn validate_output_dir(dir: &str) -> Result<(), String> {
+    if dir.trim().is_empty() {
+        return Err("Output directory is required".to_string());
+    }
+
+    // talosctl creates missing directories, so check the nearest existing one
+    let path = std::path::Path::new(dir);
+    let existing = path
+        .ancestors()
+        .find(|p| p.as_os_str().is_empty() || p.exists())
+        .map(|p| {
+            if p.as_os_str().is_empty() {
+                std::path::Path::new(".")
+            } else {
+                p
+            }
+        })
+        .unwrap_or(path);
+
+    if !existing.is_dir() {
+        return Err(format!("'{}' is not a directory", existing.display()));
+    }
+
+    // Permissions bits don't tell the whole story (ACLs, read-only mounts) - try it
+    let probe = existing.join(format!(".talos-pilot-write-test-{}", std::process::id()));
+    match std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+    {
+        Ok(_) => {
+            let _ = std::fs::remove_file(&probe);
+            Ok(())
+        }
+        Err(e) => Err(format!("'{}' is not writable: {}", existing.display(), e)),
+    }
+}
+
 impl WizardData {
     pub fn new(endpoint: String) -> Self {
-        let k8s_endpoint = format!("https://{}:6443", endpoint);
+        // Drop the Talos API port if one was given with the endpoint
+        let host = match endpoint.rsplit_once(':') {
+            Some((host, port)) if !host.contains(':') && port.parse::<u16>().is_ok() => host,
+            _ => endpoint.as_str(),
+        };
+        let k8s_endpoint = format!("https://{}:6443", host);
+        let cluster_name = "talos-cluster".to_string();
+        let output_base = default_config_dir();
         Self {
             endpoint: endpoint.clone(),
-            cluster_name: "talos-cluster".to_string(),
+            output_dir: cluster_output_dir(&output_base, &cluster_name),
+            output_base,
+            cluster_name,
             k8s_endpoint,
             node_type: NodeType::Controlplane,
-            output_dir: ".".to_string(),
+            min_disk_size: DEFAULT_MIN_DISK_SIZE,
             ..Default::default()
         }
     }
 
+    /// Endpoint to send maintenance API calls to: the one that answered on
+    /// connect, or the endpoint as given before that
+    pub fn maintenance_endpoint(&self) -> &str {
+        self.api_endpoint.as_deref().unwrap_or(&self.endpoint)
+    }
+
+    /// Validate the cluster settings, returning the first invalid field
+    pub fn validate_config(&self) -> Result<(), (ConfigField, String)> {
+        validate_cluster_name(&self.cluster_name).map_err(|e| (ConfigField::ClusterName, e))?;
+        validate_k8s_endpoint(&self.k8s_endpoint).map_err(|e| (ConfigField::K8sEndpoint, e))?;
+        validate_output_dir(&self.output_dir).map_err(|e| (ConfigField::OutputDir, e))?;
+        validate_patch_files(&self.patch_files).map_err(|e| (ConfigField::PatchFiles, e))?;
+        Ok(())
+    }
+
+    /// Every patch to pass to `talosctl gen config --config-patch`
+    pub fn all_config_patches(&self) -> Vec<String> {
+        let files = self.patch_files.split_whitespace().map(|file| {
+            let path = file.strip_prefix('@').unwrap_or(file);
+            format!("@{}", path)
+        });
+        self.config_patches.iter().cloned().chain(files).collect()
+    }
+
     /// Get installable disks (filter out read-only, CD-ROM)
     pub fn installable_disks(&self) -> Vec<&DiskInfo> {
         self.disks
@@ -200,14 +551,40 @@ impl WizardData {
             .collect()
     }
 
+    /// Whether a disk is the one the node is currently running from
+    pub fn is_system_disk(&self, disk: &DiskInfo) -> bool {
+        self.system_disk
+            .as_ref()
+            .is_some_and(|system| system.is_same_disk(disk))
+    }
+
+    /// Whether a disk is below the minimum install size
+    pub fn is_undersized(&self, disk: &DiskInfo) -> bool {
+        disk.size < self.min_disk_size
+    }
+
+    /// Minimum install size for display, e.g. "10 GiB"
+    pub fn min_disk_size_label(&self) -> String {
+        format!("{} GiB", self.min_disk_size.div_ceil(GIB))
+    }
+
     /// Get current spinner character
     pub fn spinner(&self) -> &'static str {
-        SPINNER_FRAMES[self.spinner_frame % SPINNER_FRAMES.len()]
+        let frames = glyphs().spinner;
+        frames[self.spinner_frame % frames.len()]
     }
 
     /// Advance spinner to next frame
     pub fn advance_spinner(&mut self) {
-        self.spinner_frame = (self.spinner_frame + 1) % SPINNER_FRAMES.len();
+        self.spinner_frame = (self.spinner_frame + 1) % glyphs().spinner.len();
+    }
+
+    /// Whether the current wait has used up its attempts or time
+    pub fn wait_limit_exceeded(&self) -> bool {
+        let timed_out = self
+            .wait_started
+            .is_some_and(|t| t.elapsed() >= self.wait_limits.max_elapsed);
+        timed_out || self.poll_attempts >= self.wait_limits.max_attempts
     }
 }
 
@@ -219,6 +596,7 @@ pub enum ConfigField {
     K8sEndpoint,
     NodeType,
     OutputDir,
+    PatchFiles,
 }
 
 impl ConfigField {
@@ -227,16 +605,18 @@ impl ConfigField {
             ConfigField::ClusterName => ConfigField::K8sEndpoint,
             ConfigField::K8sEndpoint => ConfigField::NodeType,
             ConfigField::NodeType => ConfigField::OutputDir,
-            ConfigField::OutputDir => ConfigField::ClusterName,
+            ConfigField::OutputDir => ConfigField::PatchFiles,
+            ConfigField::PatchFiles => ConfigField::ClusterName,
         }
     }
 
     pub fn prev(&self) -> Self {
         match self {
-            ConfigField::ClusterName => ConfigField::OutputDir,
+            ConfigField::ClusterName => ConfigField::PatchFiles,
             ConfigField::K8sEndpoint => ConfigField::ClusterName,
             ConfigField::NodeType => ConfigField::K8sEndpoint,
             ConfigField::OutputDir => ConfigField::NodeType,
+            ConfigField::PatchFiles => ConfigField::OutputDir,
         }
     }
 }
@@ -257,6 +637,23 @@ pub struct WizardComponent {
 
     /// Whether viewing volumes instead of disks
     viewing_volumes: bool,
+
+    /// Validation error shown on the config dialog
+    config_error: Option<String>,
+
+    /// Enter was pressed once on the system disk; a second press confirms
+    confirm_system_disk: bool,
+
+    /// Enter was pressed once with configs already in the output directory;
+    /// a second press overwrites them
+    confirm_overwrite: bool,
+
+    /// Why the configured output directory wasn't used, shown on the first
+    /// Enter so generated secrets don't land elsewhere unnoticed
+    output_dir_warning: Option<String>,
+
+    /// In-flight Connecting step queries
+    connect_task: Option<JoinHandle<Result<NodeInventory, String>>>,
 }
 
 impl WizardComponent {
@@ -270,6 +667,11 @@ impl WizardComponent {
             disk_table_state,
             active_field: ConfigField::default(),
             viewing_volumes: false,
+            confirm_system_disk: false,
+            confirm_overwrite: false,
+            output_dir_warning: None,
+            config_error: None,
+            connect_task: None,
         }
     }
 
@@ -294,38 +696,145 @@ impl WizardComponent {
         self.state = new_state;
     }
 
+    /// Use custom limits for the wait states
+    pub fn with_wait_limits(mut self, limits: WaitLimits) -> Self {
+        self.data.wait_limits = limits;
+        self
+    }
+
+    /// Patches from the command line, merged into the generated config
+    pub fn with_config_patches(mut self, patches: Vec<String>) -> Self {
+        self.data.config_patches = patches;
+        self
+    }
+
+    /// Refuse install disks smaller than `bytes`
+    pub fn with_min_disk_size(mut self, bytes: u64) -> Self {
+        self.data.min_disk_size = bytes;
+        self
+    }
+
+    /// Generate configs into a per-cluster subdirectory of `base`
+    pub fn with_output_dir(mut self, base: String) -> Self {
+        self.data.output_dir = cluster_output_dir(&base, &self.data.cluster_name);
+        self.data.output_base = base;
+        self
+    }
+
+    /// Warn before generating that the configured output directory was ignored
+    pub fn with_output_dir_warning(mut self, warning: Option<String>) -> Self {
+        self.output_dir_warning = warning;
+        self
+    }
+
+    /// Only generate config; the node and talosconfig are left untouched
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.data.dry_run = dry_run;
+        self
+    }
+
+    /// Enter a wait state with fresh attempt and elapsed counters
+    pub fn begin_wait(&mut self, state: WizardState) {
+        self.data.wait_started = Some(Instant::now());
+        self.data.poll_attempts = 0;
+        self.data.last_poll_error = None;
+        self.data.health = HealthChecklist::default();
+        self.transition(state);
+    }
+
+    /// Give up on the current wait state; retry with `r` resumes it
+    pub fn fail_wait(&mut self, waiting_for: &str) {
+        let elapsed = self
+            .data
+            .wait_started
+            .map(|t| t.elapsed().as_secs())
+            .unwrap_or(0);
+        let mut message = format!(
+            "Gave up waiting for {} after {} attempts ({}s)",
+            waiting_for, self.data.poll_attempts, elapsed
+        );
+        if let Some(err) = &self.data.last_poll_error {
+            message.push_str(&format!(". Last error: {}", format_poll_error(err)));
+        }
+
+        self.data.failed_wait = Some(self.state.clone());
+        self.set_error(message);
+    }
+
+    /// Record a WaitingHealthy poll, completing once every item is done
+    ///
+    /// `error` explains why the first unchecked item is still pending.
+    pub fn apply_health(&mut self, health: HealthChecklist, error: Option<String>) {
+        if self.state != WizardState::WaitingHealthy {
+            return;
+        }
+        self.data.health = health;
+        self.data.last_poll_error = error;
+        if health.is_complete() {
+            self.transition(WizardState::Complete);
+        }
+    }
+
     /// Set error state
     pub fn set_error(&mut self, message: String) {
         self.data.last_error = Some(message.clone());
         self.state = WizardState::Error(message);
     }
 
-    /// Connect to the maintenance mode node and fetch disk info
-    pub async fn connect(&mut self) -> Result<()> {
-        use talos_rs::{get_disks_insecure, get_volume_status_insecure};
+    /// Connect to the maintenance mode node in the background
+    ///
+    /// The result is picked up on a later tick, so Esc/q still work while
+    /// the node is slow to answer.
+    pub fn start_connect(&mut self) {
+        if let Some(task) = self.connect_task.take() {
+            task.abort();
+        }
+        if self.state != WizardState::Connecting {
+            self.transition(WizardState::Connecting);
+        }
+        let endpoint = self.data.endpoint.clone();
+        self.connect_task = Some(tokio::spawn(fetch_node_inventory(endpoint)));
+    }
 
-        let endpoint = &self.data.endpoint;
+    /// Abandon an in-flight connect
+    fn cancel_connect(&mut self) {
+        if let Some(task) = self.connect_task.take() {
+            task.abort();
+        }
+    }
 
-        // Fetch disks
-        match get_disks_insecure(endpoint).await {
-            Ok(disks) => {
-                self.data.disks = disks;
-                self.data.connected = true;
-            }
-            Err(e) => {
-                self.set_error(format!("Failed to connect: {}", e));
-                return Ok(());
-            }
+    /// Collect the connect result once its task has finished
+    fn poll_connect(&mut self) {
+        if !self.connect_task.as_ref().is_some_and(|t| t.is_finished()) {
+            return;
         }
+        if let Some(task) = self.connect_task.take() {
+            let result = futures::executor::block_on(task).unwrap_or_else(|e| Err(e.to_string()));
+            self.apply_connect(result);
+        }
+    }
 
-        // Fetch volumes (optional, don't fail if unavailable)
-        if let Ok(volumes) = get_volume_status_insecure(endpoint).await {
-            self.data.volumes = volumes;
+    /// Move on to disk selection, or to a retryable error
+    pub fn apply_connect(&mut self, result: Result<NodeInventory, String>) {
+        if self.state != WizardState::Connecting {
+            return;
         }
 
-        // Transition to disk selection
-        self.transition(WizardState::SelectDisk);
-        Ok(())
+        match result {
+            Ok(inventory) => {
+                // Resolve the system disk to a full entry so it can be matched
+                // by WWID/serial rather than by its dev path
+                self.data.system_disk = inventory
+                    .system_disk_id
+                    .and_then(|id| inventory.disks.iter().find(|d| d.id == id).cloned());
+                self.data.api_endpoint = Some(inventory.endpoint);
+                self.data.disks = inventory.disks;
+                self.data.volumes = inventory.volumes;
+                self.data.connected = true;
+                self.transition(WizardState::SelectDisk);
+            }
+            Err(e) => self.set_error(format!("Failed to connect: {}", e)),
+        }
     }
 
     /// Get selected disk index
@@ -340,6 +849,7 @@ impl WizardComponent {
             let i = self.selected_disk_index();
             let new_i = if i == 0 { disks.len() - 1 } else { i - 1 };
             self.disk_table_state.select(Some(new_i));
+            self.confirm_system_disk = false;
         }
     }
 
@@ -350,15 +860,38 @@ impl WizardComponent {
             let i = self.selected_disk_index();
             let new_i = (i + 1) % disks.len();
             self.disk_table_state.select(Some(new_i));
+            self.confirm_system_disk = false;
         }
     }
 
+    /// Highlighted disk, if it is too small to install to
+    fn undersized_selection(&self) -> Option<&DiskInfo> {
+        self.data
+            .installable_disks()
+            .get(self.selected_disk_index())
+            .copied()
+            .filter(|disk| self.data.is_undersized(disk))
+    }
+
     /// Confirm disk selection and move to configure
+    ///
+    /// Undersized disks are refused, and selecting the disk the node is
+    /// running from needs a second Enter.
     fn confirm_disk_selection(&mut self) {
         let disks = self.data.installable_disks();
         let idx = self.selected_disk_index();
         if idx < disks.len() {
-            self.data.selected_disk = Some(disks[idx].clone());
+            let disk = disks[idx].clone();
+            // The install would only fail after the node reboots
+            if self.data.is_undersized(&disk) {
+                return;
+            }
+            if self.data.is_system_disk(&disk) && !self.confirm_system_disk {
+                self.confirm_system_disk = true;
+                return;
+            }
+            self.confirm_system_disk = false;
+            self.data.selected_disk = Some(disk);
             self.transition(WizardState::ConfigureCluster);
         }
     }
@@ -399,8 +932,39 @@ impl WizardComponent {
                 None
             }
             KeyCode::Enter => {
-                // Generate config
-                Some(Action::WizardGenConfig)
+                // Catch bad input here rather than failing during generation
+                match self.data.validate_config() {
+                    Ok(()) => {
+                        if let Some(warning) = self.output_dir_warning.take() {
+                            self.active_field = ConfigField::OutputDir;
+                            self.config_error = Some(format!(
+                                "{} - Enter again to generate in {}",
+                                warning, self.data.output_dir
+                            ));
+                            return None;
+                        }
+                        // Regenerating replaces the cluster's secrets for good
+                        let existing = existing_config_files(&self.data.output_dir);
+                        if !existing.is_empty() && !self.confirm_overwrite {
+                            self.confirm_overwrite = true;
+                            self.active_field = ConfigField::OutputDir;
+                            self.config_error = Some(format!(
+                                "{} already in {} - Enter again to overwrite, losing its secrets",
+                                existing.join(", "),
+                                self.data.output_dir
+                            ));
+                            return None;
+                        }
+                        self.confirm_overwrite = false;
+                        self.config_error = None;
+                        Some(Action::WizardGenConfig)
+                    }
+                    Err((field, message)) => {
+                        self.active_field = field;
+                        self.config_error = Some(message);
+                        None
+                    }
+                }
             }
             KeyCode::Esc => {
                 // Go back to disk selection
@@ -408,22 +972,37 @@ impl WizardComponent {
                 None
             }
             KeyCode::Char(c) => {
+                self.config_error = None;
+                self.confirm_overwrite = false;
                 match self.active_field {
-                    ConfigField::ClusterName => self.data.cluster_name.push(c),
+                    ConfigField::ClusterName => edit_cluster_name(
+                        &mut self.data.cluster_name,
+                        &mut self.data.output_dir,
+                        &self.data.output_base,
+                        |name| name.push(c),
+                    ),
                     ConfigField::K8sEndpoint => self.data.k8s_endpoint.push(c),
                     ConfigField::NodeType => {
                         // Space or any char toggles
                         self.data.node_type = self.data.node_type.toggle();
                     }
                     ConfigField::OutputDir => self.data.output_dir.push(c),
+                    ConfigField::PatchFiles => self.data.patch_files.push(c),
                 }
                 None
             }
             KeyCode::Backspace => {
+                self.config_error = None;
+                self.confirm_overwrite = false;
                 match self.active_field {
-                    ConfigField::ClusterName => {
-                        self.data.cluster_name.pop();
-                    }
+                    ConfigField::ClusterName => edit_cluster_name(
+                        &mut self.data.cluster_name,
+                        &mut self.data.output_dir,
+                        &self.data.output_base,
+                        |name| {
+                            name.pop();
+                        },
+                    ),
                     ConfigField::K8sEndpoint => {
                         self.data.k8s_endpoint.pop();
                     }
@@ -431,6 +1010,9 @@ impl WizardComponent {
                     ConfigField::OutputDir => {
                         self.data.output_dir.pop();
                     }
+                    ConfigField::PatchFiles => {
+                        self.data.patch_files.pop();
+                    }
                 }
                 None
             }
@@ -441,6 +1023,9 @@ impl WizardComponent {
     /// Handle key events for ConfigReady state
     fn handle_config_ready_key(&mut self, key: KeyEvent) -> Option<Action> {
         match key.code {
+            // A dry run ends here
+            KeyCode::Enter if self.data.dry_run => Some(Action::Quit),
+            KeyCode::Char('a') if self.data.dry_run => None,
             KeyCode::Char('a') | KeyCode::Enter => {
                 // Apply config
                 Some(Action::WizardApplyConfig)
@@ -471,7 +1056,7 @@ impl WizardComponent {
     fn handle_complete_key(&mut self, key: KeyEvent) -> Option<Action> {
         match key.code {
             KeyCode::Enter => {
-                // Exit to secure mode
+                // Open the new context in the cluster overview
                 Some(Action::WizardComplete(self.data.context_name.clone()))
             }
             KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
@@ -483,8 +1068,12 @@ impl WizardComponent {
     fn handle_error_key(&mut self, key: KeyEvent) -> Option<Action> {
         match key.code {
             KeyCode::Char('r') => {
+                // Retry a wait state that gave up - the node may just be slow
+                if let Some(state) = self.data.failed_wait.take() {
+                    self.begin_wait(state);
+                    return None;
+                }
                 // Retry - go back to connecting
-                self.transition(WizardState::Connecting);
                 Some(Action::WizardRetry)
             }
             KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
@@ -492,6 +1081,17 @@ impl WizardComponent {
         }
     }
 
+    /// Handle key events for Connecting state
+    fn handle_connecting_key(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.cancel_connect();
+                Some(Action::Quit)
+            }
+            _ => None,
+        }
+    }
+
     /// Handle key events for waiting states
     fn handle_waiting_key(&mut self, key: KeyEvent) -> Option<Action> {
         match key.code {
@@ -523,6 +1123,10 @@ impl WizardComponent {
                     Style::default().fg(Color::DarkGray),
                 ),
                 Span::styled(title, Style::default().fg(Color::White)),
+                Span::styled(
+                    if self.data.dry_run { "  [dry run]" } else { "" },
+                    Style::default().fg(Color::Cyan),
+                ),
             ])
         } else {
             Line::from(vec![
@@ -557,6 +1161,14 @@ impl WizardComponent {
                 "  Please wait while we detect available disks.",
                 Style::default().fg(Color::DarkGray),
             ),
+            Line::raw(""),
+            Line::styled(
+                format!(
+                    "  Gives up after {}s. Press [q] or [Esc] to cancel.",
+                    CONNECT_TIMEOUT.as_secs()
+                ),
+                Style::default().fg(Color::DarkGray),
+            ),
         ])
         .block(Block::default().borders(Borders::ALL));
 
@@ -566,11 +1178,11 @@ impl WizardComponent {
     /// Draw disk selection state
     fn draw_select_disk(&mut self, frame: &mut Frame, area: Rect) {
         let layout = Layout::vertical([
-            Constraint::Length(2), // Instructions
-            Constraint::Min(8),    // Disk table
-            Constraint::Length(8), // Disk details
-            Constraint::Length(2), // Warning
-            Constraint::Length(1), // Help
+            Constraint::Length(2),  // Instructions
+            Constraint::Min(8),     // Disk table
+            Constraint::Length(10), // Disk details
+            Constraint::Length(2),  // Warning
+            Constraint::Length(1),  // Help
         ])
         .split(area);
 
@@ -592,17 +1204,30 @@ impl WizardComponent {
         self.draw_disk_details(frame, layout[2]);
 
         // Warning
+        let warning_text = if let Some(disk) = self.undersized_selection() {
+            format!(
+                "{} is too small - Talos needs at least {}",
+                disk.size_pretty,
+                self.data.min_disk_size_label()
+            )
+        } else if self.confirm_system_disk {
+            "This is the disk the node is running from - press Enter again to erase it".to_string()
+        } else {
+            "Selected disk will be COMPLETELY ERASED".to_string()
+        };
+        let warning_color = if self.confirm_system_disk || self.undersized_selection().is_some() {
+            Color::Red
+        } else {
+            Color::Yellow
+        };
         let warning = Paragraph::new(Line::from(vec![
             Span::styled(
-                "  ⚠ WARNING: ",
+                format!("  {} WARNING: ", glyphs().warning),
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(warning_color)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(
-                "Selected disk will be COMPLETELY ERASED",
-                Style::default().fg(Color::Yellow),
-            ),
+            Span::styled(warning_text, Style::default().fg(warning_color)),
         ]));
         frame.render_widget(warning, layout[3]);
 
@@ -649,10 +1274,22 @@ impl WizardComponent {
                 } else {
                     ("SSD", Color::Green)
                 };
+                let device = if self.data.is_system_disk(disk) {
+                    Cell::from(format!("{} (sys)", disk.dev_path))
+                        .style(Style::default().fg(Color::Red))
+                } else {
+                    Cell::from(disk.dev_path.clone())
+                };
+
+                let size_style = if self.data.is_undersized(disk) {
+                    Style::default().fg(Color::Red)
+                } else {
+                    Style::default()
+                };
 
                 Row::new(vec![
-                    Cell::from(disk.dev_path.clone()),
-                    Cell::from(disk.size_pretty.clone()),
+                    device,
+                    Cell::from(disk.size_pretty.clone()).style(size_style),
                     Cell::from(disk_type.0).style(Style::default().fg(disk_type.1)),
                     Cell::from(disk.transport.clone().unwrap_or_default()),
                     Cell::from(disk.model.clone().unwrap_or_else(|| "-".to_string())),
@@ -663,7 +1300,7 @@ impl WizardComponent {
         let table = Table::new(
             rows,
             [
-                Constraint::Length(15),
+                Constraint::Length(20),
                 Constraint::Length(12),
                 Constraint::Length(6),
                 Constraint::Length(10),
@@ -787,6 +1424,26 @@ impl WizardComponent {
                         Style::default().fg(Color::White),
                     ),
                 ]),
+                Line::from(vec![
+                    Span::styled("  By-ID:     ", Style::default().fg(Color::DarkGray)),
+                    Span::styled(
+                        disk.by_id_path().unwrap_or("-"),
+                        Style::default().fg(Color::White),
+                    ),
+                ]),
+                Line::from(vec![
+                    Span::styled("  Selector:  ", Style::default().fg(Color::DarkGray)),
+                    match disk.install_selector() {
+                        Some((key, value)) => Span::styled(
+                            format!("{}: {}", key, value),
+                            Style::default().fg(Color::Green),
+                        ),
+                        None => Span::styled(
+                            format!("disk: {} (no stable ID)", disk.dev_path),
+                            Style::default().fg(Color::Yellow),
+                        ),
+                    },
+                ]),
             ]
         } else {
             vec![Line::styled(
@@ -813,6 +1470,7 @@ impl WizardComponent {
             Constraint::Length(2), // K8s endpoint
             Constraint::Length(2), // Node type
             Constraint::Length(2), // Output dir
+            Constraint::Length(2), // Patch files
             Constraint::Fill(1),   // Spacer
             Constraint::Length(1), // Help
         ])
@@ -824,11 +1482,16 @@ impl WizardComponent {
             .selected_disk
             .as_ref()
             .map(|d| {
+                let selector = d
+                    .install_selector()
+                    .map(|(key, value)| format!(" [{}: {}]", key, value))
+                    .unwrap_or_default();
                 format!(
-                    "{} ({} {})",
+                    "{} ({} {}){}",
                     d.dev_path,
                     d.size_pretty,
-                    if d.rotational { "HDD" } else { "SSD" }
+                    if d.rotational { "HDD" } else { "SSD" },
+                    selector
                 )
             })
             .unwrap_or_else(|| "None".to_string());
@@ -836,7 +1499,10 @@ impl WizardComponent {
         let disk_line = Paragraph::new(Line::from(vec![
             Span::styled("  Install Disk:  ", Style::default().fg(Color::DarkGray)),
             Span::styled(&disk_info, Style::default().fg(Color::Green)),
-            Span::styled(" ✓", Style::default().fg(Color::Green)),
+            Span::styled(
+                format!(" {}", glyphs().check),
+                Style::default().fg(Color::Green),
+            ),
         ]));
         frame.render_widget(disk_line, layout[0]);
 
@@ -919,6 +1585,50 @@ impl WizardComponent {
         ]));
         frame.render_widget(dir_field, layout[4]);
 
+        // Patch files field
+        let patches_active = self.active_field == ConfigField::PatchFiles;
+        let mut patch_spans = vec![Span::styled(
+            "  Patch Files:   ",
+            Style::default().fg(Color::Cyan),
+        )];
+        if self.data.patch_files.is_empty() && !patches_active {
+            patch_spans.push(Span::styled(
+                "(optional, space-separated)",
+                Style::default().fg(Color::DarkGray),
+            ));
+        } else {
+            patch_spans.push(Span::styled(
+                &self.data.patch_files,
+                if patches_active {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default().fg(Color::White)
+                },
+            ));
+        }
+        if patches_active {
+            patch_spans.push(Span::styled("_", Style::default().fg(Color::Yellow)));
+        }
+        if !self.data.config_patches.is_empty() {
+            patch_spans.push(Span::styled(
+                format!("  + {} from --config-patch", self.data.config_patches.len()),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(patch_spans)), layout[5]);
+
+        // Inline validation error
+        if let Some(err) = &self.config_error {
+            let error_line = Paragraph::new(Line::from(vec![
+                Span::styled(
+                    format!("  {} ", glyphs().cross),
+                    Style::default().fg(Color::Red),
+                ),
+                Span::styled(err.as_str(), Style::default().fg(Color::Red)),
+            ]));
+            frame.render_widget(error_line, layout[6]);
+        }
+
         // Help
         let help = Line::from(vec![
             Span::styled(" [Tab] ", Style::default().fg(Color::Cyan)),
@@ -930,14 +1640,14 @@ impl WizardComponent {
             Span::styled(" [Esc] ", Style::default().fg(Color::Cyan)),
             Span::raw("Back"),
         ]);
-        frame.render_widget(Paragraph::new(help), layout[6]);
+        frame.render_widget(Paragraph::new(help), layout[7]);
     }
 
     /// Draw config ready state
     fn draw_config_ready(&self, frame: &mut Frame, area: Rect) {
         let config = self.data.config_result.as_ref();
 
-        let content = if let Some(cfg) = config {
+        let mut content = if let Some(cfg) = config {
             vec![
                 Line::raw(""),
                 Line::styled(
@@ -950,43 +1660,89 @@ impl WizardComponent {
                     Style::default().fg(Color::DarkGray),
                 )]),
                 Line::from(vec![
-                    Span::raw("    • "),
+                    Span::raw(format!("    {} ", glyphs().bullet)),
                     Span::styled(&cfg.controlplane_path, Style::default().fg(Color::White)),
                 ]),
                 Line::from(vec![
-                    Span::raw("    • "),
+                    Span::raw(format!("    {} ", glyphs().bullet)),
                     Span::styled(&cfg.worker_path, Style::default().fg(Color::White)),
                 ]),
                 Line::from(vec![
-                    Span::raw("    • "),
+                    Span::raw(format!("    {} ", glyphs().bullet)),
                     Span::styled(&cfg.talosconfig_path, Style::default().fg(Color::White)),
                 ]),
                 Line::raw(""),
-                if let Some(ctx) = &self.data.context_name {
-                    Line::from(vec![
+                if self.data.dry_run {
+                    Line::styled(
+                        format!(
+                            "  Talosconfig not merged - use {} directly",
+                            cfg.talosconfig_path
+                        ),
+                        Style::default().fg(Color::DarkGray),
+                    )
+                } else if let Some(ctx) = &self.data.context_name {
+                    let mut spans = vec![
                         Span::styled("  Context merged: ", Style::default().fg(Color::DarkGray)),
                         Span::styled(ctx, Style::default().fg(Color::Cyan)),
-                    ])
+                    ];
+                    if let Some(path) = &self.data.merged_into {
+                        spans.push(Span::styled(" into ", Style::default().fg(Color::DarkGray)));
+                        spans.push(Span::styled(
+                            path.display().to_string(),
+                            Style::default().fg(Color::White),
+                        ));
+                    }
+                    Line::from(spans)
                 } else {
                     Line::raw("")
                 },
                 Line::raw(""),
+            ]
+        } else {
+            vec![Line::styled(
+                "  No configuration generated",
+                Style::default().fg(Color::Red),
+            )]
+        };
+
+        let patch_count = self.data.all_config_patches().len();
+        if config.is_some() && patch_count > 0 {
+            content.push(Line::styled(
+                format!("  Config patches merged: {}", patch_count),
+                Style::default().fg(Color::DarkGray),
+            ));
+            content.push(Line::raw(""));
+        }
+        if config.is_some() && self.data.dry_run {
+            content.extend([
+                Line::styled(
+                    "  DRY RUN - nothing was applied to the node.",
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Line::raw(""),
+                Line::styled(
+                    "  Press [Enter] or [q] to exit, [Esc] to change settings.",
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]);
+        } else if config.is_some() {
+            content.extend([
                 Line::styled(
                     "  Press [a] or [Enter] to apply configuration to the node.",
                     Style::default().fg(Color::Yellow),
                 ),
                 Line::raw(""),
                 Line::styled(
-                    "  ⚠ This will install Talos to the selected disk and reboot.",
+                    format!(
+                        "  {} This will install Talos to the selected disk and reboot.",
+                        glyphs().warning
+                    ),
                     Style::default().fg(Color::Yellow),
                 ),
-            ]
-        } else {
-            vec![Line::styled(
-                "  No configuration generated",
-                Style::default().fg(Color::Red),
-            )]
-        };
+            ]);
+        }
 
         let para = Paragraph::new(content).block(Block::default().borders(Borders::ALL));
         frame.render_widget(para, area);
@@ -1055,7 +1811,10 @@ impl WizardComponent {
             Line::from(vec![
                 Span::styled("     Attempts: ", Style::default().fg(Color::DarkGray)),
                 Span::styled(
-                    format!("{}", self.data.poll_attempts),
+                    format!(
+                        "{} / {}",
+                        self.data.poll_attempts, self.data.wait_limits.max_attempts
+                    ),
                     Style::default().fg(Color::White),
                 ),
             ]),
@@ -1102,7 +1861,7 @@ impl WizardComponent {
         let mut lines = vec![
             Line::raw(""),
             Line::styled(
-                "  ✓ Node is up and configured!",
+                format!("  {} Node is up and configured!", glyphs().check),
                 Style::default().fg(Color::Green),
             ),
             Line::raw(""),
@@ -1173,7 +1932,7 @@ impl WizardComponent {
             .unwrap_or(0);
 
         let spinner = self.data.spinner();
-        let content = Paragraph::new(vec![
+        let mut lines = vec![
             Line::raw(""),
             Line::from(vec![
                 Span::styled(format!("  {} ", spinner), Style::default().fg(Color::Cyan)),
@@ -1184,24 +1943,70 @@ impl WizardComponent {
             ]),
             Line::raw(""),
             Line::from(vec![
-                Span::styled("     Elapsed: ", Style::default().fg(Color::DarkGray)),
+                Span::styled("     Elapsed:  ", Style::default().fg(Color::DarkGray)),
                 Span::styled(
                     format!("{} seconds", elapsed),
                     Style::default().fg(Color::White),
                 ),
             ]),
+            Line::from(vec![
+                Span::styled("     Attempts: ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!(
+                        "{} / {}",
+                        self.data.poll_attempts, self.data.wait_limits.max_attempts
+                    ),
+                    Style::default().fg(Color::White),
+                ),
+            ]),
             Line::raw(""),
             Line::styled("  Checklist:", Style::default().fg(Color::DarkGray)),
-            Line::raw("    [ ] etcd running"),
-            Line::raw("    [ ] API server running"),
-            Line::raw("    [ ] Node ready"),
+        ];
+
+        // Items are checked in order, so the first open one is what we wait on
+        let pending = self.data.health.items().iter().position(|(_, done)| !done);
+        for (i, (label, done)) in self.data.health.items().into_iter().enumerate() {
+            let line = if done {
+                Line::from(vec![
+                    Span::styled("    [x] ", Style::default().fg(Color::Green)),
+                    Span::styled(label, Style::default().fg(Color::Green)),
+                ])
+            } else if Some(i) == pending {
+                Line::from(vec![
+                    Span::styled(
+                        format!("    [{}] ", spinner),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::styled(label, Style::default().fg(Color::Yellow)),
+                ])
+            } else {
+                Line::styled(
+                    format!("    [ ] {}", label),
+                    Style::default().fg(Color::DarkGray),
+                )
+            };
+            lines.push(line);
+        }
+
+        if let Some(err) = &self.data.last_poll_error {
+            lines.push(Line::raw(""));
+            lines.push(Line::from(vec![
+                Span::styled("     Status:   ", Style::default().fg(Color::DarkGray)),
+                Span::styled(format_poll_error(err), Style::default().fg(Color::Red)),
+            ]));
+        }
+
+        lines.extend([
             Line::raw(""),
             Line::from(vec![
                 Span::styled(" [q] ", Style::default().fg(Color::Cyan)),
                 Span::raw("Quit"),
             ]),
-        ])
-        .block(Block::default().borders(Borders::ALL));
+        ]);
+
+        let content = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL))
+            .wrap(ratatui::widgets::Wrap { trim: false });
 
         frame.render_widget(content, area);
     }
@@ -1211,7 +2016,7 @@ impl WizardComponent {
         let content = Paragraph::new(vec![
             Line::raw(""),
             Line::styled(
-                "  🎉 Cluster bootstrap complete!",
+                format!("  {} Cluster bootstrap complete!", glyphs().celebrate),
                 Style::default()
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
@@ -1228,20 +2033,34 @@ impl WizardComponent {
                     Style::default().fg(Color::Cyan),
                 ),
             ]),
+            Line::from(vec![
+                Span::styled(" Talosconfig: ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    self.data
+                        .merged_into
+                        .as_ref()
+                        .map(|p| p.display().to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                    Style::default().fg(Color::White),
+                ),
+            ]),
             Line::raw(""),
             Line::styled("  Next steps:", Style::default().fg(Color::White)),
             Line::styled(
-                "    • Get kubeconfig: talosctl kubeconfig",
+                format!(
+                    "    {} Get kubeconfig: talosctl kubeconfig",
+                    glyphs().bullet
+                ),
                 Style::default().fg(Color::DarkGray),
             ),
             Line::styled(
-                "    • View cluster: kubectl get nodes",
+                format!("    {} View cluster: kubectl get nodes", glyphs().bullet),
                 Style::default().fg(Color::DarkGray),
             ),
             Line::raw(""),
             Line::from(vec![
                 Span::styled(" [Enter] ", Style::default().fg(Color::Green)),
-                Span::raw("Exit"),
+                Span::raw("Monitor the cluster in talos-pilot"),
                 Span::raw("  "),
                 Span::styled(" [q] ", Style::default().fg(Color::Cyan)),
                 Span::raw("Quit"),
@@ -1278,7 +2097,7 @@ impl WizardComponent {
 impl Component for WizardComponent {
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         let action = match &self.state {
-            WizardState::Connecting => self.handle_waiting_key(key),
+            WizardState::Connecting => self.handle_connecting_key(key),
             WizardState::SelectDisk => self.handle_select_disk_key(key),
             WizardState::ConfigureCluster => self.handle_configure_key(key),
             WizardState::ConfigReady => self.handle_config_ready_key(key),
@@ -1294,7 +2113,12 @@ impl Component for WizardComponent {
         Ok(action)
     }
 
-    fn update(&mut self, _action: Action) -> Result<Option<Action>> {
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        if let Action::Tick = action {
+            // Spinner and elapsed timers redraw on every tick
+            self.data.advance_spinner();
+            self.poll_connect();
+        }
         Ok(None)
     }
 
@@ -1325,4 +2149,359 @@ impl Component for WizardComponent {
 
         Ok(())
     }
+
+    fn is_capturing_input(&self) -> bool {
+        self.state == WizardState::ConfigureCluster
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_cluster_name() {
+        assert!(validate_cluster_name("talos-cluster").is_ok());
+        assert!(validate_cluster_name("c1").is_ok());
+
+        assert!(validate_cluster_name("").is_err());
+        assert!(validate_cluster_name("My-Cluster").is_err());
+        assert!(validate_cluster_name("my_cluster").is_err());
+        assert!(validate_cluster_name("-cluster").is_err());
+        assert!(validate_cluster_name("cluster-").is_err());
+        assert!(validate_cluster_name(&"a".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn test_validate_k8s_endpoint() {
+        assert!(validate_k8s_endpoint("https://192.168.1.100:6443").is_ok());
+        assert!(validate_k8s_endpoint("https://k8s.example.com:6443/").is_ok());
+        assert!(validate_k8s_endpoint("https://[fd00::1]:6443").is_ok());
+
+        assert!(validate_k8s_endpoint("http://192.168.1.100:6443").is_err());
+        assert!(validate_k8s_endpoint("https://192.168.1.100").is_err());
+        assert!(validate_k8s_endpoint("https://192.168.1.100:0").is_err());
+        assert!(validate_k8s_endpoint("https://192.168.1.100:99999").is_err());
+        assert!(validate_k8s_endpoint("https://192.168.1.100:6443/api").is_err());
+        assert!(validate_k8s_endpoint("https://:6443").is_err());
+        assert!(validate_k8s_endpoint("https://bad host:6443").is_err());
+        assert!(validate_k8s_endpoint("https://[not-ipv6]:6443").is_err());
+    }
+
+    #[test]
+    fn test_validate_output_dir() {
+        let tmp = std::env::temp_dir();
+        assert!(validate_output_dir(tmp.to_str().unwrap()).is_ok());
+        assert!(validate_output_dir(".").is_ok());
+
+        // Missing directories are fine as long as the parent is writable
+        let missing = tmp.join("talos-pilot-missing-dir/nested");
+        assert!(validate_output_dir(missing.to_str().unwrap()).is_ok());
+
+        assert!(validate_output_dir("").is_err());
+
+        // A file is not a directory
+        let file = tmp.join(format!("talos-pilot-not-a-dir-{}", std::process::id()));
+        std::fs::write(&file, b"").unwrap();
+        assert!(validate_output_dir(file.to_str().unwrap()).is_err());
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[test]
+    fn test_default_k8s_endpoint_drops_api_port() {
+        let data = WizardData::new("192.168.1.100:50000".to_string());
+        assert_eq!(data.k8s_endpoint, "https://192.168.1.100:6443");
+        assert!(validate_k8s_endpoint(&data.k8s_endpoint).is_ok());
+
+        let data = WizardData::new("192.168.1.100".to_string());
+        assert_eq!(data.k8s_endpoint, "https://192.168.1.100:6443");
+    }
+
+    #[test]
+    fn test_output_dir_follows_cluster_name() {
+        let mut wizard =
+            WizardComponent::new("192.168.1.100".to_string()).with_output_dir("/srv".to_string());
+        assert_eq!(wizard.data().output_dir, "/srv/talos-cluster");
+
+        wizard.active_field = ConfigField::ClusterName;
+        wizard.handle_configure_key(KeyEvent::from(KeyCode::Char('2')));
+        assert_eq!(wizard.data().output_dir, "/srv/talos-cluster2");
+
+        // A directory picked by hand stays put
+        wizard.data_mut().output_dir = "/tmp/out".to_string();
+        wizard.handle_configure_key(KeyEvent::from(KeyCode::Backspace));
+        assert_eq!(wizard.data().cluster_name, "talos-cluster");
+        assert_eq!(wizard.data().output_dir, "/tmp/out");
+    }
+
+    #[test]
+    fn test_generate_confirms_overwrite() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("talosconfig"), b"context: old").unwrap();
+
+        let mut wizard = WizardComponent::new("192.168.1.100".to_string());
+        wizard.data_mut().output_dir = dir.path().to_str().unwrap().to_string();
+        let enter = || KeyEvent::from(KeyCode::Enter);
+
+        // First Enter warns, the second overwrites
+        assert!(wizard.handle_configure_key(enter()).is_none());
+        assert!(
+            wizard
+                .config_error
+                .as_ref()
+                .unwrap()
+                .contains("talosconfig")
+        );
+        assert_eq!(wizard.active_field, ConfigField::OutputDir);
+        assert!(matches!(
+            wizard.handle_configure_key(enter()),
+            Some(Action::WizardGenConfig)
+        ));
+
+        // Editing the directory asks again
+        wizard.handle_configure_key(enter());
+        wizard.handle_configure_key(KeyEvent::from(KeyCode::Char('/')));
+        assert!(wizard.handle_configure_key(enter()).is_none());
+    }
+
+    #[test]
+    fn test_generate_warns_about_ignored_output_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut wizard = WizardComponent::new("192.168.1.100".to_string())
+            .with_output_dir_warning(Some("state.yaml failed to parse".to_string()));
+        wizard.data_mut().output_dir = dir.path().to_str().unwrap().to_string();
+        let enter = || KeyEvent::from(KeyCode::Enter);
+
+        // Only the first Enter warns
+        assert!(wizard.handle_configure_key(enter()).is_none());
+        assert!(
+            wizard
+                .config_error
+                .as_ref()
+                .unwrap()
+                .contains("state.yaml failed to parse")
+        );
+        assert_eq!(wizard.active_field, ConfigField::OutputDir);
+        assert!(matches!(
+            wizard.handle_configure_key(enter()),
+            Some(Action::WizardGenConfig)
+        ));
+    }
+
+    fn disk(dev_path: &str, wwid: &str) -> DiskInfo {
+        DiskInfo {
+            id: dev_path.trim_start_matches("/dev/").to_string(),
+            dev_path: dev_path.to_string(),
+            size: 64 * GIB,
+            size_pretty: "64 GiB".to_string(),
+            model: None,
+            serial: None,
+            transport: None,
+            rotational: false,
+            readonly: false,
+            cdrom: false,
+            wwid: Some(wwid.to_string()),
+            bus_path: None,
+            symlinks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_system_disk_needs_second_confirm() {
+        let mut wizard = WizardComponent::new("192.168.1.100".to_string());
+        wizard.data_mut().disks = vec![disk("/dev/sda", "naa.1"), disk("/dev/sdb", "naa.2")];
+        // The system disk was enumerated under a different name
+        wizard.data_mut().system_disk = Some(disk("/dev/sdb", "naa.1"));
+        wizard.transition(WizardState::SelectDisk);
+
+        wizard.confirm_disk_selection();
+        assert_eq!(*wizard.state(), WizardState::SelectDisk);
+        assert!(wizard.data().selected_disk.is_none());
+
+        wizard.confirm_disk_selection();
+        assert_eq!(*wizard.state(), WizardState::ConfigureCluster);
+        assert_eq!(
+            wizard
+                .data()
+                .selected_disk
+                .as_ref()
+                .map(|d| d.dev_path.as_str()),
+            Some("/dev/sda")
+        );
+    }
+
+    #[test]
+    fn test_undersized_disk_is_refused() {
+        let mut wizard = WizardComponent::new("192.168.1.100".to_string());
+        let mut small = disk("/dev/sda", "naa.1");
+        small.size = 8 * GIB;
+        wizard.data_mut().disks = vec![small, disk("/dev/sdb", "naa.2")];
+        wizard.transition(WizardState::SelectDisk);
+
+        wizard.confirm_disk_selection();
+        assert_eq!(*wizard.state(), WizardState::SelectDisk);
+        assert!(wizard.undersized_selection().is_some());
+
+        wizard.select_next_disk();
+        wizard.confirm_disk_selection();
+        assert_eq!(*wizard.state(), WizardState::ConfigureCluster);
+
+        // The minimum is configurable
+        let mut wizard =
+            WizardComponent::new("192.168.1.100".to_string()).with_min_disk_size(4 * GIB);
+        let mut small = disk("/dev/sda", "naa.1");
+        small.size = 8 * GIB;
+        wizard.data_mut().disks = vec![small];
+        wizard.transition(WizardState::SelectDisk);
+        wizard.confirm_disk_selection();
+        assert_eq!(*wizard.state(), WizardState::ConfigureCluster);
+    }
+
+    #[test]
+    fn test_config_patches() {
+        let dir = tempfile::tempdir().unwrap();
+        let mirrors = dir.path().join("mirrors.yaml");
+        std::fs::write(
+            &mirrors,
+            "machine:\n  registries:\n    mirrors:\n      docker.io:\n        endpoints: [https://mirror.local]\n",
+        )
+        .unwrap();
+        let broken = dir.path().join("broken.yaml");
+        std::fs::write(&broken, "machine: [unclosed").unwrap();
+
+        let mut data = WizardData::new("192.168.1.100".to_string());
+        assert!(data.all_config_patches().is_empty());
+
+        data.config_patches = vec!["machine: {install: {extraKernelArgs: [quiet]}}".to_string()];
+        data.patch_files = format!("{}  @{}", mirrors.display(), mirrors.display());
+        assert!(validate_patch_files(&data.patch_files).is_ok());
+        assert_eq!(
+            data.all_config_patches(),
+            [
+                "machine: {install: {extraKernelArgs: [quiet]}}".to_string(),
+                format!("@{}", mirrors.display()),
+                format!("@{}", mirrors.display()),
+            ]
+        );
+
+        assert!(validate_patch_files(&broken.display().to_string()).is_err());
+        assert!(validate_patch_files("/nonexistent/patch.yaml").is_err());
+    }
+
+    #[test]
+    fn test_health_checklist_completes_when_all_done() {
+        let mut wizard = WizardComponent::new("192.168.1.100".to_string());
+        wizard.begin_wait(WizardState::WaitingHealthy);
+
+        let partial = HealthChecklist {
+            etcd: true,
+            api_server: true,
+            node_ready: false,
+        };
+        wizard.apply_health(partial, Some("0 of 1 nodes Ready".to_string()));
+        assert_eq!(*wizard.state(), WizardState::WaitingHealthy);
+        assert_eq!(wizard.data().health, partial);
+        assert_eq!(
+            wizard.data().last_poll_error.as_deref(),
+            Some("0 of 1 nodes Ready")
+        );
+
+        let done = HealthChecklist {
+            node_ready: true,
+            ..partial
+        };
+        wizard.apply_health(done, None);
+        assert_eq!(*wizard.state(), WizardState::Complete);
+
+        // A retry starts the checklist over
+        wizard.begin_wait(WizardState::WaitingHealthy);
+        assert_eq!(wizard.data().health, HealthChecklist::default());
+    }
+
+    #[test]
+    fn test_maintenance_candidates() {
+        assert_eq!(
+            maintenance_candidates("192.168.1.100"),
+            [
+                ("192.168.1.100:50000".to_string(), 50000),
+                ("192.168.1.100:50001".to_string(), 50001)
+            ]
+        );
+        assert_eq!(
+            maintenance_candidates("192.168.1.100:50001"),
+            [("192.168.1.100:50001".to_string(), 50001)]
+        );
+        assert_eq!(
+            maintenance_candidates("fd00::1")[1],
+            ("[fd00::1]:50001".to_string(), 50001)
+        );
+        assert_eq!(
+            maintenance_candidates("[fd00::1]:50000"),
+            [("[fd00::1]:50000".to_string(), 50000)]
+        );
+    }
+
+    #[test]
+    fn test_connect_failure_is_retryable() {
+        let mut wizard = WizardComponent::new("192.168.1.100".to_string());
+        wizard.apply_connect(Err("No response from 192.168.1.100 after 15s".to_string()));
+        assert!(matches!(wizard.state(), WizardState::Error(_)));
+
+        let retry = wizard.handle_key_event(KeyEvent::from(KeyCode::Char('r')));
+        assert!(matches!(retry, Ok(Some(Action::WizardRetry))));
+
+        // A late result must not pull the wizard out of its current state
+        wizard.apply_connect(Ok(NodeInventory::default()));
+        assert!(matches!(wizard.state(), WizardState::Error(_)));
+    }
+
+    #[test]
+    fn test_connect_resolves_system_disk() {
+        let mut wizard = WizardComponent::new("192.168.1.100".to_string());
+        assert_eq!(wizard.data().maintenance_endpoint(), "192.168.1.100");
+        wizard.apply_connect(Ok(NodeInventory {
+            endpoint: "192.168.1.100:50001".to_string(),
+            disks: vec![disk("/dev/sda", "naa.1"), disk("/dev/sdb", "naa.2")],
+            volumes: Vec::new(),
+            system_disk_id: Some("sdb".to_string()),
+        }));
+        assert_eq!(*wizard.state(), WizardState::SelectDisk);
+        assert!(wizard.data().connected);
+        assert_eq!(wizard.data().maintenance_endpoint(), "192.168.1.100:50001");
+        assert_eq!(
+            wizard
+                .data()
+                .system_disk
+                .as_ref()
+                .map(|d| d.wwid.as_deref()),
+            Some(Some("naa.2"))
+        );
+    }
+
+    #[test]
+    fn test_complete_hands_context_over() {
+        let mut wizard = WizardComponent::new("192.168.1.100".to_string());
+        wizard.data_mut().context_name = Some("talos-cluster".to_string());
+        wizard.transition(WizardState::Complete);
+
+        let open = wizard.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert!(matches!(
+            open,
+            Ok(Some(Action::WizardComplete(Some(ctx)))) if ctx == "talos-cluster"
+        ));
+        let quit = wizard.handle_key_event(KeyEvent::from(KeyCode::Char('q')));
+        assert!(matches!(quit, Ok(Some(Action::Quit))));
+    }
+
+    #[test]
+    fn test_dry_run_never_applies() {
+        let mut wizard = WizardComponent::new("192.168.1.100".to_string()).with_dry_run(true);
+        wizard.transition(WizardState::ConfigReady);
+
+        let apply = wizard.handle_key_event(KeyEvent::from(KeyCode::Char('a')));
+        assert!(matches!(apply, Ok(None)));
+        let exit = wizard.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert!(matches!(exit, Ok(Some(Action::Quit))));
+        assert_eq!(wizard.state(), &WizardState::ConfigReady);
+    }
 }
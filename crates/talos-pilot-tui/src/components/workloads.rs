@@ -8,8 +8,8 @@
 //! Philosophy: Show actual K8s API state. Group by namespace with issues first.
 
 use crate::action::Action;
-use crate::components::Component;
-use crate::ui_ext::HealthIndicatorExt;
+use crate::components::{Component, help_entries};
+use crate::ui_ext::{HealthIndicatorExt, empty_state, glyphs, manual_refresh};
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
@@ -28,7 +28,7 @@ use ratatui::{
 use std::collections::HashMap;
 use std::time::Duration;
 use talos_pilot_core::constants::HIGH_RESTART_THRESHOLD;
-use talos_pilot_core::{AsyncState, HasHealth, HealthIndicator};
+use talos_pilot_core::{AsyncState, ComponentError, HasHealth, HealthIndicator};
 
 /// Health state of a workload or pod
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -229,8 +229,13 @@ impl WorkloadHealthComponent {
         self.k8s_client = Some(client);
     }
 
+    /// Get the Kubernetes client (if connected)
+    pub fn k8s_client(&self) -> Option<&Client> {
+        self.k8s_client.as_ref()
+    }
+
     /// Set an error message
-    pub fn set_error(&mut self, error: String) {
+    pub fn set_error(&mut self, error: impl Into<ComponentError>) {
         self.state.set_error(error);
     }
 
@@ -735,6 +740,24 @@ impl WorkloadHealthComponent {
         }
     }
 
+    /// Pod logs target for the selection: the namespace, plus the pod when
+    /// a problem pod is selected in drill-down
+    fn pod_logs_target(&self) -> (Option<String>, Option<String>) {
+        let Some(ns) = self
+            .data()
+            .and_then(|d| d.namespaces.get(self.selected_namespace))
+        else {
+            return (None, None);
+        };
+        let pod = self
+            .drill_down
+            .then(|| self.selected_item.checked_sub(ns.workloads.len()))
+            .flatten()
+            .and_then(|i| ns.problem_pods.get(i))
+            .map(|p| p.name.clone());
+        (Some(ns.name.clone()), pod)
+    }
+
     /// Exit drill-down mode
     fn exit_drill_down(&mut self) {
         self.drill_down = false;
@@ -771,13 +794,13 @@ impl WorkloadHealthComponent {
             ),
             Span::raw(" DaemonSets    "),
             Span::styled(
-                format!("● {}", data.total_pods_healthy),
+                format!("{} {}", glyphs().filled, data.total_pods_healthy),
                 Style::default().fg(healthy_color),
             ),
             Span::raw(" healthy  "),
             if data.total_pods_degraded > 0 {
                 Span::styled(
-                    format!("◐ {}", data.total_pods_degraded),
+                    format!("{} {}", glyphs().partial, data.total_pods_degraded),
                     Style::default().fg(Color::Yellow),
                 )
             } else {
@@ -790,7 +813,7 @@ impl WorkloadHealthComponent {
             },
             if data.total_pods_failing > 0 {
                 Span::styled(
-                    format!("✗ {}", data.total_pods_failing),
+                    format!("{} {}", glyphs().cross, data.total_pods_failing),
                     Style::default().fg(Color::Red),
                 )
             } else {
@@ -1014,6 +1037,8 @@ impl WorkloadHealthComponent {
 
         let mut spans = nav_keys;
         spans.extend(vec![
+            Span::styled("[l]", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" pod logs  "),
             Span::styled("[r]", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(" refresh  "),
             Span::styled("[a]", Style::default().add_modifier(Modifier::BOLD)),
@@ -1028,6 +1053,17 @@ impl WorkloadHealthComponent {
 }
 
 impl Component for WorkloadHealthComponent {
+    fn key_help(&self) -> Vec<(String, String)> {
+        help_entries(&[
+            ("j/k ↑/↓", "Navigate"),
+            ("Enter", "Drill into workload"),
+            ("l", "Pod logs (selected pod or namespace)"),
+            ("a", "Toggle auto-refresh"),
+            ("r", "Refresh"),
+            ("q/Esc", "Back / exit drill-down"),
+        ])
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> Result<Option<Action>> {
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => {
@@ -1052,7 +1088,11 @@ impl Component for WorkloadHealthComponent {
                 }
                 Ok(None)
             }
-            KeyCode::Char('r') => Ok(Some(Action::Refresh)),
+            KeyCode::Char('l') if self.k8s_client.is_some() => {
+                let (namespace, pod) = self.pod_logs_target();
+                Ok(Some(Action::ShowPodLogs(namespace, pod)))
+            }
+            KeyCode::Char('r') => Ok(manual_refresh(&self.state)),
             KeyCode::Char('a') => {
                 self.auto_refresh = !self.auto_refresh;
                 Ok(None)
@@ -1099,9 +1139,7 @@ impl Component for WorkloadHealthComponent {
             .map(|d| !d.namespaces.is_empty())
             .unwrap_or(false);
         if !has_namespaces {
-            let empty =
-                Paragraph::new("No workloads found").style(Style::default().fg(Color::DarkGray));
-            frame.render_widget(empty, inner);
+            frame.render_widget(empty_state("No workloads found", None), inner);
             return Ok(());
         }
 
@@ -0,0 +1,422 @@
+//! Non-interactive modes
+//!
+//! One-shot operations that run without starting the TUI, intended for
+//! scripting and automation.
+
+use crate::components::diagnostics::{
+    CheckStatus, DiagnosticCheck, DiagnosticContext, DiagnosticsData, promote_endpoint,
+    run_diagnostics,
+};
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::PathBuf;
+use talos_pilot_core::metrics::{NodeNetworkMetrics, format_prometheus};
+use talos_rs::{
+    ConnectionCounts, NetstatFilter, ProxyConfig, TalosClient, TalosConfig,
+    get_discovery_members_with_retry,
+};
+
+/// Load the talosconfig and connect to the requested (or current) context
+///
+/// `skip_verify` connects without verifying the node's server certificate.
+pub async fn connect(
+    config_path: Option<&str>,
+    context: Option<&str>,
+    proxy: Option<&ProxyConfig>,
+    skip_verify: bool,
+) -> Result<(String, TalosClient)> {
+    // Install crypto provider (needed for rustls)
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    let config = match config_path {
+        Some(path) => TalosConfig::load_from(&PathBuf::from(path))?,
+        None => TalosConfig::load_default()?,
+    };
+    let context_name = context.unwrap_or(config.context.as_str()).to_string();
+    let ctx = config.get_context(&context_name)?;
+    let client = TalosClient::from_context_with_options(ctx, proxy, skip_verify)
+        .await
+        .map_err(|e| eyre!("Failed to connect to context '{}': {}", context_name, e))?;
+    Ok((context_name, client))
+}
+
+/// Fetch network metrics from all nodes in the context and render them
+/// in Prometheus text exposition format
+pub async fn dump_metrics(
+    config_path: Option<&str>,
+    context: Option<&str>,
+    proxy: Option<&ProxyConfig>,
+    skip_verify: bool,
+) -> Result<String> {
+    let (_, client) = connect(config_path, context, proxy, skip_verify).await?;
+
+    let (dev_result, conn_result) = tokio::join!(
+        client.network_device_stats(),
+        client.netstat(NetstatFilter::All)
+    );
+    let stats = dev_result?;
+    // Connection data is supplementary - export interface stats even if netstat fails
+    let connections = conn_result.unwrap_or_else(|e| {
+        tracing::warn!("Failed to fetch connections for metrics: {}", e);
+        Vec::new()
+    });
+
+    let counts: Vec<ConnectionCounts> = stats
+        .iter()
+        .map(|node| {
+            connections
+                .iter()
+                .find(|c| c.hostname == node.hostname)
+                .map(|c| c.count_by_state())
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let nodes: Vec<NodeNetworkMetrics<'_>> = stats
+        .iter()
+        .zip(&counts)
+        .map(|(node, connections)| NodeNetworkMetrics {
+            node: &node.hostname,
+            devices: &node.devices,
+            connections,
+        })
+        .collect();
+
+    Ok(format_prometheus(&nodes))
+}
+
+/// Result of a single diagnostic check in `--check` output
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckResult {
+    /// Check category (system, kubernetes, cni, services, addons)
+    pub category: &'static str,
+    pub id: String,
+    pub name: String,
+    pub status: CheckStatus,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+/// Diagnostics results for a single node in `--check` output
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeCheckReport {
+    pub hostname: String,
+    pub address: String,
+    pub role: String,
+    /// Set if diagnostics could not be completed for this node
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub checks: Vec<CheckResult>,
+}
+
+impl NodeCheckReport {
+    fn from_data(role: &str, data: DiagnosticsData, error: Option<String>) -> Self {
+        let categories: [(&'static str, Vec<DiagnosticCheck>); 5] = [
+            ("system", data.system_checks),
+            ("kubernetes", data.kubernetes_checks),
+            ("cni", data.cni_checks),
+            ("services", data.service_checks),
+            ("addons", data.addon_checks),
+        ];
+        let checks = categories
+            .into_iter()
+            .flat_map(|(category, checks)| {
+                checks.into_iter().map(move |c| CheckResult {
+                    category,
+                    id: c.id,
+                    name: c.name,
+                    status: c.status,
+                    message: c.message,
+                    details: c.details,
+                })
+            })
+            .collect();
+        Self {
+            hostname: data.hostname,
+            address: data.address,
+            role: role.to_string(),
+            error,
+            checks,
+        }
+    }
+
+    /// Whether this node has a failed check or could not be diagnosed
+    pub fn has_failures(&self) -> bool {
+        self.error.is_some() || self.checks.iter().any(|c| c.status == CheckStatus::Fail)
+    }
+}
+
+/// Run full diagnostics on every node in the context
+///
+/// Nodes are discovered via cluster membership (falling back to etcd members),
+/// and each node runs the same checks as the diagnostics view. `on_node` is
+/// called with each node's report as soon as it finishes, so callers can
+/// stream results instead of waiting for the whole cluster.
+pub async fn run_check(
+    config_path: Option<&str>,
+    context: Option<&str>,
+    proxy: Option<&ProxyConfig>,
+    skip_verify: bool,
+    mut on_node: impl FnMut(&NodeCheckReport),
+) -> Result<Vec<NodeCheckReport>> {
+    let (context_name, client) = connect(config_path, context, proxy, skip_verify).await?;
+
+    let etcd_members = client.etcd_members().await.unwrap_or_else(|e| {
+        tracing::warn!("Failed to fetch etcd members: {}", e);
+        Vec::new()
+    });
+    let controlplane_ips: Vec<String> =
+        etcd_members.iter().filter_map(|m| m.ip_address()).collect();
+
+    // (hostname, address, role)
+    let nodes: Vec<(String, String, String)> =
+        match get_discovery_members_with_retry(&context_name, config_path, &controlplane_ips).await
+        {
+            Ok(members) => members
+                .into_iter()
+                .filter_map(|m| {
+                    let ip = m.addresses.first()?.clone();
+                    Some((m.hostname, ip, m.machine_type))
+                })
+                .collect(),
+            Err(e) => {
+                tracing::warn!("Discovery failed, checking control planes only: {}", e);
+                etcd_members
+                    .iter()
+                    .filter_map(|m| {
+                        let ip = m.ip_address()?;
+                        Some((m.hostname.clone(), ip, "controlplane".to_string()))
+                    })
+                    .collect()
+            }
+        };
+
+    if nodes.is_empty() {
+        return Err(eyre!("No nodes found in context '{}'", context_name));
+    }
+
+    // Every control plane can serve the kubeconfig for worker checks
+    let mut controlplane_endpoints: Vec<String> = nodes
+        .iter()
+        .filter(|(_, _, role)| role == "controlplane")
+        .map(|(_, ip, _)| ip.clone())
+        .collect();
+    if controlplane_endpoints.is_empty() {
+        controlplane_endpoints = controlplane_ips;
+    }
+
+    let mut reports = Vec::with_capacity(nodes.len());
+    for (hostname, address, role) in nodes {
+        let mut diag_context = DiagnosticContext::new();
+        diag_context.node_role = role.clone();
+        diag_context.hostname = hostname.clone();
+        diag_context.node_endpoint = Some(address.clone());
+        let mut data = DiagnosticsData {
+            hostname,
+            address,
+            context: diag_context,
+            ..Default::default()
+        };
+
+        let cp_endpoints = if role == "controlplane" {
+            &[][..]
+        } else {
+            controlplane_endpoints.as_slice()
+        };
+        let node_client = client.with_node(&data.address);
+        // Headless checks always cover every category, regardless of TUI settings
+        let error = run_diagnostics(
+            &node_client,
+            &mut data,
+            cp_endpoints,
+            config_path,
+            &BTreeSet::new(),
+        )
+        .await
+        .err()
+        .map(|e| e.to_string());
+        promote_endpoint(
+            &mut controlplane_endpoints,
+            data.context.kubeconfig_endpoint.as_deref(),
+        );
+        let report = NodeCheckReport::from_data(&role, data, error);
+        on_node(&report);
+        reports.push(report);
+    }
+
+    Ok(reports)
+}
+
+/// Whether any node in the report has a failed check
+pub fn has_failures(reports: &[NodeCheckReport]) -> bool {
+    reports.iter().any(NodeCheckReport::has_failures)
+}
+
+fn status_label(status: &CheckStatus) -> &'static str {
+    match status {
+        CheckStatus::Pass => "PASS",
+        CheckStatus::Warn => "WARN",
+        CheckStatus::Fail => "FAIL",
+        CheckStatus::Unknown => "UNKNOWN",
+        CheckStatus::Checking => "CHECKING",
+    }
+}
+
+/// Render check results as a plain-text summary table
+pub fn format_check_table(reports: &[NodeCheckReport]) -> String {
+    let mut out = String::new();
+    let (mut pass, mut warn, mut fail) = (0, 0, 0);
+
+    for report in reports {
+        let _ = writeln!(
+            out,
+            "{} ({}, {})",
+            report.hostname, report.address, report.role
+        );
+        if let Some(error) = &report.error {
+            let _ = writeln!(out, "  ERROR     {}", error);
+        }
+        for check in &report.checks {
+            match check.status {
+                CheckStatus::Pass => pass += 1,
+                CheckStatus::Warn => warn += 1,
+                CheckStatus::Fail => fail += 1,
+                _ => {}
+            }
+            let _ = writeln!(
+                out,
+                "  {:<9} {:<11} {:<28} {}",
+                status_label(&check.status),
+                check.category,
+                check.name,
+                check.message
+            );
+        }
+        out.push('\n');
+    }
+
+    let _ = writeln!(
+        out,
+        "{} nodes: {} passed, {} warnings, {} failed",
+        reports.len(),
+        pass,
+        warn,
+        fail
+    );
+    out
+}
+
+/// Render check results as JSON
+pub fn format_check_json(reports: &[NodeCheckReport]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(reports)?)
+}
+
+/// One line of `--format ndjson` output: a check, or a node that errored
+#[derive(Serialize)]
+struct CheckLine<'a> {
+    hostname: &'a str,
+    address: &'a str,
+    role: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    #[serde(flatten)]
+    check: Option<&'a CheckResult>,
+}
+
+/// Render one node's results as JSON lines, one object per check
+///
+/// A node that could not be diagnosed gets an extra line carrying `error`.
+/// Every line ends with a newline.
+pub fn format_check_ndjson(report: &NodeCheckReport) -> Result<String> {
+    let line = |error, check| CheckLine {
+        hostname: &report.hostname,
+        address: &report.address,
+        role: &report.role,
+        error,
+        check,
+    };
+    let mut out = String::new();
+    if let Some(error) = &report.error {
+        out.push_str(&serde_json::to_string(&line(Some(error), None))?);
+        out.push('\n');
+    }
+    for check in &report.checks {
+        out.push_str(&serde_json::to_string(&line(None, Some(check)))?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(status: CheckStatus) -> NodeCheckReport {
+        NodeCheckReport {
+            hostname: "cp-1".to_string(),
+            address: "10.0.0.1".to_string(),
+            role: "controlplane".to_string(),
+            error: None,
+            checks: vec![CheckResult {
+                category: "system",
+                id: "memory".to_string(),
+                name: "Memory".to_string(),
+                status,
+                message: "1.0 GB / 4.0 GB".to_string(),
+                details: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_has_failures() {
+        assert!(!has_failures(&[report(CheckStatus::Pass)]));
+        assert!(!has_failures(&[report(CheckStatus::Warn)]));
+        assert!(has_failures(&[report(CheckStatus::Fail)]));
+
+        let mut errored = report(CheckStatus::Pass);
+        errored.error = Some("Timeout fetching diagnostics".to_string());
+        assert!(has_failures(&[errored]));
+    }
+
+    #[test]
+    fn test_format_check_table() {
+        let output = format_check_table(&[report(CheckStatus::Fail)]);
+        assert!(output.contains("cp-1 (10.0.0.1, controlplane)"));
+        assert!(output.contains("FAIL"));
+        assert!(output.contains("Memory"));
+        assert!(output.contains("1 nodes: 0 passed, 0 warnings, 1 failed"));
+    }
+
+    #[test]
+    fn test_format_check_json() {
+        let output = format_check_json(&[report(CheckStatus::Pass)]).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(value[0]["hostname"], "cp-1");
+        assert_eq!(value[0]["checks"][0]["status"], "Pass");
+        assert!(value[0].get("error").is_none());
+    }
+
+    #[test]
+    fn test_format_check_ndjson() {
+        let mut errored = report(CheckStatus::Fail);
+        errored.error = Some("Timeout fetching diagnostics".to_string());
+        let output = format_check_ndjson(&errored).unwrap();
+
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["error"], "Timeout fetching diagnostics");
+        assert!(lines[0].get("status").is_none());
+        assert_eq!(lines[1]["hostname"], "cp-1");
+        assert_eq!(lines[1]["category"], "system");
+        assert_eq!(lines[1]["status"], "Fail");
+        assert!(lines[1].get("error").is_none());
+    }
+}
@@ -0,0 +1,284 @@
+//! User key remapping
+//!
+//! `~/.talos-pilot/keymap.yaml` binds extra keys to logical actions:
+//!
+//! ```yaml
+//! search: ["f"]
+//! down: ["ctrl+n"]
+//! up: ["ctrl+k"]
+//! ```
+//!
+//! Components keep matching today's bindings. Before a key event reaches
+//! them, the app translates a remapped key into the default key of its
+//! action, so the defaults stay the built-in bindings and every view picks
+//! up a remapping without knowing about it. Keys are not translated while a
+//! view takes typed input (filters, search boxes). Keys the app handles
+//! globally (e.g. Ctrl+P for the node switcher) can't be rebound.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Keys the app handles before any view, which a remapping would shadow
+const RESERVED_KEYS: [(KeyCode, KeyModifiers); 5] = [
+    (KeyCode::Char('p'), KeyModifiers::CONTROL), // node switcher
+    (KeyCode::Char('t'), KeyModifiers::CONTROL), // log level
+    (KeyCode::Char('z'), KeyModifiers::CONTROL), // suspend to a shell
+    (KeyCode::Char('~'), KeyModifiers::NONE),    // log tail
+    (KeyCode::Char('R'), KeyModifiers::NONE),    // reconnect
+];
+
+/// A logical action that keys can be bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyAction {
+    Quit,
+    Back,
+    Help,
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    Select,
+    Toggle,
+    NextSection,
+    PrevSection,
+    Refresh,
+    Search,
+}
+
+impl KeyAction {
+    /// The built-in key for this action, which components match on
+    pub fn default_key(&self) -> KeyEvent {
+        let code = match self {
+            KeyAction::Quit => KeyCode::Char('q'),
+            KeyAction::Back => KeyCode::Esc,
+            KeyAction::Help => KeyCode::Char('?'),
+            KeyAction::Up => KeyCode::Up,
+            KeyAction::Down => KeyCode::Down,
+            KeyAction::Left => KeyCode::Left,
+            KeyAction::Right => KeyCode::Right,
+            KeyAction::PageUp => KeyCode::PageUp,
+            KeyAction::PageDown => KeyCode::PageDown,
+            KeyAction::Top => KeyCode::Char('g'),
+            KeyAction::Bottom => KeyCode::Char('G'),
+            KeyAction::Select => KeyCode::Enter,
+            KeyAction::Toggle => KeyCode::Char(' '),
+            KeyAction::NextSection => KeyCode::Tab,
+            KeyAction::PrevSection => KeyCode::BackTab,
+            KeyAction::Refresh => KeyCode::Char('r'),
+            KeyAction::Search => KeyCode::Char('/'),
+        };
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+}
+
+/// Parse a key such as `j`, `G`, `ctrl+n`, `alt+x`, `pagedown` or `shift+tab`
+pub fn parse_key(spec: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = spec;
+    while let Some((prefix, tail)) = rest.split_once('+') {
+        // "+" on its own (or "ctrl++") is the plus key, not a separator
+        if tail.is_empty() {
+            break;
+        }
+        modifiers |= match prefix.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "alt" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            _ => return None,
+        };
+        rest = tail;
+    }
+
+    let mut chars = rest.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match rest.to_ascii_lowercase().as_str() {
+            "esc" | "escape" => KeyCode::Esc,
+            "enter" | "return" => KeyCode::Enter,
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::BackTab
+            }
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            f => KeyCode::F(f.strip_prefix('f')?.parse().ok()?),
+        },
+    };
+    Some((code, modifiers))
+}
+
+/// Drop SHIFT from character keys, where it is already in the character
+fn normalize(code: KeyCode, modifiers: KeyModifiers) -> (KeyCode, KeyModifiers) {
+    match code {
+        KeyCode::Char(_) => (code, modifiers.difference(KeyModifiers::SHIFT)),
+        _ => (code, modifiers),
+    }
+}
+
+/// Remapped keys, translated to their action's default key
+#[derive(Debug, Clone, Default)]
+pub struct Keymap {
+    bindings: Vec<((KeyCode, KeyModifiers), KeyAction)>,
+}
+
+impl Keymap {
+    /// Path to the keymap file (~/.talos-pilot/keymap.yaml)
+    pub fn path() -> PathBuf {
+        let home = dirs_next::home_dir().unwrap_or_else(|| PathBuf::from("."));
+        home.join(".talos-pilot").join("keymap.yaml")
+    }
+
+    /// Load the keymap from the default location, falling back to no remapping
+    pub fn load() -> Self {
+        Self::load_from(&Self::path())
+    }
+
+    /// Load the keymap from a specific path, falling back to no remapping
+    ///
+    /// Unknown actions, keys that can't be parsed and reserved keys are
+    /// skipped with a warning; the rest of the file still applies.
+    pub fn load_from(path: &Path) -> Self {
+        let Ok(content) = fs::read_to_string(path) else {
+            return Self::default();
+        };
+        match serde_yaml::from_str::<BTreeMap<String, Vec<String>>>(&content) {
+            Ok(config) => Self::from_config(&config),
+            Err(e) => {
+                tracing::warn!("Failed to parse keymap {}: {}", path.display(), e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Build a keymap from action name -> keys entries
+    pub fn from_config(config: &BTreeMap<String, Vec<String>>) -> Self {
+        let mut bindings = Vec::new();
+        for (name, keys) in config {
+            let value = serde_yaml::Value::String(name.clone());
+            let Ok(action) = serde_yaml::from_value::<KeyAction>(value) else {
+                tracing::warn!("Ignoring unknown keymap action {:?}", name);
+                continue;
+            };
+            for spec in keys {
+                match parse_key(spec).map(|(code, modifiers)| normalize(code, modifiers)) {
+                    Some(key) if RESERVED_KEYS.contains(&key) => {
+                        tracing::warn!("Ignoring {:?} for {:?}: the key is reserved", spec, action)
+                    }
+                    Some(key) => bindings.push((key, action)),
+                    None => tracing::warn!("Ignoring unknown key {:?} for {:?}", spec, action),
+                }
+            }
+        }
+        Self { bindings }
+    }
+
+    /// The key components should see for `key`
+    ///
+    /// A remapped key becomes its action's default key; anything else is
+    /// passed through unchanged.
+    pub fn translate(&self, key: KeyEvent) -> KeyEvent {
+        let pressed = normalize(key.code, key.modifiers);
+        match self.bindings.iter().find(|(bound, _)| *bound == pressed) {
+            Some((_, action)) => KeyEvent {
+                kind: key.kind,
+                ..action.default_key()
+            },
+            None => key,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_key() {
+        assert_eq!(
+            parse_key("j"),
+            Some((KeyCode::Char('j'), KeyModifiers::NONE))
+        );
+        assert_eq!(
+            parse_key("ctrl+n"),
+            Some((KeyCode::Char('n'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            parse_key("shift+tab"),
+            Some((KeyCode::BackTab, KeyModifiers::NONE))
+        );
+        assert_eq!(
+            parse_key("PageDown"),
+            Some((KeyCode::PageDown, KeyModifiers::NONE))
+        );
+        assert_eq!(parse_key("f5"), Some((KeyCode::F(5), KeyModifiers::NONE)));
+        assert_eq!(
+            parse_key("+"),
+            Some((KeyCode::Char('+'), KeyModifiers::NONE))
+        );
+        assert_eq!(parse_key("hyper+x"), None);
+        assert_eq!(parse_key("nope"), None);
+    }
+
+    #[test]
+    fn test_translate_remapped_keys() {
+        let config: BTreeMap<String, Vec<String>> =
+            serde_yaml::from_str("search: [f]\ndown: [ctrl+n, bogus]\nbottom: [E]\n").unwrap();
+        let keymap = Keymap::from_config(&config);
+
+        let f = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE);
+        assert_eq!(keymap.translate(f).code, KeyCode::Char('/'));
+
+        let ctrl_n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL);
+        let down = keymap.translate(ctrl_n);
+        assert_eq!(
+            (down.code, down.modifiers),
+            (KeyCode::Down, KeyModifiers::NONE)
+        );
+
+        // Terminals report uppercase letters with SHIFT held
+        let shift_e = KeyEvent::new(KeyCode::Char('E'), KeyModifiers::SHIFT);
+        assert_eq!(keymap.translate(shift_e).code, KeyCode::Char('G'));
+
+        // Unbound keys pass through
+        let n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE);
+        assert_eq!(keymap.translate(n), n);
+    }
+
+    #[test]
+    fn test_load_skips_unknown_actions_and_reserved_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keymap.yaml");
+        fs::write(&path, "serch: [f]\nsearch: [x]\nup: [ctrl+p, ctrl+k]\n").unwrap();
+        let keymap = Keymap::load_from(&path);
+
+        // A typo in one action doesn't drop the others
+        let x = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE);
+        assert_eq!(keymap.translate(x).code, KeyCode::Char('/'));
+        let f = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE);
+        assert_eq!(keymap.translate(f), f);
+
+        // Ctrl+P stays with the node switcher
+        let ctrl_p = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.translate(ctrl_p), ctrl_p);
+        let ctrl_k = KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.translate(ctrl_k).code, KeyCode::Up);
+    }
+}
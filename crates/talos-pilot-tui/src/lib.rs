@@ -8,6 +8,10 @@ pub mod app;
 pub mod audit;
 pub mod clipboard;
 pub mod components;
+pub mod headless;
+pub mod keymap;
+pub mod state;
+pub mod transition;
 pub mod tui;
 pub mod ui_ext;
 
@@ -0,0 +1,458 @@
+//! Persistent UI state
+//!
+//! Small pieces of user state that should survive restarts (e.g. pinned nodes,
+//! disabled diagnostic categories, acknowledged checks) are stored in `~/.talos-pilot/state.yaml`.
+
+use crate::components::network::{DEFAULT_SMOOTHING_ALPHA, KeyPort};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use talos_pilot_core::{CheckCategory, CheckStatus};
+use talos_rs::ConnectionThresholds;
+
+/// Default base directory for generated configs (~/.talos-pilot/clusters)
+pub fn default_config_dir() -> String {
+    let home = dirs_next::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    home.join(".talos-pilot")
+        .join("clusters")
+        .display()
+        .to_string()
+}
+
+/// Deserialize `key_ports`, skipping malformed entries with a warning
+/// instead of failing the whole state file
+fn lenient_key_ports<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<KeyPort>, D::Error> {
+    let entries = Vec::<serde_yaml::Value>::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| match serde_yaml::from_value(entry) {
+            Ok(key_port) => Some(key_port),
+            Err(e) => {
+                tracing::warn!("Ignoring key_ports entry in state file: {}", e);
+                None
+            }
+        })
+        .collect())
+}
+
+/// Deserialize `smoothing_alpha`, dropping anything but a number in (0, 1]
+/// with a warning instead of failing the whole state file
+fn checked_smoothing_alpha<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<f64>, D::Error> {
+    let value = Option::<serde_yaml::Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|value| match value.as_f64() {
+        Some(alpha) if alpha > 0.0 && alpha <= 1.0 => Some(alpha),
+        _ => {
+            tracing::warn!(
+                "Ignoring smoothing_alpha in state file, it must be a number above 0 and at most 1"
+            );
+            None
+        }
+    }))
+}
+
+/// State persisted between sessions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiState {
+    /// Pinned node names, keyed by context name
+    pub pinned_nodes: BTreeMap<String, BTreeSet<String>>,
+    /// Diagnostic categories that are skipped and hidden
+    pub disabled_diagnostics: BTreeSet<CheckCategory>,
+    /// Acknowledged diagnostic checks and the status they were acknowledged
+    /// at, keyed by context name, node hostname and check id
+    pub acknowledged_checks: BTreeMap<String, BTreeMap<String, BTreeMap<String, CheckStatus>>>,
+    /// TIME_WAIT count above which the network view warns
+    pub time_wait_warn: Option<usize>,
+    /// CLOSE_WAIT count above which the network view warns
+    pub close_wait_warn: Option<usize>,
+    /// Extra ports for the network view's service health row
+    #[serde(deserialize_with = "lenient_key_ports")]
+    pub key_ports: Vec<KeyPort>,
+    /// Weight of the newest sample in smoothed network rates (0 < alpha <= 1)
+    #[serde(deserialize_with = "checked_smoothing_alpha")]
+    pub smoothing_alpha: Option<f64>,
+    /// Directory generated configs go under, one subdirectory per cluster
+    pub config_output_dir: Option<String>,
+    /// Why the state file couldn't be parsed, if it couldn't
+    ///
+    /// The file also holds hand-edited settings, so it is never saved over
+    /// while this is set.
+    #[serde(skip)]
+    load_error: Option<String>,
+}
+
+impl UiState {
+    /// Path to the state file (~/.talos-pilot/state.yaml)
+    pub fn path() -> PathBuf {
+        let home = dirs_next::home_dir().unwrap_or_else(|| PathBuf::from("."));
+        home.join(".talos-pilot").join("state.yaml")
+    }
+
+    /// Load state from the default location, falling back to empty state
+    pub fn load() -> Self {
+        Self::load_from(&Self::path())
+    }
+
+    /// Load state from a specific path, falling back to empty state
+    ///
+    /// A file that fails to parse also gives empty state, but is left alone
+    /// by [`Self::save_to`] until it is fixed.
+    pub fn load_from(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(content) => serde_yaml::from_str(&content).unwrap_or_else(|e| {
+                tracing::warn!("Failed to parse state file {}: {}", path.display(), e);
+                Self {
+                    load_error: Some(e.to_string()),
+                    ..Self::default()
+                }
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Why the state file couldn't be parsed, if it couldn't
+    pub fn load_error(&self) -> Option<&str> {
+        self.load_error.as_deref()
+    }
+
+    /// Save state to the default location
+    pub fn save(&self) {
+        self.save_to(&Self::path());
+    }
+
+    /// Save state to a specific path, creating the parent directory if needed
+    ///
+    /// Does nothing if the file failed to parse when loaded, so a typo in a
+    /// hand-edited setting doesn't wipe the rest of the file.
+    pub fn save_to(&self, path: &Path) {
+        if let Some(e) = &self.load_error {
+            tracing::warn!(
+                "Not saving state to {}, it failed to parse: {}",
+                path.display(),
+                e
+            );
+            return;
+        }
+        if let Some(parent) = path.parent()
+            && let Err(e) = fs::create_dir_all(parent)
+        {
+            tracing::warn!("Failed to create state directory: {}", e);
+            return;
+        }
+        match serde_yaml::to_string(self) {
+            Ok(content) => {
+                if let Err(e) = fs::write(path, content) {
+                    tracing::warn!("Failed to write state file {}: {}", path.display(), e);
+                }
+            }
+            Err(e) => tracing::warn!("Failed to serialize state: {}", e),
+        }
+    }
+
+    /// Check if a node is pinned in the given context
+    pub fn is_pinned(&self, context: &str, node: &str) -> bool {
+        self.pinned_nodes
+            .get(context)
+            .is_some_and(|nodes| nodes.contains(node))
+    }
+
+    /// Toggle the pinned state of a node, returning the new state
+    pub fn toggle_pin(&mut self, context: &str, node: &str) -> bool {
+        let nodes = self.pinned_nodes.entry(context.to_string()).or_default();
+        let pinned = if nodes.remove(node) {
+            false
+        } else {
+            nodes.insert(node.to_string());
+            true
+        };
+        if nodes.is_empty() {
+            self.pinned_nodes.remove(context);
+        }
+        pinned
+    }
+
+    /// Network warning thresholds, with command-line values taking
+    /// precedence over the state file and the built-in defaults
+    pub fn connection_thresholds(
+        &self,
+        time_wait: Option<usize>,
+        close_wait: Option<usize>,
+    ) -> ConnectionThresholds {
+        let defaults = ConnectionThresholds::default();
+        ConnectionThresholds {
+            time_wait: time_wait
+                .or(self.time_wait_warn)
+                .unwrap_or(defaults.time_wait),
+            close_wait: close_wait
+                .or(self.close_wait_warn)
+                .unwrap_or(defaults.close_wait),
+        }
+    }
+
+    /// Base directory for generated configs, with the command-line value
+    /// taking precedence over the state file and the built-in default
+    pub fn config_output_dir(&self, output_dir: Option<String>) -> String {
+        output_dir
+            .or_else(|| self.config_output_dir.clone())
+            .unwrap_or_else(default_config_dir)
+    }
+
+    /// Smoothing weight for network rates, ignoring values outside (0, 1]
+    pub fn smoothing_alpha(&self) -> f64 {
+        self.smoothing_alpha
+            .filter(|a| *a > 0.0 && *a <= 1.0)
+            .unwrap_or(DEFAULT_SMOOTHING_ALPHA)
+    }
+
+    /// Toggle a diagnostic category, returning whether it is now enabled
+    pub fn toggle_diagnostic_category(&mut self, category: CheckCategory) -> bool {
+        if self.disabled_diagnostics.remove(&category) {
+            true
+        } else {
+            self.disabled_diagnostics.insert(category);
+            false
+        }
+    }
+
+    /// Acknowledged check ids on a node and the status each was acknowledged at
+    pub fn acknowledged_for(&self, context: &str, node: &str) -> BTreeMap<String, CheckStatus> {
+        self.acknowledged_checks
+            .get(context)
+            .and_then(|nodes| nodes.get(node))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Toggle the acknowledgement of a check on a node at its current
+    /// status, returning whether it is now acknowledged
+    pub fn toggle_acknowledged(
+        &mut self,
+        context: &str,
+        node: &str,
+        check_id: &str,
+        status: &CheckStatus,
+    ) -> bool {
+        let nodes = self
+            .acknowledged_checks
+            .entry(context.to_string())
+            .or_default();
+        let checks = nodes.entry(node.to_string()).or_default();
+        let acknowledged = if checks.get(check_id) == Some(status) {
+            checks.remove(check_id);
+            false
+        } else {
+            checks.insert(check_id.to_string(), status.clone());
+            true
+        };
+        if checks.is_empty() {
+            nodes.remove(node);
+        }
+        if nodes.is_empty() {
+            self.acknowledged_checks.remove(context);
+        }
+        acknowledged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::network::key_ports;
+
+    #[test]
+    fn test_toggle_pin() {
+        let mut state = UiState::default();
+        assert!(state.toggle_pin("prod", "worker-1"));
+        assert!(state.is_pinned("prod", "worker-1"));
+        assert!(!state.is_pinned("staging", "worker-1"));
+        assert!(!state.toggle_pin("prod", "worker-1"));
+        assert!(!state.is_pinned("prod", "worker-1"));
+        assert!(state.pinned_nodes.is_empty());
+    }
+
+    #[test]
+    fn test_state_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.yaml");
+        let mut state = UiState::default();
+        state.toggle_pin("prod", "cp-1");
+        state.save_to(&path);
+        assert_eq!(UiState::load_from(&path), state);
+    }
+
+    #[test]
+    fn test_toggle_diagnostic_category_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.yaml");
+        let mut state = UiState::default();
+        assert!(!state.toggle_diagnostic_category(CheckCategory::Cni));
+        assert!(!state.toggle_diagnostic_category(CheckCategory::Addons));
+        assert!(state.toggle_diagnostic_category(CheckCategory::Addons));
+        state.save_to(&path);
+
+        let loaded = UiState::load_from(&path);
+        assert_eq!(
+            loaded.disabled_diagnostics.into_iter().collect::<Vec<_>>(),
+            [CheckCategory::Cni]
+        );
+    }
+
+    #[test]
+    fn test_acknowledged_check_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.yaml");
+        let mut state = UiState::default();
+        assert!(state.toggle_acknowledged("lab", "cp-1", "volume_encryption", &CheckStatus::Warn));
+        state.save_to(&path);
+
+        let mut loaded = UiState::load_from(&path);
+        assert_eq!(
+            loaded.acknowledged_for("lab", "cp-1"),
+            BTreeMap::from([("volume_encryption".to_string(), CheckStatus::Warn)])
+        );
+        // Other nodes and contexts are unaffected
+        assert!(loaded.acknowledged_for("lab", "cp-2").is_empty());
+        assert!(loaded.acknowledged_for("prod", "cp-1").is_empty());
+
+        assert!(!loaded.toggle_acknowledged(
+            "lab",
+            "cp-1",
+            "volume_encryption",
+            &CheckStatus::Warn
+        ));
+        assert!(loaded.acknowledged_checks.is_empty());
+    }
+
+    #[test]
+    fn test_connection_thresholds_precedence() {
+        let mut state = UiState::default();
+        assert_eq!(
+            state.connection_thresholds(None, None),
+            ConnectionThresholds::default()
+        );
+
+        state.time_wait_warn = Some(2000);
+        let thresholds = state.connection_thresholds(None, Some(3));
+        assert_eq!(thresholds.time_wait, 2000);
+        assert_eq!(thresholds.close_wait, 3);
+        assert_eq!(state.connection_thresholds(Some(50), None).time_wait, 50);
+    }
+
+    #[test]
+    fn test_config_output_dir_precedence() {
+        let mut state = UiState::default();
+        assert_eq!(state.config_output_dir(None), default_config_dir());
+        state.config_output_dir = Some("/srv/talos".to_string());
+        assert_eq!(state.config_output_dir(None), "/srv/talos");
+        assert_eq!(state.config_output_dir(Some("out".to_string())), "out");
+    }
+
+    #[test]
+    fn test_smoothing_alpha_bounds() {
+        let mut state = UiState::default();
+        assert_eq!(state.smoothing_alpha(), DEFAULT_SMOOTHING_ALPHA);
+        state.smoothing_alpha = Some(0.5);
+        assert_eq!(state.smoothing_alpha(), 0.5);
+        state.smoothing_alpha = Some(0.0);
+        assert_eq!(state.smoothing_alpha(), DEFAULT_SMOOTHING_ALPHA);
+        state.smoothing_alpha = Some(1.5);
+        assert_eq!(state.smoothing_alpha(), DEFAULT_SMOOTHING_ALPHA);
+    }
+
+    #[test]
+    fn test_out_of_range_smoothing_alpha_is_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.yaml");
+        for (alpha, expected) in [
+            ("0.2", Some(0.2)),
+            ("1", Some(1.0)),
+            ("1.5", None),
+            ("fast", None),
+        ] {
+            std::fs::write(
+                &path,
+                format!("time_wait_warn: 2000\nsmoothing_alpha: {}\n", alpha),
+            )
+            .unwrap();
+
+            let state = UiState::load_from(&path);
+            assert!(state.load_error().is_none());
+            assert_eq!(state.time_wait_warn, Some(2000));
+            assert_eq!(state.smoothing_alpha, expected, "{}", alpha);
+        }
+    }
+
+    #[test]
+    fn test_key_ports_from_state_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.yaml");
+        std::fs::write(
+            &path,
+            "key_ports:\n  - { label: Cilium, port: 9879 }\n  - { label: api, port: 443 }\n",
+        )
+        .unwrap();
+
+        let ports = key_ports(&UiState::load_from(&path).key_ports);
+        let labels: Vec<_> = ports
+            .iter()
+            .map(|k| format!("{}:{}", k.label, k.port))
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                "API:443",
+                "Etcd:2379",
+                "Kubelet:10250",
+                "Scheduler:10259",
+                "Controller:10257",
+                "Cilium:9879"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_malformed_key_port_is_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.yaml");
+        std::fs::write(
+            &path,
+            "time_wait_warn: 2000\nkey_ports:\n  - { label: Big, port: 70000 }\n  - { label: Cilium, port: 9879 }\n",
+        )
+        .unwrap();
+
+        let state = UiState::load_from(&path);
+        assert!(state.load_error().is_none());
+        assert_eq!(state.time_wait_warn, Some(2000));
+        assert_eq!(
+            state.key_ports,
+            [KeyPort {
+                label: "Cilium".to_string(),
+                port: 9879
+            }]
+        );
+    }
+
+    #[test]
+    fn test_unparseable_file_is_not_overwritten() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.yaml");
+        let content = "time_wait_warn: lots\nconfig_output_dir: /srv/talos\n";
+        std::fs::write(&path, content).unwrap();
+
+        let mut state = UiState::load_from(&path);
+        assert!(state.load_error().is_some());
+        assert_eq!(state.config_output_dir, None);
+        state.toggle_pin("prod", "cp-1");
+        state.save_to(&path);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), content);
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let path = Path::new("/nonexistent/talos-pilot/state.yaml");
+        assert_eq!(UiState::load_from(path), UiState::default());
+    }
+}
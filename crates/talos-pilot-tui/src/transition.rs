@@ -0,0 +1,64 @@
+//! Transitions between views
+//!
+//! When the app switches views, the incoming view is drawn as usual and a
+//! short tachyonfx effect is rendered over it until the effect is done.
+
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use std::time::{Duration, Instant};
+use tachyonfx::{Effect, EffectRenderer, Interpolation, Motion, Shader, fx};
+
+/// Length of a view transition, in milliseconds
+const TRANSITION_MS: u32 = 250;
+
+/// Redraw interval while a transition plays (the normal tick is too coarse)
+pub const TRANSITION_FRAME: Duration = Duration::from_millis(16);
+
+/// Effect played when the view changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransitionStyle {
+    /// Fade the new view's text in
+    #[default]
+    Fade,
+    /// Slide the new view in from the right
+    Slide,
+}
+
+impl TransitionStyle {
+    fn effect(&self) -> Effect {
+        let timer = (TRANSITION_MS, Interpolation::QuadOut);
+        match self {
+            TransitionStyle::Fade => fx::fade_from_fg(Color::Black, timer),
+            TransitionStyle::Slide => fx::slide_in(Motion::RightToLeft, 12, 0, Color::Reset, timer),
+        }
+    }
+}
+
+/// A transition in progress
+pub struct Transition {
+    effect: Effect,
+    last_frame: Instant,
+}
+
+impl Transition {
+    pub fn new(style: TransitionStyle) -> Self {
+        Self {
+            effect: style.effect(),
+            last_frame: Instant::now(),
+        }
+    }
+
+    /// Render the effect over `area`, advanced by the time since the last frame
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_frame);
+        self.last_frame = now;
+        frame.render_effect(&mut self.effect, area, elapsed);
+    }
+
+    /// Whether the effect has played out
+    pub fn is_done(&self) -> bool {
+        self.effect.done()
+    }
+}
@@ -1,14 +1,26 @@
 //! Terminal UI wrapper
 //!
-//! Handles terminal setup, restoration, and the main render loop.
+//! Handles terminal setup, restoration, and the main render loop, plus
+//! handing the terminal to external programs (shell, editor) and back.
 
-use color_eyre::Result;
+use color_eyre::{Result, eyre::eyre};
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
-use std::io::{self, Stdout};
+use std::io::{self, Stdout, Write};
+use std::process::Command;
+use tempfile::NamedTempFile;
+
+/// Editor used when neither `$VISUAL` nor `$EDITOR` is set
+const DEFAULT_EDITOR: &str = "vi";
+
+/// Shell used when `$SHELL` is not set
+#[cfg(unix)]
+const DEFAULT_SHELL: &str = "/bin/sh";
+#[cfg(not(unix))]
+const DEFAULT_SHELL: &str = "cmd.exe";
 
 /// Terminal wrapper type
 pub type Tui = Terminal<CrosstermBackend<Stdout>>;
@@ -30,6 +42,103 @@ pub fn restore() -> Result<()> {
     Ok(())
 }
 
+/// Hand the terminal to another program while `f` runs
+///
+/// The TUI is reinstated even if `f` fails or leaving raw mode did, so a
+/// broken external command cannot leave the terminal unusable. The screen is
+/// cleared afterwards so the next draw repaints everything.
+pub fn suspend<T>(tui: &mut Tui, f: impl FnOnce() -> T) -> Result<T> {
+    if let Err(e) = restore() {
+        tracing::warn!("Failed to restore terminal before suspending: {}", e);
+    }
+    let result = f();
+    resume(tui)?;
+    Ok(result)
+}
+
+/// Re-enter raw mode and the alternate screen after `suspend`
+fn resume(tui: &mut Tui) -> Result<()> {
+    terminal::enable_raw_mode()?;
+    crossterm::execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    tui.clear()?;
+    Ok(())
+}
+
+/// Run `command` on the terminal, failing if it cannot start or exits non-zero
+pub fn run_suspended(tui: &mut Tui, command: &mut Command) -> Result<()> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let status = suspend(tui, || command.status())?
+        .map_err(|e| eyre!("Failed to run {}: {}", program, e))?;
+    if !status.success() {
+        return Err(eyre!("{} exited with {}", program, status));
+    }
+    Ok(())
+}
+
+/// Drop to the user's shell until it exits (Ctrl+Z)
+pub fn shell(tui: &mut Tui) -> Result<()> {
+    let shell = std::env::var("SHELL")
+        .ok()
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| DEFAULT_SHELL.to_string());
+    // The shell's exit status is whatever the user ran last, not a failure
+    suspend(tui, || {
+        println!("talos-pilot is suspended, type `exit` to return");
+        Command::new(&shell).status()
+    })?
+    .map_err(|e| eyre!("Failed to run {}: {}", shell, e))?;
+    Ok(())
+}
+
+/// Open `text` in the user's editor and return what was saved
+///
+/// The temporary file is only readable by the current user, since it may
+/// hold secrets (machine configs carry cluster CA keys), and is removed
+/// when this returns. `file_name` sets the extension editors use for
+/// syntax highlighting.
+pub fn edit_text(tui: &mut Tui, text: &str, file_name: &str) -> Result<String> {
+    let file = private_temp_file(text, file_name)?;
+    let visual = std::env::var("VISUAL").ok();
+    let editor = std::env::var("EDITOR").ok();
+    let args = editor_command(visual.as_deref(), editor.as_deref());
+    run_suspended(
+        tui,
+        Command::new(&args[0]).args(&args[1..]).arg(file.path()),
+    )?;
+    Ok(std::fs::read_to_string(file.path())?)
+}
+
+/// Command line for the user's editor, from `$VISUAL` then `$EDITOR`
+///
+/// The variable may carry arguments (e.g. `code --wait`).
+fn editor_command(visual: Option<&str>, editor: Option<&str>) -> Vec<String> {
+    [visual, editor]
+        .into_iter()
+        .flatten()
+        .map(|cmd| {
+            cmd.split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .find(|args| !args.is_empty())
+        .unwrap_or_else(|| vec![DEFAULT_EDITOR.to_string()])
+}
+
+/// Create a uniquely named temp file holding `text`
+///
+/// The file is created exclusively with mode 0600, so nothing planted in
+/// the temp directory beforehand can be followed or read, and it is
+/// deleted when the returned handle is dropped.
+fn private_temp_file(text: &str, file_name: &str) -> Result<NamedTempFile> {
+    let mut file = tempfile::Builder::new()
+        .prefix("talos-pilot-")
+        .suffix(&format!("-{}", file_name))
+        .tempfile()?;
+    file.write_all(text.as_bytes())?;
+    file.flush()?;
+    Ok(file)
+}
+
 /// Install panic hook to restore terminal on panic
 pub fn install_panic_hook() {
     let original_hook = std::panic::take_hook();
@@ -38,3 +147,35 @@ pub fn install_panic_hook() {
         original_hook(panic_info);
     }));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editor_command() {
+        assert_eq!(editor_command(None, None), ["vi"]);
+        assert_eq!(editor_command(None, Some("nano")), ["nano"]);
+        assert_eq!(
+            editor_command(Some("code --wait"), Some("nano")),
+            ["code", "--wait"]
+        );
+        assert_eq!(editor_command(Some("  "), Some("hx")), ["hx"]);
+    }
+
+    #[test]
+    fn test_private_temp_file() {
+        let file = private_temp_file("machine: {}\n", "config.yaml").unwrap();
+        let path = file.path().to_path_buf();
+        assert!(path.to_string_lossy().ends_with("-config.yaml"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "machine: {}\n");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o077, 0);
+        }
+        drop(file);
+        assert!(!path.exists());
+    }
+}
@@ -3,10 +3,161 @@
 //! Provides ratatui-specific extensions for core types, bridging the gap
 //! between the UI-agnostic core library and the TUI presentation layer.
 
-use ratatui::style::Color;
+use crate::action::Action;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph, Wrap};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 use talos_pilot_core::{
-    CheckStatus, ConnectionState, HasHealth, HealthIndicator, QuorumState, SafetyStatus,
+    AsyncState, CheckStatus, ComponentError, ConnectionState, HasHealth, HealthIndicator,
+    QuorumState, SafetyStatus,
 };
+use talos_rs::TalosError;
+
+/// Symbols drawn by the TUI that need more than plain ASCII
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyphs {
+    /// Healthy / active / selected marker
+    pub filled: &'static str,
+    /// Inactive / unreachable marker
+    pub hollow: &'static str,
+    /// Degraded / partial marker
+    pub partial: &'static str,
+    /// Success tick
+    pub check: &'static str,
+    /// Failure cross
+    pub cross: &'static str,
+    /// Warning sign
+    pub warning: &'static str,
+    /// Check still running
+    pub pending: &'static str,
+    /// List bullet
+    pub bullet: &'static str,
+    /// Celebration banner prefix
+    pub celebrate: &'static str,
+    /// Status improved since the last refresh
+    pub up: &'static str,
+    /// Status worsened since the last refresh
+    pub down: &'static str,
+    /// Pinned node marker
+    pub pin: &'static str,
+    /// Node marked for comparison
+    pub compare: &'static str,
+    /// Expanded tree section
+    pub expanded: &'static str,
+    /// Collapsed tree section
+    pub collapsed: &'static str,
+    /// Cursor on the selected row
+    pub selector: &'static str,
+    /// Spinner animation frames
+    pub spinner: &'static [&'static str],
+}
+
+/// Default glyph set for UTF-8 terminals
+pub const UNICODE_GLYPHS: Glyphs = Glyphs {
+    filled: "●",
+    hollow: "○",
+    partial: "◐",
+    check: "✓",
+    cross: "✗",
+    warning: "⚠",
+    pending: "◌",
+    bullet: "•",
+    celebrate: "🎉",
+    up: "↑",
+    down: "↓",
+    pin: "★",
+    compare: "⇄",
+    expanded: "▼",
+    collapsed: "▶",
+    selector: "▸",
+    spinner: &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+};
+
+/// Fallback glyph set for terminals/locales without Unicode support
+pub const ASCII_GLYPHS: Glyphs = Glyphs {
+    filled: "*",
+    hollow: "o",
+    partial: "~",
+    check: "+",
+    cross: "x",
+    warning: "!",
+    pending: ".",
+    bullet: "-",
+    celebrate: "[OK]",
+    up: "^",
+    down: "v",
+    pin: "*",
+    compare: "<>",
+    expanded: "v",
+    collapsed: ">",
+    selector: ">",
+    spinner: &["|", "/", "-", "\\"],
+};
+
+static ASCII_MODE: AtomicBool = AtomicBool::new(false);
+
+impl Glyphs {
+    /// Map a symbol from talos-pilot-core (e.g. `HealthIndicator::symbol`)
+    /// onto this glyph set
+    pub fn translate(&self, symbol: &'static str) -> &'static str {
+        match symbol {
+            "●" => self.filled,
+            "○" => self.hollow,
+            "◐" => self.partial,
+            "✓" => self.check,
+            "✗" => self.cross,
+            "⚠" => self.warning,
+            "◌" => self.pending,
+            other => other,
+        }
+    }
+}
+
+/// The glyph set every component should draw with
+pub fn glyphs() -> &'static Glyphs {
+    if ASCII_MODE.load(Ordering::Relaxed) {
+        &ASCII_GLYPHS
+    } else {
+        &UNICODE_GLYPHS
+    }
+}
+
+/// Select the glyph set: ASCII when forced (`--ascii`) or when the
+/// locale does not advertise UTF-8
+pub fn init_glyphs(force_ascii: bool) {
+    let env = |key: &str| std::env::var(key).ok();
+    let unicode = locale_supports_unicode(
+        env("LC_ALL").as_deref(),
+        env("LC_CTYPE").as_deref(),
+        env("LANG").as_deref(),
+    );
+    ASCII_MODE.store(force_ascii || !unicode, Ordering::Relaxed);
+}
+
+/// Whether the effective locale is UTF-8
+///
+/// Follows POSIX precedence (`LC_ALL`, then `LC_CTYPE`, then `LANG`). With
+/// none of them set (typical on Windows) Unicode is assumed.
+pub fn locale_supports_unicode(
+    lc_all: Option<&str>,
+    lc_ctype: Option<&str>,
+    lang: Option<&str>,
+) -> bool {
+    let Some(locale) = [lc_all, lc_ctype, lang]
+        .into_iter()
+        .flatten()
+        .find(|v| !v.is_empty())
+    else {
+        return true;
+    };
+    let locale = locale.to_ascii_lowercase();
+    locale.contains("utf-8") || locale.contains("utf8")
+}
 
 /// Extension trait for HealthIndicator to provide ratatui colors
 pub trait HealthIndicatorExt {
@@ -30,7 +181,7 @@ impl HealthIndicatorExt for HealthIndicator {
     }
 
     fn symbol_and_color(&self) -> (&'static str, Color) {
-        (self.symbol(), self.color())
+        (glyphs().translate(self.symbol()), self.color())
     }
 }
 
@@ -47,7 +198,7 @@ impl QuorumStateExt for QuorumState {
     fn indicator_with_color(&self) -> (&'static str, Color) {
         use talos_pilot_core::HasHealth;
         let health = self.health();
-        (health.symbol(), health.color())
+        (glyphs().translate(health.symbol()), health.color())
     }
 
     fn display_with_color(&self) -> (&'static str, Color) {
@@ -72,7 +223,7 @@ impl SafetyStatusExt for SafetyStatus {
     fn indicator_with_color(&self) -> (&'static str, Color) {
         use talos_pilot_core::HasHealth;
         let health = self.health();
-        (health.symbol(), health.color())
+        (glyphs().translate(health.symbol()), health.color())
     }
 }
 
@@ -96,7 +247,7 @@ impl ConnectionStateExt for ConnectionState {
     }
 
     fn symbol_and_color(&self) -> (&'static str, Color) {
-        (self.symbol(), self.color())
+        (glyphs().translate(self.symbol()), self.color())
     }
 }
 
@@ -111,15 +262,354 @@ impl CheckStatusExt for CheckStatus {
         let health = self.health();
         (
             if matches!(self, CheckStatus::Checking) {
-                "◌" // Special spinner symbol for checking state
+                glyphs().pending
             } else {
-                health.symbol()
+                glyphs().translate(health.symbol())
             },
             health.color(),
         )
     }
 }
 
+/// Extension trait for ComponentError to style error messages
+pub trait ComponentErrorExt {
+    /// Yellow for errors that should clear up by themselves, red otherwise
+    fn color(&self) -> Color;
+
+    /// Error text noting whether the view retries on its own
+    fn describe(&self, auto_refresh: bool) -> String;
+}
+
+impl ComponentErrorExt for ComponentError {
+    fn color(&self) -> Color {
+        if self.is_transient() {
+            Color::Yellow
+        } else {
+            Color::Red
+        }
+    }
+
+    fn describe(&self, auto_refresh: bool) -> String {
+        if self.is_fatal() {
+            format!("{} (auto-refresh paused, press r to retry)", self.message())
+        } else if self.is_transient() && auto_refresh {
+            format!("{} (retrying)", self.message())
+        } else {
+            self.message().to_string()
+        }
+    }
+}
+
+/// Categorize an error returned by a component's refresh
+///
+/// Refreshes return `color_eyre` reports; when one wraps a [`TalosError`]
+/// its kind is kept, anything else becomes [`ComponentError::Other`].
+pub fn report_error(report: &color_eyre::Report) -> ComponentError {
+    report
+        .chain()
+        .find_map(|e| e.downcast_ref::<TalosError>())
+        .map(ComponentError::from)
+        .unwrap_or_else(|| ComponentError::Other(report.to_string()))
+}
+
+/// Action for a refresh the user asked for (`r`)
+///
+/// Nothing while a load is in flight. Within [`MANUAL_REFRESH_DEBOUNCE`] of
+/// the last refresh the key is answered with a toast rather than dropped
+/// silently, so it doesn't look ignored.
+///
+/// [`MANUAL_REFRESH_DEBOUNCE`]: talos_pilot_core::MANUAL_REFRESH_DEBOUNCE
+pub fn manual_refresh<T>(state: &AsyncState<T>) -> Option<Action> {
+    if state.is_loading() {
+        None
+    } else if state.can_refresh() {
+        Some(Action::Refresh)
+    } else {
+        Some(Action::Notify(
+            "Refreshed just now".to_string(),
+            NotifyLevel::Info,
+        ))
+    }
+}
+
+/// At-a-glance identity of a node, shown at the top of node views
+///
+/// Built from what the cluster overview has already fetched, so opening a
+/// view costs no extra API calls. Anything the overview has not seen yet
+/// stays `None` and is left out of the header.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NodeInfo {
+    pub hostname: String,
+    pub address: String,
+    /// "controlplane" or "worker"
+    pub role: Option<String>,
+    /// Talos version, e.g. "v1.9.1"
+    pub version: Option<String>,
+    /// Platform name, e.g. "metal" or "aws"
+    pub platform: Option<String>,
+    /// Boot time as seconds since the Unix epoch
+    pub boot_time: Option<u64>,
+}
+
+impl NodeInfo {
+    /// Node info with only the identity known
+    pub fn new(hostname: impl Into<String>, address: impl Into<String>) -> Self {
+        Self {
+            hostname: hostname.into(),
+            address: address.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Time since boot at `now` (seconds since the Unix epoch)
+    pub fn uptime_secs(&self, now: u64) -> Option<u64> {
+        self.boot_time
+            .filter(|&boot| boot > 0)
+            .map(|boot| now.saturating_sub(boot))
+    }
+
+    /// Role, version, platform and uptime joined for display, e.g.
+    /// "controlplane · v1.9.1 · metal · up 3d"
+    pub fn summary(&self, now: u64) -> String {
+        let uptime = self.uptime_secs(now).map(|secs| {
+            format!(
+                "up {}",
+                talos_pilot_core::formatting::format_duration_human(chrono::Duration::seconds(
+                    secs as i64
+                ))
+            )
+        });
+        [
+            self.role.clone(),
+            self.version.clone(),
+            self.platform.clone(),
+            uptime,
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" · ")
+    }
+}
+
+/// Header spans for a node view: `hostname (address)` followed by the
+/// node's summary, so every view shows the same context
+pub fn node_info_spans(info: &NodeInfo) -> Vec<Span<'static>> {
+    let mut spans = vec![
+        Span::raw(info.hostname.clone()),
+        Span::styled(" (", Style::default().fg(Color::DarkGray)),
+        Span::raw(info.address.clone()),
+        Span::styled(")", Style::default().fg(Color::DarkGray)),
+    ];
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let summary = info.summary(now);
+    if !summary.is_empty() {
+        spans.push(Span::styled(
+            format!("  {}", summary),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    spans
+}
+
+/// Refresh intervals after which a view's data is flagged as stale
+const STALE_WARN_INTERVALS: u32 = 3;
+/// Refresh intervals after which stale data is flagged red
+const STALE_ALERT_INTERVALS: u32 = 10;
+
+/// Color for data last refreshed `age` ago in a view refreshing every
+/// `interval`: gray while current, yellow once it has missed a few
+/// refreshes, red once it has missed many
+pub fn staleness_color(age: Duration, interval: Duration) -> Color {
+    if age >= interval * STALE_ALERT_INTERVALS {
+        Color::Red
+    } else if age >= interval * STALE_WARN_INTERVALS {
+        Color::Yellow
+    } else {
+        Color::DarkGray
+    }
+}
+
+/// Header span such as "updated 12s ago", colored by [`staleness_color`]
+///
+/// Tables keep showing the last good data when refreshes fail or hang, so
+/// this is what tells the user it is no longer live.
+pub fn last_updated_span(age: Duration, interval: Duration) -> Span<'static> {
+    let ago =
+        talos_pilot_core::format_duration_human(chrono::Duration::seconds(age.as_secs() as i64));
+    Span::styled(
+        format!("updated {} ago", ago),
+        Style::default().fg(staleness_color(age, interval)),
+    )
+}
+
+/// Severity of a toast notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotifyLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl NotifyLevel {
+    pub fn color(&self) -> Color {
+        match self {
+            NotifyLevel::Info => Color::Cyan,
+            NotifyLevel::Success => Color::Green,
+            NotifyLevel::Warning => Color::Yellow,
+            NotifyLevel::Error => Color::Red,
+        }
+    }
+}
+
+/// How long a toast stays up
+pub const TOAST_DURATION: Duration = Duration::from_secs(3);
+/// Final stretch of a toast's life over which it fades out
+const TOAST_FADE: Duration = Duration::from_millis(800);
+/// Toasts shown at once; the oldest gives way when another arrives
+const MAX_TOASTS: usize = 3;
+
+#[derive(Debug, Clone)]
+struct Toast {
+    message: String,
+    level: NotifyLevel,
+    shown_at: Instant,
+}
+
+/// Queue of transient messages ("Copied!", "Saved to ...") drawn in the
+/// bottom-right corner without taking focus
+#[derive(Debug, Clone, Default)]
+pub struct Toasts {
+    queue: VecDeque<Toast>,
+}
+
+impl Toasts {
+    /// Show a message for [`TOAST_DURATION`]
+    pub fn push(&mut self, message: impl Into<String>, level: NotifyLevel) {
+        self.push_at(message, level, Instant::now());
+    }
+
+    fn push_at(&mut self, message: impl Into<String>, level: NotifyLevel, now: Instant) {
+        if self.queue.len() == MAX_TOASTS {
+            self.queue.pop_front();
+        }
+        self.queue.push_back(Toast {
+            message: message.into(),
+            level,
+            shown_at: now,
+        });
+    }
+
+    /// Drop toasts that have been up for their full duration
+    pub fn prune(&mut self, now: Instant) {
+        self.queue
+            .retain(|t| now.saturating_duration_since(t.shown_at) < TOAST_DURATION);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Style of a toast `age` into its life, dimming as it fades out
+    fn style(level: NotifyLevel, age: Duration) -> Style {
+        let remaining = TOAST_DURATION.saturating_sub(age);
+        if remaining > TOAST_FADE {
+            Style::default().fg(Color::Black).bg(level.color())
+        } else if remaining > TOAST_FADE / 2 {
+            Style::default().fg(level.color())
+        } else {
+            Style::default().fg(Color::DarkGray)
+        }
+    }
+
+    /// Draw the live toasts stacked above the bottom line of `area`
+    /// (where views keep their key hints), newest lowest
+    pub fn draw(&self, frame: &mut Frame, area: Rect, now: Instant) {
+        let live = self
+            .queue
+            .iter()
+            .filter(|t| now.saturating_duration_since(t.shown_at) < TOAST_DURATION);
+        let mut y = area.bottom().saturating_sub(1);
+        for toast in live.rev() {
+            if y <= area.y {
+                break;
+            }
+            y -= 1;
+            let text = format!(" {} ", toast.message);
+            let width = (text.chars().count() as u16).min(area.width);
+            let corner = Rect::new(area.right().saturating_sub(width), y, width, 1);
+            let style = Self::style(toast.level, now.saturating_duration_since(toast.shown_at));
+            frame.render_widget(Clear, corner);
+            frame.render_widget(Paragraph::new(Span::styled(text, style)), corner);
+        }
+    }
+}
+
+/// Message for a view that loaded but has nothing to show
+///
+/// Drawn instead of an empty table so a blank area isn't mistaken for a hung
+/// load. `reason` goes on a second line when the cause is known.
+pub fn empty_state(message: &str, reason: Option<&str>) -> Paragraph<'static> {
+    let mut lines = vec![Line::from(Span::styled(
+        message.to_string(),
+        Style::default().fg(Color::DarkGray),
+    ))];
+    if let Some(reason) = reason {
+        lines.push(Line::from(Span::styled(
+            reason.to_string(),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    Paragraph::new(lines).wrap(Wrap { trim: true })
+}
+
+/// Widths a table gives its columns in `width` cells
+///
+/// `width` is the table's inner width, minus the highlight symbol if it has
+/// one. Uses ratatui's default column spacing of 1.
+pub fn column_widths(constraints: &[Constraint], width: u16) -> Vec<u16> {
+    Layout::horizontal(constraints.iter().copied())
+        .spacing(1)
+        .split(Rect::new(0, 0, width, 1))
+        .iter()
+        .map(|r| r.width)
+        .collect()
+}
+
+/// Wrap a table row's cells to their column widths so nothing is truncated
+///
+/// Returns the wrapped cell texts and the row height they need.
+pub fn wrap_row(values: Vec<String>, widths: &[u16]) -> (Vec<String>, u16) {
+    let wrapped: Vec<String> = values
+        .into_iter()
+        .zip(widths.iter().copied().chain(std::iter::repeat(0)))
+        .map(|(value, width)| {
+            let chars: Vec<char> = value.chars().collect();
+            if width == 0 || chars.len() <= width as usize {
+                return value;
+            }
+            chars
+                .chunks(width as usize)
+                .map(|line| line.iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect();
+    let height = wrapped
+        .iter()
+        .map(|v| v.lines().count())
+        .max()
+        .unwrap_or(1)
+        .max(1);
+    (wrapped, height as u16)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,6 +629,138 @@ mod tests {
         assert_eq!(color, Color::Green);
     }
 
+    #[test]
+    fn test_toasts_expire_and_fade() {
+        let start = Instant::now();
+        let mut toasts = Toasts::default();
+        for i in 0..=MAX_TOASTS {
+            toasts.push_at(format!("toast {}", i), NotifyLevel::Info, start);
+        }
+        // The oldest gives way
+        assert_eq!(toasts.queue.len(), MAX_TOASTS);
+        assert_eq!(toasts.queue[0].message, "toast 1");
+
+        let fresh = Toasts::style(NotifyLevel::Success, Duration::ZERO);
+        assert_eq!(fresh.bg, Some(Color::Green));
+        let fading = Toasts::style(NotifyLevel::Success, TOAST_DURATION - TOAST_FADE / 4);
+        assert_eq!((fading.fg, fading.bg), (Some(Color::DarkGray), None));
+
+        toasts.prune(start + TOAST_DURATION);
+        assert!(toasts.is_empty());
+    }
+
+    #[test]
+    fn test_column_widths() {
+        let widths = column_widths(&[Constraint::Length(6), Constraint::Min(4)], 20);
+        assert_eq!(widths, vec![6, 13]);
+    }
+
+    #[test]
+    fn test_wrap_row() {
+        let (cells, height) = wrap_row(
+            vec!["tcp".to_string(), "10.244.12.130:43512".to_string()],
+            &[6, 8],
+        );
+        assert_eq!(cells, vec!["tcp", "10.244.1\n2.130:43\n512"]);
+        assert_eq!(height, 3);
+
+        let (cells, height) = wrap_row(vec!["short".to_string()], &[8]);
+        assert_eq!(cells, vec!["short"]);
+        assert_eq!(height, 1);
+    }
+
+    #[test]
+    fn test_component_error_describe() {
+        let timeout = ComponentError::timeout(10);
+        assert_eq!(timeout.color(), Color::Yellow);
+        assert_eq!(
+            timeout.describe(true),
+            "Request timed out after 10s (retrying)"
+        );
+        assert_eq!(timeout.describe(false), "Request timed out after 10s");
+
+        let auth = ComponentError::Auth("Authentication failed".to_string());
+        assert_eq!(auth.color(), Color::Red);
+        assert!(auth.describe(true).contains("auto-refresh paused"));
+    }
+
+    #[test]
+    fn test_report_error_keeps_talos_error_kind() {
+        let report = color_eyre::Report::new(TalosError::Connection("timeout".to_string()));
+        assert!(matches!(report_error(&report), ComponentError::Timeout(_)));
+
+        let report = color_eyre::Report::new(TalosError::Tls("bad certificate".to_string()))
+            .wrap_err("Failed to fetch processes");
+        assert!(matches!(report_error(&report), ComponentError::Auth(_)));
+
+        let report = color_eyre::eyre::eyre!("No client configured");
+        assert_eq!(
+            report_error(&report),
+            ComponentError::Other("No client configured".to_string())
+        );
+    }
+
+    #[test]
+    fn test_locale_supports_unicode() {
+        assert!(locale_supports_unicode(None, None, Some("en_US.UTF-8")));
+        assert!(locale_supports_unicode(None, Some("de_DE.utf8"), Some("C")));
+        assert!(!locale_supports_unicode(
+            Some("C"),
+            None,
+            Some("en_US.UTF-8")
+        ));
+        assert!(!locale_supports_unicode(
+            None,
+            None,
+            Some("en_US.ISO-8859-1")
+        ));
+        assert!(!locale_supports_unicode(Some(""), None, Some("POSIX")));
+        assert!(locale_supports_unicode(None, None, None));
+    }
+
+    #[test]
+    fn test_manual_refresh() {
+        let mut state: AsyncState<i32> = AsyncState::idle();
+        assert!(matches!(manual_refresh(&state), Some(Action::Refresh)));
+        state.start_loading();
+        assert!(manual_refresh(&state).is_none());
+        state.set_data(42);
+        assert!(matches!(
+            manual_refresh(&state),
+            Some(Action::Notify(_, NotifyLevel::Info))
+        ));
+    }
+
+    #[test]
+    fn test_ascii_glyphs_are_ascii() {
+        let g = ASCII_GLYPHS;
+        for s in [
+            g.filled,
+            g.hollow,
+            g.partial,
+            g.check,
+            g.cross,
+            g.warning,
+            g.pending,
+            g.bullet,
+            g.celebrate,
+            g.up,
+            g.down,
+            g.pin,
+            g.compare,
+            g.expanded,
+            g.collapsed,
+            g.selector,
+        ]
+        .iter()
+        .chain(g.spinner)
+        {
+            assert!(s.is_ascii(), "{s:?} is not ASCII");
+        }
+        assert_eq!(g.translate(HealthIndicator::Healthy.symbol()), "*");
+        assert_eq!(g.translate("?"), "?");
+    }
+
     #[test]
     fn test_quorum_state_colors() {
         let (_, color) = QuorumState::Healthy.display_with_color();
@@ -151,4 +773,44 @@ mod tests {
         .display_with_color();
         assert_eq!(color, Color::Red);
     }
+
+    #[test]
+    fn test_node_info_summary() {
+        let mut info = NodeInfo::new("cp-1", "10.0.0.10");
+        assert_eq!(info.summary(1_000_000), "");
+
+        info.role = Some("controlplane".to_string());
+        info.version = Some("v1.9.1".to_string());
+        info.platform = Some("metal".to_string());
+        info.boot_time = Some(1_000_000 - 3 * 86400 - 3600);
+        assert_eq!(
+            info.summary(1_000_000),
+            "controlplane · v1.9.1 · metal · up 3d"
+        );
+
+        // A node that did not report its boot time shows no uptime
+        info.boot_time = Some(0);
+        assert_eq!(info.uptime_secs(1_000_000), None);
+    }
+
+    #[test]
+    fn test_staleness_color() {
+        let interval = Duration::from_secs(10);
+        assert_eq!(
+            staleness_color(Duration::from_secs(5), interval),
+            Color::DarkGray
+        );
+        assert_eq!(
+            staleness_color(Duration::from_secs(30), interval),
+            Color::Yellow
+        );
+        assert_eq!(
+            staleness_color(Duration::from_secs(100), interval),
+            Color::Red
+        );
+        assert_eq!(
+            last_updated_span(Duration::from_secs(125), interval).content,
+            "updated 2m ago"
+        );
+    }
 }
@@ -2,11 +2,22 @@
 //!
 //! Handles certificate loading and TLS configuration for connecting to Talos nodes.
 
-use crate::config::Context;
+use crate::config::{Context, normalize_endpoint};
 use crate::error::TalosError;
-use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use crate::proxy::ProxyConfig;
+use hyper_util::rt::TokioIo;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, SignatureScheme};
 use std::sync::Arc;
-use tonic::transport::{Certificate, Channel, ClientTlsConfig, Identity};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tonic::transport::{Channel, Uri};
+
+/// How long to wait for each endpoint of a multi-endpoint context before trying the next
+const ENDPOINT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Convert Ed25519 private key PEM to standard PKCS8 format
 /// Talos uses "ED25519 PRIVATE KEY" header but tonic/rustls expects "PRIVATE KEY"
@@ -30,11 +41,35 @@ fn convert_ed25519_key_to_pkcs8(pem: &[u8]) -> Vec<u8> {
 
 /// Create a TLS-enabled gRPC channel from a talosconfig context
 pub async fn create_channel(ctx: &Context) -> Result<Channel, TalosError> {
-    let endpoint_url = ctx
-        .endpoint_url()
-        .ok_or_else(|| TalosError::ConfigInvalid("No endpoints configured".to_string()))?;
+    create_channel_with_proxy(ctx, None).await
+}
+
+/// Create a TLS-enabled gRPC channel, optionally tunneled through a proxy
+pub async fn create_channel_with_proxy(
+    ctx: &Context,
+    proxy: Option<&ProxyConfig>,
+) -> Result<Channel, TalosError> {
+    create_channel_with_options(ctx, proxy, false).await
+}
 
-    tracing::debug!("Connecting to endpoint: {}", endpoint_url);
+/// Create a TLS-enabled gRPC channel, optionally tunneled through a proxy
+///
+/// With `skip_verify`, the server certificate is accepted without checking it
+/// against the context CA or the endpoint hostname. The client certificate is
+/// still presented, so the node still authenticates us. Only meant for lab
+/// clusters whose CA is not in the talosconfig.
+///
+/// The channel starts on the first endpoint that accepts a connection and
+/// fails over to the others once it stops accepting connections.
+pub async fn create_channel_with_options(
+    ctx: &Context,
+    proxy: Option<&ProxyConfig>,
+    skip_verify: bool,
+) -> Result<Channel, TalosError> {
+    let endpoint_urls = ctx.endpoint_urls();
+    let active = select_endpoint(&endpoint_urls, proxy).await?;
+
+    tracing::debug!("Connecting to endpoint: {}", endpoint_urls[active]);
 
     // Decode certificates from base64
     let ca_pem = ctx.ca_pem()?;
@@ -46,30 +81,329 @@ pub async fn create_channel(ctx: &Context) -> Result<Channel, TalosError> {
     tracing::debug!("Client key size: {} bytes", client_key_pem.len());
 
     // Convert Ed25519 key header to standard PKCS8 format if needed
-    // Tonic expects "PRIVATE KEY" not "ED25519 PRIVATE KEY"
+    // rustls-pemfile expects "PRIVATE KEY" not "ED25519 PRIVATE KEY"
     let client_key_pem = convert_ed25519_key_to_pkcs8(&client_key_pem);
 
-    // Create TLS config
-    let ca = Certificate::from_pem(&ca_pem);
-    let identity = Identity::from_pem(&client_cert_pem, &client_key_pem);
+    if skip_verify {
+        let identity = (
+            parse_certificates(&client_cert_pem)?,
+            parse_private_key(&client_key_pem)?,
+        );
+        return create_unverified_channel(&endpoint_urls, active, Some(identity), proxy);
+    }
+
+    let config = build_rustls_config(&ca_pem, &client_cert_pem, &client_key_pem)?;
+    create_tls_channel(&endpoint_urls, active, (*config).clone(), proxy)
+}
+
+/// Pick the first endpoint that accepts a connection, in context order,
+/// returning its index
+///
+/// A single endpoint is returned without probing, so connection errors
+/// surface on the first request as before.
+async fn select_endpoint(
+    endpoint_urls: &[String],
+    proxy: Option<&ProxyConfig>,
+) -> Result<usize, TalosError> {
+    match endpoint_urls {
+        [] => Err(TalosError::ConfigInvalid(
+            "No endpoints configured".to_string(),
+        )),
+        [_] => Ok(0),
+        _ => {
+            let mut failures = Vec::new();
+            for (idx, url) in endpoint_urls.iter().enumerate() {
+                match probe_endpoint(url, proxy).await {
+                    Ok(()) => {
+                        tracing::info!(
+                            "Using endpoint {} ({} of {})",
+                            url,
+                            idx + 1,
+                            endpoint_urls.len()
+                        );
+                        return Ok(idx);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Endpoint {} unreachable: {}", url, e);
+                        failures.push(format!("{}: {}", url, e));
+                    }
+                }
+            }
+            Err(TalosError::Connection(format!(
+                "No endpoint responded ({})",
+                failures.join("; ")
+            )))
+        }
+    }
+}
+
+/// Check that an endpoint accepts TCP connections within the timeout
+async fn probe_endpoint(url: &str, proxy: Option<&ProxyConfig>) -> std::io::Result<()> {
+    let uri: Uri = url
+        .parse()
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("{}", e)))?;
+    let (host, port) = uri_host_port(&uri)?;
+    connect_endpoint(&host, port, proxy, Some(ENDPOINT_CONNECT_TIMEOUT))
+        .await
+        .map(drop)
+}
 
-    let tls_config = ClientTlsConfig::new().ca_certificate(ca).identity(identity);
+/// Open a TCP connection to an endpoint, directly or through the proxy,
+/// giving up after `timeout` if one is given
+async fn connect_endpoint(
+    host: &str,
+    port: u16,
+    proxy: Option<&ProxyConfig>,
+    timeout: Option<Duration>,
+) -> std::io::Result<TcpStream> {
+    let connect = async {
+        match proxy {
+            Some(proxy) => proxy.connect(host, port).await,
+            None => TcpStream::connect((host, port)).await,
+        }
+    };
+    let Some(timeout) = timeout else {
+        return connect.await;
+    };
+    match tokio::time::timeout(timeout, connect).await {
+        Ok(stream) => stream,
+        Err(_) => Err(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            format!("no response within {}s", timeout.as_secs()),
+        )),
+    }
+}
 
-    // Build the channel (use connect_lazy to defer TLS handshake)
-    let endpoint = Channel::from_shared(endpoint_url.clone())
-        .map_err(|e| {
-            TalosError::Connection(format!("Invalid endpoint URL '{}': {}", endpoint_url, e))
+/// Host (without IPv6 brackets) and port of a channel URI
+fn uri_host_port(uri: &Uri) -> std::io::Result<(String, u16)> {
+    let host = uri
+        .host()
+        .ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Endpoint '{}' has no host", uri),
+            )
         })?
-        .tls_config(tls_config)
-        .map_err(|e| TalosError::Tls(format!("TLS config error: {:?}", e)))?;
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+    Ok((host, uri.port_u16().unwrap_or(50000)))
+}
+
+/// Opens the connections of a channel, failing over between endpoints
+///
+/// tonic calls the connector again whenever the channel has to reconnect,
+/// so once the endpoint in use stops accepting connections, the next call
+/// moves on to the next endpoint that does. Endpoints are tried in context
+/// order, starting from the one that worked last.
+#[derive(Clone)]
+struct FailoverConnector {
+    /// Host and port of each endpoint, in context order
+    endpoints: Arc<[(String, u16)]>,
+    /// Index of the endpoint that accepted the last connection
+    active: Arc<AtomicUsize>,
+    proxy: Option<ProxyConfig>,
+}
+
+impl FailoverConnector {
+    fn new(
+        endpoint_urls: &[String],
+        active: usize,
+        proxy: Option<&ProxyConfig>,
+    ) -> Result<Self, TalosError> {
+        let endpoints = endpoint_urls
+            .iter()
+            .map(|url| {
+                let uri: Uri = url.parse().map_err(|e| {
+                    TalosError::Connection(format!("Invalid endpoint URL '{}': {}", url, e))
+                })?;
+                uri_host_port(&uri).map_err(TalosError::Io)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            endpoints: endpoints.into(),
+            active: Arc::new(AtomicUsize::new(active)),
+            proxy: proxy.cloned(),
+        })
+    }
+
+    /// Connect to the active endpoint, or failing that the first of the
+    /// others that accepts, returning the host connected to
+    async fn connect_tcp(&self) -> std::io::Result<(String, TcpStream)> {
+        let count = self.endpoints.len();
+        let start = self.active.load(Ordering::Relaxed);
+        // A lone endpoint has nothing to fail over to, so keep the OS timeout
+        let timeout = (count > 1).then_some(ENDPOINT_CONNECT_TIMEOUT);
+        let mut failures = Vec::new();
+        for idx in (start..count).chain(0..start) {
+            let (host, port) = &self.endpoints[idx];
+            match connect_endpoint(host, *port, self.proxy.as_ref(), timeout).await {
+                Ok(stream) => {
+                    if idx != start {
+                        tracing::warn!(
+                            "Switched to endpoint {}:{} ({} of {})",
+                            host,
+                            port,
+                            idx + 1,
+                            count
+                        );
+                        self.active.store(idx, Ordering::Relaxed);
+                    }
+                    return Ok((host.clone(), stream));
+                }
+                Err(e) if count > 1 => {
+                    tracing::warn!("Endpoint {}:{} unreachable: {}", host, port, e);
+                    failures.push(format!("{}:{}: {}", host, port, e));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(std::io::Error::new(
+            std::io::ErrorKind::NotConnected,
+            format!("No endpoint responded ({})", failures.join("; ")),
+        ))
+    }
+}
+
+/// Create a channel to a node in maintenance mode, optionally through a proxy
+///
+/// A node without a machine config serves the maintenance API with a
+/// self-signed certificate and accepts no client certificate, so neither
+/// side is verified. A configured node rejects the handshake.
+pub fn create_maintenance_channel(
+    endpoint: &str,
+    proxy: Option<&ProxyConfig>,
+) -> Result<Channel, TalosError> {
+    create_unverified_channel(&[normalize_endpoint(endpoint)], 0, None, proxy)
+}
 
-    // Use connect_lazy - connection happens on first request
-    let channel = endpoint.connect_lazy();
+/// Create a channel that accepts any server certificate, presenting the
+/// client identity if one is given
+fn create_unverified_channel(
+    endpoint_urls: &[String],
+    active: usize,
+    identity: Option<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)>,
+    proxy: Option<&ProxyConfig>,
+) -> Result<Channel, TalosError> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let builder = rustls::ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| TalosError::Tls(format!("TLS config error: {}", e)))?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(AcceptAnyServerCert(provider)));
+    let config = match identity {
+        Some((client_certs, client_key)) => {
+            tracing::warn!(
+                "TLS certificate verification is DISABLED for {}",
+                endpoint_urls.join(", ")
+            );
+            builder
+                .with_client_auth_cert(client_certs, client_key)
+                .map_err(|e| TalosError::Tls(format!("Failed to configure client auth: {}", e)))?
+        }
+        None => builder.with_no_client_auth(),
+    };
+    create_tls_channel(endpoint_urls, active, config, proxy)
+}
+
+/// Create a lazily connecting channel that does the TLS handshake with
+/// `config` on top of a [`FailoverConnector`]
+///
+/// The handshake is done in the connector rather than by tonic, so it is
+/// checked against the endpoint actually connected to, and so it can skip
+/// verification, which tonic's `ClientTlsConfig` cannot. tonic is handed a
+/// plain `http://` URI. Through a proxy, TLS is still end-to-end with the
+/// Talos node.
+fn create_tls_channel(
+    endpoint_urls: &[String],
+    active: usize,
+    mut config: rustls::ClientConfig,
+    proxy: Option<&ProxyConfig>,
+) -> Result<Channel, TalosError> {
+    // gRPC requires HTTP/2 to be negotiated via ALPN
+    config.alpn_protocols = vec![b"h2".to_vec()];
+    let tls = tokio_rustls::TlsConnector::from(Arc::new(config));
+
+    let endpoint_url = &endpoint_urls[active];
+    let plain_url = match endpoint_url.strip_prefix("https://") {
+        Some(rest) => format!("http://{}", rest),
+        None => endpoint_url.to_string(),
+    };
+    let endpoint = Channel::from_shared(plain_url).map_err(|e| {
+        TalosError::Connection(format!("Invalid endpoint URL '{}': {}", endpoint_url, e))
+    })?;
+
+    if let Some(proxy) = proxy {
+        tracing::debug!("Routing connection through proxy {}", proxy);
+    }
+    let connector = FailoverConnector::new(endpoint_urls, active, proxy)?;
+    let channel = endpoint.connect_with_connector_lazy(tower::service_fn(move |_: Uri| {
+        let connector = connector.clone();
+        let tls = tls.clone();
+        async move {
+            let (host, tcp) = connector.connect_tcp().await?;
+            let server_name = ServerName::try_from(host).map_err(|e| {
+                std::io::Error::new(std::io::ErrorKind::InvalidInput, e.to_string())
+            })?;
+            tls.connect(server_name, tcp).await.map(TokioIo::new)
+        }
+    }));
 
-    tracing::debug!("Successfully connected to {}", endpoint_url);
     Ok(channel)
 }
 
+/// Server certificate verifier that accepts any certificate chain and name
+///
+/// Handshake signatures are still checked, so the server must hold the key
+/// for the certificate it presents.
+#[derive(Debug)]
+struct AcceptAnyServerCert(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptAnyServerCert {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
 /// Parse PEM-encoded certificates into rustls types
 pub fn parse_certificates(pem_data: &[u8]) -> Result<Vec<CertificateDer<'static>>, TalosError> {
     let mut reader = std::io::BufReader::new(pem_data);
@@ -120,10 +454,14 @@ pub fn build_rustls_config(
     let client_key = parse_private_key(client_key_pem)?;
 
     // Build client config
-    let config = rustls::ClientConfig::builder()
-        .with_root_certificates(root_store)
-        .with_client_auth_cert(client_certs, client_key)
-        .map_err(|e| TalosError::Tls(format!("Failed to configure client auth: {}", e)))?;
+    let config = rustls::ClientConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()
+    .map_err(|e| TalosError::Tls(format!("TLS config error: {}", e)))?
+    .with_root_certificates(root_store)
+    .with_client_auth_cert(client_certs, client_key)
+    .map_err(|e| TalosError::Tls(format!("Failed to configure client auth: {}", e)))?;
 
     Ok(Arc::new(config))
 }
@@ -132,6 +470,54 @@ pub fn build_rustls_config(
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_select_endpoint_skips_unreachable() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let live = format!("https://{}", listener.local_addr().unwrap());
+
+        // Bind and drop to get a port nothing is listening on
+        let dead_port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let dead = format!("https://127.0.0.1:{}", dead_port);
+
+        let chosen = select_endpoint(&[dead.clone(), live], None).await.unwrap();
+        assert_eq!(chosen, 1);
+
+        assert!(select_endpoint(&[dead.clone(), dead], None).await.is_err());
+        assert!(select_endpoint(&[], None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_connector_fails_over_when_endpoint_stops_accepting() {
+        let first = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let second = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let second_addr = second.local_addr().unwrap();
+        let urls = [
+            format!("https://{}", first.local_addr().unwrap()),
+            format!("https://{}", second_addr),
+        ];
+
+        let connector = FailoverConnector::new(&urls, 0, None).unwrap();
+        let (_, stream) = connector.connect_tcp().await.unwrap();
+        assert_eq!(stream.peer_addr().unwrap(), first.local_addr().unwrap());
+
+        // The first endpoint goes away after the channel was built
+        drop(first);
+        let (host, stream) = connector.connect_tcp().await.unwrap();
+        assert_eq!(host, "127.0.0.1");
+        assert_eq!(stream.peer_addr().unwrap(), second_addr);
+
+        // Later connections stay on the endpoint that works
+        let (_, stream) = connector.connect_tcp().await.unwrap();
+        assert_eq!(stream.peer_addr().unwrap(), second_addr);
+
+        drop(second);
+        assert!(connector.connect_tcp().await.is_err());
+    }
+
     #[test]
     fn test_parse_empty_pem() {
         let result = parse_certificates(b"");
@@ -2,15 +2,20 @@
 //!
 //! Provides a convenient interface for interacting with Talos clusters.
 
-use crate::auth::create_channel;
-use crate::config::{Context, TalosConfig};
+use crate::auth::{create_channel_with_options, create_maintenance_channel};
+use crate::config::{Context, TalosConfig, endpoint_host, is_loopback_host};
 use crate::error::TalosError;
+use crate::heartbeat;
 use crate::proto::machine::machine_service_client::MachineServiceClient;
 use crate::proto::machine::{EtcdMemberListRequest, LogsRequest, NetstatRequest, netstat_request};
 use crate::proto::time::time_service_client::TimeServiceClient;
+use crate::proxy::ProxyConfig;
 use tokio_stream::StreamExt;
 use tonic::Request;
+use tonic::body::BoxBody;
+use tonic::codegen::http;
 use tonic::transport::Channel;
+use tower::util::MapResponse;
 
 /// Link type for BPF filter generation.
 ///
@@ -25,10 +30,32 @@ pub enum LinkType {
     RAW,
 }
 
+/// Channel that records every answered call in [`heartbeat`]
+type HeartbeatChannel =
+    MapResponse<Channel, fn(http::Response<BoxBody>) -> http::Response<BoxBody>>;
+
+/// Wrap a channel so its calls keep the API heartbeat alive
+fn with_heartbeat(channel: Channel) -> HeartbeatChannel {
+    MapResponse::new(channel, record_answered)
+}
+
+/// Record a successful call, i.e. any response that isn't an immediate
+/// gRPC error
+///
+/// Errors after the headers (in the trailers) still count, as the node did
+/// answer; transport errors never get here.
+fn record_answered(response: http::Response<BoxBody>) -> http::Response<BoxBody> {
+    let status = response.headers().get("grpc-status");
+    if status.is_none_or(|s| s == "0") {
+        heartbeat::record_success();
+    }
+    response
+}
+
 /// High-level client for Talos API
 #[derive(Clone)]
 pub struct TalosClient {
-    channel: Channel,
+    channel: HeartbeatChannel,
     /// Target nodes for API requests
     nodes: Vec<String>,
     /// Endpoints from configuration (used to filter out vIPs from node targeting)
@@ -38,7 +65,29 @@ pub struct TalosClient {
 impl TalosClient {
     /// Create a new client from a talosconfig context
     pub async fn from_context(ctx: &Context) -> Result<Self, TalosError> {
-        let channel = create_channel(ctx).await?;
+        Self::from_context_with_proxy(ctx, None).await
+    }
+
+    /// Create a new client from a talosconfig context, tunneling the
+    /// connection through a proxy if one is given
+    pub async fn from_context_with_proxy(
+        ctx: &Context,
+        proxy: Option<&ProxyConfig>,
+    ) -> Result<Self, TalosError> {
+        Self::from_context_with_options(ctx, proxy, false).await
+    }
+
+    /// Create a new client from a talosconfig context, optionally through a
+    /// proxy and optionally without verifying the server certificate
+    ///
+    /// `skip_verify` disables CA and hostname checks for the node's
+    /// certificate. Never enable it by default.
+    pub async fn from_context_with_options(
+        ctx: &Context,
+        proxy: Option<&ProxyConfig>,
+        skip_verify: bool,
+    ) -> Result<Self, TalosError> {
+        let channel = with_heartbeat(create_channel_with_options(ctx, proxy, skip_verify).await?);
         let nodes = ctx.target_nodes().to_vec();
         let endpoints = ctx.endpoints.clone();
 
@@ -49,6 +98,19 @@ impl TalosClient {
         })
     }
 
+    /// Create a client for a node in maintenance mode at `endpoint`
+    ///
+    /// No certificates are checked either way (see
+    /// [`create_maintenance_channel`]), so only use it to talk to a node that
+    /// is waiting for its machine config.
+    pub fn maintenance(endpoint: &str, proxy: Option<&ProxyConfig>) -> Result<Self, TalosError> {
+        Ok(Self {
+            channel: with_heartbeat(create_maintenance_channel(endpoint, proxy)?),
+            nodes: Vec::new(),
+            endpoints: vec![endpoint.to_string()],
+        })
+    }
+
     /// Create a new client from the default talosconfig
     pub async fn from_default_config() -> Result<Self, TalosError> {
         let config = TalosConfig::load_default()?;
@@ -77,13 +139,36 @@ impl TalosClient {
         }
     }
 
+    /// Create a new client targeting several nodes at once
+    ///
+    /// Responses are aggregated by apid, one message per node.
+    pub fn with_node_list(&self, nodes: &[String]) -> Self {
+        Self {
+            channel: self.channel.clone(),
+            nodes: nodes.to_vec(),
+            endpoints: self.endpoints.clone(),
+        }
+    }
+
+    /// Keep this client's node targeting but use `other`'s connection
+    ///
+    /// Used after reconnecting, so node-scoped clients move off a broken
+    /// channel without having to know which nodes they were built for.
+    pub fn with_channel_from(&self, other: &TalosClient) -> Self {
+        Self {
+            channel: other.channel.clone(),
+            nodes: self.nodes.clone(),
+            endpoints: other.endpoints.clone(),
+        }
+    }
+
     /// Get a MachineService client
-    fn machine_client(&self) -> MachineServiceClient<Channel> {
+    fn machine_client(&self) -> MachineServiceClient<HeartbeatChannel> {
         MachineServiceClient::new(self.channel.clone())
     }
 
     /// Get a TimeService client
-    fn time_client(&self) -> TimeServiceClient<Channel> {
+    fn time_client(&self) -> TimeServiceClient<HeartbeatChannel> {
         TimeServiceClient::new(self.channel.clone())
     }
 
@@ -102,11 +187,11 @@ impl TalosClient {
             .unwrap_or_else(|| {
                 self.nodes
                     .get(index)
-                    .map(|n| n.split(':').next().unwrap_or(n).to_string())
+                    .map(|n| endpoint_host(n).to_string())
                     .unwrap_or_else(|| {
                         self.nodes
                             .first()
-                            .map(|n| n.split(':').next().unwrap_or(n).to_string())
+                            .map(|n| endpoint_host(n).to_string())
                             .unwrap_or_else(|| "node".to_string())
                     })
             })
@@ -123,40 +208,7 @@ impl TalosClient {
         // Only add nodes metadata if explicitly configured (not just endpoints)
         // When nodes is empty or same as endpoints, skip the header
         if !self.nodes.is_empty() {
-            // Identify vIPs: endpoints that are NOT in the nodes list
-            // These are load balancers/VIPs that shouldn't be targeted as nodes
-            // Endpoints that ARE also in nodes are real nodes and should be kept
-            let vips: std::collections::HashSet<&str> = self
-                .endpoints
-                .iter()
-                .filter_map(|e| {
-                    let endpoint_host = e.split(':').next().unwrap_or(e);
-                    // Check if this endpoint also appears in the nodes list
-                    let is_also_a_node = self.nodes.iter().any(|n| {
-                        let node_host = n.split(':').next().unwrap_or(n);
-                        node_host == endpoint_host
-                    });
-                    // It's a vIP only if it's NOT also a node
-                    if is_also_a_node {
-                        None
-                    } else {
-                        Some(endpoint_host)
-                    }
-                })
-                .collect();
-
-            // Filter out localhost and vIPs from nodes
-            let valid_nodes: Vec<String> = self
-                .nodes
-                .iter()
-                .filter(|n| {
-                    let is_localhost = n.starts_with("127.0.0.1") || n.starts_with("localhost");
-                    let node_host = n.split(':').next().unwrap_or(n);
-                    let is_vip = vips.contains(node_host);
-                    !is_localhost && !is_vip
-                })
-                .map(|n| n.split(':').next().unwrap_or(n).to_string())
-                .collect();
+            let valid_nodes = self.filtered_target_nodes();
 
             if valid_nodes.len() == 1 {
                 // Single node: use "node" header (direct proxy, no aggregation)
@@ -180,39 +232,32 @@ impl TalosClient {
     /// Get the filtered target nodes that would be sent in API requests
     ///
     /// This filters out:
-    /// - localhost/127.0.0.1 entries (proxy endpoints)
+    /// - localhost and loopback entries (proxy or port-forwarded endpoints)
     /// - Entries that match vIPs (endpoints NOT also in nodes list)
+    /// - Repeats of a node already listed
     ///
     /// Returns the list of actual node hostnames (without ports).
     #[doc(hidden)]
     pub fn filtered_target_nodes(&self) -> Vec<String> {
         // Identify vIPs: endpoints that are NOT in the nodes list
+        // These are load balancers/VIPs that shouldn't be targeted as nodes
+        // Endpoints that ARE also in nodes are real nodes and should be kept
         let vips: std::collections::HashSet<&str> = self
             .endpoints
             .iter()
-            .filter_map(|e| {
-                let endpoint_host = e.split(':').next().unwrap_or(e);
-                let is_also_a_node = self.nodes.iter().any(|n| {
-                    let node_host = n.split(':').next().unwrap_or(n);
-                    node_host == endpoint_host
-                });
-                if is_also_a_node {
-                    None
-                } else {
-                    Some(endpoint_host)
-                }
-            })
+            .map(|e| endpoint_host(e))
+            .filter(|host| !self.nodes.iter().any(|n| endpoint_host(n) == *host))
             .collect();
 
+        // Listing a node twice (e.g. "10.5.0.2" and "10.5.0.2:50000") would
+        // make apid answer for it twice
+        let mut seen = std::collections::HashSet::new();
         self.nodes
             .iter()
-            .filter(|n| {
-                let is_localhost = n.starts_with("127.0.0.1") || n.starts_with("localhost");
-                let node_host = n.split(':').next().unwrap_or(n);
-                let is_vip = vips.contains(node_host);
-                !is_localhost && !is_vip
-            })
-            .map(|n| n.split(':').next().unwrap_or(n).to_string())
+            .map(|n| endpoint_host(n))
+            .filter(|host| !is_loopback_host(host) && !vips.contains(host))
+            .filter(|host| seen.insert(*host))
+            .map(str::to_string)
             .collect()
     }
 
@@ -407,6 +452,8 @@ impl TalosClient {
                     mem_available: m.memavailable * 1024,
                     buffers: m.buffers * 1024,
                     cached: m.cached * 1024,
+                    swap_total: m.swaptotal * 1024,
+                    swap_free: m.swapfree * 1024,
                 }),
             })
             .collect();
@@ -496,6 +543,7 @@ impl TalosClient {
 
                 NodeSystemStat {
                     node: self.node_from_metadata(msg.metadata.as_ref(), 0),
+                    boot_time: msg.boot_time,
                     cpu_total,
                     process_running: msg.process_running,
                     process_blocked: msg.process_blocked,
@@ -521,14 +569,11 @@ impl TalosClient {
         let response = client.logs(request).await?;
         let mut stream = response.into_inner();
 
-        let mut logs = String::new();
+        // Decode once at the end so characters split across chunks survive
+        let mut logs = Vec::new();
         while let Some(chunk) = stream.next().await {
             match chunk {
-                Ok(data) => {
-                    if let Ok(text) = String::from_utf8(data.bytes) {
-                        logs.push_str(&text);
-                    }
-                }
+                Ok(data) => logs.extend_from_slice(&data.bytes),
                 Err(e) => {
                     // Stop on error but return what we have
                     tracing::warn!("Log stream error: {}", e);
@@ -537,7 +582,7 @@ impl TalosClient {
             }
         }
 
-        Ok(logs)
+        Ok(sanitize_log_bytes(&logs))
     }
 
     /// Stream logs from a service (follow mode)
@@ -564,37 +609,23 @@ impl TalosClient {
 
         // Spawn a task to read from the stream and send to channel
         tokio::spawn(async move {
-            // Buffer for incomplete lines that span chunk boundaries
-            let mut pending = String::new();
+            // Bytes of an incomplete line that spans chunk boundaries; lines
+            // are decoded only once complete so multi-byte characters survive
+            let mut pending: Vec<u8> = Vec::new();
 
             while let Some(chunk) = stream.next().await {
                 match chunk {
                     Ok(data) => {
-                        if let Ok(text) = String::from_utf8(data.bytes) {
-                            // Prepend any pending partial line from previous chunk
-                            let combined = if pending.is_empty() {
-                                text
-                            } else {
-                                std::mem::take(&mut pending) + &text
-                            };
-
-                            // Check if chunk ends with newline (complete line) or not (partial)
-                            let ends_with_newline = combined.ends_with('\n');
-
-                            // Split into lines
-                            let mut lines: Vec<&str> = combined.lines().collect();
-
-                            // If doesn't end with newline, last "line" is incomplete - save it
-                            if !ends_with_newline && !lines.is_empty() {
-                                pending = lines.pop().unwrap_or("").to_string();
-                            }
-
-                            // Send complete lines
-                            for line in lines {
-                                if !line.trim().is_empty() && tx.send(line.to_string()).is_err() {
-                                    // Receiver dropped, stop streaming
-                                    return;
-                                }
+                        pending.extend_from_slice(&data.bytes);
+
+                        // Send complete lines
+                        while let Some(end) = pending.iter().position(|&b| b == b'\n') {
+                            let raw: Vec<u8> = pending.drain(..=end).collect();
+                            let line = sanitize_log_bytes(&raw);
+                            let line = line.trim_end_matches('\n');
+                            if !line.trim().is_empty() && tx.send(line.to_string()).is_err() {
+                                // Receiver dropped, stop streaming
+                                return;
                             }
                         }
                     }
@@ -606,8 +637,9 @@ impl TalosClient {
             }
 
             // Send any remaining pending content when stream ends
-            if !pending.trim().is_empty() {
-                let _ = tx.send(pending);
+            let rest = sanitize_log_bytes(&pending);
+            if !rest.trim().is_empty() {
+                let _ = tx.send(rest);
             }
         });
 
@@ -807,6 +839,35 @@ impl TalosClient {
         Ok(result)
     }
 
+    /// Get mounted filesystem usage from all configured nodes
+    pub async fn mounts(&self) -> Result<Vec<NodeMounts>, TalosError> {
+        let mut client = self.machine_client();
+        let request = self.with_nodes(Request::new(()));
+
+        let response = client.mounts(request).await?;
+        let inner = response.into_inner();
+
+        let mut result = Vec::new();
+        for (idx, msg) in inner.messages.into_iter().enumerate() {
+            let hostname = self.node_from_metadata(msg.metadata.as_ref(), idx);
+
+            let mounts = msg
+                .stats
+                .into_iter()
+                .map(|m| MountInfo {
+                    filesystem: m.filesystem,
+                    size: m.size,
+                    available: m.available,
+                    mounted_on: m.mounted_on,
+                })
+                .collect();
+
+            result.push(NodeMounts { hostname, mounts });
+        }
+
+        Ok(result)
+    }
+
     /// Get network device statistics from all configured nodes
     pub async fn network_device_stats(&self) -> Result<Vec<NodeNetworkStats>, TalosError> {
         let mut client = self.machine_client();
@@ -816,8 +877,8 @@ impl TalosClient {
         let inner = response.into_inner();
 
         let mut result = Vec::new();
-        for msg in inner.messages {
-            let hostname = self.node_from_metadata(msg.metadata.as_ref(), 0);
+        for (idx, msg) in inner.messages.into_iter().enumerate() {
+            let hostname = self.node_from_metadata(msg.metadata.as_ref(), idx);
 
             let total = msg.total.map(|t| NetDevStats::from_proto(&t));
 
@@ -834,6 +895,24 @@ impl TalosClient {
         Ok(result)
     }
 
+    /// Get block device I/O counters from all configured nodes
+    pub async fn disk_stats(&self) -> Result<Vec<NodeDiskStats>, TalosError> {
+        let mut client = self.machine_client();
+        let request = self.with_nodes(Request::new(()));
+
+        let response = client.disk_stats(request).await?;
+        let inner = response.into_inner();
+
+        let mut result = Vec::new();
+        for (idx, msg) in inner.messages.into_iter().enumerate() {
+            let hostname = self.node_from_metadata(msg.metadata.as_ref(), idx);
+            let devices = msg.devices.iter().map(DiskIoStats::from_proto).collect();
+            result.push(NodeDiskStats { hostname, devices });
+        }
+
+        Ok(result)
+    }
+
     /// Get network connections (netstat) from all configured nodes
     ///
     /// Returns TCP connections by default. Use filter to get only listening or connected.
@@ -898,14 +977,10 @@ impl TalosClient {
         let response = client.dmesg(request).await?;
         let mut stream = response.into_inner();
 
-        let mut output = String::new();
+        let mut output = Vec::new();
         while let Some(chunk) = stream.next().await {
             match chunk {
-                Ok(data) => {
-                    if let Ok(text) = String::from_utf8(data.bytes) {
-                        output.push_str(&text);
-                    }
-                }
+                Ok(data) => output.extend_from_slice(&data.bytes),
                 Err(e) => {
                     tracing::warn!("Dmesg stream error: {}", e);
                     break;
@@ -913,7 +988,7 @@ impl TalosClient {
             }
         }
 
-        Ok(output)
+        Ok(sanitize_log_bytes(&output))
     }
 
     /// Read a file from the node's filesystem
@@ -1641,6 +1716,42 @@ impl TalosClient {
             success: msg.is_some(),
         })
     }
+
+    /// Upgrade Talos on the node to the given installer image
+    ///
+    /// The node cordons and drains itself, installs the image and reboots.
+    /// With `stage` set the image is only installed on the next reboot.
+    /// Ephemeral data is preserved.
+    ///
+    /// # Arguments
+    /// * `image` - Installer image, e.g. `ghcr.io/siderolabs/installer:v1.9.3`
+    /// * `stage` - Defer the upgrade to the next reboot
+    pub async fn upgrade(&self, image: &str, stage: bool) -> Result<UpgradeResult, TalosError> {
+        use crate::proto::machine::{UpgradeRequest, upgrade_request};
+
+        let mut client = self.machine_client();
+        let request = self.with_nodes(Request::new(UpgradeRequest {
+            image: image.to_string(),
+            preserve: true,
+            stage,
+            force: false,
+            reboot_mode: upgrade_request::RebootMode::Default as i32,
+        }));
+
+        let response = client.upgrade(request).await?;
+        let inner = response.into_inner();
+
+        let msg = inner.messages.into_iter().next();
+
+        Ok(UpgradeResult {
+            node: msg
+                .as_ref()
+                .and_then(|m| m.metadata.as_ref())
+                .map(|m| m.hostname.clone())
+                .unwrap_or_else(|| self.nodes.first().cloned().unwrap_or_default()),
+            ack: msg.map(|m| m.ack).unwrap_or_default(),
+        })
+    }
 }
 
 /// Reboot mode
@@ -1662,6 +1773,15 @@ pub struct RebootResult {
     pub success: bool,
 }
 
+/// Result of an upgrade request
+#[derive(Debug, Clone)]
+pub struct UpgradeResult {
+    /// Node that was upgraded
+    pub node: String,
+    /// Acknowledgement message from the node
+    pub ack: String,
+}
+
 // ==================== Configuration Types ====================
 
 /// Mode for applying configuration changes
@@ -1689,7 +1809,7 @@ pub struct ApplyConfigResult {
 }
 
 /// Version information for a node
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct VersionInfo {
     pub node: String,
     pub version: String,
@@ -1747,6 +1867,8 @@ pub struct MemInfo {
     pub mem_available: u64,
     pub buffers: u64,
     pub cached: u64,
+    pub swap_total: u64,
+    pub swap_free: u64,
 }
 
 impl MemInfo {
@@ -1782,6 +1904,8 @@ pub struct NodeCpuInfo {
 #[derive(Debug, Clone)]
 pub struct NodeSystemStat {
     pub node: String,
+    /// Boot time as seconds since the Unix epoch
+    pub boot_time: u64,
     pub cpu_total: CpuStat,
     pub process_running: u64,
     pub process_blocked: u64,
@@ -1951,6 +2075,53 @@ impl EtcdAlarmType {
     }
 }
 
+/// Decode log output into text that is safe to render or scan
+///
+/// Invalid UTF-8 is replaced instead of dropping the whole chunk. ANSI
+/// escape sequences and other control characters (NUL, bell, backspace,
+/// carriage return) are removed so they cannot move the terminal cursor or
+/// recolor the TUI. Newlines are kept and tabs become spaces.
+pub fn sanitize_log_bytes(bytes: &[u8]) -> String {
+    let text = String::from_utf8_lossy(bytes);
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => match chars.next() {
+                // CSI: parameters and intermediates up to a final byte in @..~
+                Some('[') => {
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // OSC: up to BEL or ST (ESC \)
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' {
+                            break;
+                        }
+                        if c == '\x1b' {
+                            chars.next_if_eq(&'\\');
+                            break;
+                        }
+                    }
+                }
+                // Two-character sequences (ESC c, ESC 7, ...)
+                _ => {}
+            },
+            '\n' => out.push('\n'),
+            '\t' => out.push_str("    "),
+            c if c.is_control() => {}
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
 /// Format bytes into human-readable string (KB, MB, GB)
 fn format_bytes(bytes: u64) -> String {
     const KB: u64 = 1024;
@@ -1970,6 +2141,44 @@ fn format_bytes(bytes: u64) -> String {
 
 // ==================== Process Types ====================
 
+/// Mounted filesystems on a node
+#[derive(Debug, Clone)]
+pub struct NodeMounts {
+    /// Node hostname
+    pub hostname: String,
+    /// Mounted filesystems on this node
+    pub mounts: Vec<MountInfo>,
+}
+
+/// Usage of a single mounted filesystem
+#[derive(Debug, Clone)]
+pub struct MountInfo {
+    /// Source device (e.g., "/dev/sda6")
+    pub filesystem: String,
+    /// Total size in bytes
+    pub size: u64,
+    /// Available bytes
+    pub available: u64,
+    /// Mount point (e.g., "/var")
+    pub mounted_on: String,
+}
+
+impl MountInfo {
+    /// Used bytes
+    pub fn used(&self) -> u64 {
+        self.size.saturating_sub(self.available)
+    }
+
+    /// Usage as a percentage (0-100)
+    pub fn usage_percent(&self) -> f32 {
+        if self.size == 0 {
+            0.0
+        } else {
+            (self.used() as f64 / self.size as f64 * 100.0) as f32
+        }
+    }
+}
+
 /// Processes running on a node
 #[derive(Debug, Clone)]
 pub struct NodeProcesses {
@@ -2125,6 +2334,63 @@ pub struct NodeNetworkStats {
     pub devices: Vec<NetDevStats>,
 }
 
+/// Name prefixes of interfaces created by the CNI, container runtime or the
+/// kernel's tunnel drivers rather than backed by a NIC
+const VIRTUAL_INTERFACE_PREFIXES: &[&str] = &[
+    "veth",
+    "cni",
+    "cilium_",
+    "lxc",
+    "flannel",
+    "cali",
+    "vxlan",
+    "genev_sys",
+    "weave",
+    "kube-",
+    "nodelocaldns",
+    "kubespan",
+    "docker",
+    "br-",
+    "virbr",
+    "dummy",
+    "tun",
+    "tap",
+    "wg",
+    "gre",
+    "erspan",
+    "ip6",
+    "ip_vti",
+    "sit",
+    "teql",
+];
+
+/// Broad class of a network interface, judged by its name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterfaceKind {
+    /// The loopback device
+    Loopback,
+    /// veth pairs, CNI bridges and overlays, tunnels, KubeSpan
+    Virtual,
+    /// Everything else: NICs and the bonds, bridges and VLANs built on them
+    Physical,
+}
+
+impl InterfaceKind {
+    /// Classify an interface by name
+    pub fn of(name: &str) -> Self {
+        if name == "lo" {
+            InterfaceKind::Loopback
+        } else if VIRTUAL_INTERFACE_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+        {
+            InterfaceKind::Virtual
+        } else {
+            InterfaceKind::Physical
+        }
+    }
+}
+
 /// Statistics for a single network device
 #[derive(Debug, Clone)]
 pub struct NetDevStats {
@@ -2220,6 +2486,45 @@ impl NetDevStats {
             format!("{} B/s", bytes_per_sec)
         }
     }
+
+    /// Format rate with decimal (SI) prefixes (kB/s, MB/s, GB/s)
+    ///
+    /// 1 GB/s here is 10^9 bytes, the same scale as `format_rate_bits`.
+    pub fn format_rate_si(bytes_per_sec: u64) -> String {
+        const KB: u64 = 1000;
+        const MB: u64 = KB * 1000;
+        const GB: u64 = MB * 1000;
+
+        if bytes_per_sec >= GB {
+            format!("{:.1} GB/s", bytes_per_sec as f64 / GB as f64)
+        } else if bytes_per_sec >= MB {
+            format!("{:.1} MB/s", bytes_per_sec as f64 / MB as f64)
+        } else if bytes_per_sec >= KB {
+            format!("{:.1} kB/s", bytes_per_sec as f64 / KB as f64)
+        } else {
+            format!("{} B/s", bytes_per_sec)
+        }
+    }
+
+    /// Format rate in bits per second (bit/s, Kb/s, Mb/s, Gb/s)
+    ///
+    /// Uses decimal (SI) prefixes, matching how link speeds are quoted.
+    pub fn format_rate_bits(bytes_per_sec: u64) -> String {
+        const KB: u64 = 1000;
+        const MB: u64 = KB * 1000;
+        const GB: u64 = MB * 1000;
+
+        let bits = bytes_per_sec.saturating_mul(8);
+        if bits >= GB {
+            format!("{:.1} Gb/s", bits as f64 / GB as f64)
+        } else if bits >= MB {
+            format!("{:.1} Mb/s", bits as f64 / MB as f64)
+        } else if bits >= KB {
+            format!("{:.1} Kb/s", bits as f64 / KB as f64)
+        } else {
+            format!("{} bit/s", bits)
+        }
+    }
 }
 
 /// Calculated rate for a network device (from delta between samples)
@@ -2276,6 +2581,11 @@ impl NetDevRate {
         self.rx_bytes_per_sec + self.tx_bytes_per_sec
     }
 
+    /// Whether this is the loopback device, whose traffic never leaves the node
+    pub fn is_loopback(&self) -> bool {
+        InterfaceKind::of(&self.name) == InterfaceKind::Loopback
+    }
+
     /// Get total errors
     pub fn total_errors(&self) -> u64 {
         self.rx_errors + self.tx_errors
@@ -2287,6 +2597,123 @@ impl NetDevRate {
     }
 }
 
+// ==================== Disk I/O Types ====================
+
+/// Size of the sectors the kernel counts in /proc/diskstats
+const SECTOR_BYTES: u64 = 512;
+
+/// Block device I/O counters for a node
+#[derive(Debug, Clone)]
+pub struct NodeDiskStats {
+    /// Node hostname
+    pub hostname: String,
+    /// Per-device counters
+    pub devices: Vec<DiskIoStats>,
+}
+
+/// Cumulative I/O counters for a single block device
+#[derive(Debug, Clone, Default)]
+pub struct DiskIoStats {
+    /// Device name (e.g., "sda", "nvme0n1p1")
+    pub name: String,
+    /// Reads completed
+    pub read_completed: u64,
+    /// Sectors read
+    pub read_sectors: u64,
+    /// Time spent reading (ms)
+    pub read_time_ms: u64,
+    /// Writes completed
+    pub write_completed: u64,
+    /// Sectors written
+    pub write_sectors: u64,
+    /// Time spent writing (ms)
+    pub write_time_ms: u64,
+    /// I/Os currently in flight
+    pub io_in_progress: u64,
+    /// Time the device had I/O in flight (ms)
+    pub io_time_ms: u64,
+}
+
+impl DiskIoStats {
+    /// Create from protobuf DiskStat
+    fn from_proto(stat: &crate::proto::machine::DiskStat) -> Self {
+        Self {
+            name: stat.name.clone(),
+            read_completed: stat.read_completed,
+            read_sectors: stat.read_sectors,
+            read_time_ms: stat.read_time_ms,
+            write_completed: stat.write_completed,
+            write_sectors: stat.write_sectors,
+            write_time_ms: stat.write_time_ms,
+            io_in_progress: stat.io_in_progress,
+            io_time_ms: stat.io_time_ms,
+        }
+    }
+}
+
+/// Calculated I/O rates for a block device (from delta between samples)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DiskIoRate {
+    /// Device name
+    pub name: String,
+    /// Reads completed per second
+    pub read_iops: f64,
+    /// Writes completed per second
+    pub write_iops: f64,
+    /// Bytes read per second
+    pub read_bytes_per_sec: u64,
+    /// Bytes written per second
+    pub write_bytes_per_sec: u64,
+    /// Average time per read (ms), `None` when nothing was read
+    pub read_await_ms: Option<f64>,
+    /// Average time per write (ms), `None` when nothing was written
+    pub write_await_ms: Option<f64>,
+    /// Share of the interval the device was busy (0-100)
+    pub util_percent: f64,
+    /// I/Os in flight at the latest sample
+    pub in_flight: u64,
+}
+
+impl DiskIoRate {
+    /// Calculate rates from previous and current samples
+    pub fn from_delta(prev: &DiskIoStats, curr: &DiskIoStats, elapsed_secs: f64) -> Self {
+        let per_sec = |delta: u64| {
+            if elapsed_secs > 0.0 {
+                delta as f64 / elapsed_secs
+            } else {
+                0.0
+            }
+        };
+        let await_ms = |time_ms: u64, ops: u64| (ops > 0).then(|| time_ms as f64 / ops as f64);
+
+        let reads = curr.read_completed.saturating_sub(prev.read_completed);
+        let writes = curr.write_completed.saturating_sub(prev.write_completed);
+        let read_bytes = curr.read_sectors.saturating_sub(prev.read_sectors) * SECTOR_BYTES;
+        let write_bytes = curr.write_sectors.saturating_sub(prev.write_sectors) * SECTOR_BYTES;
+        let busy_ms = curr.io_time_ms.saturating_sub(prev.io_time_ms);
+
+        Self {
+            name: curr.name.clone(),
+            read_iops: per_sec(reads),
+            write_iops: per_sec(writes),
+            read_bytes_per_sec: per_sec(read_bytes) as u64,
+            write_bytes_per_sec: per_sec(write_bytes) as u64,
+            read_await_ms: await_ms(curr.read_time_ms.saturating_sub(prev.read_time_ms), reads),
+            write_await_ms: await_ms(
+                curr.write_time_ms.saturating_sub(prev.write_time_ms),
+                writes,
+            ),
+            util_percent: (per_sec(busy_ms) / 10.0).min(100.0),
+            in_flight: curr.io_in_progress,
+        }
+    }
+
+    /// Get total throughput (read + write)
+    pub fn total_bytes_per_sec(&self) -> u64 {
+        self.read_bytes_per_sec + self.write_bytes_per_sec
+    }
+}
+
 // ==================== Connection Types ====================
 
 /// Filter for netstat queries
@@ -2376,9 +2803,40 @@ impl ConnectionCounts {
             + self.other
     }
 
+    /// Whether TIME_WAIT is above the warning threshold
+    pub fn high_time_wait(&self, thresholds: &ConnectionThresholds) -> bool {
+        self.time_wait > thresholds.time_wait
+    }
+
+    /// Whether CLOSE_WAIT is above the warning threshold
+    pub fn high_close_wait(&self, thresholds: &ConnectionThresholds) -> bool {
+        self.close_wait > thresholds.close_wait
+    }
+
     /// Check if there are any warning conditions
-    pub fn has_warnings(&self) -> bool {
-        self.time_wait > 100 || self.close_wait > 0 || self.syn_sent > 0
+    pub fn has_warnings(&self, thresholds: &ConnectionThresholds) -> bool {
+        self.high_time_wait(thresholds) || self.high_close_wait(thresholds) || self.syn_sent > 0
+    }
+}
+
+/// Connection counts above which a state is flagged as a warning
+///
+/// The defaults suit control plane nodes. Workers fronting ingress traffic
+/// routinely hold thousands of TIME_WAIT sockets and need a higher limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionThresholds {
+    /// Warn when more than this many sockets are in TIME_WAIT
+    pub time_wait: usize,
+    /// Warn when more than this many sockets are in CLOSE_WAIT
+    pub close_wait: usize,
+}
+
+impl Default for ConnectionThresholds {
+    fn default() -> Self {
+        Self {
+            time_wait: 100,
+            close_wait: 0,
+        }
     }
 }
 
@@ -2411,11 +2869,17 @@ pub struct ConnectionInfo {
 
 impl ConnectionInfo {
     fn from_proto(record: crate::proto::machine::ConnectRecord) -> Self {
-        // Extract process info if available
-        let (process_pid, process_name) = record
+        // Extract process info if available; sockets Talos could not attribute
+        // come back with an empty name and pid 0
+        let process_pid = record
+            .process
+            .as_ref()
+            .map(|p| p.pid)
+            .filter(|&pid| pid != 0);
+        let process_name = record
             .process
-            .map(|p| (Some(p.pid), Some(p.name)))
-            .unwrap_or((None, None));
+            .map(|p| p.name)
+            .filter(|name| !name.is_empty());
 
         // Extract network namespace if non-empty
         let netns = if record.netns.is_empty() {
@@ -2573,7 +3037,9 @@ mod tests {
     fn create_test_client(nodes: Vec<String>, endpoints: Vec<String>) -> TalosClient {
         // Create a dummy channel - we won't actually use it for these tests
         // This is a bit of a hack, but it allows us to test the filtering logic
-        let channel = tonic::transport::Channel::from_static("http://[::1]:50000").connect_lazy();
+        let channel = with_heartbeat(
+            tonic::transport::Channel::from_static("http://[::1]:50000").connect_lazy(),
+        );
 
         TalosClient {
             channel,
@@ -2582,6 +3048,144 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_format_rate_bits_uses_decimal_prefixes() {
+        assert_eq!(NetDevStats::format_rate_bits(0), "0 bit/s");
+        assert_eq!(NetDevStats::format_rate_bits(100), "800 bit/s");
+        assert_eq!(NetDevStats::format_rate_bits(125), "1.0 Kb/s");
+        assert_eq!(NetDevStats::format_rate_bits(125_000_000), "1.0 Gb/s");
+        assert_eq!(NetDevStats::format_rate_bits(1_250_000), "10.0 Mb/s");
+    }
+
+    #[test]
+    fn test_format_rate_si_uses_decimal_prefixes() {
+        assert_eq!(NetDevStats::format_rate_si(999), "999 B/s");
+        assert_eq!(NetDevStats::format_rate_si(1_000), "1.0 kB/s");
+        assert_eq!(NetDevStats::format_rate_si(125_000_000), "125.0 MB/s");
+        assert_eq!(NetDevStats::format_rate_si(1_000_000_000), "1.0 GB/s");
+        // Binary scaling would show 1 GiB/s as 1.0
+        assert_eq!(NetDevStats::format_rate(1 << 30), "1.0 GB/s");
+        assert_eq!(NetDevStats::format_rate_si(1 << 30), "1.1 GB/s");
+    }
+
+    #[test]
+    fn test_interface_kind_of() {
+        assert_eq!(InterfaceKind::of("lo"), InterfaceKind::Loopback);
+        for name in ["eth0", "enp0s31f6", "ens3", "bond0", "br0", "eth0.100"] {
+            assert_eq!(InterfaceKind::of(name), InterfaceKind::Physical, "{}", name);
+        }
+        for name in [
+            "veth1a2b3c",
+            "cni0",
+            "cilium_host",
+            "lxc12ab",
+            "flannel.1",
+            "kubespan",
+            "tunl0",
+            "ip6tnl0",
+            "sit0",
+        ] {
+            assert_eq!(InterfaceKind::of(name), InterfaceKind::Virtual, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_disk_io_rate_from_delta() {
+        let prev = DiskIoStats {
+            name: "sda".to_string(),
+            read_completed: 100,
+            read_sectors: 1_000,
+            read_time_ms: 50,
+            write_completed: 200,
+            write_sectors: 4_000,
+            write_time_ms: 400,
+            io_in_progress: 0,
+            io_time_ms: 1_000,
+        };
+        let curr = DiskIoStats {
+            read_completed: 100,
+            write_completed: 400,
+            write_sectors: 8_096,
+            write_time_ms: 1_400,
+            io_in_progress: 3,
+            io_time_ms: 2_000,
+            ..prev.clone()
+        };
+
+        let rate = DiskIoRate::from_delta(&prev, &curr, 2.0);
+        assert_eq!(rate.read_iops, 0.0);
+        assert_eq!(rate.write_iops, 100.0);
+        assert_eq!(rate.read_bytes_per_sec, 0);
+        assert_eq!(rate.write_bytes_per_sec, 4_096 * 512 / 2);
+        assert_eq!(rate.read_await_ms, None);
+        assert_eq!(rate.write_await_ms, Some(5.0));
+        assert_eq!(rate.util_percent, 50.0);
+        assert_eq!(rate.in_flight, 3);
+    }
+
+    #[test]
+    fn test_connection_without_attributable_process() {
+        use crate::proto::machine::{ConnectRecord, connect_record::Process};
+
+        let record = |process| ConnectRecord {
+            localport: 6443,
+            process,
+            ..Default::default()
+        };
+        let owned = ConnectionInfo::from_proto(record(Some(Process {
+            pid: 1234,
+            name: "kube-apiserver".to_string(),
+        })));
+        assert_eq!(owned.process_pid, Some(1234));
+        assert_eq!(owned.process_name.as_deref(), Some("kube-apiserver"));
+
+        let unattributed = ConnectionInfo::from_proto(record(Some(Process::default())));
+        assert_eq!(unattributed.process_pid, None);
+        assert_eq!(unattributed.process_name, None);
+
+        let missing = ConnectionInfo::from_proto(record(None));
+        assert_eq!(missing.process_pid, None);
+    }
+
+    #[test]
+    fn test_connection_warnings_respect_thresholds() {
+        let counts = ConnectionCounts {
+            time_wait: 500,
+            ..Default::default()
+        };
+        assert!(counts.has_warnings(&ConnectionThresholds::default()));
+
+        let ingress = ConnectionThresholds {
+            time_wait: 5000,
+            ..Default::default()
+        };
+        assert!(!counts.has_warnings(&ingress));
+
+        let stuck = ConnectionCounts {
+            close_wait: 1,
+            ..Default::default()
+        };
+        assert!(stuck.has_warnings(&ingress));
+        assert!(!stuck.has_warnings(&ConnectionThresholds {
+            close_wait: 5,
+            ..ingress
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_with_channel_from_keeps_node_targeting() {
+        let scoped = create_test_client(vec![], vec!["10.0.0.1".to_string()])
+            .with_node_list(&["10.0.0.2".to_string(), "10.0.0.3".to_string()]);
+        let fresh = create_test_client(
+            vec!["10.0.0.1".to_string()],
+            vec!["10.0.0.1".to_string(), "10.0.0.4".to_string()],
+        );
+
+        let rebound = scoped.with_channel_from(&fresh);
+        assert_eq!(rebound.nodes, vec!["10.0.0.2", "10.0.0.3"]);
+        assert_eq!(rebound.endpoints, fresh.endpoints);
+    }
+
     #[tokio::test]
     async fn test_filtered_nodes_removes_vip_only_endpoint() {
         // Scenario: vIP is an endpoint but NOT in nodes list
@@ -2693,6 +3297,47 @@ mod tests {
         assert_eq!(filtered, vec!["node1"]);
     }
 
+    /// Context written by `talosctl cluster create` for a one-node docker
+    /// cluster, reachable through a port forward on localhost
+    const LOCAL_SINGLE_NODE_CONFIG: &str = r#"
+context: talos-default
+contexts:
+  talos-default:
+    endpoints:
+      - 127.0.0.1:50001
+    nodes:
+      - 10.5.0.2
+    ca: YQ==
+    crt: Yg==
+    key: Yw==
+"#;
+
+    #[tokio::test]
+    async fn test_local_single_node_context() {
+        let config: TalosConfig = serde_yaml::from_str(LOCAL_SINGLE_NODE_CONFIG).unwrap();
+        let ctx = config.current_context().unwrap();
+        assert_eq!(ctx.endpoint_urls(), vec!["https://127.0.0.1:50001"]);
+
+        // The node is targeted through the forwarded port, exactly once
+        let client = create_test_client(ctx.target_nodes().to_vec(), ctx.endpoints.clone());
+        assert_eq!(client.filtered_target_nodes(), vec!["10.5.0.2"]);
+        let request = client.with_nodes(Request::new(()));
+        assert_eq!(request.metadata().get("node").unwrap(), "10.5.0.2");
+        assert!(request.metadata().get("nodes").is_none());
+
+        // Without nodes the endpoint answers for itself, with no node header
+        let client = create_test_client(ctx.endpoints.clone(), ctx.endpoints.clone());
+        assert!(client.filtered_target_nodes().is_empty());
+        assert!(client.with_nodes(Request::new(())).metadata().is_empty());
+
+        // The same node listed with and without its port is one node
+        let client = create_test_client(
+            vec!["10.5.0.2".to_string(), "10.5.0.2:50000".to_string()],
+            ctx.endpoints.clone(),
+        );
+        assert_eq!(client.filtered_target_nodes(), vec!["10.5.0.2"]);
+    }
+
     #[tokio::test]
     async fn test_filtered_nodes_strips_ports() {
         // Ports should be stripped from node names
@@ -3028,4 +3673,15 @@ mod tests {
         assert!(nodes_values.contains(&"node2"));
         assert!(!nodes_values.iter().any(|v| v.contains(':')));
     }
+
+    #[test]
+    fn test_sanitize_log_bytes() {
+        let raw = b"\x1b[31mERROR\x1b[0m disk\0 full\r\n\x1b]0;title\x07ok\tdone\xff\n";
+        let text = sanitize_log_bytes(raw);
+        assert_eq!(text, "ERROR disk full\nok    done\u{fffd}\n");
+        assert!(!text.chars().any(|c| c.is_control() && c != '\n'));
+
+        // A truncated escape at the end of a chunk is dropped, not rendered
+        assert_eq!(sanitize_log_bytes(b"tail\x1b[3"), "tail");
+    }
 }
@@ -17,7 +17,7 @@ pub struct TalosConfig {
 }
 
 /// A single context in the talosconfig
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub struct Context {
     /// API endpoints (e.g., "127.0.0.1:50000")
     pub endpoints: Vec<String>,
@@ -108,30 +108,19 @@ impl Context {
     }
 
     /// Get the first endpoint URL
+    ///
+    /// The default Talos API port is added whenever the endpoint does not
+    /// specify one, including `https://host` style endpoints.
     pub fn endpoint_url(&self) -> Option<String> {
-        self.endpoints.first().map(|e| {
-            if e.starts_with("https://") || e.starts_with("http://") {
-                e.clone()
-            } else if e.starts_with('[') {
-                // IPv6 address with brackets - check if port is specified
-                if e.contains("]:") {
-                    // Has port specified: [::1]:50000
-                    format!("https://{}", e)
-                } else {
-                    // No port: [::1] -> add default port
-                    format!("https://{}:50000", e)
-                }
-            } else if e.contains("::") || e.matches(':').count() > 1 {
-                // Raw IPv6 address without brackets - add brackets and default port
-                format!("https://[{}]:50000", e)
-            } else if e.contains(':') {
-                // IPv4 or hostname with port specified
-                format!("https://{}", e)
-            } else {
-                // IPv4 or hostname without port - add default Talos API port
-                format!("https://{}:50000", e)
-            }
-        })
+        self.endpoints.first().map(|e| normalize_endpoint(e))
+    }
+
+    /// Get every endpoint URL, in the order listed in the context
+    pub fn endpoint_urls(&self) -> Vec<String> {
+        self.endpoints
+            .iter()
+            .map(|e| normalize_endpoint(e))
+            .collect()
     }
 
     /// Get target nodes, falling back to endpoints if not specified
@@ -144,6 +133,84 @@ impl Context {
     }
 }
 
+/// Turn a talosconfig endpoint into a URL with scheme and port
+pub(crate) fn normalize_endpoint(e: &str) -> String {
+    let (scheme, authority) = match e.split_once("://") {
+        Some((scheme, rest)) => (scheme, rest.trim_end_matches('/')),
+        None => ("https", e),
+    };
+    if authority.starts_with('[') {
+        // IPv6 address with brackets - check if port is specified
+        if authority.contains("]:") {
+            // Has port specified: [::1]:50000
+            format!("{}://{}", scheme, authority)
+        } else {
+            // No port: [::1] -> add default port
+            format!("{}://{}:50000", scheme, authority)
+        }
+    } else if authority.contains("::") || authority.matches(':').count() > 1 {
+        // Raw IPv6 address without brackets - add brackets and default port
+        format!("{}://[{}]:50000", scheme, authority)
+    } else if authority.contains(':') {
+        // IPv4 or hostname with port specified
+        format!("{}://{}", scheme, authority)
+    } else {
+        // IPv4 or hostname without port - add default Talos API port
+        format!("{}://{}:50000", scheme, authority)
+    }
+}
+
+/// Host and explicit port of a talosconfig endpoint or node entry
+///
+/// Accepts every form [`normalize_endpoint`] does: with or without a scheme,
+/// bracketed or raw IPv6, with or without a port.
+fn split_endpoint(e: &str) -> (&str, Option<&str>) {
+    let authority = e
+        .split_once("://")
+        .map_or(e, |(_, rest)| rest)
+        .trim_end_matches('/');
+    if let Some(bracketed) = authority.strip_prefix('[') {
+        let (host, rest) = bracketed.split_once(']').unwrap_or((bracketed, ""));
+        (host, rest.strip_prefix(':'))
+    } else if authority.matches(':').count() > 1 {
+        // Raw IPv6 never carries a port
+        (authority, None)
+    } else {
+        match authority.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (authority, None),
+        }
+    }
+}
+
+/// Host of a talosconfig endpoint or node entry ("https://10.5.0.2:50000" -> "10.5.0.2")
+pub fn endpoint_host(e: &str) -> &str {
+    split_endpoint(e).0
+}
+
+/// Endpoint in the form `talosctl --insecure -n` takes: the host, with the
+/// port kept only when it isn't the default ("127.0.0.1:50001" for a local
+/// `talosctl cluster create` cluster, "10.5.0.2" otherwise)
+pub fn endpoint_target(e: &str) -> String {
+    match split_endpoint(e) {
+        (host, Some(port)) if port != "50000" && host.contains(':') => {
+            format!("[{}]:{}", host, port)
+        }
+        (host, Some(port)) if port != "50000" => format!("{}:{}", host, port),
+        (host, _) => host.to_string(),
+    }
+}
+
+/// Whether a host names this machine: `localhost` or a loopback address
+///
+/// Such endpoints are port forwards or proxies to a node, never node names.
+pub fn is_loopback_host(host: &str) -> bool {
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,6 +257,33 @@ contexts:
         assert!(names.contains(&"prod"));
     }
 
+    #[test]
+    fn test_endpoint_urls_keeps_order() {
+        let ctx = Context {
+            endpoints: vec![
+                "10.0.0.2".to_string(),
+                "https://10.0.0.3:50001".to_string(),
+                "fd00::4".to_string(),
+            ],
+            nodes: vec![],
+            ca: "YQ==".to_string(),
+            crt: "Yg==".to_string(),
+            key: "Yw==".to_string(),
+        };
+        assert_eq!(
+            ctx.endpoint_urls(),
+            vec![
+                "https://10.0.0.2:50000".to_string(),
+                "https://10.0.0.3:50001".to_string(),
+                "https://[fd00::4]:50000".to_string(),
+            ]
+        );
+        assert_eq!(
+            ctx.endpoint_url(),
+            Some("https://10.0.0.2:50000".to_string())
+        );
+    }
+
     #[test]
     fn test_endpoint_url() {
         // Test endpoint with explicit port
@@ -228,6 +322,32 @@ contexts:
             ctx3.endpoint_url(),
             Some("https://192.168.1.100:50000".to_string())
         );
+
+        // Scheme without port should not fall back to 443
+        let ctx4 = Context {
+            endpoints: vec!["https://talos.example.com".to_string()],
+            nodes: vec![],
+            ca: "YQ==".to_string(),
+            crt: "Yg==".to_string(),
+            key: "Yw==".to_string(),
+        };
+        assert_eq!(
+            ctx4.endpoint_url(),
+            Some("https://talos.example.com:50000".to_string())
+        );
+
+        // Custom port is preserved
+        let ctx5 = Context {
+            endpoints: vec!["talos.example.com:6443".to_string()],
+            nodes: vec![],
+            ca: "YQ==".to_string(),
+            crt: "Yg==".to_string(),
+            key: "Yw==".to_string(),
+        };
+        assert_eq!(
+            ctx5.endpoint_url(),
+            Some("https://talos.example.com:6443".to_string())
+        );
     }
 
     #[test]
@@ -342,6 +462,26 @@ contexts:
         assert_eq!(ctx2.target_nodes(), &["192.168.1.101", "192.168.1.102"]);
     }
 
+    #[test]
+    fn test_endpoint_host_and_target() {
+        assert_eq!(endpoint_host("https://10.5.0.2:50000"), "10.5.0.2");
+        assert_eq!(endpoint_host("node1"), "node1");
+        assert_eq!(endpoint_host("[fd00::4]:50000"), "fd00::4");
+        assert_eq!(endpoint_host("fd00::4"), "fd00::4");
+
+        assert_eq!(endpoint_target("127.0.0.1:50001"), "127.0.0.1:50001");
+        assert_eq!(endpoint_target("https://10.5.0.2:50000/"), "10.5.0.2");
+        assert_eq!(endpoint_target("[::1]:50001"), "[::1]:50001");
+        assert_eq!(endpoint_target("fd00::4"), "fd00::4");
+
+        assert!(is_loopback_host("127.0.0.1"));
+        assert!(is_loopback_host("127.0.1.1"));
+        assert!(is_loopback_host("::1"));
+        assert!(is_loopback_host("LOCALHOST"));
+        assert!(!is_loopback_host("10.5.0.2"));
+        assert!(!is_loopback_host("localhost.example.com"));
+    }
+
     #[test]
     fn test_empty_endpoints() {
         let ctx = Context {
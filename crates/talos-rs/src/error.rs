@@ -53,3 +53,30 @@ pub enum TalosError {
     #[error("Could not determine home directory")]
     NoHomeDirectory,
 }
+
+impl TalosError {
+    /// Whether the node refused the call because it is in maintenance mode
+    ///
+    /// A node booted without a machine config only serves the maintenance
+    /// API and answers everything else with "API is not implemented in
+    /// maintenance mode".
+    pub fn is_maintenance_mode(&self) -> bool {
+        self.to_string().contains("maintenance mode")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_maintenance_mode() {
+        let err = TalosError::Grpc(tonic::Status::unimplemented(
+            "API is not implemented in maintenance mode",
+        ));
+        assert!(err.is_maintenance_mode());
+
+        let err = TalosError::Grpc(tonic::Status::unavailable("connection refused"));
+        assert!(!err.is_maintenance_mode());
+    }
+}
@@ -0,0 +1,48 @@
+//! Time of the last successful Talos API call
+//!
+//! Every RPC made through [`TalosClient`](crate::TalosClient) records its
+//! success here, process-wide, so a UI can tell "the view is hung" apart from
+//! "the cluster stopped answering".
+
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// Milliseconds after [`epoch`] of the last success, plus one (0 = never)
+static LAST_SUCCESS: AtomicU64 = AtomicU64::new(0);
+
+/// Reference point for [`LAST_SUCCESS`]
+fn epoch() -> Instant {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    *EPOCH.get_or_init(Instant::now)
+}
+
+/// Record that an API call just succeeded
+pub fn record_success() {
+    let millis = epoch().elapsed().as_millis() as u64 + 1;
+    LAST_SUCCESS.fetch_max(millis, Ordering::Relaxed);
+}
+
+/// Time since the last successful API call, or None if there has been none
+pub fn since_last_success() -> Option<Duration> {
+    match LAST_SUCCESS.load(Ordering::Relaxed) {
+        0 => None,
+        millis => Some(
+            epoch()
+                .elapsed()
+                .saturating_sub(Duration::from_millis(millis - 1)),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_success() {
+        record_success();
+        let since = since_last_success().unwrap();
+        assert!(since < Duration::from_secs(1));
+    }
+}
@@ -31,6 +31,8 @@ pub mod auth;
 pub mod client;
 pub mod config;
 pub mod error;
+pub mod heartbeat;
+pub mod proxy;
 pub mod talosctl;
 
 /// Generated protobuf types and gRPC clients
@@ -70,22 +72,30 @@ pub use client::{
     ConnectionCounts,
     ConnectionInfo,
     ConnectionState,
+    ConnectionThresholds,
     // Node info types
     CpuStat,
+    // Disk I/O types
+    DiskIoRate,
+    DiskIoStats,
     // Etcd types
     EtcdAlarm,
     EtcdAlarmType,
     EtcdMemberInfo,
     EtcdMemberStatus,
+    InterfaceKind,
     MemInfo,
+    MountInfo,
     // Network types
     NetDevRate,
     NetDevStats,
     NetstatFilter,
     NodeConnections,
     NodeCpuInfo,
+    NodeDiskStats,
     NodeLoadAvg,
     NodeMemory,
+    NodeMounts,
     NodeNetworkStats,
     // Process types
     NodeProcesses,
@@ -102,16 +112,24 @@ pub use client::{
     ServiceInfo,
     ServiceRestartResult,
     TalosClient,
+    UpgradeResult,
     VersionInfo,
+    // Log helpers
+    sanitize_log_bytes,
 };
-pub use config::{Context, TalosConfig};
+pub use config::{Context, TalosConfig, endpoint_host, endpoint_target, is_loopback_host};
 pub use error::TalosError;
+pub use proxy::{ProxyConfig, ProxyKind};
 pub use talosctl::{
-    AddressStatus, DiscoveryMember, DiskInfo, GenConfigResult, InsecureApplyResult,
-    InsecureVersionInfo, KubeSpanPeerStatus, MachineConfigInfo, VolumeStatus,
-    apply_config_insecure, check_insecure_connection, gen_config, get_address_status,
-    get_discovery_members, get_discovery_members_for_context, get_discovery_members_with_retry,
-    get_disks, get_disks_for_context, get_disks_for_node, get_disks_insecure, get_kubespan_peers,
-    get_machine_config, get_version_insecure, get_volume_status, get_volume_status_for_node,
-    get_volume_status_insecure, is_kubespan_enabled, reboot_insecure, shutdown_insecure,
+    AddressStatus, DiscoveryMember, DiskInfo, EncryptionKeySlot, GenConfigResult,
+    InsecureApplyResult, InsecureVersionInfo, KubeSpanPeerStatus, LinkStatus, MachineConfigInfo,
+    MemberDiscovery, PartitionInfo, VolumeEncryptionConfig, VolumeStatus, apply_config_insecure,
+    check_insecure_connection, discover_members, existing_config_files, gen_config,
+    gen_config_with_install_disk, get_address_status, get_discovery_members,
+    get_discovery_members_for_context, get_discovery_members_with_retry, get_disks,
+    get_disks_for_context, get_disks_for_node, get_disks_insecure, get_kubespan_peers,
+    get_link_status, get_machine_config, get_partitions_for_node, get_system_disk_insecure,
+    get_version_insecure, get_volume_encryption_for_node, get_volume_status,
+    get_volume_status_for_node, get_volume_status_insecure, is_kubespan_enabled, reboot_insecure,
+    route_talosctl_through, shutdown_insecure, talosctl_env,
 };
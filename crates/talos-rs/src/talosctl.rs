@@ -4,13 +4,47 @@
 //! This is necessary because the COSI State API is not exposed externally
 //! through apid - talosctl connects directly to machined via Unix socket.
 
+use crate::config::{endpoint_host, is_loopback_host};
 use crate::error::TalosError;
+use crate::proxy::ProxyConfig;
 use std::process::Command;
+use std::sync::OnceLock;
+
+/// How long a single node may take to answer a fallback discovery query
+const DISCOVERY_NODE_TIMEOUT_SECS: u64 = 10;
+
+/// URL of the local proxy bridge talosctl is pointed at, including its
+/// session token, see [`route_talosctl_through`]
+static TALOSCTL_PROXY: OnceLock<String> = OnceLock::new();
+
+/// Route every talosctl invocation through `proxy`
+///
+/// Starts a local CONNECT bridge (see [`ProxyConfig::spawn_connect_bridge`])
+/// and hands it, with its per-session credentials, to talosctl via
+/// `HTTPS_PROXY`. Only the first call has an effect; must be called from
+/// within the tokio runtime.
+pub async fn route_talosctl_through(proxy: &ProxyConfig) -> Result<(), TalosError> {
+    if TALOSCTL_PROXY.get().is_some() {
+        return Ok(());
+    }
+    let url = proxy.spawn_connect_bridge().await.map_err(TalosError::Io)?;
+    let _ = TALOSCTL_PROXY.set(url);
+    Ok(())
+}
+
+/// Environment to run talosctl with, so it uses the configured proxy
+pub fn talosctl_env() -> impl Iterator<Item = (&'static str, &'static str)> {
+    TALOSCTL_PROXY
+        .get()
+        .into_iter()
+        .flat_map(|url| [("HTTPS_PROXY", url.as_str()), ("https_proxy", url.as_str())])
+}
 
 /// Execute a talosctl command and return stdout (blocking)
 fn exec_talosctl(args: &[&str]) -> Result<String, TalosError> {
     let output = Command::new("talosctl")
         .args(args)
+        .envs(talosctl_env())
         .output()
         .map_err(TalosError::Io)?;
 
@@ -27,8 +61,11 @@ fn exec_talosctl(args: &[&str]) -> Result<String, TalosError> {
 
 /// Execute a talosctl command asynchronously and return stdout
 async fn exec_talosctl_async(args: &[&str]) -> Result<String, TalosError> {
+    // Callers may time out; don't leave talosctl running behind them
     let output = tokio::process::Command::new("talosctl")
         .args(args)
+        .envs(talosctl_env())
+        .kill_on_drop(true)
         .output()
         .await
         .map_err(TalosError::Io)?;
@@ -61,6 +98,58 @@ pub struct VolumeStatus {
     pub mount_location: Option<String>,
 }
 
+/// LUKS key slot from a VolumeConfig encryption spec
+#[derive(Debug, Clone)]
+pub struct EncryptionKeySlot {
+    /// Key slot number
+    pub slot: u32,
+    /// Key source (e.g., "nodeID", "static", "kms", "tpm")
+    pub key_type: String,
+    /// Whether the key is only usable with the STATE partition it was sealed against
+    pub lock_to_state: bool,
+}
+
+/// Volume encryption settings from VolumeConfig resource
+#[derive(Debug, Clone)]
+pub struct VolumeEncryptionConfig {
+    /// Volume ID (e.g., "STATE", "EPHEMERAL")
+    pub id: String,
+    /// Encryption provider (e.g., "luks2")
+    pub provider: String,
+    /// Cipher, if overridden from the provider default
+    pub cipher: Option<String>,
+    /// Key size in bits, if overridden from the provider default
+    pub key_size: Option<u32>,
+    /// Configured key slots
+    pub keys: Vec<EncryptionKeySlot>,
+}
+
+impl VolumeEncryptionConfig {
+    /// Cipher in use, falling back to the LUKS2 default
+    pub fn cipher_or_default(&self) -> &str {
+        self.cipher.as_deref().unwrap_or("aes-xts-plain64")
+    }
+
+    /// Key sources in slot order (e.g., "0: nodeID, 1: tpm")
+    pub fn key_sources(&self) -> String {
+        if self.keys.is_empty() {
+            return "none".to_string();
+        }
+        let mut keys: Vec<&EncryptionKeySlot> = self.keys.iter().collect();
+        keys.sort_by_key(|k| k.slot);
+        keys.iter()
+            .map(|k| {
+                if k.lock_to_state {
+                    format!("{}: {} (locked to STATE)", k.slot, k.key_type)
+                } else {
+                    format!("{}: {}", k.slot, k.key_type)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
 /// Disk information from Disks.block.talos.dev resource
 #[derive(Debug, Clone)]
 pub struct DiskInfo {
@@ -88,6 +177,65 @@ pub struct DiskInfo {
     pub wwid: Option<String>,
     /// Bus path
     pub bus_path: Option<String>,
+    /// udev symlinks pointing at the device (e.g., "/dev/disk/by-id/wwn-0x5000c500a1b2c3d4")
+    pub symlinks: Vec<String>,
+}
+
+impl DiskInfo {
+    /// Stable `/dev/disk/by-id/` path for this disk, if udev published one
+    pub fn by_id_path(&self) -> Option<&str> {
+        self.symlinks
+            .iter()
+            .map(String::as_str)
+            .find(|s| s.starts_with("/dev/disk/by-id/"))
+    }
+
+    /// Most stable `machine.install.diskSelector` key/value for this disk
+    ///
+    /// Prefers the WWID, then the serial number, then the bus path. Returns
+    /// `None` when the disk can only be addressed by its volatile dev path.
+    pub fn install_selector(&self) -> Option<(&'static str, &str)> {
+        self.wwid
+            .as_deref()
+            .map(|v| ("wwid", v))
+            .or_else(|| self.serial.as_deref().map(|v| ("serial", v)))
+            .or_else(|| self.bus_path.as_deref().map(|v| ("busPath", v)))
+    }
+
+    /// Whether two entries describe the same physical disk
+    ///
+    /// Compares WWID or serial when both sides have one, since `/dev/sdX`
+    /// names can be reassigned between boots.
+    pub fn is_same_disk(&self, other: &DiskInfo) -> bool {
+        if let (Some(a), Some(b)) = (&self.wwid, &other.wwid) {
+            return a == b;
+        }
+        if let (Some(a), Some(b)) = (&self.serial, &other.serial) {
+            return a == b;
+        }
+        self.dev_path == other.dev_path
+    }
+}
+
+/// Partition information from DiscoveredVolumes.block.talos.dev resource
+#[derive(Debug, Clone)]
+pub struct PartitionInfo {
+    /// Partition ID (e.g., "sda1", "nvme0n1p5")
+    pub id: String,
+    /// Device path (e.g., "/dev/sda1")
+    pub dev_path: String,
+    /// Parent disk ID (e.g., "sda")
+    pub parent: String,
+    /// Size in bytes
+    pub size: u64,
+    /// Human-readable size (e.g., "105 MB")
+    pub size_pretty: String,
+    /// Detected filesystem (e.g., "xfs", "vfat")
+    pub filesystem: Option<String>,
+    /// Partition or filesystem label (e.g., "EPHEMERAL")
+    pub label: Option<String>,
+    /// Partition number on the parent disk
+    pub index: Option<u32>,
 }
 
 /// Machine config info from MachineConfig resource
@@ -135,6 +283,35 @@ pub struct DiscoveryMember {
     pub operating_system: String,
 }
 
+/// Discovery members gathered from several endpoints, some of which may
+/// have failed
+#[derive(Debug, Clone, Default)]
+pub struct MemberDiscovery {
+    /// Members reported by the endpoints that answered, without duplicates
+    pub members: Vec<DiscoveryMember>,
+    /// Endpoints that did not answer, with the error each returned
+    pub failed_endpoints: Vec<(String, String)>,
+}
+
+impl MemberDiscovery {
+    /// Add members reported by one endpoint, skipping ones already known
+    fn merge(&mut self, members: Vec<DiscoveryMember>) {
+        for member in members {
+            if !self.members.iter().any(|m| m.id == member.id) {
+                self.members.push(member);
+            }
+        }
+    }
+
+    /// Error reported by the given endpoint, if it failed
+    pub fn endpoint_error(&self, endpoint: &str) -> Option<&str> {
+        self.failed_endpoints
+            .iter()
+            .find(|(e, _)| e == endpoint)
+            .map(|(_, err)| err.as_str())
+    }
+}
+
 /// Address status from AddressStatus resource (for VIP detection)
 #[derive(Debug, Clone)]
 pub struct AddressStatus {
@@ -152,6 +329,60 @@ pub struct AddressStatus {
     pub flags: Vec<String>,
 }
 
+/// Link status from LinkStatus resource (MTU, speed, duplex)
+#[derive(Debug, Clone)]
+pub struct LinkStatus {
+    /// Link ID (interface name)
+    pub id: String,
+    /// Link type (e.g., "ether", "loopback", "none")
+    pub link_type: String,
+    /// Link kind (empty for physical NICs, otherwise "vxlan", "bond", "veth", ...)
+    pub kind: String,
+    /// Maximum transmission unit in bytes
+    pub mtu: u32,
+    /// Negotiated link speed in Mbit/s (None if not reported)
+    pub speed_mbit: Option<u32>,
+    /// Duplex mode (e.g., "Full", "Half", "Unknown")
+    pub duplex: String,
+    /// Operational state (e.g., "up", "down")
+    pub operational_state: String,
+}
+
+impl LinkStatus {
+    /// Whether this link carries node-to-node traffic (a NIC, or a bond/VLAN on top of one)
+    pub fn is_uplink(&self) -> bool {
+        self.link_type == "ether" && matches!(self.kind.as_str(), "" | "bond" | "vlan")
+    }
+
+    /// Per-packet overhead in bytes if this link is a tunnel
+    ///
+    /// VXLAN and Geneve add 50 bytes, WireGuard 80 (its IPv6 worst case) and
+    /// IP-in-IP 20.
+    pub fn encapsulation_overhead(&self) -> Option<u32> {
+        match self.kind.as_str() {
+            "vxlan" | "geneve" => Some(50),
+            "wireguard" => Some(80),
+            "ipip" => Some(20),
+            _ => None,
+        }
+    }
+
+    /// Whether the link negotiated half duplex
+    pub fn is_half_duplex(&self) -> bool {
+        self.duplex.eq_ignore_ascii_case("half")
+    }
+
+    /// Format link speed for display (e.g., "1 Gb/s", "2.5 Gb/s", "100 Mb/s")
+    pub fn format_speed(&self) -> String {
+        match self.speed_mbit {
+            Some(mbit) if mbit >= 1000 && mbit % 1000 == 0 => format!("{} Gb/s", mbit / 1000),
+            Some(mbit) if mbit >= 1000 => format!("{:.1} Gb/s", mbit as f64 / 1000.0),
+            Some(mbit) => format!("{} Mb/s", mbit),
+            None => "-".to_string(),
+        }
+    }
+}
+
 /// Get volume status for a node
 ///
 /// Executes: talosctl get volumestatus --nodes <node> -o yaml
@@ -184,6 +415,32 @@ pub async fn get_volume_status_for_node(
     parse_volume_status_yaml(&output)
 }
 
+/// Get volume encryption settings for a specific node using context authentication (async, non-blocking)
+///
+/// Executes: talosctl --context <context> [--talosconfig <path>] -n <node> get volumeconfigs -o yaml
+///
+/// Only volumes with an encryption spec are returned.
+pub async fn get_volume_encryption_for_node(
+    context: &str,
+    node_ip: &str,
+    config_path: Option<&str>,
+) -> Result<Vec<VolumeEncryptionConfig>, TalosError> {
+    let mut args = vec!["--context", context];
+
+    // Add talosconfig path if provided
+    let config_path_string;
+    if let Some(path) = config_path {
+        config_path_string = path.to_string();
+        args.push("--talosconfig");
+        args.push(&config_path_string);
+    }
+
+    args.extend_from_slice(&["-n", node_ip, "get", "volumeconfigs", "-o", "yaml"]);
+
+    let output = exec_talosctl_async(&args).await?;
+    parse_volume_encryption_yaml(&output)
+}
+
 /// Get disk information for a node
 ///
 /// Executes: talosctl get disks --nodes <node> -o yaml
@@ -216,6 +473,55 @@ pub async fn get_disks_for_node(
     parse_disks_yaml(&output)
 }
 
+/// Get partition information for a specific node using context authentication (async, non-blocking)
+///
+/// Executes: talosctl --context <context> -n <node> get discoveredvolumes -o yaml
+pub async fn get_partitions_for_node(
+    context: &str,
+    node_ip: &str,
+    config_path: Option<&str>,
+) -> Result<Vec<PartitionInfo>, TalosError> {
+    let mut args = vec!["--context", context];
+
+    // Add talosconfig path if provided
+    let config_path_string;
+    if let Some(path) = config_path {
+        config_path_string = path.to_string();
+        args.push("--talosconfig");
+        args.push(&config_path_string);
+    }
+
+    args.extend_from_slice(&["-n", node_ip, "get", "discoveredvolumes", "-o", "yaml"]);
+
+    let output = exec_talosctl_async(&args).await?;
+    parse_partitions_yaml(&output)
+}
+
+/// Node to target with `-n` for a context-wide query
+///
+/// The first endpoint's host (port removed), unless it is a port forward on
+/// localhost, as with `talosctl cluster create`: apid on the node cannot
+/// target that, so the first real node is used instead.
+fn context_node(ctx: &crate::Context, context: &str) -> Result<String, TalosError> {
+    let endpoint = ctx
+        .endpoints
+        .first()
+        .ok_or_else(|| TalosError::NoEndpoints(context.to_string()))?;
+    let node_ip = match endpoint_host(endpoint) {
+        host if is_loopback_host(host) => ctx
+            .nodes
+            .iter()
+            .map(|n| endpoint_host(n))
+            .find(|n| !is_loopback_host(n))
+            .unwrap_or(host),
+        host => host,
+    };
+    if node_ip.is_empty() {
+        return Err(TalosError::NoEndpoints(context.to_string()));
+    }
+    Ok(node_ip.to_string())
+}
+
 /// Get disk information for a context (async, non-blocking)
 ///
 /// Executes: talosctl --context <context> -n <node> get disks -o yaml
@@ -236,19 +542,7 @@ pub async fn get_disks_for_context(
         .get(context)
         .ok_or_else(|| TalosError::ContextNotFound(context.to_string()))?;
 
-    // Get the first endpoint and extract the IP (remove port if present)
-    let node_ip = ctx
-        .endpoints
-        .first()
-        .ok_or_else(|| TalosError::NoEndpoints(context.to_string()))?
-        .split(':')
-        .next()
-        .unwrap_or("")
-        .to_string();
-
-    if node_ip.is_empty() {
-        return Err(TalosError::NoEndpoints(context.to_string()));
-    }
+    let node_ip = context_node(ctx, context)?;
 
     let output = exec_talosctl_async(&[
         "--context",
@@ -282,6 +576,26 @@ pub async fn get_disks_insecure(endpoint: &str) -> Result<Vec<DiskInfo>, TalosEr
     parse_disks_yaml(&output)
 }
 
+/// Get the ID of the disk Talos is currently running from, in insecure mode
+///
+/// Executes: talosctl get systemdisk --insecure -n <endpoint> -o yaml
+///
+/// Returns `None` when the node has no system disk yet (e.g. booted from ISO
+/// or PXE into maintenance mode).
+pub async fn get_system_disk_insecure(endpoint: &str) -> Result<Option<String>, TalosError> {
+    let output = exec_talosctl_async(&[
+        "get",
+        "systemdisk",
+        "--insecure",
+        "-n",
+        endpoint,
+        "-o",
+        "yaml",
+    ])
+    .await?;
+    Ok(parse_system_disk_yaml(&output))
+}
+
 /// Get volume status from a node in insecure mode (no TLS client auth)
 ///
 /// Executes: talosctl get volumestatus --insecure -n <endpoint> -o yaml
@@ -379,6 +693,38 @@ pub async fn gen_config(
     output_dir: &str,
     additional_sans: Option<&[&str]>,
     force: bool,
+) -> Result<GenConfigResult, TalosError> {
+    gen_config_with_install_disk(
+        cluster_name,
+        kubernetes_endpoint,
+        output_dir,
+        additional_sans,
+        force,
+        None,
+        &[],
+    )
+    .await
+}
+
+/// Generate Talos machine configuration targeting a specific install disk
+///
+/// Same as [`gen_config`], but when `install_disk` is given the control plane
+/// config is patched with a `machine.install.diskSelector` built from the
+/// disk's stable identifiers, so the install does not depend on `/dev/sdX`
+/// ordering. Disks without a WWID, serial, or bus path fall back to
+/// `machine.install.disk`.
+///
+/// Each entry of `config_patches` is passed as `--config-patch`, so it is
+/// either inline YAML/JSON or `@path` to a patch file, and applies to both
+/// the control plane and worker configs.
+pub async fn gen_config_with_install_disk(
+    cluster_name: &str,
+    kubernetes_endpoint: &str,
+    output_dir: &str,
+    additional_sans: Option<&[&str]>,
+    force: bool,
+    install_disk: Option<&DiskInfo>,
+    config_patches: &[String],
 ) -> Result<GenConfigResult, TalosError> {
     let mut args = vec!["gen", "config", cluster_name, kubernetes_endpoint];
 
@@ -399,6 +745,18 @@ pub async fn gen_config(
         args.push("--force");
     }
 
+    let disk_patch: String;
+    if let Some(disk) = install_disk {
+        disk_patch = install_disk_patch(disk);
+        args.push("--config-patch-control-plane");
+        args.push(&disk_patch);
+    }
+
+    for patch in config_patches {
+        args.push("--config-patch");
+        args.push(patch);
+    }
+
     exec_talosctl_async(&args).await?;
 
     Ok(GenConfigResult {
@@ -409,6 +767,43 @@ pub async fn gen_config(
     })
 }
 
+/// Files `talosctl gen config` writes into its output directory
+pub const GENERATED_CONFIG_FILES: [&str; 3] = ["controlplane.yaml", "worker.yaml", "talosconfig"];
+
+/// Generated config files already present in `output_dir`
+///
+/// Generating again with `force` replaces them, and with them the cluster
+/// secrets they hold, so callers should confirm before doing so.
+pub fn existing_config_files(output_dir: &str) -> Vec<&'static str> {
+    let dir = std::path::Path::new(output_dir);
+    GENERATED_CONFIG_FILES
+        .into_iter()
+        .filter(|file| dir.join(file).exists())
+        .collect()
+}
+
+/// Build the config patch that pins `machine.install` to a disk
+fn install_disk_patch(disk: &DiskInfo) -> String {
+    let mut install = serde_yaml::Mapping::new();
+    match disk.install_selector() {
+        Some((key, value)) => {
+            let mut selector = serde_yaml::Mapping::new();
+            selector.insert(key.into(), value.into());
+            install.insert("diskSelector".into(), selector.into());
+        }
+        None => {
+            install.insert("disk".into(), disk.dev_path.as_str().into());
+        }
+    }
+
+    let mut machine = serde_yaml::Mapping::new();
+    machine.insert("install".into(), install.into());
+    let mut patch = serde_yaml::Mapping::new();
+    patch.insert("machine".into(), machine.into());
+
+    serde_yaml::to_string(&patch).unwrap_or_default()
+}
+
 /// Result of applying configuration in insecure mode
 #[derive(Debug, Clone)]
 pub struct InsecureApplyResult {
@@ -498,7 +893,8 @@ pub fn get_discovery_members(node: &str) -> Result<Vec<DiscoveryMember>, TalosEr
 ///
 /// This version uses the context name to get the correct certificates and endpoint,
 /// and uses tokio async process to avoid blocking the runtime.
-/// It extracts a node IP from the context's endpoints to target the query.
+/// It extracts a node IP from the context's endpoints to target the query
+/// (see [`context_node`]).
 ///
 /// If `config_path` is provided, loads config from that path instead of the default.
 pub async fn get_discovery_members_for_context(
@@ -519,19 +915,7 @@ pub async fn get_discovery_members_for_context(
         .get(context)
         .ok_or_else(|| TalosError::ContextNotFound(context.to_string()))?;
 
-    // Get the first endpoint and extract the IP (remove port if present)
-    let node_ip = ctx
-        .endpoints
-        .first()
-        .ok_or_else(|| TalosError::NoEndpoints(context.to_string()))?
-        .split(':')
-        .next()
-        .unwrap_or("")
-        .to_string();
-
-    if node_ip.is_empty() {
-        return Err(TalosError::NoEndpoints(context.to_string()));
-    }
+    let node_ip = context_node(ctx, context)?;
 
     let output = exec_talosctl_async(&[
         "--context",
@@ -574,12 +958,30 @@ async fn get_discovery_members_for_node_async(
 /// individual control plane nodes directly if the VIP fails.
 ///
 /// This handles transient "no request forwarding" errors that occur when
-/// the VIP routes to a node that can't forward the request.
+/// the VIP routes to a node that can't forward the request. Use
+/// [`discover_members`] to also learn which fallback nodes failed.
 pub async fn get_discovery_members_with_retry(
     context: &str,
     config_path: Option<&str>,
     fallback_node_ips: &[String],
 ) -> Result<Vec<DiscoveryMember>, TalosError> {
+    discover_members(context, config_path, fallback_node_ips)
+        .await
+        .map(|discovery| discovery.members)
+}
+
+/// Discover cluster members, keeping whatever the reachable endpoints report
+///
+/// Tries the VIP endpoint (via context) with retries first. If that fails,
+/// every fallback node is queried at once, each bounded by
+/// `DISCOVERY_NODE_TIMEOUT_SECS` so a dead node cannot stall the refresh.
+/// Members from the nodes that answer are merged, and each node that did not
+/// is listed in `failed_endpoints`. Only fails when no endpoint answered.
+pub async fn discover_members(
+    context: &str,
+    config_path: Option<&str>,
+    fallback_node_ips: &[String],
+) -> Result<MemberDiscovery, TalosError> {
     // First, try the VIP-based approach with retries
     // VIP_MAX_RETRIES=2 means 3 total attempts (initial + 2 retries)
     const VIP_MAX_RETRIES: u32 = 2;
@@ -589,7 +991,12 @@ pub async fn get_discovery_members_with_retry(
 
     for attempt in 0..=VIP_MAX_RETRIES {
         match get_discovery_members_for_context(context, config_path).await {
-            Ok(members) => return Ok(members),
+            Ok(members) => {
+                // Members are reported once per node asked, so keep each once
+                let mut discovery = MemberDiscovery::default();
+                discovery.merge(members);
+                return Ok(discovery);
+            }
             Err(e) => {
                 last_error = Some(e);
                 if attempt < VIP_MAX_RETRIES {
@@ -605,32 +1012,52 @@ pub async fn get_discovery_members_with_retry(
         }
     }
 
-    // VIP failed, try fallback nodes directly
-    // Shuffle to distribute load and avoid repeatedly hitting a problematic node first
+    // VIP failed, ask every fallback node directly and keep partial answers
     if !fallback_node_ips.is_empty() {
         tracing::debug!(
             "VIP-based discovery failed, trying {} fallback nodes directly",
             fallback_node_ips.len()
         );
 
-        let mut shuffled_ips: Vec<&String> = fallback_node_ips.iter().collect();
-        fastrand::shuffle(&mut shuffled_ips);
+        let timeout = std::time::Duration::from_secs(DISCOVERY_NODE_TIMEOUT_SECS);
+        let queries = fallback_node_ips.iter().map(|node_ip| async move {
+            let result = tokio::time::timeout(
+                timeout,
+                get_discovery_members_for_node_async(context, node_ip),
+            )
+            .await
+            .unwrap_or_else(|_| {
+                Err(TalosError::Connection(format!(
+                    "timed out after {}s",
+                    DISCOVERY_NODE_TIMEOUT_SECS
+                )))
+            });
+            (node_ip, result)
+        });
 
-        for node_ip in shuffled_ips {
-            match get_discovery_members_for_node_async(context, node_ip).await {
+        let mut discovery = MemberDiscovery::default();
+        for (node_ip, result) in futures::future::join_all(queries).await {
+            match result {
                 Ok(members) => {
                     tracing::debug!(
-                        "Successfully fetched discovery members from fallback node {}",
+                        "Fetched {} discovery members from fallback node {}",
+                        members.len(),
                         node_ip
                     );
-                    return Ok(members);
+                    discovery.merge(members);
                 }
                 Err(e) => {
                     tracing::debug!("Fallback node {} failed: {}", node_ip, e);
+                    discovery
+                        .failed_endpoints
+                        .push((node_ip.clone(), e.to_string()));
                     last_error = Some(e);
                 }
             }
         }
+        if !discovery.members.is_empty() {
+            return Ok(discovery);
+        }
     }
 
     Err(last_error.unwrap_or_else(|| TalosError::NoEndpoints(context.to_string())))
@@ -644,6 +1071,14 @@ pub fn get_address_status(node: &str) -> Result<Vec<AddressStatus>, TalosError>
     parse_address_status_yaml(&output)
 }
 
+/// Get link status for a node (MTU, speed, duplex per interface)
+///
+/// Executes: talosctl get links --nodes <node> -o yaml
+pub fn get_link_status(node: &str) -> Result<Vec<LinkStatus>, TalosError> {
+    let output = exec_talosctl(&["get", "links", "--nodes", node, "-o", "yaml"])?;
+    parse_link_status_yaml(&output)
+}
+
 /// Check if KubeSpan is enabled for a node
 ///
 /// Executes: talosctl get kubespanconfig --nodes <node> -o yaml
@@ -765,6 +1200,92 @@ fn parse_volume_status_yaml(yaml_str: &str) -> Result<Vec<VolumeStatus>, TalosEr
     Ok(volumes)
 }
 
+/// Parse volume config YAML output from talosctl, keeping only encrypted volumes
+fn parse_volume_encryption_yaml(yaml_str: &str) -> Result<Vec<VolumeEncryptionConfig>, TalosError> {
+    let mut configs = Vec::new();
+
+    // Split by YAML document separator and parse each
+    for doc_str in yaml_str.split("\n---") {
+        let doc_str = doc_str.trim();
+        if doc_str.is_empty() {
+            continue;
+        }
+
+        let doc: serde_yaml::Value = match serde_yaml::from_str(doc_str) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        let id = doc
+            .get("metadata")
+            .and_then(|m| m.get("id"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+
+        if id.is_empty() {
+            continue;
+        }
+
+        let Some(encryption) = doc.get("spec").and_then(|s| s.get("encryption")) else {
+            continue;
+        };
+
+        // An empty or "none" provider means the volume is not encrypted
+        let provider = encryption
+            .get("provider")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+        if provider.is_empty() || provider == "none" {
+            continue;
+        }
+
+        let cipher = encryption
+            .get("cipher")
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string());
+
+        let key_size = encryption
+            .get("keySize")
+            .and_then(|v| v.as_u64())
+            .filter(|&v| v > 0)
+            .map(|v| v as u32);
+
+        let keys = encryption
+            .get("keys")
+            .and_then(|v| v.as_sequence())
+            .map(|seq| {
+                seq.iter()
+                    .map(|k| EncryptionKeySlot {
+                        slot: k.get("slot").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+                        key_type: k
+                            .get("type")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("unknown")
+                            .to_string(),
+                        lock_to_state: k
+                            .get("lockToSTATE")
+                            .and_then(|v| v.as_bool())
+                            .unwrap_or(false),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        configs.push(VolumeEncryptionConfig {
+            id,
+            provider,
+            cipher,
+            key_size,
+            keys,
+        });
+    }
+
+    Ok(configs)
+}
+
 /// Parse disks YAML output from talosctl
 fn parse_disks_yaml(yaml_str: &str) -> Result<Vec<DiskInfo>, TalosError> {
     let mut disks = Vec::new();
@@ -859,6 +1380,16 @@ fn parse_disks_yaml(yaml_str: &str) -> Result<Vec<DiskInfo>, TalosError> {
             .filter(|s| !s.is_empty())
             .map(|s| s.to_string());
 
+        let symlinks = spec
+            .and_then(|s| s.get("symlinks"))
+            .and_then(|v| v.as_sequence())
+            .map(|seq| {
+                seq.iter()
+                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
         disks.push(DiskInfo {
             id,
             dev_path,
@@ -872,12 +1403,91 @@ fn parse_disks_yaml(yaml_str: &str) -> Result<Vec<DiskInfo>, TalosError> {
             cdrom,
             wwid,
             bus_path,
+            symlinks,
         });
     }
 
     Ok(disks)
 }
 
+/// Parse system disk YAML output from talosctl, returning the disk ID
+fn parse_system_disk_yaml(yaml_str: &str) -> Option<String> {
+    yaml_str
+        .split("\n---")
+        .filter_map(|doc| serde_yaml::from_str::<serde_yaml::Value>(doc.trim()).ok())
+        .find_map(|doc| {
+            doc.get("spec")
+                .and_then(|s| s.get("diskID"))
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+        })
+}
+
+/// Parse discovered volumes YAML output from talosctl, keeping only partitions
+fn parse_partitions_yaml(yaml_str: &str) -> Result<Vec<PartitionInfo>, TalosError> {
+    let mut partitions = Vec::new();
+
+    // Split by YAML document separator and parse each
+    for doc_str in yaml_str.split("\n---") {
+        let doc_str = doc_str.trim();
+        if doc_str.is_empty() {
+            continue;
+        }
+
+        let doc: serde_yaml::Value = match serde_yaml::from_str(doc_str) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        let id = doc
+            .get("metadata")
+            .and_then(|m| m.get("id"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+
+        let spec = doc.get("spec");
+        let str_field = |key: &str| {
+            spec.and_then(|s| s.get(key))
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+        };
+
+        // Whole disks are also discovered volumes - only keep partitions
+        if id.is_empty() || str_field("type").as_deref() != Some("partition") {
+            continue;
+        }
+
+        let size = spec
+            .and_then(|s| s.get("size"))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0);
+
+        let index = spec
+            .and_then(|s| s.get("partition_index"))
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32);
+
+        partitions.push(PartitionInfo {
+            dev_path: str_field("dev_path").unwrap_or_default(),
+            parent: str_field("parent").unwrap_or_default(),
+            size,
+            size_pretty: str_field("pretty_size").unwrap_or_default(),
+            filesystem: str_field("name"),
+            label: str_field("partition_label").or_else(|| str_field("label")),
+            index,
+            id,
+        });
+    }
+
+    // Keep partitions in on-disk order
+    partitions.sort_by(|a, b| a.parent.cmp(&b.parent).then(a.index.cmp(&b.index)));
+
+    Ok(partitions)
+}
+
 /// Parse machine config YAML output from talosctl
 fn parse_machine_config_yaml(yaml_str: &str) -> Result<MachineConfigInfo, TalosError> {
     let doc: serde_yaml::Value = serde_yaml::from_str(yaml_str)
@@ -1136,6 +1746,66 @@ fn parse_address_status_yaml(yaml_str: &str) -> Result<Vec<AddressStatus>, Talos
     Ok(addresses)
 }
 
+/// Parse link status YAML output from talosctl
+fn parse_link_status_yaml(yaml_str: &str) -> Result<Vec<LinkStatus>, TalosError> {
+    let mut links = Vec::new();
+
+    for doc_str in yaml_str.split("\n---") {
+        let doc_str = doc_str.trim();
+        if doc_str.is_empty() {
+            continue;
+        }
+
+        let doc: serde_yaml::Value = match serde_yaml::from_str(doc_str) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        let id = doc
+            .get("metadata")
+            .and_then(|m| m.get("id"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+
+        if id.is_empty() {
+            continue;
+        }
+
+        let spec = doc.get("spec");
+        let spec_str = |key: &str| {
+            spec.and_then(|s| s.get(key))
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string()
+        };
+
+        let mtu = spec
+            .and_then(|s| s.get("mtu"))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0) as u32;
+
+        // Virtual links report 0 or SPEED_UNKNOWN (-1 as u32)
+        let speed_mbit = spec
+            .and_then(|s| s.get("speedMbit"))
+            .and_then(|v| v.as_u64())
+            .filter(|&speed| speed > 0 && speed < u32::MAX as u64)
+            .map(|speed| speed as u32);
+
+        links.push(LinkStatus {
+            link_type: spec_str("type"),
+            kind: spec_str("kind"),
+            duplex: spec_str("duplex"),
+            operational_state: spec_str("operationalState"),
+            id,
+            mtu,
+            speed_mbit,
+        });
+    }
+
+    Ok(links)
+}
+
 /// Parse a duration string like "2.5ms" or "1s" to milliseconds
 fn parse_duration_to_ms(s: &str) -> Option<f64> {
     let s = s.trim();
@@ -1226,6 +1896,119 @@ spec:
         assert_eq!(config.machine_type, Some("controlplane".to_string()));
     }
 
+    #[test]
+    fn test_parse_partitions() {
+        let yaml = r#"
+node: 172.20.0.5
+metadata:
+    namespace: runtime
+    type: DiscoveredVolumes.block.talos.dev
+    id: sda
+spec:
+    dev_path: /dev/sda
+    type: disk
+    size: 10485760000
+    pretty_size: 10 GB
+    name: gpt
+---
+node: 172.20.0.5
+metadata:
+    namespace: runtime
+    type: DiscoveredVolumes.block.talos.dev
+    id: sda6
+spec:
+    dev_path: /dev/sda6
+    type: partition
+    size: 9342812160
+    pretty_size: 9.3 GB
+    parent: sda
+    name: xfs
+    partition_index: 6
+    partition_label: EPHEMERAL
+---
+node: 172.20.0.5
+metadata:
+    namespace: runtime
+    type: DiscoveredVolumes.block.talos.dev
+    id: sda1
+spec:
+    dev_path: /dev/sda1
+    type: partition
+    size: 104857600
+    pretty_size: 105 MB
+    parent: sda
+    name: vfat
+    label: EFI
+    partition_index: 1
+"#;
+
+        let partitions = parse_partitions_yaml(yaml).unwrap();
+        assert_eq!(partitions.len(), 2);
+
+        // Sorted by partition index, disk itself skipped
+        assert_eq!(partitions[0].id, "sda1");
+        assert_eq!(partitions[0].parent, "sda");
+        assert_eq!(partitions[0].filesystem, Some("vfat".to_string()));
+        assert_eq!(partitions[0].label, Some("EFI".to_string()));
+        assert_eq!(partitions[0].index, Some(1));
+
+        assert_eq!(partitions[1].dev_path, "/dev/sda6");
+        assert_eq!(partitions[1].size, 9342812160);
+        assert_eq!(partitions[1].size_pretty, "9.3 GB");
+        assert_eq!(partitions[1].label, Some("EPHEMERAL".to_string()));
+    }
+
+    #[test]
+    fn test_parse_link_status() {
+        let yaml = r#"
+node: 172.20.0.5
+metadata:
+    namespace: network
+    type: LinkStatuses.net.talos.dev
+    id: eth0
+spec:
+    index: 2
+    type: ether
+    kind: ""
+    mtu: 1500
+    operationalState: up
+    speedMbit: 1000
+    duplex: Full
+---
+node: 172.20.0.5
+metadata:
+    namespace: network
+    type: LinkStatuses.net.talos.dev
+    id: flannel.1
+spec:
+    index: 5
+    type: ether
+    kind: vxlan
+    mtu: 1450
+    operationalState: unknown
+    speedMbit: 4294967295
+    duplex: Unknown
+"#;
+
+        let links = parse_link_status_yaml(yaml).unwrap();
+        assert_eq!(links.len(), 2);
+
+        assert_eq!(links[0].id, "eth0");
+        assert_eq!(links[0].mtu, 1500);
+        assert_eq!(links[0].speed_mbit, Some(1000));
+        assert_eq!(links[0].format_speed(), "1 Gb/s");
+        assert!(links[0].is_uplink());
+        assert!(!links[0].is_half_duplex());
+
+        assert_eq!(links[0].encapsulation_overhead(), None);
+
+        assert_eq!(links[1].kind, "vxlan");
+        assert_eq!(links[1].encapsulation_overhead(), Some(50));
+        assert_eq!(links[1].speed_mbit, None);
+        assert_eq!(links[1].format_speed(), "-");
+        assert!(!links[1].is_uplink());
+    }
+
     #[test]
     fn test_parse_disks() {
         let yaml = r#"
@@ -1275,6 +2058,10 @@ spec:
     sub_system: /sys/class/block
     transport: nvme
     rotational: false
+    symlinks:
+        - /dev/disk/by-diskseq/2
+        - /dev/disk/by-id/nvme-Samsung_SSD_970_EVO_Plus_S4EVNG0N123456
+        - /dev/disk/by-path/pci-0000:3d:00.0-nvme-1
 "#;
 
         let disks = parse_disks_yaml(yaml).unwrap();
@@ -1298,6 +2085,154 @@ spec:
         assert_eq!(disks[1].serial, Some("S4EVNG0N123456".to_string()));
         assert_eq!(disks[1].transport, Some("nvme".to_string()));
         assert!(!disks[1].rotational);
+        assert_eq!(
+            disks[1].by_id_path(),
+            Some("/dev/disk/by-id/nvme-Samsung_SSD_970_EVO_Plus_S4EVNG0N123456")
+        );
+
+        // No symlinks published for the first disk
+        assert!(disks[0].symlinks.is_empty());
+        assert_eq!(disks[0].by_id_path(), None);
+    }
+
+    fn test_disk(dev_path: &str, wwid: Option<&str>, serial: Option<&str>) -> DiskInfo {
+        DiskInfo {
+            id: dev_path.trim_start_matches("/dev/").to_string(),
+            dev_path: dev_path.to_string(),
+            size: 0,
+            size_pretty: String::new(),
+            model: None,
+            serial: serial.map(|s| s.to_string()),
+            transport: None,
+            rotational: false,
+            readonly: false,
+            cdrom: false,
+            wwid: wwid.map(|s| s.to_string()),
+            bus_path: None,
+            symlinks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_disk_install_selector_prefers_stable_ids() {
+        let disk = test_disk("/dev/sda", Some("naa.5000c500a1b2c3d4"), Some("ZA1B2C3D"));
+        assert_eq!(
+            disk.install_selector(),
+            Some(("wwid", "naa.5000c500a1b2c3d4"))
+        );
+
+        let disk = test_disk("/dev/sda", None, Some("ZA1B2C3D"));
+        assert_eq!(disk.install_selector(), Some(("serial", "ZA1B2C3D")));
+
+        let disk = test_disk("/dev/vda", None, None);
+        assert_eq!(disk.install_selector(), None);
+    }
+
+    #[test]
+    fn test_disk_is_same_disk_ignores_renamed_dev_path() {
+        let before = test_disk("/dev/sda", Some("naa.5000c500a1b2c3d4"), None);
+        let after = test_disk("/dev/sdb", Some("naa.5000c500a1b2c3d4"), None);
+        let other = test_disk("/dev/sda", Some("naa.5000c500deadbeef"), None);
+
+        assert!(before.is_same_disk(&after));
+        assert!(!before.is_same_disk(&other));
+
+        // Without stable IDs, fall back to the dev path
+        assert!(test_disk("/dev/vda", None, None).is_same_disk(&test_disk("/dev/vda", None, None)));
+    }
+
+    #[test]
+    fn test_install_disk_patch() {
+        let disk = test_disk("/dev/sda", None, Some("ZA1B2C3D"));
+        let patch: serde_yaml::Value = serde_yaml::from_str(&install_disk_patch(&disk)).unwrap();
+        assert_eq!(
+            patch["machine"]["install"]["diskSelector"]["serial"].as_str(),
+            Some("ZA1B2C3D")
+        );
+
+        let disk = test_disk("/dev/vda", None, None);
+        let patch: serde_yaml::Value = serde_yaml::from_str(&install_disk_patch(&disk)).unwrap();
+        assert_eq!(
+            patch["machine"]["install"]["disk"].as_str(),
+            Some("/dev/vda")
+        );
+    }
+
+    #[test]
+    fn test_parse_volume_encryption() {
+        let yaml = r#"node: 192.168.1.10
+metadata:
+    namespace: runtime
+    type: VolumeConfigs.block.talos.dev
+    id: EPHEMERAL
+    version: 2
+    owner: block.VolumeConfigController
+    phase: running
+spec:
+    type: partition
+    provisioning:
+        diskSelector:
+            match: system_disk
+    encryption:
+        provider: luks2
+        keys:
+            - slot: 1
+              type: tpm
+              lockToSTATE: false
+            - slot: 0
+              type: nodeID
+              lockToSTATE: true
+        cipher: ""
+        keySize: 0
+        blockSize: 0
+---
+node: 192.168.1.10
+metadata:
+    namespace: runtime
+    type: VolumeConfigs.block.talos.dev
+    id: STATE
+    version: 1
+    owner: block.VolumeConfigController
+    phase: running
+spec:
+    type: partition
+    provisioning:
+        diskSelector:
+            match: system_disk
+"#;
+
+        let configs = parse_volume_encryption_yaml(yaml).unwrap();
+        assert_eq!(configs.len(), 1);
+
+        let ephemeral = &configs[0];
+        assert_eq!(ephemeral.id, "EPHEMERAL");
+        assert_eq!(ephemeral.provider, "luks2");
+        assert_eq!(ephemeral.cipher, None);
+        assert_eq!(ephemeral.cipher_or_default(), "aes-xts-plain64");
+        assert_eq!(ephemeral.key_size, None);
+        assert_eq!(ephemeral.keys.len(), 2);
+        assert_eq!(
+            ephemeral.key_sources(),
+            "0: nodeID (locked to STATE), 1: tpm"
+        );
+    }
+
+    #[test]
+    fn test_parse_system_disk() {
+        let yaml = r#"node: 192.168.1.10
+metadata:
+    namespace: runtime
+    type: SystemDisks.block.talos.dev
+    id: system-disk
+    version: 1
+    owner: block.SystemDiskController
+    phase: running
+spec:
+    diskID: nvme0n1
+    devPath: /dev/nvme0n1
+"#;
+        assert_eq!(parse_system_disk_yaml(yaml), Some("nvme0n1".to_string()));
+        assert_eq!(parse_system_disk_yaml(""), None);
     }
 
     #[test]
@@ -1362,6 +2297,51 @@ spec:
         assert!(members.is_empty());
     }
 
+    #[test]
+    fn test_member_discovery_merge() {
+        let member = |id: &str| DiscoveryMember {
+            id: id.to_string(),
+            addresses: vec![],
+            hostname: id.to_string(),
+            machine_type: "worker".to_string(),
+            operating_system: String::new(),
+        };
+        let mut discovery = MemberDiscovery::default();
+        discovery.merge(vec![member("cp-1"), member("w-1")]);
+        discovery.merge(vec![member("w-1"), member("w-2")]);
+        discovery
+            .failed_endpoints
+            .push(("10.5.0.3".to_string(), "timed out after 10s".to_string()));
+
+        let ids: Vec<&str> = discovery.members.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["cp-1", "w-1", "w-2"]);
+        assert_eq!(
+            discovery.endpoint_error("10.5.0.3"),
+            Some("timed out after 10s")
+        );
+        assert_eq!(discovery.endpoint_error("10.5.0.2"), None);
+    }
+
+    #[test]
+    fn test_context_node() {
+        let ctx = |endpoints: &[&str], nodes: &[&str]| crate::Context {
+            endpoints: endpoints.iter().map(|e| e.to_string()).collect(),
+            nodes: nodes.iter().map(|n| n.to_string()).collect(),
+            ca: "YQ==".to_string(),
+            crt: "Yg==".to_string(),
+            key: "Yw==".to_string(),
+        };
+        // Local single-node cluster: the forwarded port is skipped for the node
+        let local = ctx(&["127.0.0.1:50001"], &["10.5.0.2"]);
+        assert_eq!(context_node(&local, "talos-default").unwrap(), "10.5.0.2");
+        let local = ctx(&["127.0.0.1:50001"], &[]);
+        assert_eq!(context_node(&local, "talos-default").unwrap(), "127.0.0.1");
+
+        let remote = ctx(&["https://10.0.0.10:50000"], &["10.0.0.2"]);
+        assert_eq!(context_node(&remote, "prod").unwrap(), "10.0.0.10");
+        assert!(context_node(&ctx(&[], &[]), "prod").is_err());
+    }
+
     #[test]
     fn test_parse_discovery_members_invalid_yaml() {
         // Should skip invalid documents and not panic
@@ -1382,31 +2362,4 @@ spec:
         assert_eq!(members.len(), 1);
         assert_eq!(members[0].id, "valid");
     }
-
-    #[test]
-    fn test_shuffle_fallback_ips_does_not_panic() {
-        // Test that shuffle works correctly on various inputs
-        let empty: Vec<String> = vec![];
-        let mut shuffled: Vec<&String> = empty.iter().collect();
-        fastrand::shuffle(&mut shuffled);
-        assert!(shuffled.is_empty());
-
-        let single = ["192.168.1.1".to_string()];
-        let mut shuffled: Vec<&String> = single.iter().collect();
-        fastrand::shuffle(&mut shuffled);
-        assert_eq!(shuffled.len(), 1);
-
-        let multiple = vec![
-            "192.168.1.1".to_string(),
-            "192.168.1.2".to_string(),
-            "192.168.1.3".to_string(),
-        ];
-        let mut shuffled: Vec<&String> = multiple.iter().collect();
-        fastrand::shuffle(&mut shuffled);
-        assert_eq!(shuffled.len(), 3);
-        // All original elements should still be present
-        for ip in &multiple {
-            assert!(shuffled.contains(&ip));
-        }
-    }
 }
@@ -1,12 +1,18 @@
 //! talos-pilot: A terminal UI for managing Talos Linux clusters
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use color_eyre::Result;
 use std::fs::File;
 use std::path::PathBuf;
 use talos_pilot_tui::App;
+use talos_pilot_tui::app::LogLevel;
+use talos_pilot_tui::components::network::key_ports;
+use talos_pilot_tui::components::wizard::WaitLimits;
+use talos_pilot_tui::state::UiState;
+use talos_pilot_tui::transition::TransitionStyle;
+use talos_rs::{ProxyConfig, route_talosctl_through};
 use tracing::Level;
-use tracing_subscriber::{EnvFilter, prelude::*};
+use tracing_subscriber::{EnvFilter, prelude::*, reload};
 
 /// talos-pilot: Terminal UI for Talos Linux clusters
 #[derive(Parser, Debug)]
@@ -21,7 +27,7 @@ struct Cli {
     #[arg(long)]
     config: Option<String>,
 
-    /// Enable debug logging
+    /// Enable debug logging (Ctrl+T also cycles the level at runtime)
     #[arg(short, long)]
     debug: bool,
 
@@ -40,6 +46,110 @@ struct Cli {
     /// Endpoint to connect to in insecure mode (e.g., 192.168.1.100 or 192.168.1.100:50000)
     #[arg(short, long, requires = "insecure")]
     endpoint: Option<String>,
+
+    /// Seconds the insecure-mode wizard waits for a reboot or healthy cluster before giving up
+    #[arg(long, default_value = "300", requires = "insecure")]
+    wait_timeout: u64,
+
+    /// Maximum status polls in a wizard wait state before giving up
+    #[arg(long, default_value = "60", requires = "insecure")]
+    max_poll_attempts: u32,
+
+    /// Smallest install disk the wizard accepts, in GiB
+    #[arg(long, default_value = "10", requires = "insecure")]
+    min_disk_gib: u64,
+
+    /// Machine config patch for the wizard's generated config: inline YAML or
+    /// @file, as for `talosctl gen config --config-patch`. Repeatable
+    #[arg(long = "config-patch", value_name = "PATCH", requires = "insecure")]
+    config_patches: Vec<String>,
+
+    /// Stop the wizard once config files are generated: the talosconfig is
+    /// not merged and nothing is applied to the node
+    #[arg(long, requires = "insecure")]
+    wizard_dry_run: bool,
+
+    /// Directory the wizard generates configs under, one subdirectory per
+    /// cluster (default: ~/.talos-pilot/clusters)
+    #[arg(long, value_name = "DIR", requires = "insecure")]
+    output_dir: Option<String>,
+
+    /// Proxy to reach the Talos API through (e.g., socks5://bastion:1080 or
+    /// http://proxy:3128). Falls back to the TALOS_PILOT_PROXY environment variable
+    #[arg(long, conflicts_with = "insecure")]
+    proxy: Option<ProxyConfig>,
+
+    /// Do not verify the Talos API server certificate for the selected context.
+    /// For lab clusters whose CA is not in the talosconfig; never use in production
+    #[arg(long, conflicts_with = "insecure")]
+    insecure_skip_verify: bool,
+
+    /// Print current network metrics in Prometheus text format and exit (no TUI)
+    #[arg(long, conflicts_with = "insecure")]
+    dump_metrics: bool,
+
+    /// Run diagnostics on all nodes, print a summary and exit (non-zero if any check fails)
+    #[arg(long, conflicts_with_all = ["insecure", "dump_metrics"])]
+    check: bool,
+
+    /// Output format for --check
+    #[arg(long, value_enum, default_value = "table", requires = "check")]
+    format: OutputFormat,
+
+    /// Open a diagnostics snapshot saved with `S` in the diagnostics view,
+    /// read-only and without connecting to a cluster
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["insecure", "dump_metrics", "check"])]
+    snapshot: Option<PathBuf>,
+
+    /// Warn when more than this many sockets are in TIME_WAIT (default: 100;
+    /// raise it on busy ingress nodes)
+    #[arg(long, value_name = "COUNT")]
+    time_wait_warn: Option<usize>,
+
+    /// Warn when more than this many sockets are in CLOSE_WAIT (default: 0)
+    #[arg(long, value_name = "COUNT")]
+    close_wait_warn: Option<usize>,
+
+    /// Draw with ASCII symbols only (auto-enabled when the locale is not UTF-8)
+    #[arg(long)]
+    ascii: bool,
+
+    /// Effect played when switching views
+    #[arg(long, value_enum, default_value = "fade")]
+    transition: Transition,
+
+    /// Switch views instantly, without transitions (for slow terminals)
+    #[arg(long)]
+    no_animations: bool,
+}
+
+/// View transition effect
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Transition {
+    /// Fade the new view in
+    Fade,
+    /// Slide the new view in from the right
+    Slide,
+}
+
+impl From<Transition> for TransitionStyle {
+    fn from(transition: Transition) -> Self {
+        match transition {
+            Transition::Fade => TransitionStyle::Fade,
+            Transition::Slide => TransitionStyle::Slide,
+        }
+    }
+}
+
+/// Output format for non-interactive modes
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    /// Human-readable summary table
+    Table,
+    /// Machine-readable JSON
+    Json,
+    /// One JSON object per check, streamed as each node finishes
+    Ndjson,
 }
 
 #[tokio::main]
@@ -54,27 +164,22 @@ async fn main() -> Result<()> {
     let log_path = resolve_log_path(cli.log_file);
     let log_file = File::create(&log_path)?;
 
-    // Build filter: set base level, but quiet down noisy HTTP/gRPC libraries
-    let filter = if cli.debug {
-        EnvFilter::from_default_env()
-            .add_directive(Level::DEBUG.into())
-            .add_directive("h2=info".parse().unwrap())
-            .add_directive("hyper=info".parse().unwrap())
-            .add_directive("tower=info".parse().unwrap())
-            .add_directive("tonic=info".parse().unwrap())
-            .add_directive("rustls=info".parse().unwrap())
+    // The filter sits behind a reload handle so Ctrl+T can change the level
+    let log_level = if cli.debug {
+        LogLevel::Debug
     } else {
-        EnvFilter::from_default_env().add_directive(Level::INFO.into())
+        LogLevel::Info
     };
+    let (filter, filter_handle) = reload::Layer::new(log_filter(log_level));
 
     tracing_subscriber::registry()
+        .with(filter)
         .with(
             tracing_subscriber::fmt::layer()
                 .with_writer(log_file)
                 .with_ansi(true)
                 .with_target(false),
         )
-        .with(filter)
         .init();
 
     tracing::info!("Starting talos-pilot");
@@ -86,6 +191,79 @@ async fn main() -> Result<()> {
         std::process::exit(1);
     }
 
+    // Proxy from the command line takes precedence over the environment
+    let proxy = match cli.proxy {
+        Some(proxy) => Some(proxy),
+        None if cli.insecure => None,
+        None => ProxyConfig::from_env()?,
+    };
+    if let Some(proxy) = &proxy {
+        tracing::info!("Using proxy: {}", proxy);
+        // talosctl-backed views (discovery, KubeSpan, storage) go through it too
+        route_talosctl_through(proxy).await?;
+    }
+
+    if cli.insecure_skip_verify {
+        tracing::warn!("--insecure-skip-verify: server certificates will not be verified");
+        eprintln!(
+            "WARNING: --insecure-skip-verify is set, the Talos API server certificate will NOT be verified"
+        );
+    }
+
+    // One-shot metrics dump bypasses the TUI entirely
+    if cli.dump_metrics {
+        let metrics = talos_pilot_tui::headless::dump_metrics(
+            cli.config.as_deref(),
+            cli.context.as_deref(),
+            proxy.as_ref(),
+            cli.insecure_skip_verify,
+        )
+        .await?;
+        print!("{}", metrics);
+        return Ok(());
+    }
+
+    // One-shot diagnostics run, exit code reflects cluster health
+    if cli.check {
+        let stream = matches!(cli.format, OutputFormat::Ndjson);
+        let reports = talos_pilot_tui::headless::run_check(
+            cli.config.as_deref(),
+            cli.context.as_deref(),
+            proxy.as_ref(),
+            cli.insecure_skip_verify,
+            |report| {
+                if !stream {
+                    return;
+                }
+                match talos_pilot_tui::headless::format_check_ndjson(report) {
+                    Ok(lines) => print!("{}", lines),
+                    Err(e) => tracing::warn!("Failed to encode {}: {}", report.hostname, e),
+                }
+            },
+        )
+        .await?;
+        match cli.format {
+            OutputFormat::Table => {
+                print!(
+                    "{}",
+                    talos_pilot_tui::headless::format_check_table(&reports)
+                )
+            }
+            OutputFormat::Json => {
+                println!(
+                    "{}",
+                    talos_pilot_tui::headless::format_check_json(&reports)?
+                )
+            }
+            // Already streamed node by node
+            OutputFormat::Ndjson => {}
+        }
+        if talos_pilot_tui::headless::has_failures(&reports) {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     if cli.insecure {
         tracing::info!("Insecure mode enabled");
         if let Some(ep) = &cli.endpoint {
@@ -100,20 +278,87 @@ async fn main() -> Result<()> {
         }
     }
 
+    talos_pilot_tui::ui_ext::init_glyphs(cli.ascii);
+
     // Run the TUI
+    let ui_state = UiState::load();
+    // A broken state file loses its config_output_dir; say so before the
+    // wizard writes secrets to the default directory instead
+    let output_dir_warning = cli
+        .output_dir
+        .is_none()
+        .then(|| ui_state.load_error())
+        .flatten()
+        .map(|_| {
+            format!(
+                "{} failed to parse, config_output_dir ignored",
+                UiState::path().display()
+            )
+        });
     let mut app = App::new(
         cli.config,
         cli.context,
         cli.tail,
         cli.insecure,
         cli.endpoint,
+        proxy,
+    )
+    .with_wait_limits(WaitLimits {
+        max_attempts: cli.max_poll_attempts,
+        max_elapsed: std::time::Duration::from_secs(cli.wait_timeout),
+    })
+    .with_skip_verify(cli.insecure_skip_verify)
+    .with_transitions((!cli.no_animations).then(|| cli.transition.into()))
+    .with_wizard_dry_run(cli.wizard_dry_run)
+    .with_wizard_config_patches(cli.config_patches)
+    .with_wizard_min_disk_size(cli.min_disk_gib * 1024 * 1024 * 1024)
+    .with_wizard_output_dir(ui_state.config_output_dir(cli.output_dir))
+    .with_wizard_output_dir_warning(output_dir_warning)
+    .with_connection_thresholds(
+        ui_state.connection_thresholds(cli.time_wait_warn, cli.close_wait_warn),
+    )
+    .with_key_ports(key_ports(&ui_state.key_ports))
+    .with_smoothing_alpha(ui_state.smoothing_alpha())
+    .with_log_path(log_path)
+    .with_log_level(
+        log_level,
+        Box::new(move |level| {
+            filter_handle
+                .reload(log_filter(level))
+                .map_err(|e| e.to_string())
+        }),
     );
+    if let Some(path) = cli.snapshot {
+        tracing::info!("Opening diagnostics snapshot: {}", path.display());
+        app = app.with_diagnostics_snapshot(path);
+    }
     app.run().await?;
 
     tracing::info!("Goodbye!");
     Ok(())
 }
 
+/// Log filter for `level`: RUST_LOG directives still apply, and above info
+/// the noisy HTTP/gRPC libraries stay at info
+fn log_filter(level: LogLevel) -> EnvFilter {
+    let filter = EnvFilter::from_default_env();
+    match level {
+        LogLevel::Info => filter.add_directive(Level::INFO.into()),
+        LogLevel::Debug | LogLevel::Trace => {
+            let base = if level == LogLevel::Debug {
+                Level::DEBUG
+            } else {
+                Level::TRACE
+            };
+            ["h2", "hyper", "tower", "tonic", "rustls"]
+                .iter()
+                .fold(filter.add_directive(base.into()), |filter, module| {
+                    filter.add_directive(format!("{}=info", module).parse().unwrap())
+                })
+        }
+    }
+}
+
 /// Resolve the log file path, falling back to the platform temp directory.
 fn resolve_log_path(log_file: Option<String>) -> PathBuf {
     match log_file {